@@ -10,8 +10,6 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::mem::MaybeUninit;
-
 use clap::Parser;
 use iceoryx2::prelude::*;
 use iceoryx2_bb_log::set_log_level;
@@ -74,18 +72,33 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn core::error:
         .spawn(|| {
             let sender_a2b = service_a2b
                 .publisher_builder()
-                .initial_max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
+                .initial_max_slice_len(args.payload_size);
+            let sender_a2b = match args.numa_node_participant_1 {
+                Some(node_id) => sender_a2b.numa_node(node_id),
+                None => sender_a2b,
+            }
+            .create()
+            .unwrap();
             let receiver_b2a = service_b2a.subscriber_builder().create().unwrap();
 
+            let payload = vec![0u8; args.payload_size];
+
             startup_barrier.wait();
             start_benchmark_barrier.wait();
 
+            if args.send_slice_copy {
+                for _ in 0..args.iterations {
+                    sender_a2b.send_slice_copy(&payload).unwrap();
+                    while receiver_b2a.receive().unwrap().is_none() {}
+                }
+                return;
+            }
+
             let mut sample = if args.send_copy {
-                let mut sample = sender_a2b.loan_slice_uninit(args.payload_size).unwrap();
-                sample.payload_mut().fill(MaybeUninit::new(0));
-                unsafe { sample.assume_init() }
+                sender_a2b
+                    .loan_slice_uninit(args.payload_size)
+                    .unwrap()
+                    .write_from_slice(&payload)
             } else {
                 unsafe {
                     sender_a2b
@@ -113,19 +126,32 @@ fn perform_benchmark<T: Service>(args: &Args) -> Result<(), Box<dyn core::error:
         .spawn(|| {
             let sender_b2a = service_b2a
                 .publisher_builder()
-                .initial_max_slice_len(args.payload_size)
-                .create()
-                .unwrap();
+                .initial_max_slice_len(args.payload_size);
+            let sender_b2a = match args.numa_node_participant_2 {
+                Some(node_id) => sender_b2a.numa_node(node_id),
+                None => sender_b2a,
+            }
+            .create()
+            .unwrap();
             let receiver_a2b = service_a2b.subscriber_builder().create().unwrap();
 
+            let payload = vec![0u8; args.payload_size];
+
             startup_barrier.wait();
             start_benchmark_barrier.wait();
 
             for _ in 0..args.iterations {
+                if args.send_slice_copy {
+                    while receiver_a2b.receive().unwrap().is_none() {}
+                    sender_b2a.send_slice_copy(&payload).unwrap();
+                    continue;
+                }
+
                 let sample = if args.send_copy {
-                    let mut sample = sender_b2a.loan_slice_uninit(args.payload_size).unwrap();
-                    sample.payload_mut().fill(MaybeUninit::new(0));
-                    unsafe { sample.assume_init() }
+                    sender_b2a
+                        .loan_slice_uninit(args.payload_size)
+                        .unwrap()
+                        .write_from_slice(&payload)
                 } else {
                     unsafe {
                         sender_b2a
@@ -185,6 +211,12 @@ struct Args {
     /// The cpu core that shall be used by participant 2
     #[clap(long, default_value_t = 1)]
     cpu_core_participant_2: usize,
+    /// The numa node the data segment of the publisher of participant 1 shall be bound to
+    #[clap(long)]
+    numa_node_participant_1: Option<usize>,
+    /// The numa node the data segment of the publisher of participant 2 shall be bound to
+    #[clap(long)]
+    numa_node_participant_2: Option<usize>,
     /// The size in bytes of the payload that shall be used
     #[clap(short, long, default_value_t = 8192)]
     payload_size: usize,
@@ -192,6 +224,10 @@ struct Args {
     /// how expensive serialization can be.
     #[clap(long)]
     send_copy: bool,
+    /// Send a copy of the payload with `Publisher::send_slice_copy()` instead of the manual
+    /// loan, copy and send sequence that is used with `--send-copy`.
+    #[clap(long)]
+    send_slice_copy: bool,
     /// The number of additional publishers per service in the setup.
     #[clap(long, default_value_t = 0)]
     number_of_additional_publishers: usize,
@@ -42,6 +42,20 @@ service = (
             iox2.AttributeKey.new("camera_resolution"),
             iox2.AttributeValue.new("1920x1080"),
         )
+        # a key can have multiple values, here the service advertises all
+        # supported schema versions
+        .define(
+            iox2.AttributeKey.new("schema_version"),
+            iox2.AttributeValue.new("1.0"),
+        )
+        .define(
+            iox2.AttributeKey.new("schema_version"),
+            iox2.AttributeValue.new("1.1"),
+        )
+        .define(
+            iox2.AttributeKey.new("schema_version"),
+            iox2.AttributeValue.new("2.0"),
+        )
     )
 )
 
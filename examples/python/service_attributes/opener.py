@@ -38,6 +38,14 @@ subscriber = service.subscriber_builder().create()
 
 print("defined service attributes:", service.attributes)
 
+for key in service.attributes.keys():
+    print(key, "=", service.attributes.get_key_values(key))
+
+supported_schema_versions = service.attributes.get_key_values(
+    iox2.AttributeKey.new("schema_version")
+)
+print("supported schema versions:", supported_schema_versions)
+
 COUNTER = 0
 try:
     while True:
@@ -27,6 +27,15 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
 
     let client = service.client_builder().create()?;
 
+    // wait until a server is available, otherwise the first request would not be delivered
+    println!("waiting for a server ...");
+    while !client.has_server()? {
+        if node.wait(CYCLE_TIME).is_err() {
+            println!("exit");
+            return Ok(());
+        }
+    }
+
     let mut request_counter: u64 = 0;
     let mut response_counter: u64 = 0;
 
@@ -36,6 +36,15 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
         .allocation_strategy(AllocationStrategy::PowerOfTwo)
         .create()?;
 
+    // wait until a server is available, otherwise the first request would not be delivered
+    println!("waiting for a server ...");
+    while !client.has_server()? {
+        if node.wait(CYCLE_TIME).is_err() {
+            println!("exit");
+            return Ok(());
+        }
+    }
+
     let mut counter = 1;
 
     loop {
@@ -0,0 +1,33 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Async Executor Integration
+//!
+//! The `iceoryx2-async` crate integrates the blocking iceoryx2 constructs into async
+//! executors so that an application does not require a dedicated thread per
+//! [`Listener`](iceoryx2::port::listener::Listener) or
+//! [`WaitSet`](iceoryx2::waitset::WaitSet).
+//!
+//! Every executor is provided behind a feature flag:
+//!
+//!  * `tokio` (default) - [`tokio`](crate::tokio) integration
+//!
+//! On unix platforms the underlying file descriptors are registered at the reactor of the
+//! executor, no additional threads are spawned. On all other platforms the constructs are
+//! polled periodically.
+
+#![warn(missing_docs)]
+
+/// Integration of the [`Listener`](iceoryx2::port::listener::Listener) and the
+/// [`WaitSet`](iceoryx2::waitset::WaitSet) into the tokio runtime.
+#[cfg(feature = "tokio")]
+pub mod tokio;
@@ -0,0 +1,63 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::future::Future;
+
+use iceoryx2::port::listener::Listener;
+use iceoryx2::prelude::EventId;
+use iceoryx2::service::Service;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+
+use super::registration::{self, Registration};
+
+/// Extends the [`Listener`] with methods that wait asynchronously for events. On unix
+/// platforms the file descriptor of the [`Listener`] is registered at the tokio reactor.
+///
+/// All methods must be called from within the context of a tokio runtime.
+pub trait AsyncListener {
+    /// Waits until at least one event was received and returns all received [`EventId`]s.
+    /// Dropping the returned [`Future`] cancels the wait, the [`Listener`] remains usable and
+    /// no event is lost.
+    ///
+    /// Only one wait shall be pending per [`Listener`] at a time, a concurrent wait fails
+    /// with [`ListenerWaitError::InternalFailure`].
+    fn wait_async(&self) -> impl Future<Output = Result<Vec<EventId>, ListenerWaitError>>;
+}
+
+impl<S: Service> AsyncListener for Listener<S>
+where
+    Listener<S>: FileDescriptorBased,
+{
+    async fn wait_async(&self) -> Result<Vec<EventId>, ListenerWaitError> {
+        let msg = "Unable to wait asynchronously on the listener";
+        let registration = fail!(from "AsyncListener::wait_async()",
+                                 when Registration::new(unsafe { self.file_descriptor().native_handle() }),
+                                 with ListenerWaitError::InternalFailure,
+                                 "{} since the file descriptor could not be registered at the tokio reactor.", msg);
+        let mut fallback = None;
+
+        loop {
+            let mut event_ids = vec![];
+            self.try_wait_all(|id| event_ids.push(id))?;
+            if !event_ids.is_empty() {
+                return Ok(event_ids);
+            }
+
+            fail!(from "AsyncListener::wait_async()",
+                  when core::future::poll_fn(|cx| registration::poll_ready(&registration, &mut fallback, cx)).await,
+                  with ListenerWaitError::InternalFailure,
+                  "{} since the readiness of the file descriptor could not be acquired.", msg);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_async::tokio::AsyncListener;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let runtime = tokio::runtime::Builder::new_current_thread()
+//!     .enable_all()
+//!     .build()?;
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let event = node
+//!     .service_builder(&"MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let listener = event.listener_builder().create()?;
+//!
+//! runtime.block_on(async {
+//!     for event_id in listener.wait_async().await? {
+//!         println!("received: {:?}", event_id);
+//!     }
+//!     Ok::<(), Box<dyn core::error::Error>>(())
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod listener;
+mod registration;
+mod waitset;
+
+pub use listener::AsyncListener;
+pub use waitset::{AsyncWaitSet, AsyncWaitSetGuard, AsyncWaitSetStream};
@@ -0,0 +1,99 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::future::Future;
+use core::task::{Context, Poll};
+
+#[cfg(unix)]
+mod details {
+    use core::task::{Context, Poll};
+    use std::os::fd::{AsRawFd, RawFd};
+    use tokio::io::{unix::AsyncFd, Interest};
+
+    // the file descriptor is owned by the iceoryx2 construct, the registration must
+    // never close it
+    struct BorrowedRawFd(RawFd);
+
+    impl AsRawFd for BorrowedRawFd {
+        fn as_raw_fd(&self) -> RawFd {
+            self.0
+        }
+    }
+
+    pub(crate) struct Registration(AsyncFd<BorrowedRawFd>);
+
+    impl Registration {
+        pub(crate) fn new(fd: i32) -> std::io::Result<Self> {
+            Ok(Self(AsyncFd::with_interest(
+                BorrowedRawFd(fd),
+                Interest::READABLE,
+            )?))
+        }
+
+        pub(crate) fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            match self.0.poll_read_ready(cx) {
+                Poll::Ready(Ok(mut guard)) => {
+                    guard.clear_ready();
+                    Poll::Ready(Ok(()))
+                }
+                Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    pub(crate) const POLL_INTERVAL: Option<core::time::Duration> = None;
+}
+
+#[cfg(not(unix))]
+mod details {
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+
+    pub(crate) struct Registration;
+
+    impl Registration {
+        pub(crate) fn new(_fd: i32) -> std::io::Result<Self> {
+            Ok(Self)
+        }
+
+        pub(crate) fn poll_ready(&self, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Pending
+        }
+    }
+
+    pub(crate) const POLL_INTERVAL: Option<Duration> = Some(Duration::from_millis(1));
+}
+
+pub(crate) use details::{Registration, POLL_INTERVAL};
+
+/// Polls the registration and, on platforms without a reactor integration, the fallback
+/// timer. Returns [`Poll::Ready`] when the underlying construct shall be checked again.
+pub(crate) fn poll_ready(
+    registration: &Registration,
+    fallback: &mut Option<core::pin::Pin<Box<tokio::time::Sleep>>>,
+    cx: &mut Context<'_>,
+) -> Poll<std::io::Result<()>> {
+    if let Poll::Ready(result) = registration.poll_ready(cx) {
+        return Poll::Ready(result);
+    }
+
+    if let Some(interval) = POLL_INTERVAL {
+        let sleep = fallback.get_or_insert_with(|| Box::pin(tokio::time::sleep(interval)));
+        if sleep.as_mut().poll(cx).is_ready() {
+            *fallback = None;
+            return Poll::Ready(Ok(()));
+        }
+    }
+
+    Poll::Pending
+}
@@ -0,0 +1,301 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::cell::{Cell, RefCell};
+use core::fmt::Debug;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use std::collections::{BTreeMap, VecDeque};
+
+use futures_core::Stream;
+use iceoryx2::prelude::{WaitSet, WaitSetAttachmentId, WaitSetGuard};
+use iceoryx2::service::Service;
+use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError, WaitSetRunResult};
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+
+use super::registration::{self, Registration};
+
+/// Wraps a [`WaitSet`] so that the triggered attachments can be consumed as [`Stream`]
+/// in a tokio runtime. The file descriptors of the notification attachments are registered
+/// at the tokio reactor, deadlines and intervals are realized with tokio timers.
+///
+/// All methods must be called from within the context of a tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use iceoryx2::prelude::*;
+/// use iceoryx2_async::tokio::AsyncWaitSet;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// # let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+/// # runtime.block_on(async {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// let event = node
+///     .service_builder(&"MyEventName".try_into()?)
+///     .event()
+///     .open_or_create()?;
+/// let listener = event.listener_builder().create()?;
+///
+/// let waitset = AsyncWaitSet::new(WaitSetBuilder::new().create::<ipc::Service>()?);
+/// let guard = waitset.attach_notification(&listener)?;
+///
+/// let mut stream = waitset.stream();
+/// while let Some(attachment_id) = stream.next().await {
+///     if attachment_id?.has_event_from(&guard) {
+///         while let Some(event_id) = listener.try_wait_one()? {
+///             println!("received: {:?}", event_id);
+///         }
+///     }
+/// }
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// # })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncWaitSet<S: Service> {
+    waitset: WaitSet<S>,
+    registrations: RefCell<BTreeMap<u64, Registration>>,
+    registration_counter: Cell<u64>,
+}
+
+impl Debug for Registration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Registration")
+    }
+}
+
+/// Is returned when something is attached to the [`AsyncWaitSet`]. As soon as it goes out
+/// of scope the attachment is detached. It dereferences to the underlying [`WaitSetGuard`]
+/// so that it can be used with [`WaitSetAttachmentId::has_event_from()`] and
+/// [`WaitSetAttachmentId::has_missed_deadline()`].
+pub struct AsyncWaitSetGuard<'waitset, 'attachment, S: Service> {
+    guard: WaitSetGuard<'waitset, 'attachment, S>,
+    waitset: &'waitset AsyncWaitSet<S>,
+    registration: Option<u64>,
+}
+
+impl<'waitset, 'attachment, S: Service> core::ops::Deref
+    for AsyncWaitSetGuard<'waitset, 'attachment, S>
+{
+    type Target = WaitSetGuard<'waitset, 'attachment, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<S: Service> Drop for AsyncWaitSetGuard<'_, '_, S> {
+    fn drop(&mut self) {
+        if let Some(id) = self.registration {
+            self.waitset.registrations.borrow_mut().remove(&id);
+        }
+    }
+}
+
+impl<S: Service> AsyncWaitSet<S> {
+    /// Creates a new [`AsyncWaitSet`] from a [`WaitSet`] that has no attachments.
+    pub fn new(waitset: WaitSet<S>) -> Self {
+        Self {
+            waitset,
+            registrations: RefCell::new(BTreeMap::new()),
+            registration_counter: Cell::new(0),
+        }
+    }
+
+    fn register<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        guard: WaitSetGuard<'waitset, 'attachment, S>,
+        attachment: &'attachment T,
+    ) -> Result<AsyncWaitSetGuard<'waitset, 'attachment, S>, WaitSetAttachmentError> {
+        let registration = fail!(from self,
+            when Registration::new(unsafe { attachment.file_descriptor().native_handle() }),
+            with WaitSetAttachmentError::InternalError,
+            "Unable to attach {:?} since its file descriptor could not be registered at the tokio reactor.",
+            attachment);
+
+        let id = self.registration_counter.get();
+        self.registration_counter.set(id + 1);
+        self.registrations.borrow_mut().insert(id, registration);
+
+        Ok(AsyncWaitSetGuard {
+            guard,
+            waitset: self,
+            registration: Some(id),
+        })
+    }
+
+    /// Attaches an object as notification, see [`WaitSet::attach_notification()`].
+    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<AsyncWaitSetGuard<'waitset, 'attachment, S>, WaitSetAttachmentError> {
+        let guard = self.waitset.attach_notification(attachment)?;
+        self.register(guard, attachment)
+    }
+
+    /// Attaches an object as notification with a deadline, see [`WaitSet::attach_deadline()`].
+    pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+    ) -> Result<AsyncWaitSetGuard<'waitset, 'attachment, S>, WaitSetAttachmentError> {
+        let guard = self.waitset.attach_deadline(attachment, deadline)?;
+        self.register(guard, attachment)
+    }
+
+    /// Attaches a tick event, see [`WaitSet::attach_interval()`].
+    pub fn attach_interval(
+        &self,
+        interval: Duration,
+    ) -> Result<AsyncWaitSetGuard<'_, '_, S>, WaitSetAttachmentError> {
+        Ok(AsyncWaitSetGuard {
+            guard: self.waitset.attach_interval(interval)?,
+            waitset: self,
+            registration: None,
+        })
+    }
+
+    /// Returns a [`Stream`] that yields the [`WaitSetAttachmentId`] of every triggered
+    /// attachment. Like in [`WaitSet::wait_and_process()`], a notification is yielded again
+    /// as long as the received events were not consumed. The [`Stream`] ends when a
+    /// termination was requested and the [`WaitSet`] handles termination requests.
+    pub fn stream(&self) -> AsyncWaitSetStream<'_, S> {
+        AsyncWaitSetStream {
+            waitset: self,
+            ready_attachments: VecDeque::new(),
+            timer: None,
+            is_terminated: false,
+        }
+    }
+
+    /// Returns a reference to the underlying [`WaitSet`].
+    pub fn waitset(&self) -> &WaitSet<S> {
+        &self.waitset
+    }
+
+    fn poll_registrations(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let mut result = Poll::Pending;
+        for registration in self.registrations.borrow().values() {
+            if let Poll::Ready(r) = registration.poll_ready(cx) {
+                r?;
+                result = Poll::Ready(Ok(()));
+            }
+        }
+
+        result
+    }
+}
+
+/// The [`Stream`] of triggered attachments of an [`AsyncWaitSet`], created with
+/// [`AsyncWaitSet::stream()`].
+pub struct AsyncWaitSetStream<'waitset, S: Service> {
+    waitset: &'waitset AsyncWaitSet<S>,
+    ready_attachments: VecDeque<WaitSetAttachmentId<S>>,
+    timer: Option<Pin<Box<tokio::time::Sleep>>>,
+    is_terminated: bool,
+}
+
+// the stream does not contain self-referential data, the timers are pinned on the heap
+impl<S: Service> Unpin for AsyncWaitSetStream<'_, S> {}
+
+impl<S: Service> AsyncWaitSetStream<'_, S> {
+    fn collect_ready_attachments(&mut self) -> Result<(), WaitSetRunError> {
+        let ready_attachments = &mut self.ready_attachments;
+        let result = self.waitset.waitset.wait_and_process_once_with_timeout(
+            |id| {
+                ready_attachments.push_back(id);
+                CallbackProgression::Continue
+            },
+            Duration::ZERO,
+        )?;
+
+        if result == WaitSetRunResult::TerminationRequest {
+            self.is_terminated = true;
+        }
+
+        Ok(())
+    }
+
+    fn poll_timer(&mut self, cx: &mut Context<'_>) -> Result<Poll<()>, WaitSetRunError> {
+        let mut timeout = self.waitset.waitset.duration_until_next_deadline()?;
+        if let Some(interval) = registration::POLL_INTERVAL {
+            timeout = Some(timeout.map_or(interval, |t| t.min(interval)));
+        }
+
+        match timeout {
+            Some(timeout) => {
+                let deadline = tokio::time::Instant::now() + timeout;
+                let timer = match &mut self.timer {
+                    Some(timer) => {
+                        timer.as_mut().reset(deadline);
+                        timer
+                    }
+                    None => self
+                        .timer
+                        .insert(Box::pin(tokio::time::sleep_until(deadline))),
+                };
+                Ok(timer.as_mut().poll(cx))
+            }
+            None => {
+                self.timer = None;
+                Ok(Poll::Pending)
+            }
+        }
+    }
+}
+
+impl<S: Service> Stream for AsyncWaitSetStream<'_, S> {
+    type Item = Result<WaitSetAttachmentId<S>, WaitSetRunError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(id) = this.ready_attachments.pop_front() {
+                return Poll::Ready(Some(Ok(id)));
+            }
+
+            if this.is_terminated {
+                return Poll::Ready(None);
+            }
+
+            if let Err(e) = this.collect_ready_attachments() {
+                return Poll::Ready(Some(Err(e)));
+            }
+
+            if !this.ready_attachments.is_empty() || this.is_terminated {
+                continue;
+            }
+
+            match this.waitset.poll_registrations(cx) {
+                Poll::Ready(Ok(())) => continue,
+                Poll::Ready(Err(_)) => {
+                    return Poll::Ready(Some(Err(WaitSetRunError::InternalError)))
+                }
+                Poll::Pending => (),
+            }
+
+            match this.poll_timer(cx) {
+                Ok(Poll::Ready(())) => continue,
+                Ok(Poll::Pending) => return Poll::Pending,
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod tokio_listener {
+    use core::time::Duration;
+
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_async::tokio::AsyncListener;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    fn create_event<S: Service>(node: &Node<S>) -> (Listener<S>, Notifier<S>) {
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        (
+            service.listener_builder().create().unwrap(),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    #[test]
+    fn wait_async_returns_already_received_events<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, notifier) = create_event::<S>(&node);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(12))
+            .unwrap();
+
+        let sut = runtime().block_on(listener.wait_async());
+
+        assert_that!(sut, eq Ok(vec![EventId::new(12)]));
+    }
+
+    #[test]
+    fn wait_async_wakes_up_when_event_arrives<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, notifier) = create_event::<S>(&node);
+
+        let (sut, _) = runtime().block_on(async {
+            tokio::join!(listener.wait_async(), async {
+                tokio::time::sleep(TIMEOUT).await;
+                notifier
+                    .notify_with_custom_event_id(EventId::new(5))
+                    .unwrap();
+            })
+        });
+
+        assert_that!(sut, eq Ok(vec![EventId::new(5)]));
+    }
+
+    #[test]
+    fn wait_async_does_not_return_without_events<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        let sut = runtime()
+            .block_on(async { tokio::time::timeout(TIMEOUT, listener.wait_async()).await });
+
+        assert_that!(sut, is_err);
+    }
+
+    #[test]
+    fn listener_is_usable_after_wait_async_was_cancelled<S: Service>()
+    where
+        <S::Event as Event>::Listener: FileDescriptorBased,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, notifier) = create_event::<S>(&node);
+        let rt = runtime();
+
+        let cancelled =
+            rt.block_on(async { tokio::time::timeout(TIMEOUT, listener.wait_async()).await });
+        assert_that!(cancelled, is_err);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+        let sut = rt.block_on(listener.wait_async());
+        assert_that!(sut, eq Ok(vec![EventId::new(7)]));
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(8))
+            .unwrap();
+        assert_that!(listener.try_wait_one(), eq Ok(Some(EventId::new(8))));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod tokio_waitset {
+    use core::time::Duration;
+
+    use futures::StreamExt;
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2::waitset::WaitSetRunError;
+    use iceoryx2_async::tokio::AsyncWaitSet;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::Event;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    fn create_event<S: Service>(node: &Node<S>) -> (Listener<S>, Notifier<S>) {
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .open_or_create()
+            .unwrap();
+        (
+            service.listener_builder().create().unwrap(),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    #[test]
+    fn stream_yields_triggered_notification<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+
+        runtime().block_on(async {
+            let sut = AsyncWaitSet::new(WaitSetBuilder::new().create::<S>().unwrap());
+            let guard_1 = sut.attach_notification(&listener_1).unwrap();
+            let guard_2 = sut.attach_notification(&listener_2).unwrap();
+            let mut stream = sut.stream();
+
+            let (id, _) = tokio::join!(stream.next(), async {
+                tokio::time::sleep(TIMEOUT).await;
+                notifier_1.notify().unwrap();
+            });
+
+            let id = id.unwrap().unwrap();
+            assert_that!(id.has_event_from(&guard_1), eq true);
+            assert_that!(id.has_event_from(&guard_2), eq false);
+        });
+    }
+
+    #[test]
+    fn stream_yields_interval_ticks<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        runtime().block_on(async {
+            let sut = AsyncWaitSet::new(WaitSetBuilder::new().create::<S>().unwrap());
+            let _notification_guard = sut.attach_notification(&listener).unwrap();
+            let tick_guard = sut.attach_interval(TIMEOUT).unwrap();
+            let mut stream = sut.stream();
+
+            for _ in 0..2 {
+                let id = stream.next().await.unwrap().unwrap();
+                assert_that!(id.has_event_from(&tick_guard), eq true);
+            }
+        });
+    }
+
+    #[test]
+    fn stream_yields_missed_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener, _notifier) = create_event::<S>(&node);
+
+        runtime().block_on(async {
+            let sut = AsyncWaitSet::new(WaitSetBuilder::new().create::<S>().unwrap());
+            let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+            let mut stream = sut.stream();
+
+            let id = stream.next().await.unwrap().unwrap();
+            assert_that!(id.has_missed_deadline(&guard), eq true);
+        });
+    }
+
+    #[test]
+    fn stream_does_not_yield_after_detached_notification<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+
+        runtime().block_on(async {
+            let sut = AsyncWaitSet::new(WaitSetBuilder::new().create::<S>().unwrap());
+            let guard_1 = sut.attach_notification(&listener_1).unwrap();
+            let _guard_2 = sut.attach_notification(&listener_2).unwrap();
+            drop(guard_1);
+
+            notifier_1.notify().unwrap();
+            let mut stream = sut.stream();
+            let sut = tokio::time::timeout(TIMEOUT, stream.next()).await;
+
+            assert_that!(sut, is_err);
+        });
+    }
+
+    #[test]
+    fn stream_without_attachments_yields_error<S: Service>() {
+        runtime().block_on(async {
+            let sut = AsyncWaitSet::new(WaitSetBuilder::new().create::<S>().unwrap());
+            let mut stream = sut.stream();
+
+            assert_that!(stream.next().await, eq Some(Err(WaitSetRunError::NoAttachments)));
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
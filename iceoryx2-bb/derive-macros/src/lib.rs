@@ -19,8 +19,12 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericArgument, LitStr,
+    PathArguments, Type,
+};
 
 /// Implements the [`iceoryx2_bb_elementary_traits::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
@@ -105,8 +109,15 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 }
 
 /// Implements the [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend`] trait when all fields of
-/// the struct implement it and the struct is annotated with `repr(C)`. A type name can be optionally
-/// set with the helper attribute `type_name`.
+/// the type implement it. Structs must be annotated with `repr(C)`, enums with `repr(C)` and/or a
+/// primitive representation like `repr(u8)`. Every generic type parameter is required to
+/// implement [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend`] as well. A type name
+/// can be optionally set with the helper attribute `type_name`.
+///
+/// Fields that are references, raw pointers or heap allocated types like `Box` are rejected. When
+/// a struct is annotated with the helper attribute `no_padding`, it is verified at compile-time
+/// that its layout does not contain any padding bytes. This is only supported for non-generic
+/// structs.
 ///
 /// ```
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
@@ -146,55 +157,62 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 /// needs_zero_copy_send_type(&shape2);
 /// needs_zero_copy_send_type(&shape3);
 /// assert_eq!(unsafe { Shape::type_name() }, "GeometricShape");
+///
+/// #[repr(u8)]
+/// #[derive(ZeroCopySend)]
+/// enum Command<T> {
+///     Stop,
+///     Move([T; 3]),
+/// }
+///
+/// needs_zero_copy_send_type(&Command::Move([1.0f32, 2.0, 3.0]));
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[no_padding]
+/// struct Packet {
+///     id: u32,
+///     length: u32,
+/// }
+///
+/// needs_zero_copy_send_type(&Packet { id: 1, length: 2 });
 /// ```
-#[proc_macro_derive(ZeroCopySend, attributes(type_name))]
+#[proc_macro_derive(ZeroCopySend, attributes(type_name, no_padding))]
 pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    let struct_name = &ast.ident;
 
-    // check for type_name attribute
-    let attributes: &Vec<_> = &ast
-        .attrs
-        .iter()
-        .filter(|a| a.path().is_ident("type_name"))
-        .collect();
-    if attributes.len() > 1 {
-        panic!("Too many attributes provided for ZeroCopySend trait.");
+    match zero_copy_send_impl(ast) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
     }
+}
 
-    let type_name_impl = match attributes.len() {
-        0 => {
-            quote! {
-                unsafe fn type_name() -> &'static str {
-                    core::any::type_name::<Self>()
-                }
-            }
-        }
-        _ => {
-            let type_name: LitStr = attributes[0]
-                .parse_args()
-                .expect("Wrong format for ZeroCopySend attribute. Please provide exactly one \"type_name\" in quotation marks.");
-            quote! {
-                unsafe fn type_name() -> &'static str {
-                    #type_name
-                }
-            }
-        }
-    };
+const PRIMITIVE_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
 
-    // check for repr(C) attribute
-    let has_repr_c = &ast.attrs.iter().any(|a| {
-        a.path().is_ident("repr")
-            && a.parse_args::<syn::Meta>()
-                .ok()
-                .map(|meta| meta.path().is_ident("C"))
-                .unwrap_or(false)
-    });
-    if !has_repr_c {
-        panic!("`#[derive(ZeroCopySend)]` requires the type to be annotated with #[repr(C)]");
+const HEAP_ALLOCATED_TYPES: &[&str] = &["Box", "Vec", "String", "Rc", "Arc"];
+
+fn zero_copy_send_impl(mut ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let type_name_impl = type_name_impl(&ast)?;
+    verify_repr(&ast)?;
+    verify_fields(&ast.data)?;
+    let no_padding_check = no_padding_check(&ast)?;
+
+    // every generic type parameter must be ZeroCopySend as well
+    let type_params = ast
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = ast.generics.make_where_clause();
+    for type_param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#type_param: ZeroCopySend));
     }
 
-    // implement ZeroCopySend
+    let struct_name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
     let zero_copy_send_impl = match ast.data {
@@ -314,19 +332,197 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
                 #type_name_impl
             }
         }
-        _ => {
-            return quote! {compile_error!("ZeroCopySend can only be implemented for structs and enums");}
-                .into();
+        Data::Union(ref data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "ZeroCopySend can only be implemented for structs and enums",
+            ));
         }
     };
 
-    let expanded = quote! {
+    Ok(quote! {
         unsafe impl #impl_generics ZeroCopySend for #struct_name #ty_generics #where_clause {
             #zero_copy_send_impl
         }
+
+        #no_padding_check
+    })
+}
+
+fn type_name_impl(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes: &Vec<_> = &ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("type_name"))
+        .collect();
+    if attributes.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            attributes[1],
+            "Too many attributes provided for ZeroCopySend trait.",
+        ));
+    }
+
+    match attributes.first() {
+        None => Ok(quote! {
+            unsafe fn type_name() -> &'static str {
+                core::any::type_name::<Self>()
+            }
+        }),
+        Some(attribute) => {
+            let type_name: LitStr = attribute.parse_args().map_err(|e| {
+                syn::Error::new(e.span(), "Wrong format for ZeroCopySend attribute. Please provide exactly one \"type_name\" in quotation marks.")
+            })?;
+            Ok(quote! {
+                unsafe fn type_name() -> &'static str {
+                    #type_name
+                }
+            })
+        }
+    }
+}
+
+fn verify_repr(ast: &DeriveInput) -> syn::Result<()> {
+    let mut has_repr_c = false;
+    let mut has_primitive_repr = false;
+    for attribute in ast.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                has_repr_c = true;
+            } else if PRIMITIVE_REPRS.iter().any(|r| meta.path.is_ident(r)) {
+                has_primitive_repr = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                // consume arguments of representations like align(8) or packed(2)
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    match ast.data {
+        Data::Enum(_) if !has_repr_c && !has_primitive_repr => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`#[derive(ZeroCopySend)]` requires the enum to be annotated with #[repr(C)] or a primitive representation like #[repr(u8)]",
+        )),
+        Data::Struct(_) if !has_repr_c => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`#[derive(ZeroCopySend)]` requires the type to be annotated with #[repr(C)]",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn verify_fields(data: &Data) -> syn::Result<()> {
+    let fields: Vec<&syn::Field> = match data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|v| v.fields.iter())
+            .collect(),
+        Data::Union(_) => vec![],
     };
 
-    TokenStream::from(expanded)
+    let mut result: Option<syn::Error> = None;
+    for field in fields {
+        if let Err(e) = verify_field_type(&field.ty) {
+            match result {
+                Some(ref mut error) => error.combine(e),
+                None => result = Some(e),
+            }
+        }
+    }
+
+    match result {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+fn verify_field_type(ty: &Type) -> syn::Result<()> {
+    match ty {
+        Type::Reference(_) => Err(syn::Error::new_spanned(
+            ty,
+            "`#[derive(ZeroCopySend)]` does not support references since they are not valid in another process",
+        )),
+        Type::Ptr(_) => Err(syn::Error::new_spanned(
+            ty,
+            "`#[derive(ZeroCopySend)]` does not support raw pointers since they are not valid in another process",
+        )),
+        Type::Array(array) => verify_field_type(&array.elem),
+        Type::Slice(slice) => verify_field_type(&slice.elem),
+        Type::Group(group) => verify_field_type(&group.elem),
+        Type::Paren(paren) => verify_field_type(&paren.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().try_for_each(verify_field_type),
+        Type::Path(path) => {
+            for segment in &path.path.segments {
+                if HEAP_ALLOCATED_TYPES
+                    .iter()
+                    .any(|heap_type| segment.ident == heap_type)
+                {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        format!("`#[derive(ZeroCopySend)]` does not support the heap allocated type `{}` since its memory is not valid in another process", segment.ident),
+                    ));
+                }
+
+                if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    for argument in &arguments.args {
+                        if let GenericArgument::Type(ty) = argument {
+                            verify_field_type(ty)?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn no_padding_check(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(attribute) = ast.attrs.iter().find(|a| a.path().is_ident("no_padding")) else {
+        return Ok(quote! {});
+    };
+
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "`#[no_padding]` is only supported for structs",
+            ));
+        }
+    };
+
+    if ast.generics.type_params().next().is_some() {
+        return Err(syn::Error::new_spanned(
+            attribute,
+            "`#[no_padding]` is only supported for non-generic structs",
+        ));
+    }
+
+    let struct_name = &ast.ident;
+    let field_sizes = data_struct.fields.iter().map(|f| {
+        let ty = &f.ty;
+        quote! { core::mem::size_of::<#ty>() }
+    });
+    // no leading `0 +`, it would trigger clippy::identity_op in the crate of the user
+    let sum_of_field_sizes = if data_struct.fields.is_empty() {
+        quote! { 0 }
+    } else {
+        quote! { #(#field_sizes)+* }
+    };
+    let message =
+        format!("`{struct_name}` contains padding bytes but is annotated with `#[no_padding]`");
+
+    Ok(quote_spanned! { attribute.span() =>
+        const _: () = assert!(
+            core::mem::size_of::<#struct_name>() == #sum_of_field_sizes,
+            #message
+        );
+    })
 }
 
 #[cfg(doctest)]
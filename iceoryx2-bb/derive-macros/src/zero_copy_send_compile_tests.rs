@@ -50,6 +50,9 @@ fn zero_copy_send_derive_does_not_work_for_unnamed_struct_when_not_all_members_i
 
 /// ``` compile_fail
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// struct Foo(u16);
 ///
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
@@ -57,6 +60,10 @@ fn zero_copy_send_derive_does_not_work_for_unnamed_struct_when_not_all_members_i
 ///     val1: T1,
 ///     val2: T2,
 /// }
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericNamedTestStruct { val1: Foo(1), val2: Foo(2) });
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_named_struct_when_members_do_not_implement_it() {
@@ -66,12 +73,18 @@ fn zero_copy_send_derive_does_not_work_for_generic_named_struct_when_members_do_
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
 /// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 ///
+/// struct Foo(u16);
+///
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// struct GenericNamedTestStruct<T1: ZeroCopySend, T2> {
 ///     val1: T1,
 ///     val2: T2,
 /// }
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericNamedTestStruct { val1: 1u32, val2: Foo(2) });
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_named_struct_when_not_all_members_implement_it()
@@ -80,10 +93,17 @@ fn zero_copy_send_derive_does_not_work_for_generic_named_struct_when_not_all_mem
 
 /// ``` compile_fail
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// struct Foo(u16);
 ///
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// struct GenericUnnamedTestStruct<T1, T2>(T1, T2);
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericUnnamedTestStruct(Foo(1), Foo(2)));
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_unnamed_struct_when_members_do_not_implement_it()
@@ -94,9 +114,15 @@ fn zero_copy_send_derive_does_not_work_for_generic_unnamed_struct_when_members_d
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
 /// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 ///
+/// struct Foo(u16);
+///
 /// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// struct GenericUnnamedTestStruct<T1: ZeroCopySend, T2>(T1, T2);
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericUnnamedTestStruct(1u32, Foo(2)));
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_unnamed_struct_when_not_all_members_implement_it(
@@ -282,6 +308,7 @@ fn zero_copy_send_derive_works_for_enum_with_nested_zero_copy_send_types() {}
 ///
 /// struct Foo(u16);
 ///
+/// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// enum TestEnum {
 ///     Variant1,
@@ -297,6 +324,7 @@ fn zero_copy_send_derive_does_not_work_for_enum_when_not_all_members_implement_i
 ///
 /// struct Foo(u16);
 ///
+/// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// enum TestEnum {
 ///     Variant1,
@@ -311,6 +339,7 @@ fn zero_copy_send_derive_does_not_work_for_tuple_variant_when_not_all_members_im
 ///
 /// struct Foo(u16);
 ///
+/// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// enum TestEnum {
 ///     Variant1,
@@ -322,13 +351,21 @@ fn zero_copy_send_derive_does_not_work_for_struct_variant_when_not_all_members_i
 
 /// ``` compile_fail
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// struct Foo(u16);
 ///
+/// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// enum GenericEnum<T1, T2> {
 ///     Variant1,
 ///     Variant2(T1),
 ///     Variant3 { field: T2 },
 /// }
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericEnum::<Foo, Foo>::Variant1);
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_enum_when_members_do_not_implement_it() {}
@@ -337,12 +374,193 @@ fn zero_copy_send_derive_does_not_work_for_generic_enum_when_members_do_not_impl
 /// use iceoryx2_bb_derive_macros::ZeroCopySend;
 /// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 ///
+/// struct Foo(u16);
+///
+/// #[repr(C)]
 /// #[derive(ZeroCopySend)]
 /// enum GenericEnum<T1: ZeroCopySend, T2> {
 ///     Variant1,
 ///     Variant2(T1),
 ///     Variant3 { field: T2 },
 /// }
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&GenericEnum::<u32, Foo>::Variant1);
 /// ```
 #[cfg(doctest)]
 fn zero_copy_send_derive_does_not_work_for_generic_enum_when_not_all_members_implement_it() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// enum TestEnum {
+///     Variant1,
+///     Variant2(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_enum_without_repr() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+///
+/// #[repr(align(8))]
+/// #[derive(ZeroCopySend)]
+/// enum TestEnum {
+///     Variant1,
+///     Variant2(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_enum_with_non_layout_defining_repr() {}
+
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C, u8)]
+/// #[derive(ZeroCopySend)]
+/// enum TestEnum {
+///     Variant1,
+///     Variant2(u64),
+///     Variant3 { field: [u16; 4] },
+/// }
+///
+/// fn require_zero_copy_send<T: ZeroCopySend>(_: &T) {}
+///
+/// require_zero_copy_send(&TestEnum::Variant3 { field: [1, 2, 3, 4] });
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_works_for_enum_with_combined_repr() {}
+
+/// === Field Types ===
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// struct TestStruct<'a> {
+///     val: &'a u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_reference_field() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// struct TestStruct {
+///     val: *const u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_raw_pointer_field() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// struct TestStruct {
+///     val: Box<u64>,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_box_field() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// struct TestStruct {
+///     val: [Option<Box<u64>>; 2],
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_nested_box_field() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(u8)]
+/// #[derive(ZeroCopySend)]
+/// enum TestEnum<'a> {
+///     Variant1,
+///     Variant2(&'a u32),
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_for_enum_with_reference_field() {}
+
+/// === Padding ===
+
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[no_padding]
+/// struct TestStruct {
+///     val1: u64,
+///     val2: u32,
+///     val3: u32,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_works_with_no_padding_for_struct_without_padding() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[no_padding]
+/// struct TestStruct {
+///     val1: u8,
+///     val2: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_with_no_padding_for_struct_with_padding() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(u8)]
+/// #[derive(ZeroCopySend)]
+/// #[no_padding]
+/// enum TestEnum {
+///     Variant1,
+///     Variant2(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_with_no_padding_for_enum() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// #[derive(ZeroCopySend)]
+/// #[no_padding]
+/// struct TestStruct<T> {
+///     val: T,
+/// }
+/// ```
+#[cfg(doctest)]
+fn zero_copy_send_derive_does_not_work_with_no_padding_for_generic_struct() {}
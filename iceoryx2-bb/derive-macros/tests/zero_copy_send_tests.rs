@@ -87,6 +87,49 @@ mod zero_copy_send {
         T1: ZeroCopySend,
         T2: ZeroCopySend;
 
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    struct GenericTestStructWithoutBounds<T1, T2> {
+        _val1: T1,
+        _val2: [T2; 4],
+    }
+
+    #[allow(dead_code)]
+    #[repr(u8)]
+    #[derive(ZeroCopySend)]
+    enum FieldlessTestEnum {
+        Variant1,
+        Variant2 = 12,
+        Variant3,
+    }
+
+    #[allow(dead_code)]
+    #[repr(u8)]
+    #[derive(ZeroCopySend)]
+    #[type_name("Zoidberg")]
+    enum DataCarryingTestEnum {
+        Variant1,
+        Variant2(u64, Foo),
+        Variant3 { _val: [u32; 2] },
+    }
+
+    #[allow(dead_code)]
+    #[repr(C, u16)]
+    #[derive(ZeroCopySend)]
+    enum GenericTestEnum<T1, T2> {
+        Variant1(T1),
+        Variant2 { _val: [T2; 3] },
+    }
+
+    #[repr(C)]
+    #[derive(ZeroCopySend)]
+    #[no_padding]
+    struct TestStructWithoutPadding {
+        _val1: u64,
+        _val2: [u16; 2],
+        _val3: u32,
+    }
+
     #[test]
     fn zero_copy_send_derive_works_for_named_struct() {
         let sut = NamedTestStruct {
@@ -172,4 +215,53 @@ mod zero_copy_send {
         assert_that!(is_zero_copy_send(&sut_with_attr), eq true);
         assert_that!(unsafe { GenericUnnamedTestStructWithAttr::<i32, i32>::type_name() }, eq "Smeik");
     }
+
+    #[test]
+    fn zero_copy_send_derive_works_for_generic_struct_without_explicit_bounds() {
+        let sut = GenericTestStructWithoutBounds {
+            _val1: 42u8,
+            _val2: [Foo(1), Foo(2), Foo(3), Foo(4)],
+        };
+        assert_that!(is_zero_copy_send(&sut), eq true);
+    }
+
+    #[test]
+    fn zero_copy_send_derive_works_for_enum_with_primitive_repr() {
+        let sut = FieldlessTestEnum::Variant2;
+        assert_that!(is_zero_copy_send(&sut), eq true);
+        assert_that!(sut as u8, eq 12);
+        assert_that!(core::mem::size_of::<FieldlessTestEnum>(), eq 1);
+        assert_that!(unsafe { FieldlessTestEnum::type_name() }, eq core::any::type_name::<FieldlessTestEnum>());
+    }
+
+    #[test]
+    fn zero_copy_send_derive_works_for_data_carrying_enum_with_primitive_repr() {
+        let sut = DataCarryingTestEnum::Variant2(1, Foo(2));
+        assert_that!(is_zero_copy_send(&sut), eq true);
+
+        let sut = DataCarryingTestEnum::Variant3 { _val: [3, 4] };
+        assert_that!(is_zero_copy_send(&sut), eq true);
+        assert_that!(unsafe { DataCarryingTestEnum::type_name() }, eq "Zoidberg");
+    }
+
+    #[test]
+    fn zero_copy_send_derive_works_for_generic_enum_without_explicit_bounds() {
+        let sut = GenericTestEnum::<u64, Foo>::Variant1(73);
+        assert_that!(is_zero_copy_send(&sut), eq true);
+
+        let sut = GenericTestEnum::<u64, Foo>::Variant2 {
+            _val: [Foo(1), Foo(2), Foo(3)],
+        };
+        assert_that!(is_zero_copy_send(&sut), eq true);
+    }
+
+    #[test]
+    fn zero_copy_send_derive_works_for_struct_without_padding() {
+        let sut = TestStructWithoutPadding {
+            _val1: 1,
+            _val2: [2, 3],
+            _val3: 4,
+        };
+        assert_that!(is_zero_copy_send(&sut), eq true);
+    }
 }
@@ -16,21 +16,30 @@
 //! * [`ClockType`] - describes certain types of clocks
 //! * [`nanosleep()`] & [`nanosleep_with_clock()`] - wait a defined amount of time on a custom
 //!   clock
+//! * [`monotonic_clock_domain()`] - identifies the domain in which monotonic time values are
+//!   comparable
 //! * [`AsTimeval`] - trait for easy [`posix::timeval`] conversion, required for low level posix
 //!   calls
 //! * [`AsTimespec`] - trait for easy [`posix::timespec`] conversion, required for low level posix
 //!   calls
 
+use crate::access_mode::AccessMode;
+use crate::file::FileBuilder;
 use crate::system_configuration::Feature;
 use crate::{config::DEFAULT_CLOCK_MODE, handle_errno};
 use core::time::Duration;
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::*;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+const BOOT_ID_PATH: &[u8] = b"/proc/sys/kernel/random/boot_id";
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum TimeError {
@@ -354,3 +363,48 @@ pub fn nanosleep_with_clock(
         v => (UnknownError(v as i32), "Unknown error occurred in \"nanosleep\": {{ duration: {:?}, clock_type: {:?} }}, ({})", duration, clock_type, v)
     );
 }
+
+/// Returns an identifier of the domain of the [`ClockType::Monotonic`] clock. Values of the
+/// monotonic clock are only comparable when they were acquired in the same domain, meaning on
+/// the same host since its last boot. On Linux the identifier is derived from the boot id, on
+/// platforms that do not provide a boot id it is always `0`.
+///
+/// # Examples
+/// ```
+/// use iceoryx2_bb_posix::clock::*;
+///
+/// // values that were acquired within the same process are always comparable
+/// assert_eq!(monotonic_clock_domain(), monotonic_clock_domain());
+/// ```
+pub fn monotonic_clock_domain() -> u64 {
+    static CLOCK_DOMAIN: OnceLock<u64> = OnceLock::new();
+
+    *CLOCK_DOMAIN.get_or_init(|| {
+        let path = match FilePath::new(BOOT_ID_PATH) {
+            Ok(path) => path,
+            Err(_) => return 0,
+        };
+
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(_) => return 0,
+        };
+
+        // files in procfs do not report their size, therefore it is read into a fixed buffer
+        let mut buffer = [0u8; 64];
+        let len = match file.read(&mut buffer) {
+            Ok(len) => len as usize,
+            Err(_) => return 0,
+        };
+
+        let boot_id: String = buffer[..len]
+            .iter()
+            .map(|c| *c as char)
+            .filter(|c| c.is_ascii_hexdigit())
+            .collect();
+        match u128::from_str_radix(&boot_id, 16) {
+            Ok(value) => ((value >> 64) as u64) ^ (value as u64),
+            Err(_) => 0,
+        }
+    })
+}
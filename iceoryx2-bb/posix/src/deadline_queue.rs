@@ -73,6 +73,12 @@ impl DeadlineQueueGuard<'_> {
     pub fn reset(&self) -> Result<(), TimeError> {
         self.deadline_queue.reset(self.index)
     }
+
+    /// Re-arms a deadline that was added with [`DeadlineQueue::add_deadline_at()`] so that it
+    /// fires once at the provided time point.
+    pub fn reset_to(&self, time_point: Duration) {
+        self.deadline_queue.reset_to(self.index, time_point)
+    }
 }
 
 impl Drop for DeadlineQueueGuard<'_> {
@@ -122,11 +128,16 @@ impl DeadlineQueueBuilder {
     }
 }
 
+#[derive(Debug)]
+enum AttachmentType {
+    Interval { period: u128, start_time: u128 },
+    TimePoint { time_point: u128, is_armed: bool },
+}
+
 #[derive(Debug)]
 struct Attachment {
     index: u64,
-    period: u128,
-    start_time: u128,
+    attachment_type: AttachmentType,
 }
 
 impl Attachment {
@@ -137,15 +148,26 @@ impl Attachment {
 
         Ok(Self {
             index,
-            period,
-            start_time,
+            attachment_type: AttachmentType::Interval { period, start_time },
         })
     }
 
+    fn new_time_point(index: u64, time_point: u128) -> Self {
+        Self {
+            index,
+            attachment_type: AttachmentType::TimePoint {
+                time_point,
+                is_armed: true,
+            },
+        }
+    }
+
     fn reset(&mut self, clock_type: ClockType) -> Result<(), TimeError> {
-        let start_time = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
+        if let AttachmentType::Interval { start_time, .. } = &mut self.attachment_type {
+            let now = fail!(from "Attachment::new()", when Time::now_with_clock(clock_type),
                                 "Failed to reset DeadlineQueue attachment since the current time could not be acquired.");
-        self.start_time = start_time.as_duration().as_nanos();
+            *start_time = now.as_duration().as_nanos();
+        }
         Ok(())
     }
 }
@@ -196,6 +218,34 @@ impl DeadlineQueue {
         })
     }
 
+    /// Adds a deadline to the [`DeadlineQueue`] that fires exactly once at the provided
+    /// time point and returns an [`DeadlineQueueGuard`] to identify the attachment uniquely.
+    /// The time point is measured with the [`ClockType`] of the [`DeadlineQueue`], see
+    /// [`DeadlineQueue::clock_type()`]. If the time point is already in the past, the
+    /// deadline is reported as missed with the next call to
+    /// [`DeadlineQueue::missed_deadlines()`]. After it was reported, it can be re-armed with
+    /// [`DeadlineQueueGuard::reset_to()`].
+    pub fn add_deadline_at(&self, time_point: Duration) -> DeadlineQueueGuard<'_> {
+        let current_idx = self.id_count.load(Ordering::Relaxed);
+        self.attachments
+            .borrow_mut()
+            .push(Attachment::new_time_point(
+                current_idx,
+                time_point.as_nanos(),
+            ));
+        self.id_count.fetch_add(1, Ordering::Relaxed);
+
+        DeadlineQueueGuard {
+            deadline_queue: self,
+            index: DeadlineQueueIndex(current_idx),
+        }
+    }
+
+    /// Returns the [`ClockType`] that is used for all time measurements.
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
     fn remove(&self, index: u64) {
         let mut index_to_remove = None;
         for (n, attachment) in self.attachments.borrow().iter().enumerate() {
@@ -222,6 +272,22 @@ impl DeadlineQueue {
         Ok(())
     }
 
+    fn reset_to(&self, index: DeadlineQueueIndex, new_time_point: Duration) {
+        for attachment in &mut *self.attachments.borrow_mut() {
+            if attachment.index == index.0 {
+                if let AttachmentType::TimePoint {
+                    time_point,
+                    is_armed,
+                } = &mut attachment.attachment_type
+                {
+                    *time_point = new_time_point.as_nanos();
+                    *is_armed = true;
+                }
+                break;
+            }
+        }
+    }
+
     /// Returns the waiting duration until the next deadline is reached. If there have been
     /// already deadlines missed it returns a duration of zero.
     pub fn duration_until_next_deadline(&self) -> Result<Duration, TimeError> {
@@ -233,7 +299,7 @@ impl DeadlineQueue {
                         "Unable to return next duration since the current time could not be acquired.");
         let now = now.as_duration().as_nanos();
         let mut has_missed_deadline = false;
-        self.handle_missed_deadlines(now, |_| {
+        self.handle_missed_deadlines(now, false, |_| {
             has_missed_deadline = true;
             CallbackProgression::Stop
         });
@@ -248,8 +314,24 @@ impl DeadlineQueue {
 
         let mut min_time = u128::MAX;
         for attachment in &*self.attachments.borrow() {
-            min_time =
-                min_time.min(attachment.period - (now - attachment.start_time) % attachment.period);
+            match attachment.attachment_type {
+                AttachmentType::Interval { period, start_time } => {
+                    min_time = min_time.min(period - (now - start_time) % period);
+                }
+                AttachmentType::TimePoint {
+                    time_point,
+                    is_armed: true,
+                } => {
+                    min_time = min_time.min(time_point.saturating_sub(now));
+                }
+                AttachmentType::TimePoint {
+                    is_armed: false, ..
+                } => (),
+            }
+        }
+
+        if min_time == u128::MAX {
+            return Ok(Duration::MAX);
         }
 
         Ok(Duration::from_nanos(min_time as _))
@@ -258,34 +340,52 @@ impl DeadlineQueue {
     fn handle_missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
         &self,
         now: u128,
+        disarm_time_points: bool,
         mut call: F,
     ) {
         let last = *self.previous_iteration.borrow();
 
-        for attachment in &*self.attachments.borrow() {
-            let duration_until_last = last.max(attachment.start_time) - attachment.start_time;
-            let duration_until_now = now - attachment.start_time;
-            match attachment.period {
-                0 => {
-                    if matches!(
-                        call(DeadlineQueueIndex(attachment.index)),
-                        CallbackProgression::Stop
-                    ) {
-                        return;
+        // the attachments are not borrowed while the callback is called, so that it is able to
+        // re-arm, reset or remove attachments. Since the callback may remove attachments, they
+        // are visited by their index and not by their position. The attachments are always
+        // sorted by their index since a new attachment acquires a larger index than all others.
+        let mut next_index = 0;
+        loop {
+            let index = {
+                let mut attachments = self.attachments.borrow_mut();
+                let n = attachments.partition_point(|attachment| attachment.index < next_index);
+                let attachment = match attachments.get_mut(n) {
+                    None => return,
+                    Some(attachment) => attachment,
+                };
+                next_index = attachment.index + 1;
+
+                let is_missed = match &mut attachment.attachment_type {
+                    AttachmentType::Interval { period: 0, .. } => true,
+                    AttachmentType::Interval { period, start_time } => {
+                        let duration_until_last = last.max(*start_time) - *start_time;
+                        let duration_until_now = now - *start_time;
+                        duration_until_last / *period < duration_until_now / *period
                     }
-                }
-                _ => {
-                    let last = duration_until_last / attachment.period;
-                    let current = duration_until_now / attachment.period;
-
-                    if last < current
-                        && matches!(
-                            call(DeadlineQueueIndex(attachment.index)),
-                            CallbackProgression::Stop
-                        )
-                    {
-                        return;
+                    AttachmentType::TimePoint {
+                        time_point,
+                        is_armed,
+                    } => {
+                        let is_missed = *is_armed && *time_point <= now;
+                        // time points fire only once and must be re-armed explicitly
+                        if is_missed && disarm_time_points {
+                            *is_armed = false;
+                        }
+                        is_missed
                     }
+                };
+
+                is_missed.then_some(DeadlineQueueIndex(attachment.index))
+            };
+
+            if let Some(index) = index {
+                if let CallbackProgression::Stop = call(index) {
+                    return;
                 }
             }
         }
@@ -295,13 +395,13 @@ impl DeadlineQueue {
     /// and provide the [`DeadlineQueueIndex`] to identify them.
     pub fn missed_deadlines<F: FnMut(DeadlineQueueIndex) -> CallbackProgression>(
         &self,
-        mut call: F,
+        call: F,
     ) -> Result<(), TimeError> {
         let now = fail!(from self, when Time::now_with_clock(self.clock_type),
                         "Unable to return next duration since the current time could not be acquired.");
 
         let now = now.as_duration().as_nanos();
-        self.handle_missed_deadlines(now, |idx| -> CallbackProgression { call(idx) });
+        self.handle_missed_deadlines(now, true, call);
         *self.previous_iteration.borrow_mut() = now;
 
         Ok(())
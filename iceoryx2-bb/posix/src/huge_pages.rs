@@ -0,0 +1,146 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Advises the operating system to back a memory region with transparent huge pages. Large
+//! memory regions backed by huge pages require fewer TLB entries which reduces the TLB pressure
+//! when large payloads are accessed.
+//!
+//! The advice is only a hint. Whether huge pages are actually used depends on the system
+//! configuration, e.g. `/sys/kernel/mm/transparent_hugepage/shmem_enabled` for shared memory.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::huge_pages::*;
+//!
+//! let huge_page_size = HugePages::page_size();
+//! println!("huge page size: {}", huge_page_size);
+//!
+//! if !HugePages::is_supported() {
+//!     println!("huge pages are not supported on this platform");
+//! }
+//! ```
+
+use crate::access_mode::AccessMode;
+use crate::file::{File, FileBuilder};
+use crate::system_configuration::SystemInfo;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+const DEFAULT_HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+const HUGE_PAGE_SIZE_PATH: &[u8] = b"/sys/kernel/mm/transparent_hugepage/hpage_pmd_size";
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum HugePagesAdviceError {
+    NotSupported,
+    MemoryRegionTooSmall,
+    InvalidAddressRange,
+    InsufficientResources,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for HugePagesAdviceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "HugePagesAdviceError::{self:?}")
+    }
+}
+
+impl core::error::Error for HugePagesAdviceError {}
+
+/// Provides access to the transparent huge page support of the system.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct HugePages;
+
+impl HugePages {
+    /// Returns true when the platform supports transparent huge pages, otherwise false.
+    pub fn is_supported() -> bool {
+        posix::POSIX_SUPPORT_HUGE_PAGES
+    }
+
+    /// Returns the size of a huge page. If the size cannot be acquired from the system, 2 MiB
+    /// is returned.
+    pub fn page_size() -> usize {
+        if !Self::is_supported() {
+            return DEFAULT_HUGE_PAGE_SIZE;
+        }
+
+        let path = unsafe { FilePath::new_unchecked(HUGE_PAGE_SIZE_PATH) };
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(_) => return DEFAULT_HUGE_PAGE_SIZE,
+        };
+
+        Self::read_page_size(&file).unwrap_or(DEFAULT_HUGE_PAGE_SIZE)
+    }
+
+    fn read_page_size(file: &File) -> Option<usize> {
+        // files in sysfs do not report their actual size, therefore it is read into a fixed
+        // buffer
+        let mut buffer = [0u8; 32];
+        let len = file.read(&mut buffer).ok()? as usize;
+        let page_size = core::str::from_utf8(&buffer[..len])
+            .ok()?
+            .trim()
+            .parse::<usize>()
+            .ok()?;
+
+        match page_size.is_power_of_two() && page_size > SystemInfo::PageSize.value() {
+            true => Some(page_size),
+            false => None,
+        }
+    }
+
+    /// Advises the operating system to back the provided memory region with transparent huge
+    /// pages. The region is shrunk to the page boundaries that are contained in the region.
+    ///
+    /// # Safety
+    ///
+    ///  * the memory range [address, len] must be a valid mapped memory region
+    ///
+    pub unsafe fn advise(
+        address: *const posix::void,
+        len: usize,
+    ) -> Result<(), HugePagesAdviceError> {
+        let msg = "Unable to advise huge pages";
+        let origin = "HugePages::advise()";
+        if !Self::is_supported() {
+            fail!(from origin, with HugePagesAdviceError::NotSupported,
+                "{} since huge pages are not supported on this platform.", msg);
+        }
+
+        let page_size = SystemInfo::PageSize.value();
+        let start = (address as usize).next_multiple_of(page_size);
+        let end = ((address as usize) + len) / page_size * page_size;
+        if end <= start {
+            fail!(from origin, with HugePagesAdviceError::MemoryRegionTooSmall,
+                "{} since the memory region beginning from {:#16X} with a length of {} does not contain a full page.",
+                msg, address as usize, len);
+        }
+
+        if unsafe { posix::madvise(start as *mut posix::void, end - start, posix::MADV_HUGEPAGE) }
+            == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(HugePagesAdviceError, from origin,
+            Errno::EINVAL => (NotSupported, "{} since the system does not support transparent huge pages for the memory region.", msg),
+            Errno::ENOSYS => (NotSupported, "{} since huge pages are not supported on this platform.", msg),
+            Errno::ENOMEM => (InvalidAddressRange, "{} since the memory region beginning from {:#16X} with a length of {} is not mapped.", msg, start, end - start),
+            Errno::EAGAIN => (InsufficientResources, "{} due to insufficient kernel resources.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
@@ -52,11 +52,13 @@ pub mod file_descriptor_set;
 pub mod file_lock;
 pub mod file_type;
 pub mod group;
+pub mod huge_pages;
 pub mod ipc_capable;
 pub mod memory;
 pub mod memory_lock;
 pub mod metadata;
 pub mod mutex;
+pub mod numa;
 pub mod ownership;
 pub mod permission;
 pub mod process;
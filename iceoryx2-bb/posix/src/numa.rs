@@ -0,0 +1,164 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Binds memory regions to a NUMA node. On systems with multiple NUMA nodes, e.g. dual-socket
+//! machines, accessing memory that is attached to another node is slower than accessing local
+//! memory. Binding a memory region to the node of the accessing processes avoids the cross-node
+//! memory traffic.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::numa::*;
+//!
+//! println!("number of numa nodes: {}", Numa::number_of_nodes());
+//!
+//! if !Numa::is_supported() {
+//!     println!("numa memory policies are not supported on this platform");
+//! }
+//! ```
+
+use crate::access_mode::AccessMode;
+use crate::file::{File, FileBuilder};
+use crate::system_configuration::SystemInfo;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+const ONLINE_NODES_PATH: &[u8] = b"/sys/devices/system/node/online";
+const MAX_NUMBER_OF_NODES: usize = 1024;
+const BITS_PER_MASK_ENTRY: usize = core::mem::size_of::<posix::ulong>() * 8;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NumaBindError {
+    NotSupported,
+    NodeDoesNotExist,
+    MemoryRegionTooSmall,
+    InvalidAddressRange,
+    InsufficientResources,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for NumaBindError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "NumaBindError::{self:?}")
+    }
+}
+
+impl core::error::Error for NumaBindError {}
+
+/// Provides access to the NUMA memory policies of the system.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Numa;
+
+impl Numa {
+    /// Returns true when the platform supports NUMA memory policies, otherwise false.
+    pub fn is_supported() -> bool {
+        posix::POSIX_SUPPORT_NUMA
+    }
+
+    /// Returns the number of NUMA nodes of the system. If the number cannot be acquired from
+    /// the system, 1 is returned.
+    pub fn number_of_nodes() -> usize {
+        if !Self::is_supported() {
+            return 1;
+        }
+
+        let path = unsafe { FilePath::new_unchecked(ONLINE_NODES_PATH) };
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::Read) {
+            Ok(file) => file,
+            Err(_) => return 1,
+        };
+
+        Self::read_number_of_nodes(&file).unwrap_or(1)
+    }
+
+    fn read_number_of_nodes(file: &File) -> Option<usize> {
+        // files in sysfs do not report their actual size, therefore it is read into a fixed
+        // buffer
+        let mut buffer = [0u8; 256];
+        let len = file.read(&mut buffer).ok()? as usize;
+
+        // the node list has the format "0-3,8,10-11"
+        let highest_node_id = core::str::from_utf8(&buffer[..len])
+            .ok()?
+            .trim()
+            .split([',', '-'])
+            .map(|v| v.parse::<usize>().ok())
+            .try_fold(0, |highest, v| v.map(|v| highest.max(v)))?;
+
+        Some((highest_node_id + 1).min(MAX_NUMBER_OF_NODES))
+    }
+
+    /// Binds the provided memory region to the NUMA node with the id `node_id`. Pages of the
+    /// region that are already allocated are moved to the node. The region is shrunk to the
+    /// page boundaries that are contained in the region.
+    ///
+    /// # Safety
+    ///
+    ///  * the memory range [address, len] must be a valid mapped memory region
+    ///
+    pub unsafe fn bind(
+        address: *const posix::void,
+        len: usize,
+        node_id: usize,
+    ) -> Result<(), NumaBindError> {
+        let msg = "Unable to bind memory to numa node";
+        let origin = "Numa::bind()";
+        if !Self::is_supported() {
+            fail!(from origin, with NumaBindError::NotSupported,
+                "{} {} since numa memory policies are not supported on this platform.", msg, node_id);
+        }
+
+        if Self::number_of_nodes() <= node_id {
+            fail!(from origin, with NumaBindError::NodeDoesNotExist,
+                "{} {} since the system has only {} numa nodes.", msg, node_id, Self::number_of_nodes());
+        }
+
+        let page_size = SystemInfo::PageSize.value();
+        let start = (address as usize).next_multiple_of(page_size);
+        let end = ((address as usize) + len) / page_size * page_size;
+        if end <= start {
+            fail!(from origin, with NumaBindError::MemoryRegionTooSmall,
+                "{} {} since the memory region beginning from {:#16X} with a length of {} does not contain a full page.",
+                msg, node_id, address as usize, len);
+        }
+
+        let mut node_mask: [posix::ulong; MAX_NUMBER_OF_NODES / BITS_PER_MASK_ENTRY] =
+            [0; MAX_NUMBER_OF_NODES / BITS_PER_MASK_ENTRY];
+        node_mask[node_id / BITS_PER_MASK_ENTRY] |= 1 << (node_id % BITS_PER_MASK_ENTRY);
+
+        if unsafe {
+            posix::mbind(
+                start as *mut posix::void,
+                end - start,
+                posix::MPOL_BIND,
+                node_mask.as_ptr(),
+                MAX_NUMBER_OF_NODES as _,
+                posix::MPOL_MF_MOVE,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(NumaBindError, from origin,
+            Errno::ENOSYS => (NotSupported, "{} {} since numa memory policies are not supported by the system.", msg, node_id),
+            Errno::EINVAL => (NodeDoesNotExist, "{} {} since the numa node is not available.", msg, node_id),
+            Errno::EFAULT => (InvalidAddressRange, "{} {} since the memory region beginning from {:#16X} with a length of {} is not mapped.", msg, node_id, start, end - start),
+            Errno::ENOMEM => (InsufficientResources, "{} {} due to insufficient kernel memory.", msg, node_id),
+            v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, node_id, v)
+        );
+    }
+}
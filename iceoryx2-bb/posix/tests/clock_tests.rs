@@ -83,3 +83,13 @@ fn clock_time_as_timespec_works() {
     assert_that!(timespec.tv_sec, eq now.as_duration().as_secs() as _);
     assert_that!(timespec.tv_nsec, eq now.as_duration().subsec_nanos() as _);
 }
+
+#[test]
+fn clock_monotonic_clock_domain_is_derived_from_boot_id_when_available() {
+    let sut = monotonic_clock_domain();
+
+    assert_that!(monotonic_clock_domain(), eq sut);
+    if std::path::Path::new("/proc/sys/kernel/random/boot_id").exists() {
+        assert_that!(sut, ne 0);
+    }
+}
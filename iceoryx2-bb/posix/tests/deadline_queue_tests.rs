@@ -13,6 +13,7 @@
 mod deadline_queue {
     use core::time::Duration;
     use iceoryx2_bb_elementary::CallbackProgression;
+    use iceoryx2_bb_posix::clock::Time;
     use iceoryx2_bb_posix::deadline_queue::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -224,4 +225,162 @@ mod deadline_queue {
         let next_deadline = sut.duration_until_next_deadline().unwrap();
         assert_that!(next_deadline, ne Duration::ZERO);
     }
+
+    fn now(sut: &DeadlineQueue) -> Duration {
+        Time::now_with_clock(sut.clock_type())
+            .unwrap()
+            .as_duration()
+    }
+
+    #[test]
+    fn time_point_in_the_past_is_reported_immediately() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_at(now(&sut) - Duration::from_millis(1));
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::ZERO);
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, len 1);
+        assert_that!(missed_deadlines, contains guard.index());
+    }
+
+    #[test]
+    fn time_point_is_reported_only_once() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let _guard = sut.add_deadline_at(now(&sut) + Duration::from_millis(10));
+
+        let duration = sut.duration_until_next_deadline().unwrap();
+        assert_that!(duration, le Duration::from_millis(10));
+        assert_that!(duration, gt Duration::ZERO);
+
+        std::thread::sleep(duration);
+
+        for expected_number_of_deadlines in [1, 0] {
+            let mut missed_deadlines = vec![];
+            sut.missed_deadlines(|idx| {
+                missed_deadlines.push(idx);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+            assert_that!(missed_deadlines, len expected_number_of_deadlines);
+        }
+
+        assert_that!(sut.duration_until_next_deadline().unwrap(), eq Duration::MAX);
+    }
+
+    #[test]
+    fn time_point_can_be_rearmed() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_at(now(&sut));
+        let _interval_guard = sut
+            .add_deadline_interval(Duration::from_secs(1000))
+            .unwrap();
+
+        let mut number_of_missed_deadlines = 0;
+        for _ in 0..2 {
+            sut.missed_deadlines(|idx| {
+                assert_that!(idx, eq guard.index());
+                number_of_missed_deadlines += 1;
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        }
+        assert_that!(number_of_missed_deadlines, eq 1);
+
+        guard.reset_to(now(&sut) + Duration::from_secs(100));
+        let duration = sut.duration_until_next_deadline().unwrap();
+        assert_that!(duration, gt Duration::from_secs(99));
+        assert_that!(duration, le Duration::from_secs(100));
+
+        guard.reset_to(now(&sut) - Duration::from_secs(1));
+        sut.missed_deadlines(|idx| {
+            assert_that!(idx, eq guard.index());
+            number_of_missed_deadlines += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_missed_deadlines, eq 2);
+    }
+
+    #[test]
+    fn time_point_can_be_rearmed_while_missed_deadlines_are_handled() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let guard = sut.add_deadline_at(now(&sut));
+
+        let mut number_of_missed_deadlines = 0;
+        for _ in 0..3 {
+            sut.missed_deadlines(|idx| {
+                assert_that!(idx, eq guard.index());
+                number_of_missed_deadlines += 1;
+                guard.reset_to(now(&sut) - Duration::from_secs(1));
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        }
+
+        assert_that!(number_of_missed_deadlines, eq 3);
+    }
+
+    #[test]
+    fn removing_an_attachment_while_missed_deadlines_are_handled_reports_all_others_once() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let mut guards: Vec<_> = (0..4)
+            .map(|_| Some(sut.add_deadline_at(now(&sut))))
+            .collect();
+        let indices: Vec<_> = guards
+            .iter()
+            .map(|guard| guard.as_ref().unwrap().index())
+            .collect();
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            // the callback of the second attachment detaches the first one which moves all
+            // remaining attachments one position forward
+            if idx == indices[1] {
+                guards[0].take();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, eq indices);
+    }
+
+    #[test]
+    fn attachment_removed_while_missed_deadlines_are_handled_is_not_reported() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let mut guards: Vec<_> = (0..3)
+            .map(|_| Some(sut.add_deadline_at(now(&sut))))
+            .collect();
+        let indices: Vec<_> = guards
+            .iter()
+            .map(|guard| guard.as_ref().unwrap().index())
+            .collect();
+
+        let mut missed_deadlines = vec![];
+        sut.missed_deadlines(|idx| {
+            missed_deadlines.push(idx);
+            if idx == indices[0] {
+                guards[1].take();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadlines, eq vec![indices[0], indices[2]]);
+    }
 }
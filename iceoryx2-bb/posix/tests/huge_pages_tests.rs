@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::huge_pages::*;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_pal_posix::posix;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
+
+#[test]
+fn huge_page_size_is_a_power_of_two_larger_than_the_page_size() {
+    let sut = HugePages::page_size();
+
+    assert_that!(sut.is_power_of_two(), eq true);
+    assert_that!(sut, gt SystemInfo::PageSize.value());
+}
+
+#[test]
+fn huge_pages_advice_fails_for_region_without_full_page() {
+    test_requires!(POSIX_SUPPORT_HUGE_PAGES);
+
+    let some_memory = [0u8; 16];
+
+    let sut = unsafe {
+        HugePages::advise(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+        )
+    };
+    assert_that!(sut, eq Err(HugePagesAdviceError::MemoryRegionTooSmall));
+}
+
+#[test]
+fn huge_pages_advice_for_heap_memory_succeeds_or_is_not_supported() {
+    test_requires!(POSIX_SUPPORT_HUGE_PAGES);
+
+    let some_memory = vec![0u8; 2 * HugePages::page_size()];
+
+    let sut = unsafe {
+        HugePages::advise(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+        )
+    };
+    // the advice is rejected when the kernel is compiled without transparent huge pages
+    assert_that!(sut.is_ok() || sut == Err(HugePagesAdviceError::NotSupported), eq true);
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::numa::*;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_pal_posix::posix;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_NUMA;
+
+#[test]
+fn numa_system_has_at_least_one_node() {
+    assert_that!(Numa::number_of_nodes(), ge 1);
+}
+
+#[test]
+fn numa_bind_fails_for_non_existing_node() {
+    test_requires!(POSIX_SUPPORT_NUMA);
+
+    let some_memory = vec![0u8; 2 * SystemInfo::PageSize.value()];
+
+    let sut = unsafe {
+        Numa::bind(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+            Numa::number_of_nodes(),
+        )
+    };
+    assert_that!(sut, eq Err(NumaBindError::NodeDoesNotExist));
+}
+
+#[test]
+fn numa_bind_fails_for_region_without_full_page() {
+    test_requires!(POSIX_SUPPORT_NUMA);
+
+    let some_memory = [0u8; 16];
+
+    let sut = unsafe {
+        Numa::bind(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+            0,
+        )
+    };
+    assert_that!(sut, eq Err(NumaBindError::MemoryRegionTooSmall));
+}
+
+#[test]
+fn numa_bind_for_heap_memory_succeeds_or_is_not_supported() {
+    test_requires!(POSIX_SUPPORT_NUMA);
+
+    let some_memory = vec![0u8; 4 * SystemInfo::PageSize.value()];
+
+    let sut = unsafe {
+        Numa::bind(
+            some_memory.as_ptr() as *const posix::void,
+            some_memory.len(),
+            0,
+        )
+    };
+    // the binding is rejected when the kernel is compiled without numa support
+    assert_that!(sut.is_ok() || sut == Err(NumaBindError::NotSupported), eq true);
+}
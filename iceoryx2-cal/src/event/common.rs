@@ -298,6 +298,33 @@ pub mod details {
             unsafe { self.storage.get().signal_mechanism.notify()? };
             Ok(())
         }
+
+        fn notify_multiple(&self, ids: &[TriggerId]) -> Result<(), NotifierNotifyError> {
+            let msg = "Failed to notify listener with multiple TriggerIds";
+            if ids.is_empty() {
+                return Ok(());
+            }
+
+            if !self.storage.get().has_listener.load(Ordering::Relaxed) {
+                fail!(from self, with NotifierNotifyError::Disconnected,
+                    "{} since the listener is no longer connected.", msg);
+            }
+
+            // all TriggerIds are validated before the first one is added, so that either all
+            // or none of them are delivered
+            let trigger_id_max = self.storage.get().id_tracker.trigger_id_max();
+            if let Some(id) = ids.iter().find(|id| trigger_id_max < **id) {
+                fail!(from self, with NotifierNotifyError::TriggerIdOutOfBounds,
+                    "{} since the TriggerId {:?} is greater than the max supported TriggerId {:?}.",
+                    msg, id, trigger_id_max);
+            }
+
+            for id in ids {
+                unsafe { self.storage.get().id_tracker.add(*id)? };
+            }
+            unsafe { self.storage.get().signal_mechanism.notify()? };
+            Ok(())
+        }
     }
 
     #[derive(Debug)]
@@ -21,6 +21,8 @@ pub mod unix_datagram_socket;
 
 use core::{fmt::Debug, time::Duration};
 
+use iceoryx2_bb_log::fail;
+
 pub use crate::named_concept::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
 pub use iceoryx2_bb_system_types::file_name::*;
 pub use iceoryx2_bb_system_types::path::Path;
@@ -108,6 +110,25 @@ pub trait Notifier: NamedConcept + Debug + Send {
         TriggerId::new(usize::MAX)
     }
     fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError>;
+
+    /// Notifies the listener with all provided [`TriggerId`]s. When one [`TriggerId`] exceeds
+    /// [`Notifier::trigger_id_max()`] none of them is delivered. The default implementation
+    /// sends them one after another, implementations that are able to deliver them as one
+    /// unit, so that either all or none of them arrive, override it.
+    fn notify_multiple(&self, ids: &[TriggerId]) -> Result<(), NotifierNotifyError> {
+        let trigger_id_max = self.trigger_id_max();
+        if let Some(id) = ids.iter().find(|id| trigger_id_max < **id) {
+            fail!(from self, with NotifierNotifyError::TriggerIdOutOfBounds,
+                "Failed to notify listener with multiple TriggerIds since the TriggerId {:?} is greater than the max supported TriggerId {:?}.",
+                id, trigger_id_max);
+        }
+
+        for id in ids {
+            self.notify(*id)?;
+        }
+
+        Ok(())
+    }
 }
 
 pub trait NotifierBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -10,10 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::mem::MaybeUninit;
+use core::{cell::UnsafeCell, mem::MaybeUninit};
 
 pub use crate::event::*;
 use crate::static_storage::file::NamedConceptConfiguration;
+use iceoryx2_bb_container::vec;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
     file_descriptor::FileDescriptorBased, file_descriptor_set::SynchronousMultiplexing,
@@ -138,14 +139,10 @@ impl NamedConcept for Notifier {
     }
 }
 
-impl crate::event::Notifier for Notifier {
-    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
-        let msg = "Failed to notify event::unix_datagram_socket::Listener";
+impl Notifier {
+    fn send(&self, ids: &[TriggerId], msg: &str) -> Result<(), NotifierNotifyError> {
         match self.sender.try_send(unsafe {
-            core::slice::from_raw_parts(
-                (&id as *const TriggerId).cast(),
-                core::mem::size_of::<TriggerId>(),
-            )
+            core::slice::from_raw_parts(ids.as_ptr().cast(), core::mem::size_of_val(ids))
         }) {
             Ok(true) => Ok(()),
             Ok(false) | Err(UnixDatagramSendError::MessagePartiallySend(_)) => {
@@ -166,6 +163,31 @@ impl crate::event::Notifier for Notifier {
     }
 }
 
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        self.send(
+            core::slice::from_ref(&id),
+            "Failed to notify event::unix_datagram_socket::Listener",
+        )
+    }
+
+    fn notify_multiple(&self, ids: &[TriggerId]) -> Result<(), NotifierNotifyError> {
+        let msg = "Failed to notify event::unix_datagram_socket::Listener with multiple TriggerIds";
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        if MAX_BATCH_SIZE < ids.len() {
+            fail!(from self, with NotifierNotifyError::FailedToDeliverSignal,
+                "{} since the number of TriggerIds {} exceeds the maximum batch size of {}.",
+                msg, ids.len(), MAX_BATCH_SIZE);
+        }
+
+        // all TriggerIds are sent in one datagram, the socket either accepts all of them or none
+        self.send(ids, msg)
+    }
+}
+
 #[derive(Debug)]
 pub struct NotifierBuilder {
     name: FileName,
@@ -220,6 +242,9 @@ impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
 pub struct Listener {
     receiver: UnixDatagramReceiver,
     name: FileName,
+    // TriggerIds of a received batch that were not yet handed out, stored in reverse order.
+    // Its capacity of MAX_BATCH_SIZE is acquired on creation so that receiving never allocates.
+    pending: UnsafeCell<vec::Vec<TriggerId>>,
 }
 
 impl FileDescriptorBased for Listener {
@@ -242,11 +267,16 @@ impl Listener {
         error_msg: &str,
         mut wait_call: F,
     ) -> Result<Option<TriggerId>, ListenerWaitError> {
-        let mut id_buffer = MaybeUninit::uninit();
+        let pending = unsafe { &mut *self.pending.get() };
+        if let Some(id) = pending.pop() {
+            return Ok(Some(id));
+        }
+
+        let mut id_buffer = [MaybeUninit::<TriggerId>::uninit(); MAX_BATCH_SIZE];
         match wait_call(self, unsafe {
             core::slice::from_raw_parts_mut(
                 id_buffer.as_mut_ptr() as *mut u8,
-                core::mem::size_of::<TriggerId>(),
+                core::mem::size_of_val(&id_buffer),
             )
         }) {
             Ok(v) => {
@@ -254,12 +284,17 @@ impl Listener {
                     return Ok(None);
                 }
 
-                if v as usize != core::mem::size_of::<TriggerId>() {
+                if v as usize % core::mem::size_of::<TriggerId>() != 0 {
                     fail!(from self, with ListenerWaitError::ContractViolation,
-                        "{} since the expected amount of received bytes {} does not match the expected amount of bytes {}.",
+                        "{} since the amount of received bytes {} is not a multiple of the expected amount of bytes {}.",
                         error_msg, v, core::mem::size_of::<TriggerId>());
                 }
-                Ok(Some(unsafe { id_buffer.assume_init() }))
+
+                let number_of_ids = v as usize / core::mem::size_of::<TriggerId>();
+                for id in id_buffer[1..number_of_ids].iter().rev() {
+                    pending.push(unsafe { id.assume_init() });
+                }
+                Ok(Some(unsafe { id_buffer[0].assume_init() }))
             }
             Err(v) => {
                 fail!(from self, with ListenerWaitError::InternalFailure,
@@ -302,7 +337,7 @@ impl crate::event::Listener for Listener {
             callback(id);
 
             counter += 1;
-            if counter == MAX_BATCH_SIZE {
+            if MAX_BATCH_SIZE <= counter && unsafe { &*self.pending.get() }.is_empty() {
                 break;
             }
         }
@@ -367,6 +402,7 @@ impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
             Ok(r) => Ok(Listener {
                 receiver: r,
                 name: self.name,
+                pending: UnsafeCell::new(vec::Vec::new(MAX_BATCH_SIZE)),
             }),
             Err(UnixDatagramReceiverCreationError::SocketFileAlreadyExists) => {
                 fail!(from self, with ListenerCreateError::AlreadyExists,
@@ -24,12 +24,13 @@ use crate::shared_memory::{
     SharedMemoryOpenError, ShmAllocator,
 };
 use crate::shm_allocator::pool_allocator::PoolAllocator;
-use crate::shm_allocator::ShmAllocationError;
+use crate::shm_allocator::{SharedMemorySetupHint, ShmAllocationError};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_container::slotmap::{SlotMap, SlotMapKey};
 use iceoryx2_bb_elementary_traits::allocator::AllocationError;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::huge_pages::HugePages;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
@@ -60,6 +61,18 @@ struct MemoryConfig<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> {
     base_name: FileName,
     shm: Shm::Configuration,
     allocator_config_hint: Allocator::Configuration,
+    use_huge_pages: bool,
+    numa_node: Option<usize>,
+}
+
+impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> MemoryConfig<Allocator, Shm> {
+    fn segment_size(&self, payload_size: usize) -> usize {
+        // chunks aligned to the huge page size may require padding in front of the first chunk
+        match self.use_huge_pages {
+            true => payload_size + HugePages::page_size() - 1,
+            false => payload_size,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -198,6 +211,8 @@ where
                 base_name: name.clone(),
                 allocator_config_hint: Allocator::Configuration::default(),
                 shm: Shm::Configuration::default(),
+                use_huge_pages: false,
+                numa_node: None,
             },
             shared_state: SharedState {
                 allocation_strategy: AllocationStrategy::default(),
@@ -243,6 +258,16 @@ where
         self
     }
 
+    fn use_huge_pages(mut self, value: bool) -> Self {
+        self.config.use_huge_pages = value;
+        self
+    }
+
+    fn numa_node(mut self, node_id: usize) -> Self {
+        self.config.numa_node = Some(node_id);
+        self
+    }
+
     fn create(mut self) -> Result<DynamicMemory<Allocator, Shm>, SharedMemoryCreateError> {
         let msg = "Unable to create ResizableSharedMemory";
         let origin = format!("{self:?}");
@@ -567,10 +592,16 @@ where
         segment_id: SegmentId,
         payload_size: usize,
     ) -> Result<Shm, SharedMemoryCreateError> {
-        Self::segment_builder(&config.base_name, &config.shm, segment_id)
+        let builder = Self::segment_builder(&config.base_name, &config.shm, segment_id)
             .has_ownership(true)
-            .size(payload_size)
-            .create(&config.allocator_config_hint)
+            .use_huge_pages(config.use_huge_pages)
+            .size(config.segment_size(payload_size));
+
+        match config.numa_node {
+            Some(node_id) => builder.numa_node(node_id),
+            None => builder,
+        }
+        .create(&config.allocator_config_hint)
     }
 
     fn open_segment(
@@ -614,11 +645,22 @@ where
                 layout, Self::max_number_of_reallocations());
         };
 
-        state.builder_config.allocator_config_hint = adjusted_segment_setup.config;
+        self.replace_current_segment(segment_id, adjusted_segment_setup)?;
+
+        Ok(())
+    }
+
+    fn replace_current_segment(
+        &self,
+        segment_id: SlotMapKey,
+        segment_setup: SharedMemorySetupHint<Allocator::Configuration>,
+    ) -> Result<(), SharedMemoryCreateError> {
+        let state = self.state_mut();
+        state.builder_config.allocator_config_hint = segment_setup.config;
         let shm = Self::create_segment(
             &state.builder_config,
             SegmentId::new(segment_id.value() as u8),
-            adjusted_segment_setup.payload_size,
+            segment_setup.payload_size,
         )?;
 
         match state.shared_memory_map.get(state.current_idx) {
@@ -629,8 +671,7 @@ where
             }
             None => {
                 fatal_panic!(from self,
-                        "This should never happen! {msg} {:?} since the current segment id is unavailable.",
-                        layout)
+                        "This should never happen! Unable to replace the current segment since the current segment id is unavailable.")
             }
         }
 
@@ -707,6 +748,24 @@ where
                         segment_id),
         }
     }
+
+    fn number_of_buckets(&self, segment_id: SegmentId) -> usize {
+        let segment_id_key = SlotMapKey::new(segment_id.value() as usize);
+        match self.state().shared_memory_map.get(segment_id_key) {
+            Some(entry) => entry.shm.number_of_buckets(),
+            None => fatal_panic!(from self,
+                        "This should never happen! Unable to acquire number of buckets since the segment {:?} does not exist.",
+                        segment_id),
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.state()
+            .shared_memory_map
+            .iter()
+            .map(|(_, entry)| entry.shm.bucket_size() * entry.shm.number_of_buckets())
+            .sum()
+    }
 }
 
 impl<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>> ResizableSharedMemory<Allocator, Shm>
@@ -726,6 +785,22 @@ where
         self.state().shared_memory_map.len()
     }
 
+    fn has_huge_pages(&self) -> bool {
+        let state = self.state();
+        match state.shared_memory_map.get(state.current_idx) {
+            Some(entry) => entry.shm.has_huge_pages(),
+            None => false,
+        }
+    }
+
+    fn numa_node(&self) -> Option<usize> {
+        let state = self.state();
+        state
+            .shared_memory_map
+            .get(state.current_idx)
+            .and_then(|entry| entry.shm.numa_node())
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ResizableShmAllocationError> {
         let msg = "Unable to allocate memory";
         let state = self.state_mut();
@@ -750,4 +825,54 @@ where
     unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
         self.perform_deallocation(offset, |entry| entry.shm.deallocate(offset, layout));
     }
+
+    fn shrink_to_fit(&self) -> Result<usize, ResizableShmAllocationError> {
+        let msg = "Unable to shrink the current segment";
+        let state = self.state();
+        let initial_segment_setup = Allocator::initial_setup_hint(
+            unsafe {
+                Layout::from_size_align_unchecked(
+                    state
+                        .shared_state
+                        .max_chunk_size_hint
+                        .load(Ordering::Relaxed) as usize,
+                    state
+                        .shared_state
+                        .max_chunk_alignment_hint
+                        .load(Ordering::Relaxed) as usize,
+                )
+            },
+            state
+                .shared_state
+                .max_number_of_chunks_hint
+                .load(Ordering::Relaxed) as usize,
+        );
+
+        let current_size = match state.shared_memory_map.get(state.current_idx) {
+            Some(entry) => entry.shm.size(),
+            None => fatal_panic!(from self,
+                        "This should never happen! {msg} since the current shared memory segment is not available!"),
+        };
+
+        let new_size = state
+            .builder_config
+            .segment_size(initial_segment_setup.payload_size);
+        if current_size <= new_size {
+            return Ok(0);
+        }
+
+        let new_number_of_reallocations = state.current_idx.value() + 1;
+        let segment_id = if new_number_of_reallocations < MAX_NUMBER_OF_REALLOCATIONS {
+            SlotMapKey::new(new_number_of_reallocations)
+        } else {
+            fail!(from self, with ResizableShmAllocationError::MaxReallocationsReached,
+                "{msg} since it would exceed the maximum amount of reallocations of {}.",
+                Self::max_number_of_reallocations());
+        };
+
+        fail!(from self, when self.replace_current_segment(segment_id, initial_segment_setup),
+            "{msg} since the new shared memory segment could not be created.");
+
+        Ok(current_size - new_size)
+    }
 }
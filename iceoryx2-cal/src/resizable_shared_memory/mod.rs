@@ -98,6 +98,7 @@ use core::time::Duration;
 use iceoryx2_bb_elementary::enum_gen;
 
 use crate::named_concept::*;
+use crate::shared_memory::segment_provider::SegmentProviderConfiguration;
 use crate::shared_memory::{
     SegmentId, SharedMemory, SharedMemoryCreateError, SharedMemoryOpenError, ShmPointer,
 };
@@ -163,6 +164,14 @@ pub trait ResizableSharedMemoryBuilder<
     /// acquired.
     fn allocation_strategy(self, value: AllocationStrategy) -> Self;
 
+    /// Advises the operating system to back every data [`SharedMemory`] segment with huge
+    /// pages, see [`SharedMemoryBuilder::use_huge_pages()`](crate::shared_memory::SharedMemoryBuilder::use_huge_pages()).
+    fn use_huge_pages(self, value: bool) -> Self;
+
+    /// Binds every data [`SharedMemory`] segment to the NUMA node with the id `node_id`, see
+    /// [`SharedMemoryBuilder::numa_node()`](crate::shared_memory::SharedMemoryBuilder::numa_node()).
+    fn numa_node(self, node_id: usize) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(self) -> Result<ResizableShm, SharedMemoryCreateError>;
 }
@@ -202,7 +211,7 @@ pub trait ResizableSharedMemoryView<Allocator: ShmAllocator, Shm: SharedMemory<A
 /// [`ResizableSharedMemory::allocate()`] memory and distribute the memory to all
 /// [`ResizableSharedMemoryView`]s.
 pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Allocator>>:
-    Sized + NamedConcept + NamedConceptMgmt + Debug + Send
+    Sized + NamedConcept + NamedConceptMgmt<Configuration: SegmentProviderConfiguration> + Debug + Send
 {
     /// Type alias to the [`ResizableSharedMemoryViewBuilder`] to open a
     /// [`ResizableSharedMemoryView`] to an existing [`ResizableSharedMemory`].
@@ -223,6 +232,14 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     /// Returns the number of active [`SharedMemory`] segments.
     fn number_of_active_segments(&self) -> usize;
 
+    /// Returns true when the current [`SharedMemory`] segment is backed by huge pages, see
+    /// [`SharedMemory::has_huge_pages()`].
+    fn has_huge_pages(&self) -> bool;
+
+    /// Returns the id of the NUMA node the current [`SharedMemory`] segment is bound to, see
+    /// [`SharedMemory::numa_node()`].
+    fn numa_node(&self) -> Option<usize>;
+
     /// Allocates a new piece of [`SharedMemory`] if the provided [`Layout`] exceeds the current
     /// supported [`Layout`], the memory would be out-of-memory or the number of chunks exceeds the
     /// current supported amount of chunks, a new [`SharedMemory`] segment will be created. If this
@@ -240,6 +257,14 @@ pub trait ResizableSharedMemory<Allocator: ShmAllocator, Shm: SharedMemory<Alloc
     ///    [`ShmPointer`]
     ///  * the layout must be identical to the one used in [`SharedMemory::allocate()`]
     unsafe fn deallocate(&self, offset: PointerOffset, layout: core::alloc::Layout);
+
+    /// Replaces the current [`SharedMemory`] segment with a new segment that is sized according
+    /// to [`ResizableSharedMemoryBuilder::max_chunk_layout_hint()`] and
+    /// [`ResizableSharedMemoryBuilder::max_number_of_chunks_hint()`] when the current segment
+    /// grew beyond it. The previous segment is released as soon as all of its chunks are
+    /// deallocated. Returns the number of bytes the current segment was reduced by, zero when
+    /// it already fits.
+    fn shrink_to_fit(&self) -> Result<usize, ResizableShmAllocationError>;
 }
 
 pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>>:
@@ -255,4 +280,11 @@ pub trait ResizableSharedMemoryForPoolAllocator<Shm: SharedMemory<PoolAllocator>
 
     /// Returns the bucket size of the corresponding [`PoolAllocator`]
     fn bucket_size(&self, segment_id: SegmentId) -> usize;
+
+    /// Returns the number of buckets of the corresponding [`PoolAllocator`]
+    fn number_of_buckets(&self, segment_id: SegmentId) -> usize;
+
+    /// Returns the number of bytes all active segments provide for allocations, the used and
+    /// the unused buckets combined.
+    fn capacity(&self) -> usize;
 }
@@ -12,16 +12,27 @@
 
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+use core::sync::atomic::Ordering;
 use core::{alloc::Layout, fmt::Debug};
 
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
 use crate::dynamic_storage::*;
+use crate::shared_memory::segment_provider::*;
 pub use crate::shared_memory::*;
+use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
-use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::huge_pages::HugePages;
+use iceoryx2_bb_posix::numa::Numa;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
 use crate::static_storage::file::{
     NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
@@ -34,21 +45,46 @@ pub mod details {
 
     use super::*;
 
-    fn get_payload_start_address<
-        Allocator: ShmAllocator + Debug,
-        Storage: DynamicStorage<AllocatorDetails<Allocator>>,
-    >(
-        storage: &Storage,
+    fn get_payload_start_address<Allocator: ShmAllocator + Debug>(
+        details: &AllocatorDetails<Allocator>,
     ) -> usize {
-        (storage.get() as *const AllocatorDetails<Allocator>) as usize
-            + storage.get().payload_start_offset
-            + unsafe {
-                storage
-                    .get()
-                    .allocator
-                    .assume_init_ref()
-                    .relative_start_address()
+        (details as *const AllocatorDetails<Allocator>) as usize
+            + details.payload_start_offset
+            + unsafe { details.allocator.assume_init_ref().relative_start_address() }
+    }
+
+    fn advise_huge_pages<Allocator: ShmAllocator + Debug>(
+        details: &AllocatorDetails<Allocator>,
+    ) -> bool {
+        let payload_address =
+            (details as *const AllocatorDetails<Allocator>) as usize + details.payload_start_offset;
+
+        match unsafe { HugePages::advise(payload_address as *const _, details.payload_size) } {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(from "SharedMemory::advise_huge_pages()",
+                    "Unable to back the shared memory with huge pages ({:?}), falling back to regular pages.", e);
+                false
             }
+        }
+    }
+
+    fn bind_to_numa_node<Allocator: ShmAllocator + Debug>(
+        details: &AllocatorDetails<Allocator>,
+        numa_node: Option<usize>,
+    ) -> Option<usize> {
+        let numa_node = numa_node?;
+        let payload_address =
+            (details as *const AllocatorDetails<Allocator>) as usize + details.payload_start_offset;
+
+        match unsafe { Numa::bind(payload_address as *const _, details.payload_size, numa_node) } {
+            Ok(()) => Some(numa_node),
+            Err(e) => {
+                debug!(from "SharedMemory::bind_to_numa_node()",
+                    "Unable to bind the shared memory to the numa node {} ({:?}), the setting is ignored.", numa_node, e);
+                None
+            }
+        }
     }
 
     #[derive(Debug)]
@@ -58,6 +94,7 @@ pub mod details {
     > {
         pub zero_memory: bool,
         dynamic_storage_config: Storage::Configuration,
+        segment_provider: Option<Arc<dyn SegmentProvider>>,
         _phantom: PhantomData<Allocator>,
         _phantom_storage: PhantomData<Storage>,
     }
@@ -72,6 +109,7 @@ pub mod details {
                     .path_hint(&Memory::<Allocator, Storage>::default_path_hint())
                     .suffix(&Memory::<Allocator, Storage>::default_suffix())
                     .prefix(&Memory::<Allocator, Storage>::default_prefix()),
+                segment_provider: None,
                 _phantom: PhantomData,
                 _phantom_storage: PhantomData,
             }
@@ -85,12 +123,26 @@ pub mod details {
             Self {
                 zero_memory: self.zero_memory,
                 dynamic_storage_config: self.dynamic_storage_config.clone(),
+                segment_provider: self.segment_provider.clone(),
                 _phantom: PhantomData,
                 _phantom_storage: PhantomData,
             }
         }
     }
 
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        SegmentProviderConfiguration for Configuration<Allocator, Storage>
+    {
+        fn segment_provider(mut self, provider: Arc<dyn SegmentProvider>) -> Self {
+            self.segment_provider = Some(provider);
+            self
+        }
+
+        fn get_segment_provider(&self) -> Option<&Arc<dyn SegmentProvider>> {
+            self.segment_provider.as_ref()
+        }
+    }
+
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
         NamedConceptConfiguration for Configuration<Allocator, Storage>
     {
@@ -140,6 +192,8 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        use_huge_pages: bool,
+        numa_node: Option<usize>,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -152,6 +206,8 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                use_huge_pages: false,
+                numa_node: None,
             }
         }
 
@@ -184,8 +240,13 @@ pub mod details {
             details.payload_start_offset = (memory.as_ptr() as *const u8) as usize
                 - (details as *const AllocatorDetails<Allocator>) as usize;
 
+            let max_supported_alignment = match self.use_huge_pages {
+                true => HugePages::page_size(),
+                false => SystemInfo::PageSize.value(),
+            };
+
             details.allocator.write(unsafe {
-                Allocator::new_uninit(SystemInfo::PageSize.value(), memory, allocator_config)
+                Allocator::new_uninit(max_supported_alignment, memory, allocator_config)
             });
 
             if let Err(e) = unsafe { details.allocator.assume_init_mut().init(init_allocator) } {
@@ -216,6 +277,16 @@ pub mod details {
             self
         }
 
+        fn use_huge_pages(mut self, value: bool) -> Self {
+            self.use_huge_pages = value;
+            self
+        }
+
+        fn numa_node(mut self, node_id: usize) -> Self {
+            self.numa_node = Some(node_id);
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -229,6 +300,10 @@ pub mod details {
 
             let allocator_mgmt_size = Allocator::management_size(self.size, allocator_config);
 
+            if let Some(provider) = self.config.segment_provider.clone() {
+                return self.create_provided(provider, allocator_config, allocator_mgmt_size);
+            }
+
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
                 .supplementary_size(self.size + allocator_mgmt_size)
@@ -262,17 +337,21 @@ pub mod details {
                 }
             };
 
-            Ok(Memory::<Allocator, Storage> {
-                payload_start_address: get_payload_start_address(&storage),
-                storage,
-                name: self.name,
-                _phantom: PhantomData,
-            })
+            Ok(Memory::new(
+                self.name,
+                Backing::Storage(storage),
+                self.use_huge_pages,
+                self.numa_node,
+            ))
         }
 
         fn open(self) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
             let msg = "Unable to open shared memory";
 
+            if let Some(provider) = self.config.segment_provider.clone() {
+                return self.open_provided(provider);
+            }
+
             let storage = match Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
                 .has_ownership(false)
@@ -298,33 +377,246 @@ pub mod details {
                 }
             };
 
-            if storage.get().allocator_id != Allocator::unique_id() {
+            self.verify_details(storage.get())?;
+
+            Ok(Memory::new(
+                self.name,
+                Backing::Storage(storage),
+                self.use_huge_pages,
+                self.numa_node,
+            ))
+        }
+    }
+
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        Builder<Allocator, Storage>
+    {
+        fn verify_details(
+            &self,
+            details: &AllocatorDetails<Allocator>,
+        ) -> Result<(), SharedMemoryOpenError> {
+            let msg = "Unable to open shared memory";
+            if details.allocator_id != Allocator::unique_id() {
                 fail!(from self, with SharedMemoryOpenError::WrongAllocatorSelected,
                 "{} since the shared memory contains an allocator with unique id {} but the selected allocator has the unique id {}.",
-                msg, storage.get().allocator_id, Allocator::unique_id());
+                msg, details.allocator_id, Allocator::unique_id());
             }
 
-            let payload_size = storage.get().payload_size;
-            if payload_size < self.size {
+            if details.payload_size < self.size {
                 fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
                     "{} since a memory size of {} was requested but only {} is available.",
-                    msg, self.size, payload_size);
+                    msg, self.size, details.payload_size);
             }
 
-            Ok(Memory::<Allocator, Storage> {
-                payload_start_address: get_payload_start_address(&storage),
-                name: self.name,
-                storage,
+            Ok(())
+        }
+
+        fn create_provided(
+            self,
+            provider: Arc<dyn SegmentProvider>,
+            allocator_config: &Allocator::Configuration,
+            allocator_mgmt_size: usize,
+        ) -> Result<Memory<Allocator, Storage>, SharedMemoryCreateError> {
+            let msg = "Unable to create shared memory with a segment provider";
+            let full_name = provided_segment_name(&self.name, &self.config);
+            let header_size = core::mem::size_of::<ProvidedSegmentHeader<Allocator>>();
+            let required_size = header_size + self.size + allocator_mgmt_size;
+
+            let mapping = match provider.create(&full_name, required_size) {
+                Ok(mapping) => mapping,
+                Err(SegmentProviderError::AlreadyExists) => {
+                    fail!(from self, with SharedMemoryCreateError::AlreadyExists,
+                        "{} since a shared memory with that name already exists.", msg);
+                }
+                Err(SegmentProviderError::InsufficientPermissions) => {
+                    fail!(from self, with SharedMemoryCreateError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with SharedMemoryCreateError::InternalError,
+                        "{} since the segment provider failed to create the segment ({:?}).", msg, e);
+                }
+            };
+
+            let segment = ProvidedSegment::<Allocator> {
+                provider,
+                mapping,
+                full_name,
+                has_ownership: IoxAtomicBool::new(true),
                 _phantom: PhantomData,
-            })
+            };
+
+            if mapping.size() < required_size
+                || mapping.base_address().as_ptr() as usize % SystemInfo::PageSize.value() != 0
+            {
+                fail!(from self, with SharedMemoryCreateError::InternalError,
+                    "{} since the segment provider returned a mapping {:?} that is either smaller than {} bytes or not page aligned.",
+                    msg, mapping, required_size);
+            }
+
+            let header = mapping.base_address().as_ptr() as *mut ProvidedSegmentHeader<Allocator>;
+            unsafe {
+                core::ptr::addr_of_mut!((*header).version).write(IoxAtomicU64::new(0));
+                core::ptr::addr_of_mut!((*header).details).write(AllocatorDetails {
+                    allocator_id: Allocator::unique_id(),
+                    allocator: MaybeUninit::uninit(),
+                    mgmt_size: allocator_mgmt_size,
+                    payload_size: self.size,
+                    payload_start_offset: 0,
+                })
+            };
+
+            let mut init_allocator = BumpAllocator::new(
+                unsafe { NonNull::new_unchecked(mapping.base_address().as_ptr().add(header_size)) },
+                mapping.size() - header_size,
+            );
+
+            if !self.initialize(
+                allocator_config,
+                unsafe { &mut (*header).details },
+                &mut init_allocator,
+            ) {
+                fail!(from self, with SharedMemoryCreateError::InternalError,
+                    "{} since the initialization failed.", msg);
+            }
+
+            unsafe {
+                (*header)
+                    .version
+                    .store(PackageVersion::get().to_u64(), Ordering::SeqCst)
+            };
+
+            segment
+                .has_ownership
+                .store(self.has_ownership, Ordering::Relaxed);
+            Ok(Memory::new(
+                self.name,
+                Backing::Provided(Box::new(segment)),
+                self.use_huge_pages,
+                self.numa_node,
+            ))
         }
+
+        fn open_provided(
+            self,
+            provider: Arc<dyn SegmentProvider>,
+        ) -> Result<Memory<Allocator, Storage>, SharedMemoryOpenError> {
+            let msg = "Unable to open shared memory with a segment provider";
+            let full_name = provided_segment_name(&self.name, &self.config);
+
+            let mapping = match provider.map(&full_name) {
+                Ok(mapping) => mapping,
+                Err(SegmentProviderError::DoesNotExist) => {
+                    fail!(from self, with SharedMemoryOpenError::DoesNotExist,
+                        "{} since a shared memory with that name does not exist.", msg);
+                }
+                Err(SegmentProviderError::InsufficientPermissions) => {
+                    fail!(from self, with SharedMemoryOpenError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with SharedMemoryOpenError::InternalError,
+                        "{} since the segment provider failed to map the segment ({:?}).", msg, e);
+                }
+            };
+
+            let segment = ProvidedSegment::<Allocator> {
+                provider,
+                mapping,
+                full_name,
+                has_ownership: IoxAtomicBool::new(false),
+                _phantom: PhantomData,
+            };
+
+            if mapping.size() < core::mem::size_of::<ProvidedSegmentHeader<Allocator>>() {
+                fail!(from self, with SharedMemoryOpenError::InternalError,
+                    "{} since the mapped segment is smaller than the segment header.", msg);
+            }
+
+            //////////////////////////////////////////
+            // SYNC POINT: read ProvidedSegmentHeader::details
+            //////////////////////////////////////////
+            match segment.header().version.load(Ordering::SeqCst) {
+                0 => {
+                    fail!(from self, with SharedMemoryOpenError::InitializationNotYetFinalized,
+                        "{} since the underlying shared memory is not yet initialized.", msg);
+                }
+                v if v != PackageVersion::get().to_u64() => {
+                    fail!(from self, with SharedMemoryOpenError::VersionMismatch,
+                        "{} since the version number of the construct does not match.", msg);
+                }
+                _ => (),
+            }
+
+            self.verify_details(&segment.header().details)?;
+
+            Ok(Memory::new(
+                self.name,
+                Backing::Provided(Box::new(segment)),
+                self.use_huge_pages,
+                self.numa_node,
+            ))
+        }
+    }
+
+    fn provided_segment_name<
+        Allocator: ShmAllocator + Debug,
+        Storage: DynamicStorage<AllocatorDetails<Allocator>>,
+    >(
+        name: &FileName,
+        config: &Configuration<Allocator, Storage>,
+    ) -> FileName {
+        config.dynamic_storage_config.path_for(name).file_name()
+    }
+
+    #[repr(C)]
+    struct ProvidedSegmentHeader<Allocator: ShmAllocator> {
+        version: IoxAtomicU64,
+        details: AllocatorDetails<Allocator>,
+    }
+
+    #[derive(Debug)]
+    struct ProvidedSegment<Allocator: ShmAllocator> {
+        provider: Arc<dyn SegmentProvider>,
+        mapping: SegmentMapping,
+        full_name: FileName,
+        has_ownership: IoxAtomicBool,
+        _phantom: PhantomData<Allocator>,
+    }
+
+    impl<Allocator: ShmAllocator> ProvidedSegment<Allocator> {
+        fn header(&self) -> &ProvidedSegmentHeader<Allocator> {
+            unsafe {
+                &*(self.mapping.base_address().as_ptr() as *const ProvidedSegmentHeader<Allocator>)
+            }
+        }
+    }
+
+    impl<Allocator: ShmAllocator> Drop for ProvidedSegment<Allocator> {
+        fn drop(&mut self) {
+            self.provider.unmap(&self.full_name, &self.mapping);
+
+            if self.has_ownership.load(Ordering::Relaxed) {
+                if let Err(e) = self.provider.remove(&self.full_name) {
+                    warn!(from self, "Unable to remove the segment \"{}\" ({:?}).", self.full_name, e);
+                }
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    enum Backing<Allocator: ShmAllocator, Storage: DynamicStorage<AllocatorDetails<Allocator>>> {
+        Storage(Storage),
+        Provided(Box<ProvidedSegment<Allocator>>),
     }
 
     #[derive(Debug)]
     pub struct Memory<Allocator: ShmAllocator, Storage: DynamicStorage<AllocatorDetails<Allocator>>> {
-        storage: Storage,
+        backing: Backing<Allocator, Storage>,
         name: FileName,
         payload_start_address: usize,
+        has_huge_pages: bool,
+        numa_node: Option<usize>,
         _phantom: PhantomData<Allocator>,
     }
 
@@ -338,6 +630,38 @@ pub mod details {
         payload_start_offset: usize,
     }
 
+    impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
+        Memory<Allocator, Storage>
+    {
+        fn new(
+            name: FileName,
+            backing: Backing<Allocator, Storage>,
+            use_huge_pages: bool,
+            numa_node: Option<usize>,
+        ) -> Self {
+            let details = match &backing {
+                Backing::Storage(storage) => storage.get(),
+                Backing::Provided(segment) => &segment.header().details,
+            };
+
+            Self {
+                payload_start_address: get_payload_start_address(details),
+                has_huge_pages: use_huge_pages && advise_huge_pages(details),
+                numa_node: bind_to_numa_node(details, numa_node),
+                backing,
+                name,
+                _phantom: PhantomData,
+            }
+        }
+
+        fn details(&self) -> &AllocatorDetails<Allocator> {
+            match &self.backing {
+                Backing::Storage(storage) => storage.get(),
+                Backing::Provided(segment) => &segment.header().details,
+            }
+        }
+    }
+
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
         NamedConcept for Memory<Allocator, Storage>
     {
@@ -355,6 +679,22 @@ pub mod details {
             name: &FileName,
             cfg: &Self::Configuration,
         ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+            if let Some(provider) = &cfg.segment_provider {
+                let full_name = provided_segment_name(name, cfg);
+                return match provider.map(&full_name) {
+                    Ok(mapping) => {
+                        provider.unmap(&full_name, &mapping);
+                        Ok(true)
+                    }
+                    Err(SegmentProviderError::DoesNotExist) => Ok(false),
+                    Err(e) => {
+                        fail!(from "shared_memory::posix::does_exist_cfg()",
+                            with crate::static_storage::file::NamedConceptDoesExistError::InternalError,
+                            "Unable to determine if the shared memory concept \"{}\" exists since the segment provider failed ({:?}).", name, e);
+                    }
+                };
+            }
+
             Ok(fail!(from "shared_memory::posix::does_exist_cfg()",
             when Storage::does_exist_cfg(name, &cfg.dynamic_storage_config),
             "Unable to remove shared memory concept \"{}\".", name))
@@ -363,6 +703,27 @@ pub mod details {
         fn list_cfg(
             cfg: &Self::Configuration,
         ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+            if let Some(provider) = &cfg.segment_provider {
+                return match provider.list() {
+                    Ok(segments) => Ok(segments
+                        .iter()
+                        .filter_map(|segment| {
+                            cfg.dynamic_storage_config.extract_name_from_file(segment)
+                        })
+                        .collect()),
+                    Err(SegmentProviderError::InsufficientPermissions) => {
+                        fail!(from "shared_memory::posix::list_cfg()",
+                            with crate::static_storage::file::NamedConceptListError::InsufficientPermissions,
+                            "Unable to list shared memory concepts due to insufficient permissions.");
+                    }
+                    Err(e) => {
+                        fail!(from "shared_memory::posix::list_cfg()",
+                            with crate::static_storage::file::NamedConceptListError::InternalError,
+                            "Unable to list shared memory concepts since the segment provider failed ({:?}).", e);
+                    }
+                };
+            }
+
             Ok(fail!(from "shared_memory::posix::list_cfg()",
             when Storage::list_cfg(&cfg.dynamic_storage_config),
             "Unable to list shared memory concepts."))
@@ -372,6 +733,22 @@ pub mod details {
             name: &FileName,
             cfg: &Self::Configuration,
         ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+            if let Some(provider) = &cfg.segment_provider {
+                return match provider.remove(&provided_segment_name(name, cfg)) {
+                    Ok(v) => Ok(v),
+                    Err(SegmentProviderError::InsufficientPermissions) => {
+                        fail!(from "shared_memory::posix::remove_cfg()",
+                            with crate::static_storage::file::NamedConceptRemoveError::InsufficientPermissions,
+                            "Unable to remove shared memory concept \"{}\" due to insufficient permissions.", name);
+                    }
+                    Err(e) => {
+                        fail!(from "shared_memory::posix::remove_cfg()",
+                            with crate::static_storage::file::NamedConceptRemoveError::InternalError,
+                            "Unable to remove shared memory concept \"{}\" since the segment provider failed ({:?}).", name, e);
+                    }
+                };
+            }
+
             Ok(fail!(from "shared_memory::posix::remove_cfg()",
             when Storage::remove_cfg(name, &cfg.dynamic_storage_config),
             "Unable to remove shared memory concept \"{}\".", name))
@@ -389,7 +766,7 @@ pub mod details {
         for Memory<Allocator, Storage>
     {
         fn allocator(&self) -> &Allocator {
-            unsafe { self.storage.get().allocator.assume_init_ref() }
+            unsafe { self.details().allocator.assume_init_ref() }
         }
     }
 
@@ -403,27 +780,44 @@ pub mod details {
         }
 
         fn has_ownership(&self) -> bool {
-            self.storage.has_ownership()
+            match &self.backing {
+                Backing::Storage(storage) => storage.has_ownership(),
+                Backing::Provided(segment) => segment.has_ownership.load(Ordering::Relaxed),
+            }
         }
 
         fn acquire_ownership(&self) {
-            self.storage.acquire_ownership()
+            match &self.backing {
+                Backing::Storage(storage) => storage.acquire_ownership(),
+                Backing::Provided(segment) => segment.has_ownership.store(true, Ordering::Relaxed),
+            }
         }
 
         fn release_ownership(&self) {
-            self.storage.release_ownership()
+            match &self.backing {
+                Backing::Storage(storage) => storage.release_ownership(),
+                Backing::Provided(segment) => segment.has_ownership.store(false, Ordering::Relaxed),
+            }
         }
 
         fn size(&self) -> usize {
-            self.storage.get().payload_size
+            self.details().payload_size
         }
 
         fn max_alignment(&self) -> usize {
-            unsafe { self.storage.get().allocator.assume_init_ref() }.max_alignment()
+            unsafe { self.details().allocator.assume_init_ref() }.max_alignment()
+        }
+
+        fn has_huge_pages(&self) -> bool {
+            self.has_huge_pages
+        }
+
+        fn numa_node(&self) -> Option<usize> {
+            self.numa_node
         }
 
         fn allocate(&self, layout: core::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
-            let offset = fail!(from self, when unsafe { self.storage.get().allocator.assume_init_ref().allocate(layout) },
+            let offset = fail!(from self, when unsafe { self.details().allocator.assume_init_ref().allocate(layout) },
             "Failed to allocate shared memory due to an internal allocator failure.");
 
             Ok(ShmPointer {
@@ -433,8 +827,7 @@ pub mod details {
         }
 
         unsafe fn deallocate(&self, offset: PointerOffset, layout: core::alloc::Layout) {
-            self.storage
-                .get()
+            self.details()
                 .allocator
                 .assume_init_ref()
                 .deallocate(offset, layout);
@@ -449,15 +842,23 @@ pub mod details {
         for Memory<PoolAllocator, Storage>
     {
         unsafe fn deallocate_bucket(&self, offset: PointerOffset) {
-            self.storage
-                .get()
+            self.details()
                 .allocator
                 .assume_init_ref()
                 .deallocate_bucket(offset);
         }
 
         fn bucket_size(&self) -> usize {
-            unsafe { self.storage.get().allocator.assume_init_ref().bucket_size() }
+            unsafe { self.details().allocator.assume_init_ref().bucket_size() }
+        }
+
+        fn number_of_buckets(&self) -> usize {
+            unsafe {
+                self.details()
+                    .allocator
+                    .assume_init_ref()
+                    .number_of_buckets() as usize
+            }
         }
     }
 }
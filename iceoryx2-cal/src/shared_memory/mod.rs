@@ -58,6 +58,7 @@ pub mod common;
 pub mod posix;
 pub mod process_local;
 pub mod recommended;
+pub mod segment_provider;
 
 use core::{fmt::Debug, time::Duration};
 
@@ -65,6 +66,7 @@ pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
 use iceoryx2_bb_system_types::file_name::*;
 use pool_allocator::PoolAllocator;
+use segment_provider::SegmentProviderConfiguration;
 
 /// Failure returned by [`SharedMemoryBuilder::create()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -125,6 +127,17 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Advises the operating system to back the payload of the [`SharedMemory`] with huge pages
+    /// and raises the maximum supported alignment to the huge page size. If huge pages cannot
+    /// be obtained the [`SharedMemory`] falls back to regular pages, see
+    /// [`SharedMemory::has_huge_pages()`]. By default it is disabled.
+    fn use_huge_pages(self, value: bool) -> Self;
+
+    /// Binds the payload of the [`SharedMemory`] to the NUMA node with the id `node_id`. If the
+    /// system does not support NUMA or the node does not exist the setting is ignored, see
+    /// [`SharedMemory::numa_node()`]. By default the memory is not bound to any node.
+    fn numa_node(self, node_id: usize) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -139,7 +152,12 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
 /// Abstract concept of a memory shared between multiple processes. Can be created with the
 /// [`SharedMemoryBuilder`].
 pub trait SharedMemory<Allocator: ShmAllocator>:
-    Sized + Debug + NamedConcept + NamedConceptMgmt + details::SharedMemoryLowLevelAPI<Allocator> + Send
+    Sized
+    + Debug
+    + NamedConcept
+    + NamedConceptMgmt<Configuration: SegmentProviderConfiguration>
+    + details::SharedMemoryLowLevelAPI<Allocator>
+    + Send
 {
     type Builder: SharedMemoryBuilder<Allocator, Self>;
 
@@ -149,6 +167,14 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// Returns the max supported alignment.
     fn max_alignment(&self) -> usize;
 
+    /// Returns true when the operating system accepted the huge page advice for the payload,
+    /// otherwise false.
+    fn has_huge_pages(&self) -> bool;
+
+    /// Returns the id of the NUMA node the payload is bound to. If the payload is not bound to
+    /// a node it returns [`None`].
+    fn numa_node(&self) -> Option<usize>;
+
     /// Returns the start address of the shared memory. Used by the [`ShmPointer`] to calculate
     /// the actual memory position.
     fn payload_start_address(&self) -> usize;
@@ -198,4 +224,7 @@ pub trait SharedMemoryForPoolAllocator: SharedMemory<PoolAllocator> {
 
     /// Returns the bucket size of the [`PoolAllocator`]
     fn bucket_size(&self) -> usize;
+
+    /// Returns the number of buckets of the [`PoolAllocator`]
+    fn number_of_buckets(&self) -> usize;
 }
@@ -0,0 +1,120 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`SegmentProvider`] replaces the default acquisition of the memory of a
+//! [`SharedMemory`](crate::shared_memory::SharedMemory). It can be used to back the
+//! [`SharedMemory`](crate::shared_memory::SharedMemory) with memory from a pre-reserved and
+//! locked pool. The provider is attached to the
+//! [`SharedMemory`](crate::shared_memory::SharedMemory) configuration with
+//! [`SegmentProviderConfiguration::segment_provider()`] and is used for every segment that
+//! is created, opened, listed or removed with this configuration.
+//!
+//! Every process that opens a segment must use the same provider, otherwise the segment
+//! cannot be found.
+
+use core::fmt::Debug;
+use core::ptr::NonNull;
+
+extern crate alloc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use iceoryx2_bb_system_types::file_name::FileName;
+
+/// Failures that can be reported by a [`SegmentProvider`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SegmentProviderError {
+    AlreadyExists,
+    DoesNotExist,
+    OutOfMemory,
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl core::fmt::Display for SegmentProviderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SegmentProviderError::{self:?}")
+    }
+}
+
+impl core::error::Error for SegmentProviderError {}
+
+/// A memory region of a segment that is mapped into the process space by a
+/// [`SegmentProvider`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct SegmentMapping {
+    base_address: NonNull<u8>,
+    size: usize,
+}
+
+unsafe impl Send for SegmentMapping {}
+unsafe impl Sync for SegmentMapping {}
+
+impl SegmentMapping {
+    /// Creates a new [`SegmentMapping`].
+    ///
+    /// # Safety
+    ///
+    ///  * the memory range [base_address, size] must be mapped, readable and writable until
+    ///    [`SegmentProvider::unmap()`] is called
+    ///  * the base_address must be aligned to the page size of the system
+    ///
+    pub unsafe fn new(base_address: NonNull<u8>, size: usize) -> Self {
+        Self { base_address, size }
+    }
+
+    /// Returns the start address of the mapped segment.
+    pub fn base_address(&self) -> NonNull<u8> {
+        self.base_address
+    }
+
+    /// Returns the size of the mapped segment.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Provides the memory for the segments of a
+/// [`SharedMemory`](crate::shared_memory::SharedMemory). The segments are identified by
+/// their full name, which contains the prefix and suffix of the configuration.
+pub trait SegmentProvider: Debug + Send + Sync {
+    /// Creates a new segment with at least `size` bytes and maps it into the process space.
+    /// If a segment with the same name already exists it must fail with
+    /// [`SegmentProviderError::AlreadyExists`].
+    fn create(&self, name: &FileName, size: usize) -> Result<SegmentMapping, SegmentProviderError>;
+
+    /// Maps an existing segment into the process space. If the segment does not exist it
+    /// must fail with [`SegmentProviderError::DoesNotExist`].
+    fn map(&self, name: &FileName) -> Result<SegmentMapping, SegmentProviderError>;
+
+    /// Unmaps a segment that was acquired with [`SegmentProvider::create()`] or
+    /// [`SegmentProvider::map()`] from the process space. The segment itself continues to
+    /// exist.
+    fn unmap(&self, name: &FileName, mapping: &SegmentMapping);
+
+    /// Removes the segment. Returns `true` when the segment existed, otherwise `false`.
+    fn remove(&self, name: &FileName) -> Result<bool, SegmentProviderError>;
+
+    /// Returns the full names of all existing segments.
+    fn list(&self) -> Result<Vec<FileName>, SegmentProviderError>;
+}
+
+/// Attaches a [`SegmentProvider`] to the configuration of a
+/// [`SharedMemory`](crate::shared_memory::SharedMemory).
+pub trait SegmentProviderConfiguration {
+    /// Uses the provided [`SegmentProvider`] instead of the default implementation to
+    /// acquire the memory of all segments.
+    fn segment_provider(self, provider: Arc<dyn SegmentProvider>) -> Self;
+
+    /// Returns the [`SegmentProvider`] if one is set.
+    fn get_segment_provider(&self) -> Option<&Arc<dyn SegmentProvider>>;
+}
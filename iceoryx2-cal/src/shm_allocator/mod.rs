@@ -48,6 +48,38 @@ pub enum AllocationStrategy {
     Static,
 }
 
+impl AllocationStrategy {
+    /// Estimates the number of bytes a memory with `element_count` chunks requires when every
+    /// chunk stores `element_size` bytes of payload and `overhead_bytes` of additional data,
+    /// like a header. [`AllocationStrategy::PowerOfTwo`] rounds the chunk size up to the next
+    /// power of two, [`AllocationStrategy::BestFit`] and [`AllocationStrategy::Static`] use the
+    /// exact chunk size. Alignment padding and the management data of the allocator are not
+    /// considered. If the estimate exceeds [`usize::MAX`], [`usize::MAX`] is returned.
+    ///
+    /// ```
+    /// use iceoryx2_cal::shm_allocator::AllocationStrategy;
+    ///
+    /// assert_eq!(AllocationStrategy::BestFit.estimate_memory(100, 10, 20), 1200);
+    /// assert_eq!(AllocationStrategy::PowerOfTwo.estimate_memory(100, 10, 20), 1280);
+    /// ```
+    pub fn estimate_memory(
+        &self,
+        element_size: usize,
+        element_count: usize,
+        overhead_bytes: usize,
+    ) -> usize {
+        let chunk_size = element_size.saturating_add(overhead_bytes);
+        let chunk_size = match self {
+            AllocationStrategy::PowerOfTwo => {
+                chunk_size.checked_next_power_of_two().unwrap_or(usize::MAX)
+            }
+            AllocationStrategy::BestFit | AllocationStrategy::Static => chunk_size,
+        };
+
+        chunk_size.saturating_mul(element_count)
+    }
+}
+
 /// Describes error that may occur when a [`ShmAllocator`] is initialized.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum ShmAllocatorInitError {
@@ -149,17 +149,20 @@ impl StaticStorageLocked<Storage> for Locked {
                 msg, self.storage.name());
         }
 
+        let content = Arc::new(StorageContent {
+            is_locked: false,
+            value: Vec::from(contents),
+        });
         guard.insert(
             name,
             StorageEntry {
-                content: Arc::new(StorageContent {
-                    is_locked: false,
-                    value: Vec::from(contents),
-                }),
+                content: content.clone(),
             },
         );
 
-        Ok(self.storage)
+        let mut storage = self.storage;
+        storage.content = content;
+        Ok(storage)
     }
 }
 
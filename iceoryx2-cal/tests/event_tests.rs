@@ -562,6 +562,66 @@ mod event {
         assert_that!(event_id, is_none);
     }
 
+    #[test]
+    fn notify_multiple_delivers_all_trigger_ids<Sut: Event>() {
+        const NUMBER_OF_IDS: usize = 16;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name)
+            .trigger_id_max(TriggerId::new(NUMBER_OF_IDS))
+            .config(&config)
+            .create()
+            .unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        let ids: Vec<TriggerId> = (0..NUMBER_OF_IDS).map(TriggerId::new).collect();
+        assert_that!(sut_notifier.notify_multiple(&ids), is_ok);
+
+        let mut received_ids = HashSet::new();
+        for _ in 0..NUMBER_OF_IDS {
+            let id = sut_listener.try_wait_one().unwrap();
+            assert_that!(id, is_some);
+            assert_that!(received_ids.insert(id.unwrap()), eq true);
+        }
+
+        assert_that!(sut_listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notify_multiple_with_out_of_bounds_trigger_id_delivers_nothing<Sut: Event>() {
+        test_requires!(Sut::has_trigger_id_limit());
+
+        const TRIGGER_ID_MAX: TriggerId = TriggerId::new(8);
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_listener = Sut::ListenerBuilder::new(&name)
+            .trigger_id_max(TRIGGER_ID_MAX)
+            .config(&config)
+            .create()
+            .unwrap();
+        let sut_notifier = Sut::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+
+        let result = sut_notifier.notify_multiple(&[
+            TriggerId::new(1),
+            TriggerId::new(TRIGGER_ID_MAX.as_value() + 1),
+        ]);
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq
+            NotifierNotifyError::TriggerIdOutOfBounds
+        );
+
+        assert_that!(sut_listener.try_wait_one().unwrap(), is_none);
+    }
+
     fn wait_all_collects_all_triggers<Sut: Event, F: FnMut(&mut Vec<TriggerId>, &Sut::Listener)>(
         mut wait_call: F,
     ) {
@@ -14,6 +14,8 @@
 mod resizable_shared_memory {
     use core::alloc::Layout;
 
+    use iceoryx2_bb_posix::huge_pages::HugePages;
+    use iceoryx2_bb_posix::numa::Numa;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::named_concept::*;
     use iceoryx2_cal::resizable_shared_memory::{self, *};
@@ -413,6 +415,155 @@ mod resizable_shared_memory {
         )
     }
 
+    #[test]
+    fn huge_pages_are_disabled_by_default<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(8)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.has_huge_pages(), eq false);
+    }
+
+    #[test]
+    fn huge_pages_keep_chunks_aligned_to_huge_page_size_after_resize<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let huge_page_size = HugePages::page_size();
+        let layout = unsafe { Layout::from_size_align_unchecked(huge_page_size, huge_page_size) };
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(layout)
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .use_huge_pages(true)
+            .create()
+            .unwrap();
+
+        let chunk = sut.allocate(layout).unwrap();
+        assert_that!((chunk.data_ptr as usize) % huge_page_size, eq 0);
+
+        let larger_layout =
+            unsafe { Layout::from_size_align_unchecked(huge_page_size * 2, huge_page_size) };
+        let chunk = sut.allocate(larger_layout).unwrap();
+        assert_that!((chunk.data_ptr as usize) % huge_page_size, eq 0);
+        assert_that!(sut.number_of_active_segments(), eq 2);
+    }
+
+    #[test]
+    fn numa_node_of_non_existing_node_is_ignored_for_all_segments<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .numa_node(Numa::number_of_nodes())
+            .create()
+            .unwrap();
+
+        assert_that!(sut.numa_node(), eq None);
+        assert_that!(sut.allocate(Layout::new::<u8>()), is_ok);
+        assert_that!(sut.allocate(Layout::new::<u64>()), is_ok);
+        assert_that!(sut.number_of_active_segments(), eq 2);
+        assert_that!(sut.numa_node(), eq None);
+    }
+
+    #[test]
+    fn shrink_to_fit_does_nothing_when_the_segment_did_not_grow<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        sut.allocate(Layout::new::<u8>()).unwrap();
+        assert_that!(sut.shrink_to_fit(), eq Ok(0));
+        assert_that!(sut.number_of_active_segments(), eq 1);
+    }
+
+    #[test]
+    fn shrink_to_fit_releases_unused_grown_segment<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let large_layout = Layout::from_size_align(1024, 8).unwrap();
+        let chunk = sut.allocate(large_layout).unwrap();
+        unsafe { sut.deallocate(chunk.offset, large_layout) };
+        assert_that!(sut.number_of_active_segments(), eq 1);
+
+        assert_that!(sut.shrink_to_fit().unwrap(), gt 0);
+        assert_that!(sut.number_of_active_segments(), eq 1);
+        assert_that!(sut.shrink_to_fit(), eq Ok(0));
+
+        assert_that!(sut.allocate(Layout::new::<u8>()), is_ok);
+        assert_that!(sut.number_of_active_segments(), eq 1);
+    }
+
+    #[test]
+    fn shrink_to_fit_keeps_grown_segment_until_last_chunk_is_deallocated<
+        Shm: SharedMemory<DefaultAllocator>,
+        Sut: ResizableSharedMemory<DefaultAllocator, Shm>,
+    >() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::MemoryBuilder::new(&storage_name)
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let large_layout = Layout::from_size_align(1024, 8).unwrap();
+        let chunk = sut.allocate(large_layout).unwrap();
+        unsafe { (chunk.data_ptr as *mut u64).write(91827364) };
+
+        assert_that!(sut.shrink_to_fit().unwrap(), gt 0);
+        assert_that!(sut.number_of_active_segments(), eq 2);
+        assert_that!(unsafe { *(chunk.data_ptr as *const u64) }, eq 91827364);
+
+        unsafe { sut.deallocate(chunk.offset, large_layout) };
+        assert_that!(sut.number_of_active_segments(), eq 1);
+    }
+
     #[test]
     fn deallocate_last_segment_does_not_release_it<
         Shm: SharedMemory<DefaultAllocator>,
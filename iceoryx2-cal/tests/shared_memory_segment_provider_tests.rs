@@ -0,0 +1,384 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod shared_memory_segment_provider {
+    use core::alloc::Layout;
+    use core::ptr::NonNull;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::resizable_shared_memory::{self, *};
+    use iceoryx2_cal::shared_memory::segment_provider::*;
+    use iceoryx2_cal::shared_memory::*;
+    use iceoryx2_cal::shm_allocator::ShmAllocator;
+    use iceoryx2_cal::shm_allocator::{pool_allocator::PoolAllocator, AllocationStrategy};
+    use iceoryx2_cal::testing::*;
+
+    type DefaultAllocator = PoolAllocator;
+    type AllocatorConfig = <DefaultAllocator as ShmAllocator>::Configuration;
+
+    const CHUNK_SIZE: usize = 128;
+    const DEFAULT_SIZE: usize = CHUNK_SIZE * 16;
+    const SHM_CONFIG: AllocatorConfig = AllocatorConfig {
+        bucket_layout: unsafe { Layout::from_size_align_unchecked(CHUNK_SIZE, 8) },
+    };
+
+    #[derive(Debug)]
+    struct Segment {
+        name: FileName,
+        address: usize,
+        layout: Layout,
+        number_of_mappings: usize,
+        is_removed: bool,
+    }
+
+    #[derive(Debug, Default)]
+    struct TestProvider {
+        segments: Mutex<Vec<Segment>>,
+        create_calls: AtomicUsize,
+        map_calls: AtomicUsize,
+        unmap_calls: AtomicUsize,
+        remove_calls: AtomicUsize,
+    }
+
+    impl TestProvider {
+        fn number_of_segments(&self) -> usize {
+            self.segments
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| !s.is_removed)
+                .count()
+        }
+
+        fn release_unused(segments: &mut Vec<Segment>) {
+            segments.retain(|s| {
+                if s.is_removed && s.number_of_mappings == 0 {
+                    unsafe { std::alloc::dealloc(s.address as *mut u8, s.layout) };
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+    }
+
+    impl SegmentProvider for TestProvider {
+        fn create(
+            &self,
+            name: &FileName,
+            size: usize,
+        ) -> Result<SegmentMapping, SegmentProviderError> {
+            self.create_calls.fetch_add(1, Ordering::Relaxed);
+            let mut segments = self.segments.lock().unwrap();
+            if segments.iter().any(|s| !s.is_removed && s.name == *name) {
+                return Err(SegmentProviderError::AlreadyExists);
+            }
+
+            let layout = Layout::from_size_align(size, SystemInfo::PageSize.value()).unwrap();
+            let address = unsafe { std::alloc::alloc_zeroed(layout) };
+            if address.is_null() {
+                return Err(SegmentProviderError::OutOfMemory);
+            }
+
+            segments.push(Segment {
+                name: name.clone(),
+                address: address as usize,
+                layout,
+                number_of_mappings: 1,
+                is_removed: false,
+            });
+
+            Ok(unsafe { SegmentMapping::new(NonNull::new_unchecked(address), size) })
+        }
+
+        fn map(&self, name: &FileName) -> Result<SegmentMapping, SegmentProviderError> {
+            self.map_calls.fetch_add(1, Ordering::Relaxed);
+            let mut segments = self.segments.lock().unwrap();
+            match segments
+                .iter_mut()
+                .find(|s| !s.is_removed && s.name == *name)
+            {
+                Some(s) => {
+                    s.number_of_mappings += 1;
+                    Ok(unsafe {
+                        SegmentMapping::new(
+                            NonNull::new_unchecked(s.address as *mut u8),
+                            s.layout.size(),
+                        )
+                    })
+                }
+                None => Err(SegmentProviderError::DoesNotExist),
+            }
+        }
+
+        fn unmap(&self, _name: &FileName, mapping: &SegmentMapping) {
+            self.unmap_calls.fetch_add(1, Ordering::Relaxed);
+            let mut segments = self.segments.lock().unwrap();
+            if let Some(s) = segments
+                .iter_mut()
+                .find(|s| s.address == mapping.base_address().as_ptr() as usize)
+            {
+                s.number_of_mappings -= 1;
+            }
+            Self::release_unused(&mut segments);
+        }
+
+        fn remove(&self, name: &FileName) -> Result<bool, SegmentProviderError> {
+            self.remove_calls.fetch_add(1, Ordering::Relaxed);
+            let mut segments = self.segments.lock().unwrap();
+            let has_removed = match segments
+                .iter_mut()
+                .find(|s| !s.is_removed && s.name == *name)
+            {
+                Some(s) => {
+                    s.is_removed = true;
+                    true
+                }
+                None => false,
+            };
+            Self::release_unused(&mut segments);
+            Ok(has_removed)
+        }
+
+        fn list(&self) -> Result<Vec<FileName>, SegmentProviderError> {
+            Ok(self
+                .segments
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| !s.is_removed)
+                .map(|s| s.name.clone())
+                .collect())
+        }
+    }
+
+    fn config_with_provider<Sut: SharedMemory<DefaultAllocator>>(
+        provider: &Arc<TestProvider>,
+    ) -> Sut::Configuration {
+        generate_isolated_config::<Sut>().segment_provider(provider.clone())
+    }
+
+    #[test]
+    fn create_and_open_uses_segment_provider<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(provider.create_calls.load(Ordering::Relaxed), eq 1);
+        assert_that!(provider.map_calls.load(Ordering::Relaxed), eq 1);
+        assert_that!(provider.number_of_segments(), eq 1);
+        assert_that!(sut_create.size(), ge DEFAULT_SIZE);
+        assert_that!(sut_open.size(), ge DEFAULT_SIZE);
+
+        let test_value = 918273645u64;
+        let ptr = sut_create.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { (ptr.data_ptr as *mut u64).write(test_value) };
+
+        let open_ptr = (sut_open.payload_start_address() + ptr.offset.offset()) as *const u64;
+        assert_that!(unsafe { *open_ptr }, eq test_value);
+    }
+
+    #[test]
+    fn segment_provider_does_not_use_default_storage<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = generate_isolated_config::<Sut>();
+        let provider_config = config.clone().segment_provider(provider.clone());
+
+        let _sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&provider_config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        assert_that!(Sut::does_exist_cfg(&name, &provider_config).unwrap(), eq true);
+        assert_that!(Sut::does_exist_cfg(&name, &config).unwrap(), eq false);
+
+        let sut_open = Sut::Builder::new(&name).config(&config).open();
+        assert_that!(sut_open.err().unwrap(), eq SharedMemoryOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn list_returns_segments_of_segment_provider<Sut: SharedMemory<DefaultAllocator>>() {
+        const NUMBER_OF_SEGMENTS: usize = 4;
+        let provider = Arc::new(TestProvider::default());
+        let config = generate_isolated_config::<Sut>();
+        let provider_config = config.clone().segment_provider(provider.clone());
+        let other_provider_config =
+            generate_isolated_config::<Sut>().segment_provider(provider.clone());
+
+        let mut names = vec![];
+        let mut suts = vec![];
+        for _ in 0..NUMBER_OF_SEGMENTS {
+            let name = generate_name();
+            suts.push(
+                Sut::Builder::new(&name)
+                    .size(DEFAULT_SIZE)
+                    .config(&provider_config)
+                    .create(&SHM_CONFIG)
+                    .unwrap(),
+            );
+            names.push(name);
+        }
+        let _other_sut = Sut::Builder::new(&generate_name())
+            .size(DEFAULT_SIZE)
+            .config(&other_provider_config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let mut list = Sut::list_cfg(&provider_config).unwrap();
+        list.sort();
+        names.sort();
+        assert_that!(list, eq names);
+        assert_that!(Sut::list_cfg(&other_provider_config).unwrap(), len 1);
+        assert_that!(Sut::list_cfg(&config).unwrap(), len 0);
+
+        suts.clear();
+        assert_that!(Sut::list_cfg(&provider_config).unwrap(), len 0);
+    }
+
+    #[test]
+    fn creating_it_twice_with_segment_provider_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let _sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG);
+
+        assert_that!(sut.err().unwrap(), eq SharedMemoryCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn opening_non_existing_with_segment_provider_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let sut = Sut::Builder::new(&name).config(&config).open();
+
+        assert_that!(sut.err().unwrap(), eq SharedMemoryOpenError::DoesNotExist);
+        assert_that!(Sut::does_exist_cfg(&name, &config).unwrap(), eq false);
+    }
+
+    #[test]
+    fn drop_with_ownership_removes_provided_segment<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        assert_that!(Sut::does_exist_cfg(&name, &config).unwrap(), eq true);
+        drop(sut);
+
+        assert_that!(provider.unmap_calls.load(Ordering::Relaxed), eq 2);
+        assert_that!(provider.remove_calls.load(Ordering::Relaxed), eq 1);
+        assert_that!(provider.number_of_segments(), eq 0);
+        assert_that!(Sut::does_exist_cfg(&name, &config).unwrap(), eq false);
+    }
+
+    #[test]
+    fn drop_without_ownership_keeps_provided_segment<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .has_ownership(false)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        assert_that!(sut.has_ownership(), eq false);
+        drop(sut);
+
+        assert_that!(provider.number_of_segments(), eq 1);
+        assert_that!(unsafe { Sut::remove_cfg(&name, &config) }.unwrap(), eq true);
+        assert_that!(provider.number_of_segments(), eq 0);
+        assert_that!(unsafe { Sut::remove_cfg(&name, &config) }.unwrap(), eq false);
+    }
+
+    #[test]
+    fn resizable_shared_memory_acquires_grown_segments_from_segment_provider<
+        Sut: SharedMemory<DefaultAllocator>,
+    >()
+    where
+        Sut::Builder: core::fmt::Debug,
+    {
+        type Resizable<Shm> =
+            resizable_shared_memory::dynamic::DynamicMemory<DefaultAllocator, Shm>;
+
+        let name = generate_name();
+        let provider = Arc::new(TestProvider::default());
+        let config = config_with_provider::<Sut>(&provider);
+
+        let sut =
+            <Resizable<Sut> as ResizableSharedMemory<DefaultAllocator, Sut>>::MemoryBuilder::new(
+                &name,
+            )
+            .config(&config)
+            .max_chunk_layout_hint(Layout::new::<u8>())
+            .max_number_of_chunks_hint(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let view =
+            <Resizable<Sut> as ResizableSharedMemory<DefaultAllocator, Sut>>::ViewBuilder::new(
+                &name,
+            )
+            .config(&config)
+            .open()
+            .unwrap();
+        let initial_create_calls = provider.create_calls.load(Ordering::Relaxed);
+        assert_that!(initial_create_calls, ge 1);
+
+        let test_value = 5647382910u64;
+        let _small = sut.allocate(Layout::new::<u8>()).unwrap();
+        let ptr = sut.allocate(Layout::new::<u64>()).unwrap();
+        unsafe { (ptr.data_ptr as *mut u64).write(test_value) };
+        assert_that!(provider.create_calls.load(Ordering::Relaxed), gt initial_create_calls);
+
+        let view_ptr =
+            unsafe { view.register_and_translate_offset(ptr.offset).unwrap() as *const u64 };
+        assert_that!(unsafe { *view_ptr }, eq test_value);
+    }
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::posix::Memory<DefaultAllocator>>)]
+    mod posix {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
+    mod process_local {}
+}
@@ -15,6 +15,8 @@ mod shared_memory {
     use core::alloc::Layout;
 
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::huge_pages::HugePages;
+    use iceoryx2_bb_posix::numa::Numa;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::named_concept::*;
@@ -230,6 +232,101 @@ mod shared_memory {
         }
     }
 
+    #[test]
+    fn huge_pages_are_disabled_by_default<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(sut_create.has_huge_pages(), eq false);
+        assert_that!(sut_open.has_huge_pages(), eq false);
+    }
+
+    #[test]
+    fn huge_pages_support_chunks_aligned_to_huge_page_size<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+        let huge_page_size = HugePages::page_size();
+        let layout = unsafe { Layout::from_size_align_unchecked(huge_page_size, huge_page_size) };
+        let shm_config = AllocatorConfig {
+            bucket_layout: layout,
+        };
+
+        // falls back to regular pages when the system does not provide huge pages
+        let sut_create = Sut::Builder::new(&name)
+            .size(huge_page_size * 2 + huge_page_size - 1)
+            .config(&config)
+            .use_huge_pages(true)
+            .create(&shm_config)
+            .unwrap();
+
+        assert_that!(sut_create.max_alignment(), ge huge_page_size);
+
+        let mut number_of_chunks = 0;
+        while let Ok(chunk) = sut_create.allocate(layout) {
+            assert_that!((chunk.data_ptr as usize) % huge_page_size, eq 0);
+            number_of_chunks += 1;
+        }
+        assert_that!(number_of_chunks, eq 2);
+    }
+
+    #[test]
+    fn numa_node_is_not_set_by_default<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(sut_create.numa_node(), eq None);
+        assert_that!(sut_open.numa_node(), eq None);
+    }
+
+    #[test]
+    fn numa_node_of_non_existing_node_is_ignored<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .numa_node(Numa::number_of_nodes())
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        assert_that!(sut.numa_node(), eq None);
+        assert_that!(sut.allocate(DEFAULT_LAYOUT), is_ok);
+    }
+
+    #[test]
+    fn numa_node_is_set_when_binding_succeeds<Sut: SharedMemory<DefaultAllocator>>() {
+        test_requires!(Numa::is_supported());
+
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .config(&config)
+            .numa_node(0)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        // the setting is ignored when the kernel is compiled without numa support
+        assert_that!(sut.numa_node() == Some(0) || sut.numa_node().is_none(), eq true);
+        assert_that!(sut.allocate(DEFAULT_LAYOUT), is_ok);
+    }
+
     #[test]
     fn list_shm_works<Sut: SharedMemory<DefaultAllocator>>() {
         let mut storage_names = vec![];
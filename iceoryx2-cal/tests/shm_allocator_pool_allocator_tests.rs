@@ -179,6 +179,47 @@ mod shm_allocator_pool_allocator {
         assert_that!(hint.payload_size, eq increased_layout.size() * (test_context.sut.number_of_buckets() + 1) as usize);
     }
 
+    #[test]
+    fn estimate_memory_rounds_chunk_size_only_with_power_of_two() {
+        for strategy in [AllocationStrategy::BestFit, AllocationStrategy::Static] {
+            assert_that!(strategy.estimate_memory(100, 10, 20), eq 1200);
+            assert_that!(strategy.estimate_memory(128, 4, 0), eq 512);
+        }
+
+        let strategy = AllocationStrategy::PowerOfTwo;
+        assert_that!(strategy.estimate_memory(100, 10, 20), eq 1280);
+        assert_that!(strategy.estimate_memory(128, 4, 0), eq 512);
+        assert_that!(strategy.estimate_memory(129, 4, 0), eq 1024);
+    }
+
+    #[test]
+    fn estimate_memory_matches_resize_hint_of_power_of_two() {
+        let test_context = TestContext::new(BUCKET_CONFIG);
+        let hint = test_context.sut.resize_hint(
+            Layout::from_size_align(BUCKET_CONFIG.size() + 20, BUCKET_CONFIG.align()).unwrap(),
+            AllocationStrategy::PowerOfTwo,
+        );
+
+        let number_of_buckets = test_context.sut.number_of_buckets() as usize;
+        assert_that!(
+            AllocationStrategy::PowerOfTwo.estimate_memory(BUCKET_CONFIG.size(), number_of_buckets, 20),
+            eq hint.payload_size
+        );
+    }
+
+    #[test]
+    fn estimate_memory_saturates_on_overflow() {
+        for strategy in [
+            AllocationStrategy::BestFit,
+            AllocationStrategy::PowerOfTwo,
+            AllocationStrategy::Static,
+        ] {
+            assert_that!(strategy.estimate_memory(usize::MAX, 2, 1), eq usize::MAX);
+            assert_that!(strategy.estimate_memory(usize::MAX / 2, 3, 0), eq usize::MAX);
+            assert_that!(strategy.estimate_memory(8, 0, 8), eq 0);
+        }
+    }
+
     #[test]
     fn allocate_and_release_all_buckets_works() {
         const REPETITIONS: usize = 10;
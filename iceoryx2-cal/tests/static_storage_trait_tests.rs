@@ -368,6 +368,29 @@ mod static_storage {
         }
     }
 
+    #[test]
+    fn unlocked_storage_provides_content_to_creator<Sut: StaticStorage>() {
+        let _test_guard = TEST_MUTEX.lock();
+        let storage_name = generate_name();
+
+        let content = "the creator reads this".to_string();
+
+        let storage = Sut::Builder::new(&storage_name)
+            .create_locked()
+            .unwrap()
+            .unlock(content.as_bytes())
+            .unwrap();
+
+        let content_len = content.len() as u64;
+        assert_that!(storage, len content_len);
+
+        let mut read_content = String::from_utf8(vec![b' '; content.len()]).unwrap();
+        storage
+            .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
+            .unwrap();
+        assert_that!(read_content, eq content);
+    }
+
     #[test]
     fn create_locked_works<Sut: StaticStorage>() {
         let _test_guard = TEST_MUTEX.lock();
@@ -60,10 +60,21 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Args)]
+pub struct CleanupOptions {
+    #[clap(
+        long,
+        help = "Only report the stale resources of dead nodes without removing them"
+    )]
+    pub dry_run: bool,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all nodes", help_template = help_template(HelpOptions::DontPrintCommandSection))]
     List(ListOptions),
     #[clap(about = "Show node details", help_template = help_template(HelpOptions::DontPrintCommandSection))]
     Details(DetailsOptions),
+    #[clap(about = "Remove the stale resources of dead nodes", help_template = help_template(HelpOptions::DontPrintCommandSection))]
+    Cleanup(CleanupOptions),
 }
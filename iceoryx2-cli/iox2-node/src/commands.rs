@@ -17,6 +17,9 @@ use iceoryx2_cli::filter::NodeIdentifier;
 use iceoryx2_cli::output::NodeDescription;
 use iceoryx2_cli::output::NodeDescriptor;
 use iceoryx2_cli::output::NodeList;
+use iceoryx2_cli::output::StaleResourceList;
+use iceoryx2_cli::output::StaleResourceRemovalDescriptor;
+use iceoryx2_cli::output::StaleResourceRemovalList;
 use iceoryx2_cli::Format;
 
 use crate::cli::OutputFilter;
@@ -62,3 +65,28 @@ pub fn details(identifier: NodeIdentifier, filter: OutputFilter, format: Format)
 
     Ok(())
 }
+
+pub fn cleanup(dry_run: bool, format: Format) -> Result<()> {
+    let report = Node::<ipc::Service>::stale_resource_report(Config::global_config())
+        .context("failed to scan for stale resources")?;
+
+    if dry_run {
+        print!("{}", format.as_string(&StaleResourceList::from(&report))?);
+        return Ok(());
+    }
+
+    let removals =
+        Node::<ipc::Service>::remove_reported_stale_resources(Config::global_config(), &report);
+    print!(
+        "{}",
+        format.as_string(&StaleResourceRemovalList {
+            num: removals.len(),
+            details: removals
+                .iter()
+                .map(StaleResourceRemovalDescriptor::from)
+                .collect()
+        })?
+    );
+
+    Ok(())
+}
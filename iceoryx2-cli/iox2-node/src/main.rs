@@ -55,6 +55,11 @@ fn main() -> Result<()> {
                     eprintln!("Failed to retrieve node details: {e}");
                 }
             }
+            Action::Cleanup(options) => {
+                if let Err(e) = commands::cleanup(options.dry_run, cli.format) {
+                    eprintln!("Failed to clean up stale resources: {e}");
+                }
+            }
         }
     } else {
         Cli::command().print_help().expect("Failed to print help");
@@ -12,6 +12,10 @@
 
 use core::ops::Deref;
 
+use iceoryx2::node::stale_resources::StaleResource as IceoryxStaleResource;
+use iceoryx2::node::stale_resources::StaleResourceKind as IceoryxStaleResourceKind;
+use iceoryx2::node::stale_resources::StaleResourceRemoval as IceoryxStaleResourceRemoval;
+use iceoryx2::node::stale_resources::StaleResourceReport as IceoryxStaleResourceReport;
 use iceoryx2::node::NodeDetails as IceoryxNodeDetails;
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::node::NodeState as IceoryxNodeState;
@@ -239,3 +243,97 @@ where
         }
     }
 }
+
+#[derive(serde::Serialize)]
+pub enum StaleResourceKind {
+    DeadNode,
+    DataSegment,
+    ServiceFiles,
+}
+
+impl From<IceoryxStaleResourceKind> for StaleResourceKind {
+    fn from(kind: IceoryxStaleResourceKind) -> Self {
+        match kind {
+            IceoryxStaleResourceKind::DeadNode => StaleResourceKind::DeadNode,
+            IceoryxStaleResourceKind::DataSegment => StaleResourceKind::DataSegment,
+            IceoryxStaleResourceKind::ServiceFiles => StaleResourceKind::ServiceFiles,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct StaleResourceDescriptor {
+    kind: StaleResourceKind,
+    name: String,
+    node_id: Option<NodeIdString>,
+    service_id: Option<String>,
+    size: Option<u64>,
+}
+
+impl From<&IceoryxStaleResource> for StaleResourceDescriptor {
+    fn from(resource: &IceoryxStaleResource) -> Self {
+        StaleResourceDescriptor {
+            kind: resource.kind().into(),
+            name: resource.name().to_string(),
+            node_id: resource.node_id().map(NodeIdString::from),
+            service_id: resource
+                .service_id()
+                .map(|service_id| service_id.as_str().to_string()),
+            size: resource.size(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct StaleResourceList {
+    pub num: usize,
+    pub total_size: u64,
+    pub details: Vec<StaleResourceDescriptor>,
+}
+
+impl From<&IceoryxStaleResourceReport> for StaleResourceList {
+    fn from(report: &IceoryxStaleResourceReport) -> Self {
+        StaleResourceList {
+            num: report.len(),
+            total_size: report.total_size(),
+            details: report
+                .resources()
+                .iter()
+                .map(StaleResourceDescriptor::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub enum StaleResourceRemovalOutcome {
+    Removed,
+    RemovedByOtherProcess,
+    Failed(String),
+}
+
+#[derive(serde::Serialize)]
+pub struct StaleResourceRemovalDescriptor {
+    #[serde(flatten)]
+    resource: StaleResourceDescriptor,
+    outcome: StaleResourceRemovalOutcome,
+}
+
+impl From<&IceoryxStaleResourceRemoval> for StaleResourceRemovalDescriptor {
+    fn from(removal: &IceoryxStaleResourceRemoval) -> Self {
+        StaleResourceRemovalDescriptor {
+            resource: StaleResourceDescriptor::from(removal.resource()),
+            outcome: match removal.result() {
+                Ok(true) => StaleResourceRemovalOutcome::Removed,
+                Ok(false) => StaleResourceRemovalOutcome::RemovedByOtherProcess,
+                Err(e) => StaleResourceRemovalOutcome::Failed(e.to_string()),
+            },
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct StaleResourceRemovalList {
+    pub num: usize,
+    pub details: Vec<StaleResourceRemovalDescriptor>,
+}
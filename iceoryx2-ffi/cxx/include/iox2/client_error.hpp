@@ -29,6 +29,9 @@ enum class ClientCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The delivery listener of the [`Client`], which is required when the
+    /// [`Service`] notifies on delivery, could not be created.
+    UnableToCreateDeliveryListener,
 };
 } // namespace iox2
 #endif
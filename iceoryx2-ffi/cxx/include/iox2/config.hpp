@@ -98,6 +98,11 @@ class Service {
     auto event_connection_suffix() && -> const char*;
     /// Set the suffix of a one-to-one connection
     void set_event_connection_suffix(const iox::FileName& value) &&;
+    /// Returns true when the creation and removal of every [`Service`] is announced to
+    /// discovery services
+    auto enable_discovery_announcements() && -> bool;
+    /// Enable/disable the announcement of service creations and removals
+    void set_enable_discovery_announcements(bool value) &&;
 
   private:
     friend class Global;
@@ -170,6 +175,11 @@ class PublishSubscribe {
     auto enable_safe_overflow() && -> bool;
     /// Enables/disables safe overflow
     void set_enable_safe_overflow(bool value) &&;
+    /// Defines if the [`Publisher`] stores a send timestamp in the header
+    /// of every [`Sample`].
+    auto enable_send_timestamp() && -> bool;
+    /// Enables/disables the send timestamp
+    void set_enable_send_timestamp(bool value) &&;
     /// If safe overflow is deactivated it defines the deliver strategy of the
     /// [`Publisher`] when the [`Subscriber`]s buffer is full.
     auto unable_to_deliver_strategy() && -> UnableToDeliverStrategy;
@@ -347,6 +357,11 @@ class RequestResponse {
     auto enable_fire_and_forget_requests() && -> bool;
     /// Set if fire-and-forget feature is enabled
     void set_enable_fire_and_forget_requests(bool value) &&;
+    /// Defines if a [`Server`] can send a stream of arbitrary many responses for a single
+    /// [`ActiveRequest`]. When disabled, exactly one response can be sent per [`ActiveRequest`].
+    auto enable_multi_response() && -> bool;
+    /// Set if multiple responses per request are supported
+    void set_enable_multi_response(bool value) &&;
 
   private:
     friend class Defaults;
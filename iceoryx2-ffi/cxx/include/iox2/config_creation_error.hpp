@@ -28,6 +28,9 @@ enum class ConfigCreationError : uint8_t {
     ConfigFileDoesNotExist,
     /// The config file could not be opened due to an internal error
     UnableToOpenConfigFile,
+    /// The value of an environment variable that overrides a config setting could not be
+    /// converted into the type of the setting.
+    InvalidEnvironmentVariableValue,
 };
 
 } // namespace iox2
@@ -38,6 +38,7 @@
 #include "iox2/service_type.hpp"
 #include "iox2/signal_handling_mode.hpp"
 #include "iox2/subscriber_error.hpp"
+#include "iox2/type_compatibility.hpp"
 #include "iox2/type_variant.hpp"
 #include "iox2/unable_to_deliver_strategy.hpp"
 #include "iox2/waitset_enums.hpp"
@@ -205,6 +206,8 @@ constexpr auto from<int, iox2::NodeWaitFailure>(const int value) noexcept -> iox
         return iox2::NodeWaitFailure::TerminationRequest;
     case iox2_node_wait_failure_e_INTERRUPT:
         return iox2::NodeWaitFailure::Interrupt;
+    case iox2_node_wait_failure_e_SHUTDOWN_REQUEST:
+        return iox2::NodeWaitFailure::ShutdownRequest;
     }
 
     IOX_UNREACHABLE();
@@ -218,6 +221,8 @@ constexpr auto from<iox2::NodeWaitFailure, iox2_node_wait_failure_e>(const iox2:
         return iox2_node_wait_failure_e_TERMINATION_REQUEST;
     case iox2::NodeWaitFailure::Interrupt:
         return iox2_node_wait_failure_e_INTERRUPT;
+    case iox2::NodeWaitFailure::ShutdownRequest:
+        return iox2_node_wait_failure_e_SHUTDOWN_REQUEST;
     }
 
     IOX_UNREACHABLE();
@@ -350,6 +355,8 @@ constexpr auto from<int, iox2::EventOpenOrCreateError>(const int value) noexcept
         return iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_event_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::EventOpenOrCreateError::SystemInFlux;
+    case iox2_event_open_or_create_error_e_TIMEOUT:
+        return iox2::EventOpenOrCreateError::Timeout;
     }
 
     IOX_UNREACHABLE();
@@ -401,6 +408,10 @@ from<iox2::EventOpenOrCreateError, iox2_event_open_or_create_error_e>(const iox2
         return iox2_event_open_or_create_error_e_C_INSUFFICIENT_PERMISSIONS;
     case iox2::EventOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_event_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::EventOpenOrCreateError::SystemInFlux:
+        return iox2_event_open_or_create_error_e_SYSTEM_IN_FLUX;
+    case iox2::EventOpenOrCreateError::Timeout:
+        return iox2_event_open_or_create_error_e_TIMEOUT;
     default:
         IOX_UNREACHABLE();
     }
@@ -566,6 +577,14 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSendTimestampBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_LIFESPAN:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSampleLifespan;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOwnershipBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleNotifyOnDeliveryBehavior;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -595,6 +614,8 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive;
     case iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::PublishSubscribeOpenOrCreateError::SystemInFlux;
+    case iox2_pub_sub_open_or_create_error_e_TIMEOUT:
+        return iox2::PublishSubscribeOpenOrCreateError::Timeout;
     }
 
     IOX_UNREACHABLE();
@@ -628,6 +649,14 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR:
         return iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR:
+        return iox2::PublishSubscribeOpenError::IncompatibleSendTimestampBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_LIFESPAN:
+        return iox2::PublishSubscribeOpenError::IncompatibleSampleLifespan;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR:
+        return iox2::PublishSubscribeOpenError::IncompatibleOwnershipBehavior;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR:
+        return iox2::PublishSubscribeOpenError::IncompatibleNotifyOnDeliveryBehavior;
     case iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::PublishSubscribeOpenError::InsufficientPermissions;
     case iox2_pub_sub_open_or_create_error_e_O_SERVICE_IN_CORRUPTED_STATE:
@@ -671,6 +700,14 @@ constexpr auto from<iox2::PublishSubscribeOpenError, iox2_pub_sub_open_or_create
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenError::IncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleSendTimestampBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleSampleLifespan:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_LIFESPAN;
+    case iox2::PublishSubscribeOpenError::IncompatibleOwnershipBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR;
+    case iox2::PublishSubscribeOpenError::IncompatibleNotifyOnDeliveryBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR;
     case iox2::PublishSubscribeOpenError::InsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenError::ServiceInCorruptedState:
@@ -773,6 +810,14 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES;
     case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOverflowBehavior:
         return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSendTimestampBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSampleLifespan:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SAMPLE_LIFESPAN;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleOwnershipBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR;
+    case iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleNotifyOnDeliveryBehavior:
+        return iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR;
     case iox2::PublishSubscribeOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_pub_sub_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::PublishSubscribeOpenOrCreateError::OpenServiceInCorruptedState:
@@ -799,6 +844,10 @@ constexpr auto from<iox2::PublishSubscribeOpenOrCreateError, iox2_pub_sub_open_o
         return iox2_pub_sub_open_or_create_error_e_C_HANGS_IN_CREATION;
     case iox2::PublishSubscribeOpenOrCreateError::CreateOldConnectionsStillActive:
         return iox2_pub_sub_open_or_create_error_e_C_OLD_CONNECTION_STILL_ACTIVE;
+    case iox2::PublishSubscribeOpenOrCreateError::SystemInFlux:
+        return iox2_pub_sub_open_or_create_error_e_SYSTEM_IN_FLUX;
+    case iox2::PublishSubscribeOpenOrCreateError::Timeout:
+        return iox2_pub_sub_open_or_create_error_e_TIMEOUT;
     default:
         IOX_UNREACHABLE();
     }
@@ -871,6 +920,8 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT:
         return iox2::RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient;
+    case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT:
+        return iox2::RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE:
         return iox2::RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SERVERS:
@@ -899,6 +950,14 @@ constexpr auto from<int, iox2::RequestResponseOpenError>(const int value) noexce
         return iox2::RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE:
+        return iox2::RequestResponseOpenError::IncompatibleBehaviorForMultiResponse;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR:
+        return iox2::RequestResponseOpenError::IncompatibleNotifyOnDeliveryBehavior;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_DEADLINE:
+        return iox2::RequestResponseOpenError::IncompatibleResponseDeadline;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_CREDIT_FLOW_CONTROL:
+        return iox2::RequestResponseOpenError::IncompatibleCreditFlowControl;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenError::InsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
@@ -922,6 +981,8 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENT_REQUEST_LOANS;
     case iox2::RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient:
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT;
+    case iox2::RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient:
+        return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT;
     case iox2::RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize:
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE;
     case iox2::RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers:
@@ -950,6 +1011,14 @@ constexpr auto from<iox2::RequestResponseOpenError, iox2_request_response_open_o
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenError::IncompatibleBehaviorForMultiResponse:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE;
+    case iox2::RequestResponseOpenError::IncompatibleNotifyOnDeliveryBehavior:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR;
+    case iox2::RequestResponseOpenError::IncompatibleResponseDeadline:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_DEADLINE;
+    case iox2::RequestResponseOpenError::IncompatibleCreditFlowControl:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_CREDIT_FLOW_CONTROL;
     case iox2::RequestResponseOpenError::InsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenError::InternalFailure:
@@ -981,6 +1050,8 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfClientRequestLoans;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT:
         return iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfActiveRequestsPerClient;
+    case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT:
+        return iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedRequestQueueDepthPerClient;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE:
         return iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedResponseBufferSize;
     case iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SERVERS:
@@ -1010,6 +1081,14 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleOverflowBehaviorForResponses;
     case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS:
         return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForMultiResponse;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleNotifyOnDeliveryBehavior;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_DEADLINE:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseDeadline;
+    case iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_CREDIT_FLOW_CONTROL:
+        return iox2::RequestResponseOpenOrCreateError::OpenIncompatibleCreditFlowControl;
     case iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS:
         return iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions;
     case iox2_request_response_open_or_create_error_e_O_INTERNAL_FAILURE:
@@ -1033,6 +1112,10 @@ constexpr auto from<int, iox2::RequestResponseOpenOrCreateError>(const int value
         return iox2::RequestResponseOpenOrCreateError::CreateServiceInCorruptedState;
     case iox2_request_response_open_or_create_error_e_SYSTEM_IN_FLUX:
         return iox2::RequestResponseOpenOrCreateError::SystemInFlux;
+    case iox2_request_response_open_or_create_error_e_CONFIGURATION_MISMATCH:
+        return iox2::RequestResponseOpenOrCreateError::ConfigurationMismatch;
+    case iox2_request_response_open_or_create_error_e_TIMEOUT:
+        return iox2::RequestResponseOpenOrCreateError::Timeout;
 
     default:
         IOX_UNREACHABLE();
@@ -1049,6 +1132,8 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENT_REQUEST_LOANS;
     case iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfActiveRequestsPerClient:
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT;
+    case iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedRequestQueueDepthPerClient:
+        return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT;
     case iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedResponseBufferSize:
         return iox2_request_response_open_or_create_error_e_O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE;
     case iox2::RequestResponseOpenOrCreateError::OpenDoesNotSupportRequestedAmountOfServers:
@@ -1077,6 +1162,14 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES;
     case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForFireAndForgetRequests:
         return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleBehaviorForMultiResponse:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleNotifyOnDeliveryBehavior:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleResponseDeadline:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_RESPONSE_DEADLINE;
+    case iox2::RequestResponseOpenOrCreateError::OpenIncompatibleCreditFlowControl:
+        return iox2_request_response_open_or_create_error_e_O_INCOMPATIBLE_CREDIT_FLOW_CONTROL;
     case iox2::RequestResponseOpenOrCreateError::OpenInsufficientPermissions:
         return iox2_request_response_open_or_create_error_e_O_INSUFFICIENT_PERMISSIONS;
     case iox2::RequestResponseOpenOrCreateError::OpenInternalFailure:
@@ -1100,6 +1193,10 @@ constexpr auto from<iox2::RequestResponseOpenOrCreateError, iox2_request_respons
         return iox2_request_response_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE;
     case iox2::RequestResponseOpenOrCreateError::SystemInFlux:
         return iox2_request_response_open_or_create_error_e_SYSTEM_IN_FLUX;
+    case iox2::RequestResponseOpenOrCreateError::ConfigurationMismatch:
+        return iox2_request_response_open_or_create_error_e_CONFIGURATION_MISMATCH;
+    case iox2::RequestResponseOpenOrCreateError::Timeout:
+        return iox2_request_response_open_or_create_error_e_TIMEOUT;
     default:
         IOX_UNREACHABLE();
     }
@@ -1123,6 +1220,8 @@ constexpr auto from<int, iox2::ClientCreateError>(const int value) noexcept -> i
         return iox2::ClientCreateError::UnableToCreateDataSegment;
     case iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_client_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER:
+        return iox2::ClientCreateError::UnableToCreateDeliveryListener;
     }
 
     IOX_UNREACHABLE();
@@ -1138,6 +1237,8 @@ constexpr auto from<iox2::ClientCreateError, iox2_client_create_error_e>(const i
         return iox2_client_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ClientCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_client_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ClientCreateError::UnableToCreateDeliveryListener:
+        return iox2_client_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER;
     }
 
     IOX_UNREACHABLE();
@@ -1158,6 +1259,8 @@ constexpr auto from<int, iox2::ServerCreateError>(const int value) noexcept -> i
         return iox2::ServerCreateError::UnableToCreateDataSegment;
     case iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_server_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER:
+        return iox2::ServerCreateError::UnableToCreateDeliveryListener;
     }
 
     IOX_UNREACHABLE();
@@ -1173,6 +1276,8 @@ constexpr auto from<iox2::ServerCreateError, iox2_server_create_error_e>(const i
         return iox2_server_create_error_e_UNABLE_TO_CREATE_DATA_SEGMENT;
     case iox2::ServerCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_server_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::ServerCreateError::UnableToCreateDeliveryListener:
+        return iox2_server_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER;
     }
 
     IOX_UNREACHABLE();
@@ -1263,6 +1368,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_LISTENER_BUFFER_OVERFLOW:
+        return iox2::NotifierNotifyError::ListenerBufferOverflow;
     }
 
     IOX_UNREACHABLE();
@@ -1279,6 +1386,8 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::ListenerBufferOverflow:
+        return iox2_notifier_notify_error_e_LISTENER_BUFFER_OVERFLOW;
     }
 
     IOX_UNREACHABLE();
@@ -1372,6 +1481,8 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
     case iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY:
         return iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy;
+    case iox2_subscriber_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER:
+        return iox2::SubscriberCreateError::UnableToCreateDeliveryListener;
     }
 
     IOX_UNREACHABLE();
@@ -1388,6 +1499,8 @@ from<iox2::SubscriberCreateError, iox2_subscriber_create_error_e>(const iox2::Su
         return iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS;
     case iox2::SubscriberCreateError::FailedToDeployThreadsafetyPolicy:
         return iox2_subscriber_create_error_e_FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY;
+    case iox2::SubscriberCreateError::UnableToCreateDeliveryListener:
+        return iox2_subscriber_create_error_e_UNABLE_TO_CREATE_DELIVERY_LISTENER;
     }
 
     IOX_UNREACHABLE();
@@ -1413,10 +1526,14 @@ constexpr auto from<int, iox2::SendError>(const int value) noexcept -> iox2::Sen
         return iox2::SendError::LoanErrorExceedsMaxLoans;
     case iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::SendError::LoanErrorExceedsMaxLoanSize;
+    case iox2_send_error_e_LOAN_ERROR_PAYLOAD_TOO_LARGE:
+        return iox2::SendError::LoanErrorPayloadTooLarge;
     case iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE:
         return iox2::SendError::LoanErrorInternalFailure;
     case iox2_send_error_e_CONNECTION_ERROR:
         return iox2::SendError::ConnectionError;
+    case iox2_send_error_e_MULTI_RESPONSE_DISABLED:
+        return iox2::SendError::MultiResponseDisabled;
     }
 
     IOX_UNREACHABLE();
@@ -1435,10 +1552,14 @@ constexpr auto from<iox2::SendError, iox2_send_error_e>(const iox2::SendError va
         return iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOANS;
     case iox2::SendError::LoanErrorExceedsMaxLoanSize:
         return iox2_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::SendError::LoanErrorPayloadTooLarge:
+        return iox2_send_error_e_LOAN_ERROR_PAYLOAD_TOO_LARGE;
     case iox2::SendError::LoanErrorInternalFailure:
         return iox2_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::SendError::ConnectionError:
         return iox2_send_error_e_CONNECTION_ERROR;
+    case iox2::SendError::MultiResponseDisabled:
+        return iox2_send_error_e_MULTI_RESPONSE_DISABLED;
     }
 
     IOX_UNREACHABLE();
@@ -1459,6 +1580,8 @@ constexpr auto from<int, iox2::ReceiveError>(const int value) noexcept -> iox2::
         return iox2::ReceiveError::UnableToMapSendersDataSegment;
     case iox2_receive_error_e_EXCEEDS_MAX_BORROWS:
         return iox2::ReceiveError::ExceedsMaxBorrows;
+    case iox2_receive_error_e_EXCEEDS_MAX_SLICE_LEN:
+        return iox2::ReceiveError::ExceedsMaxSliceLen;
     }
 
     IOX_UNREACHABLE();
@@ -1474,6 +1597,8 @@ constexpr auto from<iox2::ReceiveError, iox2_receive_error_e>(const iox2::Receiv
         return iox2_receive_error_e_UNABLE_TO_MAP_SENDERS_DATA_SEGMENT;
     case iox2::ReceiveError::ExceedsMaxBorrows:
         return iox2_receive_error_e_EXCEEDS_MAX_BORROWS;
+    case iox2::ReceiveError::ExceedsMaxSliceLen:
+        return iox2_receive_error_e_EXCEEDS_MAX_SLICE_LEN;
     }
 
     IOX_UNREACHABLE();
@@ -1494,6 +1619,8 @@ constexpr auto from<int, iox2::LoanError>(const int value) noexcept -> iox2::Loa
         return iox2::LoanError::OutOfMemory;
     case iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::LoanError::ExceedsMaxLoanSize;
+    case iox2_loan_error_e_PAYLOAD_TOO_LARGE:
+        return iox2::LoanError::PayloadTooLarge;
     case iox2_loan_error_e_INTERNAL_FAILURE:
         return iox2::LoanError::InternalFailure;
     }
@@ -1510,6 +1637,8 @@ constexpr auto from<iox2::LoanError, iox2_loan_error_e>(const iox2::LoanError va
         return iox2_loan_error_e_OUT_OF_MEMORY;
     case iox2::LoanError::ExceedsMaxLoanSize:
         return iox2_loan_error_e_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::LoanError::PayloadTooLarge:
+        return iox2_loan_error_e_PAYLOAD_TOO_LARGE;
     case iox2::LoanError::InternalFailure:
         return iox2_loan_error_e_INTERNAL_FAILURE;
     }
@@ -1538,10 +1667,16 @@ constexpr auto from<int, iox2::RequestSendError>(const int value) noexcept -> io
         return iox2::RequestSendError::LoanErrorExceedsMaxLoans;
     case iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE:
         return iox2::RequestSendError::LoanErrorExceedsMaxLoanSize;
+    case iox2_request_send_error_e_LOAN_ERROR_PAYLOAD_TOO_LARGE:
+        return iox2::RequestSendError::LoanErrorPayloadTooLarge;
     case iox2_request_send_error_e_LOAN_ERROR_INTERNAL_FAILURE:
         return iox2::RequestSendError::LoanErrorInternalFailure;
     case iox2_request_send_error_e_CONNECTION_ERROR:
         return iox2::RequestSendError::ConnectionError;
+    case iox2_request_send_error_e_BACK_PRESSURE:
+        return iox2::RequestSendError::BackPressure;
+    case iox2_request_send_error_e_MULTI_RESPONSE_DISABLED:
+        return iox2::RequestSendError::MultiResponseDisabled;
     }
 
     IOX_UNREACHABLE();
@@ -1563,10 +1698,16 @@ constexpr auto from<iox2::RequestSendError, iox2_request_send_error_e>(const iox
         return iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOANS;
     case iox2::RequestSendError::LoanErrorExceedsMaxLoanSize:
         return iox2_request_send_error_e_LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE;
+    case iox2::RequestSendError::LoanErrorPayloadTooLarge:
+        return iox2_request_send_error_e_LOAN_ERROR_PAYLOAD_TOO_LARGE;
     case iox2::RequestSendError::LoanErrorInternalFailure:
         return iox2_request_send_error_e_LOAN_ERROR_INTERNAL_FAILURE;
     case iox2::RequestSendError::ConnectionError:
         return iox2_request_send_error_e_CONNECTION_ERROR;
+    case iox2::RequestSendError::BackPressure:
+        return iox2_request_send_error_e_BACK_PRESSURE;
+    case iox2::RequestSendError::MultiResponseDisabled:
+        return iox2_request_send_error_e_MULTI_RESPONSE_DISABLED;
     }
 
     IOX_UNREACHABLE();
@@ -1590,6 +1731,19 @@ constexpr auto from<int, iox2::TypeVariant>(const int value) noexcept -> iox2::T
     IOX_UNREACHABLE();
 }
 
+template <>
+constexpr auto from<iox2::TypeCompatibility, iox2_type_compatibility_e>(const iox2::TypeCompatibility value) noexcept
+    -> iox2_type_compatibility_e {
+    switch (value) {
+    case iox2::TypeCompatibility::Strict:
+        return iox2_type_compatibility_e_STRICT;
+    case iox2::TypeCompatibility::LayoutOnly:
+        return iox2_type_compatibility_e_LAYOUT_ONLY;
+    }
+
+    IOX_UNREACHABLE();
+}
+
 template <>
 constexpr auto from<int, iox2::ServiceListError>(const int value) noexcept -> iox2::ServiceListError {
     const auto variant = static_cast<iox2_service_list_error_e>(value);
@@ -1709,6 +1863,8 @@ constexpr auto from<int, iox2::ConfigCreationError>(const int value) noexcept ->
     case iox2_config_creation_error_e_INVALID_FILE_PATH:
         // unreachable since this error case is excluded by using the strong type iox::FilePath
         IOX_UNREACHABLE();
+    case iox2_config_creation_error_e_INVALID_ENVIRONMENT_VARIABLE_VALUE:
+        return iox2::ConfigCreationError::InvalidEnvironmentVariableValue;
     }
 
     IOX_UNREACHABLE();
@@ -1729,6 +1885,8 @@ from<iox2::ConfigCreationError, iox2_config_creation_error_e>(const iox2::Config
         return iox2_config_creation_error_e_CONFIG_FILE_DOES_NOT_EXIST;
     case iox2::ConfigCreationError::UnableToOpenConfigFile:
         return iox2_config_creation_error_e_UNABLE_TO_OPEN_CONFIG_FILE;
+    case iox2::ConfigCreationError::InvalidEnvironmentVariableValue:
+        return iox2_config_creation_error_e_INVALID_ENVIRONMENT_VARIABLE_VALUE;
     }
 
     IOX_UNREACHABLE();
@@ -1852,6 +2010,8 @@ constexpr auto from<int, iox2::WaitSetAttachmentError>(const int value) noexcept
         return iox2::WaitSetAttachmentError::InsufficientCapacity;
     case iox2_waitset_attachment_error_e_INTERNAL_ERROR:
         return iox2::WaitSetAttachmentError::InternalError;
+    case iox2_waitset_attachment_error_e_UNSUPPORTED_ATTACHMENT:
+        return iox2::WaitSetAttachmentError::UnsupportedAttachment;
     }
 
     IOX_UNREACHABLE();
@@ -1868,6 +2028,8 @@ from<iox2::WaitSetAttachmentError, iox2_waitset_attachment_error_e>(const iox2::
         return iox2_waitset_attachment_error_e_INSUFFICIENT_CAPACITY;
     case iox2::WaitSetAttachmentError::InternalError:
         return iox2_waitset_attachment_error_e_INTERNAL_ERROR;
+    case iox2::WaitSetAttachmentError::UnsupportedAttachment:
+        return iox2_waitset_attachment_error_e_UNSUPPORTED_ATTACHMENT;
     }
 
     IOX_UNREACHABLE();
@@ -13,6 +13,8 @@
 #ifndef IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 #define IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 
+#include "iox/duration.hpp"
+#include "iox/optional.hpp"
 #include "iox2/internal/iceoryx2.hpp"
 #include "unique_port_id.hpp"
 
@@ -32,6 +34,14 @@ class HeaderPublishSubscribe {
     /// Returns the number of [`Payload`] elements in the received [`Sample`].
     auto number_of_elements() const -> uint64_t;
 
+    /// Returns the monotonic send timestamp of the [`Sample`] if the [`Service`] captures
+    /// send timestamps. The value can only be compared with samples sent on the same host.
+    auto send_timestamp_monotonic() const -> iox::optional<iox::units::Duration>;
+
+    /// Returns the system time (time since UNIX epoch) when the [`Sample`] was sent if the
+    /// [`Service`] captures send timestamps.
+    auto send_timestamp_system_time() const -> iox::optional<iox::units::Duration>;
+
   private:
     template <ServiceType, typename, typename>
     friend class Sample;
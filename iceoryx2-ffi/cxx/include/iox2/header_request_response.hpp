@@ -13,6 +13,7 @@
 #ifndef IOX2_HEADER_REQUEST_RESPONSE_HPP
 #define IOX2_HEADER_REQUEST_RESPONSE_HPP
 
+#include "iox/optional.hpp"
 #include "iox2/unique_port_id.hpp"
 
 namespace iox2 {
@@ -54,6 +55,13 @@ class ResponseHeader {
     /// Returns the [`UniqueServerId`] of the source [`Server`].
     auto server_port_id() -> UniqueServerId;
 
+    /// Returns true when the [`Server`] rejected the request with an error response.
+    auto is_error() -> bool;
+
+    /// Returns the error code the [`Server`] rejected the request with or [`iox::nullopt`]
+    /// when the response is not an error response.
+    auto error_code() -> iox::optional<uint64_t>;
+
   private:
     template <ServiceType, typename, typename>
     friend class Response;
@@ -23,6 +23,8 @@ enum class NodeWaitFailure : uint8_t {
     TerminationRequest,
     /// SIGINT signal was received
     Interrupt,
+    /// Another [`Node`] requested a shutdown via the internal shutdown service
+    ShutdownRequest,
 };
 
 } // namespace iox2
@@ -36,8 +36,12 @@ class Notifier {
     /// Returns the [`UniqueNotifierId`] of the [`Notifier`]
     auto id() const -> UniqueNotifierId;
 
+    /// Returns the [`EventId`] that is used by [`Notifier::notify()`]. If no default
+    /// [`EventId`] was provided on creation it is `EventId(0)`.
+    auto default_event_id() const -> EventId;
+
     /// Notifies all [`Listener`] connected to the service with the default
-    /// event id provided on creation.
+    /// event id provided on creation, see [`Notifier::default_event_id()`].
     /// Returns on success the number of [`Listener`]s that were notified otherwise it returns
     /// [`NotifierNotifyError`].
     auto notify() const -> iox::expected<size_t, NotifierNotifyError>;
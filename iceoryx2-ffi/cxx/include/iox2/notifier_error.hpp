@@ -41,6 +41,9 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// At least one [`Listener`] was unable to hold all [`EventId`]s of a batch
+    /// notification. None of the [`EventId`]s were delivered to this [`Listener`].
+    ListenerBufferOverflow,
 };
 
 } // namespace iox2
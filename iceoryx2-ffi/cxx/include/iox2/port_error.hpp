@@ -31,6 +31,9 @@ enum class LoanError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     ExceedsMaxLoanSize,
+    /// The provided slice size exceeds the maximum payload size that was defined when the
+    /// [`Service`] was created.
+    PayloadTooLarge,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
 };
@@ -51,10 +54,16 @@ enum class SendError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     LoanErrorExceedsMaxLoanSize,
+    /// The provided slice size exceeds the maximum payload size that was defined when the
+    /// [`Service`] was created.
+    LoanErrorPayloadTooLarge,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
+    /// A [`Response`] was already sent for the [`ActiveRequest`] and the [`Service`] does not
+    /// support multiple responses per request.
+    MultiResponseDisabled,
 };
 
 /// Defines the failure that can occur when receiving data with
@@ -69,7 +78,11 @@ enum class ReceiveError : uint8_t {
     FailedToEstablishConnection,
 
     /// Failures when mapping the corresponding data segment
-    UnableToMapSendersDataSegment
+    UnableToMapSendersDataSegment,
+
+    /// The received slice has more elements than the max slice len that was configured for
+    /// the receiver. The data was discarded.
+    ExceedsMaxSliceLen
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -92,10 +105,19 @@ enum class RequestSendError : uint8_t {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     LoanErrorExceedsMaxLoanSize,
+    /// The provided slice size exceeds the maximum payload size that was defined when the
+    /// [`Service`] was created.
+    LoanErrorPayloadTooLarge,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     LoanErrorInternalFailure,
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError,
+    /// The [`Client`] has no credits left since a [`Server`] has not yet acknowledged
+    /// enough of its previous [`RequestMut`]s.
+    BackPressure,
+    /// A [`Response`] was already sent for the [`ActiveRequest`] and the [`Service`] does not
+    /// support multiple responses per request.
+    MultiResponseDisabled,
 };
 } // namespace iox2
 
@@ -27,6 +27,9 @@ enum class ServerCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The delivery listener of the [`Server`], which is required when the
+    /// [`Service`] notifies on delivery, could not be created.
+    UnableToCreateDeliveryListener,
 };
 } // namespace iox2
 #endif
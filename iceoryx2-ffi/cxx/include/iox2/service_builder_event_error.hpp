@@ -170,6 +170,9 @@ enum class EventOpenOrCreateError : uint8_t {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 };
 
 } // namespace iox2
@@ -24,6 +24,7 @@
 #include "iox2/port_factory_publish_subscribe.hpp"
 #include "iox2/service_builder_publish_subscribe_error.hpp"
 #include "iox2/service_type.hpp"
+#include "iox2/type_compatibility.hpp"
 
 namespace iox2 {
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
@@ -39,6 +40,17 @@ class ServiceBuilderPublishSubscribe {
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     IOX_BUILDER_OPTIONAL(bool, enable_safe_overflow);
 
+    /// If the [`Service`] is created, defines if the [`Publisher`] stores a send timestamp in
+    /// the header of every [`Sample`]. The header has the same size either way. If an existing
+    /// [`Service`] is opened it requires the service to have the defined send timestamp
+    /// behavior.
+    IOX_BUILDER_OPTIONAL(bool, enable_send_timestamp);
+
+    /// If the [`Service`] is created, defines how the payload and user header types are
+    /// matched when the service is opened. If an existing [`Service`] is opened it requires
+    /// the service to use the same [`TypeCompatibility`].
+    IOX_BUILDER_OPTIONAL(TypeCompatibility, type_compatibility);
+
     /// If the [`Service`] is created it defines how many [`Sample`]s a
     /// [`Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -120,8 +132,13 @@ inline ServiceBuilderPublishSubscribe<Payload, UserHeader, S>::ServiceBuilderPub
 
 template <typename Payload, typename UserHeader, ServiceType S>
 inline void ServiceBuilderPublishSubscribe<Payload, UserHeader, S>::set_parameters() {
+    m_type_compatibility.and_then([&](auto value) {
+        iox2_service_builder_pub_sub_set_type_compatibility(&m_handle, iox::into<iox2_type_compatibility_e>(value));
+    });
     m_enable_safe_overflow.and_then(
         [&](auto value) { iox2_service_builder_pub_sub_set_enable_safe_overflow(&m_handle, value); });
+    m_enable_send_timestamp.and_then(
+        [&](auto value) { iox2_service_builder_pub_sub_set_enable_send_timestamp(&m_handle, value); });
     m_subscriber_max_borrowed_samples.and_then(
         [&](auto value) { iox2_service_builder_pub_sub_set_subscriber_max_borrowed_samples(&m_handle, value); });
     m_history_size.and_then([&](auto value) { iox2_service_builder_pub_sub_set_history_size(&m_handle, value); });
@@ -49,6 +49,14 @@ enum class PublishSubscribeOpenError : uint8_t {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required send timestamp behavior is not compatible.
+    IncompatibleSendTimestampBehavior,
+    /// The [`Service`]s sample lifespan is not equal to the user given requirement.
+    IncompatibleSampleLifespan,
+    /// The [`Service`] required exclusive ownership behavior is not compatible.
+    IncompatibleOwnershipBehavior,
+    /// The [`Service`] required notify on delivery behavior is not compatible.
+    IncompatibleNotifyOnDeliveryBehavior,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -127,6 +135,14 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     OpenDoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     OpenIncompatibleOverflowBehavior,
+    /// The [`Service`] required send timestamp behavior is not compatible.
+    OpenIncompatibleSendTimestampBehavior,
+    /// The [`Service`]s sample lifespan is not equal to the user given requirement.
+    OpenIncompatibleSampleLifespan,
+    /// The [`Service`] required exclusive ownership behavior is not compatible.
+    OpenIncompatibleOwnershipBehavior,
+    /// The [`Service`] required notify on delivery behavior is not compatible.
+    OpenIncompatibleNotifyOnDeliveryBehavior,
     /// The process has not enough permissions to open the [`Service`]
     OpenInsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing,
@@ -177,6 +193,9 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 };
 
 } // namespace iox2
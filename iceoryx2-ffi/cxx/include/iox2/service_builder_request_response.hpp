@@ -24,6 +24,7 @@
 #include "iox2/port_factory_request_response.hpp"
 #include "iox2/service_builder_request_response_error.hpp"
 #include "iox2/service_type.hpp"
+#include "iox2/type_compatibility.hpp"
 
 namespace iox2 {
 template <typename RequestPayload,
@@ -54,6 +55,11 @@ class ServiceBuilderRequestResponse {
     /// behavior.
     IOX_BUILDER_OPTIONAL(bool, enable_safe_overflow_for_responses);
 
+    /// If the [`Service`] is created, defines how the payload and user header types are
+    /// matched when the service is opened. If an existing [`Service`] is opened it requires
+    /// the service to use the same [`TypeCompatibility`].
+    IOX_BUILDER_OPTIONAL(TypeCompatibility, type_compatibility);
+
     /// Defines how many active requests a [`Server`] can hold in
     /// parallel per [`Client`]. The objects are used to send answers to a request that was
     /// received earlier from a [`Client`]
@@ -91,6 +97,10 @@ class ServiceBuilderRequestResponse {
     /// If the [`Service`] is created, defines the fire-and-forget behavior of the service for requests.
     IOX_BUILDER_OPTIONAL(bool, enable_fire_and_forget_requests);
 
+    /// If the [`Service`] is created, defines if a [`Server`] can send arbitrary many responses
+    /// per [`ActiveRequest`] or exactly one.
+    IOX_BUILDER_OPTIONAL(bool, enable_multi_response);
+
   public:
     /// Sets the request user header type of the [`Service`].
     template <typename NewRequestUserHeader>
@@ -358,6 +368,10 @@ template <typename RequestPayload,
           ServiceType S>
 inline void ServiceBuilderRequestResponse<RequestPayload, RequestUserHeader, ResponsePayload, ResponseUserHeader, S>::
     set_parameters() {
+    m_type_compatibility.and_then([&](auto value) {
+        iox2_service_builder_request_response_set_type_compatibility(&m_handle,
+                                                                     iox::into<iox2_type_compatibility_e>(value));
+    });
     m_request_payload_alignment.and_then(
         [&](auto value) { iox2_service_builder_request_response_request_payload_alignment(&m_handle, value); });
     m_response_payload_alignment.and_then(
@@ -381,6 +395,8 @@ inline void ServiceBuilderRequestResponse<RequestPayload, RequestUserHeader, Res
         [&](auto value) { iox2_service_builder_request_response_max_loaned_requests(&m_handle, value); });
     m_enable_fire_and_forget_requests.and_then(
         [&](auto value) { iox2_service_builder_request_response_enable_fire_and_forget_requests(&m_handle, value); });
+    m_enable_multi_response.and_then(
+        [&](auto value) { iox2_service_builder_request_response_enable_multi_response(&m_handle, value); });
 
     // request payload type details
     using RequestValueType = typename PayloadInfo<RequestPayload>::ValueType;
@@ -27,6 +27,9 @@ enum class RequestResponseOpenError : uint8_t {
     /// The [`Service`] has a lower maximum amount of [`ActiveRequest`]s than
     /// requested.
     DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+    /// The [`Service`] has a lower request queue depth per [`Client`] than
+    /// requested.
+    DoesNotSupportRequestedRequestQueueDepthPerClient,
     /// The [`Service`] has a lower maximum response buffer size than requested.
     DoesNotSupportRequestedResponseBufferSize,
     /// The [`Service`] has a lower maximum number of servers than requested.
@@ -56,6 +59,14 @@ enum class RequestResponseOpenError : uint8_t {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for multiple responses per request.
+    IncompatibleBehaviorForMultiResponse,
+    /// The [`Service`] required notify on delivery behavior is not compatible.
+    IncompatibleNotifyOnDeliveryBehavior,
+    /// The [`Service`]s response deadline is not equal to the required response deadline.
+    IncompatibleResponseDeadline,
+    /// The [`Service`]s credit flow control is not equal to the required credit flow control.
+    IncompatibleCreditFlowControl,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -96,6 +107,9 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     /// The [`Service`] has a lower maximum amount of [`ActiveRequest`]s than
     /// requested.
     OpenDoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+    /// The [`Service`] has a lower request queue depth per [`Client`] than
+    /// requested.
+    OpenDoesNotSupportRequestedRequestQueueDepthPerClient,
     /// The [`Service`] has a lower maximum response buffer size than requested.
     OpenDoesNotSupportRequestedResponseBufferSize,
     /// The [`Service`] has a lower maximum number of servers than requested.
@@ -125,6 +139,14 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     OpenIncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     OpenIncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for multiple responses per request.
+    OpenIncompatibleBehaviorForMultiResponse,
+    /// The [`Service`] required notify on delivery behavior is not compatible.
+    OpenIncompatibleNotifyOnDeliveryBehavior,
+    /// The [`Service`]s response deadline is not equal to the required response deadline.
+    OpenIncompatibleResponseDeadline,
+    /// The [`Service`]s credit flow control is not equal to the required credit flow control.
+    OpenIncompatibleCreditFlowControl,
     /// The process has not enough permissions to open the [`Service`].
     OpenInsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -153,6 +175,12 @@ enum class RequestResponseOpenOrCreateError : uint8_t {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The existing [`Service`] was opened with `open_or_create_or_fail()` but its parameters
+    /// differ from the parameters of the builder.
+    ConfigurationMismatch,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 };
 
 } // namespace iox2
@@ -49,6 +49,10 @@ class StaticConfigPublishSubscribe {
     /// [`Sample`] from the [`Subscriber`] when its buffer is full.
     auto has_safe_overflow() const -> bool;
 
+    /// Returns true if the [`Publisher`] stores a send timestamp in the
+    /// header of every [`Sample`], otherwise false.
+    auto has_send_timestamp() const -> bool;
+
     /// Returns the type details of the [`Service`].
     auto message_type_details() const -> MessageTypeDetails;
 
@@ -55,6 +55,10 @@ class StaticConfigRequestResponse {
     /// false.
     auto does_support_fire_and_forget_requests() const -> bool;
 
+    /// Returns true if a [`Server`] can send arbitrary many responses per [`ActiveRequest`],
+    /// otherwise false.
+    auto does_support_multi_response() const -> bool;
+
     /// Returns the maximum number of supported [`Server`] ports for the [`Service`].
     auto max_servers() const -> uint64_t;
 
@@ -30,6 +30,9 @@ enum class SubscriberCreateError : uint8_t {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`] as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The delivery listener of the [`Subscriber`], which is required when the
+    /// [`Service`] notifies on delivery, could not be created.
+    UnableToCreateDeliveryListener,
 };
 
 } // namespace iox2
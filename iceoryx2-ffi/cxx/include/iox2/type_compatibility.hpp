@@ -0,0 +1,30 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_TYPE_COMPATIBILITY_HPP
+#define IOX2_TYPE_COMPATIBILITY_HPP
+
+#include <cstdint>
+
+namespace iox2 {
+/// Defines how the payload and user header types of a [`Service`] are matched
+/// when it is opened.
+enum class TypeCompatibility : uint8_t {
+    /// The type name, size and alignment must be identical.
+    Strict,
+    /// Only size and alignment must be identical, the type name is ignored.
+    /// Useful when the same memory layout is defined in different languages.
+    LayoutOnly,
+};
+} // namespace iox2
+
+#endif
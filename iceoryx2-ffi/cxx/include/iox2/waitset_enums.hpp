@@ -42,7 +42,9 @@ enum class WaitSetAttachmentError : uint8_t {
     /// The attachment is already attached.
     AlreadyAttached,
     /// An internal error has occurred.
-    InternalError
+    InternalError,
+    /// The attachment is not supported on the current platform.
+    UnsupportedAttachment
 };
 
 /// Defines the failures that can occur when calling [`WaitSet::run()`].
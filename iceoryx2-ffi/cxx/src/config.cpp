@@ -352,6 +352,14 @@ void PublishSubscribe::set_enable_safe_overflow(bool value) && {
     iox2_config_defaults_publish_subscribe_set_enable_safe_overflow(m_config, value);
 }
 
+auto PublishSubscribe::enable_send_timestamp() && -> bool {
+    return iox2_config_defaults_publish_subscribe_enable_send_timestamp(m_config);
+}
+
+void PublishSubscribe::set_enable_send_timestamp(bool value) && {
+    iox2_config_defaults_publish_subscribe_set_enable_send_timestamp(m_config, value);
+}
+
 auto PublishSubscribe::unable_to_deliver_strategy() && -> UnableToDeliverStrategy {
     return iox::into<UnableToDeliverStrategy>(
         iox2_config_defaults_publish_subscribe_unable_to_deliver_strategy(m_config));
@@ -440,6 +448,14 @@ auto Service::event_connection_suffix() && -> const char* {
 void Service::set_event_connection_suffix(const iox::FileName& value) && {
     iox2_config_global_service_set_event_connection_suffix(m_config, value.as_string().c_str());
 }
+
+auto Service::enable_discovery_announcements() && -> bool {
+    return iox2_config_global_service_enable_discovery_announcements(m_config);
+}
+
+void Service::set_enable_discovery_announcements(bool value) && {
+    iox2_config_global_service_set_enable_discovery_announcements(m_config, value);
+}
 /////////////////////////
 // END: Service
 /////////////////////////
@@ -632,6 +648,14 @@ auto RequestResponse::enable_fire_and_forget_requests() && -> bool {
 void RequestResponse::set_enable_fire_and_forget_requests(bool value) && {
     iox2_config_defaults_request_response_set_fire_and_forget_requests(m_config, value);
 }
+
+auto RequestResponse::enable_multi_response() && -> bool {
+    return iox2_config_defaults_request_response_has_multi_response(m_config);
+}
+
+void RequestResponse::set_enable_multi_response(bool value) && {
+    iox2_config_defaults_request_response_set_multi_response(m_config, value);
+}
 /////////////////////////
 // END: RequestResponse
 /////////////////////////
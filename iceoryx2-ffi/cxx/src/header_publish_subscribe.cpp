@@ -53,4 +53,24 @@ auto HeaderPublishSubscribe::publisher_id() const -> UniquePublisherId {
 auto HeaderPublishSubscribe::number_of_elements() const -> uint64_t {
     return iox2_publish_subscribe_header_number_of_elements(&m_handle);
 }
+
+auto HeaderPublishSubscribe::send_timestamp_monotonic() const -> iox::optional<iox::units::Duration> {
+    uint64_t monotonic_ns = 0;
+    uint64_t system_time_ns = 0;
+    if (iox2_publish_subscribe_header_timestamp(&m_handle, &monotonic_ns, &system_time_ns)) {
+        return iox::units::Duration::fromNanoseconds(monotonic_ns);
+    }
+
+    return iox::nullopt;
+}
+
+auto HeaderPublishSubscribe::send_timestamp_system_time() const -> iox::optional<iox::units::Duration> {
+    uint64_t monotonic_ns = 0;
+    uint64_t system_time_ns = 0;
+    if (iox2_publish_subscribe_header_timestamp(&m_handle, &monotonic_ns, &system_time_ns)) {
+        return iox::units::Duration::fromNanoseconds(system_time_ns);
+    }
+
+    return iox::nullopt;
+}
 } // namespace iox2
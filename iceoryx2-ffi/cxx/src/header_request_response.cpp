@@ -74,6 +74,19 @@ auto ResponseHeader::server_port_id() -> UniqueServerId {
     return UniqueServerId { id_handle };
 }
 
+auto ResponseHeader::is_error() -> bool {
+    return iox2_response_header_is_error(&m_handle);
+}
+
+auto ResponseHeader::error_code() -> iox::optional<uint64_t> {
+    uint64_t value = 0;
+    if (iox2_response_header_error_code(&m_handle, &value)) {
+        return { value };
+    }
+
+    return iox::nullopt;
+}
+
 ResponseHeader::ResponseHeader(iox2_response_header_h handle)
     : m_handle(handle) {
 }
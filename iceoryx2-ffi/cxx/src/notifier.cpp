@@ -47,6 +47,13 @@ auto Notifier<S>::id() const -> UniqueNotifierId {
     return UniqueNotifierId { id_handle };
 }
 
+template <ServiceType S>
+auto Notifier<S>::default_event_id() const -> EventId {
+    iox2_event_id_t event_id {};
+    iox2_notifier_default_event_id(&m_handle, &event_id);
+    return EventId { event_id };
+}
+
 template <ServiceType S>
 auto Notifier<S>::notify() const -> iox::expected<size_t, NotifierNotifyError> {
     size_t number_of_notified_listeners = 0;
@@ -45,6 +45,10 @@ auto StaticConfigPublishSubscribe::has_safe_overflow() const -> bool {
     return m_value.enable_safe_overflow;
 }
 
+auto StaticConfigPublishSubscribe::has_send_timestamp() const -> bool {
+    return m_value.enable_send_timestamp;
+}
+
 auto StaticConfigPublishSubscribe::message_type_details() const -> MessageTypeDetails {
     return MessageTypeDetails(m_value.message_type_details);
 }
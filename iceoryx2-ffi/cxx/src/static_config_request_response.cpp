@@ -49,6 +49,10 @@ auto StaticConfigRequestResponse::does_support_fire_and_forget_requests() const
     return m_value.enable_fire_and_forget_requests;
 }
 
+auto StaticConfigRequestResponse::does_support_multi_response() const -> bool {
+    return m_value.enable_multi_response;
+}
+
 auto StaticConfigRequestResponse::max_servers() const -> uint64_t {
     return m_value.max_servers;
 }
@@ -163,6 +163,15 @@ TEST(Config, defaults_publish_subscribe_enable_safe_overflow) {
     ASSERT_THAT(config.defaults().publish_subscribe().enable_safe_overflow(), Eq(false));
 }
 
+TEST(Config, defaults_publish_subscribe_enable_send_timestamp) {
+    auto config = Config();
+
+    config.defaults().publish_subscribe().set_enable_send_timestamp(true);
+    ASSERT_THAT(config.defaults().publish_subscribe().enable_send_timestamp(), Eq(true));
+    config.defaults().publish_subscribe().set_enable_send_timestamp(false);
+    ASSERT_THAT(config.defaults().publish_subscribe().enable_send_timestamp(), Eq(false));
+}
+
 TEST(Config, defaults_publish_subscribe_unable_to_deliver_strategy) {
     auto config = Config();
 
@@ -237,6 +246,15 @@ TEST(Config, global_service_event_connection_suffix) {
     ASSERT_THAT(config.global().service().event_connection_suffix(), StrEq(test_value.as_string().c_str()));
 }
 
+TEST(Config, global_service_enable_discovery_announcements) {
+    auto config = Config();
+
+    config.global().service().set_enable_discovery_announcements(true);
+    ASSERT_THAT(config.global().service().enable_discovery_announcements(), Eq(true));
+    config.global().service().set_enable_discovery_announcements(false);
+    ASSERT_THAT(config.global().service().enable_discovery_announcements(), Eq(false));
+}
+
 TEST(Config, global_node_directory) {
     const auto test_value = iox::Path::create("eat/the/carrototier").expect("");
     auto config = Config();
@@ -399,4 +417,13 @@ TEST(Config, defaults_request_response_enable_fire_and_forget_requests) {
     config.defaults().request_response().set_enable_fire_and_forget_requests(false);
     ASSERT_THAT(config.defaults().request_response().enable_fire_and_forget_requests(), Eq(false));
 }
+
+TEST(Config, defaults_request_response_enable_multi_response) {
+    auto config = Config();
+
+    config.defaults().request_response().set_enable_multi_response(true);
+    ASSERT_THAT(config.defaults().request_response().enable_multi_response(), Eq(true));
+    config.defaults().request_response().set_enable_multi_response(false);
+    ASSERT_THAT(config.defaults().request_response().enable_multi_response(), Eq(false));
+}
 } // namespace
@@ -34,6 +34,7 @@ TEST(EnumConversionTest, config_creation_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientPermissions)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConfigFileDoesNotExist)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToOpenConfigFile)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InvalidEnvironmentVariableValue)), 1U);
 }
 
 TEST(EnumConversionTest, connection_failure_into_c_str) {
@@ -107,6 +108,7 @@ TEST(EnumConversionTest, publisher_send_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorExceedsMaxLoanSize)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::LoanErrorInternalFailure)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ConnectionError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::MultiResponseDisabled)), 1U);
 }
 
 TEST(EnumConversionTest, event_open_into_c_str) {
@@ -244,6 +246,7 @@ TEST(EnumConversionTest, subscriber_receive_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxBorrows)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::FailedToEstablishConnection)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::UnableToMapSendersDataSegment)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::ExceedsMaxSliceLen)), 1U);
 }
 
 TEST(EnumConversionTest, subscriber_create_into_c_str) {
@@ -48,6 +48,9 @@ pub enum iox2_config_creation_error_e {
     UNABLE_TO_OPEN_CONFIG_FILE,
     /// The provided string is not a valid file path
     INVALID_FILE_PATH,
+    /// The value of an environment variable that overrides a config setting could not be
+    /// converted into the type of the setting.
+    INVALID_ENVIRONMENT_VARIABLE_VALUE,
 }
 
 impl IntoCInt for ConfigCreationError {
@@ -68,6 +71,9 @@ impl IntoCInt for ConfigCreationError {
             ConfigCreationError::UnableToOpenConfigFile => {
                 iox2_config_creation_error_e::UNABLE_TO_OPEN_CONFIG_FILE
             }
+            ConfigCreationError::InvalidEnvironmentVariableValue => {
+                iox2_config_creation_error_e::INVALID_ENVIRONMENT_VARIABLE_VALUE
+            }
         }) as c_int
     }
 }
@@ -86,7 +92,7 @@ pub(super) struct ConfigOwner {
 #[repr(C)]
 #[repr(align(8))] // align_of<ConfigOwner>()
 pub struct iox2_config_storage_t {
-    internal: [u8; 3736], // size_of<ConfigOwner>()
+    internal: [u8; 3888], // size_of<ConfigOwner>()
 }
 
 /// Contains the iceoryx2 config
@@ -1076,6 +1082,49 @@ pub unsafe extern "C" fn iox2_config_global_service_set_event_connection_suffix(
         Err(e) => e as c_int,
     }
 }
+
+/// Returns true when the creation and removal of services is announced to discovery services.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_enable_discovery_announcements(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .global
+        .service
+        .enable_discovery_announcements
+}
+
+/// Enable/disable the announcement of service creations and removals to discovery services
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_global_service_set_enable_discovery_announcements(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .global
+        .service
+        .enable_discovery_announcements = value;
+}
 /////////////////
 // END: service
 /////////////////
@@ -1436,6 +1485,50 @@ pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_enable_safe_
         .enable_safe_overflow = value;
 }
 
+/// Defines if the [`iox2_publisher_h`](crate::api::iox2_publisher_h) captures a timestamp in the
+/// header of every [`iox2_sample_h`](crate::api::iox2_sample_h) it sends.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_enable_send_timestamp(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .publish_subscribe
+        .enable_send_timestamp
+}
+
+/// Enables/disables the send timestamp
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_publish_subscribe_set_enable_send_timestamp(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .publish_subscribe
+        .enable_send_timestamp = value;
+}
+
 /// If safe overflow is deactivated it defines the deliver strategy of the
 /// [`iox2_publisher_h`](crate::api::iox2_publisher_h) when the
 /// [`iox2_subscriber_h`](crate::api::iox2_subscriber_h)s buffer is full.
@@ -1767,6 +1860,50 @@ pub unsafe extern "C" fn iox2_config_defaults_request_response_set_fire_and_forg
         .enable_fire_and_forget_requests = value;
 }
 
+/// Returns if the service supports multiple responses per request. Those are streams of
+/// responses a [`iox2_server_h`](crate::api::iox2_server_h) sends for a single request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_has_multi_response(
+    handle: iox2_config_h_ref,
+) -> bool {
+    handle.assert_non_null();
+
+    let config = &*handle.as_type();
+    config
+        .value
+        .as_ref()
+        .value
+        .defaults
+        .request_response
+        .enable_multi_response
+}
+
+/// Defines if request response services shall support multiple responses per request.
+///
+/// # Safety
+///
+/// * `handle` - A valid non-owning [`iox2_config_h_ref`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_config_defaults_request_response_set_multi_response(
+    handle: iox2_config_h_ref,
+    value: bool,
+) {
+    handle.assert_non_null();
+
+    let config = &mut *handle.as_type();
+    config
+        .value
+        .as_mut()
+        .value
+        .defaults
+        .request_response
+        .enable_multi_response = value;
+}
+
 /// Defines how the [`iox2_server_h`](crate::api::iox2_server_h) buffer behaves when it is
 /// full. When safe overflow is activated, the [`iox2_client_h`](crate::api::iox2_client_h) will
 /// replace the oldest [`iox2_request_mut_h`](crate::api::iox2_request_mut_h) with the newest one.
@@ -68,6 +68,7 @@ impl IntoCInt for NodeListFailure {
 pub enum iox2_node_wait_failure_e {
     INTERRUPT = IOX2_OK as isize + 1,
     TERMINATION_REQUEST,
+    SHUTDOWN_REQUEST,
 }
 
 impl IntoCInt for NodeWaitFailure {
@@ -75,6 +76,7 @@ impl IntoCInt for NodeWaitFailure {
         (match self {
             NodeWaitFailure::TerminationRequest => iox2_node_wait_failure_e::TERMINATION_REQUEST,
             NodeWaitFailure::Interrupt => iox2_node_wait_failure_e::INTERRUPT,
+            NodeWaitFailure::ShutdownRequest => iox2_node_wait_failure_e::SHUTDOWN_REQUEST,
         }) as c_int
     }
 }
@@ -128,7 +130,7 @@ impl NodeUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeUnion>
 pub struct iox2_node_storage_t {
-    internal: [u8; 16], // magic number obtained with size_of::<Option<NodeUnion>>()
+    internal: [u8; 32], // magic number obtained with size_of::<Option<NodeUnion>>()
 }
 
 #[repr(C)]
@@ -34,6 +34,7 @@ pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
     MISSED_DEADLINE,
     UNABLE_TO_ACQUIRE_ELAPSED_TIME,
+    LISTENER_BUFFER_OVERFLOW,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -46,6 +47,9 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::UnableToAcquireElapsedTime => {
                 iox2_notifier_notify_error_e::UNABLE_TO_ACQUIRE_ELAPSED_TIME
             }
+            NotifierNotifyError::ListenerBufferOverflow => {
+                iox2_notifier_notify_error_e::LISTENER_BUFFER_OVERFLOW
+            }
         }) as c_int
     }
 }
@@ -228,6 +232,31 @@ pub unsafe extern "C" fn iox2_notifier_deadline(
         .is_some()
 }
 
+/// Stores the event id that is used by [`iox2_notifier_notify()`] in `event_id_ptr`.
+/// If no default event id was set on creation it is 0.
+///
+/// # Safety
+///
+/// * `notifier_handle` is valid, non-null and was obtained via [`iox2_port_factory_notifier_builder_create`](crate::iox2_port_factory_notifier_builder_create)
+/// * `event_id_ptr` is pointing to a valid memory location and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_default_event_id(
+    notifier_handle: iox2_notifier_h_ref,
+    event_id_ptr: *mut iox2_event_id_t,
+) {
+    notifier_handle.assert_non_null();
+    debug_assert!(!event_id_ptr.is_null());
+
+    let notifier = &mut *notifier_handle.as_type();
+
+    let event_id = match notifier.service_type {
+        iox2_service_type_e::IPC => notifier.value.as_mut().ipc.default_event_id(),
+        iox2_service_type_e::LOCAL => notifier.value.as_mut().local.default_event_id(),
+    };
+
+    *event_id_ptr = event_id.into();
+}
+
 /// Notifies all [`iox2_listener_h`](crate::iox2_listener_h) connected to the service
 /// with the default event id provided on creation.
 ///
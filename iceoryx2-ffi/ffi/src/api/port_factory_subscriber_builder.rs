@@ -35,6 +35,7 @@ pub enum iox2_subscriber_create_error_e {
     EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS = IOX2_OK as isize + 1,
     BUFFER_SIZE_EXCEEDS_MAX_SUPPORTED_BUFFER_SIZE_OF_SERVICE,
     FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY,
+    UNABLE_TO_CREATE_DELIVERY_LISTENER,
 }
 
 impl IntoCInt for SubscriberCreateError {
@@ -49,6 +50,9 @@ impl IntoCInt for SubscriberCreateError {
             SubscriberCreateError::FailedToDeployThreadsafetyPolicy => {
                 iox2_subscriber_create_error_e::FAILED_TO_DEPLOY_THREAD_SAFETY_POLICY
             }
+            SubscriberCreateError::UnableToCreateDeliveryListener => {
+                iox2_subscriber_create_error_e::UNABLE_TO_CREATE_DELIVERY_LISTENER
+            }
         }) as c_int
     }
 }
@@ -85,7 +89,7 @@ impl PortFactorySubscriberBuilderUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<PortFactorySubscriberBuilderUnion>
 pub struct iox2_port_factory_subscriber_builder_storage_t {
-    internal: [u8; 112], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
+    internal: [u8; 128], // magic number obtained with size_of::<Option<PortFactorySubscriberBuilderUnion>>()
 }
 
 #[repr(C)]
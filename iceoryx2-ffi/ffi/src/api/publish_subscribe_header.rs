@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 80], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -179,4 +179,35 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_number_of_elements(
 
     header.value.as_ref().number_of_elements()
 }
+
+/// Returns true and stores the send timestamp in `monotonic_ns` and `system_time_ns` when the
+/// service captures send timestamps, otherwise it returns false and the arguments are not
+/// modified. The monotonic timestamp can only be compared with samples sent on the same host.
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `monotonic_ns` is valid and non-null
+/// * `system_time_ns` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_timestamp(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    monotonic_ns: *mut u64,
+    system_time_ns: *mut u64,
+) -> bool {
+    header_handle.assert_non_null();
+    debug_assert!(!monotonic_ns.is_null());
+    debug_assert!(!system_time_ns.is_null());
+
+    let header = &mut *header_handle.as_type();
+
+    match header.value.as_ref().timestamp() {
+        Some(timestamp) => {
+            *monotonic_ns = timestamp.monotonic().as_nanos() as u64;
+            *system_time_ns = timestamp.system_time().as_nanos() as u64;
+            true
+        }
+        None => false,
+    }
+}
 // END C API
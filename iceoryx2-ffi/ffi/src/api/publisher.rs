@@ -45,6 +45,8 @@ pub enum iox2_send_error_e {
     LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE,
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
+    LOAN_ERROR_PAYLOAD_TOO_LARGE,
+    MULTI_RESPONSE_DISABLED,
 }
 
 impl IntoCInt for SendError {
@@ -63,10 +65,14 @@ impl IntoCInt for SendError {
             SendError::LoanError(LoanError::ExceedsMaxLoanSize) => {
                 iox2_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
             }
+            SendError::LoanError(LoanError::PayloadTooLarge) => {
+                iox2_send_error_e::LOAN_ERROR_PAYLOAD_TOO_LARGE
+            }
             SendError::LoanError(LoanError::InternalFailure) => {
                 iox2_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             SendError::ConnectionError(_) => iox2_send_error_e::CONNECTION_ERROR,
+            SendError::MultiResponseDisabled => iox2_send_error_e::MULTI_RESPONSE_DISABLED,
         }) as c_int
     }
 }
@@ -77,6 +83,7 @@ impl IntoCInt for LoanError {
             LoanError::OutOfMemory => iox2_loan_error_e::OUT_OF_MEMORY,
             LoanError::ExceedsMaxLoans => iox2_loan_error_e::EXCEEDS_MAX_LOANED_SAMPLES,
             LoanError::ExceedsMaxLoanSize => iox2_loan_error_e::EXCEEDS_MAX_LOAN_SIZE,
+            LoanError::PayloadTooLarge => iox2_loan_error_e::PAYLOAD_TOO_LARGE,
             LoanError::InternalFailure => iox2_loan_error_e::INTERNAL_FAILURE,
         }) as c_int
     }
@@ -89,6 +96,7 @@ pub enum iox2_loan_error_e {
     EXCEEDS_MAX_LOANED_SAMPLES,
     EXCEEDS_MAX_LOAN_SIZE,
     INTERNAL_FAILURE,
+    PAYLOAD_TOO_LARGE,
 }
 
 pub(super) union PublisherUnion {
@@ -45,6 +45,9 @@ pub enum iox2_request_send_error_e {
     LOAN_ERROR_INTERNAL_FAILURE,
     CONNECTION_ERROR,
     EXCEEDS_MAX_ACTIVE_REQUESTS,
+    LOAN_ERROR_PAYLOAD_TOO_LARGE,
+    BACK_PRESSURE,
+    MULTI_RESPONSE_DISABLED,
 }
 
 impl IntoCInt for RequestSendError {
@@ -65,15 +68,22 @@ impl IntoCInt for RequestSendError {
             RequestSendError::SendError(SendError::LoanError(LoanError::ExceedsMaxLoanSize)) => {
                 iox2_request_send_error_e::LOAN_ERROR_EXCEEDS_MAX_LOAN_SIZE
             }
+            RequestSendError::SendError(SendError::LoanError(LoanError::PayloadTooLarge)) => {
+                iox2_request_send_error_e::LOAN_ERROR_PAYLOAD_TOO_LARGE
+            }
             RequestSendError::SendError(SendError::LoanError(LoanError::InternalFailure)) => {
                 iox2_request_send_error_e::LOAN_ERROR_INTERNAL_FAILURE
             }
             RequestSendError::SendError(SendError::ConnectionError(_)) => {
                 iox2_request_send_error_e::CONNECTION_ERROR
             }
+            RequestSendError::SendError(SendError::MultiResponseDisabled) => {
+                iox2_request_send_error_e::MULTI_RESPONSE_DISABLED
+            }
             RequestSendError::ExceedsMaxActiveRequests => {
                 iox2_request_send_error_e::EXCEEDS_MAX_ACTIVE_REQUESTS
             }
+            RequestSendError::BackPressure => iox2_request_send_error_e::BACK_PRESSURE,
         }) as c_int
     }
 }
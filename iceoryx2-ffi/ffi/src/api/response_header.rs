@@ -26,7 +26,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<ResponseHeader>>()
 pub struct iox2_response_header_storage_t {
-    internal: [u8; 48], // core::mem::size_of::<Option<ResponseHeader>>()
+    internal: [u8; 56], // core::mem::size_of::<Option<ResponseHeader>>()
 }
 
 #[repr(C)]
@@ -176,4 +176,57 @@ pub unsafe extern "C" fn iox2_response_header_number_of_elements(
 
     header.value.as_ref().number_of_elements()
 }
+
+/// Returns `true` when the server rejected the request and the response is an error response.
+///
+/// # Arguments
+///
+/// * `header_handle` is valid, non-null and was initialized with
+///   [`iox2_response_header()`](crate::iox2_response_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_header_is_error(
+    header_handle: iox2_response_header_h_ref,
+) -> bool {
+    header_handle.assert_non_null();
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().is_error()
+}
+
+/// Returns the error code the server rejected the request with. It returns `true` if the
+/// response is an error response and sets the provided `value`, otherwise it returns `false`.
+///
+/// # Arguments
+///
+/// * `header_handle` is valid, non-null and was initialized with
+///   [`iox2_response_header()`](crate::iox2_response_header)
+/// * `value` - points to a valid memory location
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `value` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_response_header_error_code(
+    header_handle: iox2_response_header_h_ref,
+    value: *mut u64,
+) -> bool {
+    header_handle.assert_non_null();
+    debug_assert!(!value.is_null());
+
+    let header = &mut *header_handle.as_type();
+
+    match header.value.as_ref().error_code() {
+        Some(v) => {
+            *value = v;
+            true
+        }
+        None => false,
+    }
+}
 // END C API
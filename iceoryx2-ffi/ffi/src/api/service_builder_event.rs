@@ -87,6 +87,8 @@ pub enum iox2_event_open_or_create_error_e {
     C_OLD_CONNECTION_STILL_ACTIVE,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
+    #[CStr = "timeout"]
+    TIMEOUT,
 }
 
 impl IntoCInt for EventOpenError {
@@ -174,7 +176,10 @@ impl IntoCInt for EventOpenOrCreateError {
         match self {
             EventOpenOrCreateError::EventOpenError(error) => error.into_c_int(),
             EventOpenOrCreateError::EventCreateError(error) => error.into_c_int(),
-            e => e.into_c_int(),
+            EventOpenOrCreateError::SystemInFlux => {
+                iox2_event_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
+            }
+            EventOpenOrCreateError::Timeout => iox2_event_open_or_create_error_e::TIMEOUT as c_int,
         }
     }
 }
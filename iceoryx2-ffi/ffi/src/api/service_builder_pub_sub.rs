@@ -26,7 +26,7 @@ use iceoryx2::service::builder::publish_subscribe::{
     PublishSubscribeOpenOrCreateError,
 };
 use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
-use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::message_type_details::{TypeCompatibility, TypeVariant};
 use iceoryx2_bb_elementary_traits::AsCStr;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_ffi_macros::CStrRepr;
@@ -65,6 +65,14 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_NODES,
     #[CStr = "incompatible overflow behavior"]
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR,
+    #[CStr = "incompatible send timestamp behavior"]
+    O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR,
+    #[CStr = "incompatible sample lifespan"]
+    O_INCOMPATIBLE_SAMPLE_LIFESPAN,
+    #[CStr = "incompatible ownership behavior"]
+    O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR,
+    #[CStr = "incompatible notify on delivery behavior"]
+    O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "service in corrupted state"]
@@ -93,6 +101,8 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     C_HANGS_IN_CREATION,
     #[CStr = "same service is created and removed repeatedly"]
     SYSTEM_IN_FLUX,
+    #[CStr = "timeout"]
+    TIMEOUT,
 }
 
 impl IntoCInt for PublishSubscribeOpenError {
@@ -132,6 +142,18 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IncompatibleOverflowBehavior => {
              iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR
          }
+         PublishSubscribeOpenError::IncompatibleSendTimestampBehavior => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SEND_TIMESTAMP_BEHAVIOR
+         }
+         PublishSubscribeOpenError::IncompatibleSampleLifespan => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SAMPLE_LIFESPAN
+         }
+         PublishSubscribeOpenError::IncompatibleOwnershipBehavior => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_OWNERSHIP_BEHAVIOR
+         }
+         PublishSubscribeOpenError::IncompatibleNotifyOnDeliveryBehavior => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR
+         }
          PublishSubscribeOpenError::InsufficientPermissions => {
              iox2_pub_sub_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS
          }
@@ -186,7 +208,12 @@ impl IntoCInt for PublishSubscribeOpenOrCreateError {
             PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(error) => {
                 error.into_c_int()
             }
-            e => e.into_c_int(),
+            PublishSubscribeOpenOrCreateError::SystemInFlux => {
+                iox2_pub_sub_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
+            }
+            PublishSubscribeOpenOrCreateError::Timeout => {
+                iox2_pub_sub_open_or_create_error_e::TIMEOUT as c_int
+            }
         }
     }
 }
@@ -220,6 +247,35 @@ impl From<iox2_type_variant_e> for TypeVariant {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum iox2_type_compatibility_e {
+    STRICT,
+    LAYOUT_ONLY,
+}
+
+impl From<&TypeCompatibility> for iox2_type_compatibility_e {
+    fn from(value: &TypeCompatibility) -> Self {
+        match value {
+            TypeCompatibility::Strict => iox2_type_compatibility_e::STRICT,
+            TypeCompatibility::LayoutOnly => iox2_type_compatibility_e::LAYOUT_ONLY,
+        }
+    }
+}
+
+impl From<iox2_type_compatibility_e> for TypeCompatibility {
+    fn from(value: iox2_type_compatibility_e) -> Self {
+        const STRICT: usize = iox2_type_compatibility_e::STRICT as usize;
+        const LAYOUT_ONLY: usize = iox2_type_compatibility_e::LAYOUT_ONLY as usize;
+
+        match value as usize {
+            STRICT => TypeCompatibility::Strict,
+            LAYOUT_ONLY => TypeCompatibility::LayoutOnly,
+            e => fatal_panic!("Invalid iox2_type_compatibility_e value {}", e),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub enum iox2_type_detail_error_e {
@@ -716,6 +772,90 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_enable_safe_overflow(
     }
 }
 
+/// Defines how the payload and user header types are matched when the service is opened
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_pub_sub_h_ref`]
+///   obtained by [`iox2_service_builder_pub_sub`](crate::iox2_service_builder_pub_sub).
+/// * `value` - the [`iox2_type_compatibility_e`] that shall be used
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_type_compatibility(
+    service_builder_handle: iox2_service_builder_pub_sub_h_ref,
+    value: iox2_type_compatibility_e,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_pub_sub(
+                service_builder.type_compatibility(value.into()),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_pub_sub(
+                service_builder.type_compatibility(value.into()),
+            ));
+        }
+    }
+}
+
+/// Enables/disables the send timestamp in the sample header for the service
+///
+/// # Arguments
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_pub_sub_h_ref`]
+///   obtained by [`iox2_service_builder_pub_sub`](crate::iox2_service_builder_pub_sub).
+/// * `value` - defines if the send timestamp shall be captured (true) or not (false)
+///
+/// # Safety
+///
+/// * `service_builder_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_enable_send_timestamp(
+    service_builder_handle: iox2_service_builder_pub_sub_h_ref,
+    value: bool,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_pub_sub(
+                service_builder.enable_send_timestamp(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.pub_sub);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_pub_sub(
+                service_builder.enable_send_timestamp(value),
+            ));
+        }
+    }
+}
+
 /// Opens a publish-subscribe service or creates the service if it does not exist and returns a port factory to create publishers and subscribers.
 ///
 /// # Arguments
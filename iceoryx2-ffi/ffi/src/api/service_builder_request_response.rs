@@ -44,7 +44,7 @@ use super::{
     c_size_t, iox2_attribute_specifier_h_ref, iox2_attribute_verifier_h_ref,
     iox2_port_factory_request_response_h, iox2_port_factory_request_response_t,
     iox2_service_builder_request_response_h, iox2_service_builder_request_response_h_ref,
-    iox2_type_variant_e, IntoCInt, PayloadFfi, UserHeaderFfi,
+    iox2_type_compatibility_e, iox2_type_variant_e, IntoCInt, PayloadFfi, UserHeaderFfi,
 };
 
 // BEGIN types definition
@@ -57,6 +57,8 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENT_REQUEST_LOANS,
     #[CStr = "does not support requested amount of active requests per client"]
     O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT,
+    #[CStr = "does not support requested request queue depth per client"]
+    O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT,
     #[CStr = "does not support requested response buffer size"]
     O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE,
     #[CStr = "does not support requested amount of servers"]
@@ -85,6 +87,14 @@ pub enum iox2_request_response_open_or_create_error_e {
     O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
     #[CStr = "incompatible behavior for fire and forget requests"]
     O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+    #[CStr = "incompatible behavior for multi response"]
+    O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE,
+    #[CStr = "incompatible notify on delivery behavior"]
+    O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR,
+    #[CStr = "incompatible response deadline"]
+    O_INCOMPATIBLE_RESPONSE_DEADLINE,
+    #[CStr = "incompatible credit flow control"]
+    O_INCOMPATIBLE_CREDIT_FLOW_CONTROL,
     #[CStr = "insufficient permissions"]
     O_INSUFFICIENT_PERMISSIONS,
     #[CStr = "internal failure"]
@@ -107,6 +117,10 @@ pub enum iox2_request_response_open_or_create_error_e {
     C_SERVICE_IN_CORRUPTED_STATE,
     #[CStr = "system in flux"]
     SYSTEM_IN_FLUX,
+    #[CStr = "configuration mismatch"]
+    CONFIGURATION_MISMATCH,
+    #[CStr = "timeout"]
+    TIMEOUT,
 }
 
 impl IntoCInt for RequestResponseOpenError {
@@ -115,6 +129,7 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::DoesNotExist => iox2_request_response_open_or_create_error_e::O_DOES_NOT_EXIST,
             RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENT_REQUEST_LOANS,
             RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_ACTIVE_REQUESTS_PER_CLIENT,
+            RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_REQUEST_QUEUE_DEPTH_PER_CLIENT,
             RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_RESPONSE_BUFFER_SIZE,
             RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_SERVERS,
             RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients => iox2_request_response_open_or_create_error_e::O_DOES_NOT_SUPPORT_REQUESTED_AMOUNT_OF_CLIENTS,
@@ -129,6 +144,10 @@ impl IntoCInt for RequestResponseOpenError {
             RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_REQUESTS,
             RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_OVERFLOW_BEHAVIOR_FOR_RESPONSES,
             RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_BEHAVIOR_FOR_FIRE_AND_FORGET_REQUESTS,
+            RequestResponseOpenError::IncompatibleBehaviorForMultiResponse => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_BEHAVIOR_FOR_MULTI_RESPONSE,
+            RequestResponseOpenError::IncompatibleNotifyOnDeliveryBehavior => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_NOTIFY_ON_DELIVERY_BEHAVIOR,
+            RequestResponseOpenError::IncompatibleResponseDeadline => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_RESPONSE_DEADLINE,
+            RequestResponseOpenError::IncompatibleCreditFlowControl => iox2_request_response_open_or_create_error_e::O_INCOMPATIBLE_CREDIT_FLOW_CONTROL,
             RequestResponseOpenError::InsufficientPermissions => iox2_request_response_open_or_create_error_e::O_INSUFFICIENT_PERMISSIONS,
             RequestResponseOpenError::InternalFailure => iox2_request_response_open_or_create_error_e::O_INTERNAL_FAILURE,
             RequestResponseOpenError::IsMarkedForDestruction => iox2_request_response_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION,
@@ -170,6 +189,12 @@ impl IntoCInt for RequestResponseOpenOrCreateError {
             RequestResponseOpenOrCreateError::SystemInFlux => {
                 iox2_request_response_open_or_create_error_e::SYSTEM_IN_FLUX as c_int
             }
+            RequestResponseOpenOrCreateError::ConfigurationMismatch => {
+                iox2_request_response_open_or_create_error_e::CONFIGURATION_MISMATCH as c_int
+            }
+            RequestResponseOpenOrCreateError::Timeout => {
+                iox2_request_response_open_or_create_error_e::TIMEOUT as c_int
+            }
         }
     }
 }
@@ -517,6 +542,44 @@ pub unsafe extern "C" fn iox2_service_builder_request_response_enable_fire_and_f
     }
 }
 
+/// Enables/disables multiple responses per request
+///
+/// # Safety
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h_ref`]
+///   obtained by
+///   [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response).
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_enable_multi_response(
+    service_builder_handle: iox2_service_builder_request_response_h_ref,
+    value: bool,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.enable_multi_response(value),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.enable_multi_response(value),
+            ));
+        }
+    }
+}
+
 /// Enables/disables safe overflow for requests
 ///
 /// # Safety
@@ -783,6 +846,44 @@ pub unsafe extern "C" fn iox2_service_builder_request_response_set_max_nodes(
     }
 }
 
+/// Defines how the payload and user header types are matched when the service is opened
+///
+/// # Safety
+///
+/// * `service_builder_handle` - Must be a valid [`iox2_service_builder_request_response_h_ref`]
+///   obtained by
+///   [`iox2_service_builder_request_response`](crate::iox2_service_builder_request_response).
+#[no_mangle]
+pub unsafe extern "C" fn iox2_service_builder_request_response_set_type_compatibility(
+    service_builder_handle: iox2_service_builder_request_response_h_ref,
+    value: iox2_type_compatibility_e,
+) {
+    service_builder_handle.assert_non_null();
+
+    let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
+
+    match service_builder_struct.service_type {
+        iox2_service_type_e::IPC => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().ipc);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_ipc_request_response(
+                service_builder.type_compatibility(value.into()),
+            ));
+        }
+        iox2_service_type_e::LOCAL => {
+            let service_builder =
+                ManuallyDrop::take(&mut service_builder_struct.value.as_mut().local);
+
+            let service_builder = ManuallyDrop::into_inner(service_builder.request_response);
+            service_builder_struct.set(ServiceBuilderUnion::new_local_request_response(
+                service_builder.type_compatibility(value.into()),
+            ));
+        }
+    }
+}
+
 /// Sets the maximum buffer size for responses on the client side
 ///
 /// # Safety
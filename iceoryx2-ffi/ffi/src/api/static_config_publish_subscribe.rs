@@ -26,6 +26,7 @@ pub struct iox2_static_config_publish_subscribe_t {
     pub subscriber_max_buffer_size: usize,
     pub subscriber_max_borrowed_samples: usize,
     pub enable_safe_overflow: bool,
+    pub enable_send_timestamp: bool,
     pub message_type_details: iox2_message_type_details_t,
 }
 
@@ -39,6 +40,7 @@ impl From<&StaticConfig> for iox2_static_config_publish_subscribe_t {
             subscriber_max_buffer_size: c.subscriber_max_buffer_size(),
             subscriber_max_borrowed_samples: c.subscriber_max_borrowed_samples(),
             enable_safe_overflow: c.has_safe_overflow(),
+            enable_send_timestamp: c.has_send_timestamp(),
             message_type_details: c.message_type_details().into(),
         }
     }
@@ -22,6 +22,7 @@ pub struct iox2_static_config_request_response_t {
     pub enable_safe_overflow_for_requests: bool,
     pub enable_safe_overflow_for_responses: bool,
     pub enable_fire_and_forget_requests: bool,
+    pub enable_multi_response: bool,
     pub max_active_requests_per_client: usize,
     pub max_loaned_requests: usize,
     pub max_response_buffer_size: usize,
@@ -39,6 +40,7 @@ impl From<&StaticConfig> for iox2_static_config_request_response_t {
             enable_safe_overflow_for_requests: c.has_safe_overflow_for_requests(),
             enable_safe_overflow_for_responses: c.has_safe_overflow_for_responses(),
             enable_fire_and_forget_requests: c.does_support_fire_and_forget_requests(),
+            enable_multi_response: c.does_support_multi_response(),
             max_active_requests_per_client: c.max_active_requests_per_client(),
             max_loaned_requests: c.max_loaned_requests(),
             max_response_buffer_size: c.max_response_buffer_size(),
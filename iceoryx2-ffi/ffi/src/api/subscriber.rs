@@ -37,12 +37,14 @@ pub enum iox2_receive_error_e {
     EXCEEDS_MAX_BORROWS = IOX2_OK as isize + 1,
     FAILED_TO_ESTABLISH_CONNECTION,
     UNABLE_TO_MAP_SENDERS_DATA_SEGMENT,
+    EXCEEDS_MAX_SLICE_LEN,
 }
 
 impl IntoCInt for ReceiveError {
     fn into_c_int(self) -> c_int {
         (match self {
             ReceiveError::ExceedsMaxBorrows => iox2_receive_error_e::EXCEEDS_MAX_BORROWS,
+            ReceiveError::ExceedsMaxSliceLen => iox2_receive_error_e::EXCEEDS_MAX_SLICE_LEN,
             ReceiveError::ConnectionFailure(ConnectionFailure::FailedToEstablishConnection(_)) => {
                 iox2_receive_error_e::FAILED_TO_ESTABLISH_CONNECTION
             }
@@ -85,6 +85,7 @@ pub enum iox2_waitset_attachment_error_e {
     INSUFFICIENT_CAPACITY = IOX2_OK as isize + 1,
     ALREADY_ATTACHED,
     INTERNAL_ERROR,
+    UNSUPPORTED_ATTACHMENT,
 }
 
 impl IntoCInt for WaitSetAttachmentError {
@@ -99,6 +100,9 @@ impl IntoCInt for WaitSetAttachmentError {
             WaitSetAttachmentError::InternalError => {
                 iox2_waitset_attachment_error_e::INTERNAL_ERROR
             }
+            WaitSetAttachmentError::UnsupportedAttachment => {
+                iox2_waitset_attachment_error_e::UNSUPPORTED_ATTACHMENT
+            }
         }) as c_int
     }
 }
@@ -13,5 +13,6 @@
 """Python language bindings for iceoryx2."""
 
 from ._iceoryx2 import *
+from .payload_codec import PayloadCodec
 from .publish_subscribe_extensions import *
 from .slice import Slice
@@ -0,0 +1,73 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+"""PayloadCodec - Bridges a `ctypes.Structure` to the byte based payload API."""
+
+import ctypes
+from typing import Generic, Type, TypeVar, Union
+
+from ._iceoryx2 import TypeDetail, TypeName, TypeVariant
+from .type_name import get_type_name
+
+T = TypeVar("T", bound=ctypes.Structure)
+
+
+class PayloadCodec(Generic[T]):
+    """
+    Derives the `TypeDetail` of a `ctypes.Structure` and converts instances of
+    it into the bytes that are sent and received over a service.
+
+    T -  The `ctypes.Structure` subclass that describes the payload layout.
+    """
+
+    def __init__(self, t: Type[T]) -> None:
+        """Creates a codec for the `ctypes.Structure` subclass `t`."""
+        if not isinstance(t, type) or not issubclass(t, ctypes.Structure):
+            raise TypeError(f"{t!r} is not a ctypes.Structure subclass")
+        self.contained_type = t
+
+    def __str__(self) -> str:
+        """Returns human-readable string of the contents."""
+        return f"PayloadCodec {{ type: {self.contained_type.__name__} }}"
+
+    def type_detail(self) -> TypeDetail:
+        """Returns the `TypeDetail` that matches the layout of the structure."""
+        return (
+            TypeDetail.new()
+            .type_variant(TypeVariant.FixedSize)
+            .type_name(TypeName.new(get_type_name(self.contained_type)))
+            .size(ctypes.sizeof(self.contained_type))
+            .alignment(ctypes.alignment(self.contained_type))
+        )
+
+    def matches(self, type_detail: TypeDetail) -> bool:
+        """Returns `True` when `type_detail` describes the same layout."""
+        return self.type_detail() == type_detail
+
+    def pack(self, value: T) -> bytes:
+        """Converts `value` into the bytes of its in-memory representation."""
+        if not isinstance(value, self.contained_type):
+            raise TypeError(
+                f"expected {self.contained_type.__name__}, "
+                f"got {type(value).__name__}"
+            )
+        return bytes(value)
+
+    def unpack(self, data: Union[bytes, bytearray, memoryview]) -> T:
+        """Creates a copy of the structure from the provided bytes."""
+        expected_size = ctypes.sizeof(self.contained_type)
+        if len(data) != expected_size:
+            raise ValueError(
+                f"{self.contained_type.__name__} requires {expected_size} "
+                f"bytes but {len(data)} bytes were provided"
+            )
+        return self.contained_type.from_buffer_copy(data)
@@ -13,7 +13,16 @@
 """Strong type safe extensions for the publish-subscribe messaging pattern."""
 
 import ctypes
-from typing import Any, Type, TypeVar, get_args, get_origin
+import struct
+from typing import (
+    Any,
+    Callable,
+    Optional,
+    Type,
+    TypeVar,
+    get_args,
+    get_origin,
+)
 
 from ._iceoryx2 import *
 from .slice import Slice
@@ -95,8 +104,8 @@ def set_user_header(
     return result
 
 
-def send_copy(self: Publisher, t: Type[T]) -> Any:
-    """Sends a copy of the provided type."""
+def send_copy(self: Publisher, t: Type[T]) -> int:
+    """Sends a copy of the provided type and returns the number of `Subscriber`s that received it."""
     sample_uninit = self.loan_uninit()
 
     assert ctypes.sizeof(t) == ctypes.sizeof(
@@ -111,6 +120,27 @@ def send_copy(self: Publisher, t: Type[T]) -> Any:
     return sample.send()
 
 
+def send_with_timestamp(self: Publisher, t: Type[T], timestamp_ns: int) -> int:
+    """
+    Sends a copy of the provided type with the provided monotonic timestamp in nanoseconds.
+
+    The timestamp is stored in the header instead of the time of sending, for instance to
+    record when the data was captured. Returns the number of `Subscriber`s that received it.
+    """
+    sample_uninit = self.loan_uninit()
+
+    assert ctypes.sizeof(t) == ctypes.sizeof(
+        sample_uninit.__payload_type_details
+    )
+    assert ctypes.alignment(t) == ctypes.alignment(
+        sample_uninit.__payload_type_details
+    )
+
+    ctypes.memmove(sample_uninit.payload_ptr, ctypes.byref(t), ctypes.sizeof(t))
+    sample = sample_uninit.assume_init()
+    return sample.send_with_timestamp(timestamp_ns)
+
+
 def write_payload(self: SampleMutUninit, t: Type[T]) -> SampleMut:
     """Sends a copy of the provided type."""
     assert ctypes.sizeof(t) == ctypes.sizeof(self.__payload_type_details)
@@ -120,6 +150,85 @@ def write_payload(self: SampleMutUninit, t: Type[T]) -> SampleMut:
     return self.assume_init()
 
 
+def _payload_size(self: Any) -> int:
+    if get_origin(self.__payload_type_details) is Slice:
+        (contained_type,) = get_args(self.__payload_type_details)
+        return self.__slice_len * ctypes.sizeof(contained_type)
+
+    return ctypes.sizeof(self.__payload_type_details)
+
+
+def _verify_write(self: Any, offset: int, size: int, alignment: int) -> None:
+    payload_size = _payload_size(self)
+    if offset < 0 or offset + size > payload_size:
+        raise IndexError(
+            f"Writing {size} bytes at offset {offset} exceeds the payload "
+            f"size of {payload_size} bytes."
+        )
+
+    if (self.payload_ptr + offset) % alignment != 0:
+        raise ValueError(
+            f"The offset {offset} does not satisfy the required alignment "
+            f"of {alignment} bytes."
+        )
+
+
+_INT_FORMATS = {1: "b", 2: "h", 4: "i", 8: "q"}
+_FLOAT_FORMATS = {4: "f", 8: "d"}
+
+
+def write_bytes(self: Any, offset: int, data: bytes) -> None:
+    """
+    Copies `data` into the payload starting at byte `offset`.
+
+    Emits `IndexError` when `data` does not fit into the payload.
+    """
+    _verify_write(self, offset, len(data), 1)
+    ctypes.memmove(self.payload_ptr + offset, data, len(data))
+
+
+def write_int(
+    self: Any, offset: int, value: int, size: int, signed: bool = True
+) -> None:
+    """
+    Writes the integer `value` with `size` bytes (1, 2, 4 or 8) in native byte
+    order into the payload at byte `offset`.
+
+    Emits `IndexError` when the integer does not fit into the payload and
+    `ValueError` when the offset is not aligned to `size` or `value` cannot be
+    represented with `size` bytes.
+    """
+    if size not in _INT_FORMATS:
+        raise ValueError(f"Unsupported integer size of {size} bytes.")
+
+    int_format = _INT_FORMATS[size]
+    if not signed:
+        int_format = int_format.upper()
+
+    _verify_write(self, offset, size, size)
+    try:
+        data = struct.pack("=" + int_format, value)
+    except struct.error as e:
+        raise ValueError(f"Unable to write {value} with {size} bytes: {e}")
+    ctypes.memmove(self.payload_ptr + offset, data, size)
+
+
+def write_float(self: Any, offset: int, value: float, size: int = 8) -> None:
+    """
+    Writes the floating point `value` with `size` bytes (4 or 8) in native
+    byte order into the payload at byte `offset`.
+
+    Emits `IndexError` when the value does not fit into the payload and
+    `ValueError` when the offset is not aligned to `size`.
+    """
+    if size not in _FLOAT_FORMATS:
+        raise ValueError(f"Unsupported floating point size of {size} bytes.")
+
+    _verify_write(self, offset, size, size)
+    data = struct.pack("=" + _FLOAT_FORMATS[size], value)
+    ctypes.memmove(self.payload_ptr + offset, data, size)
+
+
 def loan_uninit(self: Publisher) -> SampleMutUninit:
     """
     Loans/allocates a `SampleMutUninit` from the underlying data segment of the `Publisher`.
@@ -147,6 +256,28 @@ def loan_slice_uninit(
     return self.__loan_slice_uninit(number_of_elements)
 
 
+def loan_slice(self: Publisher, number_of_elements: int) -> SampleMut:
+    """
+    Loans/allocates a `SampleMut` from the underlying data segment of the `Publisher`.
+
+    The payload is zero-initialized. A `number_of_elements` of 0 returns a valid
+    `SampleMut` with an empty payload that can be sent like any other sample.
+    Fails when it is called for data types which are not a slice.
+    On failure it returns `LoanError` describing the failure.
+    """
+    assert get_origin(self.__payload_type_details) is Slice
+    (contained_type,) = get_args(self.__payload_type_details)
+
+    sample_uninit = self.__loan_slice_uninit(number_of_elements)
+    if number_of_elements > 0:
+        ctypes.memset(
+            sample_uninit.payload_ptr,
+            0,
+            number_of_elements * ctypes.sizeof(contained_type),
+        )
+    return sample_uninit.assume_init()
+
+
 def initial_max_slice_len(
     self: PortFactoryPublisher, value: int
 ) -> PortFactoryPublisher:
@@ -170,22 +301,62 @@ def allocation_strategy(
     return self.__allocation_strategy(value)
 
 
+def for_each(
+    self: Subscriber,
+    callback: Callable[[Sample], None],
+    max_iterations: Optional[int] = None,
+) -> int:
+    """
+    Receives the available `Sample`s one at a time and passes each to the callback.
+
+    Every `Sample` is released after the callback returns and before the next one is
+    received, so that the `Publisher` never stalls because of samples held by the
+    `Subscriber`. Stops when no more samples are available or after `max_iterations`
+    samples and returns the number of processed samples. On failure it emits
+    `ReceiveError`.
+    """
+    number_of_processed_samples = 0
+    while max_iterations is None or number_of_processed_samples < max_iterations:
+        sample = self.receive()
+        if sample is None:
+            break
+
+        try:
+            callback(sample)
+        finally:
+            sample.delete()
+
+        number_of_processed_samples += 1
+
+    return number_of_processed_samples
+
+
 PortFactoryPublisher.initial_max_slice_len = initial_max_slice_len
 PortFactoryPublisher.allocation_strategy = allocation_strategy
 
 Publisher.send_copy = send_copy
+Publisher.send_with_timestamp = send_with_timestamp
 Publisher.loan_uninit = loan_uninit
 Publisher.loan_slice_uninit = loan_slice_uninit
+Publisher.loan_slice = loan_slice
+
+Subscriber.for_each = for_each
 
 Sample.payload = payload
 Sample.user_header = user_header
 
 SampleMut.payload = payload
 SampleMut.user_header = user_header
+SampleMut.write_bytes = write_bytes
+SampleMut.write_int = write_int
+SampleMut.write_float = write_float
 
 SampleMutUninit.write_payload = write_payload
 SampleMutUninit.payload = payload
 SampleMutUninit.user_header = user_header
+SampleMutUninit.write_bytes = write_bytes
+SampleMutUninit.write_int = write_int
+SampleMutUninit.write_float = write_float
 
 ServiceBuilder.publish_subscribe = publish_subscribe
 ServiceBuilderPublishSubscribe.user_header = set_user_header
@@ -60,6 +60,20 @@ class Slice(Generic[T]):
         )
         typed_ptr[index] = value
 
+    def __len__(self) -> int:
+        """Returns the length / number of elements contained in the `Slice`."""
+        return self.number_of_elements
+
+    def __bytes__(self) -> bytes:
+        """Returns a copy of the contents or empty bytes for an empty `Slice`."""
+        if self.number_of_elements == 0:
+            return b""
+
+        return ctypes.string_at(
+            self.data_ptr,
+            self.number_of_elements * ctypes.sizeof(self.contained_type),
+        )
+
     def len(self) -> int:
         """Returns the length / number of elements contained in the `Slice`."""
         return self.number_of_elements
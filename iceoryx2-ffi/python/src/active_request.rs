@@ -0,0 +1,262 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::{
+    builder::{CustomHeaderMarker, CustomPayloadMarker},
+    static_config::message_type_details::TypeDetail,
+};
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+
+use crate::{
+    error::{LoanError, SendError},
+    parc::Parc,
+    response::{payload_as_bytes, user_header_as_bytes},
+    type_detail::number_of_payload_elements,
+    unique_client_id::UniqueClientId,
+};
+
+type IpcActiveRequest = iceoryx2::active_request::ActiveRequest<
+    crate::IpcService,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+type LocalActiveRequest = iceoryx2::active_request::ActiveRequest<
+    crate::LocalService,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+
+pub(crate) enum ActiveRequestType {
+    Ipc(Option<IpcActiveRequest>),
+    Local(Option<LocalActiveRequest>),
+}
+
+#[pyclass]
+/// Represents a one-to-one connection to a `Client` holding the corresponding
+/// `PendingResponse` that is coupled with the `RequestMut` the `Client` sent to the `Server`.
+/// The `Server` uses it to send one or, when the `Service` supports multiple responses, a
+/// stream of `Response`s.
+pub struct ActiveRequest {
+    pub(crate) value: Parc<ActiveRequestType>,
+    pub(crate) response_payload_type_details: TypeDetail,
+    pub(crate) request_header_size: usize,
+    pub(crate) response_header_size: usize,
+}
+
+#[pymethods]
+impl ActiveRequest {
+    #[getter]
+    /// Returns `True` until the `PendingResponse` on the `Client`s side is released and the
+    /// `Client` no longer receives `Response`s. Returns `False` when the `Service` does not
+    /// support multiple responses and the `Response` was already sent.
+    pub fn is_connected(&self) -> bool {
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => v.is_connected(),
+            ActiveRequestType::Local(Some(v)) => v.is_connected(),
+            _ => fatal_panic!(from "ActiveRequest::is_connected()",
+                "Accessing a released active request."),
+        }
+    }
+
+    #[getter]
+    /// Returns the `UniqueClientId` of the `Client` that sent the request.
+    pub fn origin(&self) -> UniqueClientId {
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => UniqueClientId(v.origin()),
+            ActiveRequestType::Local(Some(v)) => UniqueClientId(v.origin()),
+            _ => fatal_panic!(from "ActiveRequest::origin()",
+                "Accessing a released active request."),
+        }
+    }
+
+    #[getter]
+    /// Returns the time of the monotonic clock in nanoseconds when the `Client` sent the
+    /// request.
+    pub fn timestamp_ns(&self) -> u64 {
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => v.header().timestamp_ns(),
+            ActiveRequestType::Local(Some(v)) => v.header().timestamp_ns(),
+            _ => fatal_panic!(from "ActiveRequest::timestamp_ns()",
+                "Accessing a released active request."),
+        }
+    }
+
+    /// Returns a copy of the request payload as `bytes`.
+    pub fn payload<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            ActiveRequestType::Local(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            _ => fatal_panic!(from "ActiveRequest::payload()",
+                "Accessing a released active request."),
+        }
+    }
+
+    /// Returns a copy of the user header of the request as `bytes`. Its size is defined by the
+    /// request header `TypeDetail` of the `Service`.
+    pub fn user_header<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => PyBytes::new(
+                py,
+                user_header_as_bytes(v.user_header(), self.request_header_size),
+            ),
+            ActiveRequestType::Local(Some(v)) => PyBytes::new(
+                py,
+                user_header_as_bytes(v.user_header(), self.request_header_size),
+            ),
+            _ => fatal_panic!(from "ActiveRequest::user_header()",
+                "Accessing a released active request."),
+        }
+    }
+
+    /// Sends a copy of the provided `bytes` as `Response` to the `PendingResponse` of the
+    /// corresponding `Client`. The `bytes` must match the response payload type of the
+    /// `Service`, otherwise `ValueError` is emitted. The optional `user_header` must have the
+    /// size of the response header type, otherwise `ValueError` is emitted. If it is not
+    /// provided, the user header is filled with zeros.
+    ///
+    /// When the `Service` supports multiple responses, it can be called repeatedly to send a
+    /// stream of `Response`s. Otherwise the stream ends with the first `Response` and any
+    /// further call emits `SendError` with `SendError::MultiResponseDisabled`.
+    #[pyo3(signature = (payload, user_header = None))]
+    pub fn send_copy(&self, payload: &[u8], user_header: Option<&[u8]>) -> PyResult<()> {
+        let number_of_elements =
+            number_of_payload_elements(&self.response_payload_type_details, payload.len())?;
+        verify_user_header_size(self.response_header_size, user_header)?;
+
+        match &*self.value.lock() {
+            ActiveRequestType::Ipc(Some(v)) => {
+                let mut response = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    response.user_header_mut(),
+                    self.response_header_size,
+                    user_header,
+                );
+                copy_payload(response.payload_mut(), payload);
+                unsafe { response.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))
+            }
+            ActiveRequestType::Local(Some(v)) => {
+                let mut response = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    response.user_header_mut(),
+                    self.response_header_size,
+                    user_header,
+                );
+                copy_payload(response.payload_mut(), payload);
+                unsafe { response.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))
+            }
+            _ => fatal_panic!(from "ActiveRequest::send_copy()",
+                "Accessing a released active request."),
+        }
+    }
+
+    /// Signals the corresponding `PendingResponse` that the stream of `Response`s has ended
+    /// and releases the `ActiveRequest`.
+    ///
+    /// After this call the `ActiveRequest` is no longer usable!
+    pub fn finish(&mut self) {
+        self.delete()
+    }
+
+    /// Releases the `ActiveRequest` and signals the `Client` that no more `Response`s will be
+    /// sent.
+    ///
+    /// After this call the `ActiveRequest` is no longer usable!
+    pub fn delete(&mut self) {
+        match &mut *self.value.lock() {
+            ActiveRequestType::Ipc(ref mut v) => {
+                v.take();
+            }
+            ActiveRequestType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
+
+impl ActiveRequest {
+    pub(crate) fn reject(&mut self, error_code: u64) -> PyResult<()> {
+        match &mut *self.value.lock() {
+            ActiveRequestType::Ipc(ref mut v) => match v.take() {
+                Some(v) => unsafe { v.reject_custom_payload(error_code) }
+                    .map_err(|e| SendError::new_err(format!("{e:?}"))),
+                None => fatal_panic!(from "ActiveRequest::reject()",
+                    "Accessing a released active request."),
+            },
+            ActiveRequestType::Local(ref mut v) => match v.take() {
+                Some(v) => unsafe { v.reject_custom_payload(error_code) }
+                    .map_err(|e| SendError::new_err(format!("{e:?}"))),
+                None => fatal_panic!(from "ActiveRequest::reject()",
+                    "Accessing a released active request."),
+            },
+        }
+    }
+}
+
+pub(crate) fn verify_user_header_size(
+    user_header_size: usize,
+    user_header: Option<&[u8]>,
+) -> PyResult<()> {
+    match user_header {
+        Some(user_header) if user_header.len() != user_header_size => {
+            Err(PyValueError::new_err(format!(
+                "The user header with {} bytes does not fit the user header type with {} bytes.",
+                user_header.len(),
+                user_header_size
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn copy_user_header(
+    destination: &mut CustomHeaderMarker,
+    user_header_size: usize,
+    source: Option<&[u8]>,
+) {
+    let destination: *mut u8 = (destination as *mut CustomHeaderMarker).cast();
+    match source {
+        Some(source) => {
+            debug_assert!(user_header_size == source.len());
+            unsafe { core::ptr::copy_nonoverlapping(source.as_ptr(), destination, source.len()) };
+        }
+        None => unsafe { core::ptr::write_bytes(destination, 0, user_header_size) },
+    }
+}
+
+pub(crate) fn copy_payload(
+    destination: &mut [core::mem::MaybeUninit<CustomPayloadMarker>],
+    source: &[u8],
+) {
+    debug_assert!(destination.len() == source.len());
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            source.as_ptr(),
+            destination.as_mut_ptr().cast(),
+            source.len(),
+        )
+    };
+}
@@ -12,6 +12,8 @@
 
 use pyo3::prelude::*;
 
+use crate::error::InvalidAllocationStrategyValue;
+
 #[pyclass(eq, eq_int)]
 #[derive(PartialEq, Clone, Debug)]
 /// Describes generically an `AllocationStrategy`, meaning how the memory is increased when the
@@ -19,12 +21,12 @@ use pyo3::prelude::*;
 pub enum AllocationStrategy {
     /// Increases the memory so that it perfectly fits the new size requirements. This may lead
     /// to a lot of reallocations but has the benefit that no byte is wasted.
-    BestFit,
+    BestFit = 0,
     /// Increases the memory by rounding the increased memory size up to the next power of two.
     /// Reduces reallocations a lot at the cost of increased memory usage.
-    PowerOfTwo,
+    PowerOfTwo = 1,
     /// The memory is not increased. This may lead to an out-of-memory error when allocating.
-    Static,
+    Static = 2,
 }
 
 #[pymethods]
@@ -32,6 +34,65 @@ impl AllocationStrategy {
     pub fn __str__(&self) -> String {
         format!("{self:?}")
     }
+
+    pub fn __repr__(&self) -> String {
+        format!("AllocationStrategy.{self:?}")
+    }
+
+    /// Returns the stable ordinal value of the `AllocationStrategy` that can be used for
+    /// serialization. It can be converted back with `AllocationStrategy.from_int()`.
+    pub fn __int__(&self) -> u64 {
+        self.clone() as u64
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_str")]
+    /// Creates an `AllocationStrategy` from its name, e.g. `"PowerOfTwo"`, or its
+    /// representation, e.g. `"AllocationStrategy.PowerOfTwo"`. If the name is unknown it
+    /// emits an `InvalidAllocationStrategyValue`.
+    pub fn from_string(value: &str) -> PyResult<AllocationStrategy> {
+        match value.strip_prefix("AllocationStrategy.").unwrap_or(value) {
+            "BestFit" => Ok(AllocationStrategy::BestFit),
+            "PowerOfTwo" => Ok(AllocationStrategy::PowerOfTwo),
+            "Static" => Ok(AllocationStrategy::Static),
+            _ => Err(InvalidAllocationStrategyValue::new_err(format!(
+                "This: (\"{value}\") is not a valid allocation strategy"
+            ))),
+        }
+    }
+
+    #[staticmethod]
+    /// Creates an `AllocationStrategy` from its ordinal value. If the value does not
+    /// correspond to any `AllocationStrategy` it emits an `InvalidAllocationStrategyValue`.
+    pub fn from_int(value: u64) -> PyResult<AllocationStrategy> {
+        match value {
+            0 => Ok(AllocationStrategy::BestFit),
+            1 => Ok(AllocationStrategy::PowerOfTwo),
+            2 => Ok(AllocationStrategy::Static),
+            _ => Err(InvalidAllocationStrategyValue::new_err(format!(
+                "This: ({value}) is not a valid allocation strategy ordinal"
+            ))),
+        }
+    }
+
+    /// Estimates the number of bytes a memory with `element_count` chunks requires when every
+    /// chunk stores `element_size` bytes of payload and `overhead_bytes` of additional data,
+    /// like a header. `AllocationStrategy.PowerOfTwo` rounds the chunk size up to the next
+    /// power of two, `AllocationStrategy.BestFit` and `AllocationStrategy.Static` use the
+    /// exact chunk size. Alignment padding and the management data of the allocator are not
+    /// considered.
+    pub fn estimate_memory(
+        &self,
+        element_size: usize,
+        element_count: usize,
+        overhead_bytes: usize,
+    ) -> usize {
+        iceoryx2::prelude::AllocationStrategy::from(self.clone()).estimate_memory(
+            element_size,
+            element_count,
+            overhead_bytes,
+        )
+    }
 }
 
 impl From<iceoryx2::prelude::AllocationStrategy> for AllocationStrategy {
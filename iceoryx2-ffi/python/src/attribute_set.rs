@@ -45,6 +45,26 @@ impl AttributeSet {
         ret_val
     }
 
+    /// Returns all `AttributeValue`s that belong to a specified `AttributeKey`. A key can be
+    /// defined multiple times, for instance to advertise several supported versions. If the
+    /// key is not defined, an empty list is returned.
+    pub fn get_key_values(&self, key: &AttributeKey) -> Vec<AttributeValue> {
+        self.key_values(key)
+    }
+
+    /// Returns all distinct `AttributeKey`s stored in the `AttributeSet`. Every key is listed
+    /// once, even when it has multiple values.
+    pub fn keys(&self) -> Vec<AttributeKey> {
+        let mut ret_val: Vec<AttributeKey> = vec![];
+        for attribute in &*self.0 {
+            if !ret_val.iter().any(|key| key.0 == *attribute.key()) {
+                ret_val.push(AttributeKey(attribute.key().clone()));
+            }
+        }
+
+        ret_val
+    }
+
     #[getter]
     /// Returns all `Attribute`s stored in the `AttributeSet`
     pub fn values(&self) -> Vec<Attribute> {
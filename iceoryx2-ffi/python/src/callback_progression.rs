@@ -0,0 +1,49 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::prelude::*;
+
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Clone, Debug)]
+/// Defines if a callback, for instance the one of `WaitSet::wait_and_process()`, shall
+/// continue with the next element or stop the processing.
+pub enum CallbackProgression {
+    /// Stops the processing and returns.
+    Stop,
+    /// Continues with the next element.
+    Continue,
+}
+
+#[pymethods]
+impl CallbackProgression {
+    pub fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl From<iceoryx2::prelude::CallbackProgression> for CallbackProgression {
+    fn from(value: iceoryx2::prelude::CallbackProgression) -> Self {
+        match value {
+            iceoryx2::prelude::CallbackProgression::Stop => CallbackProgression::Stop,
+            iceoryx2::prelude::CallbackProgression::Continue => CallbackProgression::Continue,
+        }
+    }
+}
+
+impl From<CallbackProgression> for iceoryx2::prelude::CallbackProgression {
+    fn from(value: CallbackProgression) -> Self {
+        match value {
+            CallbackProgression::Stop => iceoryx2::prelude::CallbackProgression::Stop,
+            CallbackProgression::Continue => iceoryx2::prelude::CallbackProgression::Continue,
+        }
+    }
+}
@@ -12,10 +12,19 @@
 
 use std::sync::Arc;
 
-use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
-use pyo3::prelude::*;
+use iceoryx2::service::{
+    builder::{CustomHeaderMarker, CustomPayloadMarker},
+    static_config::message_type_details::TypeDetail,
+};
+use pyo3::{exceptions::PyTypeError, prelude::*};
 
-use crate::unique_client_id::UniqueClientId;
+use crate::{
+    active_request::{copy_payload, copy_user_header, verify_user_header_size},
+    error::{LoanError, SendError},
+    pending_response::{PendingResponse, PendingResponseType},
+    type_detail::number_of_payload_elements,
+    unique_client_id::UniqueClientId,
+};
 
 type IpcClient = Arc<
     iceoryx2::port::client::Client<
@@ -42,17 +51,173 @@ pub(crate) enum ClientType {
 }
 
 #[pyclass]
-/// Represents the receiving endpoint of an event based communication.
-pub struct Client(pub(crate) ClientType);
+/// Represents the sending endpoint of a request-response based communication.
+pub struct Client {
+    pub(crate) value: ClientType,
+    pub(crate) request_payload_type_details: TypeDetail,
+    pub(crate) request_header_size: usize,
+    pub(crate) response_header_size: usize,
+    pub(crate) does_support_fire_and_forget_requests: bool,
+}
 
 #[pymethods]
 impl Client {
     #[getter]
     /// Returns the `UniqueClientId` of the `Client`
     pub fn id(&self) -> UniqueClientId {
-        match &self.0 {
+        match &self.value {
             ClientType::Ipc(v) => UniqueClientId(v.id()),
             ClientType::Local(v) => UniqueClientId(v.id()),
         }
     }
+
+    #[getter]
+    /// Returns the number of requests the `Client` can send until the `Server` with the most
+    /// unacknowledged requests has to acknowledge previous ones. Returns `None` when the
+    /// service has no credit flow control enabled.
+    pub fn available_credits(&self) -> Option<u32> {
+        match &self.value {
+            ClientType::Ipc(v) => v.available_credits(),
+            ClientType::Local(v) => v.available_credits(),
+        }
+    }
+
+    /// Sends a copy of the provided `bytes` as request to all connected `Server`s and returns
+    /// the `PendingResponse` to receive the corresponding `Response`s. The `bytes` must match
+    /// the request payload type of the `Service`, otherwise `ValueError` is emitted. The
+    /// optional `user_header` must have the size of the request header type, otherwise
+    /// `ValueError` is emitted. If it is not provided, the user header is filled with zeros.
+    ///
+    /// On failure it emits `LoanError` or `SendError` describing the failure.
+    #[pyo3(signature = (payload, user_header = None))]
+    pub fn send_copy(
+        &self,
+        payload: &[u8],
+        user_header: Option<&[u8]>,
+    ) -> PyResult<PendingResponse> {
+        let number_of_elements =
+            number_of_payload_elements(&self.request_payload_type_details, payload.len())?;
+        verify_user_header_size(self.request_header_size, user_header)?;
+
+        match &self.value {
+            ClientType::Ipc(v) => {
+                let mut request = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    request.user_header_mut(),
+                    self.request_header_size,
+                    user_header,
+                );
+                copy_payload(request.payload_mut(), payload);
+                let pending_response = unsafe { request.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))?;
+                Ok(PendingResponse::new(
+                    PendingResponseType::Ipc(Some(pending_response)),
+                    self.response_header_size,
+                ))
+            }
+            ClientType::Local(v) => {
+                let mut request = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    request.user_header_mut(),
+                    self.request_header_size,
+                    user_header,
+                );
+                copy_payload(request.payload_mut(), payload);
+                let pending_response = unsafe { request.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))?;
+                Ok(PendingResponse::new(
+                    PendingResponseType::Local(Some(pending_response)),
+                    self.response_header_size,
+                ))
+            }
+        }
+    }
+
+    /// Cancels the in-flight request of the provided `PendingResponse`. The request slot is
+    /// released immediately and the `Server`s are informed that the `Response`s are no longer
+    /// required. All `Response`s that are sent afterwards are discarded. Cancelling an already
+    /// deleted `PendingResponse` has no effect. If the `PendingResponse` belongs to a
+    /// `Client` of another service type, `TypeError` is emitted.
+    pub fn cancel_pending_response(&self, pending_response: &PendingResponse) -> PyResult<()> {
+        match (&self.value, &mut *pending_response.value.lock()) {
+            (ClientType::Ipc(client), PendingResponseType::Ipc(v)) => {
+                if let Some(v) = v.take() {
+                    client.cancel_pending_response(v);
+                }
+                Ok(())
+            }
+            (ClientType::Local(client), PendingResponseType::Local(v)) => {
+                if let Some(v) = v.take() {
+                    client.cancel_pending_response(v);
+                }
+                Ok(())
+            }
+            _ => Err(PyTypeError::new_err(
+                "The pending response belongs to a client of another service type.",
+            )),
+        }
+    }
+
+    /// Sends a copy of the provided `bytes` as request to all connected `Server`s without
+    /// expecting any `Response`. The `bytes` must match the request payload type of the
+    /// `Service`, otherwise `ValueError` is emitted. If the `Service` was not created with
+    /// `enable_fire_and_forget_requests(True)`, `TypeError` is emitted. The optional
+    /// `user_header` is handled like in `Client::send_copy`.
+    ///
+    /// On failure it emits `LoanError` or `SendError` describing the failure.
+    #[pyo3(signature = (payload, user_header = None))]
+    pub fn fire_and_forget(&self, payload: &[u8], user_header: Option<&[u8]>) -> PyResult<()> {
+        if !self.does_support_fire_and_forget_requests {
+            return Err(PyTypeError::new_err(
+                "The service does not support fire and forget requests.",
+            ));
+        }
+
+        let number_of_elements =
+            number_of_payload_elements(&self.request_payload_type_details, payload.len())?;
+        verify_user_header_size(self.request_header_size, user_header)?;
+
+        match &self.value {
+            ClientType::Ipc(v) => {
+                let mut request = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    request.user_header_mut(),
+                    self.request_header_size,
+                    user_header,
+                );
+                copy_payload(request.payload_mut(), payload);
+                unsafe { request.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))?;
+            }
+            ClientType::Local(v) => {
+                let mut request = unsafe {
+                    v.loan_custom_payload(number_of_elements)
+                        .map_err(|e| LoanError::new_err(format!("{e:?}")))?
+                };
+                copy_user_header(
+                    request.user_header_mut(),
+                    self.request_header_size,
+                    user_header,
+                );
+                copy_payload(request.payload_mut(), payload);
+                unsafe { request.assume_init() }
+                    .send()
+                    .map_err(|e| SendError::new_err(format!("{e:?}")))?;
+            }
+        }
+
+        Ok(())
+    }
 }
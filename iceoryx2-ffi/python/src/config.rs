@@ -11,7 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::duration::Duration;
-use crate::error::ConfigCreationError;
+use crate::error::{ConfigCreationError, InvalidDirectory};
 use crate::file_name::FileName;
 use crate::file_path::FilePath;
 use crate::parc::Parc;
@@ -19,6 +19,28 @@ use crate::path::Path;
 use crate::unable_to_deliver_strategy::UnableToDeliverStrategy;
 use pyo3::prelude::*;
 
+fn verify_directory_is_usable(
+    root_path: &iceoryx2::prelude::Path,
+    directory: &iceoryx2::prelude::Path,
+) -> PyResult<()> {
+    let mut path = root_path.clone();
+    path.add_path_entry(directory).map_err(|e| {
+        InvalidDirectory::new_err(format!(
+            "The directory \"{directory}\" cannot be added to the root path \"{root_path}\" ({e:?})."
+        ))
+    })?;
+
+    let path_str = path.to_string();
+    let fs_path = std::path::Path::new(&path_str);
+    if fs_path.exists() && !fs_path.is_dir() {
+        return Err(InvalidDirectory::new_err(format!(
+            "The path \"{path_str}\" exists but is not a directory."
+        )));
+    }
+
+    Ok(())
+}
+
 #[pyclass]
 /// All configurable settings of a `Node`.
 pub struct Node(Parc<iceoryx2::config::Config>);
@@ -123,9 +145,14 @@ impl Service {
     }
 
     #[setter]
-    /// Set the directory in which all service files are stored
-    pub fn set_directory(&self, value: &Path) {
-        self.0.lock().global.service.directory = value.0.clone()
+    /// Set the directory in which all service files are stored. The directory is relative to
+    /// the `root_path`. Emits `InvalidDirectory` when the resulting service directory cannot be
+    /// represented as `Path` or refers to an existing non-directory entry.
+    pub fn set_directory(&self, value: &Path) -> PyResult<()> {
+        let mut config = self.0.lock();
+        verify_directory_is_usable(config.global.root_path(), &value.0)?;
+        config.global.service.directory = value.0.clone();
+        Ok(())
     }
 
     #[getter]
@@ -214,6 +241,19 @@ impl Service {
     pub fn set_event_connection_suffix(&self, value: &FileName) {
         self.0.lock().global.service.event_connection_suffix = value.0.clone()
     }
+
+    #[getter]
+    /// When true, the creation and removal of every `Service` is announced to discovery
+    /// services so that they do not have to poll.
+    pub fn enable_discovery_announcements(&self) -> bool {
+        self.0.lock().global.service.enable_discovery_announcements
+    }
+
+    #[setter]
+    /// Enable/disable the announcement of service creations and removals
+    pub fn set_enable_discovery_announcements(&self, value: bool) {
+        self.0.lock().global.service.enable_discovery_announcements = value
+    }
 }
 
 #[pyclass]
@@ -366,6 +406,27 @@ impl PublishSubscribe {
             .enable_safe_overflow = value
     }
 
+    #[getter]
+    /// Defines if the `Publisher` stores a send timestamp in the header of
+    /// every `Sample`.
+    pub fn enable_send_timestamp(&self) -> bool {
+        self.0
+            .lock()
+            .defaults
+            .publish_subscribe
+            .enable_send_timestamp
+    }
+
+    #[setter]
+    /// Enables/disables the send timestamp
+    pub fn set_enable_send_timestamp(&self, value: bool) {
+        self.0
+            .lock()
+            .defaults
+            .publish_subscribe
+            .enable_send_timestamp = value
+    }
+
     #[getter]
     /// If safe overflow is deactivated it defines the deliver strategy of the
     /// `Publisher` when the `Subscriber`s buffer is full.
@@ -411,6 +472,44 @@ impl PublishSubscribe {
             .publish_subscribe
             .subscriber_expired_connection_buffer = value
     }
+
+    #[getter]
+    /// Defines how long a `Sample` is valid after it was sent. Older `Sample`s are discarded
+    /// by the `Subscriber` instead of being received. A zero duration means that no lifespan
+    /// is set.
+    pub fn sample_lifespan(&self) -> Duration {
+        Duration(
+            self.0
+                .lock()
+                .defaults
+                .publish_subscribe
+                .sample_lifespan
+                .unwrap_or(core::time::Duration::ZERO),
+        )
+    }
+
+    #[setter]
+    /// Sets the sample lifespan, a zero duration disables it.
+    pub fn set_sample_lifespan(&self, value: &Duration) {
+        if value.0.is_zero() {
+            self.0.lock().defaults.publish_subscribe.sample_lifespan = None
+        } else {
+            self.0.lock().defaults.publish_subscribe.sample_lifespan = Some(value.0)
+        }
+    }
+
+    #[getter]
+    /// Defines the id of the NUMA node the data segments of all `Publisher`s are bound to.
+    /// Returns `None` when the data segments are not bound to a node.
+    pub fn publisher_numa_node(&self) -> Option<usize> {
+        self.0.lock().defaults.publish_subscribe.publisher_numa_node
+    }
+
+    #[setter]
+    /// Sets the NUMA node of the `Publisher` data segments, `None` disables the binding.
+    pub fn set_publisher_numa_node(&self, value: Option<usize>) {
+        self.0.lock().defaults.publish_subscribe.publisher_numa_node = value
+    }
 }
 
 #[pyclass]
@@ -911,6 +1010,27 @@ impl RequestResponse {
             .request_response
             .enable_fire_and_forget_requests = value
     }
+
+    #[getter]
+    /// Defines if a `Server` can send a stream of arbitrary many `Response`s for a single
+    /// `ActiveRequest`. When disabled, exactly one `Response` can be sent per `ActiveRequest`.
+    pub fn enable_multi_response(&self) -> bool {
+        self.0
+            .lock()
+            .defaults
+            .request_response
+            .enable_multi_response
+    }
+
+    #[setter]
+    /// Set if multiple responses per request are supported
+    pub fn set_enable_multi_response(&self, value: bool) {
+        self.0
+            .lock()
+            .defaults
+            .request_response
+            .enable_multi_response = value
+    }
 }
 
 #[pyclass]
@@ -954,9 +1074,15 @@ impl Global {
     }
 
     #[setter]
-    /// Defines the path under which all other directories or files will be created
-    pub fn set_root_path(&self, value: &Path) {
-        self.0.lock().global.set_root_path(&value.0.clone())
+    /// Defines the path under which all other directories or files will be created.
+    /// Emits `InvalidDirectory` when the service or node directory cannot be placed under the
+    /// new root path.
+    pub fn set_root_path(&self, value: &Path) -> PyResult<()> {
+        let mut config = self.0.lock();
+        verify_directory_is_usable(&value.0, &config.global.service.directory)?;
+        verify_directory_is_usable(&value.0, &config.global.node.directory)?;
+        config.global.set_root_path(&value.0);
+        Ok(())
     }
 
     #[getter]
@@ -1025,6 +1151,26 @@ impl Config {
         format!("{:?}", self.0.lock())
     }
 
+    /// Returns the effective config in the TOML format of the config file, including all
+    /// environment variable overrides.
+    pub fn to_toml(&self) -> String {
+        self.0.lock().to_toml()
+    }
+
+    /// Returns an independent copy of the config. Modifying the copy does not affect the
+    /// original config.
+    pub fn clone(&self) -> Config {
+        Config(Parc::new(self.0.lock().clone()))
+    }
+
+    /// Returns a copy of the config where all settings of `other` that differ from the default
+    /// config are layered on top. Settings of `other` that equal the default config keep the
+    /// value of this config, even when they were set explicitly.
+    pub fn merge(&self, other: &Config) -> Config {
+        let other = other.0.lock().clone();
+        Config(Parc::new(self.0.lock().merge(&other)))
+    }
+
     #[getter]
     /// Returns the `Global` part of the config
     pub fn global_cfg(&self) -> Global {
@@ -89,3 +89,15 @@ impl Duration {
         self.0.subsec_nanos()
     }
 }
+
+/// Accepts either an iceoryx2 `Duration` or a `datetime.timedelta` as argument.
+pub struct DurationLike(pub(crate) core::time::Duration);
+
+impl<'py> FromPyObject<'py> for DurationLike {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match obj.downcast::<Duration>() {
+            Ok(v) => Ok(DurationLike(v.borrow().0)),
+            Err(_) => Ok(DurationLike(obj.extract::<core::time::Duration>()?)),
+        }
+    }
+}
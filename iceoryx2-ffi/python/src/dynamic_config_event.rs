@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::CallbackProgression;
+use pyo3::prelude::*;
+
+use crate::{unique_listener_id::UniqueListenerId, unique_notifier_id::UniqueNotifierId};
+
+#[pyclass]
+/// A snapshot of the dynamic configuration of a `MessagingPattern::Event` based service.
+/// All ports are captured in one call on creation, the snapshot does not change afterwards
+/// even when ports are created or removed.
+pub struct DynamicConfigEvent {
+    notifier_ids: Vec<iceoryx2::port::port_identifiers::UniqueNotifierId>,
+    listener_ids: Vec<iceoryx2::port::port_identifiers::UniqueListenerId>,
+}
+
+impl DynamicConfigEvent {
+    pub(crate) fn new(config: &iceoryx2::service::dynamic_config::event::DynamicConfig) -> Self {
+        let mut notifier_ids = vec![];
+        config.list_notifiers(|details| {
+            notifier_ids.push(details.notifier_id);
+            CallbackProgression::Continue
+        });
+
+        let mut listener_ids = vec![];
+        config.list_listeners(|details| {
+            listener_ids.push(details.listener_id);
+            CallbackProgression::Continue
+        });
+
+        Self {
+            notifier_ids,
+            listener_ids,
+        }
+    }
+}
+
+#[pymethods]
+impl DynamicConfigEvent {
+    #[getter]
+    /// Returns the number of `Notifier`s at the time the snapshot was taken.
+    pub fn number_of_notifiers(&self) -> usize {
+        self.notifier_ids.len()
+    }
+
+    #[getter]
+    /// Returns the number of `Listener`s at the time the snapshot was taken.
+    pub fn number_of_listeners(&self) -> usize {
+        self.listener_ids.len()
+    }
+
+    #[getter]
+    /// Returns the `UniqueNotifierId`s of all `Notifier`s at the time the snapshot was taken.
+    pub fn notifier_ids(&self) -> Vec<UniqueNotifierId> {
+        self.notifier_ids
+            .iter()
+            .map(|id| UniqueNotifierId(*id))
+            .collect()
+    }
+
+    #[getter]
+    /// Returns the `UniqueListenerId`s of all `Listener`s at the time the snapshot was taken.
+    pub fn listener_ids(&self) -> Vec<UniqueListenerId> {
+        self.listener_ids
+            .iter()
+            .map(|id| UniqueListenerId(*id))
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::CallbackProgression;
+use pyo3::prelude::*;
+
+use crate::{unique_publisher_id::UniquePublisherId, unique_subscriber_id::UniqueSubscriberId};
+
+#[pyclass]
+/// A snapshot of the dynamic configuration of a `MessagingPattern::PublishSubscribe` based service.
+/// All ports are captured in one call on creation, the snapshot does not change afterwards
+/// even when ports are created or removed.
+pub struct DynamicConfigPublishSubscribe {
+    publisher_ids: Vec<iceoryx2::port::port_identifiers::UniquePublisherId>,
+    subscriber_ids: Vec<iceoryx2::port::port_identifiers::UniqueSubscriberId>,
+}
+
+impl DynamicConfigPublishSubscribe {
+    pub(crate) fn new(
+        config: &iceoryx2::service::dynamic_config::publish_subscribe::DynamicConfig,
+    ) -> Self {
+        let mut publisher_ids = vec![];
+        config.list_publishers(|details| {
+            publisher_ids.push(details.publisher_id);
+            CallbackProgression::Continue
+        });
+
+        let mut subscriber_ids = vec![];
+        config.list_subscribers(|details| {
+            subscriber_ids.push(details.subscriber_id);
+            CallbackProgression::Continue
+        });
+
+        Self {
+            publisher_ids,
+            subscriber_ids,
+        }
+    }
+}
+
+#[pymethods]
+impl DynamicConfigPublishSubscribe {
+    #[getter]
+    /// Returns the number of `Publisher`s at the time the snapshot was taken.
+    pub fn number_of_publishers(&self) -> usize {
+        self.publisher_ids.len()
+    }
+
+    #[getter]
+    /// Returns the number of `Subscriber`s at the time the snapshot was taken.
+    pub fn number_of_subscribers(&self) -> usize {
+        self.subscriber_ids.len()
+    }
+
+    #[getter]
+    /// Returns the `UniquePublisherId`s of all `Publisher`s at the time the snapshot was taken.
+    pub fn publisher_ids(&self) -> Vec<UniquePublisherId> {
+        self.publisher_ids
+            .iter()
+            .map(|id| UniquePublisherId(*id))
+            .collect()
+    }
+
+    #[getter]
+    /// Returns the `UniqueSubscriberId`s of all `Subscriber`s at the time the snapshot was taken.
+    pub fn subscriber_ids(&self) -> Vec<UniqueSubscriberId> {
+        self.subscriber_ids
+            .iter()
+            .map(|id| UniqueSubscriberId(*id))
+            .collect()
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::CallbackProgression;
+use pyo3::prelude::*;
+
+use crate::{unique_client_id::UniqueClientId, unique_server_id::UniqueServerId};
+
+#[pyclass]
+/// A snapshot of the dynamic configuration of a `MessagingPattern::RequestResponse` based service.
+/// All ports are captured in one call on creation, the snapshot does not change afterwards
+/// even when ports are created or removed.
+pub struct DynamicConfigRequestResponse {
+    client_ids: Vec<iceoryx2::port::port_identifiers::UniqueClientId>,
+    server_ids: Vec<iceoryx2::port::port_identifiers::UniqueServerId>,
+}
+
+impl DynamicConfigRequestResponse {
+    pub(crate) fn new(
+        config: &iceoryx2::service::dynamic_config::request_response::DynamicConfig,
+    ) -> Self {
+        let mut client_ids = vec![];
+        config.list_clients(|details| {
+            client_ids.push(details.client_id);
+            CallbackProgression::Continue
+        });
+
+        let mut server_ids = vec![];
+        config.list_servers(|details| {
+            server_ids.push(details.server_id);
+            CallbackProgression::Continue
+        });
+
+        Self {
+            client_ids,
+            server_ids,
+        }
+    }
+}
+
+#[pymethods]
+impl DynamicConfigRequestResponse {
+    #[getter]
+    /// Returns the number of `Client`s at the time the snapshot was taken.
+    pub fn number_of_clients(&self) -> usize {
+        self.client_ids.len()
+    }
+
+    #[getter]
+    /// Returns the number of `Server`s at the time the snapshot was taken.
+    pub fn number_of_servers(&self) -> usize {
+        self.server_ids.len()
+    }
+
+    #[getter]
+    /// Returns the `UniqueClientId`s of all `Client`s at the time the snapshot was taken.
+    pub fn client_ids(&self) -> Vec<UniqueClientId> {
+        self.client_ids
+            .iter()
+            .map(|id| UniqueClientId(*id))
+            .collect()
+    }
+
+    #[getter]
+    /// Returns the `UniqueServerId`s of all `Server`s at the time the snapshot was taken.
+    pub fn server_ids(&self) -> Vec<UniqueServerId> {
+        self.server_ids
+            .iter()
+            .map(|id| UniqueServerId(*id))
+            .collect()
+    }
+}
@@ -27,6 +27,13 @@ create_exception!(
     "Errors caused when creating a new client port."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    ClockDomainError,
+    PyException,
+    "Errors caused when time values of different clock domains, e.g. of different hosts, are compared."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     ConnectionFailure,
@@ -76,6 +83,13 @@ create_exception!(
     "Errors caused when waiting on a node."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    NodeSignalShutdownFailure,
+    PyException,
+    "Errors caused when a node requests a shutdown of all nodes."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     NotifierCreateError,
@@ -90,6 +104,41 @@ create_exception!(
     "Errors caused when sending a notification via the Notifier port in an event service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    BlackboardOpenError,
+    PyException,
+    "Errors caused when opening a blackboard service."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    BlackboardOpenExceedsMaxNumberOfNodesError,
+    BlackboardOpenError,
+    "Errors caused when a blackboard service could not be opened since the maximum number of nodes is reached."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    BlackboardCreateError,
+    PyException,
+    "Errors caused when creating a blackboard service."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    BlackboardOpenOrCreateError,
+    PyException,
+    "Errors caused when open or creating a blackboard service."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    BlackboardOpenOrCreateExceedsMaxNumberOfNodesError,
+    BlackboardOpenOrCreateError,
+    "Errors caused when a blackboard service could not be opened or created since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     EventOpenError,
@@ -97,6 +146,13 @@ create_exception!(
     "Errors caused when opening an event service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    EventOpenExceedsMaxNumberOfNodesError,
+    EventOpenError,
+    "Errors caused when an event service could not be opened since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     EventCreateError,
@@ -111,6 +167,13 @@ create_exception!(
     "Errors caused when open or creating an event service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    EventOpenOrCreateExceedsMaxNumberOfNodesError,
+    EventOpenOrCreateError,
+    "Errors caused when an event service could not be opened or created since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     InvalidAlignmentValue,
@@ -118,6 +181,20 @@ create_exception!(
     "Errors caused when the value of the alignment is not a power of two or exceeds the maximum supported value."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    InvalidDirectory,
+    PyException,
+    "Errors caused when a configured directory cannot be used to store iceoryx2 resources."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    InvalidAllocationStrategyValue,
+    PyException,
+    "Errors caused when a string or ordinal does not correspond to any `AllocationStrategy`."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     ListenerWaitError,
@@ -125,6 +202,13 @@ create_exception!(
     "Errors caused when waiting on a Listener port in an event service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    ListenerDeadlineError,
+    PyException,
+    "Errors caused when checking a Listener port for a missed deadline."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     PublisherCreateError,
@@ -132,6 +216,13 @@ create_exception!(
     "Errors caused when creating a publisher port."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    PublisherSetHistorySizeError,
+    PyException,
+    "Errors caused when adjusting the history size of a publisher port."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     PublishSubscribeOpenError,
@@ -139,6 +230,13 @@ create_exception!(
     "Errors caused when opening a publish-subscribe service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    PublishSubscribeOpenExceedsMaxNumberOfNodesError,
+    PublishSubscribeOpenError,
+    "Errors caused when a publish-subscribe service could not be opened since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     PublishSubscribeCreateError,
@@ -153,6 +251,13 @@ create_exception!(
     "Errors caused when open or creating a publish-subscribe service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError,
+    PublishSubscribeOpenOrCreateError,
+    "Errors caused when a publish-subscribe service could not be opened or created since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     ReceiveError,
@@ -160,6 +265,20 @@ create_exception!(
     "Errors caused when receiving data."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    ReaderCreateError,
+    PyException,
+    "Errors caused when creating a new Reader port."
+);
+
+create_exception!(
+    iceoryx2_ffi_python,
+    ReaderReadError,
+    PyException,
+    "Errors caused when a Reader port accesses a blackboard entry."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     RequestResponseOpenError,
@@ -167,6 +286,13 @@ create_exception!(
     "Errors caused when opening a request-response service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    RequestResponseOpenExceedsMaxNumberOfNodesError,
+    RequestResponseOpenError,
+    "Errors caused when a request-response service could not be opened since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     RequestResponseCreateError,
@@ -181,6 +307,13 @@ create_exception!(
     "Errors caused when open or creating a request-response service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError,
+    RequestResponseOpenOrCreateError,
+    "Errors caused when a request-response service could not be opened or created since the maximum number of nodes is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     SemanticStringError,
@@ -195,6 +328,13 @@ create_exception!(
     "Errors caused when sending data."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    RateLimitedError,
+    SendError,
+    "Errors caused when data could not be sent without blocking since the rate limit of the publisher is reached."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     ServiceDetailsError,
@@ -223,6 +363,13 @@ create_exception!(
     "Errors caused when creating a subscriber port."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    SubscriberMarkReceivedError,
+    PyException,
+    "Errors caused when a subscriber acknowledges a received sample."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     WaitSetAttachmentError,
@@ -243,3 +390,10 @@ create_exception!(
     PyException,
     "Errors caused by calling WaitSet::wait_and_process()."
 );
+
+create_exception!(
+    iceoryx2_ffi_python,
+    WriterWriteError,
+    PyException,
+    "Errors caused when a Writer port writes a blackboard entry."
+);
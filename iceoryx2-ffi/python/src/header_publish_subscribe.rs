@@ -38,4 +38,11 @@ impl HeaderPublishSubscribe {
     pub fn number_of_elements(&self) -> u64 {
         self.0.number_of_elements()
     }
+
+    #[getter]
+    /// Returns the sequence number of the `Sample`. Every `Publisher` numbers its sent samples
+    /// consecutively, starting with 0.
+    pub fn sequence_number(&self) -> u64 {
+        self.0.sequence_number()
+    }
 }
@@ -0,0 +1,67 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::parc::{Parc, ParcWeak};
+
+/// Implemented by the internal sample types that can be released explicitly, so that the
+/// loaned memory is returned before the python object is garbage collected.
+pub(crate) trait Release: Send {
+    fn release(&mut self);
+}
+
+trait HeldSample: Send {
+    fn is_alive(&self) -> bool;
+    fn release(&self);
+}
+
+impl<T: Release> HeldSample for ParcWeak<T> {
+    fn is_alive(&self) -> bool {
+        self.upgrade().is_some()
+    }
+
+    fn release(&self) {
+        if let Some(sample) = self.upgrade() {
+            sample.lock().release();
+        }
+    }
+}
+
+/// Tracks the samples a port has handed out to python so that they can be released
+/// together with the port, e.g. when a port is used as context manager.
+#[derive(Clone)]
+pub(crate) struct HeldSamples {
+    samples: Parc<Vec<Box<dyn HeldSample>>>,
+}
+
+impl HeldSamples {
+    pub(crate) fn new() -> Self {
+        Self {
+            samples: Parc::new(Vec::new()),
+        }
+    }
+
+    /// Starts tracking the sample. Samples that were already dropped are removed so that
+    /// the list never grows beyond the number of samples that are held at the same time.
+    pub(crate) fn add<T: Release + 'static>(&self, sample: &Parc<T>) {
+        let mut samples = self.samples.lock();
+        samples.retain(|s| s.is_alive());
+        samples.push(Box::new(sample.downgrade()));
+    }
+
+    /// Releases all tracked samples that are still held.
+    pub(crate) fn release_all(&self) {
+        let samples = core::mem::take(&mut *self.samples.lock());
+        for sample in samples {
+            sample.release();
+        }
+    }
+}
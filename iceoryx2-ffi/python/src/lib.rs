@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod active_request;
 pub mod alignment;
 pub mod allocation_strategy;
 pub mod attribute;
@@ -18,58 +19,78 @@ pub mod attribute_set;
 pub mod attribute_specifier;
 pub mod attribute_value;
 pub mod attribute_verifier;
+pub mod callback_progression;
 pub mod cleanup_state;
 pub mod client;
 pub mod config;
 pub mod duration;
+pub mod dynamic_config_event;
+pub mod dynamic_config_publish_subscribe;
+pub mod dynamic_config_request_response;
 pub mod error;
 pub mod event_id;
 pub mod file_descriptor;
 pub mod file_name;
 pub mod file_path;
 pub mod header_publish_subscribe;
+pub(crate) mod held_samples;
 pub mod listener;
 pub mod log;
 pub mod log_level;
+pub mod memory_usage;
 pub mod message_type_details;
 pub mod messaging_pattern;
 pub mod node;
 pub mod node_builder;
+pub mod node_group;
 pub mod node_id;
 pub mod node_name;
 pub mod node_state;
 pub mod notifier;
 pub mod parc;
 pub mod path;
+pub mod pending_response;
+pub mod port_factory_blackboard;
 pub mod port_factory_client;
 pub mod port_factory_event;
 pub mod port_factory_listener;
 pub mod port_factory_notifier;
 pub mod port_factory_publish_subscribe;
 pub mod port_factory_publisher;
+pub mod port_factory_reader;
 pub mod port_factory_request_response;
 pub mod port_factory_server;
 pub mod port_factory_subscriber;
+pub mod port_factory_writer;
 pub mod publisher;
+pub mod rate_limiter;
+pub mod reader;
+pub mod response;
 pub mod sample;
 pub mod sample_mut;
 pub mod sample_mut_uninit;
 pub mod server;
 pub mod service;
 pub mod service_builder;
+pub mod service_builder_blackboard;
 pub mod service_builder_event;
 pub mod service_builder_publish_subscribe;
 pub mod service_builder_request_response;
 pub mod service_details;
 pub mod service_id;
+pub mod service_memory_footprint;
 pub mod service_name;
 pub mod service_type;
+pub mod shm_region;
 pub mod signal_handling_mode;
+pub mod static_config_blackboard;
 pub mod static_config_event;
 pub mod static_config_publish_subscribe;
 pub mod static_config_request_response;
 pub mod subscriber;
+pub mod termination_signal;
 pub mod testing;
+pub mod type_compatibility;
 pub mod type_detail;
 pub mod type_name;
 pub mod type_storage;
@@ -86,6 +107,7 @@ pub mod waitset_attachment_id;
 pub mod waitset_builder;
 pub mod waitset_guard;
 pub mod waitset_run_result;
+pub mod writer;
 
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
@@ -104,6 +126,7 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         crate::log::set_log_level_from_env_or_default
     ))?;
 
+    m.add_class::<crate::active_request::ActiveRequest>()?;
     m.add_class::<crate::alignment::Alignment>()?;
     m.add_class::<crate::allocation_strategy::AllocationStrategy>()?;
     m.add_class::<crate::attribute::Attribute>()?;
@@ -112,18 +135,24 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::attribute_specifier::AttributeSpecifier>()?;
     m.add_class::<crate::attribute_key::AttributeKey>()?;
     m.add_class::<crate::attribute_value::AttributeValue>()?;
+    m.add_class::<crate::callback_progression::CallbackProgression>()?;
     m.add_class::<crate::client::Client>()?;
     m.add_class::<crate::duration::Duration>()?;
+    m.add_class::<crate::dynamic_config_event::DynamicConfigEvent>()?;
+    m.add_class::<crate::dynamic_config_publish_subscribe::DynamicConfigPublishSubscribe>()?;
+    m.add_class::<crate::dynamic_config_request_response::DynamicConfigRequestResponse>()?;
     m.add_class::<crate::event_id::EventId>()?;
     m.add_class::<crate::file_name::FileName>()?;
     m.add_class::<crate::file_path::FilePath>()?;
     m.add_class::<crate::header_publish_subscribe::HeaderPublishSubscribe>()?;
     m.add_class::<crate::listener::Listener>()?;
     m.add_class::<crate::log_level::LogLevel>()?;
+    m.add_class::<crate::memory_usage::MemoryUsage>()?;
     m.add_class::<crate::messaging_pattern::MessagingPattern>()?;
     m.add_class::<crate::message_type_details::MessageTypeDetails>()?;
     m.add_class::<crate::node::Node>()?;
     m.add_class::<crate::node_builder::NodeBuilder>()?;
+    m.add_class::<crate::node_group::NodeGroup>()?;
     m.add_class::<crate::node_id::NodeId>()?;
     m.add_class::<crate::node_name::NodeName>()?;
     m.add_class::<crate::node_state::NodeState>()?;
@@ -132,34 +161,48 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::node_state::NodeDetails>()?;
     m.add_class::<crate::notifier::Notifier>()?;
     m.add_class::<crate::path::Path>()?;
+    m.add_class::<crate::pending_response::PendingResponse>()?;
+    m.add_class::<crate::port_factory_blackboard::PortFactoryBlackboard>()?;
     m.add_class::<crate::port_factory_client::PortFactoryClient>()?;
     m.add_class::<crate::port_factory_event::PortFactoryEvent>()?;
     m.add_class::<crate::port_factory_listener::PortFactoryListener>()?;
     m.add_class::<crate::port_factory_notifier::PortFactoryNotifier>()?;
     m.add_class::<crate::port_factory_publisher::PortFactoryPublisher>()?;
     m.add_class::<crate::port_factory_publish_subscribe::PortFactoryPublishSubscribe>()?;
+    m.add_class::<crate::port_factory_reader::PortFactoryReader>()?;
     m.add_class::<crate::port_factory_request_response::PortFactoryRequestResponse>()?;
     m.add_class::<crate::port_factory_server::PortFactoryServer>()?;
     m.add_class::<crate::port_factory_subscriber::PortFactorySubscriber>()?;
+    m.add_class::<crate::port_factory_writer::PortFactoryWriter>()?;
     m.add_class::<crate::publisher::Publisher>()?;
+    m.add_class::<crate::reader::Reader>()?;
+    m.add_class::<crate::response::Response>()?;
     m.add_class::<crate::sample::Sample>()?;
     m.add_class::<crate::sample_mut::SampleMut>()?;
     m.add_class::<crate::sample_mut_uninit::SampleMutUninit>()?;
     m.add_class::<crate::server::Server>()?;
+    m.add_class::<crate::server::RequestIterator>()?;
     m.add_class::<crate::service::Service>()?;
     m.add_class::<crate::service_builder::ServiceBuilder>()?;
+    m.add_class::<crate::service_builder_blackboard::ServiceBuilderBlackboard>()?;
     m.add_class::<crate::service_builder_event::ServiceBuilderEvent>()?;
     m.add_class::<crate::service_builder_publish_subscribe::ServiceBuilderPublishSubscribe>()?;
     m.add_class::<crate::service_builder_request_response::ServiceBuilderRequestResponse>()?;
     m.add_class::<crate::service_details::ServiceDetails>()?;
     m.add_class::<crate::service_id::ServiceId>()?;
+    m.add_class::<crate::service_memory_footprint::ServiceMemoryFootprint>()?;
     m.add_class::<crate::service_name::ServiceName>()?;
     m.add_class::<crate::service_type::ServiceType>()?;
+    m.add_class::<crate::shm_region::SharedMemoryRegion>()?;
+    m.add_class::<crate::shm_region::ShmUsageStats>()?;
     m.add_class::<crate::signal_handling_mode::SignalHandlingMode>()?;
+    m.add_class::<crate::static_config_blackboard::StaticConfigBlackboard>()?;
     m.add_class::<crate::static_config_event::StaticConfigEvent>()?;
     m.add_class::<crate::static_config_publish_subscribe::StaticConfigPublishSubscribe>()?;
     m.add_class::<crate::static_config_request_response::StaticConfigRequestResponse>()?;
     m.add_class::<crate::subscriber::Subscriber>()?;
+    m.add_class::<crate::termination_signal::TerminationSignal>()?;
+    m.add_class::<crate::type_compatibility::TypeCompatibility>()?;
     m.add_class::<crate::type_detail::TypeDetail>()?;
     m.add_class::<crate::type_variant::TypeVariant>()?;
     m.add_class::<crate::type_name::TypeName>()?;
@@ -175,11 +218,36 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::waitset_builder::WaitSetBuilder>()?;
     m.add_class::<crate::waitset_guard::WaitSetGuard>()?;
     m.add_class::<crate::waitset_run_result::WaitSetRunResult>()?;
+    m.add_class::<crate::writer::Writer>()?;
 
+    m.add(
+        "BlackboardOpenError",
+        py.get_type::<crate::error::BlackboardOpenError>(),
+    )?;
+    m.add(
+        "BlackboardOpenExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::BlackboardOpenExceedsMaxNumberOfNodesError>(),
+    )?;
+    m.add(
+        "BlackboardCreateError",
+        py.get_type::<crate::error::BlackboardCreateError>(),
+    )?;
+    m.add(
+        "BlackboardOpenOrCreateError",
+        py.get_type::<crate::error::BlackboardOpenOrCreateError>(),
+    )?;
+    m.add(
+        "BlackboardOpenOrCreateExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::BlackboardOpenOrCreateExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "ClientCreateError",
         py.get_type::<crate::error::ClientCreateError>(),
     )?;
+    m.add(
+        "ClockDomainError",
+        py.get_type::<crate::error::ClockDomainError>(),
+    )?;
     m.add(
         "ConfigCreationError",
         py.get_type::<crate::error::ConfigCreationError>(),
@@ -192,6 +260,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "EventOpenError",
         py.get_type::<crate::error::EventOpenError>(),
     )?;
+    m.add(
+        "EventOpenExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::EventOpenExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "EventCreateError",
         py.get_type::<crate::error::EventCreateError>(),
@@ -200,10 +272,22 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "EventOpenOrCreateError",
         py.get_type::<crate::error::EventOpenOrCreateError>(),
     )?;
+    m.add(
+        "EventOpenOrCreateExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::EventOpenOrCreateExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "InvalidAlignmentValue",
         py.get_type::<crate::error::InvalidAlignmentValue>(),
     )?;
+    m.add(
+        "InvalidDirectory",
+        py.get_type::<crate::error::InvalidDirectory>(),
+    )?;
+    m.add(
+        "InvalidAllocationStrategyValue",
+        py.get_type::<crate::error::InvalidAllocationStrategyValue>(),
+    )?;
     m.add("LoanError", py.get_type::<crate::error::LoanError>())?;
     m.add(
         "ListenerCreateError",
@@ -213,6 +297,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "ListenerWaitError",
         py.get_type::<crate::error::ListenerWaitError>(),
     )?;
+    m.add(
+        "ListenerDeadlineError",
+        py.get_type::<crate::error::ListenerDeadlineError>(),
+    )?;
     m.add(
         "NodeCreationFailure",
         py.get_type::<crate::error::NodeCreationFailure>(),
@@ -229,6 +317,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "NodeWaitFailure",
         py.get_type::<crate::error::NodeWaitFailure>(),
     )?;
+    m.add(
+        "NodeSignalShutdownFailure",
+        py.get_type::<crate::error::NodeSignalShutdownFailure>(),
+    )?;
     m.add(
         "NotifierCreateError",
         py.get_type::<crate::error::NotifierCreateError>(),
@@ -238,6 +330,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         py.get_type::<crate::error::NotifierNotifyError>(),
     )?;
     m.add("SendError", py.get_type::<crate::error::SendError>())?;
+    m.add(
+        "RateLimitedError",
+        py.get_type::<crate::error::RateLimitedError>(),
+    )?;
     m.add(
         "SemanticStringError",
         py.get_type::<crate::error::SemanticStringError>(),
@@ -246,10 +342,18 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "PublisherCreateError",
         py.get_type::<crate::error::PublisherCreateError>(),
     )?;
+    m.add(
+        "PublisherSetHistorySizeError",
+        py.get_type::<crate::error::PublisherSetHistorySizeError>(),
+    )?;
     m.add(
         "PublishSubscribeOpenError",
         py.get_type::<crate::error::PublishSubscribeOpenError>(),
     )?;
+    m.add(
+        "PublishSubscribeOpenExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::PublishSubscribeOpenExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "PublishSubscribeCreateError",
         py.get_type::<crate::error::PublishSubscribeCreateError>(),
@@ -258,11 +362,27 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "PublishSubscribeOpenOrCreateError",
         py.get_type::<crate::error::PublishSubscribeOpenOrCreateError>(),
     )?;
+    m.add(
+        "PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add("ReceiveError", py.get_type::<crate::error::ReceiveError>())?;
+    m.add(
+        "ReaderCreateError",
+        py.get_type::<crate::error::ReaderCreateError>(),
+    )?;
+    m.add(
+        "ReaderReadError",
+        py.get_type::<crate::error::ReaderReadError>(),
+    )?;
     m.add(
         "RequestResponseOpenError",
         py.get_type::<crate::error::RequestResponseOpenError>(),
     )?;
+    m.add(
+        "RequestResponseOpenExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::RequestResponseOpenExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "RequestResponseCreateError",
         py.get_type::<crate::error::RequestResponseCreateError>(),
@@ -271,6 +391,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "RequestResponseOpenOrCreateError",
         py.get_type::<crate::error::RequestResponseOpenOrCreateError>(),
     )?;
+    m.add(
+        "RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError",
+        py.get_type::<crate::error::RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError>(),
+    )?;
     m.add(
         "ServerCreateError",
         py.get_type::<crate::error::ServerCreateError>(),
@@ -283,6 +407,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "SubscriberCreateError",
         py.get_type::<crate::error::SubscriberCreateError>(),
     )?;
+    m.add(
+        "SubscriberMarkReceivedError",
+        py.get_type::<crate::error::SubscriberMarkReceivedError>(),
+    )?;
     m.add(
         "WaitSetAttachmentError",
         py.get_type::<crate::error::WaitSetAttachmentError>(),
@@ -295,6 +423,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "WaitSetRunError",
         py.get_type::<crate::error::WaitSetRunError>(),
     )?;
+    m.add(
+        "WriterWriteError",
+        py.get_type::<crate::error::WriterWriteError>(),
+    )?;
 
     Ok(())
 }
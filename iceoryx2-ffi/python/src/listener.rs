@@ -12,18 +12,21 @@
 
 use std::sync::Arc;
 
+use iceoryx2_bb_log::fatal_panic;
 use pyo3::prelude::*;
 
 use crate::{
-    duration::Duration, error::ListenerWaitError, event_id::EventId,
+    duration::Duration,
+    error::{ListenerDeadlineError, ListenerWaitError},
+    event_id::EventId,
     unique_listener_id::UniqueListenerId,
 };
 
 #[allow(clippy::large_enum_variant)] // used purely for python and there it will reside always in
                                      // the heap
 pub(crate) enum ListenerType {
-    Ipc(Arc<iceoryx2::port::listener::Listener<crate::IpcService>>),
-    Local(Arc<iceoryx2::port::listener::Listener<crate::LocalService>>),
+    Ipc(Option<Arc<iceoryx2::port::listener::Listener<crate::IpcService>>>),
+    Local(Option<Arc<iceoryx2::port::listener::Listener<crate::LocalService>>>),
 }
 
 #[pyclass]
@@ -36,23 +39,43 @@ impl Listener {
     /// Returns the deadline of the corresponding `Service`.
     pub fn deadline(&self) -> Option<Duration> {
         match &self.0 {
-            ListenerType::Ipc(v) => v.deadline().map(Duration),
-            ListenerType::Local(v) => v.deadline().map(Duration),
+            ListenerType::Ipc(Some(v)) => v.deadline().map(Duration),
+            ListenerType::Local(Some(v)) => v.deadline().map(Duration),
+            _ => fatal_panic!(from "Listener::deadline()",
+                "Accessing a released Listener."),
         }
     }
 
-    /// Non-blocking wait for a new `EventId`. If no `EventId` was notified it returns `None`.
+    /// Returns `True` when the `Service` has a deadline and no notification was received within
+    /// it, otherwise `False`. On error it emits `ListenerDeadlineError`.
+    pub fn has_missed_deadline(&self) -> PyResult<bool> {
+        match &self.0 {
+            ListenerType::Ipc(Some(v)) => v
+                .has_missed_deadline()
+                .map_err(|e| ListenerDeadlineError::new_err(format!("{e:?}"))),
+            ListenerType::Local(Some(v)) => v
+                .has_missed_deadline()
+                .map_err(|e| ListenerDeadlineError::new_err(format!("{e:?}"))),
+            _ => fatal_panic!(from "Listener::has_missed_deadline()",
+                "Accessing a released Listener."),
+        }
+    }
+
+    /// Non-blocking wait for a new `EventId`. If no `EventId` was notified it returns `None`
+    /// without emitting an exception, so that it can be cheaply called in a polling loop.
     /// On error it emits `ListenerWaitError`.
     pub fn try_wait_one(&self) -> PyResult<Option<EventId>> {
         match &self.0 {
-            ListenerType::Ipc(v) => Ok(v
+            ListenerType::Ipc(Some(v)) => Ok(v
                 .try_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
-            ListenerType::Local(v) => Ok(v
+            ListenerType::Local(Some(v)) => Ok(v
                 .try_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
+            _ => fatal_panic!(from "Listener::try_wait_one()",
+                "Accessing a released Listener."),
         }
     }
 
@@ -61,14 +84,16 @@ impl Listener {
     /// On error it emits `ListenerWaitError`.
     pub fn timed_wait_one(&self, timeout: &Duration) -> PyResult<Option<EventId>> {
         match &self.0 {
-            ListenerType::Ipc(v) => Ok(v
+            ListenerType::Ipc(Some(v)) => Ok(v
                 .timed_wait_one(timeout.0)
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
-            ListenerType::Local(v) => Ok(v
+            ListenerType::Local(Some(v)) => Ok(v
                 .timed_wait_one(timeout.0)
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
+            _ => fatal_panic!(from "Listener::timed_wait_one()",
+                "Accessing a released Listener."),
         }
     }
 
@@ -77,14 +102,16 @@ impl Listener {
     /// On error it emits `ListenerWaitError`.
     pub fn blocking_wait_one(&self) -> PyResult<Option<EventId>> {
         match &self.0 {
-            ListenerType::Ipc(v) => Ok(v
+            ListenerType::Ipc(Some(v)) => Ok(v
                 .blocking_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
-            ListenerType::Local(v) => Ok(v
+            ListenerType::Local(Some(v)) => Ok(v
                 .blocking_wait_one()
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
                 .map(EventId)),
+            _ => fatal_panic!(from "Listener::blocking_wait_one()",
+                "Accessing a released Listener."),
         }
     }
 
@@ -94,12 +121,14 @@ impl Listener {
     pub fn try_wait_all(&self) -> PyResult<Vec<EventId>> {
         let mut event_ids = vec![];
         match &self.0 {
-            ListenerType::Ipc(v) => v
+            ListenerType::Ipc(Some(v)) => v
                 .try_wait_all(|e| event_ids.push(EventId(e)))
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
-            ListenerType::Local(v) => v
+            ListenerType::Local(Some(v)) => v
                 .try_wait_all(|e| event_ids.push(EventId(e)))
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
+            _ => fatal_panic!(from "Listener::try_wait_all()",
+                "Accessing a released Listener."),
         }
 
         Ok(event_ids)
@@ -112,12 +141,14 @@ impl Listener {
     pub fn timed_wait_all(&self, timeout: &Duration) -> PyResult<Vec<EventId>> {
         let mut event_ids = vec![];
         match &self.0 {
-            ListenerType::Ipc(v) => v
+            ListenerType::Ipc(Some(v)) => v
                 .timed_wait_all(|e| event_ids.push(EventId(e)), timeout.0)
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
-            ListenerType::Local(v) => v
+            ListenerType::Local(Some(v)) => v
                 .timed_wait_all(|e| event_ids.push(EventId(e)), timeout.0)
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
+            _ => fatal_panic!(from "Listener::timed_wait_all()",
+                "Accessing a released Listener."),
         }
 
         Ok(event_ids)
@@ -130,23 +161,99 @@ impl Listener {
     pub fn blocking_wait_all(&self) -> PyResult<Vec<EventId>> {
         let mut event_ids = vec![];
         match &self.0 {
-            ListenerType::Ipc(v) => v
+            ListenerType::Ipc(Some(v)) => v
                 .blocking_wait_all(|e| event_ids.push(EventId(e)))
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
-            ListenerType::Local(v) => v
+            ListenerType::Local(Some(v)) => v
                 .blocking_wait_all(|e| event_ids.push(EventId(e)))
                 .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
+            _ => fatal_panic!(from "Listener::blocking_wait_all()",
+                "Accessing a released Listener."),
         }
 
         Ok(event_ids)
     }
 
+    #[pyo3(signature = (max_count = None))]
+    /// Non-blocking drain of the received `EventId`s. Returns the values of up to `max_count`
+    /// `EventId`s in a single call or all currently available ones when `max_count` is not
+    /// provided. The GIL is released while the events are collected.
+    /// On error it emits `ListenerWaitError`.
+    pub fn receive_all_events(
+        &self,
+        py: Python<'_>,
+        max_count: Option<usize>,
+    ) -> PyResult<Vec<usize>> {
+        py.allow_threads(|| match &self.0 {
+            ListenerType::Ipc(Some(v)) => receive_all_events(v, max_count),
+            ListenerType::Local(Some(v)) => receive_all_events(v, max_count),
+            _ => fatal_panic!(from "Listener::receive_all_events()",
+                "Accessing a released Listener."),
+        })
+    }
+
+    /// Releases the `Listener`.
+    ///
+    /// After this call the `Listener` is no longer usable!
+    pub fn delete(&mut self) {
+        match self.0 {
+            ListenerType::Ipc(ref mut v) => {
+                v.take();
+            }
+            ListenerType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+
     #[getter]
     /// Returns the `UniqueListenerId` of the `Listener`
     pub fn id(&self) -> UniqueListenerId {
         match &self.0 {
-            ListenerType::Ipc(v) => UniqueListenerId(v.id()),
-            ListenerType::Local(v) => UniqueListenerId(v.id()),
+            ListenerType::Ipc(Some(v)) => UniqueListenerId(v.id()),
+            ListenerType::Local(Some(v)) => UniqueListenerId(v.id()),
+            _ => fatal_panic!(from "Listener::id()",
+                "Accessing a released Listener."),
         }
     }
+    /// Returns the `Listener` itself so that it can be used as context manager.
+    pub fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Releases the `Listener` when leaving the `with` block. Calling it multiple times has
+    /// no further effect.
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) {
+        self.delete();
+    }
+}
+
+fn receive_all_events<S: iceoryx2::service::Service>(
+    listener: &iceoryx2::port::listener::Listener<S>,
+    max_count: Option<usize>,
+) -> PyResult<Vec<usize>> {
+    let mut event_ids = vec![];
+    match max_count {
+        None => listener
+            .try_wait_all(|e| event_ids.push(e.as_value()))
+            .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?,
+        Some(max_count) => {
+            while event_ids.len() < max_count {
+                match listener
+                    .try_wait_one()
+                    .map_err(|e| ListenerWaitError::new_err(format!("{e:?}")))?
+                {
+                    Some(e) => event_ids.push(e.as_value()),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    Ok(event_ids)
 }
@@ -0,0 +1,56 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::prelude::*;
+
+#[pyclass(str = "{0:?}", eq)]
+#[derive(PartialEq, Eq)]
+/// The shared memory usage of the data segments of all `Publisher`s of a `Service`.
+pub struct MemoryUsage(
+    pub(crate) iceoryx2::service::dynamic_config::publish_subscribe::MemoryUsage,
+);
+
+#[pymethods]
+impl MemoryUsage {
+    #[getter]
+    /// Returns the number of bytes of all chunks that are currently allocated, meaning they
+    /// are either loaned, in delivery or held by a `Subscriber`.
+    pub fn allocated_bytes(&self) -> u64 {
+        self.0.allocated_bytes
+    }
+
+    #[getter]
+    /// Returns the number of chunks that are currently allocated.
+    pub fn number_of_chunks(&self) -> u64 {
+        self.0.number_of_chunks
+    }
+
+    #[getter]
+    /// Returns the sum of the highest number of bytes every `Publisher` had allocated at the
+    /// same time.
+    pub fn allocated_bytes_high_water_mark(&self) -> u64 {
+        self.0.allocated_bytes_high_water_mark
+    }
+
+    #[getter]
+    /// Returns the sum of the highest number of chunks every `Publisher` had allocated at the
+    /// same time.
+    pub fn number_of_chunks_high_water_mark(&self) -> u64 {
+        self.0.number_of_chunks_high_water_mark
+    }
+
+    #[getter]
+    /// Returns the number of bytes the current data segments of all `Publisher`s can provide.
+    pub fn data_segment_size(&self) -> u64 {
+        self.0.data_segment_size
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     cleanup_state::CleanupState,
     config::Config,
     duration::Duration,
-    error::{NodeListFailure, NodeWaitFailure},
+    error::{NodeListFailure, NodeSignalShutdownFailure, NodeWaitFailure},
     node_id::NodeId,
     node_name::NodeName,
     node_state::{AliveNodeView, AliveNodeViewType, DeadNodeView, DeadNodeViewType, NodeState},
@@ -24,7 +24,9 @@ use crate::{
     service_builder::{ServiceBuilder, ServiceBuilderType},
     service_name::ServiceName,
     service_type::ServiceType,
+    shm_region::SharedMemoryRegion,
     signal_handling_mode::SignalHandlingMode,
+    termination_signal::TerminationSignal,
 };
 
 pub(crate) enum NodeType {
@@ -155,6 +157,44 @@ impl Node {
         Ok(())
     }
 
+    /// Blocks until the termination signal `SIGTERM`, `SIGINT` or `SIGHUP` was received and
+    /// returns it as `TerminationSignal`. The signals are captured by the iceoryx2 signal
+    /// handler, starting with the first call, so that a `WaitSet` or `Node.wait()` still
+    /// observe them. The GIL is released while waiting.
+    /// On failure it emits a `NodeWaitFailure`.
+    pub fn wait_for_termination_signal(&self, py: Python<'_>) -> PyResult<TerminationSignal> {
+        py.allow_threads(crate::termination_signal::wait_for_termination_signal)
+            .map_err(NodeWaitFailure::new_err)
+    }
+
+    /// Requests a shutdown of all `Node`s that share the same `Config`. Every `Node` that
+    /// was created with `NodeBuilder.handle_shutdown_requests(True)` emits `NodeWaitFailure`
+    /// on its next `Node.wait()` call.
+    /// On failure it emits a `NodeSignalShutdownFailure`.
+    pub fn signal_shutdown(&self) -> PyResult<()> {
+        match &*self.0.lock() {
+            NodeType::Ipc(node) => node
+                .signal_shutdown()
+                .map_err(|e| NodeSignalShutdownFailure::new_err(format!("{e:?}")))?,
+            NodeType::Local(node) => node
+                .signal_shutdown()
+                .map_err(|e| NodeSignalShutdownFailure::new_err(format!("{e:?}")))?,
+        };
+
+        Ok(())
+    }
+
+    /// Returns a list of all `SharedMemoryRegion`s the `Publisher`s, `Client`s and `Server`s
+    /// of the `Node` allocated for their samples, requests and responses.
+    pub fn shm_regions(&self) -> Vec<SharedMemoryRegion> {
+        let regions = match &*self.0.lock() {
+            NodeType::Ipc(node) => node.shm_regions(),
+            NodeType::Local(node) => node.shm_regions(),
+        };
+
+        regions.into_iter().map(SharedMemoryRegion).collect()
+    }
+
     #[getter]
     /// Returns the `SignalHandlingMode` with which the `Node` was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
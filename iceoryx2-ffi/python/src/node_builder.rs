@@ -25,7 +25,7 @@ use crate::{
 #[derive(Default)]
 #[pyclass(str = "{0:?}")]
 /// Creates a new `Node`.
-pub struct NodeBuilder(iceoryx2::prelude::NodeBuilder);
+pub struct NodeBuilder(pub(crate) iceoryx2::prelude::NodeBuilder);
 
 #[pymethods]
 impl NodeBuilder {
@@ -52,6 +52,15 @@ impl NodeBuilder {
         Self(this)
     }
 
+    /// Defines if the `Node` observes shutdown requests that are sent with
+    /// `Node.signal_shutdown()` by any `Node` that uses the same `Config`. When enabled,
+    /// `Node.wait()` emits `NodeWaitFailure` after a shutdown was requested.
+    pub fn handle_shutdown_requests(&mut self, value: bool) -> Self {
+        let this = self.0.clone();
+        let this = this.handle_shutdown_requests(value);
+        Self(this)
+    }
+
     /// The `Config` that shall be used for the `Node`. If no `Config`
     /// is specified the `config.global_config()` is used.
     pub fn config(&mut self, config: &Config) -> Self {
@@ -0,0 +1,189 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+
+use crate::{
+    config::Config,
+    duration::Duration,
+    error::{NodeCreationFailure, NodeWaitFailure},
+    node_builder::NodeBuilder,
+    node_id::NodeId,
+    parc::Parc,
+    service_builder::{ServiceBuilder, ServiceBuilderType},
+    service_name::ServiceName,
+    service_type::ServiceType,
+};
+
+pub(crate) enum NodeGroupType {
+    Ipc(iceoryx2::node::node_group::NodeGroup<crate::IpcService>),
+    Local(iceoryx2::node::node_group::NodeGroup<crate::LocalService>),
+}
+
+#[pyclass]
+/// Groups multiple `Node`s with a shared lifecycle. The `Node`s are added as
+/// `NodeBuilder`s, are all created with `NodeGroup.start_all()` and all released with
+/// `NodeGroup.stop_all()`. `Node`s of other processes can be watched with
+/// `NodeGroup.watch_node()`. When a watched `Node` dies, its stale resources are removed
+/// so that it is also removed from all services the other members opened.
+/// When used as context manager, all `Node`s are started when entering and stopped when
+/// leaving the `with` block.
+pub struct NodeGroup(pub(crate) Parc<NodeGroupType>);
+
+#[pymethods]
+impl NodeGroup {
+    #[staticmethod]
+    /// Creates a new empty `NodeGroup` for the provided `ServiceType` whose `Node`s use the
+    /// provided `Config`.
+    pub fn new(service_type: &ServiceType, config: &Config) -> Self {
+        let config = config.0.lock();
+        match service_type {
+            ServiceType::Ipc => Self(Parc::new(NodeGroupType::Ipc(
+                iceoryx2::node::node_group::NodeGroup::new(&config),
+            ))),
+            ServiceType::Local => Self(Parc::new(NodeGroupType::Local(
+                iceoryx2::node::node_group::NodeGroup::new(&config),
+            ))),
+        }
+    }
+
+    /// Adds a `Node` that is created with the next `NodeGroup.start_all()` call. The
+    /// `Config` of the `NodeBuilder` is replaced with the `Config` of the group.
+    pub fn add_node(&self, builder: &NodeBuilder) {
+        match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.add_node(builder.0.clone()),
+            NodeGroupType::Local(v) => v.add_node(builder.0.clone()),
+        }
+    }
+
+    /// Adds the `Node` with the provided `NodeId`, usually owned by another process, to the
+    /// members that are observed by `NodeGroup.wait_any_terminated()` and
+    /// `NodeGroup.wait_all_terminated()`.
+    pub fn watch_node(&self, node_id: &NodeId) {
+        match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.watch_node(node_id.0),
+            NodeGroupType::Local(v) => v.watch_node(node_id.0),
+        }
+    }
+
+    /// Returns the number of `Node`s that were added with `NodeGroup.add_node()`.
+    pub fn __len__(&self) -> usize {
+        match &*self.0.lock() {
+            NodeGroupType::Ipc(v) => v.len(),
+            NodeGroupType::Local(v) => v.len(),
+        }
+    }
+
+    #[getter]
+    /// Returns true when all added `Node`s are running.
+    pub fn is_running(&self) -> bool {
+        match &*self.0.lock() {
+            NodeGroupType::Ipc(v) => v.is_running(),
+            NodeGroupType::Local(v) => v.is_running(),
+        }
+    }
+
+    #[getter]
+    /// Returns the `NodeId`s of all running `Node`s followed by the `NodeId`s of all watched
+    /// `Node`s that have not yet terminated.
+    pub fn member_ids(&self) -> Vec<NodeId> {
+        let ids = match &*self.0.lock() {
+            NodeGroupType::Ipc(v) => v.member_ids(),
+            NodeGroupType::Local(v) => v.member_ids(),
+        };
+
+        ids.into_iter().map(NodeId).collect()
+    }
+
+    /// Creates all `Node`s that are not yet running. When one `Node` cannot be created, all
+    /// `Node`s that were created in this call are released again.
+    /// Emits `NodeCreationFailure` on failure.
+    pub fn start_all(&self) -> PyResult<()> {
+        match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.start_all(),
+            NodeGroupType::Local(v) => v.start_all(),
+        }
+        .map_err(|e| NodeCreationFailure::new_err(format!("{e:?}")))
+    }
+
+    /// Releases all running `Node`s in the reverse order of their creation.
+    pub fn stop_all(&self) {
+        match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.stop_all(),
+            NodeGroupType::Local(v) => v.stop_all(),
+        }
+    }
+
+    /// Blocks until at least one member terminated and returns the `NodeId`s of all
+    /// terminated members. The members are checked once per `cycle_time`.
+    /// Emits `NodeWaitFailure` when a termination or shutdown request was received.
+    pub fn wait_any_terminated(&self, cycle_time: &Duration) -> PyResult<Vec<NodeId>> {
+        let ids = match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.wait_any_terminated(cycle_time.0),
+            NodeGroupType::Local(v) => v.wait_any_terminated(cycle_time.0),
+        }
+        .map_err(|e| NodeWaitFailure::new_err(format!("{e:?}")))?;
+
+        Ok(ids.into_iter().map(NodeId).collect())
+    }
+
+    /// Blocks until all members terminated. Since the running `Node`s of the group are
+    /// members as well, it only returns successfully when the group is stopped.
+    /// Emits `NodeWaitFailure` when a termination or shutdown request was received.
+    pub fn wait_all_terminated(&self, cycle_time: &Duration) -> PyResult<()> {
+        match &mut *self.0.lock() {
+            NodeGroupType::Ipc(v) => v.wait_all_terminated(cycle_time.0),
+            NodeGroupType::Local(v) => v.wait_all_terminated(cycle_time.0),
+        }
+        .map_err(|e| NodeWaitFailure::new_err(format!("{e:?}")))
+    }
+
+    /// Instantiates a `ServiceBuilder` for a service with the provided name from the running
+    /// `Node` with the provided index. The index corresponds to the order in which the
+    /// `Node`s were added. Emits `IndexError` when no such `Node` is running.
+    pub fn service_builder(&self, index: usize, name: &ServiceName) -> PyResult<ServiceBuilder> {
+        let out_of_range =
+            || PyIndexError::new_err(format!("No running node with the index {index}."));
+        match &*self.0.lock() {
+            NodeGroupType::Ipc(v) => match v.nodes().get(index) {
+                Some(node) => Ok(ServiceBuilder(ServiceBuilderType::Ipc(
+                    node.service_builder(&name.0),
+                ))),
+                None => Err(out_of_range()),
+            },
+            NodeGroupType::Local(v) => match v.nodes().get(index) {
+                Some(node) => Ok(ServiceBuilder(ServiceBuilderType::Local(
+                    node.service_builder(&name.0),
+                ))),
+                None => Err(out_of_range()),
+            },
+        }
+    }
+
+    /// Starts all `Node`s and returns the `NodeGroup` itself so that it can be used as
+    /// context manager. Emits `NodeCreationFailure` on failure.
+    pub fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.start_all()?;
+        Ok(slf)
+    }
+
+    /// Stops all `Node`s when leaving the `with` block.
+    pub fn __exit__(
+        &self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) {
+        self.stop_all();
+    }
+}
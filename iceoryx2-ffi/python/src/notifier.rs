@@ -10,39 +10,77 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use pyo3::prelude::*;
+use std::collections::HashSet;
+
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 use crate::{
-    duration::Duration, error::NotifierNotifyError, event_id::EventId,
-    unique_notifier_id::UniqueNotifierId,
+    duration::Duration, error::NotifierNotifyError, event_id::EventId, parc::Parc,
+    port_factory_event::PortFactoryEventType, unique_notifier_id::UniqueNotifierId,
 };
 
 pub(crate) enum NotifierType {
-    Ipc(iceoryx2::port::notifier::Notifier<crate::IpcService>),
-    Local(iceoryx2::port::notifier::Notifier<crate::LocalService>),
+    Ipc(Option<iceoryx2::port::notifier::Notifier<crate::IpcService>>),
+    Local(Option<iceoryx2::port::notifier::Notifier<crate::LocalService>>),
 }
 
 #[pyclass]
 /// Represents the sending endpoint of an event based communication.
-pub struct Notifier(pub(crate) NotifierType);
+pub struct Notifier {
+    pub(crate) value: NotifierType,
+    // the service is required to create a `Listener` when the `Notifier` is added as guard to
+    // a `WaitSet`
+    pub(crate) factory: Parc<PortFactoryEventType>,
+}
 
 #[pymethods]
 impl Notifier {
     #[getter]
     /// Returns the `UniqueNotifierId` of the `Notifier`
     pub fn id(&self) -> UniqueNotifierId {
-        match &self.0 {
-            NotifierType::Ipc(v) => UniqueNotifierId(v.id()),
-            NotifierType::Local(v) => UniqueNotifierId(v.id()),
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => UniqueNotifierId(v.id()),
+            NotifierType::Local(Some(v)) => UniqueNotifierId(v.id()),
+            _ => fatal_panic!(from "Notifier::id()",
+                "Accessing a released Notifier."),
+        }
+    }
+
+    #[getter]
+    /// Returns the `EventId` that is used by `Notifier.notify()`. If no default `EventId`
+    /// was provided on creation it is `EventId.new(0)`.
+    pub fn default_event_id(&self) -> EventId {
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => EventId(v.default_event_id()),
+            NotifierType::Local(Some(v)) => EventId(v.default_event_id()),
+            _ => fatal_panic!(from "Notifier::default_event_id()",
+                "Accessing a released Notifier."),
         }
     }
 
     #[getter]
     /// Returns the deadline of the corresponding `Service`.
     pub fn deadline(&self) -> Option<Duration> {
-        match &self.0 {
-            NotifierType::Ipc(v) => v.deadline().map(Duration),
-            NotifierType::Local(v) => v.deadline().map(Duration),
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => v.deadline().map(Duration),
+            NotifierType::Local(Some(v)) => v.deadline().map(Duration),
+            _ => fatal_panic!(from "Notifier::deadline()",
+                "Accessing a released Notifier."),
+        }
+    }
+
+    /// Releases the `Notifier`.
+    ///
+    /// After this call the `Notifier` is no longer usable!
+    pub fn delete(&mut self) {
+        match self.value {
+            NotifierType::Ipc(ref mut v) => {
+                v.take();
+            }
+            NotifierType::Local(ref mut v) => {
+                v.take();
+            }
         }
     }
 
@@ -51,27 +89,81 @@ impl Notifier {
     /// Returns on success the number of `Listener`s that were notified otherwise it emits
     /// `NotifierNotifyError`.
     pub fn notify(&self) -> PyResult<usize> {
-        match &self.0 {
-            NotifierType::Ipc(v) => Ok(v
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => Ok(v
                 .notify()
                 .map_err(|e| NotifierNotifyError::new_err(format!("{e:?}")))?),
-            NotifierType::Local(v) => Ok(v
+            NotifierType::Local(Some(v)) => Ok(v
                 .notify()
                 .map_err(|e| NotifierNotifyError::new_err(format!("{e:?}")))?),
+            _ => fatal_panic!(from "Notifier::notify()",
+                "Accessing a released Notifier."),
         }
     }
 
     /// Notifies all `Listener` connected to the service with a custom `EventId`.
     /// Returns on success the number of `Listener`s that were notified otherwise it returns
-    /// `NotifierNotifyError`.
+    /// `NotifierNotifyError`. If the `EventId` is greater than the `event_id_max_value` of the
+    /// `Service` it emits `ValueError`.
     pub fn notify_with_custom_event_id(&self, event_id: &EventId) -> PyResult<usize> {
-        match &self.0 {
-            NotifierType::Ipc(v) => Ok(v
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => Ok(v
                 .notify_with_custom_event_id(event_id.0)
-                .map_err(|e| NotifierNotifyError::new_err(format!("{e:?}")))?),
-            NotifierType::Local(v) => Ok(v
+                .map_err(notify_error)?),
+            NotifierType::Local(Some(v)) => Ok(v
                 .notify_with_custom_event_id(event_id.0)
-                .map_err(|e| NotifierNotifyError::new_err(format!("{e:?}")))?),
+                .map_err(notify_error)?),
+            _ => fatal_panic!(from "Notifier::notify_with_custom_event_id()",
+                "Accessing a released Notifier."),
+        }
+    }
+
+    /// Notifies all `Listener` connected to the service with all provided event ids as one
+    /// batch. Every `Listener` either receives all event ids or none of them.
+    /// Returns on success the number of `Listener`s that were notified otherwise it emits
+    /// `NotifierNotifyError`, also when a `Listener` cannot hold the whole batch. If one event
+    /// id is greater than the `event_id_max_value` of the `Service` nothing is delivered and
+    /// it emits `ValueError`.
+    pub fn notify_id_set(&self, ids: HashSet<usize>) -> PyResult<usize> {
+        let mut ids: Vec<_> = ids
+            .into_iter()
+            .map(iceoryx2::prelude::EventId::new)
+            .collect();
+        ids.sort();
+
+        match &self.value {
+            NotifierType::Ipc(Some(v)) => {
+                Ok(v.notify_with_custom_event_ids(&ids).map_err(notify_error)?)
+            }
+            NotifierType::Local(Some(v)) => {
+                Ok(v.notify_with_custom_event_ids(&ids).map_err(notify_error)?)
+            }
+            _ => fatal_panic!(from "Notifier::notify_id_set()",
+                "Accessing a released Notifier."),
+        }
+    }
+    /// Returns the `Notifier` itself so that it can be used as context manager.
+    pub fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Releases the `Notifier` when leaving the `with` block. Calling it multiple times has
+    /// no further effect.
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) {
+        self.delete();
+    }
+}
+
+fn notify_error(e: iceoryx2::port::notifier::NotifierNotifyError) -> PyErr {
+    match e {
+        iceoryx2::port::notifier::NotifierNotifyError::EventIdOutOfBounds => {
+            PyValueError::new_err(format!("{e:?}"))
         }
+        _ => NotifierNotifyError::new_err(format!("{e:?}")),
     }
 }
@@ -11,7 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::fmt::Debug;
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
 
 /// Internal helper struct to handle the Python memory model where everything
 /// which is shared - even accross thread boundaries - is just a pointer to
@@ -49,4 +49,23 @@ impl<T: Send> Parc<T> {
     pub fn lock(&self) -> MutexGuard<'_, T> {
         self.value.lock().unwrap()
     }
+
+    /// Creates a [`ParcWeak`] that refers to the same object without keeping it alive.
+    pub fn downgrade(&self) -> ParcWeak<T> {
+        ParcWeak {
+            value: Arc::downgrade(&self.value),
+        }
+    }
+}
+
+/// Non-owning counterpart of [`Parc`], acquired with [`Parc::downgrade()`].
+pub struct ParcWeak<T: Send> {
+    value: Weak<Mutex<T>>,
+}
+
+impl<T: Send> ParcWeak<T> {
+    /// Returns the [`Parc`] when the object is still alive, otherwise [`None`].
+    pub fn upgrade(&self) -> Option<Parc<T>> {
+        self.value.upgrade().map(|value| Parc { value })
+    }
 }
@@ -0,0 +1,257 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use pyo3::prelude::*;
+
+use crate::{
+    duration::Duration,
+    error::ReceiveError,
+    parc::Parc,
+    response::{Response, ResponseType},
+};
+
+type IpcPendingResponse = iceoryx2::pending_response::PendingResponse<
+    crate::IpcService,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+type LocalPendingResponse = iceoryx2::pending_response::PendingResponse<
+    crate::LocalService,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+    [CustomPayloadMarker],
+    CustomHeaderMarker,
+>;
+
+pub(crate) enum PendingResponseType {
+    Ipc(Option<IpcPendingResponse>),
+    Local(Option<LocalPendingResponse>),
+}
+
+#[pyclass]
+/// Represents an active connection to all `Server` that received the `RequestMut`. The
+/// `Client` can use it to receive the corresponding `Response`s.
+///
+/// As soon as it goes out of scope or is deleted, the connections are closed and the `Server`s
+/// are informed.
+pub struct PendingResponse {
+    pub(crate) value: Parc<PendingResponseType>,
+    pub(crate) response_header_size: usize,
+    deadline_miss_handler: Mutex<Option<Py<PyAny>>>,
+    has_handled_deadline_miss: IoxAtomicBool,
+}
+
+impl PendingResponse {
+    pub(crate) fn new(value: PendingResponseType, response_header_size: usize) -> Self {
+        Self {
+            value: Parc::new(value),
+            response_header_size,
+            deadline_miss_handler: Mutex::new(None),
+            has_handled_deadline_miss: IoxAtomicBool::new(false),
+        }
+    }
+
+    fn has_missed_deadline_impl(&self) -> bool {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.has_missed_deadline(),
+            PendingResponseType::Local(Some(v)) => v.has_missed_deadline(),
+            _ => fatal_panic!(from "PendingResponse::has_missed_deadline()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    pub(crate) fn handle_deadline_miss(&self, py: Python<'_>) -> PyResult<bool> {
+        if !self.has_missed_deadline_impl() {
+            return Ok(false);
+        }
+
+        let handler = match &*self.deadline_miss_handler.lock().unwrap() {
+            Some(handler) => handler.clone_ref(py),
+            None => return Ok(true),
+        };
+
+        if !self.has_handled_deadline_miss.swap(true, Ordering::Relaxed) {
+            handler.call0(py)?;
+        }
+
+        Ok(true)
+    }
+
+    fn receive_impl(&self) -> PyResult<Option<Response>> {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => Ok(unsafe {
+                v.receive_custom_payload()
+                    .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                    .map(|r| Response {
+                        value: Parc::new(ResponseType::Ipc(Some(r))),
+                        user_header_size: self.response_header_size,
+                    })
+            }),
+            PendingResponseType::Local(Some(v)) => Ok(unsafe {
+                v.receive_custom_payload()
+                    .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                    .map(|r| Response {
+                        value: Parc::new(ResponseType::Local(Some(r))),
+                        user_header_size: self.response_header_size,
+                    })
+            }),
+            _ => fatal_panic!(from "PendingResponse::receive()",
+                "Accessing a released pending response."),
+        }
+    }
+}
+
+#[pymethods]
+impl PendingResponse {
+    #[getter]
+    /// Returns `True` until the `ActiveRequest` on the `Server`s side is finished, indicating
+    /// that the `Server` will no longer send `Response`s. It also returns `False` when there
+    /// are no `Server`s.
+    pub fn is_connected(&self) -> bool {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.is_connected(),
+            PendingResponseType::Local(Some(v)) => v.is_connected(),
+            _ => fatal_panic!(from "PendingResponse::is_connected()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    #[getter]
+    /// Returns how many `Server`s received the corresponding `RequestMut` initially.
+    pub fn number_of_server_connections(&self) -> usize {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.number_of_server_connections(),
+            PendingResponseType::Local(Some(v)) => v.number_of_server_connections(),
+            _ => fatal_panic!(from "PendingResponse::number_of_server_connections()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    #[getter]
+    /// Returns the time of the monotonic clock in nanoseconds when the `Client` sent the
+    /// corresponding `RequestMut`.
+    pub fn timestamp_ns(&self) -> u64 {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.header().timestamp_ns(),
+            PendingResponseType::Local(Some(v)) => v.header().timestamp_ns(),
+            _ => fatal_panic!(from "PendingResponse::timestamp_ns()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    /// Returns the time in nanoseconds between sending the corresponding `RequestMut` and
+    /// sending the most recently received `Response`. Returns `None` as long as no `Response`
+    /// was received.
+    pub fn round_trip_latency_ns(&self) -> Option<u64> {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.round_trip_latency_ns(),
+            PendingResponseType::Local(Some(v)) => v.round_trip_latency_ns(),
+            _ => fatal_panic!(from "PendingResponse::round_trip_latency_ns()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    #[getter]
+    /// Returns the response deadline of the `Service`. If it is `None`, the `Service` has no
+    /// response deadline.
+    pub fn response_deadline(&self) -> Option<Duration> {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.response_deadline().map(Duration),
+            PendingResponseType::Local(Some(v)) => v.response_deadline().map(Duration),
+            _ => fatal_panic!(from "PendingResponse::response_deadline()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    /// Returns the remaining time until the response deadline is missed. Returns `None` when
+    /// the `Service` has no response deadline or when a `Response` was already received. The
+    /// `WaitSet::attach_response_deadline()` is armed with it.
+    pub fn time_until_deadline(&self) -> Option<Duration> {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.time_until_deadline().map(Duration),
+            PendingResponseType::Local(Some(v)) => v.time_until_deadline().map(Duration),
+            _ => fatal_panic!(from "PendingResponse::time_until_deadline()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    /// Returns `True` when the `Service` has a response deadline and no `Response` was sent
+    /// within the deadline, otherwise `False`. When the deadline is missed, the callback
+    /// registered with `PendingResponse::on_deadline_miss()` is called once.
+    pub fn has_missed_deadline(&self, py: Python<'_>) -> PyResult<bool> {
+        self.handle_deadline_miss(py)
+    }
+
+    /// Registers a callback that is called once as soon as `PendingResponse::has_missed_deadline()`
+    /// or `PendingResponse::receive()` detects that the response deadline was missed. When the
+    /// `PendingResponse` is attached with `WaitSet::attach_response_deadline()`, the `WaitSet`
+    /// calls it when the deadline fires, which allows to implement retry or failover logic
+    /// without busy polling.
+    pub fn on_deadline_miss(&self, callback: Py<PyAny>) {
+        *self.deadline_miss_handler.lock().unwrap() = Some(callback);
+        self.has_handled_deadline_miss
+            .store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `True` when a `Server` has sent a `Response` otherwise `False`.
+    pub fn has_response(&self) -> bool {
+        match &*self.value.lock() {
+            PendingResponseType::Ipc(Some(v)) => v.has_response(),
+            PendingResponseType::Local(Some(v)) => v.has_response(),
+            _ => fatal_panic!(from "PendingResponse::has_response()",
+                "Accessing a released pending response."),
+        }
+    }
+
+    /// Receives the next `Response` from one of the `Server`s that received the `RequestMut`.
+    /// If no `Response` is available, it returns `None`. A `Server` can send a stream of
+    /// `Response`s, so the `Client` can call it repeatedly until no `Response` is returned and
+    /// `PendingResponse::is_connected` is `False`.
+    /// If a failure occurs `ReceiveError` is emitted.
+    pub fn receive(&self, py: Python<'_>) -> PyResult<Option<Response>> {
+        let response = self.receive_impl()?;
+        self.handle_deadline_miss(py)?;
+        Ok(response)
+    }
+
+    /// Receives the next `Response` of the stream of `Response`s the `Server`s send for the
+    /// `RequestMut`. If no `Response` is available, it returns `None`. When it returns `None`
+    /// and `PendingResponse::is_connected` is `False`, all `Server`s have finished their
+    /// `ActiveRequest` and the stream has ended.
+    /// If a failure occurs `ReceiveError` is emitted.
+    pub fn receive_next(&self, py: Python<'_>) -> PyResult<Option<Response>> {
+        self.receive(py)
+    }
+
+    /// Releases the `PendingResponse` and signals the `Server`s that the `Client` is no
+    /// longer interested in `Response`s.
+    ///
+    /// After this call the `PendingResponse` is no longer usable!
+    pub fn delete(&mut self) {
+        match &mut *self.value.lock() {
+            PendingResponseType::Ipc(ref mut v) => {
+                v.take();
+            }
+            PendingResponseType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@
 use std::sync::Arc;
 
 use crate::error::ClientCreateError;
+use iceoryx2::prelude::PortFactory;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use pyo3::prelude::*;
 
@@ -153,19 +154,50 @@ impl PortFactoryClient {
 
     /// Creates a new `Client` or emits a `ClientCreateError` on failure.
     pub fn create(&self) -> PyResult<Client> {
-        let _guard = self.factory.lock();
+        let factory = self.factory.lock();
+        let static_config = match &*factory {
+            PortFactoryRequestResponseType::Ipc(v) => v.static_config().clone(),
+            PortFactoryRequestResponseType::Local(v) => v.static_config().clone(),
+        };
+        let request_payload_type_details =
+            static_config.request_message_type_details().payload.clone();
+        let request_header_size = static_config
+            .request_message_type_details()
+            .user_header
+            .size;
+        let response_header_size = static_config
+            .response_message_type_details()
+            .user_header
+            .size;
+        let does_support_fire_and_forget_requests =
+            static_config.does_support_fire_and_forget_requests();
+
         match &self.value {
             PortFactoryClientType::Ipc(v) => {
                 let this = unsafe { (*v.lock()).__internal_partial_clone() };
-                Ok(Client(ClientType::Ipc(Arc::new(this.create().map_err(
-                    |e| ClientCreateError::new_err(format!("{e:?}")),
-                )?))))
+                Ok(Client {
+                    value: ClientType::Ipc(Arc::new(
+                        this.create()
+                            .map_err(|e| ClientCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    request_payload_type_details,
+                    request_header_size,
+                    response_header_size,
+                    does_support_fire_and_forget_requests,
+                })
             }
             PortFactoryClientType::Local(v) => {
                 let this = unsafe { (*v.lock()).__internal_partial_clone() };
-                Ok(Client(ClientType::Local(Arc::new(this.create().map_err(
-                    |e| ClientCreateError::new_err(format!("{e:?}")),
-                )?))))
+                Ok(Client {
+                    value: ClientType::Local(Arc::new(
+                        this.create()
+                            .map_err(|e| ClientCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    request_payload_type_details,
+                    request_header_size,
+                    response_header_size,
+                    does_support_fire_and_forget_requests,
+                })
             }
         }
     }
@@ -15,6 +15,7 @@ use pyo3::prelude::*;
 
 use crate::{
     attribute_set::AttributeSet,
+    dynamic_config_event::DynamicConfigEvent,
     error::NodeListFailure,
     node_id::NodeId,
     node_state::{AliveNodeView, AliveNodeViewType, DeadNodeView, DeadNodeViewType, NodeState},
@@ -75,6 +76,16 @@ impl PortFactoryEvent {
         }
     }
 
+    /// Returns a snapshot of the `DynamicConfig` of the `Service`. It contains all ports that
+    /// are connected to the `Service` at the moment of the call and does not change when ports
+    /// are created or removed afterwards.
+    pub fn dynamic_config(&self) -> DynamicConfigEvent {
+        match &*self.0.lock() {
+            PortFactoryEventType::Ipc(v) => DynamicConfigEvent::new(v.dynamic_config()),
+            PortFactoryEventType::Local(v) => DynamicConfigEvent::new(v.dynamic_config()),
+        }
+    }
+
     #[getter]
     /// Returns a list of all `NodeState` of all the `Node`s which have opened the `Service`.
     pub fn nodes(&self) -> PyResult<Vec<NodeState>> {
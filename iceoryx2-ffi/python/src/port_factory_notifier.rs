@@ -78,15 +78,23 @@ impl PortFactoryNotifier {
         match &self.value {
             PortFactoryNotifierType::Ipc(v) => {
                 let this = v.clone();
-                Ok(Notifier(NotifierType::Ipc(this.create().map_err(|e| {
-                    NotifierCreateError::new_err(format!("{e:?}"))
-                })?)))
+                Ok(Notifier {
+                    value: NotifierType::Ipc(Some(
+                        this.create()
+                            .map_err(|e| NotifierCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    factory: self.factory.clone(),
+                })
             }
             PortFactoryNotifierType::Local(v) => {
                 let this = v.clone();
-                Ok(Notifier(NotifierType::Local(this.create().map_err(
-                    |e| NotifierCreateError::new_err(format!("{e:?}")),
-                )?)))
+                Ok(Notifier {
+                    value: NotifierType::Local(Some(
+                        this.create()
+                            .map_err(|e| NotifierCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    factory: self.factory.clone(),
+                })
             }
         }
     }
@@ -15,7 +15,9 @@ use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use pyo3::prelude::*;
 
 use crate::attribute_set::AttributeSet;
+use crate::dynamic_config_publish_subscribe::DynamicConfigPublishSubscribe;
 use crate::error::NodeListFailure;
+use crate::memory_usage::MemoryUsage;
 use crate::node_id::NodeId;
 use crate::node_state::{
     AliveNodeView, AliveNodeViewType, DeadNodeView, DeadNodeViewType, NodeState,
@@ -111,6 +113,29 @@ impl PortFactoryPublishSubscribe {
         }
     }
 
+    /// Returns a snapshot of the `DynamicConfig` of the `Service`. It contains all ports that
+    /// are connected to the `Service` at the moment of the call and does not change when ports
+    /// are created or removed afterwards.
+    pub fn dynamic_config(&self) -> DynamicConfigPublishSubscribe {
+        match &*self.value.lock() {
+            PortFactoryPublishSubscribeType::Ipc(v) => {
+                DynamicConfigPublishSubscribe::new(v.dynamic_config())
+            }
+            PortFactoryPublishSubscribeType::Local(v) => {
+                DynamicConfigPublishSubscribe::new(v.dynamic_config())
+            }
+        }
+    }
+
+    /// Returns the current shared memory usage of the data segments of all `Publisher`s of
+    /// the `Service`.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        match &*self.value.lock() {
+            PortFactoryPublishSubscribeType::Ipc(v) => MemoryUsage(v.memory_usage()),
+            PortFactoryPublishSubscribeType::Local(v) => MemoryUsage(v.memory_usage()),
+        }
+    }
+
     #[getter]
     /// Returns a list of all `NodeState` of all the `Node`s which have opened the `Service`.
     pub fn nodes(&self) -> PyResult<Vec<NodeState>> {
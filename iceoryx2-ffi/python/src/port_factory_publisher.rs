@@ -16,9 +16,11 @@ use pyo3::prelude::*;
 use crate::{
     allocation_strategy::AllocationStrategy,
     error::PublisherCreateError,
+    held_samples::HeldSamples,
     parc::Parc,
     port_factory_publish_subscribe::PortFactoryPublishSubscribeType,
     publisher::{Publisher, PublisherType},
+    rate_limiter::RateLimiter,
     type_storage::TypeStorage,
     unable_to_deliver_strategy::UnableToDeliverStrategy,
 };
@@ -142,6 +144,43 @@ impl PortFactoryPublisher {
         }
     }
 
+    /// Backs the data segment of the `Publisher` with huge pages on platforms that support
+    /// them. If the huge pages cannot be obtained, the `Publisher` falls back to regular pages,
+    /// see `Publisher.has_huge_pages`.
+    pub fn use_huge_pages(&self, value: bool) -> Self {
+        let _guard = self.factory.lock();
+        match &self.value {
+            PortFactoryPublisherType::Ipc(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.use_huge_pages(value);
+                self.clone_ipc(this)
+            }
+            PortFactoryPublisherType::Local(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.use_huge_pages(value);
+                self.clone_local(this)
+            }
+        }
+    }
+
+    /// Binds the data segment of the `Publisher` to the NUMA node with the id `node_id`.
+    /// Systems without NUMA support ignore the setting, see `Publisher.numa_node`.
+    pub fn numa_node(&self, node_id: usize) -> Self {
+        let _guard = self.factory.lock();
+        match &self.value {
+            PortFactoryPublisherType::Ipc(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.numa_node(node_id);
+                self.clone_ipc(this)
+            }
+            PortFactoryPublisherType::Local(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.numa_node(node_id);
+                self.clone_local(this)
+            }
+        }
+    }
+
     /// Sets the maximum slice length that a user can allocate with
     /// `ActiveRequest::loan_slice()` or `ActiveRequest::loan_slice_uninit()`.
     pub fn __initial_max_slice_len(&self, value: usize) -> Self {
@@ -193,6 +232,8 @@ impl PortFactoryPublisher {
                     ))),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: HeldSamples::new(),
+                    rate_limiter: RateLimiter::new(),
                 })
             }
             PortFactoryPublisherType::Local(v) => {
@@ -204,6 +245,8 @@ impl PortFactoryPublisher {
                     ))),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: HeldSamples::new(),
+                    rate_limiter: RateLimiter::new(),
                 })
             }
         }
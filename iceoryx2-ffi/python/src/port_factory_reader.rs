@@ -0,0 +1,106 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::builder::CustomKeyMarker;
+use pyo3::prelude::*;
+
+use crate::{
+    error::ReaderCreateError,
+    parc::Parc,
+    port_factory_blackboard::PortFactoryBlackboardType,
+    reader::{Reader, ReaderType},
+};
+
+#[derive(Clone)]
+pub(crate) enum PortFactoryReaderType {
+    Ipc(
+        iceoryx2::service::port_factory::reader::PortFactoryReader<
+            'static,
+            crate::IpcService,
+            CustomKeyMarker,
+        >,
+    ),
+    Local(
+        iceoryx2::service::port_factory::reader::PortFactoryReader<
+            'static,
+            crate::LocalService,
+            CustomKeyMarker,
+        >,
+    ),
+}
+
+#[pyclass]
+/// Factory to create a new `Reader` port/endpoint for `MessagingPattern::Blackboard` based
+/// communication.
+pub struct PortFactoryReader {
+    // required to hold since the PortFactoryReader has a reference to it and thanks to the
+    // garbage collector we do not know how long it will be available
+    // also: lifetime parameters are forbidden in pyclass
+    factory: Parc<PortFactoryBlackboardType>,
+    value: PortFactoryReaderType,
+}
+
+impl PortFactoryReader {
+    pub(crate) fn new(factory: Parc<PortFactoryBlackboardType>) -> Self {
+        Self {
+            factory: factory.clone(),
+            value: match &*factory.lock() {
+                PortFactoryBlackboardType::Ipc(v) => {
+                    let v: *const iceoryx2::service::port_factory::blackboard::PortFactory<
+                        crate::IpcService,
+                        CustomKeyMarker,
+                    > = v;
+                    // by converting the factory into a pointer we change the lifetime into 'static
+                    // and with the factory reference hold by this object we ensure that it
+                    // lifes long enough
+                    PortFactoryReaderType::Ipc(unsafe { &*v }.reader_builder())
+                }
+                PortFactoryBlackboardType::Local(v) => {
+                    let v: *const iceoryx2::service::port_factory::blackboard::PortFactory<
+                        crate::LocalService,
+                        CustomKeyMarker,
+                    > = v;
+                    // by converting the factory into a pointer we change the lifetime into 'static
+                    // and with the factory reference hold by this object we ensure that it
+                    // lifes long enough
+                    PortFactoryReaderType::Local(unsafe { &*v }.reader_builder())
+                }
+            },
+        }
+    }
+}
+
+#[pymethods]
+impl PortFactoryReader {
+    /// Creates the `Reader` port or emits a `ReaderCreateError` on failure.
+    pub fn create(&self) -> PyResult<Reader> {
+        let _guard = self.factory.lock();
+        match &self.value {
+            PortFactoryReaderType::Ipc(v) => {
+                let this = v.clone();
+                Ok(Reader(ReaderType::Ipc(Some(Arc::new(
+                    this.create()
+                        .map_err(|e| ReaderCreateError::new_err(format!("{e:?}")))?,
+                )))))
+            }
+            PortFactoryReaderType::Local(v) => {
+                let this = v.clone();
+                Ok(Reader(ReaderType::Local(Some(Arc::new(
+                    this.create()
+                        .map_err(|e| ReaderCreateError::new_err(format!("{e:?}")))?,
+                )))))
+            }
+        }
+    }
+}
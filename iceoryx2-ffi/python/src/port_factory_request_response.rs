@@ -15,6 +15,7 @@ use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use pyo3::prelude::*;
 
 use crate::attribute_set::AttributeSet;
+use crate::dynamic_config_request_response::DynamicConfigRequestResponse;
 use crate::error::NodeListFailure;
 use crate::node_id::NodeId;
 use crate::node_state::{
@@ -24,6 +25,7 @@ use crate::parc::Parc;
 use crate::port_factory_client::PortFactoryClient;
 use crate::port_factory_server::PortFactoryServer;
 use crate::service_id::ServiceId;
+use crate::service_memory_footprint::ServiceMemoryFootprint;
 use crate::service_name::ServiceName;
 use crate::static_config_request_response::StaticConfigRequestResponse;
 
@@ -97,6 +99,31 @@ impl PortFactoryRequestResponse {
         }
     }
 
+    /// Returns a snapshot of the `DynamicConfig` of the `Service`. It contains all ports that
+    /// are connected to the `Service` at the moment of the call and does not change when ports
+    /// are created or removed afterwards.
+    pub fn dynamic_config(&self) -> DynamicConfigRequestResponse {
+        match &*self.0.lock() {
+            PortFactoryRequestResponseType::Ipc(v) => {
+                DynamicConfigRequestResponse::new(v.dynamic_config())
+            }
+            PortFactoryRequestResponseType::Local(v) => {
+                DynamicConfigRequestResponse::new(v.dynamic_config())
+            }
+        }
+    }
+
+    /// Returns the shared memory footprint of the `Service`. It contains the data segments of
+    /// all `Client`s and `Server`s that are currently connected to the `Service`.
+    pub fn memory_footprint(&self) -> ServiceMemoryFootprint {
+        match &*self.0.lock() {
+            PortFactoryRequestResponseType::Ipc(v) => ServiceMemoryFootprint(v.memory_footprint()),
+            PortFactoryRequestResponseType::Local(v) => {
+                ServiceMemoryFootprint(v.memory_footprint())
+            }
+        }
+    }
+
     #[getter]
     /// Returns a list of all `NodeState` of all the `Node`s which have opened the `Service`.
     pub fn nodes(&self) -> PyResult<Vec<NodeState>> {
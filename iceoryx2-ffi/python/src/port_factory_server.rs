@@ -12,6 +12,7 @@
 
 use std::sync::Arc;
 
+use iceoryx2::prelude::PortFactory;
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use pyo3::prelude::*;
 
@@ -179,19 +180,48 @@ impl PortFactoryServer {
 
     /// Creates a new `Server` or emits a `ServerCreateError` on failure.
     pub fn create(&self) -> PyResult<Server> {
-        let _guard = self.factory.lock();
+        let factory = self.factory.lock();
+        let static_config = match &*factory {
+            PortFactoryRequestResponseType::Ipc(v) => v.static_config().clone(),
+            PortFactoryRequestResponseType::Local(v) => v.static_config().clone(),
+        };
+        let response_payload_type_details = static_config
+            .response_message_type_details()
+            .payload
+            .clone();
+        let request_header_size = static_config
+            .request_message_type_details()
+            .user_header
+            .size;
+        let response_header_size = static_config
+            .response_message_type_details()
+            .user_header
+            .size;
+
         match &self.value {
             PortFactoryServerType::Ipc(v) => {
                 let this = unsafe { (*v.lock()).__internal_partial_clone() };
-                Ok(Server(ServerType::Ipc(Arc::new(this.create().map_err(
-                    |e| ServerCreateError::new_err(format!("{e:?}")),
-                )?))))
+                Ok(Server {
+                    value: ServerType::Ipc(Arc::new(
+                        this.create()
+                            .map_err(|e| ServerCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    response_payload_type_details,
+                    request_header_size,
+                    response_header_size,
+                })
             }
             PortFactoryServerType::Local(v) => {
                 let this = unsafe { (*v.lock()).__internal_partial_clone() };
-                Ok(Server(ServerType::Local(Arc::new(this.create().map_err(
-                    |e| ServerCreateError::new_err(format!("{e:?}")),
-                )?))))
+                Ok(Server {
+                    value: ServerType::Local(Arc::new(
+                        this.create()
+                            .map_err(|e| ServerCreateError::new_err(format!("{e:?}")))?,
+                    )),
+                    response_payload_type_details,
+                    request_header_size,
+                    response_header_size,
+                })
             }
         }
     }
@@ -15,6 +15,7 @@ use pyo3::prelude::*;
 
 use crate::{
     error::SubscriberCreateError,
+    held_samples::HeldSamples,
     parc::Parc,
     port_factory_publish_subscribe::PortFactoryPublishSubscribeType,
     subscriber::{Subscriber, SubscriberType},
@@ -104,6 +105,9 @@ impl PortFactorySubscriber {
 #[pymethods]
 impl PortFactorySubscriber {
     /// Defines the buffer size of the `Subscriber`. Smallest possible value is `1`.
+    /// Every `Subscriber` of a `Service` can have its own buffer size as long as it does
+    /// not exceed `subscriber_max_buffer_size` of the `Service`, otherwise `create()` emits a
+    /// `SubscriberCreateError`.
     pub fn buffer_size(&self, value: usize) -> Self {
         let _guard = self.factory.lock();
         match &self.value {
@@ -120,6 +124,25 @@ impl PortFactorySubscriber {
         }
     }
 
+    /// Defines the maximum number of elements a received slice may contain. By default, slices
+    /// of any length are accepted. When a larger slice is received, it is discarded and
+    /// `receive()` emits a `ReceiveError`. Smallest possible value is `1`.
+    pub fn max_slice_len(&self, value: usize) -> Self {
+        let _guard = self.factory.lock();
+        match &self.value {
+            PortFactorySubscriberType::Ipc(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.max_slice_len(value);
+                self.clone_ipc(this)
+            }
+            PortFactorySubscriberType::Local(v) => {
+                let this = unsafe { (*v.lock()).__internal_partial_clone() };
+                let this = this.max_slice_len(value);
+                self.clone_local(this)
+            }
+        }
+    }
+
     /// Creates a new `Subscriber` or emits a `SubscriberCreateError` on failure.
     pub fn create(&self) -> PyResult<Subscriber> {
         let _guard = self.factory.lock();
@@ -133,6 +156,7 @@ impl PortFactorySubscriber {
                     ))),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: HeldSamples::new(),
                 })
             }
             PortFactorySubscriberType::Local(v) => {
@@ -144,6 +168,7 @@ impl PortFactorySubscriber {
                     ))),
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: HeldSamples::new(),
                 })
             }
         }
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::builder::CustomKeyMarker;
+use pyo3::prelude::*;
+
+use crate::{
+    parc::Parc,
+    port_factory_blackboard::PortFactoryBlackboardType,
+    writer::{Writer, WriterType},
+};
+
+#[derive(Clone)]
+pub(crate) enum PortFactoryWriterType {
+    Ipc(
+        iceoryx2::service::port_factory::writer::PortFactoryWriter<
+            'static,
+            crate::IpcService,
+            CustomKeyMarker,
+        >,
+    ),
+    Local(
+        iceoryx2::service::port_factory::writer::PortFactoryWriter<
+            'static,
+            crate::LocalService,
+            CustomKeyMarker,
+        >,
+    ),
+}
+
+#[pyclass]
+/// Factory to create a new `Writer` port/endpoint for `MessagingPattern::Blackboard` based
+/// communication.
+pub struct PortFactoryWriter {
+    // required to hold since the PortFactoryWriter has a reference to it and thanks to the
+    // garbage collector we do not know how long it will be available
+    // also: lifetime parameters are forbidden in pyclass
+    factory: Parc<PortFactoryBlackboardType>,
+    value: PortFactoryWriterType,
+}
+
+impl PortFactoryWriter {
+    pub(crate) fn new(factory: Parc<PortFactoryBlackboardType>) -> Self {
+        Self {
+            factory: factory.clone(),
+            value: match &*factory.lock() {
+                PortFactoryBlackboardType::Ipc(v) => {
+                    let v: *const iceoryx2::service::port_factory::blackboard::PortFactory<
+                        crate::IpcService,
+                        CustomKeyMarker,
+                    > = v;
+                    // by converting the factory into a pointer we change the lifetime into 'static
+                    // and with the factory reference hold by this object we ensure that it
+                    // lifes long enough
+                    PortFactoryWriterType::Ipc(unsafe { &*v }.writer_builder())
+                }
+                PortFactoryBlackboardType::Local(v) => {
+                    let v: *const iceoryx2::service::port_factory::blackboard::PortFactory<
+                        crate::LocalService,
+                        CustomKeyMarker,
+                    > = v;
+                    // by converting the factory into a pointer we change the lifetime into 'static
+                    // and with the factory reference hold by this object we ensure that it
+                    // lifes long enough
+                    PortFactoryWriterType::Local(unsafe { &*v }.writer_builder())
+                }
+            },
+        }
+    }
+}
+
+#[pymethods]
+impl PortFactoryWriter {
+    /// Creates the `Writer` port.
+    pub fn create(&self) -> Writer {
+        let _guard = self.factory.lock();
+        match &self.value {
+            PortFactoryWriterType::Ipc(v) => {
+                Writer(WriterType::Ipc(Some(Arc::new(v.clone().create()))))
+            }
+            PortFactoryWriterType::Local(v) => {
+                Writer(WriterType::Local(Some(Arc::new(v.clone().create()))))
+            }
+        }
+    }
+}
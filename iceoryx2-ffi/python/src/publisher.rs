@@ -15,12 +15,15 @@ use iceoryx2_bb_log::fatal_panic;
 use pyo3::prelude::*;
 
 use crate::{
-    error::LoanError,
+    error::{LoanError, PublisherSetHistorySizeError},
+    held_samples::HeldSamples,
     parc::Parc,
+    rate_limiter::RateLimiter,
     sample_mut_uninit::{SampleMutUninit, SampleMutUninitType},
     type_storage::TypeStorage,
     unable_to_deliver_strategy::UnableToDeliverStrategy,
     unique_publisher_id::UniquePublisherId,
+    unique_subscriber_id::UniqueSubscriberId,
 };
 
 pub(crate) enum PublisherType {
@@ -50,6 +53,8 @@ pub struct Publisher {
     pub(crate) value: Parc<PublisherType>,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
+    pub(crate) held_samples: HeldSamples,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 #[pymethods]
@@ -82,6 +87,41 @@ impl Publisher {
         }
     }
 
+    #[getter]
+    /// Returns the number of samples that were dropped due to a buffer overflow of a
+    /// connected `Subscriber` since the `Publisher` was created.
+    pub fn overflow_count(&self) -> u64 {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.overflow_count(),
+            PublisherType::Local(Some(v)) => v.overflow_count(),
+            _ => fatal_panic!(from "Publisher::overflow_count()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    #[getter]
+    /// Returns true when the current data segment of the `Publisher` is backed by huge pages.
+    pub fn has_huge_pages(&self) -> bool {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.has_huge_pages(),
+            PublisherType::Local(Some(v)) => v.has_huge_pages(),
+            _ => fatal_panic!(from "Publisher::has_huge_pages()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    #[getter]
+    /// Returns the id of the NUMA node the current data segment of the `Publisher` is bound
+    /// to. Returns `None` when it is not bound to a node.
+    pub fn numa_node(&self) -> Option<usize> {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.numa_node(),
+            PublisherType::Local(Some(v)) => v.numa_node(),
+            _ => fatal_panic!(from "Publisher::numa_node()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
     #[getter]
     /// Returns the maximum initial slice length configured for this `Publisher`.
     pub fn initial_max_slice_len(&self) -> usize {
@@ -93,6 +133,94 @@ impl Publisher {
         }
     }
 
+    #[getter]
+    /// Returns the maximum slice length the `Publisher` can currently loan without reallocating
+    /// its data segment. It starts with `initial_max_slice_len` and grows whenever the
+    /// `AllocationStrategy` requires a larger data segment.
+    pub fn max_slice_len(&self) -> usize {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.max_slice_len(),
+            PublisherType::Local(Some(v)) => v.max_slice_len(),
+            _ => fatal_panic!(from "Publisher::max_slice_len()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    #[getter]
+    /// Returns how often the data segment of the `Publisher` was reallocated since its creation.
+    pub fn number_of_data_segment_reallocations(&self) -> usize {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.number_of_data_segment_reallocations(),
+            PublisherType::Local(Some(v)) => v.number_of_data_segment_reallocations(),
+            _ => fatal_panic!(from "Publisher::number_of_data_segment_reallocations()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    #[getter]
+    /// Returns the number of samples the `Publisher` currently keeps in its history and
+    /// delivers to newly connected `Subscriber`s.
+    pub fn history_size(&self) -> usize {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.history_size(),
+            PublisherType::Local(Some(v)) => v.history_size(),
+            _ => fatal_panic!(from "Publisher::history_size()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    /// Adjusts the number of samples the `Publisher` keeps in its history. Every `Subscriber`
+    /// that connects afterwards receives at most the new number of samples. When the history
+    /// shrinks, the oldest samples are evicted.
+    ///
+    /// Emits `PublisherSetHistorySizeError` when the value exceeds the history size the
+    /// `Service` was created with.
+    pub fn set_history_size(&self, value: usize) -> PyResult<()> {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v
+                .set_history_size(value)
+                .map_err(|e| PublisherSetHistorySizeError::new_err(format!("{e:?}"))),
+            PublisherType::Local(Some(v)) => v
+                .set_history_size(value)
+                .map_err(|e| PublisherSetHistorySizeError::new_err(format!("{e:?}"))),
+            _ => fatal_panic!(from "Publisher::set_history_size()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
+    #[getter]
+    /// Returns the number of samples per second the `Publisher` is limited to or `None` when no
+    /// rate limit is set.
+    pub fn rate_limit(&self) -> Option<f64> {
+        self.rate_limiter.rate_limit()
+    }
+
+    /// Limits the number of samples the `Publisher` sends per second with a token bucket.
+    /// `SampleMut::send()` blocks until a token is available, `SampleMut::try_send()` emits
+    /// `RateLimitedError` instead. The limit also applies to samples that were loaned before.
+    ///
+    /// Emits `ValueError` when `tokens_per_second` is not a positive number.
+    pub fn set_rate_limit(&self, tokens_per_second: f64) -> PyResult<()> {
+        self.rate_limiter.set_rate_limit(tokens_per_second)
+    }
+
+    /// Removes the rate limit of the `Publisher`.
+    pub fn disable_rate_limit(&self) {
+        self.rate_limiter.disable();
+    }
+
+    /// Returns the highest sequence number of this `Publisher`s samples that the `Subscriber`
+    /// has acknowledged with `Subscriber::mark_received()`. If nothing was acknowledged yet or
+    /// the `Subscriber` is not connected, it returns `None`.
+    pub fn acknowledged_sequence_number(&self, subscriber_id: &UniqueSubscriberId) -> Option<u64> {
+        match &*self.value.lock() {
+            PublisherType::Ipc(Some(v)) => v.acknowledged_sequence_number(subscriber_id.0),
+            PublisherType::Local(Some(v)) => v.acknowledged_sequence_number(subscriber_id.0),
+            _ => fatal_panic!(from "Publisher::acknowledged_sequence_number()",
+                "Accessing a deleted publisher."),
+        }
+    }
+
     /// Loans/allocates a `SampleMutUninit` from the underlying data segment of the `Publisher`.
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -113,10 +241,14 @@ impl Publisher {
                     v.loan_custom_payload(number_of_elements)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
                 };
+                let value = Parc::new(SampleMutUninitType::Ipc(Some(sample)));
+                self.held_samples.add(&value);
                 Ok(SampleMutUninit {
-                    value: Parc::new(SampleMutUninitType::Ipc(Some(sample))),
+                    value,
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: self.held_samples.clone(),
+                    rate_limiter: self.rate_limiter.clone(),
                 })
             }
             PublisherType::Local(Some(v)) => {
@@ -124,10 +256,14 @@ impl Publisher {
                     v.loan_custom_payload(number_of_elements)
                         .map_err(|e| LoanError::new_err(format!("{e:?}")))?
                 };
+                let value = Parc::new(SampleMutUninitType::Local(Some(sample)));
+                self.held_samples.add(&value);
                 Ok(SampleMutUninit {
-                    value: Parc::new(SampleMutUninitType::Local(Some(sample))),
+                    value,
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    held_samples: self.held_samples.clone(),
+                    rate_limiter: self.rate_limiter.clone(),
                 })
             }
             _ => fatal_panic!(from "Publisher::id()",
@@ -148,4 +284,21 @@ impl Publisher {
             }
         }
     }
+
+    /// Returns the `Publisher` itself so that it can be used as context manager.
+    pub fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Releases the `Publisher` and all loaned samples that were not yet sent when leaving the `with` block. Calling it
+    /// multiple times has no further effect.
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) {
+        self.held_samples.release_all();
+        self.delete();
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use std::time::Instant;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::parc::Parc;
+
+// the bucket holds at most one token so that a limited publisher cannot send a burst after
+// an idle period
+const MAX_TOKENS: f64 = 1.0;
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens_per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(tokens_per_second: f64) -> Self {
+        Self {
+            tokens_per_second,
+            tokens: MAX_TOKENS,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.tokens_per_second).min(MAX_TOKENS);
+        self.last_refill = now;
+    }
+
+    fn try_acquire(&mut self) -> Result<(), Duration> {
+        self.refill();
+        if 1.0 <= self.tokens {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.tokens_per_second,
+            ))
+        }
+    }
+}
+
+/// Token bucket rate limiter that is shared between a `Publisher` and all `SampleMut`s it
+/// loaned, so that the limit applies to the send path of every sample.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter(Parc<Option<TokenBucket>>);
+
+impl RateLimiter {
+    pub(crate) fn new() -> Self {
+        Self(Parc::new(None))
+    }
+
+    pub(crate) fn set_rate_limit(&self, tokens_per_second: f64) -> PyResult<()> {
+        if !tokens_per_second.is_finite() || tokens_per_second <= 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "The rate limit must be a positive number of tokens per second but {tokens_per_second} was provided."
+            )));
+        }
+
+        *self.0.lock() = Some(TokenBucket::new(tokens_per_second));
+        Ok(())
+    }
+
+    pub(crate) fn disable(&self) {
+        *self.0.lock() = None;
+    }
+
+    pub(crate) fn rate_limit(&self) -> Option<f64> {
+        self.0.lock().as_ref().map(|b| b.tokens_per_second)
+    }
+
+    /// Acquires a token without blocking. Returns `false` when the bucket is empty.
+    pub(crate) fn try_acquire(&self) -> bool {
+        match &mut *self.0.lock() {
+            Some(bucket) => bucket.try_acquire().is_ok(),
+            None => true,
+        }
+    }
+
+    /// Blocks until a token is available. The GIL is released while waiting.
+    pub(crate) fn acquire(&self, py: Python<'_>) -> PyResult<()> {
+        loop {
+            let wait_time = match &mut *self.0.lock() {
+                Some(bucket) => match bucket.try_acquire() {
+                    Ok(()) => return Ok(()),
+                    Err(wait_time) => wait_time,
+                },
+                None => return Ok(()),
+            };
+
+            py.allow_threads(|| std::thread::sleep(wait_time));
+            py.check_signals()?;
+        }
+    }
+}
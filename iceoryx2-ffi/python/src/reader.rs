@@ -0,0 +1,125 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::prelude::CallbackProgression;
+use iceoryx2::service::builder::CustomKeyMarker;
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::{error::ReaderReadError, type_detail::TypeDetail};
+
+pub(crate) enum ReaderType {
+    Ipc(Option<Arc<iceoryx2::port::reader::Reader<crate::IpcService, CustomKeyMarker>>>),
+    Local(Option<Arc<iceoryx2::port::reader::Reader<crate::LocalService, CustomKeyMarker>>>),
+}
+
+#[pyclass]
+/// Represents the reading endpoint of a blackboard based communication. Every `Reader` tracks
+/// which entries it has seen, so that the changes since its creation or since the last read of
+/// an entry can be queried. An entry that was written multiple times in between is reported
+/// only once.
+pub struct Reader(pub(crate) ReaderType);
+
+fn read_value<'py, F: FnOnce(*mut u8) -> Result<(), iceoryx2::port::reader::ReaderReadError>>(
+    py: Python<'py>,
+    value_type: &TypeDetail,
+    read: F,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let mut value = vec![0u8; value_type.0.size];
+    read(value.as_mut_ptr()).map_err(|e| ReaderReadError::new_err(format!("{e:?}")))?;
+    Ok(PyBytes::new(py, &value))
+}
+
+fn collect_keys<'py, F: FnOnce(&mut dyn FnMut(&[u8]) -> CallbackProgression)>(
+    py: Python<'py>,
+    changed_keys: F,
+) -> Vec<Bound<'py, PyBytes>> {
+    let mut keys = vec![];
+    changed_keys(&mut |key| {
+        keys.push(PyBytes::new(py, key));
+        CallbackProgression::Continue
+    });
+    keys
+}
+
+#[pymethods]
+impl Reader {
+    /// Reads the current value of the entry with the provided key and marks it as seen. The
+    /// `value_type` must be identical to the `TypeDetail` the entry was added with.
+    /// Emits a `ReaderReadError` on failure.
+    pub fn read<'py>(
+        &self,
+        py: Python<'py>,
+        key: &[u8],
+        value_type: &TypeDetail,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        match &self.0 {
+            ReaderType::Ipc(Some(v)) => read_value(py, value_type, |target| unsafe {
+                v.__internal_read(key, &value_type.0, target)
+            }),
+            ReaderType::Local(Some(v)) => read_value(py, value_type, |target| unsafe {
+                v.__internal_read(key, &value_type.0, target)
+            }),
+            _ => fatal_panic!(from "Reader::read()",
+                "Accessing a released Reader."),
+        }
+    }
+
+    /// Returns `True` when the entry with the provided key was written since the `Reader` was
+    /// created or since it was read the last time. Emits a `ReaderReadError` on failure.
+    pub fn has_changed(&self, key: &[u8]) -> PyResult<bool> {
+        match &self.0 {
+            ReaderType::Ipc(Some(v)) => v
+                .__internal_has_changed(key)
+                .map_err(|e| ReaderReadError::new_err(format!("{e:?}"))),
+            ReaderType::Local(Some(v)) => v
+                .__internal_has_changed(key)
+                .map_err(|e| ReaderReadError::new_err(format!("{e:?}"))),
+            _ => fatal_panic!(from "Reader::has_changed()",
+                "Accessing a released Reader."),
+        }
+    }
+
+    /// Returns the keys of all entries that were written since the `Reader` was created, since
+    /// they were read the last time or since they were returned by a previous call. Every
+    /// change is reported exactly once, independent of how often the entry was written in
+    /// between.
+    pub fn changed_keys<'py>(&self, py: Python<'py>) -> Vec<Bound<'py, PyBytes>> {
+        match &self.0 {
+            ReaderType::Ipc(Some(v)) => {
+                collect_keys(py, |callback| v.__internal_changed_keys(callback))
+            }
+            ReaderType::Local(Some(v)) => {
+                collect_keys(py, |callback| v.__internal_changed_keys(callback))
+            }
+            _ => fatal_panic!(from "Reader::changed_keys()",
+                "Accessing a released Reader."),
+        }
+    }
+
+    /// Releases the `Reader`.
+    ///
+    /// After this call the `Reader` is no longer usable!
+    pub fn delete(&mut self) {
+        match self.0 {
+            ReaderType::Ipc(ref mut v) => {
+                v.take();
+            }
+            ReaderType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
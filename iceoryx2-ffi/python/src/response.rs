@@ -0,0 +1,149 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::{prelude::*, types::PyBytes};
+
+use crate::{parc::Parc, unique_server_id::UniqueServerId};
+
+pub(crate) enum ResponseType {
+    Ipc(
+        Option<
+            iceoryx2::response::Response<
+                crate::IpcService,
+                [CustomPayloadMarker],
+                CustomHeaderMarker,
+            >,
+        >,
+    ),
+    Local(
+        Option<
+            iceoryx2::response::Response<
+                crate::LocalService,
+                [CustomPayloadMarker],
+                CustomHeaderMarker,
+            >,
+        >,
+    ),
+}
+
+#[pyclass]
+/// It stores the payload and is acquired by the `PendingResponse` whenever
+/// it receives new data from a `Server` via `PendingResponse::receive()`.
+pub struct Response {
+    pub(crate) value: Parc<ResponseType>,
+    pub(crate) user_header_size: usize,
+}
+
+#[pymethods]
+impl Response {
+    #[getter]
+    /// Returns the `UniqueServerId` of the `Server` that sent the `Response`. It is equal to
+    /// the `Server.id` of the sending `Server`.
+    pub fn origin(&self) -> UniqueServerId {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => UniqueServerId(v.origin()),
+            ResponseType::Local(Some(v)) => UniqueServerId(v.origin()),
+            _ => fatal_panic!(from "Response::origin()",
+                "Accessing a released response."),
+        }
+    }
+
+    #[getter]
+    /// Returns `True` when the `Server` rejected the request with
+    /// `Server::reject_request`. The payload of an error response contains no user data.
+    pub fn is_error(&self) -> bool {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => v.header().is_error(),
+            ResponseType::Local(Some(v)) => v.header().is_error(),
+            _ => fatal_panic!(from "Response::is_error()",
+                "Accessing a released response."),
+        }
+    }
+
+    #[getter]
+    /// Returns the reason the `Server` rejected the request with or `0` when the
+    /// `Response` is not an error response.
+    pub fn error_code(&self) -> u64 {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => v.header().error_code().unwrap_or(0),
+            ResponseType::Local(Some(v)) => v.header().error_code().unwrap_or(0),
+            _ => fatal_panic!(from "Response::error_code()",
+                "Accessing a released response."),
+        }
+    }
+
+    #[getter]
+    /// Returns the time of the monotonic clock in nanoseconds when the `Server` sent the
+    /// `Response`.
+    pub fn timestamp_ns(&self) -> u64 {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => v.header().timestamp_ns(),
+            ResponseType::Local(Some(v)) => v.header().timestamp_ns(),
+            _ => fatal_panic!(from "Response::timestamp_ns()",
+                "Accessing a released response."),
+        }
+    }
+
+    /// Returns a copy of the payload as `bytes`.
+    pub fn payload<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            ResponseType::Local(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            _ => fatal_panic!(from "Response::payload()",
+                "Accessing a released response."),
+        }
+    }
+
+    /// Returns a copy of the user header as `bytes`. Its size is defined by the response header
+    /// `TypeDetail` of the `Service`.
+    pub fn user_header<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        match &*self.value.lock() {
+            ResponseType::Ipc(Some(v)) => PyBytes::new(
+                py,
+                user_header_as_bytes(v.user_header(), self.user_header_size),
+            ),
+            ResponseType::Local(Some(v)) => PyBytes::new(
+                py,
+                user_header_as_bytes(v.user_header(), self.user_header_size),
+            ),
+            _ => fatal_panic!(from "Response::user_header()",
+                "Accessing a released response."),
+        }
+    }
+
+    /// Releases the `Response`.
+    ///
+    /// After this call the `Response` is no longer usable!
+    pub fn delete(&mut self) {
+        match &mut *self.value.lock() {
+            ResponseType::Ipc(ref mut v) => {
+                v.take();
+            }
+            ResponseType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
+
+pub(crate) fn user_header_as_bytes(user_header: &CustomHeaderMarker, size: usize) -> &[u8] {
+    // the custom header marker is a placeholder for the underlying header with the size
+    // defined in the header type details
+    unsafe { core::slice::from_raw_parts((user_header as *const CustomHeaderMarker).cast(), size) }
+}
+
+pub(crate) fn payload_as_bytes(payload: &[CustomPayloadMarker]) -> &[u8] {
+    // the custom payload marker is a byte sized placeholder for the underlying payload
+    unsafe { core::slice::from_raw_parts(payload.as_ptr().cast(), payload.len()) }
+}
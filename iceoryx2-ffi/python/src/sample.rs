@@ -12,10 +12,11 @@
 
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_bb_log::fatal_panic;
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 
 use crate::{
-    header_publish_subscribe::HeaderPublishSubscribe, parc::Parc, type_storage::TypeStorage,
+    error::ClockDomainError, header_publish_subscribe::HeaderPublishSubscribe,
+    held_samples::Release, parc::Parc, response::payload_as_bytes, type_storage::TypeStorage,
 };
 
 pub(crate) enum SampleType {
@@ -35,6 +36,19 @@ pub(crate) enum SampleType {
     ),
 }
 
+impl Release for SampleType {
+    fn release(&mut self) {
+        match self {
+            SampleType::Ipc(ref mut v) => {
+                v.take();
+            }
+            SampleType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
+
 #[pyclass]
 /// It stores the payload and is acquired by the `Subscriber` whenever
 /// it receives new data from a `Publisher` via `Subscriber::receive()`.
@@ -77,6 +91,37 @@ impl Sample {
         }
     }
 
+    #[getter]
+    /// Returns the monotonic send timestamp of the `Sample` in nanoseconds. It is either
+    /// captured when the `Sample` was sent or provided with `Publisher.send_with_timestamp()`.
+    /// Returns `None` when the `Sample` does not have a timestamp.
+    pub fn timestamp_ns(&self) -> Option<u64> {
+        let timestamp = match &*self.value.lock() {
+            SampleType::Ipc(Some(v)) => v.header().timestamp(),
+            SampleType::Local(Some(v)) => v.header().timestamp(),
+            _ => fatal_panic!(from "Sample::timestamp_ns()",
+                "Accessing a released sample."),
+        };
+
+        timestamp.map(|t| t.monotonic().as_nanos() as u64)
+    }
+
+    /// Returns how many nanoseconds have passed on the monotonic clock since the `Sample` was
+    /// timestamped. Returns `None` when the `Sample` does not have a timestamp. Raises
+    /// `ClockDomainError` when the `Sample` was timestamped in another clock domain, e.g. on
+    /// another host.
+    pub fn age_ns(&self) -> PyResult<Option<u64>> {
+        let age = match &*self.value.lock() {
+            SampleType::Ipc(Some(v)) => v.age(),
+            SampleType::Local(Some(v)) => v.age(),
+            _ => fatal_panic!(from "Sample::age_ns()",
+                "Accessing a released sample."),
+        };
+
+        age.map(|age| age.map(|v| v.as_nanos() as u64))
+            .map_err(|e| ClockDomainError::new_err(format!("{e:?}")))
+    }
+
     #[getter]
     /// Returns a pointer to the user header.
     pub fn user_header_ptr(&self) -> usize {
@@ -99,17 +144,22 @@ impl Sample {
         }
     }
 
+    /// Returns an owned copy of the payload as `bytes` that remains valid after the `Sample`
+    /// was released. Every call copies the whole payload out of the shared memory, prefer
+    /// `Sample.payload()` for zero-copy access.
+    pub fn payload_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        match &*self.value.lock() {
+            SampleType::Ipc(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            SampleType::Local(Some(v)) => PyBytes::new(py, payload_as_bytes(v.payload())),
+            _ => fatal_panic!(from "Sample::payload_bytes()",
+                "Accessing a released sample."),
+        }
+    }
+
     /// Releases the `Sample`.
     ///
     /// After this call the `Sample` is no longer usable!
     pub fn delete(&mut self) {
-        match &mut *self.value.lock() {
-            SampleType::Ipc(ref mut v) => {
-                v.take();
-            }
-            SampleType::Local(ref mut v) => {
-                v.take();
-            }
-        }
+        self.value.lock().release();
     }
 }
@@ -10,12 +10,19 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::header::publish_subscribe::Timestamp;
 use iceoryx2_bb_log::fatal_panic;
 use pyo3::prelude::*;
 
 use crate::{
-    error::SendError, header_publish_subscribe::HeaderPublishSubscribe, parc::Parc,
+    error::{RateLimitedError, SendError},
+    header_publish_subscribe::HeaderPublishSubscribe,
+    held_samples::Release,
+    parc::Parc,
+    rate_limiter::RateLimiter,
     type_storage::TypeStorage,
 };
 
@@ -40,6 +47,19 @@ pub(crate) enum SampleMutType {
     ),
 }
 
+impl Release for SampleMutType {
+    fn release(&mut self) {
+        match self {
+            SampleMutType::Ipc(ref mut v) => {
+                v.take();
+            }
+            SampleMutType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
+
 #[pyclass]
 /// Acquired by a `Publisher` via
 ///  * `Publisher::loan()`,
@@ -52,6 +72,7 @@ pub struct SampleMut {
     pub(crate) value: Parc<SampleMutType>,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 #[pymethods]
@@ -117,14 +138,7 @@ impl SampleMut {
     ///
     /// After this call the `SampleMut` is no longer usable!
     pub fn delete(&mut self) {
-        match &mut *self.value.lock() {
-            SampleMutType::Ipc(ref mut v) => {
-                v.take();
-            }
-            SampleMutType::Local(ref mut v) => {
-                v.take();
-            }
-        }
+        self.value.lock().release();
     }
 
     /// Send a previously loaned `Publisher::loan_uninit()` `SampleMut` to all connected
@@ -132,19 +146,57 @@ impl SampleMut {
     ///
     /// On success the number of `Subscriber`s that received
     /// the data is returned, otherwise a `SendError` is emitted describing the failure.
-    pub fn send(&self) -> PyResult<usize> {
+    /// When the `Publisher` has a rate limit, it blocks until the sample can be sent.
+    pub fn send(&self, py: Python<'_>) -> PyResult<usize> {
+        self.rate_limiter.acquire(py)?;
+        self.send_impl(None)
+    }
+
+    /// Sends the `SampleMut` like `SampleMut::send()` but stores the provided monotonic
+    /// timestamp in nanoseconds in the header instead of the time of sending, for instance to
+    /// record when the data was captured.
+    pub fn send_with_timestamp(&self, py: Python<'_>, timestamp_ns: u64) -> PyResult<usize> {
+        let timestamp = Timestamp::from_monotonic(Duration::from_nanos(timestamp_ns))
+            .map_err(|e| SendError::new_err(format!("{e:?}")))?;
+        self.rate_limiter.acquire(py)?;
+        self.send_impl(Some(timestamp))
+    }
+
+    /// Send a previously loaned `Publisher::loan_uninit()` `SampleMut` to all connected
+    /// `Subscriber`s of the service without waiting for the rate limit of the `Publisher`.
+    ///
+    /// On success the number of `Subscriber`s that received the data is returned. When the
+    /// rate limit is reached, `RateLimitedError` is emitted and the `SampleMut` stays usable,
+    /// otherwise a `SendError` is emitted describing the failure.
+    pub fn try_send(&self) -> PyResult<usize> {
+        if !self.rate_limiter.try_acquire() {
+            return Err(RateLimitedError::new_err(
+                "The rate limit of the publisher is reached.",
+            ));
+        }
+
+        self.send_impl(None)
+    }
+}
+
+impl SampleMut {
+    fn send_impl(&self, timestamp: Option<Timestamp>) -> PyResult<usize> {
         match &mut *self.value.lock() {
             SampleMutType::Ipc(ref mut v) => {
                 let sample = v.take().unwrap();
-                Ok(sample
-                    .send()
-                    .map_err(|e| SendError::new_err(format!("{e:?}")))?)
+                let result = match timestamp {
+                    Some(timestamp) => sample.send_with_timestamp(timestamp),
+                    None => sample.send(),
+                };
+                Ok(result.map_err(|e| SendError::new_err(format!("{e:?}")))?)
             }
             SampleMutType::Local(ref mut v) => {
                 let sample = v.take().unwrap();
-                Ok(sample
-                    .send()
-                    .map_err(|e| SendError::new_err(format!("{e:?}")))?)
+                let result = match timestamp {
+                    Some(timestamp) => sample.send_with_timestamp(timestamp),
+                    None => sample.send(),
+                };
+                Ok(result.map_err(|e| SendError::new_err(format!("{e:?}")))?)
             }
         }
     }
@@ -18,7 +18,9 @@ use pyo3::prelude::*;
 
 use crate::{
     header_publish_subscribe::HeaderPublishSubscribe,
+    held_samples::{HeldSamples, Release},
     parc::Parc,
+    rate_limiter::RateLimiter,
     sample_mut::{SampleMut, SampleMutType},
     type_storage::TypeStorage,
 };
@@ -44,6 +46,19 @@ pub(crate) enum SampleMutUninitType {
     ),
 }
 
+impl Release for SampleMutUninitType {
+    fn release(&mut self) {
+        match self {
+            SampleMutUninitType::Ipc(ref mut v) => {
+                v.take();
+            }
+            SampleMutUninitType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
+
 #[pyclass]
 /// Acquired by a `Publisher` via
 ///  * `Publisher::loan_uninit()`
@@ -55,6 +70,8 @@ pub struct SampleMutUninit {
     pub(crate) value: Parc<SampleMutUninitType>,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
+    pub(crate) held_samples: HeldSamples,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 #[pymethods]
@@ -120,14 +137,7 @@ impl SampleMutUninit {
     ///
     /// After this call the `SampleMutUninit` is no longer usable!
     pub fn delete(&mut self) {
-        match &mut *self.value.lock() {
-            SampleMutUninitType::Ipc(ref mut v) => {
-                v.take();
-            }
-            SampleMutUninitType::Local(ref mut v) => {
-                v.take();
-            }
-        }
+        self.value.lock().release();
     }
 
     /// Extracts the value of the uninitialized payload and labels the `SampleMutUninit` as
@@ -138,18 +148,24 @@ impl SampleMutUninit {
         match &mut *self.value.lock() {
             SampleMutUninitType::Ipc(ref mut v) => {
                 let sample = v.take().unwrap();
+                let value = Parc::new(SampleMutType::Ipc(Some(unsafe { sample.assume_init() })));
+                self.held_samples.add(&value);
                 SampleMut {
-                    value: Parc::new(SampleMutType::Ipc(Some(unsafe { sample.assume_init() }))),
+                    value,
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    rate_limiter: self.rate_limiter.clone(),
                 }
             }
             SampleMutUninitType::Local(ref mut v) => {
                 let sample = v.take().unwrap();
+                let value = Parc::new(SampleMutType::Local(Some(unsafe { sample.assume_init() })));
+                self.held_samples.add(&value);
                 SampleMut {
-                    value: Parc::new(SampleMutType::Local(Some(unsafe { sample.assume_init() }))),
+                    value,
                     payload_type_details: self.payload_type_details.clone(),
                     user_header_type_details: self.user_header_type_details.clone(),
+                    rate_limiter: self.rate_limiter.clone(),
                 }
             }
         }
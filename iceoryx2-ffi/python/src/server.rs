@@ -10,12 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
-use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::{
+    builder::{CustomHeaderMarker, CustomPayloadMarker},
+    static_config::message_type_details::TypeDetail,
+};
 use pyo3::prelude::*;
 
-use crate::unique_server_id::UniqueServerId;
+use crate::{
+    active_request::{ActiveRequest, ActiveRequestType},
+    error::{ConnectionFailure, ReceiveError},
+    parc::Parc,
+    unique_server_id::UniqueServerId,
+};
 
 type IpcServer = Arc<
     iceoryx2::port::server::Server<
@@ -41,18 +49,144 @@ pub(crate) enum ServerType {
     Local(LocalServer),
 }
 
+enum WeakServerType {
+    Ipc(Weak<<IpcServer as core::ops::Deref>::Target>),
+    Local(Weak<<LocalServer as core::ops::Deref>::Target>),
+}
+
 #[pyclass]
-/// Represents the receiving endpoint of an event based communication.
-pub struct Server(pub(crate) ServerType);
+/// Represents the receiving endpoint of a request-response based communication.
+pub struct Server {
+    pub(crate) value: ServerType,
+    pub(crate) response_payload_type_details: TypeDetail,
+    pub(crate) request_header_size: usize,
+    pub(crate) response_header_size: usize,
+}
 
 #[pymethods]
 impl Server {
     #[getter]
     /// Returns the `UniqueServerId` of the `Server`
     pub fn id(&self) -> UniqueServerId {
-        match &self.0 {
+        match &self.value {
             ServerType::Ipc(v) => UniqueServerId(v.id()),
             ServerType::Local(v) => UniqueServerId(v.id()),
         }
     }
+
+    /// Returns `True` if the `Server` has requests in the buffer that can be received with
+    /// `Server::receive`. Emits `ConnectionFailure` on error.
+    pub fn has_requests(&self) -> PyResult<bool> {
+        match &self.value {
+            ServerType::Ipc(v) => v
+                .has_requests()
+                .map_err(|e| ConnectionFailure::new_err(format!("{e:?}"))),
+            ServerType::Local(v) => v
+                .has_requests()
+                .map_err(|e| ConnectionFailure::new_err(format!("{e:?}"))),
+        }
+    }
+
+    /// Receives a request from a `Client` and returns the `ActiveRequest` to send the
+    /// `Response`s. If no request could be received `None` is returned.
+    /// If a failure occurs `ReceiveError` is emitted.
+    pub fn receive(&self) -> PyResult<Option<ActiveRequest>> {
+        self.details().receive(&self.value)
+    }
+
+    /// Returns a `RequestIterator` that yields all currently pending requests as
+    /// `ActiveRequest` in the order they were received and stops when no request is left.
+    /// The iterator does not keep the `Server` alive, when the `Server` is dropped during the
+    /// iteration, `ReceiveError` is emitted.
+    pub fn request_iterator(&self) -> RequestIterator {
+        RequestIterator {
+            server: match &self.value {
+                ServerType::Ipc(v) => WeakServerType::Ipc(Arc::downgrade(v)),
+                ServerType::Local(v) => WeakServerType::Local(Arc::downgrade(v)),
+            },
+            details: self.details(),
+        }
+    }
+
+    /// Rejects the `request` by sending an error response with the user defined `reason`
+    /// to the `Client`. The `Response` has `Response::is_error` set and provides the `reason`
+    /// via `Response::error_code`. The `request` is released afterwards and no longer usable!
+    /// If a failure occurs `SendError` is emitted.
+    pub fn reject_request(&self, request: &mut ActiveRequest, reason: u64) -> PyResult<()> {
+        request.reject(reason)
+    }
+}
+
+impl Server {
+    fn details(&self) -> ActiveRequestDetails {
+        ActiveRequestDetails {
+            response_payload_type_details: self.response_payload_type_details.clone(),
+            request_header_size: self.request_header_size,
+            response_header_size: self.response_header_size,
+        }
+    }
+}
+
+struct ActiveRequestDetails {
+    response_payload_type_details: TypeDetail,
+    request_header_size: usize,
+    response_header_size: usize,
+}
+
+impl ActiveRequestDetails {
+    fn receive(&self, server: &ServerType) -> PyResult<Option<ActiveRequest>> {
+        match server {
+            ServerType::Ipc(v) => Ok(unsafe {
+                v.receive_custom_payload()
+                    .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                    .map(|r| ActiveRequest {
+                        value: Parc::new(ActiveRequestType::Ipc(Some(r))),
+                        response_payload_type_details: self.response_payload_type_details.clone(),
+                        request_header_size: self.request_header_size,
+                        response_header_size: self.response_header_size,
+                    })
+            }),
+            ServerType::Local(v) => Ok(unsafe {
+                v.receive_custom_payload()
+                    .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                    .map(|r| ActiveRequest {
+                        value: Parc::new(ActiveRequestType::Local(Some(r))),
+                        response_payload_type_details: self.response_payload_type_details.clone(),
+                        request_header_size: self.request_header_size,
+                        response_header_size: self.response_header_size,
+                    })
+            }),
+        }
+    }
+}
+
+#[pyclass]
+/// Iterates over all pending requests of a `Server`. Acquired via
+/// `Server::request_iterator()`.
+pub struct RequestIterator {
+    server: WeakServerType,
+    details: ActiveRequestDetails,
+}
+
+#[pymethods]
+impl RequestIterator {
+    pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Returns the next pending request as `ActiveRequest` or stops the iteration when no
+    /// request is left. Emits `ReceiveError` when the `Server` was dropped or a failure occurs.
+    pub fn __next__(&self) -> PyResult<Option<ActiveRequest>> {
+        let server = match &self.server {
+            WeakServerType::Ipc(v) => v.upgrade().map(ServerType::Ipc),
+            WeakServerType::Local(v) => v.upgrade().map(ServerType::Local),
+        };
+
+        match server {
+            Some(server) => self.details.receive(&server),
+            None => Err(ReceiveError::new_err(
+                "The server was dropped while iterating over its requests.",
+            )),
+        }
+    }
 }
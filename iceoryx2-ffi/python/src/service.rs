@@ -13,6 +13,7 @@
 use pyo3::prelude::*;
 
 use crate::{
+    attribute_verifier::AttributeVerifier,
     config::Config,
     error::{ServiceDetailsError, ServiceListError},
     messaging_pattern::MessagingPattern,
@@ -80,20 +81,36 @@ impl Service {
     }
 
     #[staticmethod]
-    /// Returns a list of all services created under a given `Config`.
-    pub fn list(config: &Config, service_type: ServiceType) -> PyResult<Vec<ServiceDetails>> {
+    #[pyo3(signature = (config, service_type, attribute_verifier = None))]
+    /// Returns a list of all services created under a given `Config`. When an
+    /// `AttributeVerifier` is provided, only the services whose attributes satisfy its
+    /// requirements are returned.
+    pub fn list(
+        config: &Config,
+        service_type: ServiceType,
+        attribute_verifier: Option<&AttributeVerifier>,
+    ) -> PyResult<Vec<ServiceDetails>> {
         use iceoryx2::service::Service;
+        let attribute_verifier = attribute_verifier.map(|v| v.0.clone()).unwrap_or_default();
         let mut ret_val = vec![];
         match service_type {
-            ServiceType::Ipc => crate::IpcService::list(&config.0.lock(), |service| {
-                ret_val.push(ServiceDetails(ServiceDetailsType::Ipc(service)));
-                iceoryx2::prelude::CallbackProgression::Continue
-            })
+            ServiceType::Ipc => crate::IpcService::list_with_attributes(
+                &config.0.lock(),
+                &attribute_verifier,
+                |service| {
+                    ret_val.push(ServiceDetails(ServiceDetailsType::Ipc(service)));
+                    iceoryx2::prelude::CallbackProgression::Continue
+                },
+            )
             .map_err(|e| ServiceListError::new_err(format!("{e:?}")))?,
-            ServiceType::Local => crate::LocalService::list(&config.0.lock(), |service| {
-                ret_val.push(ServiceDetails(ServiceDetailsType::Local(service)));
-                iceoryx2::prelude::CallbackProgression::Continue
-            })
+            ServiceType::Local => crate::LocalService::list_with_attributes(
+                &config.0.lock(),
+                &attribute_verifier,
+                |service| {
+                    ret_val.push(ServiceDetails(ServiceDetailsType::Local(service)));
+                    iceoryx2::prelude::CallbackProgression::Continue
+                },
+            )
             .map_err(|e| ServiceListError::new_err(format!("{e:?}")))?,
         };
 
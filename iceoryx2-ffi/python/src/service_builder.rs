@@ -10,11 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+use iceoryx2::service::builder::{CustomHeaderMarker, CustomKeyMarker, CustomPayloadMarker};
 use pyo3::prelude::*;
 
 use crate::service_builder_request_response::ServiceBuilderRequestResponseType;
 use crate::{
+    service_builder_blackboard::{ServiceBuilderBlackboard, ServiceBuilderBlackboardType},
     service_builder_event::{ServiceBuilderEvent, ServiceBuilderEventType},
     service_builder_publish_subscribe::{
         ServiceBuilderPublishSubscribe, ServiceBuilderPublishSubscribeType,
@@ -33,6 +34,24 @@ pub struct ServiceBuilder(pub(crate) ServiceBuilderType);
 
 #[pymethods]
 impl ServiceBuilder {
+    /// Create a new builder to create a `MessagingPattern::Blackboard` `Service`.
+    pub fn blackboard(&self) -> ServiceBuilderBlackboard {
+        match &self.0 {
+            ServiceBuilderType::Ipc(v) => {
+                let this = v.clone();
+                ServiceBuilderBlackboard(ServiceBuilderBlackboardType::Ipc(
+                    this.blackboard::<CustomKeyMarker>(),
+                ))
+            }
+            ServiceBuilderType::Local(v) => {
+                let this = v.clone();
+                ServiceBuilderBlackboard(ServiceBuilderBlackboardType::Local(
+                    this.blackboard::<CustomKeyMarker>(),
+                ))
+            }
+        }
+    }
+
     /// Create a new builder to create a `MessagingPattern::Event` `Service`.
     pub fn event(&self) -> ServiceBuilderEvent {
         match &self.0 {
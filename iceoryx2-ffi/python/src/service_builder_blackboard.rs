@@ -0,0 +1,331 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+use iceoryx2::service::builder::CustomKeyMarker;
+use pyo3::prelude::*;
+
+use crate::{
+    attribute_specifier::AttributeSpecifier,
+    attribute_verifier::AttributeVerifier,
+    duration::DurationLike,
+    error::{
+        BlackboardCreateError, BlackboardOpenError, BlackboardOpenExceedsMaxNumberOfNodesError,
+        BlackboardOpenOrCreateError, BlackboardOpenOrCreateExceedsMaxNumberOfNodesError,
+    },
+    parc::Parc,
+    port_factory_blackboard::{PortFactoryBlackboard, PortFactoryBlackboardType},
+    type_detail::TypeDetail,
+};
+
+#[derive(Clone)]
+pub(crate) enum ServiceBuilderBlackboardType {
+    Ipc(iceoryx2::service::builder::blackboard::Builder<CustomKeyMarker, crate::IpcService>),
+    Local(iceoryx2::service::builder::blackboard::Builder<CustomKeyMarker, crate::LocalService>),
+}
+
+#[pyclass]
+/// Builder to create new `MessagingPattern::Blackboard` based `Service`s
+pub struct ServiceBuilderBlackboard(pub(crate) ServiceBuilderBlackboardType);
+
+#[pymethods]
+impl ServiceBuilderBlackboard {
+    /// Defines the key type. To be able to connect to a `Service` the `TypeDetail` must be
+    /// identical in all participants since the communication is always strongly typed.
+    pub fn key_type_details(&self, value: &TypeDetail) -> Self {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                let this = unsafe { this.__internal_set_key_type_details(&value.0) };
+                Self(ServiceBuilderBlackboardType::Ipc(this))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                let this = unsafe { this.__internal_set_key_type_details(&value.0) };
+                Self(ServiceBuilderBlackboardType::Local(this))
+            }
+        }
+    }
+
+    /// Adds an entry with its initial value to the `Service`. The `key` must have the size of
+    /// the key type and the `value` the size of the `value_type`. The entries are defined by
+    /// the creator of the `Service`, entries added when an existing `Service` is opened are
+    /// ignored. Adding a key twice replaces the previous entry.
+    pub fn add(&self, key: &[u8], value_type: &TypeDetail, value: &[u8]) -> Self {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                let this = unsafe { this.__internal_add(key, &value_type.0, value) };
+                Self(ServiceBuilderBlackboardType::Ipc(this))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                let this = unsafe { this.__internal_add(key, &value_type.0, value) };
+                Self(ServiceBuilderBlackboardType::Local(this))
+            }
+        }
+    }
+
+    /// If the `Service` is created it defines how many `Reader`s shall be supported at most.
+    /// If an existing `Service` is opened it defines how many `Reader`s must be at least
+    /// supported.
+    pub fn max_readers(&self, value: usize) -> Self {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.max_readers(value);
+                Self(ServiceBuilderBlackboardType::Ipc(this))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                let this = this.max_readers(value);
+                Self(ServiceBuilderBlackboardType::Local(this))
+            }
+        }
+    }
+
+    /// If the `Service` is created it defines how many `Node`s shall be able to open it in
+    /// parallel. If an existing `Service` is opened it defines how many `Node`s must be at least
+    /// supported.
+    pub fn max_nodes(&self, value: usize) -> Self {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.max_nodes(value);
+                Self(ServiceBuilderBlackboardType::Ipc(this))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                let this = this.max_nodes(value);
+                Self(ServiceBuilderBlackboardType::Local(this))
+            }
+        }
+    }
+
+    /// Overrides the service creation timeout of the `Config` for this builder only. It defines
+    /// how long it is waited for a `Service` that is still being created by another process.
+    /// The `value` can be provided as `Duration` or as `datetime.timedelta`.
+    pub fn creation_timeout(&self, value: DurationLike) -> Self {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                Self(ServiceBuilderBlackboardType::Ipc(this))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                Self(ServiceBuilderBlackboardType::Local(this))
+            }
+        }
+    }
+
+    /// If the `Service` exists, it will be opened otherwise a new `Service` will be
+    /// created. On failure it emits a `BlackboardOpenOrCreateError`
+    pub fn open_or_create(&self) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
+                )))
+            }
+        }
+    }
+
+    /// If the `Service` exists, it will be opened otherwise a new `Service` will be
+    /// created. It defines a set of attributes. If the `Service` already exists all attribute
+    /// requirements must be satisfied otherwise the open process will fail. If the `Service`
+    /// does not exist the required attributes will be defined in the `Service`.
+    /// Emits a `BlackboardOpenOrCreateError` on failure.
+    pub fn open_or_create_with_attributes(
+        &self,
+        verifier: &AttributeVerifier,
+    ) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(
+                        this.open_or_create_with_attributes(&verifier.0)
+                            .map_err(open_or_create_error)?,
+                    ),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(
+                        this.open_or_create_with_attributes(&verifier.0)
+                            .map_err(open_or_create_error)?,
+                    ),
+                )))
+            }
+        }
+    }
+
+    /// Opens an existing `Service`. Emits a `BlackboardOpenError` on failure.
+    pub fn open(&self) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(this.open().map_err(open_error)?),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(this.open().map_err(open_error)?),
+                )))
+            }
+        }
+    }
+
+    /// Opens an existing `Service`. Returns `None` when the `Service` does not exist.
+    /// Emits a `BlackboardOpenError` on any other failure.
+    pub fn try_open(&self) -> PyResult<Option<PortFactoryBlackboard>> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryBlackboard(Parc::new(
+                        PortFactoryBlackboardType::Ipc(v),
+                    )))),
+                    Err(
+                        iceoryx2::service::builder::blackboard::BlackboardOpenError::DoesNotExist,
+                    ) => Ok(None),
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryBlackboard(Parc::new(
+                        PortFactoryBlackboardType::Local(v),
+                    )))),
+                    Err(
+                        iceoryx2::service::builder::blackboard::BlackboardOpenError::DoesNotExist,
+                    ) => Ok(None),
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+        }
+    }
+
+    /// Opens an existing `Service` with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail. Emits a `BlackboardOpenError`
+    /// on failure.
+    pub fn open_with_attributes(
+        &self,
+        verifier: &AttributeVerifier,
+    ) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
+                    ),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
+                    ),
+                )))
+            }
+        }
+    }
+
+    /// Creates a new `Service`. Emits a `BlackboardCreateError` on failure.
+    pub fn create(&self) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(
+                        this.create()
+                            .map_err(|e| BlackboardCreateError::new_err(format!("{e:?}")))?,
+                    ),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(
+                        this.create()
+                            .map_err(|e| BlackboardCreateError::new_err(format!("{e:?}")))?,
+                    ),
+                )))
+            }
+        }
+    }
+
+    /// Creates a new `Service` with a set of attributes. Emits a `BlackboardCreateError` on
+    /// failure.
+    pub fn create_with_attributes(
+        &self,
+        attributes: &AttributeSpecifier,
+    ) -> PyResult<PortFactoryBlackboard> {
+        match &self.0 {
+            ServiceBuilderBlackboardType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Ipc(
+                        this.create_with_attributes(&attributes.0)
+                            .map_err(|e| BlackboardCreateError::new_err(format!("{e:?}")))?,
+                    ),
+                )))
+            }
+            ServiceBuilderBlackboardType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryBlackboard(Parc::new(
+                    PortFactoryBlackboardType::Local(
+                        this.create_with_attributes(&attributes.0)
+                            .map_err(|e| BlackboardCreateError::new_err(format!("{e:?}")))?,
+                    ),
+                )))
+            }
+        }
+    }
+}
+
+fn open_error(e: iceoryx2::service::builder::blackboard::BlackboardOpenError) -> PyErr {
+    match e {
+        iceoryx2::service::builder::blackboard::BlackboardOpenError::ExceedsMaxNumberOfNodes => {
+            BlackboardOpenExceedsMaxNumberOfNodesError::new_err(format!("{e:?}"))
+        }
+        _ => BlackboardOpenError::new_err(format!("{e:?}")),
+    }
+}
+
+fn open_or_create_error(
+    e: iceoryx2::service::builder::blackboard::BlackboardOpenOrCreateError,
+) -> PyErr {
+    match e {
+        iceoryx2::service::builder::blackboard::BlackboardOpenOrCreateError::BlackboardOpenError(
+            iceoryx2::service::builder::blackboard::BlackboardOpenError::ExceedsMaxNumberOfNodes,
+        ) => BlackboardOpenOrCreateExceedsMaxNumberOfNodesError::new_err(format!("{e:?}")),
+        _ => BlackboardOpenOrCreateError::new_err(format!("{e:?}")),
+    }
+}
@@ -15,8 +15,11 @@ use pyo3::prelude::*;
 use crate::{
     attribute_specifier::AttributeSpecifier,
     attribute_verifier::AttributeVerifier,
-    duration::Duration,
-    error::{EventCreateError, EventOpenError, EventOpenOrCreateError},
+    duration::DurationLike,
+    error::{
+        EventCreateError, EventOpenError, EventOpenExceedsMaxNumberOfNodesError,
+        EventOpenOrCreateError, EventOpenOrCreateExceedsMaxNumberOfNodesError,
+    },
     event_id::EventId,
     parc::Parc,
     port_factory_event::{PortFactoryEvent, PortFactoryEventType},
@@ -34,17 +37,19 @@ pub struct ServiceBuilderEvent(pub(crate) ServiceBuilderEventType);
 #[pymethods]
 impl ServiceBuilderEvent {
     /// Enables the deadline property of the service. There must be a notification emitted by any
-    /// `Notifier` after at least the provided `deadline`.
-    pub fn deadline(&self, deadline: &Duration) -> Self {
+    /// `Notifier` after at least the provided `deadline`. The `deadline` can be provided as
+    /// `Duration` or as `datetime.timedelta`.
+    pub fn deadline(&self, deadline: DurationLike) -> Self {
+        let deadline = deadline.0;
         match &self.0 {
             ServiceBuilderEventType::Ipc(v) => {
                 let this = v.clone();
-                let this = this.deadline(deadline.0);
+                let this = this.deadline(deadline);
                 ServiceBuilderEvent(ServiceBuilderEventType::Ipc(this))
             }
             ServiceBuilderEventType::Local(v) => {
                 let this = v.clone();
-                let this = this.deadline(deadline.0);
+                let this = this.deadline(deadline);
                 ServiceBuilderEvent(ServiceBuilderEventType::Local(this))
             }
         }
@@ -85,9 +90,28 @@ impl ServiceBuilderEvent {
         }
     }
 
-    /// If the `Service` is created it set the greatest supported `NodeId` value
-    /// If an existing `Service` is opened it defines the value size the `NodeId`
-    /// must at least support.
+    /// Overrides the service creation timeout of the `Config` for this builder only. It defines
+    /// how long it is waited for a `Service` that is still being created by another process.
+    /// The `value` can be provided as `Duration` or as `datetime.timedelta`.
+    pub fn creation_timeout(&self, value: DurationLike) -> Self {
+        match &self.0 {
+            ServiceBuilderEventType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                ServiceBuilderEvent(ServiceBuilderEventType::Ipc(this))
+            }
+            ServiceBuilderEventType::Local(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                ServiceBuilderEvent(ServiceBuilderEventType::Local(this))
+            }
+        }
+    }
+
+    /// If the `Service` is created it sets the greatest supported `EventId` value.
+    /// If an existing `Service` is opened it defines the value size the `EventId`
+    /// must at least support. Notifying an `EventId` greater than this value emits
+    /// `ValueError`.
     pub fn event_id_max_value(&self, value: usize) -> Self {
         match &self.0 {
             ServiceBuilderEventType::Ipc(v) => {
@@ -103,6 +127,11 @@ impl ServiceBuilderEvent {
         }
     }
 
+    /// Same as `ServiceBuilderEvent.event_id_max_value()`.
+    pub fn max_event_id_value(&self, value: usize) -> Self {
+        self.event_id_max_value(value)
+    }
+
     /// If the `Service` is created it defines how many `Notifier` shall be supported at most. If
     /// an existing `Service` is opened it defines how many `Notifier` must be at least supported.
     pub fn max_notifiers(&self, value: usize) -> Self {
@@ -246,15 +275,13 @@ impl ServiceBuilderEvent {
             ServiceBuilderEventType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Ipc(
-                    this.open_or_create()
-                        .map_err(|e| EventOpenOrCreateError::new_err(format!("{e:?}")))?,
+                    this.open_or_create().map_err(open_or_create_error)?,
                 ))))
             }
             ServiceBuilderEventType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Local(
-                    this.open_or_create()
-                        .map_err(|e| EventOpenOrCreateError::new_err(format!("{e:?}")))?,
+                    this.open_or_create().map_err(open_or_create_error)?,
                 ))))
             }
         }
@@ -274,14 +301,14 @@ impl ServiceBuilderEvent {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Ipc(
                     this.open_or_create_with_attributes(&verifier.0)
-                        .map_err(|e| EventOpenOrCreateError::new_err(format!("{e:?}")))?,
+                        .map_err(open_or_create_error)?,
                 ))))
             }
             ServiceBuilderEventType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Local(
                     this.open_or_create_with_attributes(&verifier.0)
-                        .map_err(|e| EventOpenOrCreateError::new_err(format!("{e:?}")))?,
+                        .map_err(open_or_create_error)?,
                 ))))
             }
         }
@@ -293,20 +320,49 @@ impl ServiceBuilderEvent {
             ServiceBuilderEventType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Ipc(
-                    this.open()
-                        .map_err(|e| EventOpenError::new_err(format!("{e:?}")))?,
+                    this.open().map_err(open_error)?,
                 ))))
             }
             ServiceBuilderEventType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Local(
-                    this.open()
-                        .map_err(|e| EventOpenError::new_err(format!("{e:?}")))?,
+                    this.open().map_err(open_error)?,
                 ))))
             }
         }
     }
 
+    /// Opens an existing `Service`. Returns `None` when the `Service` does not exist.
+    /// Emits an `EventOpenError` on any other failure.
+    pub fn try_open(&self) -> PyResult<Option<PortFactoryEvent>> {
+        match &self.0 {
+            ServiceBuilderEventType::Ipc(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryEvent(Parc::new(
+                        PortFactoryEventType::Ipc(v),
+                    )))),
+                    Err(iceoryx2::service::builder::event::EventOpenError::DoesNotExist) => {
+                        Ok(None)
+                    }
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+            ServiceBuilderEventType::Local(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryEvent(Parc::new(
+                        PortFactoryEventType::Local(v),
+                    )))),
+                    Err(iceoryx2::service::builder::event::EventOpenError::DoesNotExist) => {
+                        Ok(None)
+                    }
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+        }
+    }
+
     /// Opens an existing `Service` with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail. Emits an `EventOpenError`
     /// on failure.
@@ -315,15 +371,13 @@ impl ServiceBuilderEvent {
             ServiceBuilderEventType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Ipc(
-                    this.open_with_attributes(&verifier.0)
-                        .map_err(|e| EventOpenError::new_err(format!("{e:?}")))?,
+                    this.open_with_attributes(&verifier.0).map_err(open_error)?,
                 ))))
             }
             ServiceBuilderEventType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryEvent(Parc::new(PortFactoryEventType::Local(
-                    this.open_with_attributes(&verifier.0)
-                        .map_err(|e| EventOpenError::new_err(format!("{e:?}")))?,
+                    this.open_with_attributes(&verifier.0).map_err(open_error)?,
                 ))))
             }
         }
@@ -372,3 +426,21 @@ impl ServiceBuilderEvent {
         }
     }
 }
+
+fn open_error(e: iceoryx2::service::builder::event::EventOpenError) -> PyErr {
+    match e {
+        iceoryx2::service::builder::event::EventOpenError::ExceedsMaxNumberOfNodes => {
+            EventOpenExceedsMaxNumberOfNodesError::new_err(format!("{e:?}"))
+        }
+        _ => EventOpenError::new_err(format!("{e:?}")),
+    }
+}
+
+fn open_or_create_error(e: iceoryx2::service::builder::event::EventOpenOrCreateError) -> PyErr {
+    match e {
+        iceoryx2::service::builder::event::EventOpenOrCreateError::EventOpenError(
+            iceoryx2::service::builder::event::EventOpenError::ExceedsMaxNumberOfNodes,
+        ) => EventOpenOrCreateExceedsMaxNumberOfNodesError::new_err(format!("{e:?}")),
+        _ => EventOpenOrCreateError::new_err(format!("{e:?}")),
+    }
+}
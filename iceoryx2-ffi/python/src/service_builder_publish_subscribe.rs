@@ -16,13 +16,18 @@ use pyo3::prelude::*;
 use crate::alignment::Alignment;
 use crate::attribute_specifier::AttributeSpecifier;
 use crate::attribute_verifier::AttributeVerifier;
+use crate::duration::DurationLike;
 use crate::error::{
-    PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+    PublishSubscribeCreateError, PublishSubscribeOpenError,
+    PublishSubscribeOpenExceedsMaxNumberOfNodesError, PublishSubscribeOpenOrCreateError,
+    PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError,
 };
 use crate::port_factory_publish_subscribe::{
     PortFactoryPublishSubscribe, PortFactoryPublishSubscribeType,
 };
+use crate::type_compatibility::TypeCompatibility;
 use crate::type_detail::TypeDetail;
+use crate::type_name::type_name_override;
 use crate::type_storage::TypeStorage;
 
 type IpcBuilder = iceoryx2::service::builder::publish_subscribe::Builder<
@@ -138,6 +143,43 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 
+    /// Overrides the type name of the payload, e.g. with a language neutral name like
+    /// `com.acme.Image`, so that participants in different languages can agree on it. Emits
+    /// `SemanticStringError` when the name is empty, too long or not printable ASCII.
+    pub fn payload_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.payload_type_name(&value);
+                Ok(self.clone_ipc(this))
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.payload_type_name(&value);
+                Ok(self.clone_local(this))
+            }
+        }
+    }
+
+    /// Overrides the type name of the user header. Emits `SemanticStringError` when the
+    /// name is empty, too long or not printable ASCII.
+    pub fn user_header_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.user_header_type_name(&value);
+                Ok(self.clone_ipc(this))
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.user_header_type_name(&value);
+                Ok(self.clone_local(this))
+            }
+        }
+    }
+
     /// If the `Service` is created, defines the overflow behavior of the service. If an existing
     /// `Service` is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(&self, value: bool) -> Self {
@@ -155,6 +197,80 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 
+    /// If the `Service` is created, defines how the payload and user header types are matched
+    /// when the service is opened. If an existing `Service` is opened it requires the service to
+    /// use the same `TypeCompatibility`.
+    pub fn type_compatibility(&self, value: &TypeCompatibility) -> Self {
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.type_compatibility(value.clone().into());
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.type_compatibility(value.clone().into());
+                self.clone_local(this)
+            }
+        }
+    }
+
+    /// If the `Service` is created, defines if the `Publisher` stores a send timestamp in the
+    /// header of every `Sample`. The header has the same size either way. If an existing
+    /// `Service` is opened it requires the service to have the defined send timestamp behavior.
+    pub fn enable_send_timestamp(&self, value: bool) -> Self {
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.enable_send_timestamp(value);
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.enable_send_timestamp(value);
+                self.clone_local(this)
+            }
+        }
+    }
+
+    /// If the `Service` is created, defines how long a `Sample` is valid after it was sent. The
+    /// `Subscriber` discards and releases older `Sample`s instead of receiving them. A lifespan
+    /// of zero disables the lifespan. If an existing `Service` is opened it requires the
+    /// service to have the same lifespan. The `value` can be provided as `Duration` or as
+    /// `datetime.timedelta`.
+    pub fn sample_lifespan(&self, value: DurationLike) -> Self {
+        let value = value.0;
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.sample_lifespan(value);
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.sample_lifespan(value);
+                self.clone_local(this)
+            }
+        }
+    }
+
+    /// If the `Service` is created, `Sample`s never expire. If an existing `Service` is opened
+    /// it requires the service to have no sample lifespan.
+    pub fn disable_sample_lifespan(&self) -> Self {
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.disable_sample_lifespan();
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.disable_sample_lifespan();
+                self.clone_local(this)
+            }
+        }
+    }
+
     /// If the `Service` is created it defines how many `Sample`s a
     /// `Subscriber` can borrow at most in parallel. If an existing
     /// `Service` is opened it defines the minimum required.
@@ -261,6 +377,24 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 
+    /// Overrides the service creation timeout of the `Config` for this builder only. It defines
+    /// how long it is waited for a `Service` that is still being created by another process.
+    /// The `value` can be provided as `Duration` or as `datetime.timedelta`.
+    pub fn creation_timeout(&self, value: DurationLike) -> Self {
+        match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                self.clone_ipc(this)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                self.clone_local(this)
+            }
+        }
+    }
+
     /// If the `Service` exists, it will be opened otherwise a new `Service` will be created.
     /// On failure it emits `PublishSubscribeOpenOrCreateError`
     pub fn open_or_create(&self) -> PyResult<PortFactoryPublishSubscribe> {
@@ -268,9 +402,9 @@ impl ServiceBuilderPublishSubscribe {
             ServiceBuilderPublishSubscribeType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
-                    PortFactoryPublishSubscribeType::Ipc(this.open_or_create().map_err(|e| {
-                        PublishSubscribeOpenOrCreateError::new_err(format!("{e:?}"))
-                    })?),
+                    PortFactoryPublishSubscribeType::Ipc(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
                 ))
@@ -278,9 +412,9 @@ impl ServiceBuilderPublishSubscribe {
             ServiceBuilderPublishSubscribeType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
-                    PortFactoryPublishSubscribeType::Local(this.open_or_create().map_err(|e| {
-                        PublishSubscribeOpenOrCreateError::new_err(format!("{e:?}"))
-                    })?),
+                    PortFactoryPublishSubscribeType::Local(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
                 ))
@@ -303,9 +437,7 @@ impl ServiceBuilderPublishSubscribe {
                 Ok(PortFactoryPublishSubscribe::new(
                     PortFactoryPublishSubscribeType::Ipc(
                         this.open_or_create_with_attributes(&verifier.0)
-                            .map_err(|e| {
-                                PublishSubscribeOpenOrCreateError::new_err(format!("{e:?}"))
-                            })?,
+                            .map_err(open_or_create_error)?,
                     ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
@@ -316,9 +448,7 @@ impl ServiceBuilderPublishSubscribe {
                 Ok(PortFactoryPublishSubscribe::new(
                     PortFactoryPublishSubscribeType::Local(
                         this.open_or_create_with_attributes(&verifier.0)
-                            .map_err(|e| {
-                                PublishSubscribeOpenOrCreateError::new_err(format!("{e:?}"))
-                            })?,
+                            .map_err(open_or_create_error)?,
                     ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
@@ -334,10 +464,7 @@ impl ServiceBuilderPublishSubscribe {
             ServiceBuilderPublishSubscribeType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
-                    PortFactoryPublishSubscribeType::Ipc(
-                        this.open()
-                            .map_err(|e| PublishSubscribeOpenError::new_err(format!("{e:?}")))?,
-                    ),
+                    PortFactoryPublishSubscribeType::Ipc(this.open().map_err(open_error)?),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
                 ))
@@ -345,10 +472,7 @@ impl ServiceBuilderPublishSubscribe {
             ServiceBuilderPublishSubscribeType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
-                    PortFactoryPublishSubscribeType::Local(
-                        this.open()
-                            .map_err(|e| PublishSubscribeOpenError::new_err(format!("{e:?}")))?,
-                    ),
+                    PortFactoryPublishSubscribeType::Local(this.open().map_err(open_error)?),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
                 ))
@@ -356,6 +480,31 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 
+    /// Opens an existing `Service`. Returns `None` when the `Service` does not exist.
+    /// On any other failure it emits `PublishSubscribeOpenError`.
+    pub fn try_open(&self) -> PyResult<Option<PortFactoryPublishSubscribe>> {
+        let port_factory = match &self.value {
+            ServiceBuilderPublishSubscribeType::Ipc(v) => {
+                let this = v.clone();
+                this.open().map(PortFactoryPublishSubscribeType::Ipc)
+            }
+            ServiceBuilderPublishSubscribeType::Local(v) => {
+                let this = v.clone();
+                this.open().map(PortFactoryPublishSubscribeType::Local)
+            }
+        };
+
+        match port_factory {
+            Ok(v) => Ok(Some(PortFactoryPublishSubscribe::new(
+                v,
+                self.payload_type_details.clone(),
+                self.user_header_type_details.clone(),
+            ))),
+            Err(iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError::DoesNotExist) => Ok(None),
+            Err(e) => Err(open_error(e)),
+        }
+    }
+
     /// Opens an existing `Service` with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     /// On failure it emits `PublishSubscribeOpenError`.
@@ -368,8 +517,7 @@ impl ServiceBuilderPublishSubscribe {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
                     PortFactoryPublishSubscribeType::Ipc(
-                        this.open_with_attributes(&verifier.0)
-                            .map_err(|e| PublishSubscribeOpenError::new_err(format!("{e:?}")))?,
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
                     ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
@@ -379,8 +527,7 @@ impl ServiceBuilderPublishSubscribe {
                 let this = v.clone();
                 Ok(PortFactoryPublishSubscribe::new(
                     PortFactoryPublishSubscribeType::Local(
-                        this.open_with_attributes(&verifier.0)
-                            .map_err(|e| PublishSubscribeOpenError::new_err(format!("{e:?}")))?,
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
                     ),
                     self.payload_type_details.clone(),
                     self.user_header_type_details.clone(),
@@ -450,3 +597,25 @@ impl ServiceBuilderPublishSubscribe {
         }
     }
 }
+
+fn open_error(
+    e: iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError,
+) -> PyErr {
+    match e {
+        iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes => {
+            PublishSubscribeOpenExceedsMaxNumberOfNodesError::new_err(format!("{e:?}"))
+        }
+        _ => PublishSubscribeOpenError::new_err(format!("{e:?}")),
+    }
+}
+
+fn open_or_create_error(
+    e: iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError,
+) -> PyErr {
+    match e {
+        iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(
+            iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes,
+        ) => PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError::new_err(format!("{e:?}")),
+        _ => PublishSubscribeOpenOrCreateError::new_err(format!("{e:?}")),
+    }
+}
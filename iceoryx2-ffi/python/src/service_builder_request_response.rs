@@ -16,14 +16,19 @@ use pyo3::prelude::*;
 use crate::alignment::Alignment;
 use crate::attribute_specifier::AttributeSpecifier;
 use crate::attribute_verifier::AttributeVerifier;
+use crate::duration::DurationLike;
 use crate::error::{
-    RequestResponseCreateError, RequestResponseOpenError, RequestResponseOpenOrCreateError,
+    RequestResponseCreateError, RequestResponseOpenError,
+    RequestResponseOpenExceedsMaxNumberOfNodesError, RequestResponseOpenOrCreateError,
+    RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError, SemanticStringError,
 };
 use crate::parc::Parc;
 use crate::port_factory_request_response::{
     PortFactoryRequestResponse, PortFactoryRequestResponseType,
 };
+use crate::type_compatibility::TypeCompatibility;
 use crate::type_detail::TypeDetail;
+use crate::type_name::type_name_override;
 
 #[derive(Clone)]
 pub(crate) enum ServiceBuilderRequestResponseType {
@@ -51,8 +56,71 @@ pub(crate) enum ServiceBuilderRequestResponseType {
 /// Builder to create new `MessagingPattern::RequestResponse` based `Service`s
 pub struct ServiceBuilderRequestResponse(pub(crate) ServiceBuilderRequestResponseType);
 
+impl ServiceBuilderRequestResponse {
+    fn settings<ServiceType: iceoryx2::service::Service>(
+        builder: &iceoryx2::service::builder::request_response::Builder<
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            [CustomPayloadMarker],
+            CustomHeaderMarker,
+            ServiceType,
+        >,
+    ) -> String {
+        let config = builder.__internal_static_config();
+        format!(
+            "max_active_requests_per_client={}, max_request_queue_depth_per_client={}, \
+            max_loaned_requests={}, max_response_buffer_size={}, \
+            max_borrowed_responses_per_pending_response={}, max_servers={}, max_clients={}, \
+            max_nodes={}, enable_safe_overflow_for_requests={}, \
+            enable_safe_overflow_for_responses={}, enable_fire_and_forget_requests={}, \
+            enable_multi_response={}, request_payload_type_details_set={}, \
+            request_header_type_details_set={}, response_payload_type_details_set={}, \
+            response_header_type_details_set={}",
+            config.max_active_requests_per_client(),
+            config.max_request_queue_depth_per_client(),
+            config.max_loaned_requests(),
+            config.max_response_buffer_size(),
+            config.max_borrowed_responses_per_pending_response(),
+            config.max_servers(),
+            config.max_clients(),
+            config.max_nodes(),
+            python_bool(config.has_safe_overflow_for_requests()),
+            python_bool(config.has_safe_overflow_for_responses()),
+            python_bool(config.does_support_fire_and_forget_requests()),
+            python_bool(config.does_support_multi_response()),
+            python_bool(builder.__internal_request_payload_type_details().is_some()),
+            python_bool(builder.__internal_request_header_type_details().is_some()),
+            python_bool(builder.__internal_response_payload_type_details().is_some()),
+            python_bool(builder.__internal_response_header_type_details().is_some()),
+        )
+    }
+}
+
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
 #[pymethods]
 impl ServiceBuilderRequestResponse {
+    pub fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    /// Returns the current settings of the builder, the configured limits, the overflow and
+    /// feature flags and whether the payload and header type details are set, so that the
+    /// configuration can be verified right before `create()`.
+    pub fn __repr__(&self) -> String {
+        let settings = match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => Self::settings(v),
+            ServiceBuilderRequestResponseType::Local(v) => Self::settings(v),
+        };
+        format!("ServiceBuilderRequestResponse({settings})")
+    }
+
     /// Defines the payload type for requests. To be able to connect to a `Service` the
     /// `TypeDetail` must be identical in all participants since the communication is always
     /// strongly typed.
@@ -88,6 +156,17 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// Shorthand for `request_header_type_details()` when only the size and the alignment of
+    /// the request header are known, for instance in a bridge or a proxy. It reserves `size`
+    /// bytes with the provided `alignment` for the header.
+    /// If the alignment is zero or not a power of 2 it emits an `InvalidAlignmentValue`.
+    pub fn with_request_header_size(&self, size: usize, alignment: usize) -> PyResult<Self> {
+        let alignment = Alignment::new(alignment)?;
+        Ok(self.request_header_type_details(
+            &TypeDetail::new().size(size).alignment(alignment.value()),
+        ))
+    }
+
     /// Defines the payload type for responses. To be able to connect to a `Service` the
     /// `TypeDetail` must be identical in all participants since the communication is always
     /// strongly typed.
@@ -123,6 +202,90 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// Shorthand for `response_header_type_details()` when only the size and the alignment of
+    /// the response header are known, for instance in a bridge or a proxy. It reserves `size`
+    /// bytes with the provided `alignment` for the header.
+    /// If the alignment is zero or not a power of 2 it emits an `InvalidAlignmentValue`.
+    pub fn with_response_header_size(&self, size: usize, alignment: usize) -> PyResult<Self> {
+        let alignment = Alignment::new(alignment)?;
+        Ok(self.response_header_type_details(
+            &TypeDetail::new().size(size).alignment(alignment.value()),
+        ))
+    }
+
+    /// Overrides the type name of the request payload, e.g. with a language neutral name
+    /// like `com.acme.Request`. Emits `SemanticStringError` when the name is empty, too long
+    /// or not printable ASCII.
+    pub fn request_payload_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.request_payload_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Ipc(this)))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.request_payload_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Local(this)))
+            }
+        }
+    }
+
+    /// Overrides the type name of the response payload. Emits `SemanticStringError` when
+    /// the name is empty, too long or not printable ASCII.
+    pub fn response_payload_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.response_payload_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Ipc(this)))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.response_payload_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Local(this)))
+            }
+        }
+    }
+
+    /// Overrides the type name of the request user header. Emits `SemanticStringError`
+    /// when the name is empty, too long or not printable ASCII.
+    pub fn request_user_header_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.request_user_header_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Ipc(this)))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.request_user_header_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Local(this)))
+            }
+        }
+    }
+
+    /// Overrides the type name of the response user header. Emits `SemanticStringError`
+    /// when the name is empty, too long or not printable ASCII.
+    pub fn response_user_header_type_name(&self, value: &str) -> PyResult<Self> {
+        let value = type_name_override(value)?;
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.response_user_header_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Ipc(this)))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.response_user_header_type_name(&value);
+                Ok(Self(ServiceBuilderRequestResponseType::Local(this)))
+            }
+        }
+    }
+
     /// Overrides and increases the alignment of the request payload - useful when the payload is
     /// used in SIMD operations. To be able to connect to a `Service` the payload alignment must be
     /// identical in all participants since the communication is always strongly typed.
@@ -195,6 +358,24 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// If the `Service` is created, defines how the payload and user header types are matched
+    /// when the service is opened. If an existing `Service` is opened it requires the service to
+    /// use the same `TypeCompatibility`.
+    pub fn type_compatibility(&self, value: &TypeCompatibility) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.type_compatibility(value.clone().into());
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.type_compatibility(value.clone().into());
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
     /// If the `Service` is created, defines the fire-and-forget behavior of the service for
     /// requests.
     pub fn enable_fire_and_forget_requests(&self, value: bool) -> Self {
@@ -212,6 +393,100 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// If the `Service` is created, defines if a `Server` can send a stream of arbitrary many
+    /// `Response`s per `ActiveRequest` or exactly one. If an existing `Service` is opened it
+    /// requires the service to have the defined multi response behavior.
+    pub fn enable_multi_response(&self, value: bool) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.enable_multi_response(value);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.enable_multi_response(value);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Enables the response deadline property of the service. A `Server` must send the first
+    /// `Response` to a request within the provided `deadline`, otherwise the
+    /// `PendingResponse` has missed its deadline. The `deadline` can be provided as
+    /// `Duration` or as `datetime.timedelta`.
+    pub fn with_response_deadline(&self, deadline: DurationLike) -> Self {
+        let deadline = deadline.0;
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.response_deadline(deadline);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.response_deadline(deadline);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Disables the response deadline property of the service. `Server`s can respond to
+    /// requests at any time.
+    pub fn disable_response_deadline(&self) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.disable_response_deadline();
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.disable_response_deadline();
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Enables credit based flow control so that a `Client` cannot flood the request queues
+    /// of the `Server`s. Every `Server` grants every `Client` `credits_per_client` credits and
+    /// every request it receives consumes one of them. The credit is returned when the `Server`
+    /// sends the first `Response` or drops the `ActiveRequest`. With multiple `Server`s the
+    /// slowest one determines the available credits. When no credits are left, sending a request
+    /// emits `SendError`. If an existing `Service` is opened it requires the service to have
+    /// the same setting.
+    pub fn enable_credit_flow_control(&self, credits_per_client: u32) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.enable_credit_flow_control(credits_per_client);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.enable_credit_flow_control(credits_per_client);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Disables the credit based flow control of the service. `Client`s can send requests
+    /// until the request queue of the `Server` is full.
+    pub fn disable_credit_flow_control(&self) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.disable_credit_flow_control();
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.disable_credit_flow_control();
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
     /// Defines how many active requests a `Server` can hold in
     /// parallel per `Client`. The objects are used to send answers to a request that was
     /// received earlier from a `Client`.
@@ -230,6 +505,29 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// Defines the depth of the queue in which the requests of every `Client` wait until the
+    /// `Server` receives them. When not set, it is equal to `max_active_requests_per_client`,
+    /// which limits all requests of a `Client` that are not yet completed, the queued ones as
+    /// well as the ones the `Server` has already received. Therefore, the depth is limited to
+    /// `max_active_requests_per_client`. A smaller depth reduces the memory of every `Client`
+    /// data segment, which contains `max_servers * (max_active_requests_per_client +
+    /// max_request_queue_depth_per_client) + max_loaned_requests` requests.
+    /// If an existing `Service` is opened it defines the minimum required.
+    pub fn max_request_queue_depth_per_client(&self, value: usize) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.max_request_queue_depth_per_client(value);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.max_request_queue_depth_per_client(value);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
     /// If the `Service` is created it defines how many `RequestMut` a
     /// `Client` can loan in parallel.
     pub fn max_loaned_requests(&self, value: usize) -> Self {
@@ -318,6 +616,85 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// If the `Service` is created it defines the maximum number of payload elements of a
+    /// request, for a byte payload this is the number of bytes. Sending a larger request fails
+    /// with `LoanError`. If an existing `Service` is opened, the limit of the existing `Service`
+    /// applies.
+    pub fn max_request_payload_size(&self, value: usize) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.max_request_payload_size(value);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.max_request_payload_size(value);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// If the `Service` is created it defines the maximum number of payload elements of a
+    /// response, for a byte payload this is the number of bytes. Sending a larger response fails
+    /// with `LoanError`. If an existing `Service` is opened, the limit of the existing `Service`
+    /// applies.
+    pub fn max_response_payload_size(&self, value: usize) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.max_response_payload_size(value);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.max_response_payload_size(value);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Overrides the service creation timeout of the `Config` for this builder only. It defines
+    /// how long it is waited for a `Service` that is still being created by another process.
+    /// The `value` can be provided as `Duration` or as `datetime.timedelta`.
+    pub fn creation_timeout(&self, value: DurationLike) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.creation_timeout(value.0);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
+    /// Prepends the `prefix` to the `ServiceName` of the builder to group `Service`s into
+    /// name-spaces, e.g. with the prefix `"robot/arm/"` the builder for `"joint_state"`
+    /// creates or opens the `Service` named `"robot/arm/joint_state"`.
+    /// Emits `SemanticStringError` when the prefixed name is not a valid `ServiceName`.
+    pub fn with_topic_prefix(&self, prefix: &str) -> PyResult<Self> {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this
+                    .with_topic_prefix(prefix)
+                    .map_err(|e| SemanticStringError::new_err(format!("{e:?}")))?;
+                Ok(Self(ServiceBuilderRequestResponseType::Ipc(this)))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this
+                    .with_topic_prefix(prefix)
+                    .map_err(|e| SemanticStringError::new_err(format!("{e:?}")))?;
+                Ok(Self(ServiceBuilderRequestResponseType::Local(this)))
+            }
+        }
+    }
+
     /// If the `Service` is created it defines how many `Response`s shall
     /// be able to be borrowed in parallel per `PendingResponse`. If an
     /// existing `Service` is opened it defines how many borrows must be at least supported.
@@ -336,6 +713,26 @@ impl ServiceBuilderRequestResponse {
         }
     }
 
+    /// Relaxes the payload type check when an existing `Service` is opened. The payload of the
+    /// `Service` may then be larger or smaller than the local payload, e.g. when it was created
+    /// with a newer or an older version of the type that differs in its trailing fields. When a
+    /// received payload is smaller than the local one, the fields unknown to the sender are
+    /// zeroed. The static configuration of the opened `Service` contains the local payload sizes.
+    pub fn enable_type_evolution(&self, value: bool) -> Self {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                let this = this.enable_type_evolution(value);
+                Self(ServiceBuilderRequestResponseType::Ipc(this))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                let this = this.enable_type_evolution(value);
+                Self(ServiceBuilderRequestResponseType::Local(this))
+            }
+        }
+    }
+
     /// If the `Service` exists, it will be opened otherwise a new `Service` will be created.
     /// On failure `RequestResponseOpenOrCreateError` will be emitted.
     pub fn open_or_create(&self) -> PyResult<PortFactoryRequestResponse> {
@@ -343,17 +740,17 @@ impl ServiceBuilderRequestResponse {
             ServiceBuilderRequestResponseType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
-                    PortFactoryRequestResponseType::Ipc(this.open_or_create().map_err(|e| {
-                        RequestResponseOpenOrCreateError::new_err(format!("{e:?}"))
-                    })?),
+                    PortFactoryRequestResponseType::Ipc(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
                 )))
             }
             ServiceBuilderRequestResponseType::Local(v) => {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
-                    PortFactoryRequestResponseType::Local(this.open_or_create().map_err(|e| {
-                        RequestResponseOpenOrCreateError::new_err(format!("{e:?}"))
-                    })?),
+                    PortFactoryRequestResponseType::Local(
+                        this.open_or_create().map_err(open_or_create_error)?,
+                    ),
                 )))
             }
         }
@@ -376,9 +773,7 @@ impl ServiceBuilderRequestResponse {
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Ipc(
                         this.open_or_create_with_attributes(&verifier.0)
-                            .map_err(|e| {
-                                RequestResponseOpenOrCreateError::new_err(format!("{e:?}"))
-                            })?,
+                            .map_err(open_or_create_error)?,
                     ),
                 )))
             }
@@ -387,9 +782,7 @@ impl ServiceBuilderRequestResponse {
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Local(
                         this.open_or_create_with_attributes(&verifier.0)
-                            .map_err(|e| {
-                                RequestResponseOpenOrCreateError::new_err(format!("{e:?}"))
-                            })?,
+                            .map_err(open_or_create_error)?,
                     ),
                 )))
             }
@@ -399,13 +792,62 @@ impl ServiceBuilderRequestResponse {
     /// Opens an existing `Service`.
     /// On failure `RequestResponseOpenError` will be emitted.
     pub fn open(&self) -> PyResult<PortFactoryRequestResponse> {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryRequestResponse(Parc::new(
+                    PortFactoryRequestResponseType::Ipc(this.open().map_err(open_error)?),
+                )))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryRequestResponse(Parc::new(
+                    PortFactoryRequestResponseType::Local(this.open().map_err(open_error)?),
+                )))
+            }
+        }
+    }
+
+    /// Opens an existing `Service`. Returns `None` when the `Service` does not exist.
+    /// On any other failure `RequestResponseOpenError` will be emitted.
+    pub fn try_open(&self) -> PyResult<Option<PortFactoryRequestResponse>> {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryRequestResponse(Parc::new(
+                        PortFactoryRequestResponseType::Ipc(v),
+                    )))),
+                    Err(iceoryx2::service::builder::request_response::RequestResponseOpenError::DoesNotExist) => Ok(None),
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                match this.open() {
+                    Ok(v) => Ok(Some(PortFactoryRequestResponse(Parc::new(
+                        PortFactoryRequestResponseType::Local(v),
+                    )))),
+                    Err(iceoryx2::service::builder::request_response::RequestResponseOpenError::DoesNotExist) => Ok(None),
+                    Err(e) => Err(open_error(e)),
+                }
+            }
+        }
+    }
+
+    /// Opens an existing `Service` with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    /// On failure `RequestResponseOpenError` will be emitted.
+    pub fn open_with_attributes(
+        &self,
+        verifier: &AttributeVerifier,
+    ) -> PyResult<PortFactoryRequestResponse> {
         match &self.0 {
             ServiceBuilderRequestResponseType::Ipc(v) => {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Ipc(
-                        this.open()
-                            .map_err(|e| RequestResponseOpenError::new_err(format!("{e:?}")))?,
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
                     ),
                 )))
             }
@@ -413,18 +855,39 @@ impl ServiceBuilderRequestResponse {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Local(
-                        this.open()
-                            .map_err(|e| RequestResponseOpenError::new_err(format!("{e:?}")))?,
+                        this.open_with_attributes(&verifier.0).map_err(open_error)?,
                     ),
                 )))
             }
         }
     }
 
-    /// Opens an existing `Service` with attribute requirements. If the defined attribute
-    /// requirements are not satisfied the open process will fail.
+    /// Opens an existing `Service` like `open()` but requires that all explicitly defined
+    /// limits, like `max_clients`, match the limits of the `Service` exactly. `open()` treats
+    /// them as minimum requirements.
     /// On failure `RequestResponseOpenError` will be emitted.
-    pub fn open_with_attributes(
+    pub fn open_strict(&self) -> PyResult<PortFactoryRequestResponse> {
+        match &self.0 {
+            ServiceBuilderRequestResponseType::Ipc(v) => {
+                let this = v.clone();
+                Ok(PortFactoryRequestResponse(Parc::new(
+                    PortFactoryRequestResponseType::Ipc(this.open_strict().map_err(open_error)?),
+                )))
+            }
+            ServiceBuilderRequestResponseType::Local(v) => {
+                let this = v.clone();
+                Ok(PortFactoryRequestResponse(Parc::new(
+                    PortFactoryRequestResponseType::Local(this.open_strict().map_err(open_error)?),
+                )))
+            }
+        }
+    }
+
+    /// Opens an existing `Service` like `open_with_attributes()` but requires that all
+    /// explicitly defined limits, like `max_clients`, match the limits of the `Service`
+    /// exactly. `open_with_attributes()` treats them as minimum requirements.
+    /// On failure `RequestResponseOpenError` will be emitted.
+    pub fn open_strict_with_attributes(
         &self,
         verifier: &AttributeVerifier,
     ) -> PyResult<PortFactoryRequestResponse> {
@@ -433,8 +896,8 @@ impl ServiceBuilderRequestResponse {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Ipc(
-                        this.open_with_attributes(&verifier.0)
-                            .map_err(|e| RequestResponseOpenError::new_err(format!("{e:?}")))?,
+                        this.open_strict_with_attributes(&verifier.0)
+                            .map_err(open_error)?,
                     ),
                 )))
             }
@@ -442,8 +905,8 @@ impl ServiceBuilderRequestResponse {
                 let this = v.clone();
                 Ok(PortFactoryRequestResponse(Parc::new(
                     PortFactoryRequestResponseType::Local(
-                        this.open_with_attributes(&verifier.0)
-                            .map_err(|e| RequestResponseOpenError::new_err(format!("{e:?}")))?,
+                        this.open_strict_with_attributes(&verifier.0)
+                            .map_err(open_error)?,
                     ),
                 )))
             }
@@ -503,3 +966,23 @@ impl ServiceBuilderRequestResponse {
         }
     }
 }
+
+fn open_error(e: iceoryx2::service::builder::request_response::RequestResponseOpenError) -> PyErr {
+    match e {
+        iceoryx2::service::builder::request_response::RequestResponseOpenError::ExceedsMaxNumberOfNodes => {
+            RequestResponseOpenExceedsMaxNumberOfNodesError::new_err(format!("{e:?}"))
+        }
+        _ => RequestResponseOpenError::new_err(format!("{e:?}")),
+    }
+}
+
+fn open_or_create_error(
+    e: iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError,
+) -> PyErr {
+    match e {
+        iceoryx2::service::builder::request_response::RequestResponseOpenOrCreateError::RequestResponseOpenError(
+            iceoryx2::service::builder::request_response::RequestResponseOpenError::ExceedsMaxNumberOfNodes,
+        ) => RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError::new_err(format!("{e:?}")),
+        _ => RequestResponseOpenOrCreateError::new_err(format!("{e:?}")),
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::prelude::*;
+
+#[pyclass(str = "{0:?}", eq)]
+#[derive(PartialEq, Eq)]
+/// The shared memory footprint of a `MessagingPattern::RequestResponse` based `Service`.
+/// The pool sizes are the sizes the allocators of the data segments actually provide.
+pub struct ServiceMemoryFootprint(
+    pub(crate) iceoryx2::service::port_factory::request_response::ServiceMemoryFootprint,
+);
+
+#[pymethods]
+impl ServiceMemoryFootprint {
+    #[getter]
+    /// Returns the sum of all other sizes.
+    pub fn total_bytes(&self) -> u64 {
+        self.0.total_bytes
+    }
+
+    #[getter]
+    /// Returns the number of bytes of the data segments of all `Client`s that provide the
+    /// requests.
+    pub fn request_pool_bytes(&self) -> u64 {
+        self.0.request_pool_bytes
+    }
+
+    #[getter]
+    /// Returns the number of bytes of the data segments of all `Server`s that provide the
+    /// responses.
+    pub fn response_pool_bytes(&self) -> u64 {
+        self.0.response_pool_bytes
+    }
+
+    #[getter]
+    /// Returns the number of bytes of the static and dynamic configuration of the `Service`.
+    pub fn metadata_bytes(&self) -> u64 {
+        self.0.metadata_bytes
+    }
+}
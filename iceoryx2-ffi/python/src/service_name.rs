@@ -38,6 +38,13 @@ impl ServiceName {
         iceoryx2::prelude::ServiceName::max_len()
     }
 
+    /// Returns the remainder of the `ServiceName` without the `prefix`, e.g. `"joint_state"`
+    /// for the name `"robot/arm/joint_state"` and the prefix `"robot/arm/"`. Returns `None`
+    /// when the `ServiceName` does not start with the `prefix`.
+    pub fn strip_topic_prefix(&self, prefix: &str) -> Option<String> {
+        self.0.strip_topic_prefix(prefix).map(|v| v.to_string())
+    }
+
     /// Converts the `ServiceName` into a `String`
     #[allow(clippy::inherent_to_string)] // method required to generate this API in Python
     pub fn to_string(&self) -> String {
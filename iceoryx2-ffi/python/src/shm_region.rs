@@ -0,0 +1,79 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::prelude::*;
+
+#[pyclass(str = "{0:?}", eq)]
+#[derive(PartialEq)]
+/// Snapshot of the memory usage of a `SharedMemoryRegion`. All sizes are measured in bytes of
+/// the chunks the region provides for samples, requests or responses.
+pub struct ShmUsageStats(pub(crate) iceoryx2::node::shm_region::ShmUsageStats);
+
+#[pymethods]
+impl ShmUsageStats {
+    #[getter]
+    /// Returns the name of the `SharedMemoryRegion`.
+    pub fn region_name(&self) -> String {
+        self.0.region_name().to_string()
+    }
+
+    #[getter]
+    /// Returns the number of bytes of all chunks of the region.
+    pub fn total_bytes(&self) -> usize {
+        self.0.total_bytes()
+    }
+
+    #[getter]
+    /// Returns the number of bytes of all chunks that are currently loaned or sent and not yet
+    /// released by all receivers.
+    pub fn used_bytes(&self) -> usize {
+        self.0.used_bytes()
+    }
+
+    #[getter]
+    /// Returns the number of bytes of all unused chunks.
+    pub fn free_bytes(&self) -> usize {
+        self.0.free_bytes()
+    }
+
+    #[getter]
+    /// Returns the share of the free bytes, between `0.0` and `1.0`, that cannot be used for
+    /// new allocations since they belong to a previous segment of a region that grew.
+    pub fn fragmentation_ratio(&self) -> f64 {
+        self.0.fragmentation_ratio()
+    }
+}
+
+#[pyclass(str = "{0:?}")]
+/// A shared memory region a port of a `Node` allocated to provide its samples, requests or
+/// responses. When the owning port is dropped, the region is released and reports no memory.
+pub struct SharedMemoryRegion(pub(crate) iceoryx2::node::shm_region::SharedMemoryRegion);
+
+#[pymethods]
+impl SharedMemoryRegion {
+    #[getter]
+    /// Returns the name of the `SharedMemoryRegion`.
+    pub fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    #[getter]
+    /// Returns true when the owning port was dropped and the memory was released.
+    pub fn is_released(&self) -> bool {
+        self.0.is_released()
+    }
+
+    /// Returns the current `ShmUsageStats` of the `SharedMemoryRegion`.
+    pub fn usage_stats(&self) -> ShmUsageStats {
+        ShmUsageStats(self.0.usage_stats())
+    }
+}
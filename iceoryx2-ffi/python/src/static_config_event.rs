@@ -54,6 +54,12 @@ impl StaticConfigEvent {
         self.0.event_id_max_value()
     }
 
+    #[getter]
+    /// Same as `StaticConfigEvent.event_id_max_value`.
+    pub fn max_event_id_value(&self) -> usize {
+        self.0.event_id_max_value()
+    }
+
     #[getter]
     /// Returns the emitted `EventId` when a new notifier is created.
     pub fn notifier_created_event(&self) -> Option<EventId> {
@@ -12,7 +12,10 @@
 
 use pyo3::prelude::*;
 
-use crate::message_type_details::MessageTypeDetails;
+use crate::{
+    duration::Duration, message_type_details::MessageTypeDetails,
+    type_compatibility::TypeCompatibility,
+};
 
 #[pyclass]
 /// The static configuration of an `MessagingPattern::PublishSubscribe` based `Service`. Contains
@@ -68,6 +71,28 @@ impl StaticConfigPublishSubscribe {
         self.0.has_safe_overflow()
     }
 
+    #[getter]
+    /// Returns the `TypeCompatibility` that is used to match the payload and user header types
+    /// when the `Service` is opened.
+    pub fn type_compatibility(&self) -> TypeCompatibility {
+        self.0.type_compatibility().into()
+    }
+
+    #[getter]
+    /// Returns true if the `Publisher` stores a send timestamp in the header
+    /// of every `Sample`, otherwise false.
+    pub fn has_send_timestamp(&self) -> bool {
+        self.0.has_send_timestamp()
+    }
+
+    #[getter]
+    /// Returns the lifespan of a `Sample`. A `Sample` that is older than the lifespan is
+    /// discarded by the `Subscriber` instead of being received. If no lifespan is set, it
+    /// returns `None`.
+    pub fn sample_lifespan(&self) -> Option<Duration> {
+        self.0.sample_lifespan().map(Duration)
+    }
+
     #[getter]
     /// Returns the type details of the `Service`.
     pub fn message_type_details(&self) -> MessageTypeDetails {
@@ -12,7 +12,10 @@
 
 use pyo3::prelude::*;
 
-use crate::message_type_details::MessageTypeDetails;
+use crate::{
+    duration::Duration, message_type_details::MessageTypeDetails,
+    type_compatibility::TypeCompatibility,
+};
 
 #[pyclass]
 /// The static configuration of an `MessagingPattern::RequestResponse` based service. Contains all
@@ -51,6 +54,13 @@ impl StaticConfigRequestResponse {
         self.0.has_safe_overflow_for_responses()
     }
 
+    #[getter]
+    /// Returns the `TypeCompatibility` that is used to match the payload and user header types
+    /// when the `Service` is opened.
+    pub fn type_compatibility(&self) -> TypeCompatibility {
+        self.0.type_compatibility().into()
+    }
+
     #[getter]
     /// Returns true if fire and forget `RequestMut`s can be sent from the `Client`, otherwise
     /// false.
@@ -58,6 +68,27 @@ impl StaticConfigRequestResponse {
         self.0.does_support_fire_and_forget_requests()
     }
 
+    #[getter]
+    /// Returns true if a `Server` can send arbitrary many `Response`s per `ActiveRequest`,
+    /// otherwise false.
+    pub fn does_support_multi_response(&self) -> bool {
+        self.0.does_support_multi_response()
+    }
+
+    #[getter]
+    /// Returns the response deadline of the service. If a `Server` does not respond to a
+    /// request within the deadline, the `PendingResponse` has missed its deadline.
+    pub fn response_deadline(&self) -> Option<Duration> {
+        self.0.response_deadline().map(Duration)
+    }
+
+    #[getter]
+    /// Returns the number of requests a `Client` can send before a `Server` acknowledged them
+    /// when credit flow control is enabled, otherwise `None`.
+    pub fn credits_per_client(&self) -> Option<u32> {
+        self.0.credits_per_client()
+    }
+
     #[getter]
     /// Returns the maximum number of borrowed `Response`s a `Client` can hold in parallel per
     /// `PendingResponse`
@@ -71,6 +102,13 @@ impl StaticConfigRequestResponse {
         self.0.max_active_requests_per_client()
     }
 
+    #[getter]
+    /// Returns the depth of the queue in which the requests of a `Client` wait until the
+    /// `Server` receives them.
+    pub fn max_request_queue_depth_per_client(&self) -> usize {
+        self.0.max_request_queue_depth_per_client()
+    }
+
     #[getter]
     /// Returns the maximum buffer size for responses for a `PendingResponse`.
     pub fn max_response_buffer_size(&self) -> usize {
@@ -100,4 +138,18 @@ impl StaticConfigRequestResponse {
     pub fn max_nodes(&self) -> usize {
         self.0.max_nodes()
     }
+
+    #[getter]
+    /// Returns the maximum number of payload elements of a request or `None` when it is
+    /// unlimited.
+    pub fn max_request_payload_size(&self) -> Option<usize> {
+        self.0.max_request_payload_size()
+    }
+
+    #[getter]
+    /// Returns the maximum number of payload elements of a response or `None` when it is
+    /// unlimited.
+    pub fn max_response_payload_size(&self) -> Option<usize> {
+        self.0.max_response_payload_size()
+    }
 }
@@ -12,10 +12,13 @@
 
 use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
 use iceoryx2_bb_log::fatal_panic;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 use crate::{
-    error::{ConnectionFailure, ReceiveError},
+    duration::Duration,
+    error::{ConnectionFailure, ReceiveError, SubscriberMarkReceivedError},
+    header_publish_subscribe::HeaderPublishSubscribe,
+    held_samples::HeldSamples,
     parc::Parc,
     sample::{Sample, SampleType},
     type_storage::TypeStorage,
@@ -49,6 +52,7 @@ pub struct Subscriber {
     pub(crate) value: Parc<SubscriberType>,
     pub(crate) payload_type_details: TypeStorage,
     pub(crate) user_header_type_details: TypeStorage,
+    pub(crate) held_samples: HeldSamples,
 }
 
 #[pymethods]
@@ -64,6 +68,77 @@ impl Subscriber {
         }
     }
 
+    #[getter]
+    /// Returns the number of `Sample`s that were dropped due to a buffer overflow since the
+    /// `Subscriber` was created.
+    pub fn dropped_sample_count(&self) -> u64 {
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.dropped_sample_count(),
+            SubscriberType::Local(Some(v)) => v.dropped_sample_count(),
+            _ => fatal_panic!(from "Subscriber::dropped_sample_count()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
+    #[getter]
+    /// Returns the number of `Sample`s that were discarded since they exceeded the sample
+    /// lifespan of the `Service` or the max sample age of the `Subscriber` before they were
+    /// received.
+    pub fn number_of_expired_samples(&self) -> u64 {
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.number_of_expired_samples(),
+            SubscriberType::Local(Some(v)) => v.number_of_expired_samples(),
+            _ => fatal_panic!(from "Subscriber::number_of_expired_samples()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
+    #[getter]
+    /// Returns the maximum age a `Sample` can have to be received by the `Subscriber` or
+    /// `None` when no max sample age is set.
+    pub fn max_sample_age(&self) -> Option<Duration> {
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.max_sample_age().map(Duration),
+            SubscriberType::Local(Some(v)) => v.max_sample_age().map(Duration),
+            _ => fatal_panic!(from "Subscriber::max_sample_age()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
+    /// Defines the maximum age in seconds a `Sample` can have to be received by the
+    /// `Subscriber`. Older `Sample`s are discarded on receive and counted in
+    /// `number_of_expired_samples`. A max age of zero disables it. It has no effect when
+    /// the publishers of the `Service` do not capture a send timestamp.
+    pub fn set_max_sample_age(&self, max_age_secs: f64) -> PyResult<()> {
+        if !max_age_secs.is_finite() || max_age_secs < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "The max sample age must be a non-negative number of seconds but {max_age_secs} was provided."
+            )));
+        }
+
+        let max_age = core::time::Duration::from_secs_f64(max_age_secs);
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.set_max_sample_age(max_age),
+            SubscriberType::Local(Some(v)) => v.set_max_sample_age(max_age),
+            _ => fatal_panic!(from "Subscriber::set_max_sample_age()",
+                    "Accessing a released Subscriber."),
+        }
+
+        Ok(())
+    }
+
+    #[getter]
+    /// Returns the maximum number of elements a received slice may contain or `None` when
+    /// slices of any length are accepted.
+    pub fn max_slice_len(&self) -> Option<usize> {
+        match &*self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => v.max_slice_len(),
+            SubscriberType::Local(Some(v)) => v.max_slice_len(),
+            _ => fatal_panic!(from "Subscriber::max_slice_len()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
     #[getter]
     /// Returns the internal buffer size of the `Subscriber`.
     pub fn buffer_size(&self) -> usize {
@@ -97,19 +172,27 @@ impl Subscriber {
             SubscriberType::Ipc(Some(v)) => Ok(unsafe {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
-                    .map(|s| Sample {
-                        value: Parc::new(SampleType::Ipc(Some(s))),
-                        payload_type_details: self.payload_type_details.clone(),
-                        user_header_type_details: self.user_header_type_details.clone(),
+                    .map(|s| {
+                        let value = Parc::new(SampleType::Ipc(Some(s)));
+                        self.held_samples.add(&value);
+                        Sample {
+                            value,
+                            payload_type_details: self.payload_type_details.clone(),
+                            user_header_type_details: self.user_header_type_details.clone(),
+                        }
                     })
             }),
             SubscriberType::Local(Some(v)) => Ok(unsafe {
                 v.receive_custom_payload()
                     .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
-                    .map(|s| Sample {
-                        value: Parc::new(SampleType::Local(Some(s))),
-                        payload_type_details: self.payload_type_details.clone(),
-                        user_header_type_details: self.user_header_type_details.clone(),
+                    .map(|s| {
+                        let value = Parc::new(SampleType::Local(Some(s)));
+                        self.held_samples.add(&value);
+                        Sample {
+                            value,
+                            payload_type_details: self.payload_type_details.clone(),
+                            user_header_type_details: self.user_header_type_details.clone(),
+                        }
                     })
             }),
             _ => fatal_panic!(from "Subscriber::receive()",
@@ -117,6 +200,55 @@ impl Subscriber {
         }
     }
 
+    /// Returns the `HeaderPublishSubscribe` of the oldest `Sample` without consuming it, so
+    /// that its origin and size can be inspected before deciding to receive it. The next call
+    /// to `Subscriber::receive` returns the same `Sample`. If no sample could be received
+    /// `None` is returned. If a failure occurs `ReceiveError` is returned.
+    ///
+    /// The peeked `Sample` is held by the `Subscriber` until it is received and counts towards
+    /// the max borrowed samples.
+    pub fn peek(&self) -> PyResult<Option<HeaderPublishSubscribe>> {
+        match &mut *self.value.lock() {
+            SubscriberType::Ipc(Some(v)) => Ok(unsafe { v.peek_custom_payload() }
+                .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                .map(|s| HeaderPublishSubscribe(*s.header()))),
+            SubscriberType::Local(Some(v)) => Ok(unsafe { v.peek_custom_payload() }
+                .map_err(|e| ReceiveError::new_err(format!("{e:?}")))?
+                .map(|s| HeaderPublishSubscribe(*s.header()))),
+            _ => fatal_panic!(from "Subscriber::peek()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
+    /// Acknowledges that the `Sample` was processed. Its sequence number is recorded in an
+    /// acknowledgement log in the shared memory of the `Service` so that the `Publisher` can
+    /// determine with `Publisher::acknowledged_sequence_number()` which samples were not
+    /// processed when the `Subscriber` crashes. Acknowledging a `Sample` implicitly
+    /// acknowledges all of its predecessors from the same `Publisher`.
+    ///
+    /// Emits `SubscriberMarkReceivedError` when the `Sample` belongs to another `Subscriber`
+    /// or when its `Publisher` is no longer connected.
+    pub fn mark_received(&self, sample: &Sample) -> PyResult<()> {
+        match (&*self.value.lock(), &*sample.value.lock()) {
+            (SubscriberType::Ipc(Some(v)), SampleType::Ipc(Some(s))) => v
+                .mark_received(s)
+                .map_err(|e| SubscriberMarkReceivedError::new_err(format!("{e:?}"))),
+            (SubscriberType::Local(Some(v)), SampleType::Local(Some(s))) => v
+                .mark_received(s)
+                .map_err(|e| SubscriberMarkReceivedError::new_err(format!("{e:?}"))),
+            (SubscriberType::Ipc(Some(_)), SampleType::Ipc(None))
+            | (SubscriberType::Local(Some(_)), SampleType::Local(None)) => {
+                fatal_panic!(from "Subscriber::mark_received()",
+                    "Accessing a released sample.")
+            }
+            (SubscriberType::Ipc(Some(_)), _) | (SubscriberType::Local(Some(_)), _) => Err(
+                SubscriberMarkReceivedError::new_err("SampleBelongsToDifferentSubscriber"),
+            ),
+            _ => fatal_panic!(from "Subscriber::mark_received()",
+                    "Accessing a released Subscriber."),
+        }
+    }
+
     /// Releases the `Subscriber`.
     ///
     /// After this call the `Subscriber` is no longer usable!
@@ -130,4 +262,21 @@ impl Subscriber {
             }
         }
     }
+
+    /// Returns the `Subscriber` itself so that it can be used as context manager.
+    pub fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Releases the `Subscriber` and all `Sample`s it has received that are still held when leaving the `with` block. Calling it
+    /// multiple times has no further effect.
+    pub fn __exit__(
+        &mut self,
+        _exc_type: Option<PyObject>,
+        _exc_value: Option<PyObject>,
+        _traceback: Option<PyObject>,
+    ) {
+        self.held_samples.release_all();
+        self.delete();
+    }
 }
@@ -0,0 +1,107 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWaitBuilder, AdaptiveWaitError};
+use iceoryx2_bb_posix::clock::{ClockType, NanosleepError};
+use iceoryx2_bb_posix::signal::{FetchableSignal, SignalGuard, SignalHandler};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU8;
+use pyo3::prelude::*;
+
+const NO_SIGNAL: u8 = 0;
+const SIGTERM: u8 = 1;
+const SIGINT: u8 = 2;
+const SIGHUP: u8 = 3;
+
+static RECEIVED_SIGNAL: IoxAtomicU8 = IoxAtomicU8::new(NO_SIGNAL);
+static SIGNAL_GUARD: Mutex<Option<SignalGuard>> = Mutex::new(None);
+
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Clone, Debug)]
+/// The termination signal that was received by `Node::wait_for_termination_signal()`.
+pub enum TerminationSignal {
+    /// A termination signal `SIGTERM` was received.
+    Sigterm,
+    /// An interrupt signal `SIGINT` was received.
+    Sigint,
+    /// A hangup signal `SIGHUP` was received.
+    Sighup,
+}
+
+#[pymethods]
+impl TerminationSignal {
+    pub fn __str__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+fn capture_termination_signal(signal: FetchableSignal) {
+    let value = match signal {
+        FetchableSignal::Terminate => SIGTERM,
+        FetchableSignal::Interrupt => SIGINT,
+        FetchableSignal::Hangup => SIGHUP,
+        _ => return,
+    };
+
+    RECEIVED_SIGNAL.store(value, Ordering::Relaxed);
+}
+
+// The callback is registered on top of the iceoryx2 signal handler which still captures the
+// signal, therefore constructs like the `WaitSet` or `Node::wait()` observe it as well. It
+// stays registered for the lifetime of the process so that no signal is lost between two
+// calls.
+fn register_termination_signals() -> Result<(), String> {
+    let mut guard = SIGNAL_GUARD.lock().map_err(|e| format!("{e:?}"))?;
+    if guard.is_none() {
+        *guard = Some(
+            SignalHandler::register_multiple_signals(
+                &vec![
+                    FetchableSignal::Terminate,
+                    FetchableSignal::Interrupt,
+                    FetchableSignal::Hangup,
+                ],
+                &capture_termination_signal,
+            )
+            .map_err(|e| format!("{e:?}"))?,
+        );
+    }
+
+    Ok(())
+}
+
+/// Blocks until `SIGTERM`, `SIGINT` or `SIGHUP` was received. A signal that was received after
+/// the first call but before the current call returns immediately.
+pub(crate) fn wait_for_termination_signal() -> Result<TerminationSignal, String> {
+    register_termination_signals()?;
+
+    let mut wait = AdaptiveWaitBuilder::new()
+        .clock_type(ClockType::Monotonic)
+        .create()
+        .map_err(|e| format!("{e:?}"))?;
+
+    loop {
+        match RECEIVED_SIGNAL.swap(NO_SIGNAL, Ordering::Relaxed) {
+            SIGTERM => return Ok(TerminationSignal::Sigterm),
+            SIGINT => return Ok(TerminationSignal::Sigint),
+            SIGHUP => return Ok(TerminationSignal::Sighup),
+            _ => match wait.wait() {
+                // the signal itself interrupts the wait, it is fetched in the next iteration
+                Ok(_)
+                | Err(AdaptiveWaitError::NanosleepError(NanosleepError::InterruptedBySignal(_))) => {
+                }
+                Err(e) => return Err(format!("{e:?}")),
+            },
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use pyo3::prelude::*;
+
+#[pyclass(eq, eq_int)]
+#[derive(PartialEq, Clone, Debug)]
+/// Defines how the payload and user header types of a `Service` are matched
+/// when it is opened.
+pub enum TypeCompatibility {
+    /// The type name, size and alignment must be identical.
+    Strict,
+    /// Only size and alignment must be identical, the type name is ignored.
+    /// Useful when the same memory layout is defined in different languages.
+    LayoutOnly,
+}
+
+impl From<iceoryx2::service::static_config::message_type_details::TypeCompatibility>
+    for TypeCompatibility
+{
+    fn from(
+        value: iceoryx2::service::static_config::message_type_details::TypeCompatibility,
+    ) -> TypeCompatibility {
+        match value {
+            iceoryx2::service::static_config::message_type_details::TypeCompatibility::Strict => {
+                TypeCompatibility::Strict
+            }
+            iceoryx2::service::static_config::message_type_details::TypeCompatibility::LayoutOnly => {
+                TypeCompatibility::LayoutOnly
+            }
+        }
+    }
+}
+
+impl From<TypeCompatibility>
+    for iceoryx2::service::static_config::message_type_details::TypeCompatibility
+{
+    fn from(
+        value: TypeCompatibility,
+    ) -> iceoryx2::service::static_config::message_type_details::TypeCompatibility {
+        match value {
+            TypeCompatibility::Strict => {
+                iceoryx2::service::static_config::message_type_details::TypeCompatibility::Strict
+            }
+            TypeCompatibility::LayoutOnly => {
+                iceoryx2::service::static_config::message_type_details::TypeCompatibility::LayoutOnly
+            }
+        }
+    }
+}
@@ -69,3 +69,23 @@ impl TypeDetail {
         Self(this)
     }
 }
+
+/// Returns the number of payload elements of the given layout that are stored in
+/// `number_of_bytes`. Emits `ValueError` when the bytes do not fit the layout.
+pub(crate) fn number_of_payload_elements(
+    details: &iceoryx2::service::static_config::message_type_details::TypeDetail,
+    number_of_bytes: usize,
+) -> PyResult<usize> {
+    use iceoryx2::service::static_config::message_type_details::TypeVariant;
+
+    match details.variant {
+        TypeVariant::FixedSize if number_of_bytes == details.size => Ok(1),
+        TypeVariant::Dynamic if details.size != 0 && number_of_bytes % details.size == 0 => {
+            Ok(number_of_bytes / details.size)
+        }
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "The payload with {} bytes does not fit the payload type {:?}.",
+            number_of_bytes, details
+        ))),
+    }
+}
@@ -47,3 +47,15 @@ impl TypeName {
         self.0.to_string()
     }
 }
+
+/// Converts a user provided type name override into a validated
+/// `iceoryx2::service::static_config::message_type_details::TypeName`. Emits a
+/// `SemanticStringError` when the name is empty, too long or contains characters other than
+/// printable ASCII characters.
+pub(crate) fn type_name_override(
+    name: &str,
+) -> PyResult<iceoryx2::service::static_config::message_type_details::TypeName> {
+    use iceoryx2::prelude::SemanticString;
+    iceoryx2::service::static_config::message_type_details::TypeName::new(name.as_bytes())
+        .map_err(|e| SemanticStringError::new_err(format!("{e:?}")))
+}
@@ -11,15 +11,21 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use core::ops::Deref;
+use std::sync::{Arc, Weak};
 
-use pyo3::prelude::*;
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::{
+    callback_progression::CallbackProgression,
     duration::Duration,
-    error::{WaitSetAttachmentError, WaitSetRunError},
+    error::{ListenerCreateError, WaitSetAttachmentError, WaitSetRunError},
     file_descriptor::FileDescriptor,
     listener::{Listener, ListenerType},
+    notifier::{Notifier, NotifierType},
     parc::Parc,
+    pending_response::{PendingResponse, PendingResponseType},
+    port_factory_event::PortFactoryEventType,
     signal_handling_mode::SignalHandlingMode,
     waitset_attachment_id::{WaitSetAttachmentId, WaitSetAttachmentIdType},
     waitset_guard::{StorageType, WaitSetGuard, WaitSetGuardType},
@@ -31,6 +37,40 @@ pub(crate) enum WaitSetType {
     Local(iceoryx2::waitset::WaitSet<crate::LocalService>),
 }
 
+enum GuardListener {
+    Ipc(Weak<iceoryx2::port::listener::Listener<crate::IpcService>>),
+    Local(Weak<iceoryx2::port::listener::Listener<crate::LocalService>>),
+}
+
+impl GuardListener {
+    /// Consumes all pending events of the guard `Listener`. Returns `None` when the guard was
+    /// already detached, otherwise if the guard was notified.
+    fn drain(&self) -> Option<bool> {
+        match self {
+            GuardListener::Ipc(v) => v.upgrade().map(|l| drain_listener(&l)),
+            GuardListener::Local(v) => v.upgrade().map(|l| drain_listener(&l)),
+        }
+    }
+}
+
+fn drain_listener<S: iceoryx2::service::Service>(
+    listener: &iceoryx2::port::listener::Listener<S>,
+) -> bool {
+    let mut has_events = false;
+    // a failure is handled like a guard that was not notified, the attachment id is still
+    // reported to the user
+    let _ = listener.try_wait_all(|_| has_events = true);
+    has_events
+}
+
+// a response deadline that was attached with WaitSet::attach_response_deadline(), the token
+// expires as soon as the corresponding guard is deleted
+struct ResponseDeadline {
+    attachment_id: WaitSetAttachmentIdType,
+    token: Weak<()>,
+    pending_response: Py<PendingResponse>,
+}
+
 #[pyclass]
 /// The `WaitSet` implements a reactor pattern and allows to wait on multiple events in one
 /// single call `WaitSet::wait_and_process()` until a interrupt or termination signal was received.
@@ -38,7 +78,178 @@ pub(crate) enum WaitSetType {
 /// The `Listener` can be attached as well as sockets or anything else that is a `FileDescriptor`.
 ///
 /// Can be created via the `WaitSetBuilder`.
-pub struct WaitSet(pub(crate) Parc<WaitSetType>);
+pub struct WaitSet {
+    pub(crate) waitset: Parc<WaitSetType>,
+    guard_listeners: Parc<Vec<GuardListener>>,
+    response_deadlines: Parc<Vec<ResponseDeadline>>,
+}
+
+impl WaitSet {
+    pub(crate) fn new(waitset: WaitSetType) -> Self {
+        Self {
+            waitset: Parc::new(waitset),
+            guard_listeners: Parc::new(vec![]),
+            response_deadlines: Parc::new(vec![]),
+        }
+    }
+
+    /// Calls the deadline miss handler of every `PendingResponse` whose response deadline
+    /// fired and removes the response deadlines that were detached in the meantime.
+    fn handle_response_deadlines(
+        &self,
+        py: Python<'_>,
+        attachment_ids: &[WaitSetAttachmentId],
+    ) -> PyResult<()> {
+        let mut fired_deadlines = vec![];
+        self.response_deadlines.lock().retain(|deadline| {
+            if deadline.token.strong_count() == 0 {
+                return false;
+            }
+
+            if attachment_ids
+                .iter()
+                .any(|id| id.0 == deadline.attachment_id)
+            {
+                fired_deadlines.push(deadline.pending_response.clone_ref(py));
+            }
+            true
+        });
+
+        // the handlers are called without holding the lock so that they can use the WaitSet
+        for pending_response in fired_deadlines {
+            pending_response.borrow(py).handle_deadline_miss(py)?;
+        }
+
+        Ok(())
+    }
+
+    /// Drains all guard `Listener`s and removes the ones that were detached in the meantime.
+    /// Returns true if at least one guard was notified.
+    fn drain_guard_listeners(&self) -> bool {
+        let mut guard_woken = false;
+        self.guard_listeners
+            .lock()
+            .retain(|guard| match guard.drain() {
+                Some(has_events) => {
+                    guard_woken |= has_events;
+                    true
+                }
+                None => false,
+            });
+        guard_woken
+    }
+
+    fn to_run_result(&self, result: iceoryx2::waitset::WaitSetRunResult) -> WaitSetRunResult {
+        // signals take precedence, the events of the guards are consumed in the next call
+        if result == iceoryx2::waitset::WaitSetRunResult::AllEventsHandled
+            && self.drain_guard_listeners()
+        {
+            WaitSetRunResult::GuardWoken
+        } else {
+            result.into()
+        }
+    }
+
+    fn wait_and_process_impl(
+        &self,
+    ) -> PyResult<(
+        Vec<WaitSetAttachmentId>,
+        iceoryx2::waitset::WaitSetRunResult,
+    )> {
+        let mut ret_val = vec![];
+        let result = match &*self.waitset.lock() {
+            WaitSetType::Ipc(v) => v
+                .wait_and_process_once(|v| {
+                    ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Ipc(v)));
+                    iceoryx2::prelude::CallbackProgression::Continue
+                })
+                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
+            WaitSetType::Local(v) => v
+                .wait_and_process_once(|v| {
+                    ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Local(v)));
+                    iceoryx2::prelude::CallbackProgression::Continue
+                })
+                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
+        };
+
+        Ok((ret_val, result))
+    }
+
+    fn wait_and_process_with_timeout_impl(
+        &self,
+        timeout: &Duration,
+    ) -> PyResult<(
+        Vec<WaitSetAttachmentId>,
+        iceoryx2::waitset::WaitSetRunResult,
+    )> {
+        let mut ret_val = vec![];
+        let result = match &*self.waitset.lock() {
+            WaitSetType::Ipc(v) => v
+                .wait_and_process_once_with_timeout(
+                    |v| {
+                        ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Ipc(v)));
+                        iceoryx2::prelude::CallbackProgression::Continue
+                    },
+                    timeout.0,
+                )
+                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
+            WaitSetType::Local(v) => v
+                .wait_and_process_once_with_timeout(
+                    |v| {
+                        ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Local(v)));
+                        iceoryx2::prelude::CallbackProgression::Continue
+                    },
+                    timeout.0,
+                )
+                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
+        };
+
+        Ok((ret_val, result))
+    }
+
+    /// Calls the `callback` for every attachment id until it returns
+    /// `CallbackProgression.Stop`. Any other return value continues the processing. Returns
+    /// true if the processing was stopped.
+    fn call_for_each(
+        py: Python<'_>,
+        callback: &Py<PyAny>,
+        attachment_ids: Vec<WaitSetAttachmentId>,
+    ) -> PyResult<bool> {
+        for attachment_id in attachment_ids {
+            let progression = callback.call1(py, (attachment_id,))?;
+            if let Ok(CallbackProgression::Stop) = progression.extract::<CallbackProgression>(py) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn wait_and_process_once_with_callback(
+        &self,
+        py: Python<'_>,
+        callback: &Py<PyAny>,
+        timeout: Option<&Duration>,
+    ) -> PyResult<WaitSetRunResult> {
+        // the GIL is released so that other python threads can wake up the WaitSet via a guard
+        let (attachment_ids, result) = match timeout {
+            Some(timeout) => {
+                py.allow_threads(|| self.wait_and_process_with_timeout_impl(timeout))?
+            }
+            None => py.allow_threads(|| self.wait_and_process_impl())?,
+        };
+        let result = self.to_run_result(result);
+        self.handle_response_deadlines(py, &attachment_ids)?;
+
+        // the callback is called after the WaitSet was released so that it can use the
+        // WaitSet, for instance to attach or detach something
+        if Self::call_for_each(py, callback, attachment_ids)? {
+            return Ok(WaitSetRunResult::StopRequest);
+        }
+
+        Ok(result)
+    }
+}
 
 #[pymethods]
 impl WaitSet {
@@ -46,12 +257,24 @@ impl WaitSet {
     /// object the `WaitSet` informs the user in `WaitSet::wait_and_process()` to handle the event.
     /// The object cannot be attached twice and the
     /// `WaitSet::capacity()` is limited by the underlying implementation.
-    pub fn attach_notification(&self, attachment: &Listener) -> PyResult<WaitSetGuard> {
-        match &*self.0.lock() {
+    /// The optional `priority` defines the order in which ready attachments are returned
+    /// within one wakeup, higher priorities first.
+    #[pyo3(signature = (attachment, priority = 0))]
+    pub fn attach_notification(
+        &self,
+        attachment: &Listener,
+        priority: u8,
+    ) -> PyResult<WaitSetGuard> {
+        if let ListenerType::Ipc(None) | ListenerType::Local(None) = &attachment.0 {
+            fatal_panic!(from "WaitSet::attach_notification()",
+                "Accessing a released Listener.");
+        }
+
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => {
-                if let ListenerType::Ipc(attachment) = &attachment.0 {
+                if let ListenerType::Ipc(Some(attachment)) = &attachment.0 {
                     let guard = v
-                        .attach_notification(attachment.deref())
+                        .attach_notification_with_priority(attachment.deref(), priority)
                         .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                     Ok(WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
                         // safe since the waitset arc and the attachment arc become a member of the
@@ -67,7 +290,7 @@ impl WaitSet {
                                 >,
                             >(guard)
                         }),
-                        waitset: self.0.clone(),
+                        waitset: self.waitset.clone(),
                         _attachment: Some(attachment.clone()),
                     })))
                 } else {
@@ -77,9 +300,9 @@ impl WaitSet {
                 }
             }
             WaitSetType::Local(v) => {
-                if let ListenerType::Local(attachment) = &attachment.0 {
+                if let ListenerType::Local(Some(attachment)) = &attachment.0 {
                     let guard = v
-                        .attach_notification(attachment.deref())
+                        .attach_notification_with_priority(attachment.deref(), priority)
                         .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                     Ok(WaitSetGuard(WaitSetGuardType::Local(StorageType {
                         // safe since the waitset arc and the attachment arc become a member of the
@@ -95,7 +318,7 @@ impl WaitSet {
                                 >,
                             >(guard)
                         }),
-                        waitset: self.0.clone(),
+                        waitset: self.waitset.clone(),
                         _attachment: Some(attachment.clone()),
                     })))
                 } else {
@@ -111,11 +334,18 @@ impl WaitSet {
     /// object the `WaitSet` informs the user in `WaitSet::wait_and_process()` to handle the event.
     /// The object cannot be attached twice and the
     /// `WaitSet::capacity()` is limited by the underlying implementation.
-    pub fn attach_notification_fd(&self, attachment: &FileDescriptor) -> PyResult<WaitSetGuard> {
-        match &*self.0.lock() {
+    /// The optional `priority` defines the order in which ready attachments are returned
+    /// within one wakeup, higher priorities first.
+    #[pyo3(signature = (attachment, priority = 0))]
+    pub fn attach_notification_fd(
+        &self,
+        attachment: &FileDescriptor,
+        priority: u8,
+    ) -> PyResult<WaitSetGuard> {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => {
                 let guard = v
-                    .attach_notification(attachment)
+                    .attach_notification_with_priority(attachment, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
                     // safe since the waitset arc and the attachment arc become a member of the
@@ -127,13 +357,13 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::IpcService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: Some(attachment.0.clone()),
                 })))
             }
             WaitSetType::Local(v) => {
                 let guard = v
-                    .attach_notification(attachment)
+                    .attach_notification_with_priority(attachment, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Local(StorageType {
                     // safe since the waitset arc and the attachment arc become a member of the
@@ -145,7 +375,7 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::LocalService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: Some(attachment.0.clone()),
                 })))
             }
@@ -157,16 +387,25 @@ impl WaitSet {
     /// The object cannot be attached twice and the
     /// `WaitSet::capacity()` is limited by the underlying implementation.
     /// Whenever the object emits an event the deadline is reset by the `WaitSet`.
+    /// The optional `priority` defines the order in which ready attachments are returned
+    /// within one wakeup, higher priorities first.
+    #[pyo3(signature = (attachment, deadline, priority = 0))]
     pub fn attach_deadline(
         &self,
         attachment: &Listener,
         deadline: &Duration,
+        priority: u8,
     ) -> PyResult<WaitSetGuard> {
-        match &*self.0.lock() {
+        if let ListenerType::Ipc(None) | ListenerType::Local(None) = &attachment.0 {
+            fatal_panic!(from "WaitSet::attach_deadline()",
+                "Accessing a released Listener.");
+        }
+
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => {
-                if let ListenerType::Ipc(attachment) = &attachment.0 {
+                if let ListenerType::Ipc(Some(attachment)) = &attachment.0 {
                     let guard = v
-                        .attach_deadline(attachment.deref(), deadline.0)
+                        .attach_deadline_with_priority(attachment.deref(), deadline.0, priority)
                         .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                     Ok(WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
                         // safe since the waitset arc and the attachment arc become a member of the
@@ -182,7 +421,7 @@ impl WaitSet {
                                 >,
                             >(guard)
                         }),
-                        waitset: self.0.clone(),
+                        waitset: self.waitset.clone(),
                         _attachment: Some(attachment.clone()),
                     })))
                 } else {
@@ -192,9 +431,9 @@ impl WaitSet {
                 }
             }
             WaitSetType::Local(v) => {
-                if let ListenerType::Local(attachment) = &attachment.0 {
+                if let ListenerType::Local(Some(attachment)) = &attachment.0 {
                     let guard = v
-                        .attach_deadline(attachment.deref(), deadline.0)
+                        .attach_deadline_with_priority(attachment.deref(), deadline.0, priority)
                         .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                     Ok(WaitSetGuard(WaitSetGuardType::Local(StorageType {
                         // safe since the waitset arc and the attachment arc become a member of the
@@ -210,7 +449,7 @@ impl WaitSet {
                                 >,
                             >(guard)
                         }),
-                        waitset: self.0.clone(),
+                        waitset: self.waitset.clone(),
                         _attachment: Some(attachment.clone()),
                     })))
                 } else {
@@ -227,15 +466,19 @@ impl WaitSet {
     /// The object cannot be attached twice and the
     /// `WaitSet::capacity()` is limited by the underlying implementation.
     /// Whenever the object emits an event the deadline is reset by the `WaitSet`.
+    /// The optional `priority` defines the order in which ready attachments are returned
+    /// within one wakeup, higher priorities first.
+    #[pyo3(signature = (attachment, deadline, priority = 0))]
     pub fn attach_deadline_fd(
         &self,
         attachment: &FileDescriptor,
         deadline: &Duration,
+        priority: u8,
     ) -> PyResult<WaitSetGuard> {
-        match &*self.0.lock() {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => {
                 let guard = v
-                    .attach_deadline(attachment, deadline.0)
+                    .attach_deadline_with_priority(attachment, deadline.0, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
                     // safe since the waitset arc and the attachment arc become a member of the
@@ -247,13 +490,13 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::IpcService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: Some(attachment.0.clone()),
                 })))
             }
             WaitSetType::Local(v) => {
                 let guard = v
-                    .attach_deadline(attachment, deadline.0)
+                    .attach_deadline_with_priority(attachment, deadline.0, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Local(StorageType {
                     // safe since the waitset arc and the attachment arc become a member of the
@@ -265,20 +508,120 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::LocalService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: Some(attachment.0.clone()),
                 })))
             }
         }
     }
 
+    /// Attaches the response deadline of a `PendingResponse` to the `WaitSet`. It is armed with
+    /// `PendingResponse::time_until_deadline()` and fires exactly once when the deadline is
+    /// reached. If the `Response` was not received in time, the `WaitSet` calls the callback
+    /// registered with `PendingResponse::on_deadline_miss()` before it informs the user in
+    /// `WaitSet::wait_and_process()`.
+    ///
+    /// Emits `WaitSetAttachmentError` when the `Service` has no response deadline or when the
+    /// `PendingResponse` has already received a `Response`.
+    pub fn attach_response_deadline(
+        &self,
+        py: Python<'_>,
+        pending_response: Py<PendingResponse>,
+    ) -> PyResult<WaitSetGuard> {
+        let token = Arc::new(());
+        let (guard, attachment_id) = match &*self.waitset.lock() {
+            WaitSetType::Ipc(v) => match &*pending_response.borrow(py).value.lock() {
+                PendingResponseType::Ipc(Some(attachment)) => {
+                    let guard = v
+                        .attach_response_deadline(attachment)
+                        .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
+                    let attachment_id = WaitSetAttachmentIdType::Ipc(
+                        iceoryx2::waitset::WaitSetAttachmentId::from_guard(&guard),
+                    );
+                    let guard = WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
+                        // safe since the waitset arc becomes a member of the guard and therefore
+                        // the waitset lives at least as long as the guard
+                        guard: Some(unsafe {
+                            core::mem::transmute::<
+                                iceoryx2::waitset::WaitSetGuard<'_, '_, crate::IpcService>,
+                                iceoryx2::waitset::WaitSetGuard<
+                                    'static,
+                                    'static,
+                                    crate::IpcService,
+                                >,
+                            >(guard)
+                        }),
+                        waitset: self.waitset.clone(),
+                        _attachment: Some(token.clone()),
+                    }));
+                    (guard, attachment_id)
+                }
+                PendingResponseType::Ipc(None) | PendingResponseType::Local(None) => {
+                    fatal_panic!(from "WaitSet::attach_response_deadline()",
+                        "Accessing a released pending response.")
+                }
+                PendingResponseType::Local(Some(_)) => {
+                    return Err(WaitSetAttachmentError::new_err(
+                        "The attachment has the wrong service type.",
+                    ))
+                }
+            },
+            WaitSetType::Local(v) => match &*pending_response.borrow(py).value.lock() {
+                PendingResponseType::Local(Some(attachment)) => {
+                    let guard = v
+                        .attach_response_deadline(attachment)
+                        .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
+                    let attachment_id = WaitSetAttachmentIdType::Local(
+                        iceoryx2::waitset::WaitSetAttachmentId::from_guard(&guard),
+                    );
+                    let guard = WaitSetGuard(WaitSetGuardType::Local(StorageType {
+                        // safe since the waitset arc becomes a member of the guard and therefore
+                        // the waitset lives at least as long as the guard
+                        guard: Some(unsafe {
+                            core::mem::transmute::<
+                                iceoryx2::waitset::WaitSetGuard<'_, '_, crate::LocalService>,
+                                iceoryx2::waitset::WaitSetGuard<
+                                    'static,
+                                    'static,
+                                    crate::LocalService,
+                                >,
+                            >(guard)
+                        }),
+                        waitset: self.waitset.clone(),
+                        _attachment: Some(token.clone()),
+                    }));
+                    (guard, attachment_id)
+                }
+                PendingResponseType::Ipc(None) | PendingResponseType::Local(None) => {
+                    fatal_panic!(from "WaitSet::attach_response_deadline()",
+                        "Accessing a released pending response.")
+                }
+                PendingResponseType::Ipc(Some(_)) => {
+                    return Err(WaitSetAttachmentError::new_err(
+                        "The attachment has the wrong service type.",
+                    ))
+                }
+            },
+        };
+
+        self.response_deadlines.lock().push(ResponseDeadline {
+            attachment_id,
+            token: Arc::downgrade(&token),
+            pending_response,
+        });
+        Ok(guard)
+    }
+
     /// Attaches a tick event to the `WaitSet`. Whenever the timeout is reached the `WaitSet`
     /// informs the user in `WaitSet::wait_and_process()`.
-    pub fn attach_interval(&self, interval: &Duration) -> PyResult<WaitSetGuard> {
-        match &*self.0.lock() {
+    /// The optional `priority` defines the order in which ready attachments are returned
+    /// within one wakeup, higher priorities first.
+    #[pyo3(signature = (interval, priority = 0))]
+    pub fn attach_interval(&self, interval: &Duration, priority: u8) -> PyResult<WaitSetGuard> {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => {
                 let guard = v
-                    .attach_interval(interval.0)
+                    .attach_interval_with_priority(interval.0, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Ipc(StorageType {
                     // safe since the waitset arc becomes a member of the guard and therefore the
@@ -289,13 +632,13 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::IpcService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: None,
                 })))
             }
             WaitSetType::Local(v) => {
                 let guard = v
-                    .attach_interval(interval.0)
+                    .attach_interval_with_priority(interval.0, priority)
                     .map_err(|e| WaitSetAttachmentError::new_err(format!("{e:?}")))?;
                 Ok(WaitSetGuard(WaitSetGuardType::Local(StorageType {
                     // safe since the waitset arc becomes a member of the guard and therefore the
@@ -306,37 +649,123 @@ impl WaitSet {
                             iceoryx2::waitset::WaitSetGuard<'static, 'static, crate::LocalService>,
                         >(guard)
                     }),
-                    waitset: self.0.clone(),
+                    waitset: self.waitset.clone(),
                     _attachment: None,
                 })))
             }
         }
     }
 
-    /// Waits until an event arrives on the `WaitSet`, then collects the events corresponding
-    /// `WaitSetAttachmentId` in a vector and returns it.
+    /// Adds the `Notifier` as guard to the `WaitSet`. A `Listener` of the `Notifier`s service is
+    /// attached as notification and whenever the `Notifier` is used, for instance from another
+    /// thread, `WaitSet::wait_and_process()` wakes up and returns
+    /// [`WaitSetRunResult::GuardWoken`]. The events of the guard are consumed by the `WaitSet`
+    /// and `WaitSetAttachmentId::has_event_from()` identifies the guard in the returned
+    /// attachments.
+    ///
+    /// The `Listener` counts towards the maximum number of `Listener`s of the service. The guard
+    /// is removed as soon as the returned `WaitSetGuard` is deleted.
+    pub fn add_notifier_as_guard(&self, notifier: &Notifier) -> PyResult<WaitSetGuard> {
+        if let NotifierType::Ipc(None) | NotifierType::Local(None) = &notifier.value {
+            fatal_panic!(from "WaitSet::add_notifier_as_guard()",
+                "Accessing a released Notifier.");
+        }
+
+        let (listener, guard_listener) = match &*notifier.factory.lock() {
+            PortFactoryEventType::Ipc(v) => {
+                let listener = Arc::new(
+                    v.listener_builder()
+                        .create()
+                        .map_err(|e| ListenerCreateError::new_err(format!("{e:?}")))?,
+                );
+                let guard_listener = GuardListener::Ipc(Arc::downgrade(&listener));
+                (Listener(ListenerType::Ipc(Some(listener))), guard_listener)
+            }
+            PortFactoryEventType::Local(v) => {
+                let listener = Arc::new(
+                    v.listener_builder()
+                        .create()
+                        .map_err(|e| ListenerCreateError::new_err(format!("{e:?}")))?,
+                );
+                let guard_listener = GuardListener::Local(Arc::downgrade(&listener));
+                (
+                    Listener(ListenerType::Local(Some(listener))),
+                    guard_listener,
+                )
+            }
+        };
+
+        // the guard owns the only strong reference to the listener, when it is deleted the
+        // guard listener expires
+        let guard = self.attach_notification(&listener, 0)?;
+        self.guard_listeners.lock().push(guard_listener);
+        Ok(guard)
+    }
+
+    /// Without a `callback` it waits until an event arrives on the `WaitSet`, then collects
+    /// the events corresponding `WaitSetAttachmentId` in a vector and returns it together
+    /// with the `WaitSetRunResult`.
+    ///
+    /// With a `callback` it runs in a loop and calls the `callback` with the
+    /// `WaitSetAttachmentId` of every event until the `callback` returns
+    /// `CallbackProgression.Stop`, then it returns [`WaitSetRunResult::StopRequest`]. Any
+    /// other return value of the `callback` continues the loop.
     ///
     /// If an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received, it will exit
     /// the loop and inform the user with [`WaitSetRunResult::Interrupt`] or
-    /// [`WaitSetRunResult::TerminationRequest`].
-    pub fn wait_and_process(&self) -> PyResult<(Vec<WaitSetAttachmentId>, WaitSetRunResult)> {
-        let mut ret_val = vec![];
-        let result = match &*self.0.lock() {
-            WaitSetType::Ipc(v) => v
-                .wait_and_process_once(|v| {
-                    ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Ipc(v)));
-                    iceoryx2::prelude::CallbackProgression::Continue
-                })
-                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
-            WaitSetType::Local(v) => v
-                .wait_and_process_once(|v| {
-                    ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Local(v)));
-                    iceoryx2::prelude::CallbackProgression::Continue
-                })
-                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
-        };
+    /// [`WaitSetRunResult::TerminationRequest`]. If a guard `Notifier` was used, it returns
+    /// [`WaitSetRunResult::GuardWoken`], which makes the guard usable as stop token for the
+    /// loop.
+    #[pyo3(signature = (callback = None))]
+    pub fn wait_and_process(
+        &self,
+        py: Python<'_>,
+        callback: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        match callback {
+            Some(callback) => loop {
+                match self.wait_and_process_once_with_callback(py, &callback, None)? {
+                    WaitSetRunResult::AllEventsHandled => (),
+                    result => return result.into_py_any(py),
+                }
+            },
+            None => {
+                // the GIL is released so that other python threads can wake up the WaitSet via
+                // a guard
+                let (ret_val, result) = py.allow_threads(|| self.wait_and_process_impl())?;
+                self.handle_response_deadlines(py, &ret_val)?;
+                (ret_val, self.to_run_result(result)).into_py_any(py)
+            }
+        }
+    }
+
+    /// Waits until an event arrives on the `WaitSet`, then calls the `callback` with the
+    /// `WaitSetAttachmentId` of every event and returns. If the `callback` returns
+    /// `CallbackProgression.Stop` the remaining events are not processed and
+    /// [`WaitSetRunResult::StopRequest`] is returned. Any other return value of the `callback`
+    /// continues with the next event.
+    ///
+    /// If an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received, it
+    /// informs the user with [`WaitSetRunResult::Interrupt`] or
+    /// [`WaitSetRunResult::TerminationRequest`]. If a guard `Notifier` was used, it returns
+    /// [`WaitSetRunResult::GuardWoken`], otherwise [`WaitSetRunResult::AllEventsHandled`].
+    pub fn wait_and_process_once(
+        &self,
+        py: Python<'_>,
+        callback: Py<PyAny>,
+    ) -> PyResult<WaitSetRunResult> {
+        self.wait_and_process_once_with_callback(py, &callback, None)
+    }
 
-        Ok((ret_val, result.into()))
+    /// Like `WaitSet::wait_and_process_once()` but returns at the latest when the provided
+    /// timeout has passed.
+    pub fn wait_and_process_once_with_timeout(
+        &self,
+        py: Python<'_>,
+        callback: Py<PyAny>,
+        timeout: &Duration,
+    ) -> PyResult<WaitSetRunResult> {
+        self.wait_and_process_once_with_callback(py, &callback, Some(timeout))
     }
 
     /// Waits until an event arrives on the `WaitSet` or the provided timeout has passed, then
@@ -344,40 +773,23 @@ impl WaitSet {
     ///
     /// If an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was received, it will exit
     /// the loop and inform the user with [`WaitSetRunResult::Interrupt`] or
-    /// [`WaitSetRunResult::TerminationRequest`].
+    /// [`WaitSetRunResult::TerminationRequest`]. If a guard `Notifier` was used, it returns
+    /// [`WaitSetRunResult::GuardWoken`].
     pub fn wait_and_process_with_timeout(
         &self,
+        py: Python<'_>,
         timeout: &Duration,
     ) -> PyResult<(Vec<WaitSetAttachmentId>, WaitSetRunResult)> {
-        let mut ret_val = vec![];
-        let result = match &*self.0.lock() {
-            WaitSetType::Ipc(v) => v
-                .wait_and_process_once_with_timeout(
-                    |v| {
-                        ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Ipc(v)));
-                        iceoryx2::prelude::CallbackProgression::Continue
-                    },
-                    timeout.0,
-                )
-                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
-            WaitSetType::Local(v) => v
-                .wait_and_process_once_with_timeout(
-                    |v| {
-                        ret_val.push(WaitSetAttachmentId(WaitSetAttachmentIdType::Local(v)));
-                        iceoryx2::prelude::CallbackProgression::Continue
-                    },
-                    timeout.0,
-                )
-                .map_err(|e| WaitSetRunError::new_err(format!("{e:?}")))?,
-        };
-
-        Ok((ret_val, result.into()))
+        let (ret_val, result) =
+            py.allow_threads(|| self.wait_and_process_with_timeout_impl(timeout))?;
+        self.handle_response_deadlines(py, &ret_val)?;
+        Ok((ret_val, self.to_run_result(result)))
     }
 
     #[getter]
     /// Returns the capacity of the `WaitSet`
     pub fn capacity(&self) -> usize {
-        match &*self.0.lock() {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => v.capacity(),
             WaitSetType::Local(v) => v.capacity(),
         }
@@ -386,7 +798,7 @@ impl WaitSet {
     #[getter]
     /// Returns the number of attachments.
     pub fn len(&self) -> usize {
-        match &*self.0.lock() {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => v.len(),
             WaitSetType::Local(v) => v.len(),
         }
@@ -395,7 +807,7 @@ impl WaitSet {
     #[getter]
     /// Returns true if the `WaitSet` has no attachments, otherwise false.
     pub fn is_empty(&self) -> bool {
-        match &*self.0.lock() {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => v.is_empty(),
             WaitSetType::Local(v) => v.is_empty(),
         }
@@ -404,7 +816,7 @@ impl WaitSet {
     #[getter]
     /// Returns the `SignalHandlingMode` with which the `WaitSet` was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
-        match &*self.0.lock() {
+        match &*self.waitset.lock() {
             WaitSetType::Ipc(v) => v.signal_handling_mode().into(),
             WaitSetType::Local(v) => v.signal_handling_mode().into(),
         }
@@ -14,7 +14,6 @@ use pyo3::prelude::*;
 
 use crate::{
     error::WaitSetCreateError,
-    parc::Parc,
     service_type::ServiceType,
     signal_handling_mode::SignalHandlingMode,
     waitset::{WaitSet, WaitSetType},
@@ -46,14 +45,14 @@ impl WaitSetBuilder {
     pub fn create(&mut self, service_type: &ServiceType) -> PyResult<WaitSet> {
         let this = self.0.clone();
         match service_type {
-            ServiceType::Ipc => Ok(WaitSet(Parc::new(WaitSetType::Ipc(
+            ServiceType::Ipc => Ok(WaitSet::new(WaitSetType::Ipc(
                 this.create::<crate::IpcService>()
                     .map_err(|e| WaitSetCreateError::new_err(format!("{e:?}")))?,
-            )))),
-            ServiceType::Local => Ok(WaitSet(Parc::new(WaitSetType::Local(
+            ))),
+            ServiceType::Local => Ok(WaitSet::new(WaitSetType::Local(
                 this.create::<crate::LocalService>()
                     .map_err(|e| WaitSetCreateError::new_err(format!("{e:?}")))?,
-            )))),
+            ))),
         }
     }
 }
@@ -47,6 +47,7 @@ impl WaitSetGuard {
                 // reference on drop
                 let _guard = v.waitset.lock();
                 v.guard.take();
+                v._attachment.take();
             }
             WaitSetGuardType::Local(ref mut v) => {
                 // the waitset needs to be locked otherwise we encounter a race condition since the
@@ -54,6 +55,7 @@ impl WaitSetGuard {
                 // reference on drop
                 let _guard = v.waitset.lock();
                 v.guard.take();
+                v._attachment.take();
             }
         }
     }
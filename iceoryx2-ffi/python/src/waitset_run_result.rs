@@ -24,6 +24,9 @@ pub enum WaitSetRunResult {
     StopRequest,
     /// All events were handled.
     AllEventsHandled,
+    /// A `Notifier` that was added as guard with `WaitSet::add_notifier_as_guard()` woke up
+    /// the `WaitSet`.
+    GuardWoken,
 }
 
 #[pymethods]
@@ -53,7 +56,8 @@ impl From<WaitSetRunResult> for iceoryx2::waitset::WaitSetRunResult {
         match value {
             WaitSetRunResult::Interrupt => iceoryx2::waitset::WaitSetRunResult::Interrupt,
             WaitSetRunResult::StopRequest => iceoryx2::waitset::WaitSetRunResult::StopRequest,
-            WaitSetRunResult::AllEventsHandled => {
+            // the guard is handled like any other attachment in the underlying WaitSet
+            WaitSetRunResult::AllEventsHandled | WaitSetRunResult::GuardWoken => {
                 iceoryx2::waitset::WaitSetRunResult::AllEventsHandled
             }
             WaitSetRunResult::TerminationRequest => {
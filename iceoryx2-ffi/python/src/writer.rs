@@ -0,0 +1,72 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::Arc;
+
+use iceoryx2::service::builder::CustomKeyMarker;
+use iceoryx2_bb_log::fatal_panic;
+use pyo3::prelude::*;
+
+use crate::{error::WriterWriteError, type_detail::TypeDetail};
+
+pub(crate) enum WriterType {
+    Ipc(Option<Arc<iceoryx2::port::writer::Writer<crate::IpcService, CustomKeyMarker>>>),
+    Local(Option<Arc<iceoryx2::port::writer::Writer<crate::LocalService, CustomKeyMarker>>>),
+}
+
+#[pyclass]
+/// Represents the writing endpoint of a blackboard based communication. A write replaces the
+/// value of an existing entry, every `Reader` sees the entry as changed afterwards.
+pub struct Writer(pub(crate) WriterType);
+
+#[pymethods]
+impl Writer {
+    /// Replaces the value of the entry with the provided key. The `value_type` must be
+    /// identical to the `TypeDetail` the entry was added with and the `value` must have its
+    /// size. Emits a `WriterWriteError` on failure.
+    pub fn write(&self, key: &[u8], value_type: &TypeDetail, value: &[u8]) -> PyResult<()> {
+        if value.len() != value_type.0.size {
+            return Err(WriterWriteError::new_err(format!(
+                "The value has a size of {} bytes but its type requires {} bytes.",
+                value.len(),
+                value_type.0.size
+            )));
+        }
+
+        match &self.0 {
+            WriterType::Ipc(Some(v)) => {
+                unsafe { v.__internal_write(key, &value_type.0, value.as_ptr()) }
+                    .map_err(|e| WriterWriteError::new_err(format!("{e:?}")))
+            }
+            WriterType::Local(Some(v)) => {
+                unsafe { v.__internal_write(key, &value_type.0, value.as_ptr()) }
+                    .map_err(|e| WriterWriteError::new_err(format!("{e:?}")))
+            }
+            _ => fatal_panic!(from "Writer::write()",
+                "Accessing a released Writer."),
+        }
+    }
+
+    /// Releases the `Writer`.
+    ///
+    /// After this call the `Writer` is no longer usable!
+    pub fn delete(&mut self) {
+        match self.0 {
+            WriterType::Ipc(ref mut v) => {
+                v.take();
+            }
+            WriterType::Local(ref mut v) => {
+                v.take();
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import pytest
+
+import iceoryx2 as iox2
+
+allocation_strategies = [
+    iox2.AllocationStrategy.BestFit,
+    iox2.AllocationStrategy.PowerOfTwo,
+    iox2.AllocationStrategy.Static,
+]
+
+
+def test_allocation_strategy_repr_is_unambiguous() -> None:
+    assert (
+        repr(iox2.AllocationStrategy.PowerOfTwo)
+        == "AllocationStrategy.PowerOfTwo"
+    )
+    assert repr(iox2.AllocationStrategy.BestFit) == "AllocationStrategy.BestFit"
+    assert repr(iox2.AllocationStrategy.Static) == "AllocationStrategy.Static"
+
+
+def test_allocation_strategy_int_returns_stable_ordinal() -> None:
+    assert int(iox2.AllocationStrategy.BestFit) == 0
+    assert int(iox2.AllocationStrategy.PowerOfTwo) == 1
+    assert int(iox2.AllocationStrategy.Static) == 2
+
+
+@pytest.mark.parametrize("allocation_strategy", allocation_strategies)
+def test_allocation_strategy_round_trips_via_str(
+    allocation_strategy: iox2.AllocationStrategy,
+) -> None:
+    sut = iox2.AllocationStrategy.from_str(str(allocation_strategy))
+    assert sut == allocation_strategy
+
+    sut = iox2.AllocationStrategy.from_str(repr(allocation_strategy))
+    assert sut == allocation_strategy
+
+
+@pytest.mark.parametrize("allocation_strategy", allocation_strategies)
+def test_allocation_strategy_round_trips_via_int(
+    allocation_strategy: iox2.AllocationStrategy,
+) -> None:
+    sut = iox2.AllocationStrategy.from_int(int(allocation_strategy))
+    assert sut == allocation_strategy
+
+
+def test_allocation_strategy_from_invalid_str_fails() -> None:
+    with pytest.raises(iox2.InvalidAllocationStrategyValue):
+        iox2.AllocationStrategy.from_str("NotAStrategy")
+
+
+def test_allocation_strategy_from_invalid_int_fails() -> None:
+    with pytest.raises(iox2.InvalidAllocationStrategyValue):
+        iox2.AllocationStrategy.from_int(3)
+
+
+def test_estimate_memory_uses_exact_chunk_size_without_power_of_two() -> None:
+    for strategy in [
+        iox2.AllocationStrategy.BestFit,
+        iox2.AllocationStrategy.Static,
+    ]:
+        assert strategy.estimate_memory(100, 10, 20) == 1200
+        assert strategy.estimate_memory(8, 0, 8) == 0
+
+
+def test_estimate_memory_rounds_chunk_size_up_for_power_of_two() -> None:
+    sut = iox2.AllocationStrategy.PowerOfTwo
+    assert sut.estimate_memory(100, 10, 20) == 1280
+    assert sut.estimate_memory(128, 4, 0) == 512
+    assert sut.estimate_memory(129, 4, 0) == 1024
+
+
+def test_estimate_memory_rejects_negative_values() -> None:
+    with pytest.raises(OverflowError):
+        iox2.AllocationStrategy.BestFit.estimate_memory(-1, 10, 0)
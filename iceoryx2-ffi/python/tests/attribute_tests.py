@@ -41,3 +41,44 @@ def test_attribute_verifier_require_key_works() -> None:
     assert keys[0] == key
 
     assert sut.verify_requirements(specifier.attributes) is None
+
+
+def test_attribute_set_get_key_values_returns_all_values_of_a_key() -> None:
+    key = iox2.AttributeKey.new("schema_version")
+    other_key = iox2.AttributeKey.new("encoding")
+    unknown_key = iox2.AttributeKey.new("unknown")
+
+    specifier = (
+        iox2.AttributeSpecifier.new()
+        .define(key, iox2.AttributeValue.new("1"))
+        .define(other_key, iox2.AttributeValue.new("cdr"))
+        .define(key, iox2.AttributeValue.new("2"))
+        .define(key, iox2.AttributeValue.new("3"))
+    )
+    sut = specifier.attributes
+
+    values = sut.get_key_values(key)
+    assert len(values) == 3
+    for value in ["1", "2", "3"]:
+        assert iox2.AttributeValue.new(value) in values
+
+    assert sut.get_key_values(other_key) == [iox2.AttributeValue.new("cdr")]
+    assert sut.get_key_values(unknown_key) == []
+
+
+def test_attribute_set_keys_lists_every_key_once() -> None:
+    key = iox2.AttributeKey.new("schema_version")
+    other_key = iox2.AttributeKey.new("encoding")
+
+    specifier = (
+        iox2.AttributeSpecifier.new()
+        .define(key, iox2.AttributeValue.new("1"))
+        .define(other_key, iox2.AttributeValue.new("cdr"))
+        .define(key, iox2.AttributeValue.new("2"))
+    )
+
+    keys = specifier.attributes.keys()
+    assert len(keys) == 2
+    assert key in keys
+    assert other_key in keys
+    assert iox2.AttributeSpecifier.new().attributes.keys() == []
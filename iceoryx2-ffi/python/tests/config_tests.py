@@ -10,6 +10,11 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+import ctypes
+import os
+import pathlib
+import tempfile
+
 import pytest
 
 import iceoryx2 as iox2
@@ -36,6 +41,45 @@ def test_global_service_directory_can_be_set() -> None:
     assert sut.global_cfg.service.directory == path
 
 
+def test_global_service_directory_that_is_a_file_is_rejected() -> None:
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        sut = iox2.config.default()
+        pathlib.Path(tmp_dir, "not_a_directory").write_text("")
+        sut.global_cfg.root_path = iox2.Path.new(tmp_dir)
+        old_directory = sut.global_cfg.service.directory
+
+        with pytest.raises(iox2.InvalidDirectory):
+            sut.global_cfg.service.directory = iox2.Path.new("not_a_directory")
+
+        assert sut.global_cfg.service.directory == old_directory
+
+
+def test_node_creates_service_files_in_custom_directory_with_custom_prefix() -> (
+    None
+):
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        config = iox2.config.default()
+        config.global_cfg.root_path = iox2.Path.new(tmp_dir)
+        config.global_cfg.service.directory = iox2.Path.new("tenant_a")
+        config.global_cfg.prefix = iox2.FileName.new("tenant_a_")
+
+        node = iox2.NodeBuilder.new().config(config).create(
+            iox2.ServiceType.Ipc
+        )
+        service_name = iox2.ServiceName.new("isolated_service")
+        service = (
+            node.service_builder(service_name)
+            .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+            .create()
+        )
+
+        entries = list(pathlib.Path(tmp_dir, "tenant_a").iterdir())
+        assert len(entries) > 0
+        assert all(entry.name.startswith("tenant_a_") for entry in entries)
+        del service
+        del node
+
+
 def test_global_service_data_segment_suffix_can_be_set() -> None:
     sut = iox2.config.default()
     path = iox2.FileName.new(".let_suf_the_fix")
@@ -78,6 +122,14 @@ def test_global_service_event_connection_suffix_can_be_set() -> None:
     assert sut.global_cfg.service.event_connection_suffix == path
 
 
+def test_global_service_enable_discovery_announcements_can_be_set() -> None:
+    sut = iox2.config.default()
+    sut.global_cfg.service.enable_discovery_announcements = True
+    assert sut.global_cfg.service.enable_discovery_announcements
+    sut.global_cfg.service.enable_discovery_announcements = False
+    assert not sut.global_cfg.service.enable_discovery_announcements
+
+
 def test_global_node_directory_can_be_set() -> None:
     sut = iox2.config.default()
     value = iox2.Path.new("/dir/to/somewher")
@@ -288,6 +340,14 @@ def test_defaults_request_response_enable_fire_and_forget_requests_can_be_set()
     assert not sut.defaults.request_response.enable_fire_and_forget_requests
 
 
+def test_defaults_request_response_enable_multi_response_can_be_set() -> None:
+    sut = iox2.config.default()
+    sut.defaults.request_response.enable_multi_response = True
+    assert sut.defaults.request_response.enable_multi_response
+    sut.defaults.request_response.enable_multi_response = False
+    assert not sut.defaults.request_response.enable_multi_response
+
+
 def test_defaults_event_max_listeners_can_be_set() -> None:
     sut = iox2.config.default()
     value = 8891
@@ -418,6 +478,14 @@ def test_defaults_publish_subscribe_enable_safe_overflow_can_be_set() -> None:
     assert not sut.defaults.publish_subscribe.enable_safe_overflow
 
 
+def test_defaults_publish_subscribe_enable_send_timestamp_can_be_set() -> None:
+    sut = iox2.config.default()
+    sut.defaults.publish_subscribe.enable_send_timestamp = False
+    assert not sut.defaults.publish_subscribe.enable_send_timestamp
+    sut.defaults.publish_subscribe.enable_send_timestamp = True
+    assert sut.defaults.publish_subscribe.enable_send_timestamp
+
+
 def test_defaults_publish_subscribe_unable_to_deliver_strategy_can_be_set() -> (
     None
 ):
@@ -448,3 +516,86 @@ def test_defaults_publish_subscribe_subscriber_expired_connection_buffer_can_be_
         sut.defaults.publish_subscribe.subscriber_expired_connection_buffer
         == value
     )
+
+
+def test_defaults_publish_subscribe_sample_lifespan_can_be_set() -> None:
+    sut = iox2.config.default()
+    assert sut.defaults.publish_subscribe.sample_lifespan.as_nanos() == 0
+    value = iox2.Duration.from_millis(250)
+    sut.defaults.publish_subscribe.sample_lifespan = value
+    assert sut.defaults.publish_subscribe.sample_lifespan == value
+
+
+def test_defaults_publish_subscribe_publisher_numa_node_can_be_set() -> None:
+    sut = iox2.config.default()
+    assert sut.defaults.publish_subscribe.publisher_numa_node is None
+    sut.defaults.publish_subscribe.publisher_numa_node = 1
+    assert sut.defaults.publish_subscribe.publisher_numa_node == 1
+    sut.defaults.publish_subscribe.publisher_numa_node = None
+    assert sut.defaults.publish_subscribe.publisher_numa_node is None
+
+
+def test_environment_variable_overrides_config_file_setting() -> None:
+    variable = "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS"
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        config_file = pathlib.Path(tmp_dir, "iceoryx2.toml")
+        config_file.write_text(iox2.config.default().to_toml())
+
+        os.environ[variable] = "64"
+        try:
+            sut = iox2.config.from_file(iox2.FilePath.new(str(config_file)))
+        finally:
+            del os.environ[variable]
+
+        assert sut.defaults.publish_subscribe.max_subscribers == 64
+        assert "max-subscribers = 64" in sut.to_toml()
+
+
+def test_invalid_environment_variable_value_fails_to_load_config() -> None:
+    variable = "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS"
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        config_file = pathlib.Path(tmp_dir, "iceoryx2.toml")
+        config_file.write_text(iox2.config.default().to_toml())
+
+        os.environ[variable] = "many"
+        try:
+            with pytest.raises(iox2.ConfigCreationError):
+                iox2.config.from_file(iox2.FilePath.new(str(config_file)))
+        finally:
+            del os.environ[variable]
+
+
+def test_cloned_config_is_independent_of_original() -> None:
+    sut = iox2.config.default()
+    copy = sut.clone()
+
+    copy.defaults.publish_subscribe.max_subscribers = 17
+
+    assert copy.defaults.publish_subscribe.max_subscribers == 17
+    assert sut == iox2.config.default()
+
+
+def test_merge_applies_non_default_settings_of_other() -> None:
+    base = iox2.config.default()
+    base.defaults.publish_subscribe.max_subscribers = 5
+    other = iox2.config.default()
+    other.defaults.publish_subscribe.max_publishers = 3
+    base_copy = base.clone()
+
+    sut = base.merge(other)
+
+    assert sut.defaults.publish_subscribe.max_subscribers == 5
+    assert sut.defaults.publish_subscribe.max_publishers == 3
+    assert base == base_copy
+
+
+def test_merge_ignores_settings_of_other_that_equal_default() -> None:
+    base = iox2.config.default()
+    base.defaults.publish_subscribe.max_subscribers = 5
+    other = iox2.config.default()
+    default_value = other.defaults.publish_subscribe.max_subscribers
+    other.defaults.publish_subscribe.max_subscribers = default_value
+
+    sut = base.merge(other)
+
+    assert sut == base
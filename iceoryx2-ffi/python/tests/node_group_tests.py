@@ -0,0 +1,109 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+CYCLE_TIME = iox2.Duration.from_millis(10)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_new_node_group_is_empty_and_not_running(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeGroup.new(service_type, config)
+
+    assert len(sut) == 0
+    assert not sut.is_running
+    assert sut.member_ids == []
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_start_all_and_stop_all_work(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeGroup.new(service_type, config)
+    sut.add_node(iox2.NodeBuilder.new())
+    sut.add_node(iox2.NodeBuilder.new())
+
+    sut.start_all()
+    assert len(sut) == 2
+    assert sut.is_running
+    assert len(sut.member_ids) == 2
+
+    sut.stop_all()
+    assert not sut.is_running
+    assert sut.member_ids == []
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_node_group_as_context_manager_starts_and_stops_nodes(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeGroup.new(service_type, config)
+    sut.add_node(iox2.NodeBuilder.new())
+
+    with sut as group:
+        assert group.is_running
+
+    assert not sut.is_running
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_builder_of_running_node_works(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    service_name = iox2.testing.generate_service_name()
+    sut = iox2.NodeGroup.new(service_type, config)
+    sut.add_node(iox2.NodeBuilder.new())
+
+    with pytest.raises(IndexError):
+        sut.service_builder(0, service_name)
+
+    with sut:
+        service = sut.service_builder(0, service_name).event().create()
+        assert service.name == service_name
+        with pytest.raises(IndexError):
+            sut.service_builder(1, service_name)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_any_terminated_reports_dropped_watched_node(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    watched_node_1 = iox2.NodeBuilder.new().config(config).create(service_type)
+    watched_node_2 = iox2.NodeBuilder.new().config(config).create(service_type)
+    watched_id_1 = watched_node_1.id
+    watched_id_2 = watched_node_2.id
+
+    sut = iox2.NodeGroup.new(service_type, config)
+    sut.watch_node(watched_id_1)
+    sut.watch_node(watched_id_2)
+    assert len(sut.member_ids) == 2
+
+    del watched_node_1
+
+    assert sut.wait_any_terminated(CYCLE_TIME) == [watched_id_1]
+    assert sut.member_ids == [watched_id_2]
+
+    del watched_node_2
+
+    sut.wait_all_terminated(CYCLE_TIME)
+    assert sut.member_ids == []
@@ -10,6 +10,12 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+import ctypes
+import os
+import signal
+import threading
+import time
+
 import pytest
 
 import iceoryx2 as iox2
@@ -95,3 +101,128 @@ def test_wait_can_be_called(service_type: iox2.ServiceType) -> None:
         sut.wait(iox2.Duration.from_millis(1))
     except iox2.NodeWaitFailure:
         assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_shutdown_request_is_delivered_to_nodes_that_handle_it(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = (
+        iox2.NodeBuilder.new()
+        .handle_shutdown_requests(True)
+        .config(config)
+        .create(service_type)
+    )
+    ignoring_node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    sut.wait(iox2.Duration.from_millis(1))
+
+    ignoring_node.signal_shutdown()
+
+    with pytest.raises(iox2.NodeWaitFailure):
+        sut.wait(iox2.Duration.from_millis(1))
+
+    ignoring_node.wait(iox2.Duration.from_millis(1))
+
+
+def raise_signal_delayed(sig: signal.Signals) -> threading.Thread:
+    def raise_signal() -> None:
+        time.sleep(0.1)
+        os.kill(os.getpid(), sig)
+
+    thread = threading.Thread(target=raise_signal)
+    thread.start()
+    return thread
+
+
+@pytest.mark.parametrize("service_type", service_types)
+@pytest.mark.parametrize(
+    "sig, termination_signal",
+    [
+        (signal.SIGTERM, iox2.TerminationSignal.Sigterm),
+        (signal.SIGINT, iox2.TerminationSignal.Sigint),
+        (signal.SIGHUP, iox2.TerminationSignal.Sighup),
+    ],
+)
+def test_wait_for_termination_signal_returns_received_signal(
+    service_type: iox2.ServiceType,
+    sig: signal.Signals,
+    termination_signal: iox2.TerminationSignal,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    thread = raise_signal_delayed(sig)
+    result = sut.wait_for_termination_signal()
+    thread.join()
+
+    assert result == termination_signal
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_termination_signal_is_observed_by_node_and_waitset(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeBuilder.new().config(config).create(service_type)
+    waitset = iox2.WaitSetBuilder.new().create(service_type)
+    _guard = waitset.attach_interval(iox2.Duration.from_secs(10))
+
+    results = []
+    waiter = threading.Thread(
+        target=lambda: results.append(sut.wait_for_termination_signal())
+    )
+    waiter.start()
+
+    thread = raise_signal_delayed(signal.SIGINT)
+    result = iox2.WaitSetRunResult.AllEventsHandled
+    while result == iox2.WaitSetRunResult.AllEventsHandled:
+        (_, result) = waitset.wait_and_process_with_timeout(
+            iox2.Duration.from_millis(10)
+        )
+    thread.join()
+    waiter.join()
+
+    assert result in [
+        iox2.WaitSetRunResult.Interrupt,
+        iox2.WaitSetRunResult.TerminationRequest,
+    ]
+    assert results == [iox2.TerminationSignal.Sigint]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_shm_regions_report_usage_of_publisher_data_segment(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    sut = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        sut.service_builder(service_name)
+        .publish_subscribe(ctypes.c_uint64)
+        .create()
+    )
+    assert sut.shm_regions() == []
+
+    publisher = service.publisher_builder().create()
+    regions = sut.shm_regions()
+    assert len(regions) == 1
+
+    stats = regions[0].usage_stats()
+    assert stats.region_name == regions[0].name
+    assert stats.total_bytes > 0
+    assert stats.used_bytes == 0
+    assert stats.free_bytes == stats.total_bytes
+    assert stats.fragmentation_ratio == 0.0
+
+    sample = publisher.loan_uninit()
+    stats = regions[0].usage_stats()
+    assert stats.used_bytes > 0
+    assert stats.free_bytes == stats.total_bytes - stats.used_bytes
+
+    sample.delete()
+    publisher.delete()
+    assert regions[0].is_released
+    assert regions[0].usage_stats().total_bytes == 0
+    assert sut.shm_regions() == []
@@ -0,0 +1,127 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import ctypes
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+class Position(ctypes.Structure):
+    _fields_ = [
+        ("x", ctypes.c_int32),
+        ("y", ctypes.c_int32),
+        ("z", ctypes.c_double),
+    ]
+
+
+class TypeWithCustomName(ctypes.Structure):
+    _fields_ = [("value", ctypes.c_uint8)]
+
+    @staticmethod
+    def type_name() -> str:
+        return "custom_name"
+
+
+def test_type_detail_matches_struct_layout() -> None:
+    sut = iox2.PayloadCodec(Position)
+
+    assert sut.type_detail() == (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("Position"))
+        .size(ctypes.sizeof(Position))
+        .alignment(ctypes.alignment(Position))
+    )
+
+
+def test_type_detail_uses_custom_type_name() -> None:
+    sut = iox2.PayloadCodec(TypeWithCustomName)
+
+    assert sut.matches(
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("custom_name"))
+        .size(1)
+        .alignment(1)
+    )
+    assert not sut.matches(iox2.PayloadCodec(Position).type_detail())
+
+
+def test_creating_codec_for_non_structure_fails() -> None:
+    with pytest.raises(TypeError):
+        iox2.PayloadCodec(ctypes.c_uint64)
+
+
+def test_pack_and_unpack_roundtrip() -> None:
+    sut = iox2.PayloadCodec(Position)
+
+    data = sut.pack(Position(x=1, y=-2, z=3.5))
+    assert len(data) == ctypes.sizeof(Position)
+
+    value = sut.unpack(data)
+    assert value.x == 1
+    assert value.y == -2
+    assert value.z == 3.5
+
+
+def test_pack_rejects_other_types() -> None:
+    sut = iox2.PayloadCodec(Position)
+
+    with pytest.raises(TypeError):
+        sut.pack(TypeWithCustomName(value=1))
+
+
+def test_unpack_rejects_bytes_with_wrong_size() -> None:
+    sut = iox2.PayloadCodec(Position)
+
+    with pytest.raises(ValueError):
+        sut.unpack(b"\x00" * (ctypes.sizeof(Position) - 1))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_codec_can_be_used_for_request_response(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    sut = iox2.PayloadCodec(Position)
+
+    service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(sut.type_detail())
+        .response_payload_type_details(sut.type_detail())
+        .create()
+    )
+    static_config = service.static_config
+    assert sut.matches(static_config.request_message_type_details.payload)
+
+    server = service.server_builder().create()
+    client = service.client_builder().create()
+
+    pending_response = client.send_copy(sut.pack(Position(x=4, y=5, z=6.0)))
+    active_request = server.receive()
+    request = sut.unpack(active_request.payload())
+    assert request.x == 4
+    assert request.y == 5
+    assert request.z == 6.0
+
+    request.z *= 2
+    active_request.send_copy(sut.pack(request))
+    response = pending_response.receive()
+    assert response is not None
+    assert sut.unpack(response.payload()).z == 12.0
@@ -11,6 +11,7 @@
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
 import ctypes
+import time
 
 import pytest
 
@@ -163,3 +164,301 @@ def test_deleting_sample_mut_releases_it(
         sample_uninit = sut.loan_uninit()
     except iox2.LoanError:
         assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_set_history_size_larger_than_service_history_size_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .history_size(3)
+        .create()
+    )
+
+    sut = service.publisher_builder().create()
+    assert sut.history_size == 3
+
+    with pytest.raises(iox2.PublisherSetHistorySizeError):
+        sut.set_history_size(4)
+
+    sut.set_history_size(2)
+    assert sut.history_size == 2
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_reduced_history_size_is_delivered_to_new_subscribers(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .history_size(4)
+        .subscriber_max_buffer_size(4)
+        .create()
+    )
+
+    sut = service.publisher_builder().create()
+    for i in range(4):
+        sut.send_copy(Payload(data=10 + i))
+
+    sut.set_history_size(2)
+
+    subscriber = service.subscriber_builder().create()
+    # the publisher delivers the history when it updates its connections
+    sut.send_copy(Payload(data=14))
+
+    assert subscriber.receive().payload().contents.data == 12
+    assert subscriber.receive().payload().contents.data == 13
+    assert subscriber.receive().payload().contents.data == 14
+    assert subscriber.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_publisher_is_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .max_publishers(1)
+        .create()
+    )
+
+    with service.publisher_builder().create() as sut:
+        with pytest.raises(iox2.PublisherCreateError):
+            service.publisher_builder().create()
+
+    sut.__exit__(None, None, None)
+
+    try:
+        sut = service.publisher_builder().create()
+    except iox2.PublisherCreateError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_loaned_samples_are_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    with service.publisher_builder().create() as sut:
+        sample_uninit = sut.loan_uninit()
+        sample = sut.loan_uninit().assume_init()
+
+    with pytest.raises(BaseException):
+        sample_uninit.payload_ptr()
+
+    with pytest.raises(BaseException):
+        sample.payload_ptr()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_send_returns_number_of_subscribers_that_received_the_sample(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .max_subscribers(2)
+        .create()
+    )
+
+    sut = service.publisher_builder().create()
+    assert sut.send_copy(Payload(data=1)) == 0
+
+    subscriber_1 = service.subscriber_builder().create()
+    subscriber_2 = service.subscriber_builder().create()
+    assert sut.send_copy(Payload(data=2)) == 2
+    assert sut.loan_uninit().write_payload(Payload(data=3)).send() == 2
+
+    subscriber_1.delete()
+    assert sut.send_copy(Payload(data=4)) == 1
+
+    subscriber_2.delete()
+    assert sut.send_copy(Payload(data=5)) == 0
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_rate_limited_publisher_does_not_exceed_rate_limit(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    _subscriber = service.subscriber_builder().create()
+
+    sut = service.publisher_builder().create()
+    assert sut.rate_limit is None
+    sut.set_rate_limit(100.0)
+    assert sut.rate_limit == 100.0
+
+    number_of_sent_samples = 0
+    start = time.monotonic()
+    while time.monotonic() - start < 1.0:
+        sut.send_copy(Payload(data=1))
+        number_of_sent_samples += 1
+
+    assert number_of_sent_samples <= 110
+    assert number_of_sent_samples >= 50
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_send_fails_when_rate_limit_is_reached(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    subscriber = service.subscriber_builder().create()
+
+    sut = service.publisher_builder().create()
+    sut.set_rate_limit(0.1)
+
+    assert sut.loan_uninit().write_payload(Payload(data=1)).try_send() == 1
+
+    sample = sut.loan_uninit().write_payload(Payload(data=2))
+    with pytest.raises(iox2.RateLimitedError):
+        sample.try_send()
+    assert issubclass(iox2.RateLimitedError, iox2.SendError)
+
+    sut.disable_rate_limit()
+    assert sut.rate_limit is None
+    assert sample.try_send() == 1
+
+    assert subscriber.receive().payload().contents.data == 1
+    assert subscriber.receive().payload().contents.data == 2
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_set_rate_limit_with_invalid_value_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    sut = service.publisher_builder().create()
+
+    for value in [0.0, -1.0, float("inf"), float("nan")]:
+        with pytest.raises(ValueError):
+            sut.set_rate_limit(value)
+
+    assert sut.rate_limit is None
+
+
+class Structured(ctypes.Structure):
+    _fields_ = [
+        ("a", ctypes.c_uint32),
+        ("b", ctypes.c_int16),
+        ("c", ctypes.c_double),
+        ("d", ctypes.c_ubyte * 4),
+    ]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_write_helpers_write_structured_fields_into_payload(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Structured)
+        .create()
+    )
+    subscriber = service.subscriber_builder().create()
+    sut = service.publisher_builder().create()
+
+    sample = sut.loan_uninit()
+    sample.write_int(Structured.a.offset, 4000000000, 4, signed=False)
+    sample.write_int(Structured.b.offset, -1234, 2)
+    sample.write_float(Structured.c.offset, 2.5)
+    sample.write_bytes(Structured.d.offset, b"\x01\x02\x03\x04")
+    sample.assume_init().send()
+
+    received = subscriber.receive().payload().contents
+    assert received.a == 4000000000
+    assert received.b == -1234
+    assert received.c == 2.5
+    assert list(received.d) == [1, 2, 3, 4]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_write_helpers_fail_for_out_of_range_writes(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+    sut = service.publisher_builder().initial_max_slice_len(16).create()
+    sample = sut.loan_slice(8)
+
+    with pytest.raises(IndexError):
+        sample.write_bytes(4, b"\x00" * 5)
+    with pytest.raises(IndexError):
+        sample.write_int(8, 1, 1)
+    with pytest.raises(IndexError):
+        sample.write_float(-8, 1.0)
+    with pytest.raises(ValueError):
+        sample.write_int(0, 256, 1, signed=False)
+    with pytest.raises(ValueError):
+        sample.write_int(0, 1, 3)
+
+    sample.write_bytes(0, b"\x07" * 8)
+    assert bytes(sample.payload()) == b"\x07" * 8
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_write_helpers_fail_for_misaligned_writes(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Structured)
+        .create()
+    )
+    sut = service.publisher_builder().create()
+    sample = sut.loan_uninit()
+
+    with pytest.raises(ValueError):
+        sample.write_int(1, 1, 4)
+    with pytest.raises(ValueError):
+        sample.write_float(4, 1.0)
+    sample.write_float(8, 1.0)
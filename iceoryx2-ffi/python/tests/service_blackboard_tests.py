@@ -0,0 +1,138 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def u64_type() -> iox2.TypeDetail:
+    return (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("u64"))
+        .size(8)
+        .alignment(8)
+    )
+
+
+def u32_type() -> iox2.TypeDetail:
+    return (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("u32"))
+        .size(4)
+        .alignment(4)
+    )
+
+
+def key(value: int) -> bytes:
+    return value.to_bytes(8, "little")
+
+
+def value(value: int) -> bytes:
+    return value.to_bytes(4, "little")
+
+
+def create_service(node: iox2.Node) -> iox2.PortFactoryBlackboard:
+    return (
+        node.service_builder(iox2.testing.generate_service_name())
+        .blackboard()
+        .key_type_details(u64_type())
+        .add(key(0), u32_type(), value(0))
+        .add(key(1), u32_type(), value(7))
+        .create()
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_initial_value_can_be_read(service_type: iox2.ServiceType) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    reader = service.reader_builder().create()
+
+    assert reader.read(key(1), u32_type()) == value(7)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_written_value_can_be_read(service_type: iox2.ServiceType) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    reader = service.reader_builder().create()
+    writer = service.writer_builder().create()
+
+    writer.write(key(0), u32_type(), value(42))
+    assert reader.has_changed(key(0))
+    assert reader.read(key(0), u32_type()) == value(42)
+    assert not reader.has_changed(key(0))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_key_written_and_overwritten_between_reads_is_reported_exactly_once(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    reader = service.reader_builder().create()
+    writer = service.writer_builder().create()
+
+    writer.write(key(1), u32_type(), value(10))
+    writer.write(key(1), u32_type(), value(11))
+
+    assert reader.changed_keys() == [key(1)]
+    assert reader.changed_keys() == []
+    assert reader.read(key(1), u32_type()) == value(11)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_accessing_non_existing_entry_fails(service_type: iox2.ServiceType) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    reader = service.reader_builder().create()
+    writer = service.writer_builder().create()
+
+    with pytest.raises(iox2.ReaderReadError):
+        reader.read(key(5), u32_type())
+
+    with pytest.raises(iox2.WriterWriteError):
+        writer.write(key(5), u32_type(), value(1))
+
+    with pytest.raises(iox2.WriterWriteError):
+        writer.write(key(0), u64_type(), key(1))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_number_of_readers_is_limited(service_type: iox2.ServiceType) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .blackboard()
+        .key_type_details(u64_type())
+        .max_readers(1)
+        .create()
+    )
+
+    _reader = service.reader_builder().create()
+
+    with pytest.raises(iox2.ReaderCreateError):
+        service.reader_builder().create()
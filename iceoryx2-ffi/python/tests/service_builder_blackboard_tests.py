@@ -0,0 +1,309 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def key_type() -> iox2.TypeDetail:
+    return (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("u64"))
+        .size(8)
+        .alignment(8)
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_non_existing_service_can_be_created(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    try:
+        service_name = iox2.testing.generate_service_name()
+        sut = (
+            node.service_builder(service_name)
+            .blackboard()
+            .key_type_details(key_type())
+            .create()
+        )
+        assert sut.name == service_name
+    except iox2.BlackboardCreateError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_existing_service_cannot_be_created(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create()
+    )
+
+    with pytest.raises(iox2.BlackboardCreateError):
+        node.service_builder(service_name).blackboard().key_type_details(
+            key_type()
+        ).create()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_existing_service_can_be_opened(service_type: iox2.ServiceType) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create()
+    )
+    try:
+        sut = (
+            node.service_builder(service_name)
+            .blackboard()
+            .key_type_details(key_type())
+            .open()
+        )
+        assert sut.name == service_name
+        assert (
+            sut.service_id.as_str == _existing_service.service_id.as_str
+        )
+    except iox2.BlackboardOpenError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_non_existing_service_cannot_be_opened(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    with pytest.raises(iox2.BlackboardOpenError):
+        node.service_builder(service_name).blackboard().key_type_details(
+            key_type()
+        ).open()
+
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_returns_none_for_non_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .try_open()
+    )
+    assert sut is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_opens_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create()
+    )
+    sut = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .try_open()
+    )
+    assert sut is not None
+    assert sut.name == service_name
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_fails_with_different_key_type(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create()
+    )
+    with pytest.raises(iox2.BlackboardOpenError):
+        node.service_builder(service_name).blackboard().key_type_details(
+            key_type().size(4).alignment(4)
+        ).try_open()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_with_different_key_type_cannot_be_opened(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create()
+    )
+
+    with pytest.raises(iox2.BlackboardOpenError):
+        node.service_builder(service_name).blackboard().key_type_details(
+            key_type().size(4).alignment(4)
+        ).open()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_or_create_creates_and_opens_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut_create = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .open_or_create()
+    )
+    sut_open = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .open_or_create()
+    )
+
+    assert sut_create.name == service_name
+    assert sut_open.service_id.as_str == sut_create.service_id.as_str
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_builder_configuration_works(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .max_readers(7)
+        .max_nodes(5)
+        .creation_timeout(iox2.Duration.from_millis(10))
+        .create()
+    )
+
+    assert sut.static_config.max_readers == 7
+    assert sut.static_config.max_nodes == 5
+    assert sut.static_config.type_details == key_type()
+    assert len(sut.nodes) == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_create_and_open_service_with_attributes_work(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    attribute_spec = iox2.AttributeSpecifier.new().define(
+        iox2.AttributeKey.new("fuu"), iox2.AttributeValue.new("bar")
+    )
+    attribute_verifier = iox2.AttributeVerifier.new().require(
+        iox2.AttributeKey.new("fuu"), iox2.AttributeValue.new("bar")
+    )
+
+    service_name = iox2.testing.generate_service_name()
+    sut_create = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .create_with_attributes(attribute_spec)
+    )
+    sut_open = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .open_with_attributes(attribute_verifier)
+    )
+    sut_open_or_create = (
+        node.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .open_or_create_with_attributes(attribute_verifier)
+    )
+
+    assert sut_create.attributes == attribute_spec.attributes
+    assert sut_open.attributes == sut_create.attributes
+    assert sut_open_or_create.attributes == sut_create.attributes
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_opening_service_with_max_nodes_reached_raises_distinct_error(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node_1 = iox2.NodeBuilder.new().config(config).create(service_type)
+    node_2 = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _sut = (
+        node_1.service_builder(service_name)
+        .blackboard()
+        .key_type_details(key_type())
+        .max_nodes(1)
+        .create()
+    )
+
+    with pytest.raises(iox2.BlackboardOpenExceedsMaxNumberOfNodesError):
+        node_2.service_builder(service_name).blackboard().key_type_details(
+            key_type()
+        ).open()
+
+    with pytest.raises(
+        iox2.BlackboardOpenOrCreateExceedsMaxNumberOfNodesError
+    ):
+        node_2.service_builder(service_name).blackboard().key_type_details(
+            key_type()
+        ).open_or_create()
@@ -10,6 +10,8 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+from datetime import timedelta
+
 import pytest
 
 import iceoryx2 as iox2
@@ -58,6 +60,28 @@ def test_existing_service_can_be_opened(service_type: iox2.ServiceType) -> None:
     except iox2.EventOpenError:
         assert False
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_can_be_opened_with_custom_creation_timeout(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .event()
+        .creation_timeout(timedelta(milliseconds=10))
+        .create()
+    )
+    sut = (
+        node.service_builder(service_name)
+        .event()
+        .creation_timeout(iox2.Duration.from_millis(0))
+        .open_or_create()
+    )
+    assert sut.name == service_name
+
 
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_cannot_be_opened(
@@ -71,6 +95,48 @@ def test_non_existing_service_cannot_be_opened(
         node.service_builder(service_name).event().open()
 
 
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_returns_none_for_non_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = node.service_builder(service_name).event().try_open()
+    assert sut is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_opens_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = node.service_builder(service_name).event().create()
+    sut = node.service_builder(service_name).event().try_open()
+    assert sut is not None
+    assert sut.name == service_name
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_fails_with_incompatible_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name).event().max_listeners(1).create()
+    )
+    with pytest.raises(iox2.EventOpenError):
+        node.service_builder(service_name).event().max_listeners(2).try_open()
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_is_created_with_open_or_create(
     service_type: iox2.ServiceType,
@@ -248,6 +314,37 @@ def test_service_builder_configuration_works(
     assert static_config.notifier_dropped_event == notifier_dropped
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_max_event_id_value_is_negotiated_on_open(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    max_event_id_value = 12
+    sut_create = (
+        node.service_builder(service_name)
+        .event()
+        .max_event_id_value(max_event_id_value)
+        .create()
+    )
+    assert sut_create.static_config.max_event_id_value == max_event_id_value
+
+    sut_open = (
+        node.service_builder(service_name)
+        .event()
+        .max_event_id_value(max_event_id_value - 1)
+        .open()
+    )
+    assert sut_open.static_config.max_event_id_value == max_event_id_value
+
+    with pytest.raises(iox2.EventOpenError):
+        node.service_builder(service_name).event().max_event_id_value(
+            max_event_id_value + 1
+        ).open()
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_service_builder_based_on_custom_config_works(
     service_type: iox2.ServiceType,
@@ -262,3 +359,30 @@ def test_service_builder_based_on_custom_config_works(
 
     static_config = sut.static_config
     assert static_config.max_nodes == max_nodes
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_opening_service_with_max_nodes_reached_raises_distinct_error(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node_1 = iox2.NodeBuilder.new().config(config).create(service_type)
+    node_2 = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = node_1.service_builder(service_name).event().max_nodes(1).create()
+    assert sut.static_config.max_nodes == 1
+
+    with pytest.raises(iox2.EventOpenExceedsMaxNumberOfNodesError):
+        node_2.service_builder(service_name).event().open()
+
+    with pytest.raises(iox2.EventOpenOrCreateExceedsMaxNumberOfNodesError):
+        node_2.service_builder(service_name).event().open_or_create()
+
+    assert issubclass(
+        iox2.EventOpenExceedsMaxNumberOfNodesError, iox2.EventOpenError
+    )
+    assert issubclass(
+        iox2.EventOpenOrCreateExceedsMaxNumberOfNodesError,
+        iox2.EventOpenOrCreateError,
+    )
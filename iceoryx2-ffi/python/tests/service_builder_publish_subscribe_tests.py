@@ -11,6 +11,7 @@
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
 import ctypes
+from datetime import timedelta
 
 import pytest
 
@@ -27,6 +28,10 @@ class HeaderPayload(ctypes.Structure):
     _fields_ = [("data", ctypes.c_ubyte), ("fuu", ctypes.c_int)]
 
 
+class SameLayoutPayload(ctypes.Structure):
+    _fields_ = [("value", ctypes.c_ubyte)]
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_can_be_created(
     service_type: iox2.ServiceType,
@@ -78,6 +83,28 @@ def test_existing_service_can_be_opened(service_type: iox2.ServiceType) -> None:
     except iox2.PublishSubscribeOpenError:
         assert False
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_can_be_opened_with_custom_creation_timeout(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .creation_timeout(timedelta(milliseconds=10))
+        .create()
+    )
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .creation_timeout(iox2.Duration.from_millis(0))
+        .open_or_create()
+    )
+    assert sut.name == service_name
+
 
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_cannot_be_opened(
@@ -91,6 +118,60 @@ def test_non_existing_service_cannot_be_opened(
         node.service_builder(service_name).publish_subscribe(Payload).open()
 
 
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_returns_none_for_non_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .try_open()
+    )
+    assert sut is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_opens_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .try_open()
+    )
+    assert sut is not None
+    assert sut.name == service_name
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_fails_with_incompatible_payload_type(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    with pytest.raises(iox2.PublishSubscribeOpenError):
+        node.service_builder(service_name).publish_subscribe(
+            HeaderPayload
+        ).try_open()
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_is_created_with_open_or_create(
     service_type: iox2.ServiceType,
@@ -250,6 +331,7 @@ def test_service_builder_configuration_works(
 
     service_name = iox2.testing.generate_service_name()
     safe_overflow = False
+    send_timestamp = False
     subscriber_max_borrowed_samples = 10
     history_size = 29
     subscriber_max_buffer_size = 38
@@ -260,6 +342,7 @@ def test_service_builder_configuration_works(
         node.service_builder(service_name)
         .publish_subscribe(Payload)
         .enable_safe_overflow(safe_overflow)
+        .enable_send_timestamp(send_timestamp)
         .subscriber_max_borrowed_samples(subscriber_max_borrowed_samples)
         .history_size(history_size)
         .subscriber_max_buffer_size(subscriber_max_buffer_size)
@@ -282,6 +365,7 @@ def test_service_builder_configuration_works(
         == subscriber_max_borrowed_samples
     )
     assert static_config.has_safe_overflow == safe_overflow
+    assert static_config.has_send_timestamp == send_timestamp
 
 
 @pytest.mark.parametrize("service_type", service_types)
@@ -339,3 +423,159 @@ def test_custom_payload_works(service_type: iox2.ServiceType) -> None:
     sut = node.service_builder(service_name).publish_subscribe(Payload).create()
 
     assert sut.static_config.message_type_details.payload == payload
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_opening_service_with_max_nodes_reached_raises_distinct_error(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node_1 = iox2.NodeBuilder.new().config(config).create(service_type)
+    node_2 = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node_1.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .max_nodes(1)
+        .create()
+    )
+    assert sut.static_config.max_nodes == 1
+
+    with pytest.raises(iox2.PublishSubscribeOpenExceedsMaxNumberOfNodesError):
+        node_2.service_builder(service_name).publish_subscribe(Payload).open()
+
+    with pytest.raises(
+        iox2.PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError
+    ):
+        node_2.service_builder(service_name).publish_subscribe(
+            Payload
+        ).open_or_create()
+
+    assert issubclass(
+        iox2.PublishSubscribeOpenExceedsMaxNumberOfNodesError,
+        iox2.PublishSubscribeOpenError,
+    )
+    assert issubclass(
+        iox2.PublishSubscribeOpenOrCreateExceedsMaxNumberOfNodesError,
+        iox2.PublishSubscribeOpenOrCreateError,
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_opening_service_with_different_sample_lifespan_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .sample_lifespan(timedelta(milliseconds=100))
+        .create()
+    )
+    assert sut.static_config.sample_lifespan == iox2.Duration.from_millis(100)
+
+    with pytest.raises(iox2.PublishSubscribeOpenError):
+        node.service_builder(service_name).publish_subscribe(
+            Payload
+        ).disable_sample_lifespan().open()
+
+    sut_open = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .sample_lifespan(iox2.Duration.from_millis(100))
+        .open()
+    )
+    assert sut_open.static_config.sample_lifespan == iox2.Duration.from_millis(
+        100
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_layout_only_type_compatibility_ignores_type_name(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .type_compatibility(iox2.TypeCompatibility.LayoutOnly)
+        .create()
+    )
+    assert (
+        sut.static_config.type_compatibility
+        == iox2.TypeCompatibility.LayoutOnly
+    )
+
+    with pytest.raises(iox2.PublishSubscribeOpenError):
+        node.service_builder(service_name).publish_subscribe(
+            SameLayoutPayload
+        ).open()
+
+    sut_open = (
+        node.service_builder(service_name)
+        .publish_subscribe(SameLayoutPayload)
+        .type_compatibility(iox2.TypeCompatibility.LayoutOnly)
+        .open()
+    )
+    assert (
+        sut_open.static_config.type_compatibility
+        == iox2.TypeCompatibility.LayoutOnly
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_payload_type_name_can_be_overridden(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    payload = (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("com.acme.Payload"))
+        .size(ctypes.sizeof(Payload))
+        .alignment(ctypes.alignment(Payload))
+    )
+    sut = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .payload_type_name("com.acme.Payload")
+        .create()
+    )
+    assert sut.static_config.message_type_details.payload == payload
+
+    with pytest.raises(iox2.PublishSubscribeOpenError):
+        node.service_builder(service_name).publish_subscribe(
+            SameLayoutPayload
+        ).open()
+
+    sut_open = (
+        node.service_builder(service_name)
+        .publish_subscribe(SameLayoutPayload)
+        .payload_type_name("com.acme.Payload")
+        .open()
+    )
+    assert sut_open.static_config.message_type_details.payload == payload
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_invalid_payload_type_name_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    builder = node.service_builder(service_name).publish_subscribe(Payload)
+    for name in ["", "com.acme Payload", "a" * (iox2.TypeName.max_len() + 1)]:
+        with pytest.raises(iox2.SemanticStringError):
+            builder.payload_type_name(name)
@@ -10,6 +10,8 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+from datetime import timedelta
+
 import pytest
 
 import iceoryx2 as iox2
@@ -62,6 +64,64 @@ def test_existing_service_can_be_opened(service_type: iox2.ServiceType) -> None:
     except iox2.RequestResponseOpenError:
         assert False
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_service_can_be_opened_with_custom_creation_timeout(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .creation_timeout(timedelta(milliseconds=10))
+        .create()
+    )
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .creation_timeout(iox2.Duration.from_millis(0))
+        .open_or_create()
+    )
+    assert sut.name == service_name
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_topic_prefix_is_prepended_to_service_name(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    _existing_service = (
+        node.service_builder(iox2.ServiceName.new("robot/arm/joint_state"))
+        .request_response()
+        .create()
+    )
+    sut = (
+        node.service_builder(iox2.ServiceName.new("joint_state"))
+        .request_response()
+        .with_topic_prefix("robot/arm/")
+        .open()
+    )
+    assert sut.name.to_string() == "robot/arm/joint_state"
+    assert sut.name.strip_topic_prefix("robot/arm/") == "joint_state"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_topic_prefix_fails_when_service_name_becomes_too_long(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    prefix = "x" * iox2.ServiceName.max_len()
+    with pytest.raises(iox2.SemanticStringError):
+        node.service_builder(
+            iox2.ServiceName.new("joint_state")
+        ).request_response().with_topic_prefix(prefix)
+
 
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_cannot_be_opened(
@@ -75,6 +135,58 @@ def test_non_existing_service_cannot_be_opened(
         node.service_builder(service_name).request_response().open()
 
 
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_returns_none_for_non_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = node.service_builder(service_name).request_response().try_open()
+    assert sut is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_opens_existing_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name).request_response().create()
+    )
+    sut = node.service_builder(service_name).request_response().try_open()
+    assert sut is not None
+    assert sut.name == service_name
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_try_open_fails_with_incompatible_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .enable_multi_response(False)
+        .create()
+    )
+    with pytest.raises(iox2.RequestResponseOpenError):
+        (
+            node.service_builder(service_name)
+            .request_response()
+            .enable_multi_response(True)
+            .try_open()
+        )
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_non_existing_service_is_created_with_open_or_create(
     service_type: iox2.ServiceType,
@@ -232,7 +344,9 @@ def test_service_builder_configuration_works(
     safe_overflow_requests = False
     safe_overflow_responses = False
     fire_and_forget = False
+    multi_response = False
     max_active_requests_per_client = 99
+    max_request_queue_depth_per_client = 22
     max_loaned_requests = 88
     max_response_buffer_size = 77
     max_servers = 66
@@ -245,7 +359,9 @@ def test_service_builder_configuration_works(
         .enable_safe_overflow_for_requests(safe_overflow_requests)
         .enable_safe_overflow_for_responses(safe_overflow_responses)
         .enable_fire_and_forget_requests(fire_and_forget)
+        .enable_multi_response(multi_response)
         .max_active_requests_per_client(max_active_requests_per_client)
+        .max_request_queue_depth_per_client(max_request_queue_depth_per_client)
         .max_loaned_requests(max_loaned_requests)
         .max_response_buffer_size(max_response_buffer_size)
         .max_servers(max_servers)
@@ -267,6 +383,7 @@ def test_service_builder_configuration_works(
     assert (
         static_config.does_support_fire_and_forget_requests == fire_and_forget
     )
+    assert static_config.does_support_multi_response == multi_response
     assert (
         static_config.max_borrowed_responses_per_pending_response
         == max_borrowed_responses_per_pending_response
@@ -275,6 +392,10 @@ def test_service_builder_configuration_works(
         static_config.max_active_requests_per_client
         == max_active_requests_per_client
     )
+    assert (
+        static_config.max_request_queue_depth_per_client
+        == max_request_queue_depth_per_client
+    )
     assert static_config.max_response_buffer_size == max_response_buffer_size
     assert static_config.max_loaned_requests == max_loaned_requests
     assert static_config.max_servers == max_servers
@@ -282,6 +403,115 @@ def test_service_builder_configuration_works(
     assert static_config.max_nodes == max_nodes
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_fails_with_incompatible_multi_response_behavior(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .enable_multi_response(False)
+        .create()
+    )
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        (
+            node.service_builder(service_name)
+            .request_response()
+            .enable_multi_response(True)
+            .open()
+        )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_response_deadline_is_stored_in_static_config(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    deadline = iox2.Duration.from_millis(123)
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .with_response_deadline(deadline)
+        .create()
+    )
+
+    assert sut.static_config.response_deadline == deadline
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_fails_with_incompatible_response_deadline(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .with_response_deadline(iox2.Duration.from_millis(100))
+        .create()
+    )
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        (
+            node.service_builder(service_name)
+            .request_response()
+            .disable_response_deadline()
+            .open()
+        )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_credit_flow_control_is_stored_in_static_config(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .enable_credit_flow_control(3)
+        .create()
+    )
+
+    assert sut.static_config.credits_per_client == 3
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_fails_with_incompatible_credit_flow_control(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .enable_credit_flow_control(3)
+        .create()
+    )
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        (
+            node.service_builder(service_name)
+            .request_response()
+            .disable_credit_flow_control()
+            .open()
+        )
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_custom_request_payload_works(service_type: iox2.ServiceType) -> None:
     config = iox2.testing.generate_isolated_config()
@@ -331,6 +561,49 @@ def test_custom_request_header_works(service_type: iox2.ServiceType) -> None:
     )
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_header_size_can_be_defined(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .with_request_header_size(24, 8)
+        .create()
+    )
+
+    assert (
+        sut.static_config.request_message_type_details.user_header
+        == iox2.TypeDetail.new().size(24).alignment(8)
+    )
+
+    builder = node.service_builder(service_name).request_response()
+    try:
+        builder.with_request_header_size(24, 8).open()
+    except iox2.RequestResponseOpenError:
+        assert False
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        builder.with_request_header_size(32, 8).open()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_header_size_with_invalid_alignment_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    builder = node.service_builder(service_name).request_response()
+    with pytest.raises(iox2.InvalidAlignmentValue):
+        builder.with_request_header_size(24, 3)
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_custom_response_payload_works(service_type: iox2.ServiceType) -> None:
     config = iox2.testing.generate_isolated_config()
@@ -378,3 +651,257 @@ def test_custom_response_header_works(service_type: iox2.ServiceType) -> None:
         sut.static_config.response_message_type_details.user_header
         == user_header
     )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_response_header_size_can_be_defined(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .with_response_header_size(24, 8)
+        .create()
+    )
+
+    assert (
+        sut.static_config.response_message_type_details.user_header
+        == iox2.TypeDetail.new().size(24).alignment(8)
+    )
+
+    builder = node.service_builder(service_name).request_response()
+    try:
+        builder.with_response_header_size(24, 8).open()
+    except iox2.RequestResponseOpenError:
+        assert False
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        builder.with_response_header_size(32, 8).open()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_response_header_size_with_invalid_alignment_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    builder = node.service_builder(service_name).request_response()
+    with pytest.raises(iox2.InvalidAlignmentValue):
+        builder.with_response_header_size(24, 3)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_type_evolution_allows_opening_service_with_larger_payload(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+
+    def payload(size: int) -> iox2.TypeDetail:
+        return (
+            iox2.TypeDetail.new()
+            .type_variant(iox2.TypeVariant.FixedSize)
+            .type_name(iox2.TypeName.new("EvolvingType"))
+            .size(size)
+            .alignment(8)
+        )
+
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(payload(16))
+        .create()
+    )
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        (
+            node.service_builder(service_name)
+            .request_response()
+            .request_payload_type_details(payload(8))
+            .open()
+        )
+
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(payload(8))
+        .enable_type_evolution(True)
+        .open()
+    )
+
+    assert (
+        sut.static_config.request_message_type_details.payload == payload(8)
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_type_evolution_allows_opening_service_with_smaller_payload(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+
+    def payload(size: int) -> iox2.TypeDetail:
+        return (
+            iox2.TypeDetail.new()
+            .type_variant(iox2.TypeVariant.FixedSize)
+            .type_name(iox2.TypeName.new("EvolvingType"))
+            .size(size)
+            .alignment(8)
+        )
+
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(payload(8))
+        .create()
+    )
+
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(payload(16))
+        .enable_type_evolution(True)
+        .open()
+    )
+
+    assert (
+        sut.static_config.request_message_type_details.payload == payload(16)
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_treats_limits_as_minimum_requirements(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .max_clients(10)
+        .create()
+    )
+
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .max_clients(5)
+        .open_with_attributes(iox2.AttributeVerifier.new())
+    )
+    assert sut.static_config.max_clients == 10
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        node.service_builder(service_name).request_response().max_clients(
+            11
+        ).open()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_open_strict_requires_exact_limits(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    _existing_service = (
+        node.service_builder(service_name)
+        .request_response()
+        .max_clients(10)
+        .create()
+    )
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        node.service_builder(service_name).request_response().max_clients(
+            5
+        ).open_strict()
+
+    with pytest.raises(iox2.RequestResponseOpenError):
+        node.service_builder(service_name).request_response().max_clients(
+            5
+        ).open_strict_with_attributes(iox2.AttributeVerifier.new())
+
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .max_clients(10)
+        .open_strict()
+    )
+    assert sut.static_config.max_clients == 10
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_opening_service_with_max_nodes_reached_raises_distinct_error(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node_1 = iox2.NodeBuilder.new().config(config).create(service_type)
+    node_2 = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node_1.service_builder(service_name)
+        .request_response()
+        .max_nodes(1)
+        .create()
+    )
+    assert sut.static_config.max_nodes == 1
+
+    with pytest.raises(iox2.RequestResponseOpenExceedsMaxNumberOfNodesError):
+        node_2.service_builder(service_name).request_response().open()
+
+    with pytest.raises(
+        iox2.RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError
+    ):
+        node_2.service_builder(service_name).request_response().open_or_create()
+
+    assert issubclass(
+        iox2.RequestResponseOpenExceedsMaxNumberOfNodesError,
+        iox2.RequestResponseOpenError,
+    )
+    assert issubclass(
+        iox2.RequestResponseOpenOrCreateExceedsMaxNumberOfNodesError,
+        iox2.RequestResponseOpenOrCreateError,
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_repr_reflects_current_settings(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    sut = (
+        node.service_builder(service_name)
+        .request_response()
+        .max_clients(3)
+        .max_servers(4)
+        .enable_safe_overflow_for_requests(False)
+        .request_payload_type_details(
+            iox2.TypeDetail.new().size(8).alignment(8)
+        )
+    )
+
+    representation = repr(sut)
+    assert representation.startswith("ServiceBuilderRequestResponse(")
+    assert "max_clients=3" in representation
+    assert "max_servers=4" in representation
+    assert "enable_safe_overflow_for_requests=False" in representation
+    assert "request_payload_type_details_set=True" in representation
+    assert "response_payload_type_details_set=False" in representation
+    assert str(sut) == representation
@@ -10,6 +10,9 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+import datetime
+import time
+
 import pytest
 
 import iceoryx2 as iox2
@@ -35,6 +38,24 @@ def test_notifier_use_default_event_id(
     assert listener.try_wait_one() == event_id
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_notifier_default_event_id_can_be_acquired(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    event_id = iox2.EventId.new(23)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    sut_default = service.notifier_builder().create()
+    sut_custom = service.notifier_builder().default_event_id(event_id).create()
+
+    assert sut_default.default_event_id == iox2.EventId.new(0)
+    assert sut_custom.default_event_id == event_id
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_notification_with_custom_event_id_works(
     service_type: iox2.ServiceType,
@@ -53,6 +74,81 @@ def test_notification_with_custom_event_id_works(
     assert listener.try_wait_one() == event_id
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_notification_with_event_id_greater_than_max_value_fails(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    max_event_id_value = 16
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .event()
+        .max_event_id_value(max_event_id_value)
+        .create()
+    )
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    with pytest.raises(ValueError):
+        notifier.notify_with_custom_event_id(
+            iox2.EventId.new(max_event_id_value + 1)
+        )
+    assert listener.try_wait_one() is None
+
+    notifier.notify_with_custom_event_id(iox2.EventId.new(max_event_id_value))
+    assert listener.try_wait_one() == iox2.EventId.new(max_event_id_value)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_notify_id_set_delivers_all_event_ids(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    ids = {2, 5, 11}
+    assert notifier.notify_id_set(ids) == 1
+
+    events = listener.try_wait_all()
+    assert len(events) == len(ids)
+    for i in ids:
+        assert iox2.EventId.new(i) in events
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_notify_id_set_with_out_of_bounds_id_delivers_nothing(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    max_event_id_value = 16
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .event()
+        .max_event_id_value(max_event_id_value)
+        .create()
+    )
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    with pytest.raises(ValueError):
+        notifier.notify_id_set({1, max_event_id_value + 1})
+    assert listener.try_wait_one() is None
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_deadline_can_be_acquired_via_ports(
     service_type: iox2.ServiceType,
@@ -73,6 +169,64 @@ def test_deadline_can_be_acquired_via_ports(
     assert notifier.deadline == deadline
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_deadline_can_be_set_via_timedelta(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    deadline = datetime.timedelta(milliseconds=123)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).event().deadline(deadline).create()
+    )
+
+    listener = service.listener_builder().create()
+
+    assert listener.deadline == iox2.Duration.from_millis(123)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_detects_missed_deadline(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    deadline = datetime.timedelta(milliseconds=10)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).event().deadline(deadline).create()
+    )
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    time.sleep(0.05)
+    assert listener.has_missed_deadline()
+
+    with pytest.raises(iox2.NotifierNotifyError):
+        notifier.notify()
+    assert not listener.has_missed_deadline()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_without_deadline_never_misses_deadline(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    listener = service.listener_builder().create()
+
+    time.sleep(0.01)
+    assert not listener.has_missed_deadline()
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_listener_try_wait_one_works(
     service_type: iox2.ServiceType,
@@ -95,6 +249,29 @@ def test_listener_try_wait_one_works(
     assert listener.try_wait_one() == event_id_2
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_try_wait_one_returns_none_when_no_event_is_pending(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    event_id = iox2.EventId.new(7)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    for _ in range(0, 100):
+        assert listener.try_wait_one() is None
+
+    notifier.notify_with_custom_event_id(event_id)
+
+    assert listener.try_wait_one() == event_id
+    assert listener.try_wait_one() is None
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_listener_timed_wait_one_works(
     service_type: iox2.ServiceType,
@@ -214,3 +391,119 @@ def test_listener_blocking_wait_all_works(
     assert len(events) == 2
     assert events[0] == event_id_1
     assert events[1] == event_id_2
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_receive_all_events_drains_all_events(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    notifier.notify_with_custom_event_id(iox2.EventId.new(18))
+    notifier.notify_with_custom_event_id(iox2.EventId.new(81))
+
+    events = listener.receive_all_events()
+
+    assert events == [18, 81]
+    assert listener.receive_all_events() == []
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_receive_all_events_drains_at_most_max_count_events(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    notifier.notify_with_custom_event_id(iox2.EventId.new(19))
+    notifier.notify_with_custom_event_id(iox2.EventId.new(91))
+    notifier.notify_with_custom_event_id(iox2.EventId.new(119))
+
+    assert listener.receive_all_events(2) == [19, 91]
+    assert listener.receive_all_events(2) == [119]
+    assert listener.receive_all_events(0) == []
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_notifier_is_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).event().max_notifiers(1).create()
+    )
+
+    with service.notifier_builder().create() as sut:
+        with pytest.raises(iox2.NotifierCreateError):
+            service.notifier_builder().create()
+
+    sut.__exit__(None, None, None)
+
+    try:
+        sut = service.notifier_builder().create()
+    except iox2.NotifierCreateError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_listener_is_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).event().max_listeners(1).create()
+    )
+
+    with service.listener_builder().create() as sut:
+        with pytest.raises(iox2.ListenerCreateError):
+            service.listener_builder().create()
+
+    sut.__exit__(None, None, None)
+
+    try:
+        sut = service.listener_builder().create()
+    except iox2.ListenerCreateError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_dynamic_config_snapshot_contains_all_ports(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+
+    notifier = service.notifier_builder().create()
+    listener = service.listener_builder().create()
+
+    sut = service.dynamic_config()
+    assert sut.number_of_notifiers == 1
+    assert sut.number_of_listeners == 1
+    assert sut.notifier_ids == [notifier.id]
+    assert sut.listener_ids == [listener.id]
+
+    listener_id = listener.id
+    listener.delete()
+    assert sut.listener_ids == [listener_id]
+    assert service.dynamic_config().number_of_listeners == 0
@@ -24,3 +24,13 @@ def test_service_name_construction_fails_when_max_len_is_exceeded() -> None:
     sut_value = "x" * (iox2.ServiceName.max_len() + 1)
     with pytest.raises(iox2.SemanticStringError):
         iox2.ServiceName.new(sut_value)
+
+
+def test_service_name_strip_topic_prefix_returns_remainder() -> None:
+    sut = iox2.ServiceName.new("robot/arm/joint_state")
+    assert sut.strip_topic_prefix("robot/arm/") == "joint_state"
+
+
+def test_service_name_strip_topic_prefix_returns_none_without_prefix() -> None:
+    sut = iox2.ServiceName.new("robot/arm/joint_state")
+    assert sut.strip_topic_prefix("robot/leg/") is None
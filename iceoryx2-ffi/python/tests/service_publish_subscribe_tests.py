@@ -11,6 +11,8 @@
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
 import ctypes
+import time
+from datetime import timedelta
 
 import pytest
 
@@ -175,7 +177,10 @@ def test_published_header_is_the_same_as_received_header(
 
     service_name = iox2.testing.generate_service_name()
     service = (
-        node.service_builder(service_name).publish_subscribe(Payload).create()
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_send_timestamp(False)
+        .create()
     )
 
     publisher = service.publisher_builder().create()
@@ -259,6 +264,64 @@ def test_slice_api_can_be_used(
         publisher.loan_slice_uninit(9)
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_empty_slice_can_be_loaned_sent_and_received(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = (
+        service.publisher_builder()
+        .initial_max_slice_len(8)
+        .allocation_strategy(iox2.AllocationStrategy.Static)
+        .create()
+    )
+    subscriber = service.subscriber_builder().create()
+
+    sample = publisher.loan_slice(0)
+    assert sample.payload().len() == 0
+    sample.send()
+
+    received_sample = subscriber.receive()
+    assert received_sample is not None
+    assert received_sample.payload().len() == 0
+    assert bytes(received_sample.payload()) == b""
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_loaned_slice_is_zero_initialized(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = service.publisher_builder().initial_max_slice_len(8).create()
+    subscriber = service.subscriber_builder().create()
+
+    sample = publisher.loan_slice(8)
+    sample.payload()[3] = 7
+    sample.send()
+
+    received_sample = subscriber.receive()
+    assert received_sample is not None
+    assert bytes(received_sample.payload()) == bytes([0, 0, 0, 7, 0, 0, 0, 0])
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_slice_api_allocation_strategy_works(
     service_type: iox2.ServiceType,
@@ -286,6 +349,67 @@ def test_slice_api_allocation_strategy_works(
         assert False
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_slice_api_max_slice_len_reflects_data_segment_growth(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = (
+        service.publisher_builder()
+        .initial_max_slice_len(8)
+        .allocation_strategy(iox2.AllocationStrategy.PowerOfTwo)
+        .create()
+    )
+
+    assert publisher.max_slice_len == 8
+    assert publisher.number_of_data_segment_reallocations == 0
+
+    publisher.loan_slice_uninit(64)
+
+    assert publisher.max_slice_len >= 64
+    assert publisher.number_of_data_segment_reallocations == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_slices_exceeding_subscriber_max_slice_len_are_discarded(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(iox2.Slice[ctypes.c_uint8])
+        .create()
+    )
+
+    publisher = (
+        service.publisher_builder()
+        .initial_max_slice_len(8)
+        .allocation_strategy(iox2.AllocationStrategy.PowerOfTwo)
+        .create()
+    )
+    subscriber = service.subscriber_builder().max_slice_len(8).create()
+    assert subscriber.max_slice_len == 8
+
+    sample = publisher.loan_slice(32)
+    sample.send()
+
+    with pytest.raises(iox2.ReceiveError):
+        subscriber.receive()
+    assert subscriber.receive() is None
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_slice_type_forbids_use_of_non_slice_api(
     service_type: iox2.ServiceType,
@@ -334,3 +458,331 @@ def test_non_slice_type_forbids_use_of_slice_api(
 
     with pytest.raises(AssertionError):
         publisher.loan_slice_uninit(1)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_samples_exceeding_the_sample_lifespan_are_discarded(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(2)
+        .sample_lifespan(timedelta(milliseconds=50))
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=1))
+    time.sleep(0.1)
+    publisher.send_copy(Payload(data=2))
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.payload().contents.data == 2
+    assert subscriber.receive() is None
+    assert subscriber.number_of_expired_samples == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_send_with_timestamp_stores_provided_timestamp(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_send_timestamp(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    capture_time_ns = 987654321
+    publisher.send_with_timestamp(Payload(data=1), capture_time_ns)
+    publisher.send_copy(Payload(data=2))
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.payload().contents.data == 1
+    assert sample.timestamp_ns == capture_time_ns
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.timestamp_ns is not None
+    assert sample.timestamp_ns > capture_time_ns
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_sample_has_no_timestamp_when_send_timestamp_is_disabled(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_send_timestamp(False)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=1))
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.timestamp_ns is None
+    assert sample.age_ns() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_sample_age_is_derived_from_timestamp(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_send_timestamp(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=1))
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.timestamp_ns is not None
+    age_ns = sample.age_ns()
+    assert age_ns is not None
+    assert sample.age_ns() >= age_ns
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_samples_exceeding_the_max_sample_age_are_discarded(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(2)
+        .enable_send_timestamp(True)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+    assert subscriber.max_sample_age is None
+    subscriber.set_max_sample_age(0.05)
+    assert subscriber.max_sample_age is not None
+    assert subscriber.max_sample_age.as_millis() == 50
+
+    publisher.send_copy(Payload(data=1))
+    time.sleep(0.1)
+    publisher.send_copy(Payload(data=2))
+
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.payload().contents.data == 2
+    assert subscriber.receive() is None
+    assert subscriber.number_of_expired_samples == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_overflow_counters_count_samples_replaced_by_safe_overflow(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_safe_overflow(True)
+        .subscriber_max_buffer_size(2)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+    assert publisher.overflow_count == 0
+    assert subscriber.dropped_sample_count == 0
+
+    publisher.send_copy(Payload(data=0))
+    assert subscriber.receive() is not None
+
+    for n in range(1, 6):
+        publisher.send_copy(Payload(data=n))
+    while subscriber.receive() is not None:
+        pass
+
+    assert publisher.overflow_count == 3
+    assert subscriber.dropped_sample_count == 3
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_publisher_with_huge_pages_delivers_samples(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    default_publisher = service.publisher_builder().create()
+    assert not default_publisher.has_huge_pages
+    default_publisher.delete()
+
+    # falls back to regular pages when huge pages are not available
+    publisher = service.publisher_builder().use_huge_pages(True).create()
+    subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=71))
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.payload().contents.data == 71
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_publisher_ignores_non_existing_numa_node(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    default_publisher = service.publisher_builder().create()
+    assert default_publisher.numa_node is None
+    default_publisher.delete()
+
+    publisher = service.publisher_builder().numa_node(1024).create()
+    subscriber = service.subscriber_builder().create()
+    assert publisher.numa_node is None
+
+    publisher.send_copy(Payload(data=72))
+    sample = subscriber.receive()
+    assert sample is not None
+    assert sample.payload().contents.data == 72
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_negative_max_sample_age_is_rejected(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+    subscriber = service.subscriber_builder().create()
+
+    with pytest.raises(ValueError):
+        subscriber.set_max_sample_age(-1.0)
+    with pytest.raises(ValueError):
+        subscriber.set_max_sample_age(float("nan"))
+    assert subscriber.max_sample_age is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_dynamic_config_snapshot_does_not_change_after_creation(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    publisher_1 = service.publisher_builder().create()
+    publisher_2 = service.publisher_builder().create()
+    subscriber = service.subscriber_builder().create()
+
+    sut = service.dynamic_config()
+    assert sut.number_of_publishers == 2
+    assert sut.number_of_subscribers == 1
+    assert publisher_1.id in sut.publisher_ids
+    assert publisher_2.id in sut.publisher_ids
+    assert sut.subscriber_ids == [subscriber.id]
+
+    publisher_1.delete()
+    subscriber.delete()
+
+    assert sut.number_of_publishers == 2
+    assert sut.number_of_subscribers == 1
+
+    sut = service.dynamic_config()
+    assert sut.publisher_ids == [publisher_2.id]
+    assert sut.number_of_subscribers == 0
+    assert sut.subscriber_ids == []
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_memory_usage_tracks_allocated_chunks(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(LargePayload)
+        .create()
+    )
+
+    usage = service.memory_usage()
+    assert usage.number_of_chunks == 0
+    assert usage.allocated_bytes == 0
+    assert usage.data_segment_size == 0
+
+    publisher = service.publisher_builder().create()
+    sample = publisher.loan_uninit()
+
+    usage = service.memory_usage()
+    assert usage.number_of_chunks == 1
+    assert usage.allocated_bytes >= ctypes.sizeof(LargePayload)
+    assert usage.data_segment_size >= usage.allocated_bytes
+
+    sample.delete()
+    usage = service.memory_usage()
+    assert usage.number_of_chunks == 0
+    assert usage.allocated_bytes == 0
+    assert usage.number_of_chunks_high_water_mark == 1
+    assert usage.allocated_bytes_high_water_mark >= ctypes.sizeof(
+        LargePayload
+    )
@@ -0,0 +1,788 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import time
+from datetime import timedelta
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+def byte_slice() -> iox2.TypeDetail:
+    return (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.Dynamic)
+        .type_name(iox2.TypeName.new("u8"))
+        .size(1)
+        .alignment(1)
+    )
+
+
+def create_service(
+    node: iox2.Node, multi_response: bool = True, fire_and_forget: bool = True
+) -> iox2.PortFactoryRequestResponse:
+    service_name = iox2.testing.generate_service_name()
+    return (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .enable_multi_response(multi_response)
+        .enable_fire_and_forget_requests(fire_and_forget)
+        .create()
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_is_received_by_server(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    assert not server.has_requests()
+    _pending_response = client.send_copy(b"hello")
+    assert server.has_requests()
+
+    active_request = server.receive()
+    assert active_request is not None
+    assert active_request.payload() == b"hello"
+    assert active_request.origin == client.id
+    assert server.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_client_with_exhausted_credits_experiences_back_pressure(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .enable_credit_flow_control(1)
+        .create()
+    )
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+    assert client.available_credits == 1
+
+    _pending_response = client.send_copy(b"hello")
+    assert client.available_credits == 0
+    with pytest.raises(iox2.SendError):
+        client.send_copy(b"flood")
+
+    active_request = server.receive()
+    assert active_request is not None
+    active_request.send_copy(b"world")
+
+    assert client.available_credits == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_response_is_received_by_client(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"request")
+    active_request = server.receive()
+
+    assert not pending_response.has_response()
+    active_request.send_copy(b"response")
+    assert pending_response.has_response()
+
+    response = pending_response.receive()
+    assert response is not None
+    assert response.payload() == b"response"
+    assert pending_response.receive() is None
+
+    active_request.delete()
+    assert not pending_response.is_connected
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_server_can_stream_multiple_responses(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, multi_response=True)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"stream")
+    active_request = server.receive()
+
+    active_request.send_copy(b"first")
+    active_request.send_copy(b"second")
+    assert pending_response.is_connected
+
+    responses = []
+    response = pending_response.receive()
+    while response is not None:
+        responses.append(response.payload())
+        response = pending_response.receive()
+    assert responses == [b"first", b"second"]
+
+    active_request.finish()
+    assert not pending_response.is_connected
+    assert pending_response.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_receive_next_returns_responses_until_stream_is_finished(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, multi_response=True)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"stream")
+    active_request = server.receive()
+
+    for payload in [b"one", b"two", b"three"]:
+        active_request.send_copy(payload)
+    active_request.finish()
+
+    responses = []
+    response = pending_response.receive_next()
+    while response is not None:
+        responses.append(response.payload())
+        response = pending_response.receive_next()
+
+    assert responses == [b"one", b"two", b"three"]
+    assert not pending_response.is_connected
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_responses_identify_the_server_that_sent_them(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server_1 = service.server_builder().initial_max_slice_len(16).create()
+    server_2 = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+    assert server_1.id != server_2.id
+
+    pending_response = client.send_copy(b"who")
+    for server in [server_1, server_2]:
+        active_request = server.receive()
+        assert active_request is not None
+        active_request.send_copy(b"me")
+
+    origins = []
+    response = pending_response.receive()
+    while response is not None:
+        origins.append(response.origin)
+        response = pending_response.receive()
+
+    assert len(origins) == 2
+    assert server_1.id in origins
+    assert server_2.id in origins
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_and_response_carry_send_timestamps(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+    assert pending_response.timestamp_ns > 0
+    assert pending_response.round_trip_latency_ns() is None
+
+    active_request = server.receive()
+    assert active_request.timestamp_ns == pending_response.timestamp_ns
+    active_request.send_copy(b"pong")
+
+    response = pending_response.receive()
+    assert response is not None
+    assert response.timestamp_ns >= pending_response.timestamp_ns
+    assert (
+        pending_response.round_trip_latency_ns()
+        == response.timestamp_ns - pending_response.timestamp_ns
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_missed_response_deadline_calls_deadline_miss_handler_once(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .with_response_deadline(timedelta(milliseconds=10))
+        .create()
+    )
+
+    _server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+    missed_deadlines = []
+    pending_response.on_deadline_miss(lambda: missed_deadlines.append(1))
+
+    assert pending_response.response_deadline == iox2.Duration.from_millis(10)
+    assert pending_response.time_until_deadline() is not None
+    assert not pending_response.has_missed_deadline()
+
+    time.sleep(0.02)
+
+    assert pending_response.time_until_deadline() == iox2.Duration.from_secs(0)
+    assert pending_response.receive() is None
+    assert pending_response.has_missed_deadline()
+    assert len(missed_deadlines) == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_response_within_deadline_does_not_call_deadline_miss_handler(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .with_response_deadline(timedelta(seconds=10))
+        .create()
+    )
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+    missed_deadlines = []
+    pending_response.on_deadline_miss(lambda: missed_deadlines.append(1))
+
+    server.receive().send_copy(b"pong")
+
+    assert pending_response.receive() is not None
+    assert pending_response.time_until_deadline() is None
+    assert not pending_response.has_missed_deadline()
+    assert len(missed_deadlines) == 0
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_waitset_calls_deadline_miss_handler_when_attached_response_deadline_fires(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .with_response_deadline(timedelta(milliseconds=10))
+        .create()
+    )
+
+    _server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+    waitset = iox2.WaitSetBuilder.new().create(service_type)
+
+    pending_response = client.send_copy(b"ping")
+    missed_deadlines = []
+    pending_response.on_deadline_miss(lambda: missed_deadlines.append(1))
+    guard = waitset.attach_response_deadline(pending_response)
+
+    (attachment_ids, _) = waitset.wait_and_process()
+
+    assert len(attachment_ids) == 1
+    assert attachment_ids[0].has_event_from(guard)
+    assert len(missed_deadlines) == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_attaching_response_deadline_fails_without_response_deadline(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    client = service.client_builder().initial_max_slice_len(16).create()
+    waitset = iox2.WaitSetBuilder.new().create(service_type)
+
+    pending_response = client.send_copy(b"ping")
+
+    with pytest.raises(iox2.WaitSetAttachmentError):
+        waitset.attach_response_deadline(pending_response)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_pending_response_without_response_deadline_never_misses_it(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    _server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+
+    assert pending_response.response_deadline is None
+    assert pending_response.time_until_deadline() is None
+    assert not pending_response.has_missed_deadline()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_server_can_send_only_one_response_without_multi_response(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, multi_response=False)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"request")
+    active_request = server.receive()
+
+    active_request.send_copy(b"response")
+    assert not active_request.is_connected
+    assert not pending_response.is_connected
+
+    with pytest.raises(iox2.SendError, match="MultiResponseDisabled"):
+        active_request.send_copy(b"another")
+
+    response = pending_response.receive()
+    assert response is not None
+    assert response.payload() == b"response"
+    assert pending_response.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_cancelled_pending_response_disconnects_active_request(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"request")
+    active_request = server.receive()
+    assert active_request.is_connected
+
+    client.cancel_pending_response(pending_response)
+    assert not active_request.is_connected
+
+    active_request.send_copy(b"discarded")
+    client.cancel_pending_response(pending_response)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_cancelled_request_is_not_received_without_fire_and_forget(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, fire_and_forget=False)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"request")
+    client.cancel_pending_response(pending_response)
+
+    assert server.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_rejected_request_delivers_error_response(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"request")
+    active_request = server.receive()
+    active_request.send_copy(b"partial")
+    server.reject_request(active_request, 42)
+    assert not pending_response.is_connected
+
+    response = pending_response.receive()
+    assert not response.is_error
+    assert response.error_code == 0
+    assert response.payload() == b"partial"
+
+    response = pending_response.receive()
+    assert response.is_error
+    assert response.error_code == 42
+    assert response.payload() == b""
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_rejected_request_of_fixed_size_type_delivers_error_response(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    u64_type = (
+        iox2.TypeDetail.new()
+        .type_variant(iox2.TypeVariant.FixedSize)
+        .type_name(iox2.TypeName.new("u64"))
+        .size(8)
+        .alignment(8)
+    )
+    service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(u64_type)
+        .response_payload_type_details(u64_type)
+        .create()
+    )
+
+    server = service.server_builder().create()
+    client = service.client_builder().create()
+
+    pending_response = client.send_copy(bytes(8))
+    server.reject_request(server.receive(), 7)
+
+    response = pending_response.receive()
+    assert response.is_error
+    assert response.error_code == 7
+    assert response.payload() == bytes(8)
+
+
+def create_service_with_user_headers(
+    node: iox2.Node,
+) -> iox2.PortFactoryRequestResponse:
+    service_name = iox2.testing.generate_service_name()
+    return (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .with_request_header_size(8, 8)
+        .with_response_header_size(4, 4)
+        .create()
+    )
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_user_headers_are_delivered_in_both_directions(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service_with_user_headers(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    request_header = (0x1122334455667788).to_bytes(8, "little")
+    pending_response = client.send_copy(b"ping", request_header)
+    active_request = server.receive()
+    assert active_request.user_header() == request_header
+
+    response_header = (0xAABBCCDD).to_bytes(4, "little")
+    active_request.send_copy(b"pong", user_header=response_header)
+    response = pending_response.receive()
+    assert response.user_header() == response_header
+    assert response.payload() == b"pong"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_user_header_is_zeroed_when_not_provided(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service_with_user_headers(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+    active_request = server.receive()
+    assert active_request.user_header() == bytes(8)
+
+    active_request.send_copy(b"pong")
+    assert pending_response.receive().user_header() == bytes(4)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_user_header_with_wrong_size_is_rejected(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service_with_user_headers(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    with pytest.raises(ValueError):
+        client.send_copy(b"ping", bytes(4))
+
+    _pending_response = client.send_copy(b"ping", bytes(8))
+    active_request = server.receive()
+
+    with pytest.raises(ValueError):
+        active_request.send_copy(b"pong", bytes(8))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_user_header_is_empty_without_header_type(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    pending_response = client.send_copy(b"ping")
+    active_request = server.receive()
+    assert active_request.user_header() == b""
+
+    active_request.send_copy(b"pong")
+    assert pending_response.receive().user_header() == b""
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_fire_and_forget_request_is_received_by_server(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, fire_and_forget=True)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    assert client.fire_and_forget(b"fire") is None
+    assert server.has_requests()
+
+    active_request = server.receive()
+    assert active_request is not None
+    assert active_request.payload() == b"fire"
+    assert not active_request.is_connected
+    assert server.receive() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_fire_and_forget_fails_when_service_does_not_support_it(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node, fire_and_forget=False)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    with pytest.raises(TypeError):
+        client.fire_and_forget(b"fire")
+    assert not server.has_requests()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_payload_larger_than_max_payload_size_is_rejected(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(byte_slice())
+        .response_payload_type_details(byte_slice())
+        .max_request_payload_size(4)
+        .max_response_payload_size(2)
+        .create()
+    )
+    assert service.static_config.max_request_payload_size == 4
+    assert service.static_config.max_response_payload_size == 2
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    with pytest.raises(iox2.LoanError):
+        client.send_copy(b"hello")
+
+    _pending_response = client.send_copy(b"hell")
+    active_request = server.receive()
+
+    with pytest.raises(iox2.LoanError):
+        active_request.send_copy(b"abc")
+    active_request.send_copy(b"ab")
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_payload_that_does_not_fit_the_type_is_rejected(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .request_response()
+        .request_payload_type_details(
+            iox2.TypeDetail.new()
+            .type_variant(iox2.TypeVariant.FixedSize)
+            .type_name(iox2.TypeName.new("u64"))
+            .size(8)
+            .alignment(8)
+        )
+        .create()
+    )
+
+    client = service.client_builder().create()
+
+    with pytest.raises(ValueError):
+        client.send_copy(b"abc")
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_dynamic_config_snapshot_contains_all_ports(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    sut = service.dynamic_config()
+    assert sut.number_of_clients == 0
+    assert sut.number_of_servers == 0
+
+    server = service.server_builder().create()
+    client = service.client_builder().create()
+
+    assert sut.number_of_clients == 0
+    sut = service.dynamic_config()
+    assert sut.number_of_clients == 1
+    assert sut.number_of_servers == 1
+    assert sut.client_ids == [client.id]
+    assert sut.server_ids == [server.id]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_iterator_yields_all_pending_requests_in_order(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    assert list(server.request_iterator()) == []
+
+    pending_responses = [
+        client.send_copy(payload) for payload in [b"one", b"two", b"three"]
+    ]
+    payloads = [request.payload() for request in server.request_iterator()]
+
+    assert payloads == [b"one", b"two", b"three"]
+    assert len(pending_responses) == 3
+    assert not server.has_requests()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_request_iterator_fails_when_server_is_dropped(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+    _pending_response = client.send_copy(b"hello")
+
+    sut = server.request_iterator()
+    del server
+
+    with pytest.raises(iox2.ReceiveError):
+        next(sut)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_memory_footprint_contains_data_segments_of_all_ports(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = create_service(node)
+
+    sut = service.memory_footprint()
+    assert sut.request_pool_bytes == 0
+    assert sut.response_pool_bytes == 0
+    assert sut.metadata_bytes > 0
+    assert sut.total_bytes == sut.metadata_bytes
+
+    server = service.server_builder().initial_max_slice_len(16).create()
+    client = service.client_builder().initial_max_slice_len(16).create()
+
+    sut = service.memory_footprint()
+    assert sut.request_pool_bytes >= 16
+    assert sut.response_pool_bytes >= 16
+    assert sut.total_bytes == (
+        sut.request_pool_bytes + sut.response_pool_bytes + sut.metadata_bytes
+    )
+
+    del client
+    del server
+    sut = service.memory_footprint()
+    assert sut.request_pool_bytes == 0
+    assert sut.response_pool_bytes == 0
@@ -0,0 +1,75 @@
+# Copyright (c) 2025 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import pytest
+
+import iceoryx2 as iox2
+
+service_types = [iox2.ServiceType.Ipc, iox2.ServiceType.Local]
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_list_returns_all_services(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    service_names = []
+    services = []
+    for _ in range(0, 4):
+        service_name = iox2.testing.generate_service_name()
+        services.append(node.service_builder(service_name).event().create())
+        service_names.append(service_name)
+
+    sut = iox2.Service.list(config, service_type)
+
+    assert len(sut) == len(service_names)
+    for details in sut:
+        assert details.name() in service_names
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_list_with_attribute_verifier_returns_only_matching_services(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+
+    matching_service_names = []
+    services = []
+    for schema in ["v1", "v2", "v1", "v2"]:
+        service_name = iox2.testing.generate_service_name()
+        attribute_spec = iox2.AttributeSpecifier.new().define(
+            iox2.AttributeKey.new("schema"), iox2.AttributeValue.new(schema)
+        )
+        services.append(
+            node.service_builder(service_name)
+            .event()
+            .create_with_attributes(attribute_spec)
+        )
+        if schema == "v2":
+            matching_service_names.append(service_name)
+
+    attribute_verifier = iox2.AttributeVerifier.new().require(
+        iox2.AttributeKey.new("schema"), iox2.AttributeValue.new("v2")
+    )
+    sut = iox2.Service.list(config, service_type, attribute_verifier)
+
+    assert len(sut) == len(matching_service_names)
+    for details in sut:
+        assert details.name() in matching_service_names
+
+    attribute_verifier = iox2.AttributeVerifier.new().require_key(
+        iox2.AttributeKey.new("version")
+    )
+    assert len(iox2.Service.list(config, service_type, attribute_verifier)) == 0
@@ -50,3 +50,23 @@ def test_get_set_item_raises_exception_when_out_of_bounds() -> None:
 
     with pytest.raises(IndexError):
         sut[number_of_elements + 1] = ctypes.c_uint8(7)
+
+
+def test_empty_slice_has_no_elements_and_no_bytes() -> None:
+    sut = iox2.Slice[ctypes.c_uint64](0, 0, ctypes.c_uint64)
+
+    assert len(sut) == 0
+    assert bytes(sut) == b""
+
+    with pytest.raises(IndexError):
+        _unused = sut[0]
+
+
+def test_slice_bytes_returns_copy_of_contents() -> None:
+    data = (ctypes.c_uint16 * 3)(1, 2, 3)
+    sut = iox2.Slice[ctypes.c_uint16](
+        ctypes.addressof(data), len(data), ctypes.c_uint16
+    )
+
+    assert len(sut) == 3
+    assert bytes(sut) == bytes(data)
@@ -101,3 +101,304 @@ def test_deleting_sample_releases_it(
         sample = sut.receive()
     except iox2.ReceiveError:
         assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_payload_bytes_outlives_the_sample(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_borrowed_samples(1)
+        .create()
+    )
+
+    sut = service.subscriber_builder().create()
+    publisher = service.publisher_builder().create()
+    publisher.send_copy(Payload(data=7823))
+
+    sample = sut.receive()
+    payload = sample.payload_bytes()
+    sample.delete()
+
+    assert isinstance(payload, bytes)
+    assert payload == bytes(Payload(data=7823))
+    assert Payload.from_buffer_copy(payload).data == 7823
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_for_each_releases_every_sample_before_receiving_the_next(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(5)
+        .subscriber_max_borrowed_samples(1)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+
+    for i in range(3):
+        publisher.send_copy(Payload(data=20 + i))
+
+    received = []
+    number_of_samples = sut.for_each(
+        lambda sample: received.append(sample.payload().contents.data)
+    )
+
+    assert number_of_samples == 3
+    assert received == [20, 21, 22]
+    assert sut.for_each(lambda sample: None) == 0
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_for_each_stops_after_max_iterations(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(5)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+
+    for i in range(4):
+        publisher.send_copy(Payload(data=40 + i))
+
+    received = []
+    number_of_samples = sut.for_each(
+        lambda sample: received.append(sample.payload().contents.data),
+        max_iterations=3,
+    )
+
+    assert number_of_samples == 3
+    assert received == [40, 41, 42]
+    assert sut.receive().payload().contents.data == 43
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_mark_received_records_acknowledged_sequence_number(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(5)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+
+    assert publisher.acknowledged_sequence_number(sut.id) is None
+
+    for i in range(3):
+        publisher.send_copy(Payload(data=i))
+
+    sample = sut.receive()
+    assert sample.header.sequence_number == 0
+    sample.delete()
+
+    sample = sut.receive()
+    sut.mark_received(sample)
+    assert sample.header.sequence_number == 1
+    sample.delete()
+
+    assert publisher.acknowledged_sequence_number(sut.id) == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_peek_returns_none_when_no_sample_is_queued(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    sut = service.subscriber_builder().create()
+
+    assert sut.peek() is None
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_peek_does_not_consume_the_sample(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(5)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=1))
+    publisher.send_copy(Payload(data=2))
+
+    header = sut.peek()
+    assert header is not None
+    assert header.publisher_id == publisher.id
+    assert header.number_of_elements == 1
+    assert header.sequence_number == 0
+    assert sut.peek() == header
+
+    sample = sut.receive()
+    assert sample.header == header
+    assert sample.payload().contents.data == 1
+    sample.delete()
+
+    assert sut.peek().sequence_number == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_mark_received_fails_for_samples_of_other_subscribers(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    publisher = service.publisher_builder().create()
+    sut = service.subscriber_builder().create()
+    other_subscriber = service.subscriber_builder().create()
+
+    publisher.send_copy(Payload(data=1))
+    sample = other_subscriber.receive()
+
+    with pytest.raises(iox2.SubscriberMarkReceivedError):
+        sut.mark_received(sample)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscriber_is_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .max_subscribers(1)
+        .create()
+    )
+
+    with service.subscriber_builder().create() as sut:
+        with pytest.raises(iox2.SubscriberCreateError):
+            service.subscriber_builder().create()
+
+    sut.__exit__(None, None, None)
+
+    try:
+        sut = service.subscriber_builder().create()
+    except iox2.SubscriberCreateError:
+        assert False
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_held_samples_are_released_when_leaving_with_block(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name).publish_subscribe(Payload).create()
+    )
+
+    publisher = service.publisher_builder().create()
+
+    with service.subscriber_builder().create() as sut:
+        publisher.send_copy(Payload(data=12))
+        sample = sut.receive()
+        assert sample.payload().contents.data == 12
+
+    with pytest.raises(BaseException):
+        sample.payload_bytes()
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_subscribers_of_one_factory_can_have_distinct_buffer_sizes(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .enable_safe_overflow(True)
+        .subscriber_max_buffer_size(16)
+        .create()
+    )
+
+    publisher = service.publisher_builder().create()
+    factory = service.subscriber_builder()
+    small_sut = factory.buffer_size(4).create()
+    large_sut = factory.buffer_size(16).create()
+
+    assert small_sut.buffer_size == 4
+    assert large_sut.buffer_size == 16
+
+    for i in range(16):
+        publisher.send_copy(Payload(data=i))
+
+    received = []
+    small_sut.for_each(
+        lambda sample: received.append(sample.payload().contents.data)
+    )
+    assert received == list(range(12, 16))
+
+    received = []
+    large_sut.for_each(
+        lambda sample: received.append(sample.payload().contents.data)
+    )
+    assert received == list(range(16))
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_create_fails_when_buffer_size_exceeds_max_buffer_size_of_service(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = (
+        node.service_builder(service_name)
+        .publish_subscribe(Payload)
+        .subscriber_max_buffer_size(4)
+        .create()
+    )
+
+    with pytest.raises(iox2.SubscriberCreateError):
+        service.subscriber_builder().buffer_size(5).create()
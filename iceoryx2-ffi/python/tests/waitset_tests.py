@@ -10,6 +10,9 @@
 #
 # SPDX-License-Identifier: Apache-2.0 OR MIT
 
+import threading
+import time
+
 import pytest
 
 import iceoryx2 as iox2
@@ -181,6 +184,39 @@ def test_wait_and_process_returns_triggered_listeners(
             assert not triggers[i].has_event_from(waitset_guards[k])
 
 
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_returns_triggers_in_priority_order(
+    service_type: iox2.ServiceType,
+) -> None:
+    priorities = [3, 0, 42, 7]
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    listeners = []
+    notifiers = []
+    for _ in priorities:
+        service_name = iox2.testing.generate_service_name()
+        service = node.service_builder(service_name).event().create()
+        listeners.append(service.listener_builder().create())
+        notifiers.append(service.notifier_builder().create())
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    waitset_guards = []
+    for listener, priority in zip(listeners, priorities):
+        waitset_guards.append(sut.attach_notification(listener, priority))
+
+    for notifier in notifiers:
+        notifier.notify()
+
+    (triggers, result) = sut.wait_and_process()
+    assert len(triggers) == len(priorities)
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+    assert triggers[0].has_event_from(waitset_guards[2])
+    assert triggers[1].has_event_from(waitset_guards[3])
+    assert triggers[2].has_event_from(waitset_guards[0])
+    assert triggers[3].has_event_from(waitset_guards[1])
+
+
 @pytest.mark.parametrize("service_type", service_types)
 def test_reports_missed_deadline(
     service_type: iox2.ServiceType,
@@ -253,3 +289,208 @@ def test_deleting_guard_explicitly_removes_attachment(
 
     assert sut.len == 0
     assert sut.is_empty
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_guard_notifier_wakes_up_waitset_from_another_thread(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service_name = iox2.testing.generate_service_name()
+    service = node.service_builder(service_name).event().create()
+    notifier = service.notifier_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.add_notifier_as_guard(notifier)
+    assert sut.len == 1
+
+    thread = threading.Timer(0.05, notifier.notify)
+    thread.start()
+    (triggers, result) = sut.wait_and_process()
+    thread.join()
+
+    assert result == iox2.WaitSetRunResult.GuardWoken
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(guard)
+
+    (triggers, result) = sut.wait_and_process_with_timeout(
+        iox2.Duration.from_millis(1)
+    )
+    assert len(triggers) == 0
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_guard_notifier_is_distinguishable_from_data_notifications(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    guard_service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .event()
+        .create()
+    )
+    data_service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .event()
+        .create()
+    )
+    guard_notifier = guard_service.notifier_builder().create()
+    data_notifier = data_service.notifier_builder().create()
+    data_listener = data_service.listener_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    guard = sut.add_notifier_as_guard(guard_notifier)
+    data_guard = sut.attach_notification(data_listener)
+
+    data_notifier.notify()
+    (triggers, result) = sut.wait_and_process()
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+    assert len(triggers) == 1
+    assert triggers[0].has_event_from(data_guard)
+    assert not triggers[0].has_event_from(guard)
+    data_listener.try_wait_all()
+
+    guard.delete()
+    assert sut.len == 1
+    guard_notifier.notify()
+    (triggers, result) = sut.wait_and_process_with_timeout(
+        iox2.Duration.from_millis(1)
+    )
+    assert len(triggers) == 0
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+
+
+def test_callback_progression_matches_rust_api() -> None:
+    assert iox2.CallbackProgression.Stop != iox2.CallbackProgression.Continue
+    assert str(iox2.CallbackProgression.Stop) == "Stop"
+    assert str(iox2.CallbackProgression.Continue) == "Continue"
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_once_calls_callback_for_every_event(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .event()
+        .create()
+    )
+    listener = service.listener_builder().create()
+    notifier = service.notifier_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    listener_guard = sut.attach_notification(listener)
+    _interval_guard = sut.attach_interval(iox2.Duration.from_millis(1))
+
+    triggers = []
+
+    def on_event(
+        attachment_id: iox2.WaitSetAttachmentId,
+    ) -> iox2.CallbackProgression:
+        triggers.append(attachment_id)
+        return iox2.CallbackProgression.Continue
+
+    notifier.notify()
+    result = sut.wait_and_process_once(on_event)
+    assert result == iox2.WaitSetRunResult.AllEventsHandled
+    assert len(triggers) >= 1
+    assert any(t.has_event_from(listener_guard) for t in triggers)
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_once_stops_when_callback_returns_stop(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    _guard_1 = sut.attach_interval(iox2.Duration.from_millis(1))
+    _guard_2 = sut.attach_interval(iox2.Duration.from_millis(1))
+
+    number_of_calls = 0
+
+    def on_event(
+        _attachment_id: iox2.WaitSetAttachmentId,
+    ) -> iox2.CallbackProgression:
+        nonlocal number_of_calls
+        number_of_calls += 1
+        return iox2.CallbackProgression.Stop
+
+    time.sleep(0.01)
+    result = sut.wait_and_process_once_with_timeout(
+        on_event, iox2.Duration.from_millis(100)
+    )
+    assert result == iox2.WaitSetRunResult.StopRequest
+    assert number_of_calls == 1
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_with_callback_runs_until_stop(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    _guard = sut.attach_interval(iox2.Duration.from_millis(1))
+
+    number_of_calls = 0
+
+    def on_event(
+        _attachment_id: iox2.WaitSetAttachmentId,
+    ) -> iox2.CallbackProgression | None:
+        nonlocal number_of_calls
+        number_of_calls += 1
+        if number_of_calls == 3:
+            return iox2.CallbackProgression.Stop
+        # any other return value continues the loop
+        return None
+
+    result = sut.wait_and_process(on_event)
+    assert result == iox2.WaitSetRunResult.StopRequest
+    assert number_of_calls == 3
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_with_callback_returns_when_guard_is_used(
+    service_type: iox2.ServiceType,
+) -> None:
+    config = iox2.testing.generate_isolated_config()
+    node = iox2.NodeBuilder.new().config(config).create(service_type)
+    service = (
+        node.service_builder(iox2.testing.generate_service_name())
+        .event()
+        .create()
+    )
+    stop_token = service.notifier_builder().create()
+
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    _guard = sut.add_notifier_as_guard(stop_token)
+    _interval_guard = sut.attach_interval(iox2.Duration.from_millis(1))
+
+    def on_event(
+        _attachment_id: iox2.WaitSetAttachmentId,
+    ) -> iox2.CallbackProgression:
+        return iox2.CallbackProgression.Continue
+
+    thread = threading.Thread(target=stop_token.notify)
+    thread.start()
+    result = sut.wait_and_process(on_event)
+    thread.join()
+    assert result == iox2.WaitSetRunResult.GuardWoken
+
+
+@pytest.mark.parametrize("service_type", service_types)
+def test_wait_and_process_with_callback_forwards_exceptions(
+    service_type: iox2.ServiceType,
+) -> None:
+    sut = iox2.WaitSetBuilder.new().create(service_type)
+    _guard = sut.attach_interval(iox2.Duration.from_millis(1))
+
+    def on_event(
+        _attachment_id: iox2.WaitSetAttachmentId,
+    ) -> iox2.CallbackProgression:
+        raise RuntimeError("stop processing")
+
+    with pytest.raises(RuntimeError):
+        sut.wait_and_process(on_event)
@@ -43,6 +43,10 @@
 #include <unistd.h>
 #endif
 
+#ifdef __linux__
+#include <sys/syscall.h>
+#endif
+
 #include <errno.h>
 #include <fcntl.h>
 #include <signal.h>
@@ -147,6 +147,25 @@ pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
 
+pub unsafe fn madvise(_addr: *mut void, _len: size_t, _advice: int) -> int {
+    // transparent huge pages are not available on this platform
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn mbind(
+    _addr: *mut void,
+    _len: size_t,
+    _mode: int,
+    _nodemask: *const ulong,
+    _maxnode: ulong,
+    _flags: uint,
+) -> int {
+    // numa memory policies are not available on this platform
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
 unsafe fn trim_ascii(value: &[i8]) -> &[u8] {
     let length = value.iter().position(|&c| c == 0).unwrap_or(value.len());
     core::slice::from_raw_parts(value.as_ptr().cast(), length)
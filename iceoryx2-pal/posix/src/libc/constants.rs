@@ -55,6 +55,9 @@ pub const MCL_CURRENT: int = libc::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = libc::MCL_FUTURE as _;
 pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MADV_HUGEPAGE: int = libc::MADV_HUGEPAGE as _;
+pub const MPOL_BIND: int = 2;
+pub const MPOL_MF_MOVE: uint = 1 << 1;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = libc::PTHREAD_EXPLICIT_SCHED as _;
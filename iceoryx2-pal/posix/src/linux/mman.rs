@@ -94,3 +94,26 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
+
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    crate::internal::madvise(addr, len, advice)
+}
+
+pub unsafe fn mbind(
+    addr: *mut void,
+    len: size_t,
+    mode: int,
+    nodemask: *const ulong,
+    maxnode: ulong,
+    flags: uint,
+) -> int {
+    crate::internal::syscall(
+        crate::internal::SYS_mbind as _,
+        addr,
+        len,
+        mode,
+        nodemask,
+        maxnode,
+        flags,
+    ) as _
+}
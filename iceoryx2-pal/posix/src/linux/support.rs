@@ -22,3 +22,5 @@ pub const POSIX_SUPPORT_MESSAGE_QUEUE: bool = true;
 pub const POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING: bool = true;
 pub const POSIX_SUPPORT_CONSOLE_SIGNAL_HANDLING: bool = true;
 pub const POSIX_SUPPORT_SCHEDULER: bool = true;
+pub const POSIX_SUPPORT_HUGE_PAGES: bool = true;
+pub const POSIX_SUPPORT_NUMA: bool = true;
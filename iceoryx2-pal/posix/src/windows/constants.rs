@@ -51,6 +51,9 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_FAILED: *mut void = 0 as *mut void;
+pub const MADV_HUGEPAGE: int = 14;
+pub const MPOL_BIND: int = 2;
+pub const MPOL_MF_MOVE: uint = 1 << 1;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
 pub const PTHREAD_MUTEX_RECURSIVE: int = 2;
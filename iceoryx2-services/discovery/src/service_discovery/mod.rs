@@ -54,6 +54,33 @@
 //!
 //!     Ok(())
 //! }
+//! ```
+//!
+//! ## Announcements
+//!
+//! Instead of spinning periodically, the service can subscribe to the service change
+//! announcements of the iceoryx2 core. Every node publishes a `ServiceAdded` or
+//! `ServiceRemoved` record with the static configuration of the service when it creates or
+//! removes a service, `global.service.enable_discovery_announcements` is enabled in the
+//! iceoryx2 config and [`Config::listen_for_announcements`] is set. The
+//! [`Service::announcement_subscriber()`] can be attached to a `WaitSet` so that
+//! [`Service::spin()`] is only called when something changed.
+//!
+//! ## Limits and Stale Entries
+//!
+//! * When [`Config::max_subscribers`] is exceeded, further subscribers cannot be created. They
+//!   can still acquire the complete state from the request-response server.
+//! * When the buffer of a subscriber overflows, the oldest records are discarded. The subscriber
+//!   can resynchronize with the request-response server.
+//! * When more than [`Config::max_announcers`] nodes announce changes, the nodes that cannot
+//!   connect to the announcement service do not announce anything and their changes are
+//!   detected by the next spin.
+//! * When [`Config::max_announcement_subscribers`] is exceeded, creating a further service with
+//!   [`Config::listen_for_announcements`] fails.
+//! * Services of crashed creators are removed by the dead node cleanup of iceoryx2 without an
+//!   announcement, their `ServiceAdded` record becomes stale. Since [`Service::spin()`] compares
+//!   the tracked services with the services that exist, every service that no longer exists is
+//!   retired and published as [`Discovery::Removed`] with the next spin.
 
 /// A service discovery service that tracks and publishes information about services in the system.
 mod service;
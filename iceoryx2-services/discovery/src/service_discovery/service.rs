@@ -20,16 +20,18 @@ use iceoryx2::{
         notifier::{Notifier, NotifierCreateError, NotifierNotifyError},
         publisher::{Publisher, PublisherCreateError},
         server::Server,
+        subscriber::{Subscriber, SubscriberCreateError},
         LoanError, SendError,
     },
     prelude::ServiceName,
     service::{
+        __internal_discovery_announcement_service_name,
         builder::{
             event::EventOpenOrCreateError, publish_subscribe::PublishSubscribeOpenOrCreateError,
         },
         port_factory::request_response::PortFactory,
         static_config::StaticConfig,
-        Service as ServiceType, ServiceDetails,
+        Service as ServiceType, ServiceAnnouncement, ServiceDetails,
     },
 };
 
@@ -79,6 +81,10 @@ pub enum CreationError {
 
     /// A notifier to the service already exists.
     NotifierAlreadyExists,
+
+    /// Failed to create the subscriber for the discovery announcements, e.g. because the
+    /// maximum number of subscribers of the announcement service is exhausted.
+    AnnouncementSubscriberCreationFailure,
 }
 
 impl core::fmt::Display for CreationError {
@@ -127,6 +133,12 @@ impl From<NotifierCreateError> for CreationError {
     }
 }
 
+impl From<SubscriberCreateError> for CreationError {
+    fn from(_: SubscriberCreateError) -> Self {
+        CreationError::AnnouncementSubscriberCreationFailure
+    }
+}
+
 impl From<SyncError> for CreationError {
     fn from(error: SyncError) -> Self {
         match error {
@@ -151,6 +163,9 @@ pub enum SpinError {
     /// Failed to send a notification about service changes.
     NotifyFailure,
 
+    /// Failed to consume the received discovery announcements.
+    AnnouncementReceptionFailure,
+
     /// Server error while requesting, receiving or loaning services.
     ServerSpinError(ServerSpinError),
 }
@@ -272,6 +287,24 @@ pub struct Config {
 
     /// The initial maximum slice length for the server.
     pub initial_max_slice_len: usize,
+
+    /// Whether to subscribe to the service change announcements of the iceoryx2 core.
+    ///
+    /// Requires `global.service.enable_discovery_announcements` to be enabled in the iceoryx2
+    /// config of all participants. Announcements are hints that a change occurred, they can be
+    /// awaited via the [`Service::announcement_subscriber()`] instead of spinning periodically.
+    /// When an announcement is lost, e.g. because the maximum number of announcers was
+    /// exceeded, the change is detected by the next spin.
+    pub listen_for_announcements: bool,
+
+    /// The maximum number of nodes that can announce service changes. Every node that
+    /// announces a change holds one publisher of the announcement service.
+    pub max_announcers: usize,
+
+    /// The maximum number of subscribers of the announcement service. When it is exhausted,
+    /// creating a further service with [`Config::listen_for_announcements`] fails with
+    /// [`CreationError::AnnouncementSubscriberCreationFailure`].
+    pub max_announcement_subscribers: usize,
 }
 
 impl Default for Config {
@@ -289,6 +322,9 @@ impl Default for Config {
             max_listeners: defaults.event.max_listeners,
             enable_server: true,
             initial_max_slice_len: 10,
+            listen_for_announcements: false,
+            max_announcers: defaults.publish_subscribe.max_publishers,
+            max_announcement_subscribers: defaults.publish_subscribe.max_subscribers,
         }
     }
 }
@@ -311,6 +347,7 @@ pub struct Service<S: ServiceType> {
     request_response: Option<PortFactory<S, (), (), [StaticConfig], ()>>,
     server: Option<Server<S, (), (), [StaticConfig], ()>>,
     notifier: Option<Notifier<S>>,
+    announcement_subscriber: Option<Subscriber<S, ServiceAnnouncement, ()>>,
     tracker: Tracker<S>,
 }
 
@@ -362,6 +399,19 @@ impl<S: ServiceType> Service<S> {
             notifier = Some(port);
         }
 
+        let mut announcement_subscriber = None;
+        if discovery_config.listen_for_announcements {
+            let publish_subscribe = node
+                .service_builder(&__internal_discovery_announcement_service_name())
+                .publish_subscribe::<ServiceAnnouncement>()
+                .max_publishers(discovery_config.max_announcers)
+                .max_subscribers(discovery_config.max_announcement_subscribers)
+                .enable_notify_on_delivery(true)
+                .open_or_create()?;
+
+            announcement_subscriber = Some(publish_subscribe.subscriber_builder().create()?);
+        }
+
         let mut tracker = Tracker::<S>::new();
 
         if discovery_config.sync_on_initialization {
@@ -396,6 +446,7 @@ impl<S: ServiceType> Service<S> {
             request_response,
             server,
             notifier,
+            announcement_subscriber,
             tracker,
         })
     }
@@ -427,6 +478,15 @@ impl<S: ServiceType> Service<S> {
         mut on_added: FAddedService,
         mut on_removed: FRemovedService,
     ) -> Result<(), SpinError> {
+        // Consume announcements, the sync below covers all announced changes
+        if let Some(subscriber) = &self.announcement_subscriber {
+            while subscriber
+                .receive()
+                .map_err(|_| SpinError::AnnouncementReceptionFailure)?
+                .is_some()
+            {}
+        }
+
         // Detect changes
         let (added_ids, removed_services) = self.tracker.sync(&self.iceoryx_config)?;
         let changes_detected = !added_ids.is_empty() || !removed_services.is_empty();
@@ -476,6 +536,16 @@ impl<S: ServiceType> Service<S> {
         Ok(())
     }
 
+    /// Returns the subscriber that receives a [`ServiceAnnouncement`] whenever a service is
+    /// created or removed.
+    ///
+    /// Only available when [`Config::listen_for_announcements`] is enabled. It can be attached
+    /// to a [`WaitSet`](iceoryx2::waitset::WaitSet) to call [`Service::spin()`] only when the
+    /// service landscape changed. [`Service::spin()`] consumes all received announcements.
+    pub fn announcement_subscriber(&self) -> Option<&Subscriber<S, ServiceAnnouncement, ()>> {
+        self.announcement_subscriber.as_ref()
+    }
+
     /// Returns the service details of all the current services.
     ///
     /// This function is called within the spin function, so that
@@ -14,12 +14,13 @@ mod service_discovery_service {
 
     use iceoryx2::prelude::*;
     use iceoryx2::service::static_config::StaticConfig;
+    use iceoryx2::service::ServiceAnnouncement;
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::test_fail;
     use iceoryx2_services_discovery::service_discovery::{
-        service_name, Config, Discovery, Payload, Service,
+        service_name, Config, CreationError, Discovery, Payload, Service,
     };
 
     fn generate_name() -> ServiceName {
@@ -152,6 +153,126 @@ mod service_discovery_service {
         assert_that!(result, is_some);
     }
 
+    #[test]
+    fn announcement_subscriber_receives_records_when_services_are_added_or_removed() {
+        let mut iceoryx_config = generate_isolated_config();
+        iceoryx_config.global.service.enable_discovery_announcements = true;
+
+        let discovery_config = Config {
+            include_internal: false,
+            publish_events: false,
+            send_notifications: false,
+            listen_for_announcements: true,
+            ..Default::default()
+        };
+        let mut sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+        sut.spin(|_| {}, |_| {}).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        // add a service
+        let service_name = generate_name();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let result = sut.announcement_subscriber().unwrap().receive();
+        assert_that!(result, is_ok);
+        let sample = result.unwrap().unwrap();
+        assert_that!(matches!(*sample, ServiceAnnouncement::ServiceAdded(_)), eq true);
+        assert_that!(*sample.static_config().name(), eq service_name);
+        assert_that!(sample.static_config().service_id(), eq service.service_id());
+        drop(sample);
+
+        let mut added = vec![];
+        sut.spin(|s| added.push(s.static_details.name().clone()), |_| {})
+            .unwrap();
+        assert_that!(added, eq vec![service_name.clone()]);
+
+        // remove the service
+        drop(service);
+
+        // spin consumes the announcement and retires the removed service
+        let mut removed = vec![];
+        sut.spin(|_| {}, |s| removed.push(s.static_details.name().clone()))
+            .unwrap();
+        assert_that!(removed, eq vec![service_name]);
+        assert_that!(
+            sut.announcement_subscriber().unwrap().receive().unwrap(),
+            is_none
+        );
+    }
+
+    #[test]
+    fn service_changes_are_not_announced_when_disabled_in_config() {
+        let iceoryx_config = generate_isolated_config();
+
+        let discovery_config = Config {
+            publish_events: false,
+            send_notifications: false,
+            enable_server: false,
+            listen_for_announcements: true,
+            ..Default::default()
+        };
+        let sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let _service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let result = sut.announcement_subscriber().unwrap().receive();
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_none);
+    }
+
+    #[test]
+    fn creation_fails_when_announcement_subscribers_are_exhausted() {
+        let mut iceoryx_config = generate_isolated_config();
+        iceoryx_config.global.service.enable_discovery_announcements = true;
+
+        let discovery_config = Config {
+            publish_events: false,
+            send_notifications: false,
+            enable_server: false,
+            listen_for_announcements: true,
+            max_announcement_subscribers: 1,
+            ..Default::default()
+        };
+        let sut = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config).unwrap();
+
+        let result = Service::<ipc::Service>::create(&discovery_config, &iceoryx_config);
+        assert_that!(result.err(), eq Some(CreationError::AnnouncementSubscriberCreationFailure));
+
+        // the existing subscriber still receives announcements
+        let node = NodeBuilder::new()
+            .config(&iceoryx_config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let service_name = generate_name();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sample = sut.announcement_subscriber().unwrap().receive().unwrap();
+        assert_that!(sample, is_some);
+        let sample = sample.unwrap();
+        assert_that!(*sample.static_config().name(), eq service_name);
+    }
+
     #[test]
     fn monitors_internal_services_when_configured() {
         let iceoryx_config = generate_isolated_config();
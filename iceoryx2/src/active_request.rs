@@ -47,7 +47,7 @@ use core::{
     sync::atomic::Ordering,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -58,7 +58,7 @@ use iceoryx2_cal::{
 
 use crate::{
     port::{
-        details::chunk_details::ChunkDetails,
+        details::{chunk_details::ChunkDetails, padded_payload::PaddedPayload},
         port_identifiers::{UniqueClientId, UniqueServerId},
         server::{SharedServerState, INVALID_CONNECTION_ID},
         LoanError, SendError,
@@ -98,6 +98,11 @@ pub struct ActiveRequest<
     pub(crate) request_id: u64,
     pub(crate) channel_id: ChannelId,
     pub(crate) connection_id: usize,
+    pub(crate) sent_single_response: Option<Arc<IoxAtomicBool>>,
+    pub(crate) returned_credit: Option<Arc<IoxAtomicBool>>,
+    // owns the zero padded copy of the payload `ptr` refers to, when the sender used an older,
+    // smaller version of an evolved payload type
+    pub(crate) _padded_payload: Option<PaddedPayload>,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
 }
@@ -169,7 +174,7 @@ impl<
             .lock()
             .request_receiver
             .release_offset(&self.details, ChannelId::new(0));
-        self.finish();
+        self.close();
     }
 }
 
@@ -181,12 +186,15 @@ impl<
         ResponseHeader: Debug + ZeroCopySend,
     > ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
 {
-    fn finish(&self) {
+    fn close(&self) {
         if self.connection_id != INVALID_CONNECTION_ID {
-            self.shared_state
-                .lock()
-                .response_sender
-                .invalidate_channel_state(self.channel_id, self.connection_id, self.request_id);
+            let shared_state = self.shared_state.lock();
+            shared_state.acknowledge_request(&self.returned_credit, self.connection_id);
+            shared_state.response_sender.invalidate_channel_state(
+                self.channel_id,
+                self.connection_id,
+                self.request_id,
+            );
         }
     }
 
@@ -229,6 +237,12 @@ impl<
         UniqueClientId(UniqueSystemId::from(self.details.origin))
     }
 
+    /// Signals the corresponding [`PendingResponse`](crate::pending_response::PendingResponse)
+    /// that the stream of [`Response`](crate::response::Response)s has ended and no further
+    /// [`Response`](crate::response::Response) will be sent. It is equivalent to letting the
+    /// [`ActiveRequest`] go out of scope.
+    pub fn finish(self) {}
+
     fn increment_loan_counter(&self) -> Result<(), LoanError> {
         let mut current_loan_count = self.shared_loan_counter.load(Ordering::Relaxed);
         loop {
@@ -307,6 +321,10 @@ impl<
                     )),
                     request_id: self.request_id,
                     number_of_elements: 1,
+                    payload_size: shared_state.response_sender.payload_size() as _,
+                    error_code: 0,
+                    timestamp_ns: 0,
+                    is_error: false,
                 },
             )
         };
@@ -332,6 +350,8 @@ impl<
                 channel_id: self.channel_id,
                 connection_id: self.connection_id,
                 sample_size: chunk.size,
+                sent_single_response: self.sent_single_response.clone(),
+                returned_credit: self.returned_credit.clone(),
                 _response_payload: PhantomData,
                 _response_header: PhantomData,
             },
@@ -415,6 +435,45 @@ impl<
             .loan_uninit()?
             .write_payload(ResponsePayload::default()))
     }
+
+    /// Rejects the request by sending an error response with the user defined `error_code`
+    /// to the [`PendingResponse`](crate::pending_response::PendingResponse). The payload of
+    /// the error response is default initialized. Since the [`ActiveRequest`] is consumed,
+    /// no further responses can be sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node
+    /// #     .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    /// # let server = service.server_builder().create()?;
+    /// #
+    /// # let pending_response = client.send_copy(123)?;
+    ///
+    /// let active_request = server.receive()?.unwrap();
+    /// active_request.reject(42)?;
+    ///
+    /// let response = pending_response.receive()?.unwrap();
+    /// assert!(response.header().is_error());
+    /// assert_eq!(response.header().error_code(), Some(42));
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reject(self, error_code: u64) -> Result<(), SendError> {
+        let msg = "Unable to reject request";
+        let mut response = fail!(from self, when self.loan(),
+                                "{} since the loan of the error response failed.", msg);
+        response.mark_as_error(error_code);
+        response.send()
+    }
 }
 ////////////////////////
 // END: typed API
@@ -525,6 +584,47 @@ impl<
         unsafe { self.loan_slice_uninit_impl(slice_len, slice_len) }
     }
 
+    /// Rejects the request by sending an error response with the user defined `error_code`
+    /// to the [`PendingResponse`](crate::pending_response::PendingResponse). The payload of
+    /// the error response is an empty slice. Since the [`ActiveRequest`] is consumed,
+    /// no further responses can be sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node
+    /// #     .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, [usize]>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    /// # let server = service.server_builder().create()?;
+    /// #
+    /// # let pending_response = client.send_copy(123)?;
+    ///
+    /// let active_request = server.receive()?.unwrap();
+    /// active_request.reject(42)?;
+    ///
+    /// let response = pending_response.receive()?.unwrap();
+    /// assert_eq!(response.header().error_code(), Some(42));
+    /// assert!(response.payload().is_empty());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reject(self, error_code: u64) -> Result<(), SendError> {
+        debug_assert!(TypeId::of::<ResponsePayload>() != TypeId::of::<CustomPayloadMarker>());
+        let msg = "Unable to reject request";
+        let response = fail!(from self, when unsafe { self.loan_slice_uninit_impl(0, 0) },
+                                "{} since the loan of the error response failed.", msg);
+        let mut response = unsafe { response.assume_init() };
+        response.mark_as_error(error_code);
+        response.send()
+    }
+
     unsafe fn loan_slice_uninit_impl(
         &self,
         slice_len: usize,
@@ -534,6 +634,20 @@ impl<
         let shared_state = self.shared_state.lock();
         let max_slice_len = shared_state.config.initial_max_slice_len;
 
+        if let Some(max_payload_size) = shared_state
+            .response_sender
+            .service_state
+            .static_config
+            .request_response()
+            .max_response_payload_size
+        {
+            if max_payload_size < slice_len {
+                fail!(from self, with LoanError::PayloadTooLarge,
+                    "Unable to loan slice with {} elements since it would exceed the max response payload size of {} of the service.",
+                    slice_len, max_payload_size);
+            }
+        }
+
         if shared_state.config.allocation_strategy == AllocationStrategy::Static
             && max_slice_len < slice_len
         {
@@ -546,6 +660,7 @@ impl<
 
         let response_layout = shared_state.response_sender.sample_layout(slice_len);
         let chunk = shared_state.response_sender.allocate(response_layout)?;
+        shared_state.update_data_segment_size();
 
         unsafe {
             (chunk.header as *mut service::header::request_response::ResponseHeader).write(
@@ -555,6 +670,10 @@ impl<
                     )),
                     request_id: self.request_id,
                     number_of_elements: slice_len as _,
+                    payload_size: shared_state.response_sender.payload_size() as _,
+                    error_code: 0,
+                    timestamp_ns: 0,
+                    is_error: false,
                 },
             )
         };
@@ -583,6 +702,8 @@ impl<
                 channel_id: self.channel_id,
                 connection_id: self.connection_id,
                 sample_size: chunk.size,
+                sent_single_response: self.sent_single_response.clone(),
+                returned_credit: self.returned_credit.clone(),
                 _response_payload: PhantomData,
                 _response_header: PhantomData,
             },
@@ -623,6 +744,29 @@ impl<
             shared_state.response_sender.payload_size() * slice_len,
         )
     }
+
+    #[doc(hidden)]
+    pub unsafe fn reject_custom_payload(self, error_code: u64) -> Result<(), SendError> {
+        let number_of_elements = match self
+            .shared_state
+            .lock()
+            .response_sender
+            .payload_type_variant()
+        {
+            TypeVariant::FixedSize => 1,
+            TypeVariant::Dynamic => 0,
+        };
+
+        let msg = "Unable to reject request";
+        let mut response = fail!(from self, when self.loan_custom_payload(number_of_elements),
+                                "{} since the loan of the error response failed.", msg);
+        let payload = response.payload_mut();
+        core::ptr::write_bytes(payload.as_mut_ptr(), 0, payload.len());
+
+        let mut response = response.assume_init();
+        response.mark_as_error(error_code);
+        response.send()
+    }
 }
 ////////////////////////
 // END: sliced API
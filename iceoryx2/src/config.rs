@@ -36,6 +36,44 @@
 //! # }
 //! ```
 //!
+//! ## Per-Service Overrides
+//!
+//! The defaults of the service settings can be overridden for all services whose name matches
+//! a `[services."<service name or pattern>"]` section. The pattern supports `*`, matching any
+//! sequence of characters, and `?`, matching exactly one character.
+//!
+//! ```toml
+//! [services."my/high/fanout/topic".publish-subscribe]
+//! max-subscribers = 64
+//!
+//! [services."sensor/*".event]
+//! max-listeners = 4
+//! ```
+//!
+//! The value of a setting is determined in the following order:
+//!
+//!  1. the value provided explicitly to the service builder
+//!  2. the value of the most specific matching `[services]` section, where an exact service name
+//!     is more specific than any pattern and a longer pattern is more specific than a shorter one
+//!  3. the value in the `[defaults]` section
+//!
+//! ## Environment Variable Overrides
+//!
+//! When a config is loaded, every setting can be overridden with an environment variable. Its
+//! name is the path of the setting in the config file, prefixed with `IOX2`, in upper case and
+//! with every `.` and `-` replaced by `_`, for instance `IOX2_GLOBAL_PREFIX` or
+//! `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS`. The overrides are applied after the config
+//! file was read and before the service builder settings. The effective config can be inspected
+//! with [`Config::to_toml()`].
+//!
+//! ## Reload Config On Changes
+//!
+//! Long running processes can pick up changes of the config file with [`Config::watch()`].
+//! The returned [`ConfigWatcher`] reloads and validates the config whenever
+//! [`ConfigWatcher::check_for_changes()`] detects a change and hands the new config to a
+//! callback. Settings that identify the iceoryx2 instance, like the prefix or the root path,
+//! cannot change at runtime and are reported as requiring a restart.
+//!
 //! ## Set Global Config From Custom File
 //!
 //! The [`crate::config::Config::setup_global_config_from_file()`] call must be the first
@@ -80,15 +118,20 @@ use iceoryx2_bb_posix::{
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
 
 use iceoryx2_bb_log::{debug, fail, fatal_panic, info, trace, warn};
 
 use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use crate::service::service_name::ServiceName;
 
 const DEFAULT_CONFIG_FILE_NAME: &[u8] = b"iceoryx2.toml";
 const RELATIVE_LOCAL_CONFIG_PATH: &[u8] = b"config";
 const RELATIVE_CONFIG_FILE_PATH: &[u8] = b"iceoryx2";
+const ENVIRONMENT_VARIABLE_PREFIX: &str = "IOX2";
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ConfigIterationFailure {
@@ -111,6 +154,9 @@ pub enum ConfigCreationError {
     ConfigFileDoesNotExist,
     /// Since the config file could not be opened
     UnableToOpenConfigFile,
+    /// The value of an environment variable that overrides a config setting could not be
+    /// converted into the type of the setting.
+    InvalidEnvironmentVariableValue,
 }
 
 impl core::fmt::Display for ConfigCreationError {
@@ -141,6 +187,12 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// When true, the creation and removal of every [`Service`](crate::service::Service) is
+    /// published as [`ServiceAnnouncement`](crate::service::ServiceAnnouncement) by its
+    /// [`Node`](crate::node::Node) on the internal discovery announcement service so that
+    /// discovery services can react to changes instead of polling
+    /// [`Service::list()`](crate::service::Service::list()).
+    pub enable_discovery_announcements: bool,
 }
 
 /// All configurable settings of a [`Node`](crate::node::Node).
@@ -164,6 +216,17 @@ pub struct Node {
     /// cleans up all their stale resources whenever an existing [`Node`](crate::node::Node) is
     /// going out of scope.
     pub cleanup_dead_nodes_on_destruction: bool,
+    /// The monitoring interval of a [`Node`](crate::node::Node) when no custom interval is
+    /// defined with [`NodeBuilder::monitoring_interval()`](crate::node::NodeBuilder::monitoring_interval()).
+    /// Observers reevaluate the liveness of the [`Node`](crate::node::Node) at least once per
+    /// interval.
+    pub monitoring_interval: Duration,
+    /// The smallest monitoring interval a [`Node`](crate::node::Node) can have. Smaller
+    /// intervals are clamped to this value.
+    pub min_monitoring_interval: Duration,
+    /// The largest monitoring interval a [`Node`](crate::node::Node) can have. Larger
+    /// intervals are clamped to this value.
+    pub max_monitoring_interval: Duration,
 }
 
 /// The global settings
@@ -265,6 +328,12 @@ pub struct PublishSubscribe {
     /// full. When safe overflow is activated, the [`Publisher`](crate::port::publisher::Publisher) will
     /// replace the oldest [`Sample`](crate::sample::Sample) with the newest one.
     pub enable_safe_overflow: bool,
+    /// Defines if the [`Publisher`](crate::port::publisher::Publisher) captures a
+    /// [`Timestamp`](crate::service::header::publish_subscribe::Timestamp) in the
+    /// [`Header`](crate::service::header::publish_subscribe::Header) of every
+    /// [`Sample`](crate::sample::Sample) it sends. The header has the same size either way,
+    /// disabling it only saves the clock reads on send.
+    pub enable_send_timestamp: bool,
     /// If safe overflow is deactivated it defines the deliver strategy of the
     /// [`Publisher`](crate::port::publisher::Publisher) when the [`Subscriber`](crate::port::subscriber::Subscriber)s
     /// buffer is full.
@@ -275,6 +344,27 @@ pub struct PublishSubscribe {
     /// disconnected from a service and the connection
     /// still contains unconsumed [`Sample`](crate::sample::Sample)s.
     pub subscriber_expired_connection_buffer: usize,
+    /// Defines how long a [`Sample`](crate::sample::Sample) is valid after it was sent. Older
+    /// [`Sample`](crate::sample::Sample)s are discarded by the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) instead of being received.
+    pub sample_lifespan: Option<Duration>,
+    /// Defines if the service uses exclusive ownership. The
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) then receives only the
+    /// [`Sample`](crate::sample::Sample)s of the alive
+    /// [`Publisher`](crate::port::publisher::Publisher) with the highest ownership strength.
+    pub enable_exclusive_ownership: bool,
+    /// Defines if every [`Subscriber`](crate::port::subscriber::Subscriber) is notified when a
+    /// [`Sample`](crate::sample::Sample) is delivered, so that it can be attached directly to a
+    /// [`WaitSet`](crate::waitset::WaitSet).
+    pub enable_notify_on_delivery: bool,
+    /// Defines how often a [`Subscriber`](crate::port::subscriber::Subscriber) of a service
+    /// with exclusive ownership verifies that the owning
+    /// [`Publisher`](crate::port::publisher::Publisher) is still alive. It bounds the time
+    /// until a weaker [`Publisher`](crate::port::publisher::Publisher) takes over.
+    pub ownership_monitoring_interval: Duration,
+    /// Binds the data segment of every [`Publisher`](crate::port::publisher::Publisher) to the
+    /// NUMA node with the provided id. Systems without NUMA support ignore the setting.
+    pub publisher_numa_node: Option<usize>,
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
@@ -317,6 +407,10 @@ pub struct RequestResonse {
     /// The maximum of [`ActiveRequest`](crate::active_request::ActiveRequest)s a
     /// [`Server`](crate::port::server::Server) can hold in parallel per [`Client`](crate::port::client::Client).
     pub max_active_requests_per_client: usize,
+    /// The depth of the queue in which the requests of a [`Client`](crate::port::client::Client)
+    /// wait until the [`Server`](crate::port::server::Server) receives them. When not set, it is
+    /// equal to `max_active_requests_per_client`.
+    pub max_request_queue_depth_per_client: Option<usize>,
     /// The maximum buffer size for [`Response`](crate::response::Response)s for a [`PendingResponse`](crate::pending_response::PendingResponse)
     /// for each [`Server`](crate::port::server::Server) connection.
     /// In a multi [`Server`](crate::port::server::Server) scenario every
@@ -366,12 +460,28 @@ pub struct RequestResonse {
     /// Consider enabling this feature if you do not want to loose any
     /// [`RequestMut`](crate::response_mut::ResponseMut).
     pub enable_fire_and_forget_requests: bool,
+    /// Defines if a [`Server`](crate::port::server::Server) can send a stream of arbitrary many
+    /// [`Response`](crate::response::Response)s for a single
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest). When disabled, exactly one
+    /// [`Response`](crate::response::Response) can be sent per
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest).
+    pub enable_multi_response: bool,
+    /// Defines if the [`Server`](crate::port::server::Server) is notified when a request is
+    /// delivered and the [`PendingResponse`](crate::pending_response::PendingResponse) when a
+    /// [`Response`](crate::response::Response) is delivered, so that both can be attached
+    /// directly to a [`WaitSet`](crate::waitset::WaitSet).
+    pub enable_notify_on_delivery: bool,
     /// Defines the size of the internal [`Server`](crate::port::server::Server)
     /// buffer that contains expired connections. A
     /// connection is expired when the [`Client`](crate::port::client::Client)
     /// disconnected from a service and the connection
     /// still contains unconsumed [`ActiveRequest`](crate::active_request::ActiveRequest)s.
     pub server_expired_connection_buffer: usize,
+    /// Defines the maximum time a [`Server`](crate::port::server::Server) has to send the first
+    /// [`Response`](crate::response::Response) to a request. If no
+    /// [`Response`](crate::response::Response) was received after the defined time, the
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) has missed its deadline.
+    pub response_deadline: Option<Duration>,
 }
 
 /// Default settings for the blackboard messaging pattern. These settings are used unless
@@ -387,6 +497,250 @@ pub struct Blackboard {
     pub max_nodes: usize,
 }
 
+macro_rules! service_overrides {
+    ($(#[$meta:meta])* $name:ident => $defaults:ident { $($field:ident: $type:ty),* $(,)? }) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        #[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+        #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+        pub struct $name {
+            $(
+                #[doc = concat!("Overrides [`", stringify!($defaults), "::", stringify!($field), "`] when set.")]
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$type>,
+            )*
+        }
+
+        impl $name {
+            fn apply_to(&self, defaults: &mut $defaults) {
+                $(
+                    if let Some(value) = &self.$field {
+                        defaults.$field = value.clone();
+                    }
+                )*
+            }
+        }
+    };
+}
+
+service_overrides! {
+    /// Overrides of the [`PublishSubscribe`] defaults for the services matching a
+    /// [`ServiceOverrides`] section.
+    PublishSubscribeOverrides => PublishSubscribe {
+        max_subscribers: usize,
+        max_publishers: usize,
+        max_nodes: usize,
+        subscriber_max_buffer_size: usize,
+        subscriber_max_borrowed_samples: usize,
+        publisher_history_size: usize,
+        enable_safe_overflow: bool,
+        enable_send_timestamp: bool,
+        sample_lifespan: Option<Duration>,
+        enable_exclusive_ownership: bool,
+        enable_notify_on_delivery: bool,
+    }
+}
+
+service_overrides! {
+    /// Overrides of the [`Event`] defaults for the services matching a [`ServiceOverrides`]
+    /// section.
+    EventOverrides => Event {
+        max_listeners: usize,
+        max_notifiers: usize,
+        max_nodes: usize,
+        event_id_max_value: usize,
+        deadline: Option<Duration>,
+        notifier_created_event: Option<usize>,
+        notifier_dropped_event: Option<usize>,
+        notifier_dead_event: Option<usize>,
+    }
+}
+
+service_overrides! {
+    /// Overrides of the [`RequestResonse`] defaults for the services matching a
+    /// [`ServiceOverrides`] section.
+    RequestResponseOverrides => RequestResonse {
+        enable_safe_overflow_for_requests: bool,
+        enable_safe_overflow_for_responses: bool,
+        max_active_requests_per_client: usize,
+        max_request_queue_depth_per_client: Option<usize>,
+        max_response_buffer_size: usize,
+        max_servers: usize,
+        max_clients: usize,
+        max_nodes: usize,
+        max_borrowed_responses_per_pending_response: usize,
+        max_loaned_requests: usize,
+        enable_fire_and_forget_requests: bool,
+        enable_multi_response: bool,
+        enable_notify_on_delivery: bool,
+        response_deadline: Option<Duration>,
+    }
+}
+
+service_overrides! {
+    /// Overrides of the [`Blackboard`] defaults for the services matching a
+    /// [`ServiceOverrides`] section.
+    BlackboardOverrides => Blackboard {
+        max_readers: usize,
+        max_nodes: usize,
+    }
+}
+
+/// Overrides the [`Defaults`] of the service settings for all
+/// [`Service`](crate::service::Service)s whose name matches the section. Only the settings
+/// that are stored in the static configuration of a [`Service`](crate::service::Service)
+/// can be overridden, the defaults of the ports are not affected.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ServiceOverrides {
+    /// Overrides for the messaging pattern publish-subscribe
+    #[serde(default)]
+    pub publish_subscribe: PublishSubscribeOverrides,
+    /// Overrides for the messaging pattern event
+    #[serde(default)]
+    pub event: EventOverrides,
+    /// Overrides for the messaging pattern request-response
+    #[serde(default)]
+    pub request_response: RequestResponseOverrides,
+    /// Overrides for the messaging pattern blackboard
+    #[serde(default)]
+    pub blackboard: BlackboardOverrides,
+}
+
+impl ServiceOverrides {
+    fn apply_to(&self, defaults: &mut Defaults) {
+        self.publish_subscribe
+            .apply_to(&mut defaults.publish_subscribe);
+        self.event.apply_to(&mut defaults.event);
+        self.request_response
+            .apply_to(&mut defaults.request_response);
+        self.blackboard.apply_to(&mut defaults.blackboard);
+    }
+}
+
+fn deserialize_service_overrides<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<String, ServiceOverrides>, D::Error> {
+    BTreeMap::<String, toml::Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(
+            |(pattern, section)| match ServiceOverrides::deserialize(section) {
+                Ok(overrides) => Ok((pattern, overrides)),
+                Err(e) => Err(D::Error::custom(format!(
+                    "invalid section [services.\"{}\"]: {}",
+                    pattern,
+                    e.message()
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Returns true when the `name` matches the `pattern`. A `*` in the pattern matches any
+/// sequence of characters and a `?` exactly one character.
+fn matches_pattern(pattern: &[u8], name: &[u8]) -> bool {
+    let mut p = 0;
+    let mut n = 0;
+    let mut last_wildcard: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            last_wildcard = Some((p, n));
+            p += 1;
+        } else if let Some((wildcard_p, wildcard_n)) = last_wildcard {
+            p = wildcard_p + 1;
+            n = wildcard_n + 1;
+            last_wildcard = Some((wildcard_p, wildcard_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+struct EnvironmentOverride {
+    path: Vec<String>,
+    variable: String,
+    value: toml::Value,
+}
+
+fn environment_variable_name(parent: &str, key: &str) -> String {
+    format!("{}_{}", parent, key.to_uppercase().replace(['-', '.'], "_"))
+}
+
+fn parse_environment_value(current: &toml::Value, value: &str) -> Option<toml::Value> {
+    match current {
+        toml::Value::String(_) => Some(toml::Value::String(value.to_string())),
+        toml::Value::Integer(_) => value.parse().ok().map(toml::Value::Integer),
+        toml::Value::Float(_) => value.parse().ok().map(toml::Value::Float),
+        toml::Value::Boolean(_) => value.parse().ok().map(toml::Value::Boolean),
+        _ => None,
+    }
+}
+
+fn collect_environment_overrides(
+    table: &toml::Table,
+    path: &mut Vec<String>,
+    variable_prefix: &str,
+    overrides: &mut Vec<EnvironmentOverride>,
+) -> Result<(), String> {
+    for (key, current) in table {
+        let variable = environment_variable_name(variable_prefix, key);
+        path.push(key.clone());
+
+        if let toml::Value::Table(table) = current {
+            collect_environment_overrides(table, path, &variable, overrides)?;
+        } else if let Some(value) = std::env::var_os(&variable) {
+            let value = match value
+                .to_str()
+                .and_then(|value| parse_environment_value(current, value))
+            {
+                Some(value) => value,
+                None => return Err(variable),
+            };
+
+            overrides.push(EnvironmentOverride {
+                path: path.clone(),
+                variable,
+                value,
+            });
+        }
+
+        path.pop();
+    }
+
+    Ok(())
+}
+
+fn merge_non_default_settings(
+    target: &mut toml::Table,
+    source: &toml::Table,
+    defaults: Option<&toml::Table>,
+) {
+    for (key, value) in source {
+        let default = defaults.and_then(|defaults| defaults.get(key));
+        match (value, target.get_mut(key)) {
+            (toml::Value::Table(source), Some(toml::Value::Table(target))) => {
+                let defaults = match default {
+                    Some(toml::Value::Table(defaults)) => Some(defaults),
+                    _ => None,
+                };
+                merge_non_default_settings(target, source, defaults);
+            }
+            _ => {
+                if default != Some(value) {
+                    target.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [`Global`] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [`Defaults`] for communication within that iceoryx2 instance. The user has the
@@ -399,6 +753,15 @@ pub struct Config {
     pub global: Global,
     /// Default settings
     pub defaults: Defaults,
+    /// Overrides of the [`Defaults`] for all [`Service`](crate::service::Service)s whose name
+    /// matches the key. The key is either a service name or a pattern, see
+    /// [`Config::defaults_for()`].
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        deserialize_with = "deserialize_service_overrides"
+    )]
+    pub services: BTreeMap<String, ServiceOverrides>,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
@@ -418,6 +781,7 @@ impl Default for Config {
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    enable_discovery_announcements: false,
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
@@ -426,6 +790,9 @@ impl Default for Config {
                     service_tag_suffix: FileName::new(b".service_tag").unwrap(),
                     cleanup_dead_nodes_on_creation: true,
                     cleanup_dead_nodes_on_destruction: true,
+                    monitoring_interval: Duration::from_millis(100),
+                    min_monitoring_interval: Duration::from_millis(10),
+                    max_monitoring_interval: Duration::from_secs(10),
                 },
             },
             defaults: Defaults {
@@ -433,6 +800,7 @@ impl Default for Config {
                     enable_safe_overflow_for_requests: true,
                     enable_safe_overflow_for_responses: true,
                     max_active_requests_per_client: 4,
+                    max_request_queue_depth_per_client: None,
                     max_response_buffer_size: 2,
                     max_servers: 2,
                     max_clients: 8,
@@ -445,6 +813,9 @@ impl Default for Config {
                     client_expired_connection_buffer: 128,
                     server_expired_connection_buffer: 128,
                     enable_fire_and_forget_requests: true,
+                    enable_multi_response: true,
+                    enable_notify_on_delivery: false,
+                    response_deadline: None,
                 },
                 publish_subscribe: PublishSubscribe {
                     max_subscribers: 8,
@@ -455,8 +826,14 @@ impl Default for Config {
                     subscriber_max_borrowed_samples: 2,
                     publisher_max_loaned_samples: 2,
                     enable_safe_overflow: true,
+                    enable_send_timestamp: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                     subscriber_expired_connection_buffer: 128,
+                    sample_lifespan: None,
+                    enable_exclusive_ownership: false,
+                    enable_notify_on_delivery: false,
+                    ownership_monitoring_interval: Duration::from_millis(100),
+                    publisher_numa_node: None,
                 },
                 event: Event {
                     max_listeners: 16,
@@ -473,11 +850,152 @@ impl Default for Config {
                     max_nodes: 20,
                 },
             },
+            services: BTreeMap::new(),
         }
     }
 }
 
 impl Config {
+    /// Returns the [`Defaults`] for the [`Service`](crate::service::Service) with the provided
+    /// [`ServiceName`] with all matching [`ServiceOverrides`] applied. The overrides are applied
+    /// from the least to the most specific section. A section with the exact service name is
+    /// more specific than every pattern and a longer pattern is more specific than a shorter one.
+    pub fn defaults_for(&self, service_name: &ServiceName) -> Defaults {
+        let mut defaults = self.defaults.clone();
+        let name = service_name.as_str().as_bytes();
+
+        let mut matching_sections = self
+            .services
+            .iter()
+            .filter(|(pattern, _)| matches_pattern(pattern.as_bytes(), name))
+            .collect::<Vec<_>>();
+        matching_sections.sort_by_key(|(pattern, _)| (pattern.as_bytes() == name, pattern.len()));
+
+        for (_, overrides) in matching_sections {
+            overrides.apply_to(&mut defaults);
+        }
+
+        defaults
+    }
+
+    /// Overrides the settings with the values of the corresponding environment variables, see
+    /// the [module documentation](crate::config) for the naming scheme. Only settings that have
+    /// a value can be overridden, unset optional settings and the per-service overrides are not
+    /// considered. It is called by [`Config::from_file()`] and when the global config is loaded.
+    ///
+    /// When the value of an environment variable cannot be converted into the type of the
+    /// setting, the [`Config`] remains unchanged and
+    /// [`ConfigCreationError::InvalidEnvironmentVariableValue`] is returned.
+    pub fn apply_environment_overrides(&mut self) -> Result<(), ConfigCreationError> {
+        let msg = "Unable to apply the environment variable overrides";
+        let mut config = fatal_panic!(from self,
+            when toml::Table::try_from(&*self),
+            "This should never happen! The config could not be converted into a toml table.");
+
+        let mut overrides = Vec::new();
+        for (key, value) in &config {
+            // the keys of the per-service overrides are service names or patterns
+            if key == "services" {
+                continue;
+            }
+
+            if let toml::Value::Table(table) = value {
+                if let Err(variable) = collect_environment_overrides(
+                    table,
+                    &mut vec![key.clone()],
+                    &environment_variable_name(ENVIRONMENT_VARIABLE_PREFIX, key),
+                    &mut overrides,
+                ) {
+                    fail!(from self, with ConfigCreationError::InvalidEnvironmentVariableValue,
+                        "{} since the value of the environment variable {} has not the type of the setting.",
+                        msg, variable);
+                }
+            }
+        }
+
+        if overrides.is_empty() {
+            return Ok(());
+        }
+
+        for environment_override in &overrides {
+            let (key, parents) = environment_override
+                .path
+                .split_last()
+                .expect("the path of a setting is never empty");
+            let mut table = &mut config;
+            for parent in parents {
+                table = match table.get_mut(parent) {
+                    Some(toml::Value::Table(t)) => t,
+                    _ => fatal_panic!(from self,
+                        "This should never happen! The path of the setting {} does not exist.",
+                        environment_override.variable),
+                };
+            }
+            table.insert(key.clone(), environment_override.value.clone());
+
+            if let Err(e) = Config::deserialize(config.clone()) {
+                fail!(from self, with ConfigCreationError::InvalidEnvironmentVariableValue,
+                    "{} since the value of the environment variable {} is not a valid setting ({}).",
+                    msg, environment_override.variable, e.message());
+            }
+
+            debug!(from self, "Setting overridden by the environment variable {}.",
+                environment_override.variable);
+        }
+
+        *self = fatal_panic!(from self,
+            when Config::deserialize(config),
+            "This should never happen! The validated config could not be deserialized.");
+
+        Ok(())
+    }
+
+    /// Returns a copy of the [`Config`] where all settings of `other` that are set are layered
+    /// on top. A setting counts as set when its value differs from the value of
+    /// [`Config::default()`], every other setting of `other` is treated as default and keeps
+    /// the value of `self`. Consequently, a setting that was explicitly set to its default
+    /// value in `other` does not override a different value in `self`. Optional settings
+    /// without a value are never applied. The [`ServiceOverrides`] of `other` are added, when
+    /// both contain a section for the same service name or pattern, the settings of the
+    /// section are merged individually.
+    ///
+    /// ```
+    /// use iceoryx2::config::Config;
+    ///
+    /// let base = Config::default();
+    /// let mut tweaks = Config::default();
+    /// tweaks.defaults.publish_subscribe.max_subscribers = 16;
+    ///
+    /// let config = base.merge(&tweaks);
+    /// assert_eq!(config.defaults.publish_subscribe.max_subscribers, 16);
+    /// assert_eq!(config.global, base.global);
+    /// ```
+    pub fn merge(&self, other: &Config) -> Config {
+        let mut config = fatal_panic!(from self,
+            when toml::Table::try_from(self),
+            "This should never happen! The config could not be converted into a toml table.");
+        let other = fatal_panic!(from self,
+            when toml::Table::try_from(other),
+            "This should never happen! The config could not be converted into a toml table.");
+        let defaults = fatal_panic!(from self,
+            when toml::Table::try_from(Config::default()),
+            "This should never happen! The default config could not be converted into a toml table.");
+
+        merge_non_default_settings(&mut config, &other, Some(&defaults));
+
+        fatal_panic!(from self,
+            when Config::deserialize(config),
+            "This should never happen! The merged config could not be deserialized.")
+    }
+
+    /// Returns the effective [`Config`] in the TOML format of the config file, including all
+    /// environment variable overrides.
+    pub fn to_toml(&self) -> String {
+        fatal_panic!(from self,
+            when toml::to_string(self),
+            "This should never happen! The config could not be serialized.")
+    }
+
     fn relative_local_config_path() -> Path {
         fatal_panic!(from "Config::relative_local_config_path",
             when Path::new(RELATIVE_LOCAL_CONFIG_PATH),
@@ -590,7 +1108,12 @@ impl Config {
     /// [`ConfigCreationError`] describing the failure.
     pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
         let msg = "Failed to create config";
-        let mut new_config = Self::default();
+        let contents = Self::read_config_file(config_file, msg)?;
+        Self::from_contents(&contents, msg)
+    }
+
+    fn read_config_file(config_file: &FilePath, msg: &str) -> Result<String, ConfigCreationError> {
+        let new_config = Self::default();
 
         let file = match FileBuilder::new(config_file).open_existing(AccessMode::Read) {
             Ok(file) => file,
@@ -619,7 +1142,13 @@ impl Config {
                 with ConfigCreationError::FailedToReadConfigFileContents,
                 "{} since the config file contents could not be read.", msg);
 
-        match toml::from_str(&contents) {
+        Ok(contents)
+    }
+
+    fn from_contents(contents: &str, msg: &str) -> Result<Config, ConfigCreationError> {
+        let mut new_config = Self::default();
+
+        match toml::from_str(contents) {
             Ok(v) => new_config = v,
             Err(e) => {
                 fail!(from new_config, with ConfigCreationError::UnableToDeserializeContents,
@@ -627,10 +1156,56 @@ impl Config {
             }
         }
 
+        new_config.apply_environment_overrides()?;
+
         trace!(from new_config, "Loaded.");
         Ok(new_config)
     }
 
+    /// Creates a [`ConfigWatcher`] that loads the [`Config`] from the provided file and calls
+    /// the callback with the reloaded [`Config`] whenever
+    /// [`ConfigWatcher::check_for_changes()`] detects that the file contents have changed.
+    /// On failure it returns a [`ConfigCreationError`] describing why the initial [`Config`]
+    /// could not be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::config::Config;
+    /// use iceoryx2_bb_system_types::file_path::FilePath;
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let mut watcher = Config::watch(
+    ///     &FilePath::new(b"my/custom/config/file.toml")?,
+    ///     |update| {
+    ///         if update.requires_restart() {
+    ///             println!("restart required for {:?}", update.settings_requiring_restart());
+    ///         }
+    ///         // use update.config() for all nodes and services created from now on
+    ///     })?;
+    ///
+    /// // called periodically, for instance in the main loop of a long running process
+    /// watcher.check_for_changes()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch<F: FnMut(&ConfigUpdate)>(
+        config_file: &FilePath,
+        callback: F,
+    ) -> Result<ConfigWatcher<F>, ConfigCreationError> {
+        let msg = "Unable to watch config";
+        let contents = Self::read_config_file(config_file, msg)?;
+        let config = Self::from_contents(&contents, msg)?;
+
+        Ok(ConfigWatcher {
+            config_file: config_file.clone(),
+            contents,
+            config,
+            callback,
+        })
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
@@ -689,9 +1264,167 @@ impl Config {
             if !is_config_file_set {
                 warn!(from origin,
                     "No config file was loaded, a config with default values will be used.");
-                ICEORYX2_CONFIG.set_value(Config::default());
+                let mut config = Config::default();
+                if let Err(e) = config.apply_environment_overrides() {
+                    warn!(from origin,
+                        "The environment variable overrides could not be applied ({:?}), the default values will be used.", e);
+                    config = Config::default();
+                }
+                ICEORYX2_CONFIG.set_value(config);
             }
         }
         ICEORYX2_CONFIG.get()
     }
 }
+
+/// The [`Config`] that was reloaded by the [`ConfigWatcher`]. The settings in
+/// [`ConfigUpdate::settings_requiring_restart()`] identify the iceoryx2 instance and cannot
+/// change while the process is running. They keep their previous values in
+/// [`ConfigUpdate::config()`] and are applied only after a restart.
+#[derive(Debug, Clone)]
+pub struct ConfigUpdate {
+    config: Config,
+    settings_requiring_restart: Vec<&'static str>,
+}
+
+impl ConfigUpdate {
+    /// Returns the reloaded [`Config`]. It shall be used for all
+    /// [`Node`](crate::node::Node)s and [`Service`](crate::service::Service)s that are created
+    /// from now on, existing ones keep the [`Config`] they were created with.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Returns the names of the changed settings, as they appear in the config file, that
+    /// require a restart of the process to become effective.
+    pub fn settings_requiring_restart(&self) -> &[&'static str] {
+        &self.settings_requiring_restart
+    }
+
+    /// Returns true when at least one changed setting requires a restart of the process.
+    pub fn requires_restart(&self) -> bool {
+        !self.settings_requiring_restart.is_empty()
+    }
+}
+
+/// Watches a config file and reloads the [`Config`] whenever the file contents have changed.
+/// It does not spawn a background thread, the file is polled with
+/// [`ConfigWatcher::check_for_changes()`]. Created with [`Config::watch()`].
+pub struct ConfigWatcher<F: FnMut(&ConfigUpdate)> {
+    config_file: FilePath,
+    contents: String,
+    config: Config,
+    callback: F,
+}
+
+impl<F: FnMut(&ConfigUpdate)> core::fmt::Debug for ConfigWatcher<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ConfigWatcher {{ config_file: {}, config: {:?} }}",
+            self.config_file, self.config
+        )
+    }
+}
+
+impl<F: FnMut(&ConfigUpdate)> ConfigWatcher<F> {
+    /// Returns the path of the watched config file.
+    pub fn config_file(&self) -> &FilePath {
+        &self.config_file
+    }
+
+    /// Returns the most recently loaded valid [`Config`].
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Reads the config file and, when its contents have changed, validates the new contents
+    /// and calls the callback with the reloaded [`Config`]. Returns true when the callback was
+    /// called.
+    ///
+    /// When the config file cannot be read or the new contents are not a valid [`Config`], the
+    /// callback is not called, the current [`Config`] stays active and a [`ConfigCreationError`]
+    /// describing the failure is returned. The contents are validated again on the next call.
+    pub fn check_for_changes(&mut self) -> Result<bool, ConfigCreationError> {
+        let msg = "Unable to reload config";
+        let contents = Config::read_config_file(&self.config_file, msg)?;
+        if contents == self.contents {
+            return Ok(false);
+        }
+
+        let mut config = Config::from_contents(&contents, msg)?;
+        self.contents = contents;
+
+        let settings_requiring_restart = retain_immutable_settings(&self.config, &mut config);
+        if settings_requiring_restart.is_empty() && config == self.config {
+            return Ok(false);
+        }
+
+        if !settings_requiring_restart.is_empty() {
+            warn!(from self,
+                "The config file \"{}\" changed the settings {:?} which require a restart to become effective.",
+                self.config_file, settings_requiring_restart);
+        }
+
+        self.config = config.clone();
+        debug!(from self, "Reloaded.");
+        (self.callback)(&ConfigUpdate {
+            config,
+            settings_requiring_restart,
+        });
+
+        Ok(true)
+    }
+}
+
+/// Resets all settings of `new` that cannot change while the process is running to the values
+/// of `current` and returns the names of the settings that were changed.
+fn retain_immutable_settings(current: &Config, new: &mut Config) -> Vec<&'static str> {
+    let mut changed_settings = Vec::new();
+
+    macro_rules! retain {
+        ($name:expr, $($setting:ident).+) => {
+            if current.$($setting).+ != new.$($setting).+ {
+                changed_settings.push($name);
+                new.$($setting).+ = current.$($setting).+.clone();
+            }
+        };
+    }
+
+    retain!("global.root-path-unix", global.root_path_unix);
+    retain!("global.root-path-windows", global.root_path_windows);
+    retain!("global.prefix", global.prefix);
+    retain!("global.service.directory", global.service.directory);
+    retain!(
+        "global.service.data-segment-suffix",
+        global.service.data_segment_suffix
+    );
+    retain!(
+        "global.service.static-config-storage-suffix",
+        global.service.static_config_storage_suffix
+    );
+    retain!(
+        "global.service.dynamic-config-storage-suffix",
+        global.service.dynamic_config_storage_suffix
+    );
+    retain!(
+        "global.service.connection-suffix",
+        global.service.connection_suffix
+    );
+    retain!(
+        "global.service.event-connection-suffix",
+        global.service.event_connection_suffix
+    );
+    retain!("global.node.directory", global.node.directory);
+    retain!("global.node.monitor-suffix", global.node.monitor_suffix);
+    retain!(
+        "global.node.static-config-suffix",
+        global.node.static_config_suffix
+    );
+    retain!(
+        "global.node.service-tag-suffix",
+        global.node.service_tag_suffix
+    );
+
+    changed_settings
+}
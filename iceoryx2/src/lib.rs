@@ -476,6 +476,12 @@ pub mod sample_mut_uninit;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// Forwards the samples of a publish-subscribe [`Service`](crate::service::Service) into a
+/// [`Service`](crate::service::Service) of another type, for instance from a
+/// [`local::Service`](crate::service::local::Service) into an
+/// [`ipc::Service`](crate::service::ipc::Service).
+pub mod service_bridge;
+
 /// Defines how constructs like the [`Node`](crate::node::Node) or the
 /// [`WaitSet`](crate::waitset::WaitSet) shall handle system signals.
 pub mod signal_handling_mode;
@@ -133,6 +133,10 @@
 //!         Err(NodeWaitFailure::Interrupt) => {
 //!             println!("Someone send an interrupt signal ...");
 //!         }
+//!         Err(NodeWaitFailure::ShutdownRequest) => {
+//!             println!("Shutdown requested via Node::signal_shutdown()");
+//!             break;
+//!         }
 //!     }
 //! }
 //! # Ok(())
@@ -142,18 +146,35 @@
 /// The name for a node.
 pub mod node_name;
 
+/// Manages multiple nodes with a shared lifecycle.
+pub mod node_group;
+
+/// The shared memory regions a node allocated and their memory usage.
+pub mod shm_region;
+
+/// Reports and removes the stale resources of dead nodes.
+pub mod stale_resources;
+
 #[doc(hidden)]
 pub mod testing;
 
 use crate::node::node_name::NodeName;
+use crate::node::shm_region::{SharedMemoryRegion, ShmRegionUsage};
+use crate::port::listener::Listener;
+use crate::port::port_identifiers::UniquePortId;
+use crate::port::publisher::Publisher;
+use crate::service::builder::event::EventOpenOrCreateError;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
     node_details_path, node_monitoring_config, service_tag_config,
 };
+use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
 use crate::service::{
-    self, remove_service_tag, remove_static_service_config, ServiceRemoveNodeError,
+    self, open_dynamic_config, remove_service_tag, remove_static_service_config,
+    ServiceAnnouncement, ServiceDetailsError, ServiceRemoveNodeError,
 };
 use crate::signal_handling_mode::SignalHandlingMode;
 use crate::{config::Config, service::config_scheme::node_details_config};
@@ -172,6 +193,8 @@ use iceoryx2_bb_posix::process::{Process, ProcessId};
 use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::ListenerWaitError;
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
 use iceoryx2_cal::{
     monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
@@ -182,7 +205,9 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+const SHUTDOWN_SERVICE_NAME: &str = "node/shutdown";
 
 /// The system-wide unique id of a [`Node`]
 #[derive(
@@ -247,6 +272,9 @@ pub enum NodeWaitFailure {
     Interrupt,
     /// A termination signal `SIGTERM` was received.
     TerminationRequest,
+    /// Another [`Node`] requested a shutdown with [`Node::signal_shutdown()`]. Only reported
+    /// when the [`Node`] was created with [`NodeBuilder::handle_shutdown_requests()`].
+    ShutdownRequest,
 }
 
 impl core::fmt::Display for NodeWaitFailure {
@@ -255,6 +283,25 @@ impl core::fmt::Display for NodeWaitFailure {
     }
 }
 
+/// The failures that can occur when a shutdown is requested with [`Node::signal_shutdown()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeSignalShutdownFailure {
+    /// The internal shutdown service could not be opened or created.
+    UnableToOpenShutdownService,
+    /// The notifier that delivers the shutdown request could not be created.
+    UnableToCreateNotifier,
+    /// The shutdown request could not be delivered.
+    UnableToNotify,
+}
+
+impl core::fmt::Display for NodeSignalShutdownFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "NodeSignalShutdownFailure::{self:?}")
+    }
+}
+
+impl core::error::Error for NodeSignalShutdownFailure {}
+
 impl core::error::Error for NodeWaitFailure {}
 
 /// The failures that can occur when a list of [`NodeState`]s is created with [`Node::list()`].
@@ -318,15 +365,16 @@ pub struct NodeDetails {
     executable: FileName,
     name: NodeName,
     config: Config,
+    monitoring_interval: Duration,
 }
 
 impl NodeDetails {
     #[doc(hidden)]
     pub fn __internal_new(node_name: &Option<NodeName>, config: &Config) -> Self {
-        Self::new(node_name, config)
+        Self::new(node_name, config, config.global.node.monitoring_interval)
     }
 
-    fn new(node_name: &Option<NodeName>, config: &Config) -> Self {
+    fn new(node_name: &Option<NodeName>, config: &Config, monitoring_interval: Duration) -> Self {
         let executable = match Process::from_self().executable() {
             Ok(n) => n.file_name(),
             Err(e) => {
@@ -344,6 +392,7 @@ impl NodeDetails {
                 NodeName::new("").expect("An empty NodeName is always valid.")
             },
             config: config.clone(),
+            monitoring_interval,
         }
     }
 
@@ -362,6 +411,61 @@ impl NodeDetails {
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// Returns the effective monitoring interval of the [`Node`]. Observers reevaluate the
+    /// liveness of the [`Node`] at least once per interval.
+    pub fn monitoring_interval(&self) -> Duration {
+        self.monitoring_interval
+    }
+}
+
+/// Defines how a [`Node`] acquired a [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ServiceRole {
+    /// The [`Service`](crate::service::Service) was created by the [`Node`].
+    Creator,
+    /// The [`Service`](crate::service::Service) already existed and was opened by the [`Node`].
+    Opener,
+}
+
+/// Describes a [`Service`](crate::service::Service) that is currently held by a [`Node`] and
+/// all ports that were created from it. Acquired with [`Node::opened_services()`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OpenedService {
+    name: ServiceName,
+    service_id: ServiceId,
+    messaging_pattern: MessagingPattern,
+    role: ServiceRole,
+    ports: Vec<UniquePortId>,
+}
+
+impl OpenedService {
+    /// Returns the [`ServiceName`] of the [`Service`](crate::service::Service).
+    pub fn name(&self) -> &ServiceName {
+        &self.name
+    }
+
+    /// Returns the [`ServiceId`] of the [`Service`](crate::service::Service).
+    pub fn service_id(&self) -> &ServiceId {
+        &self.service_id
+    }
+
+    /// Returns the [`MessagingPattern`] of the [`Service`](crate::service::Service).
+    pub fn messaging_pattern(&self) -> MessagingPattern {
+        self.messaging_pattern
+    }
+
+    /// Returns whether the [`Node`] created or opened the
+    /// [`Service`](crate::service::Service).
+    pub fn role(&self) -> ServiceRole {
+        self.role
+    }
+
+    /// Returns the [`UniquePortId`]s of all ports the [`Node`] created from the
+    /// [`Service`](crate::service::Service) and that are still alive.
+    pub fn ports(&self) -> &[UniquePortId] {
+        &self.ports
+    }
 }
 
 /// The current state of the [`Node`]. If the [`Node`] is dead all of its resources can be removed
@@ -735,47 +839,82 @@ fn remove_node<Service: service::Service>(
     Ok(true)
 }
 
+#[derive(Debug)]
+struct RegisteredService {
+    handle: ContainerHandle,
+    reference_counter: u64,
+    name: ServiceName,
+    messaging_pattern: MessagingPattern,
+    role: ServiceRole,
+}
+
 #[derive(Debug)]
 pub(crate) struct RegisteredServices {
-    data: Mutex<HashMap<ServiceId, (ContainerHandle, u64)>>,
+    data: Mutex<HashMap<ServiceId, RegisteredService>>,
 }
 
 unsafe impl Send for RegisteredServices {}
 unsafe impl Sync for RegisteredServices {}
 
 impl RegisteredServices {
-    pub(crate) fn add(&self, service_id: &ServiceId, handle: ContainerHandle) {
+    fn insert(&self, static_config: &StaticConfig, handle: ContainerHandle, role: ServiceRole) {
+        let service = RegisteredService {
+            handle,
+            reference_counter: 1,
+            name: static_config.name().clone(),
+            messaging_pattern: static_config.messaging_pattern().pattern(),
+            role,
+        };
+
         if self
             .data
             .lock()
             .unwrap()
-            .insert(service_id.clone(), (handle, 1))
+            .insert(static_config.service_id().clone(), service)
             .is_some()
         {
             fatal_panic!(from "RegisteredServices::add()",
-                "This should never happen! The service with the {:?} was already registered.", service_id);
+                "This should never happen! The service with the {:?} was already registered.", static_config.service_id());
         }
     }
 
+    pub(crate) fn add(&self, static_config: &StaticConfig, handle: ContainerHandle) {
+        self.insert(static_config, handle, ServiceRole::Creator)
+    }
+
     pub(crate) fn add_or<F: FnMut() -> Result<ContainerHandle, OpenDynamicStorageFailure>>(
         &self,
-        service_id: &ServiceId,
+        static_config: &StaticConfig,
         mut or_callback: F,
     ) -> Result<(), OpenDynamicStorageFailure> {
         let mut data = self.data.lock().unwrap();
-        match data.get_mut(service_id) {
-            Some(handle) => {
-                handle.1 += 1;
+        match data.get_mut(static_config.service_id()) {
+            Some(service) => {
+                service.reference_counter += 1;
             }
             None => {
                 drop(data);
                 let handle = or_callback()?;
-                self.add(service_id, handle);
+                self.insert(static_config, handle, ServiceRole::Opener);
             }
         };
         Ok(())
     }
 
+    fn list<F: FnMut(&ServiceId, &ServiceName, MessagingPattern, ServiceRole)>(
+        &self,
+        mut callback: F,
+    ) {
+        for (service_id, service) in self.data.lock().unwrap().iter() {
+            callback(
+                service_id,
+                &service.name,
+                service.messaging_pattern,
+                service.role,
+            );
+        }
+    }
+
     pub(crate) fn remove<F: FnMut(ContainerHandle)>(
         &self,
         service_id: &ServiceId,
@@ -783,9 +922,9 @@ impl RegisteredServices {
     ) {
         let mut data = self.data.lock().unwrap();
         if let Some(entry) = data.get_mut(service_id) {
-            entry.1 -= 1;
-            if entry.1 == 0 {
-                cleanup_call(entry.0);
+            entry.reference_counter -= 1;
+            if entry.reference_counter == 0 {
+                cleanup_call(entry.handle);
                 data.remove(service_id);
             }
         } else {
@@ -795,13 +934,27 @@ impl RegisteredServices {
     }
 }
 
+/// The publisher of a [`Node`] that announces the creation and removal of its services, see
+/// [`ServiceAnnouncement`](crate::service::ServiceAnnouncement).
+#[derive(Debug)]
+pub(crate) enum DiscoveryAnnouncer<Service: service::Service> {
+    /// The announcement service was not yet opened, it is opened with the next announcement.
+    Unopened,
+    Open(Publisher<Service, ServiceAnnouncement, ()>),
+    /// The [`Node`] was dropped, the announcer must not be opened again since it would keep
+    /// the [`SharedNode`] alive.
+    Closed,
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedNode<Service: service::Service> {
     id: NodeId,
     details: NodeDetails,
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
     registered_services: RegisteredServices,
+    shm_regions: Mutex<Vec<SharedMemoryRegion>>,
     signal_handling_mode: SignalHandlingMode,
+    discovery_announcer: Mutex<DiscoveryAnnouncer<Service>>,
     _details_storage: Service::StaticStorage,
 }
 
@@ -820,6 +973,16 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn registered_services(&self) -> &RegisteredServices {
         &self.registered_services
     }
+
+    pub(crate) fn discovery_announcer(&self) -> MutexGuard<'_, DiscoveryAnnouncer<Service>> {
+        self.discovery_announcer.lock().unwrap()
+    }
+
+    pub(crate) fn register_shm_region(&self, name: &FileName, usage: &Arc<ShmRegionUsage>) {
+        let mut shm_regions = self.shm_regions.lock().unwrap();
+        shm_regions.retain(|region| !region.is_released());
+        shm_regions.push(SharedMemoryRegion::new(name, usage));
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
@@ -844,11 +1007,81 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 /// Can be created via the [`NodeBuilder`].
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
+    shutdown_request_handler: Option<Box<Mutex<ShutdownRequestHandler<Service>>>>,
+    dead_node_monitor: Box<Mutex<DeadNodeMonitor>>,
+    dead_node_dispatch: Mutex<()>,
     shared: Arc<SharedNode<Service>>,
 }
 
+#[derive(Debug)]
+struct ShutdownRequestHandler<Service: service::Service> {
+    listener: Listener<Service>,
+    is_requested: bool,
+}
+
+type DeadNodeCallback = Box<dyn FnMut(&NodeId) + Send>;
+
+#[derive(Default)]
+struct DeadNodeMonitor {
+    callbacks: Vec<DeadNodeCallback>,
+    reported_nodes: Vec<NodeId>,
+}
+
+impl DeadNodeMonitor {
+    // a callback that panicked while the monitor was locked does not prevent further
+    // dead node reports
+    fn lock(monitor: &Mutex<Self>) -> MutexGuard<'_, Self> {
+        match monitor.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+// Owns the callbacks while they are called outside of the monitor lock and returns them to
+// the monitor when it goes out of scope, also when a callback panics.
+struct DispatchedDeadNodeCallbacks<'monitor> {
+    monitor: &'monitor Mutex<DeadNodeMonitor>,
+    callbacks: Vec<DeadNodeCallback>,
+}
+
+impl Drop for DispatchedDeadNodeCallbacks<'_> {
+    fn drop(&mut self) {
+        let mut monitor = DeadNodeMonitor::lock(self.monitor);
+        // callbacks that were registered during the dispatch are called after the existing ones
+        self.callbacks.append(&mut monitor.callbacks);
+        monitor.callbacks = core::mem::take(&mut self.callbacks);
+    }
+}
+
+impl core::fmt::Debug for DeadNodeMonitor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DeadNodeMonitor {{ number_of_callbacks: {}, reported_nodes: {:?} }}",
+            self.callbacks.len(),
+            self.reported_nodes
+        )
+    }
+}
+
+// The listener is only accessed while the surrounding mutex is locked.
+unsafe impl<Service: service::Service> Send for ShutdownRequestHandler<Service> {}
+
 unsafe impl<Service: service::Service> Send for Node<Service> {}
 
+impl<Service: service::Service> Drop for Node<Service> {
+    fn drop(&mut self) {
+        // the announcer holds the announcement service which holds the shared node, it is
+        // released outside of the lock since releasing its service announces nothing
+        let announcer = core::mem::replace(
+            &mut *self.shared.discovery_announcer(),
+            DiscoveryAnnouncer::Closed,
+        );
+        drop(announcer);
+    }
+}
+
 impl<Service: service::Service> Node<Service> {
     /// Returns the [`NodeName`].
     pub fn name(&self) -> &NodeName {
@@ -865,11 +1098,94 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
+    /// Returns the effective monitoring interval of the [`Node`], see
+    /// [`NodeBuilder::monitoring_interval()`].
+    pub fn monitoring_interval(&self) -> Duration {
+        self.shared.details.monitoring_interval
+    }
+
     /// Instantiates a [`ServiceBuilder`](Builder) for a service with the provided name.
     pub fn service_builder(&self, name: &ServiceName) -> Builder<Service> {
         Builder::new(name, self.shared.clone())
     }
 
+    /// Returns all [`Service`](crate::service::Service)s that were created or opened by the
+    /// [`Node`] and are still held by at least one port factory or port, together with the
+    /// ports that were created from them. Ports that were dropped are no longer listed.
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// for service in node.opened_services()? {
+    ///     println!("{} ({:?}, {:?}): {:?}", service.name(), service.messaging_pattern(),
+    ///                                       service.role(), service.ports());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn opened_services(&self) -> Result<Vec<OpenedService>, ServiceDetailsError> {
+        let mut services = vec![];
+        self.shared
+            .registered_services()
+            .list(|service_id, name, messaging_pattern, role| {
+                services.push(OpenedService {
+                    name: name.clone(),
+                    service_id: service_id.clone(),
+                    messaging_pattern,
+                    role,
+                    ports: vec![],
+                })
+            });
+
+        for service in &mut services {
+            let dynamic_config = fail!(from self,
+                when open_dynamic_config::<Service>(self.config(), &service.service_id),
+                "Unable to acquire the opened services since the dynamic config of the service {} could not be opened.",
+                service.name);
+
+            if let Some(dynamic_config) = dynamic_config {
+                dynamic_config
+                    .get()
+                    .list_ports_of_node(self.id(), |port_id| service.ports.push(port_id));
+            }
+        }
+
+        Ok(services)
+    }
+
+    /// Returns the [`SharedMemoryRegion`]s that the ports of the [`Node`] allocated for their
+    /// samples, requests and responses. Every
+    /// [`Publisher`](crate::port::publisher::Publisher),
+    /// [`Client`](crate::port::client::Client) and [`Server`](crate::port::server::Server)
+    /// owns one region that is listed as long as the port exists.
+    ///
+    /// ```
+    /// # use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// for region in node.shm_regions() {
+    ///     println!("{:?}", region.usage_stats());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn shm_regions(&self) -> Vec<SharedMemoryRegion> {
+        let mut shm_regions = self.shared.shm_regions.lock().unwrap();
+        shm_regions.retain(|region| !region.is_released());
+        shm_regions.clone()
+    }
+
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
     /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
     /// return [`CallbackProgression::Continue`] to perform the next iteration or
@@ -922,6 +1238,77 @@ impl<Service: service::Service> Node<Service> {
         (*self.shared.monitoring_token.get()).take().unwrap()
     }
 
+    fn shutdown_service_name() -> ServiceName {
+        fatal_panic!(from "Node::shutdown_service_name()",
+            when ServiceName::__internal_new_prefixed(SHUTDOWN_SERVICE_NAME),
+            "This should never happen! The shutdown service name is not a valid service name.")
+    }
+
+    fn open_shutdown_service(
+        &self,
+    ) -> Result<crate::service::port_factory::event::PortFactory<Service>, EventOpenOrCreateError>
+    {
+        self.service_builder(&Self::shutdown_service_name())
+            .event()
+            .open_or_create()
+    }
+
+    fn create_shutdown_listener(&self) -> Result<Listener<Service>, NodeCreationFailure> {
+        let msg = "Unable to create the shutdown request listener";
+        let service = fail!(from self, when self.open_shutdown_service(),
+                with NodeCreationFailure::InternalError,
+                "{msg} since the shutdown service could not be opened or created.");
+
+        Ok(fail!(from self, when service.listener_builder().create(),
+                with NodeCreationFailure::InternalError,
+                "{msg} since the listener port could not be created."))
+    }
+
+    /// Requests a shutdown of all [`Node`]s that share the same [`Config`], including this
+    /// [`Node`]. Every [`Node`] that was created with
+    /// [`NodeBuilder::handle_shutdown_requests()`] returns
+    /// [`NodeWaitFailure::ShutdownRequest`] on its next [`Node::wait()`] call. The request
+    /// is delivered via an internal event service and does not raise any
+    /// [`Signal`](iceoryx2_bb_posix::signal::Signal).
+    pub fn signal_shutdown(&self) -> Result<(), NodeSignalShutdownFailure> {
+        let msg = "Unable to signal shutdown";
+        let service = fail!(from self, when self.open_shutdown_service(),
+                with NodeSignalShutdownFailure::UnableToOpenShutdownService,
+                "{msg} since the shutdown service could not be opened or created.");
+
+        let notifier = fail!(from self, when service.notifier_builder().create(),
+                with NodeSignalShutdownFailure::UnableToCreateNotifier,
+                "{msg} since the notifier port could not be created.");
+
+        fail!(from self, when notifier.notify(),
+                with NodeSignalShutdownFailure::UnableToNotify,
+                "{msg} since the shutdown request could not be delivered.");
+
+        Ok(())
+    }
+
+    fn handle_shutdown_request(
+        &self,
+        handler: &mut ShutdownRequestHandler<Service>,
+        error_msg: &str,
+    ) -> Result<(), NodeWaitFailure> {
+        let mut has_received_request = false;
+        if let Err(e) = handler
+            .listener
+            .try_wait_all(|_| has_received_request = true)
+        {
+            warn!(from self, "Unable to acquire pending shutdown requests ({:?}).", e);
+        }
+
+        handler.is_requested |= has_received_request;
+        if handler.is_requested {
+            fail!(from self, with NodeWaitFailure::ShutdownRequest,
+                "{error_msg} since a shutdown request was received.");
+        }
+
+        Ok(())
+    }
+
     fn handle_termination_request(&self, error_msg: &str) -> Result<(), NodeWaitFailure> {
         if self.shared.signal_handling_mode == SignalHandlingMode::HandleTerminationRequests
             && SignalHandler::termination_requested()
@@ -933,13 +1320,136 @@ impl<Service: service::Service> Node<Service> {
         Ok(())
     }
 
+    /// Registers a callback that is called with the [`NodeId`] of every [`Node`] under the
+    /// same [`Config`] that is detected as dead. No background thread is involved, the
+    /// [`Node`]s are checked in every [`Node::wait()`] call and with
+    /// [`Node::check_for_dead_nodes()`]. Every dead [`Node`] is reported once, as long as its
+    /// stale resources were not removed.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// node.on_dead_node(|node_id| {
+    ///     println!("node {:?} died, initiate failover", node_id);
+    /// });
+    ///
+    /// while node.wait(Duration::from_secs(1)).is_ok() {}
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_dead_node<F: FnMut(&NodeId) + Send + 'static>(&self, callback: F) {
+        self.lock_dead_node_monitor()
+            .callbacks
+            .push(Box::new(callback));
+    }
+
+    fn lock_dead_node_monitor(&self) -> MutexGuard<'_, DeadNodeMonitor> {
+        DeadNodeMonitor::lock(&self.dead_node_monitor)
+    }
+
+    /// Calls the callbacks registered with [`Node::on_dead_node()`] for every [`Node`] that was
+    /// detected as dead since the last check. It is called by [`Node::wait()`] and can be used
+    /// in custom event loops. On failure it returns a [`NodeListFailure`] describing why the
+    /// [`Node`]s could not be listed.
+    pub fn check_for_dead_nodes(&self) -> Result<(), NodeListFailure> {
+        // only one caller dispatches at a time, otherwise concurrent calls could report the
+        // same dead node twice. The other callers, including a callback that checks again,
+        // return since the ongoing dispatch reports the dead nodes.
+        let _dispatch_guard = match self.dead_node_dispatch.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return Ok(()),
+        };
+
+        if self.lock_dead_node_monitor().callbacks.is_empty() {
+            return Ok(());
+        }
+
+        let mut dead_nodes = Vec::new();
+        Self::list(self.config(), |node_state| {
+            if let NodeState::Dead(view) = node_state {
+                dead_nodes.push(*view.id());
+            }
+            CallbackProgression::Continue
+        })?;
+
+        // the callbacks are called without holding the lock, so that they are able to
+        // register further callbacks and a panicking callback does not poison the monitor
+        let (new_dead_nodes, mut dispatched) = {
+            let mut monitor = self.lock_dead_node_monitor();
+            let new_dead_nodes: Vec<NodeId> = dead_nodes
+                .iter()
+                .filter(|id| !monitor.reported_nodes.contains(id))
+                .copied()
+                .collect();
+            // nodes whose resources were removed are no longer listed and need no tracking
+            monitor.reported_nodes = dead_nodes;
+            let dispatched = DispatchedDeadNodeCallbacks {
+                monitor: &self.dead_node_monitor,
+                callbacks: core::mem::take(&mut monitor.callbacks),
+            };
+            (new_dead_nodes, dispatched)
+        };
+
+        for node_id in &new_dead_nodes {
+            debug!(from self, "Detected dead node {:?}.", node_id);
+            for callback in dispatched.callbacks.iter_mut() {
+                callback(node_id);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
     /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
-    /// signal was received.
+    /// signal was received. When the [`Node`] handles shutdown requests it returns
+    /// [`NodeWaitFailure::ShutdownRequest`] as soon as a shutdown was requested via
+    /// [`Node::signal_shutdown()`]. Afterwards, the callbacks registered with
+    /// [`Node::on_dead_node()`] are called for all newly detected dead [`Node`]s.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        self.wait_for_next_cycle(cycle_time)?;
+
+        warn!(from self, when self.check_for_dead_nodes(),
+            "Unable to check for dead nodes.");
+
+        Ok(())
+    }
+
+    fn wait_for_next_cycle(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
         let msg = "Unable to wait on node";
         self.handle_termination_request(msg)?;
 
+        if let Some(handler) = &self.shutdown_request_handler {
+            let mut handler = handler.lock().unwrap();
+            self.handle_shutdown_request(&mut handler, msg)?;
+
+            // some event implementations interpret a zero timeout as an infinite one
+            if cycle_time.is_zero() {
+                return Ok(());
+            }
+
+            match handler.listener.timed_wait_one(cycle_time) {
+                Ok(Some(_)) => handler.is_requested = true,
+                Ok(None) => (),
+                Err(ListenerWaitError::InterruptSignal) => {
+                    fail!(from self, with NodeWaitFailure::Interrupt,
+                        "{msg} since a interrupt signal was received.");
+                }
+                Err(e) => {
+                    fatal_panic!(from self,
+                        "Failed to wait for shutdown requests with cycle time {:?} in main event loop, caused by ({:?}).",
+                        cycle_time, e);
+                }
+            }
+
+            self.handle_termination_request(msg)?;
+            return self.handle_shutdown_request(&mut handler, msg);
+        }
+
         match nanosleep(cycle_time) {
             Ok(()) => {
                 self.handle_termination_request(msg)?;
@@ -1052,7 +1562,10 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
-    fn get_node_state(config: &Config, node_id: &NodeId) -> Result<State, NodeListFailure> {
+    pub(crate) fn get_node_state(
+        config: &Config,
+        node_id: &NodeId,
+    ) -> Result<State, NodeListFailure> {
         let my_pid = Process::from_self().id();
         let node_pid = node_id.0.pid();
 
@@ -1207,7 +1720,9 @@ impl<Service: service::Service> Node<Service> {
 pub struct NodeBuilder {
     name: Option<NodeName>,
     signal_handling_mode: SignalHandlingMode,
+    handle_shutdown_requests: bool,
     config: Option<Config>,
+    monitoring_interval: Option<Duration>,
 }
 
 impl NodeBuilder {
@@ -1230,6 +1745,19 @@ impl NodeBuilder {
         self
     }
 
+    /// Defines if the [`Node`] observes shutdown requests that are sent with
+    /// [`Node::signal_shutdown()`] by any [`Node`] that uses the same [`Config`]. When enabled,
+    /// [`Node::wait()`] returns [`NodeWaitFailure::ShutdownRequest`] after a shutdown was
+    /// requested. By default, shutdown requests are not handled.
+    ///
+    /// Every [`Node`] that handles shutdown requests occupies one
+    /// [`Listener`] port of an internal event service, hence the number of those [`Node`]s is
+    /// limited by the default event settings in the [`Config`].
+    pub fn handle_shutdown_requests(mut self, value: bool) -> Self {
+        self.handle_shutdown_requests = value;
+        self
+    }
+
     /// Sets the config of the [`Node`] that will be used to create all entities owned by the
     /// [`Node`].
     pub fn config(mut self, value: &Config) -> Self {
@@ -1237,6 +1765,18 @@ impl NodeBuilder {
         self
     }
 
+    /// Defines the monitoring interval of the [`Node`]. Observers, like a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) of a service with exclusive
+    /// ownership, reevaluate the liveness of the [`Node`] at least once per interval, hence it
+    /// bounds the time until the death of the [`Node`] is detected.
+    ///
+    /// The interval is clamped to the bounds defined in the [`Config`]. If it is not set,
+    /// the default monitoring interval of the [`Config`] is used.
+    pub fn monitoring_interval(mut self, value: Duration) -> Self {
+        self.monitoring_interval = Some(value);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1269,18 +1809,53 @@ impl NodeBuilder {
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
 
-        Ok(Node {
+        let mut node = Node {
+            shutdown_request_handler: None,
+            dead_node_monitor: Box::new(Mutex::new(DeadNodeMonitor::default())),
+            dead_node_dispatch: Mutex::new(()),
             shared: Arc::new(SharedNode {
                 id: NodeId(node_id),
                 monitoring_token: UnsafeCell::new(Some(monitoring_token)),
                 registered_services: RegisteredServices {
                     data: Mutex::new(HashMap::new()),
                 },
+                shm_regions: Mutex::new(vec![]),
+                discovery_announcer: Mutex::new(DiscoveryAnnouncer::Unopened),
                 _details_storage: details_storage,
                 signal_handling_mode: self.signal_handling_mode,
                 details,
             }),
-        })
+        };
+
+        if self.handle_shutdown_requests {
+            node.shutdown_request_handler = Some(Box::new(Mutex::new(ShutdownRequestHandler {
+                listener: node.create_shutdown_listener()?,
+                is_requested: false,
+            })));
+        }
+
+        Ok(node)
+    }
+
+    fn effective_monitoring_interval(&self, config: &Config) -> Duration {
+        let node_config = &config.global.node;
+        let monitoring_interval = self
+            .monitoring_interval
+            .unwrap_or(node_config.monitoring_interval);
+
+        if monitoring_interval < node_config.min_monitoring_interval {
+            warn!(from self,
+                "The monitoring interval of {:?} is smaller than the minimum of {:?} and is clamped to the minimum.",
+                monitoring_interval, node_config.min_monitoring_interval);
+            node_config.min_monitoring_interval
+        } else if node_config.max_monitoring_interval < monitoring_interval {
+            warn!(from self,
+                "The monitoring interval of {:?} is larger than the maximum of {:?} and is clamped to the maximum.",
+                monitoring_interval, node_config.max_monitoring_interval);
+            node_config.max_monitoring_interval
+        } else {
+            monitoring_interval
+        }
     }
 
     fn create_token<Service: service::Service>(
@@ -1316,7 +1891,11 @@ impl NodeBuilder {
         node_id: &NodeId,
     ) -> Result<(Service::StaticStorage, NodeDetails), NodeCreationFailure> {
         let msg = "Unable to create node details storage";
-        let details = NodeDetails::new(&self.name, config);
+        let details = NodeDetails::new(
+            &self.name,
+            config,
+            self.effective_monitoring_interval(config),
+        );
 
         let details_config = node_details_config::<Service>(&details.config, node_id);
         let serialized_details = match <Service::ConfigSerializer>::serialize(&details) {
@@ -0,0 +1,269 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`NodeGroup`] manages the lifecycle of multiple [`Node`]s that shall be started and
+//! stopped together. Additionally, [`Node`]s of other processes can be watched, so that the
+//! group observes when one of its members terminates. When a member dies, its stale resources
+//! are removed right away, which removes its ports from all services the other members opened.
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::node::node_group::NodeGroup;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let mut group = NodeGroup::<ipc::Service>::new(Config::global_config());
+//! group.add_node(NodeBuilder::new().name(&"camera".try_into()?));
+//! group.add_node(NodeBuilder::new().name(&"lidar".try_into()?));
+//!
+//! group.start_all()?;
+//! let camera = &group.nodes()[0];
+//! let service = camera
+//!     .service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! group.stop_all();
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError};
+use iceoryx2_bb_posix::signal::SignalHandler;
+
+use crate::config::Config;
+use crate::node::{
+    Node, NodeBuilder, NodeCreationFailure, NodeId, NodeState, NodeView, NodeWaitFailure,
+};
+use crate::service;
+
+/// Groups multiple [`Node`]s with a shared lifecycle. The [`Node`]s are added as
+/// [`NodeBuilder`]s and are all created with [`NodeGroup::start_all()`] and all dropped with
+/// [`NodeGroup::stop_all()`]. [`Node`]s of other processes can be added with
+/// [`NodeGroup::watch_node()`] and are observed by [`NodeGroup::wait_any_terminated()`] and
+/// [`NodeGroup::wait_all_terminated()`].
+///
+/// All [`Node`]s of the group use the [`Config`] of the group.
+#[derive(Debug)]
+pub struct NodeGroup<Service: service::Service> {
+    config: Config,
+    builders: Vec<NodeBuilder>,
+    nodes: Vec<Node<Service>>,
+    watched_nodes: Vec<NodeId>,
+}
+
+impl<Service: service::Service> NodeGroup<Service> {
+    /// Creates a new empty [`NodeGroup`] whose [`Node`]s use the provided [`Config`].
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            builders: Vec::new(),
+            nodes: Vec::new(),
+            watched_nodes: Vec::new(),
+        }
+    }
+
+    /// Returns the [`Config`] of the [`NodeGroup`].
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Adds a [`Node`] to the [`NodeGroup`]. The [`Node`] is created with the next
+    /// [`NodeGroup::start_all()`] call. The [`Config`] of the [`NodeBuilder`] is replaced with
+    /// the [`Config`] of the group.
+    pub fn add_node(&mut self, builder: NodeBuilder) {
+        self.builders.push(builder.config(&self.config));
+    }
+
+    /// Adds the [`Node`] with the provided [`NodeId`], usually owned by another process, to
+    /// the members that are observed by [`NodeGroup::wait_any_terminated()`] and
+    /// [`NodeGroup::wait_all_terminated()`]. The [`Node`] must use the same [`Config`] as
+    /// the group.
+    pub fn watch_node(&mut self, node_id: NodeId) {
+        if !self.watched_nodes.contains(&node_id) {
+            self.watched_nodes.push(node_id);
+        }
+    }
+
+    /// Returns the number of [`Node`]s that were added with [`NodeGroup::add_node()`].
+    pub fn len(&self) -> usize {
+        self.builders.len()
+    }
+
+    /// Returns true when no [`Node`] was added with [`NodeGroup::add_node()`].
+    pub fn is_empty(&self) -> bool {
+        self.builders.is_empty()
+    }
+
+    /// Returns true when all added [`Node`]s are running.
+    pub fn is_running(&self) -> bool {
+        !self.nodes.is_empty() && self.nodes.len() == self.builders.len()
+    }
+
+    /// Returns the running [`Node`]s in the order they were added.
+    pub fn nodes(&self) -> &[Node<Service>] {
+        &self.nodes
+    }
+
+    /// Returns the [`NodeId`]s of all running [`Node`]s followed by the [`NodeId`]s of all
+    /// watched [`Node`]s that have not yet terminated.
+    pub fn member_ids(&self) -> Vec<NodeId> {
+        self.nodes
+            .iter()
+            .map(|node| *node.id())
+            .chain(self.watched_nodes.iter().copied())
+            .collect()
+    }
+
+    /// Creates all [`Node`]s that are not yet running. When one [`Node`] cannot be created,
+    /// all [`Node`]s that were created in this call are dropped again and the
+    /// [`NodeCreationFailure`] is returned.
+    pub fn start_all(&mut self) -> Result<(), NodeCreationFailure> {
+        let number_of_running_nodes = self.nodes.len();
+        for builder in self.builders.iter().skip(number_of_running_nodes) {
+            match builder.clone().create::<Service>() {
+                Ok(node) => self.nodes.push(node),
+                Err(e) => {
+                    self.stop_nodes(number_of_running_nodes);
+                    fail!(from self, with e,
+                        "Unable to start all nodes of the group since a node could not be created ({:?}).", e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops all running [`Node`]s in the reverse order of their creation.
+    pub fn stop_all(&mut self) {
+        self.stop_nodes(0);
+    }
+
+    /// Blocks until at least one member of the [`NodeGroup`] terminated and returns the
+    /// [`NodeId`]s of all terminated members. A member terminated when it no longer exists or
+    /// when it died, in which case its stale resources are removed. Terminated members are
+    /// no longer watched. The running [`Node`]s of the group stay alive until
+    /// [`NodeGroup::stop_all()`] is called.
+    ///
+    /// The members are checked once per `cycle_time`. It returns a [`NodeWaitFailure`] when a
+    /// termination signal or, for a first [`Node`] that handles shutdown requests, a shutdown
+    /// request was received.
+    pub fn wait_any_terminated(
+        &mut self,
+        cycle_time: Duration,
+    ) -> Result<Vec<NodeId>, NodeWaitFailure> {
+        loop {
+            let terminated_nodes = self.remove_terminated_members();
+            if !terminated_nodes.is_empty() {
+                return Ok(terminated_nodes);
+            }
+
+            self.wait_for_next_cycle(cycle_time)?;
+        }
+    }
+
+    /// Blocks until all watched members of the [`NodeGroup`] terminated, see
+    /// [`NodeGroup::wait_any_terminated()`]. Since the running [`Node`]s of the group are
+    /// members as well, it only returns successfully when the group is stopped.
+    pub fn wait_all_terminated(&mut self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        loop {
+            self.remove_terminated_members();
+            if self.nodes.is_empty() && self.watched_nodes.is_empty() {
+                return Ok(());
+            }
+
+            self.wait_for_next_cycle(cycle_time)?;
+        }
+    }
+
+    fn stop_nodes(&mut self, number_of_remaining_nodes: usize) {
+        while self.nodes.len() > number_of_remaining_nodes {
+            self.nodes.pop();
+        }
+    }
+
+    fn remove_terminated_members(&mut self) -> Vec<NodeId> {
+        if self.watched_nodes.is_empty() {
+            return Vec::new();
+        }
+
+        let mut existing_nodes = Vec::new();
+        let mut dead_nodes = Vec::new();
+        if let Err(e) = Node::<Service>::list(&self.config, |node_state| {
+            let node_id = *node_state.node_id();
+            if self.watched_nodes.contains(&node_id) {
+                existing_nodes.push(node_id);
+                if let NodeState::Dead(view) = node_state {
+                    dead_nodes.push(view);
+                }
+            }
+            CallbackProgression::Continue
+        }) {
+            warn!(from self, "Unable to acquire the state of the members ({:?}).", e);
+            return Vec::new();
+        }
+
+        for view in dead_nodes {
+            let node_id = *view.id();
+            debug!(from self, "The member {:?} of the group died.", node_id);
+            existing_nodes.retain(|id| *id != node_id);
+            if let Err(e) = view.remove_stale_resources() {
+                warn!(from self,
+                    "Unable to remove the stale resources of the dead member {:?} ({:?}).",
+                    node_id, e);
+            }
+        }
+
+        let (alive_nodes, terminated_nodes) = self
+            .watched_nodes
+            .iter()
+            .partition(|node_id| existing_nodes.contains(node_id));
+        self.watched_nodes = alive_nodes;
+        terminated_nodes
+    }
+
+    fn wait_for_next_cycle(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
+        if let Some(node) = self.nodes.first() {
+            return node.wait(cycle_time);
+        }
+
+        let msg = "Unable to wait on node group";
+        match nanosleep(cycle_time) {
+            Ok(()) => (),
+            Err(NanosleepError::InterruptedBySignal(_)) => {
+                fail!(from self, with NodeWaitFailure::Interrupt,
+                    "{msg} since a interrupt signal was received.");
+            }
+            Err(v) => {
+                fatal_panic!(from self,
+                    "Failed to wait with cycle time {:?}, caused by ({:?}).", cycle_time, v);
+            }
+        }
+
+        if SignalHandler::termination_requested() {
+            fail!(from self, with NodeWaitFailure::TerminationRequest,
+                "{msg} since a termination request was received.");
+        }
+
+        Ok(())
+    }
+}
+
+impl<Service: service::Service> Drop for NodeGroup<Service> {
+    fn drop(&mut self) {
+        self.stop_all();
+    }
+}
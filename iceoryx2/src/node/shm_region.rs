@@ -0,0 +1,191 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! for region in node.shm_regions() {
+//!     let stats = region.usage_stats();
+//!     println!("{}: {} of {} bytes used", stats.region_name(), stats.used_bytes(),
+//!              stats.total_bytes());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+extern crate alloc;
+use alloc::sync::{Arc, Weak};
+
+/// The usage counters of a data segment. They are updated by the owning port and can be read
+/// from any thread.
+#[derive(Debug, Default)]
+pub(crate) struct ShmRegionUsage {
+    total_bytes: IoxAtomicUsize,
+    current_segment_bytes: IoxAtomicUsize,
+    used_bytes: IoxAtomicUsize,
+    used_bytes_in_current_segment: IoxAtomicUsize,
+}
+
+impl ShmRegionUsage {
+    pub(crate) fn total_bytes(&self) -> usize {
+        self.total_bytes.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn update_capacity(&self, total_bytes: usize, current_segment_bytes: usize) {
+        self.total_bytes.store(total_bytes, Ordering::Relaxed);
+        self.current_segment_bytes
+            .store(current_segment_bytes, Ordering::Relaxed);
+    }
+
+    /// Called when allocations are served by a newly created segment. The chunks that are
+    /// still in use remain in the previous segments.
+    pub(crate) fn start_new_segment(&self) {
+        self.used_bytes_in_current_segment
+            .store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn acquire(&self, bytes: usize) {
+        self.used_bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.used_bytes_in_current_segment
+            .fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn release(&self, bytes: usize, is_current_segment: bool) {
+        self.used_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        if is_current_segment {
+            self.used_bytes_in_current_segment
+                .fetch_sub(bytes, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Snapshot of the memory usage of a [`SharedMemoryRegion`], acquired with
+/// [`SharedMemoryRegion::usage_stats()`]. All sizes are measured in bytes of the chunks the
+/// region provides for samples, requests or responses. Since memory is handed out in chunks,
+/// a chunk counts as used completely, even when the payload occupies only a part of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShmUsageStats {
+    region_name: FileName,
+    total_bytes: usize,
+    used_bytes: usize,
+    free_bytes: usize,
+    fragmentation_ratio: f64,
+}
+
+impl ShmUsageStats {
+    /// Returns the name of the [`SharedMemoryRegion`].
+    pub fn region_name(&self) -> &FileName {
+        &self.region_name
+    }
+
+    /// Returns the number of bytes of all chunks of the region.
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Returns the number of bytes of all chunks that are currently loaned or sent and not yet
+    /// released by all receivers.
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes
+    }
+
+    /// Returns the number of bytes of all unused chunks.
+    pub fn free_bytes(&self) -> usize {
+        self.free_bytes
+    }
+
+    /// Returns the share of the free bytes, between `0.0` and `1.0`, that cannot be used for
+    /// new allocations. When a dynamic region grows, it creates a new segment that serves all
+    /// new allocations while the previous segment is kept until its last chunk is released.
+    /// The free chunks of such a previous segment are fragmented. Regions that never grew and
+    /// regions without free bytes have a ratio of `0.0`.
+    pub fn fragmentation_ratio(&self) -> f64 {
+        self.fragmentation_ratio
+    }
+}
+
+/// A shared memory region a port of a [`Node`](crate::node::Node) allocated to provide its
+/// samples, requests or responses. Acquired with
+/// [`Node::shm_regions()`](crate::node::Node::shm_regions()).
+///
+/// The [`SharedMemoryRegion`] does not keep the underlying memory alive. When the owning port
+/// is dropped, the region is released and reports no memory.
+#[derive(Debug, Clone)]
+pub struct SharedMemoryRegion {
+    name: FileName,
+    usage: Weak<ShmRegionUsage>,
+}
+
+impl SharedMemoryRegion {
+    pub(crate) fn new(name: &FileName, usage: &Arc<ShmRegionUsage>) -> Self {
+        Self {
+            name: name.clone(),
+            usage: Arc::downgrade(usage),
+        }
+    }
+
+    /// Returns the name of the [`SharedMemoryRegion`].
+    pub fn name(&self) -> &FileName {
+        &self.name
+    }
+
+    /// Returns true when the owning port was dropped and the memory was released.
+    pub fn is_released(&self) -> bool {
+        self.usage.strong_count() == 0
+    }
+
+    /// Returns the current [`ShmUsageStats`] of the [`SharedMemoryRegion`]. When the region
+    /// was released, all sizes are zero.
+    pub fn usage_stats(&self) -> ShmUsageStats {
+        let mut stats = ShmUsageStats {
+            region_name: self.name.clone(),
+            total_bytes: 0,
+            used_bytes: 0,
+            free_bytes: 0,
+            fragmentation_ratio: 0.0,
+        };
+
+        let usage = match self.usage.upgrade() {
+            Some(usage) => usage,
+            None => return stats,
+        };
+
+        stats.total_bytes = usage.total_bytes.load(Ordering::Relaxed);
+        stats.used_bytes = usage.used_bytes.load(Ordering::Relaxed);
+        stats.free_bytes = stats.total_bytes.saturating_sub(stats.used_bytes);
+
+        let usable_free_bytes = usage
+            .current_segment_bytes
+            .load(Ordering::Relaxed)
+            .saturating_sub(usage.used_bytes_in_current_segment.load(Ordering::Relaxed));
+        if stats.free_bytes != 0 {
+            stats.fragmentation_ratio =
+                stats.free_bytes.saturating_sub(usable_free_bytes) as f64 / stats.free_bytes as f64;
+        }
+
+        stats
+    }
+}
@@ -0,0 +1,602 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::stale_resources::StaleResourceKind;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let config = Config::global_config();
+//!
+//! // collect the stale resources without removing anything
+//! let mut report = Node::<ipc::Service>::stale_resource_report(config)?;
+//! for resource in report.resources() {
+//!     println!("{:?} {} of node {:?}: {:?} bytes", resource.kind(), resource.name(),
+//!              resource.node_id(), resource.size());
+//! }
+//!
+//! // remove only the orphaned data segments, a dead node can only be removed together with
+//! // everything it owns
+//! report.retain(|resource| {
+//!     resource.kind() == StaleResourceKind::DataSegment && resource.node_id().is_none()
+//! });
+//! for removal in Node::<ipc::Service>::remove_reported_stale_resources(config, &report) {
+//!     println!("{} removed: {:?}", removal.resource().name(), removal.result());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+use std::collections::{HashMap, HashSet};
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_log::{debug, fail, fatal_panic};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptListError, NamedConceptMgmt, NamedConceptRemoveError,
+};
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageBuilder};
+
+use crate::config::Config;
+use crate::port::port_identifiers::UniquePortId;
+use crate::service::config_scheme::{data_segment_config, static_config_storage_config};
+use crate::service::naming_scheme::data_segment_name;
+use crate::service::service_id::ServiceId;
+use crate::service::{self, open_dynamic_config, ServiceListError};
+
+use super::{Node, NodeCleanupFailure, NodeId, NodeListFailure, NodeState, NodeView};
+
+/// Defines what kind of artifact a [`StaleResource`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StaleResourceKind {
+    /// The monitoring token, the details and the service tags of a dead [`Node`].
+    DeadNode,
+    /// The data segment of a [`Publisher`](crate::port::publisher::Publisher),
+    /// [`Client`](crate::port::client::Client) or [`Server`](crate::port::server::Server)
+    /// that was owned by a dead [`Node`]. An orphaned data segment, whose port is not
+    /// registered at any [`Service`](crate::service::Service) anymore and whose creating
+    /// process is dead, is not owned by any [`Node`].
+    DataSegment,
+    /// The static and dynamic configuration of a [`Service`](crate::service::Service) that
+    /// is only attached to dead [`Node`]s.
+    ServiceFiles,
+}
+
+/// A stale artifact of a dead [`Node`], reported by [`Node::stale_resource_report()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleResource {
+    kind: StaleResourceKind,
+    name: FileName,
+    node_id: Option<NodeId>,
+    service_id: Option<ServiceId>,
+    size: Option<u64>,
+}
+
+impl StaleResource {
+    /// Returns the [`StaleResourceKind`].
+    pub fn kind(&self) -> StaleResourceKind {
+        self.kind
+    }
+
+    /// Returns the name of the underlying resource.
+    pub fn name(&self) -> &FileName {
+        &self.name
+    }
+
+    /// Returns the [`NodeId`] of the dead [`Node`] that owns the resource. Orphaned
+    /// [`StaleResourceKind::DataSegment`]s are not owned by any [`Node`].
+    pub fn node_id(&self) -> Option<&NodeId> {
+        self.node_id.as_ref()
+    }
+
+    /// Returns the [`ServiceId`] of the [`Service`](crate::service::Service) the resource
+    /// belongs to. [`StaleResourceKind::DeadNode`] resources and orphaned
+    /// [`StaleResourceKind::DataSegment`]s do not belong to a known service.
+    pub fn service_id(&self) -> Option<&ServiceId> {
+        self.service_id.as_ref()
+    }
+
+    /// Returns the size of the resource in bytes. For a [`StaleResourceKind::DeadNode`] it is
+    /// the size of the node details, for a [`StaleResourceKind::DataSegment`] the size of the
+    /// shared memory and for [`StaleResourceKind::ServiceFiles`] the size of the static
+    /// service config. Returns [`None`] when the size could not be acquired.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    fn is_same_resource(&self, other: &StaleResource) -> bool {
+        self.kind == other.kind && self.name == other.name && self.node_id == other.node_id
+    }
+}
+
+/// Contains all [`StaleResource`]s that were found by [`Node::stale_resource_report()`].
+/// Acquiring the report does not remove anything. The report, or a filtered version of it,
+/// can be passed to [`Node::remove_reported_stale_resources()`] to remove the resources.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StaleResourceReport {
+    resources: Vec<StaleResource>,
+}
+
+impl StaleResourceReport {
+    /// Returns all reported [`StaleResource`]s.
+    pub fn resources(&self) -> &[StaleResource] {
+        &self.resources
+    }
+
+    /// Returns the number of reported [`StaleResource`]s.
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Returns true when no [`StaleResource`] was found, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    /// Returns the sum of the sizes of all [`StaleResource`]s with a known size.
+    pub fn total_size(&self) -> u64 {
+        self.resources.iter().filter_map(|r| r.size).sum()
+    }
+
+    /// Keeps only the [`StaleResource`]s for which the predicate returns true.
+    pub fn retain<F: FnMut(&StaleResource) -> bool>(&mut self, predicate: F) {
+        self.resources.retain(predicate)
+    }
+
+    fn contains(&self, resource: &StaleResource) -> bool {
+        self.resources.iter().any(|r| r.is_same_resource(resource))
+    }
+}
+
+/// Describes why a [`StaleResource`] could not be removed by
+/// [`Node::remove_reported_stale_resources()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleResourceRemovalFailure {
+    /// The [`StaleResourceReport`] does not contain all [`StaleResource`]s of the dead
+    /// [`Node`] that owns the resource, or the [`StaleResourceKind::ServiceFiles`] of the
+    /// [`Node`] are still used by a dead [`Node`] that is not part of the report. Nothing
+    /// of the [`Node`] was removed.
+    IncompleteNodeSelection,
+    /// The process received an interrupt signal while removing the resource.
+    Interrupt,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalError,
+    /// The resource could not be removed since the process does not have sufficient
+    /// permissions.
+    InsufficientPermissions,
+    /// The dead [`Node`] that owns the resource was using a different iceoryx2 version.
+    VersionMismatch,
+}
+
+impl From<NodeCleanupFailure> for StaleResourceRemovalFailure {
+    fn from(value: NodeCleanupFailure) -> Self {
+        match value {
+            NodeCleanupFailure::Interrupt => StaleResourceRemovalFailure::Interrupt,
+            NodeCleanupFailure::InternalError => StaleResourceRemovalFailure::InternalError,
+            NodeCleanupFailure::InsufficientPermissions => {
+                StaleResourceRemovalFailure::InsufficientPermissions
+            }
+            NodeCleanupFailure::VersionMismatch => StaleResourceRemovalFailure::VersionMismatch,
+        }
+    }
+}
+
+impl From<NodeListFailure> for StaleResourceRemovalFailure {
+    fn from(value: NodeListFailure) -> Self {
+        match value {
+            NodeListFailure::Interrupt => StaleResourceRemovalFailure::Interrupt,
+            NodeListFailure::InternalError => StaleResourceRemovalFailure::InternalError,
+            NodeListFailure::InsufficientPermissions => {
+                StaleResourceRemovalFailure::InsufficientPermissions
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for StaleResourceRemovalFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "StaleResourceRemovalFailure::{self:?}")
+    }
+}
+
+impl core::error::Error for StaleResourceRemovalFailure {}
+
+/// The outcome of the removal of a single [`StaleResource`], returned by
+/// [`Node::remove_reported_stale_resources()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleResourceRemoval {
+    resource: StaleResource,
+    result: Result<bool, StaleResourceRemovalFailure>,
+}
+
+impl StaleResourceRemoval {
+    /// Returns the [`StaleResource`] that shall be removed.
+    pub fn resource(&self) -> &StaleResource {
+        &self.resource
+    }
+
+    /// Returns the result of the removal. `Ok(true)` when the resource was removed by this
+    /// call and `Ok(false)` when it was removed by someone else.
+    pub fn result(&self) -> Result<bool, StaleResourceRemovalFailure> {
+        self.result
+    }
+}
+
+impl<Service: service::Service> Node<Service> {
+    /// Scans the namespace of the provided [`Config`] for stale resources of dead [`Node`]s
+    /// and for orphaned data segments and returns them as [`StaleResourceReport`] without
+    /// removing anything.
+    pub fn stale_resource_report(config: &Config) -> Result<StaleResourceReport, NodeListFailure> {
+        let mut report = StaleResourceReport::default();
+        let mut reported_services = Vec::<ServiceId>::new();
+
+        Node::<Service>::list(config, |node_state| {
+            if let NodeState::Dead(dead_node) = node_state {
+                report_dead_node::<Service>(
+                    config,
+                    dead_node.id(),
+                    &mut reported_services,
+                    &mut report,
+                );
+            }
+            CallbackProgression::Continue
+        })?;
+
+        report_orphaned_data_segments::<Service>(config, &mut report)?;
+
+        Ok(report)
+    }
+
+    /// Removes the [`StaleResource`]s of a [`StaleResourceReport`] acquired with
+    /// [`Node::stale_resource_report()`] and returns the outcome for every resource.
+    ///
+    /// The resources of a dead [`Node`] can only be removed together. When the report does
+    /// not contain every resource of a dead [`Node`], or when its
+    /// [`StaleResourceKind::ServiceFiles`] are still used by another dead [`Node`] that is
+    /// not part of the report, nothing of the [`Node`] is removed and all of its resources
+    /// fail with [`StaleResourceRemovalFailure::IncompleteNodeSelection`]. Orphaned
+    /// [`StaleResourceKind::DataSegment`]s are removed individually.
+    pub fn remove_reported_stale_resources(
+        config: &Config,
+        report: &StaleResourceReport,
+    ) -> Vec<StaleResourceRemoval> {
+        let removal = |resource: &StaleResource, result| StaleResourceRemoval {
+            resource: resource.clone(),
+            result,
+        };
+
+        let current_report = match Node::<Service>::stale_resource_report(config) {
+            Ok(v) => v,
+            Err(e) => {
+                return report
+                    .resources
+                    .iter()
+                    .map(|resource| removal(resource, Err(e.into())))
+                    .collect()
+            }
+        };
+
+        let selected_nodes: HashSet<NodeId> = report
+            .resources
+            .iter()
+            .filter_map(|resource| resource.node_id)
+            .collect();
+        let mut results = HashMap::<NodeId, Result<bool, StaleResourceRemovalFailure>>::new();
+
+        report
+            .resources
+            .iter()
+            .map(|resource| match resource.node_id {
+                Some(node_id) => {
+                    let result = *results.entry(node_id).or_insert_with(|| {
+                        if is_complete_node_selection::<Service>(
+                            config,
+                            &node_id,
+                            report,
+                            &current_report,
+                            &selected_nodes,
+                        ) {
+                            remove_dead_node::<Service>(config, &node_id).map_err(|e| e.into())
+                        } else {
+                            Err(StaleResourceRemovalFailure::IncompleteNodeSelection)
+                        }
+                    });
+                    removal(resource, result)
+                }
+                None => {
+                    let result = if current_report.contains(resource) {
+                        remove_orphaned_data_segment::<Service>(config, &resource.name)
+                    } else {
+                        Ok(false)
+                    };
+                    removal(resource, result)
+                }
+            })
+            .collect()
+    }
+}
+
+fn is_complete_node_selection<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+    report: &StaleResourceReport,
+    current_report: &StaleResourceReport,
+    selected_nodes: &HashSet<NodeId>,
+) -> bool {
+    current_report
+        .resources
+        .iter()
+        .filter(|resource| resource.node_id.as_ref() == Some(node_id))
+        .all(|resource| {
+            if !report.contains(resource) {
+                return false;
+            }
+
+            match (resource.kind, &resource.service_id) {
+                (StaleResourceKind::ServiceFiles, Some(service_id)) => {
+                    !is_used_by_unselected_dead_node::<Service>(config, service_id, selected_nodes)
+                }
+                _ => true,
+            }
+        })
+}
+
+fn is_used_by_unselected_dead_node<Service: service::Service>(
+    config: &Config,
+    service_id: &ServiceId,
+    selected_nodes: &HashSet<NodeId>,
+) -> bool {
+    let dynamic_config = match open_dynamic_config::<Service>(config, service_id) {
+        Ok(Some(v)) => v,
+        _ => return false,
+    };
+
+    let mut is_used = false;
+    dynamic_config.get().list_node_ids(|id| {
+        if !selected_nodes.contains(id)
+            && matches!(
+                NodeState::<Service>::new(id, config),
+                Ok(Some(NodeState::Dead(_)))
+            )
+        {
+            is_used = true;
+            return CallbackProgression::Stop;
+        }
+        CallbackProgression::Continue
+    });
+
+    is_used
+}
+
+fn remove_dead_node<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+) -> Result<bool, NodeCleanupFailure> {
+    match NodeState::<Service>::new(node_id, config) {
+        Ok(Some(NodeState::Dead(dead_node))) => dead_node.remove_stale_resources(),
+        Ok(None) => Ok(false),
+        Ok(Some(NodeState::Inaccessible(_))) => Err(NodeCleanupFailure::InsufficientPermissions),
+        Ok(Some(_)) => Err(NodeCleanupFailure::InternalError),
+        Err(NodeListFailure::Interrupt) => Err(NodeCleanupFailure::Interrupt),
+        Err(NodeListFailure::InsufficientPermissions) => {
+            Err(NodeCleanupFailure::InsufficientPermissions)
+        }
+        Err(NodeListFailure::InternalError) => Err(NodeCleanupFailure::InternalError),
+    }
+}
+
+fn remove_orphaned_data_segment<Service: service::Service>(
+    config: &Config,
+    name: &FileName,
+) -> Result<bool, StaleResourceRemovalFailure> {
+    match unsafe {
+        <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            name,
+            &data_segment_config::<Service>(config),
+        )
+    } {
+        Ok(v) => Ok(v),
+        Err(NamedConceptRemoveError::InsufficientPermissions) => {
+            fail!(from "Node::remove_reported_stale_resources()",
+                with StaleResourceRemovalFailure::InsufficientPermissions,
+                "Unable to remove the orphaned data segment {} due to insufficient permissions.", name);
+        }
+        Err(e) => {
+            fail!(from "Node::remove_reported_stale_resources()",
+                with StaleResourceRemovalFailure::InternalError,
+                "Unable to remove the orphaned data segment {} due to an internal error ({:?}).", name, e);
+        }
+    }
+}
+
+fn data_segment_port_id(port_id: UniquePortId) -> Option<u128> {
+    match port_id {
+        UniquePortId::Publisher(id) => Some(id.value()),
+        UniquePortId::Client(id) => Some(id.value()),
+        UniquePortId::Server(id) => Some(id.value()),
+        _ => None,
+    }
+}
+
+fn report_dead_node<Service: service::Service>(
+    config: &Config,
+    node_id: &NodeId,
+    reported_services: &mut Vec<ServiceId>,
+    report: &mut StaleResourceReport,
+) {
+    let origin = format!("Node::stale_resource_report({node_id:?})");
+    let name = fatal_panic!(from origin, when FileName::new(node_id.0.value().to_string().as_bytes()),
+                "This should never happen! The NodeId is not a valid file name.");
+
+    report.resources.push(StaleResource {
+        kind: StaleResourceKind::DeadNode,
+        name,
+        node_id: Some(*node_id),
+        service_id: None,
+        size: match Node::<Service>::open_node_storage(config, node_id) {
+            Ok(Some(storage)) => Some(storage.len()),
+            _ => None,
+        },
+    });
+
+    let result = Node::<Service>::service_tags(config, node_id, |service_id| {
+        let dynamic_config = match open_dynamic_config::<Service>(config, service_id) {
+            Ok(v) => v,
+            Err(e) => {
+                debug!(from origin, "Unable to open the dynamic config of the service {:?} ({:?}).",
+                    service_id, e);
+                return CallbackProgression::Continue;
+            }
+        };
+
+        let mut has_alive_nodes = false;
+        if let Some(dynamic_config) = &dynamic_config {
+            dynamic_config.get().list_ports_of_node(node_id, |port_id| {
+                if let Some(port_id) = data_segment_port_id(port_id) {
+                    let name = data_segment_name(port_id);
+                    report.resources.push(StaleResource {
+                        kind: StaleResourceKind::DataSegment,
+                        size: data_segment_size::<Service>(config, &name),
+                        name,
+                        node_id: Some(*node_id),
+                        service_id: Some(service_id.clone()),
+                    });
+                }
+            });
+
+            dynamic_config.get().list_node_ids(|id| {
+                if !matches!(
+                    NodeState::<Service>::new(id, config),
+                    Ok(Some(NodeState::Dead(_))) | Ok(None)
+                ) {
+                    has_alive_nodes = true;
+                    return CallbackProgression::Stop;
+                }
+                CallbackProgression::Continue
+            });
+        }
+
+        if !has_alive_nodes && !reported_services.contains(service_id) {
+            reported_services.push(service_id.clone());
+            report.resources.push(StaleResource {
+                kind: StaleResourceKind::ServiceFiles,
+                name: service_id.0.clone().into(),
+                node_id: Some(*node_id),
+                service_id: Some(service_id.clone()),
+                size: static_service_config_size::<Service>(config, service_id),
+            });
+        }
+
+        CallbackProgression::Continue
+    });
+
+    if let Err(e) = result {
+        debug!(from origin, "Unable to acquire all services of the dead node ({:?}).", e);
+    }
+}
+
+fn report_orphaned_data_segments<Service: service::Service>(
+    config: &Config,
+    report: &mut StaleResourceReport,
+) -> Result<(), NodeListFailure> {
+    let msg = "Unable to report the orphaned data segments";
+    let origin = "Node::stale_resource_report()";
+
+    let segments = fail!(from origin,
+        when <Service::SharedMemory as NamedConceptMgmt>::list_cfg(&data_segment_config::<Service>(config)),
+        map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+        unmatched NodeListFailure::InternalError,
+        "{} since the data segments could not be listed.", msg);
+
+    if segments.is_empty() {
+        return Ok(());
+    }
+
+    // every data segment of a port that is registered at a service is either in use or owned
+    // by a dead node and therefore already reported with the node
+    let mut registered_ports = HashSet::<u128>::new();
+    fail!(from origin,
+        when Service::list(config, |service| {
+            if let Ok(Some(dynamic_config)) =
+                open_dynamic_config::<Service>(config, service.static_details.service_id())
+            {
+                dynamic_config.get().list_node_ids(|node_id| {
+                    dynamic_config.get().list_ports_of_node(node_id, |port_id| {
+                        if let Some(port_id) = data_segment_port_id(port_id) {
+                            registered_ports.insert(port_id);
+                        }
+                    });
+                    CallbackProgression::Continue
+                });
+            }
+            CallbackProgression::Continue
+        }),
+        map ServiceListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+        unmatched NodeListFailure::InternalError,
+        "{} since the services could not be listed.", msg);
+
+    for name in segments {
+        let port_id = match name.to_string().parse::<u128>() {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        // a port registers itself at the service after its data segment was created, the
+        // creating process must be dead before the segment is considered orphaned
+        if registered_ports.contains(&port_id)
+            || Process::from_pid(UniqueSystemId::from(port_id).pid()).is_alive()
+        {
+            continue;
+        }
+
+        report.resources.push(StaleResource {
+            kind: StaleResourceKind::DataSegment,
+            size: data_segment_size::<Service>(config, &name),
+            name,
+            node_id: None,
+            service_id: None,
+        });
+    }
+
+    Ok(())
+}
+
+fn data_segment_size<Service: service::Service>(config: &Config, name: &FileName) -> Option<u64> {
+    <Service::SharedMemory as SharedMemory<_>>::Builder::new(name)
+        .config(&data_segment_config::<Service>(config))
+        .timeout(Duration::ZERO)
+        .open()
+        .ok()
+        .map(|memory| memory.size() as u64)
+}
+
+fn static_service_config_size<Service: service::Service>(
+    config: &Config,
+    service_id: &ServiceId,
+) -> Option<u64> {
+    <<Service::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        Service::StaticStorage,
+    >>::new(&service_id.0.clone().into())
+    .config(&static_config_storage_config::<Service>(config))
+    .has_ownership(false)
+    .open(Duration::ZERO)
+    .ok()
+    .map(|storage| storage.len())
+}
@@ -57,17 +57,24 @@
 
 use core::ops::Deref;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 use core::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::port::client::ClientSharedState;
 use crate::port::details::chunk::Chunk;
 use crate::port::details::chunk_details::ChunkDetails;
+use crate::port::details::padded_payload::PaddedPayload;
 use crate::raw_sample::RawSample;
 use crate::service::builder::CustomPayloadMarker;
+use crate::service::header::request_response::monotonic_timestamp_ns;
+use crate::waitset::Attachable;
 use crate::{port::ReceiveError, request_mut::RequestMut, response::Response, service};
 
 /// Represents an active connection to all [`Server`](crate::port::server::Server)
@@ -87,6 +94,9 @@ pub struct PendingResponse<
     pub(crate) request:
         RequestMut<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
     pub(crate) number_of_server_connections: usize,
+    pub(crate) response_deadline: Option<Duration>,
+    pub(crate) first_response_timestamp_ns: IoxAtomicU64,
+    pub(crate) latest_response_timestamp_ns: IoxAtomicU64,
     pub(crate) _service: PhantomData<Service>,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
@@ -105,6 +115,38 @@ where
 {
 }
 
+/// Can only be attached to a [`WaitSet`](crate::waitset::WaitSet) when the
+/// [`Service`](crate::service::Service) was created with
+/// [`enable_notify_on_delivery()`](crate::service::builder::request_response::Builder::enable_notify_on_delivery()),
+/// otherwise attaching it fails with
+/// [`WaitSetAttachmentError::UnsupportedAttachment`](crate::waitset::WaitSetAttachmentError::UnsupportedAttachment).
+/// All [`PendingResponse`]s of a [`Client`](crate::port::client::Client) share the same
+/// [`FileDescriptor`], therefore a delivered [`Response`] wakes up every attached
+/// [`PendingResponse`] of the [`Client`](crate::port::client::Client).
+impl<
+        Service: crate::service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Attachable
+    for PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+    fn attachment_file_descriptor(&self) -> Option<&FileDescriptor> {
+        let fd = self
+            .request
+            .client_shared_state
+            .lock()
+            .response_receiver
+            .delivery_file_descriptor()? as *const FileDescriptor;
+        // the delivery listener and its file descriptor never change during the lifetime
+        // of the client port
+        Some(unsafe { &*fd })
+    }
+}
+
 impl<
         Service: crate::service::Service,
         RequestPayload: Debug + ZeroCopySend + ?Sized,
@@ -218,6 +260,62 @@ impl<
         self.number_of_server_connections
     }
 
+    /// Returns the time in nanoseconds between sending the corresponding [`RequestMut`] and
+    /// sending the most recently received [`Response`]. Both timestamps are captured with the
+    /// monotonic clock, see
+    /// [`RequestHeader::timestamp_ns()`](service::header::request_response::RequestHeader::timestamp_ns())
+    /// and
+    /// [`ResponseHeader::timestamp_ns()`](service::header::request_response::ResponseHeader::timestamp_ns()).
+    /// As long as no [`Response`] was received it returns [`None`].
+    pub fn round_trip_latency_ns(&self) -> Option<u64> {
+        match self.latest_response_timestamp_ns.load(Ordering::Relaxed) {
+            0 => None,
+            response_timestamp => {
+                Some(response_timestamp.saturating_sub(self.request.header().timestamp_ns()))
+            }
+        }
+    }
+
+    /// Returns the response deadline of the [`Service`](crate::service::Service), see
+    /// [`crate::service::static_config::request_response::StaticConfig::response_deadline()`].
+    pub fn response_deadline(&self) -> Option<Duration> {
+        self.response_deadline
+    }
+
+    /// Returns the remaining time until the response deadline is missed. It returns
+    /// [`None`] when the [`Service`](crate::service::Service) has no response deadline or when a
+    /// [`Response`] was already received and [`Duration::ZERO`] when the deadline has passed.
+    /// It can be used as timeout for a [`WaitSet`](crate::waitset::WaitSet) to wake up when
+    /// the deadline is missed.
+    pub fn time_until_deadline(&self) -> Option<Duration> {
+        let deadline = self.response_deadline?;
+        if self.first_response_timestamp_ns.load(Ordering::Relaxed) != 0 {
+            return None;
+        }
+
+        let elapsed = Duration::from_nanos(
+            monotonic_timestamp_ns().saturating_sub(self.request.header().timestamp_ns()),
+        );
+        Some(deadline.saturating_sub(elapsed))
+    }
+
+    /// Returns [`true`] when the [`Service`](crate::service::Service) has a response deadline
+    /// and no [`Response`] was sent within the deadline after the [`RequestMut`] was sent,
+    /// otherwise [`false`].
+    pub fn has_missed_deadline(&self) -> bool {
+        let deadline = match self.response_deadline {
+            Some(deadline) => deadline.as_nanos() as u64,
+            None => return false,
+        };
+
+        let response_timestamp = match self.first_response_timestamp_ns.load(Ordering::Relaxed) {
+            0 => monotonic_timestamp_ns(),
+            t => t,
+        };
+
+        response_timestamp.saturating_sub(self.request.header().timestamp_ns()) > deadline
+    }
+
     /// Returns [`true`] when a [`Server`](crate::port::server::Server) has sent a [`Response`]
     /// otherwise [`false`].
     pub fn has_response(&self) -> bool {
@@ -228,15 +326,42 @@ impl<
             .has_samples(self.request.channel_id)
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+    fn track_response_timestamp(&self, timestamp_ns: u64) {
+        let _ = self.first_response_timestamp_ns.compare_exchange(
+            0,
+            timestamp_ns,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+        self.latest_response_timestamp_ns
+            .store(timestamp_ns, Ordering::Relaxed);
+    }
+
+    fn receive_impl(
+        &self,
+    ) -> Result<Option<(ChunkDetails, Chunk, Option<PaddedPayload>)>, ReceiveError> {
         let client_shared_state = self.request.client_shared_state.lock();
         let msg = "Unable to receive response";
         fail!(from self, when client_shared_state.update_connections(),
                 "{msg} since the connections could not be updated.");
 
-        client_shared_state
+        match client_shared_state
             .response_receiver
-            .receive(self.request.channel_id)
+            .receive(self.request.channel_id)?
+        {
+            None => Ok(None),
+            Some((details, mut chunk)) => {
+                let header = unsafe {
+                    &*(chunk.header as *const service::header::request_response::ResponseHeader)
+                };
+                let padded_payload = client_shared_state.response_receiver.pad_evolved_payload(
+                    &mut chunk,
+                    header.payload_size as _,
+                    header.number_of_elements as _,
+                );
+                Ok(Some((details, chunk, padded_payload)))
+            }
+        }
     }
 }
 
@@ -284,11 +409,12 @@ impl<
         loop {
             match self.receive_impl()? {
                 None => return Ok(None),
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let response = Response {
                         details,
                         client_shared_state: self.request.client_shared_state.clone(),
                         channel_id: self.request.channel_id,
+                        _padded_payload: padded_payload,
                         ptr: unsafe {
                             RawSample::new_unchecked(
                                 chunk.header.cast(),
@@ -302,6 +428,7 @@ impl<
                         continue;
                     }
 
+                    self.track_response_timestamp(response.header().timestamp_ns());
                     return Ok(Some(response));
                 }
             }
@@ -353,7 +480,7 @@ impl<
         loop {
             match self.receive_impl()? {
                 None => return Ok(None),
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let header = unsafe {
                         &*(chunk.header as *const service::header::request_response::ResponseHeader)
                     };
@@ -362,6 +489,7 @@ impl<
                         details,
                         channel_id: self.request.channel_id,
                         client_shared_state: self.request.client_shared_state.clone(),
+                        _padded_payload: padded_payload,
                         ptr: unsafe {
                             RawSample::new_slice_unchecked(
                                 chunk.header.cast(),
@@ -378,6 +506,7 @@ impl<
                         continue;
                     }
 
+                    self.track_response_timestamp(response.header().timestamp_ns());
                     return Ok(Some(response));
                 }
             }
@@ -406,7 +535,7 @@ impl<
         loop {
             match self.receive_impl()? {
                 None => return Ok(None),
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let header = unsafe {
                         &*(chunk.header as *const service::header::request_response::ResponseHeader)
                     };
@@ -424,6 +553,7 @@ impl<
                         details,
                         channel_id: self.request.channel_id,
                         client_shared_state: self.request.client_shared_state.clone(),
+                        _padded_payload: padded_payload,
                         ptr: unsafe {
                             RawSample::new_slice_unchecked(
                                 chunk.header.cast(),
@@ -440,6 +570,7 @@ impl<
                         continue;
                     }
 
+                    self.track_response_timestamp(response.header().timestamp_ns());
                     return Ok(Some(response));
                 }
             }
@@ -83,6 +83,7 @@ use iceoryx2_bb_elementary::{cyclic_tagger::CyclicTagger, CallbackProgression};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy,
     dynamic_storage::DynamicStorage,
@@ -93,7 +94,7 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, Iox
 
 use crate::{
     pending_response::PendingResponse,
-    port::{details::data_segment::DataSegment, UniqueClientId},
+    port::{details::data_segment::DataSegment, UniqueClientId, UniqueServerId},
     prelude::{PortFactory, UnableToDeliverStrategy},
     raw_sample::RawSampleMut,
     request_mut::RequestMut,
@@ -112,12 +113,13 @@ use crate::{
 use super::{
     details::{
         data_segment::DataSegmentType,
+        delivery_notification::DeliveryListener,
         receiver::{Receiver, SenderDetails},
         segment_state::SegmentState,
-        sender::{ReceiverDetails, Sender},
+        sender::{ReceiverDetails, Sender, INVALID_DATA_SEGMENT_USAGE_INDEX},
     },
     update_connections::ConnectionFailure,
-    LoanError, SendError,
+    LoanError, SendError, ShrinkToFitError,
 };
 
 /// Failure that can be emitted when a [`RequestMut`] is sent.
@@ -128,6 +130,11 @@ pub enum RequestSendError {
     /// can be sent.
     ExceedsMaxActiveRequests,
 
+    /// The [`Client`] has no credits left since a
+    /// [`Server`](crate::port::server::Server) has not yet acknowledged enough of its
+    /// previous [`RequestMut`]s. Only emitted when the service has credit flow control enabled.
+    BackPressure,
+
     /// Underlying [`SendError`]s.
     SendError(SendError),
 }
@@ -183,6 +190,22 @@ impl<Service: service::Service> Drop for ClientSharedState<Service> {
 }
 
 impl<Service: service::Service> ClientSharedState<Service> {
+    /// Publishes the current size of the data segment in the dynamic config so that the
+    /// memory footprint of the service can be acquired from any process.
+    fn update_data_segment_size(&self) {
+        if let Some(handle) = unsafe { *self.client_handle.get() } {
+            self.request_sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .set_client_data_segment_size(
+                    handle,
+                    self.request_sender.data_segment.usage().total_bytes(),
+                );
+        }
+    }
+
     fn prepare_channel_to_receive_responses(&self, channel_id: ChannelId, request_id: u64) {
         self.response_receiver
             .set_channel_state(channel_id, request_id);
@@ -213,18 +236,73 @@ impl<Service: service::Service> ClientSharedState<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        if self.credits() == Some(0) {
+            fail!(from self, with RequestSendError::BackPressure,
+                "{} since the client has no credits left and a server has not yet acknowledged enough of its previous requests.", msg);
+        }
+
         self.prepare_channel_to_receive_responses(channel_id, request_id);
 
         self.active_request_counter.fetch_add(1, Ordering::Relaxed);
-        Ok(self.request_sender.deliver_offset(
+        Ok(self.request_sender.deliver_offset_and_track(
             offset,
             sample_size,
             // All requests are delivered on the same channel, therefore we can use
             // ChannelId::new(0).
             ChannelId::new(0),
+            |server_index, has_overwritten_request| {
+                self.track_delivered_request(server_index, has_overwritten_request)
+            },
         )?)
     }
 
+    pub(crate) fn credits(&self) -> Option<u32> {
+        let credits_per_client = self
+            .request_sender
+            .service_state
+            .static_config
+            .request_response()
+            .credits_per_client?;
+
+        match unsafe { *self.client_handle.get() } {
+            Some(handle) => Some(
+                self.request_sender
+                    .service_state
+                    .dynamic_storage
+                    .get()
+                    .request_response()
+                    .client_credits(handle.index() as usize),
+            ),
+            None => Some(credits_per_client),
+        }
+    }
+
+    fn track_delivered_request(&self, server_index: usize, has_overwritten_request: bool) {
+        if let Some(handle) = self.credit_handle() {
+            let dynamic_config = self
+                .request_sender
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response();
+
+            // the overwritten request was discarded by safe overflow and will never be
+            // acknowledged, the new request takes its place in the servers buffer
+            if !has_overwritten_request {
+                dynamic_config.add_outstanding_request(server_index, handle.index() as usize);
+            }
+        }
+    }
+
+    fn credit_handle(&self) -> Option<ContainerHandle> {
+        self.request_sender
+            .service_state
+            .static_config
+            .request_response()
+            .credits_per_client
+            .and(unsafe { *self.client_handle.get() })
+    }
+
     pub(crate) fn update_connections(
         &self,
     ) -> Result<(), super::update_connections::ConnectionFailure> {
@@ -385,6 +463,8 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_requests,
+                false,
+                None,
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -392,6 +472,8 @@ impl<
                 global_config,
                 number_of_requests,
                 client_factory.config.allocation_strategy,
+                false,
+                None,
             ),
         };
 
@@ -399,6 +481,10 @@ impl<
             when data_segment,
             with ClientCreateError::UnableToCreateDataSegment,
             "{} since the client data segment could not be created.", msg);
+        service
+            .__internal_state()
+            .shared_node
+            .register_shm_region(&segment_name, data_segment.usage());
 
         let client_details = ClientDetails {
             client_id,
@@ -425,7 +511,7 @@ impl<
             connections: (0..server_list.capacity())
                 .map(|_| UnsafeCell::new(None))
                 .collect(),
-            receiver_max_buffer_size: static_config.max_active_requests_per_client,
+            receiver_max_buffer_size: static_config.max_request_queue_depth_per_client(),
             receiver_max_borrowed_samples: static_config.max_active_requests_per_client,
             enable_safe_overflow: static_config.enable_safe_overflow_for_requests,
             degradation_callback: client_factory.request_degradation_callback,
@@ -434,6 +520,7 @@ impl<
             service_state: service.__internal_state().clone(),
             tagger: CyclicTagger::new(),
             loan_counter: IoxAtomicUsize::new(0),
+            number_of_overflows: IoxAtomicU64::new(0),
             sender_max_borrowed_samples: static_config.max_loaned_requests,
             unable_to_deliver_strategy: client_factory.config.unable_to_deliver_strategy,
             message_type_details: static_config.request_message_type_details.clone(),
@@ -443,6 +530,9 @@ impl<
             // but the requests have one shared buffer that the user can configure, therefore
             // one channel suffices
             number_of_channels: 1,
+            data_segment_usage_index: IoxAtomicUsize::new(INVALID_DATA_SEGMENT_USAGE_INDEX),
+            connection_generation: IoxAtomicU64::new(0),
+            enable_notify_on_delivery: static_config.enable_notify_on_delivery,
         };
 
         let number_of_to_be_removed_connections = service
@@ -456,6 +546,17 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        let delivery_listener = match static_config.enable_notify_on_delivery {
+            true => Some(fail!(from origin,
+                when DeliveryListener::new(
+                    client_id.value(),
+                    global_config,
+                    ClientCreateError::UnableToCreateDeliveryListener,
+                ),
+                "{} since the delivery listener could not be created.", msg)),
+            false => None,
+        };
+
         let response_receiver = Receiver {
             connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
             receiver_port_id: client_id.value(),
@@ -472,6 +573,8 @@ impl<
             enable_safe_overflow: static_config.enable_safe_overflow_for_responses,
             number_of_channels: number_of_requests,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            connection_generation: IoxAtomicU64::new(0),
+            delivery_listener,
         };
 
         let client_shared_state = Service::ArcThreadSafetyPolicy::new(ClientSharedState {
@@ -538,6 +641,10 @@ impl<
                 }
             }
         };
+        new_self
+            .client_shared_state
+            .lock()
+            .update_data_segment_size();
 
         Ok(new_self)
     }
@@ -547,6 +654,15 @@ impl<
         self.client_id
     }
 
+    /// Returns the number of [`RequestMut`]s the [`Client`] can send until the
+    /// [`Server`](crate::port::server::Server) with the most unacknowledged requests has to
+    /// acknowledge previous ones. Returns
+    /// [`None`] when the service has no credit flow control enabled, see
+    /// [`StaticConfig::credits_per_client()`](crate::service::static_config::request_response::StaticConfig::credits_per_client()).
+    pub fn available_credits(&self) -> Option<u32> {
+        self.client_shared_state.lock().credits()
+    }
+
     /// Returns the strategy the [`Client`] follows when a [`RequestMut`] cannot be delivered
     /// if the [`Server`](crate::port::server::Server)s buffer is full.
     pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
@@ -555,6 +671,143 @@ impl<
             .request_sender
             .unable_to_deliver_strategy
     }
+
+    /// Returns the number of [`Server`](crate::port::server::Server)s of the
+    /// [`Service`](crate::service::Service) the [`Client`] is connected to. The value is
+    /// acquired from the dynamic state of the [`Service`](crate::service::Service) and tracks
+    /// [`Server`](crate::port::server::Server)s that were created, dropped or removed by the
+    /// dead node cleanup.
+    pub fn number_of_connected_servers(&self) -> Result<usize, ConnectionFailure> {
+        let mut number_of_servers = 0;
+        self.connected_servers(|_| {
+            number_of_servers += 1;
+            CallbackProgression::Continue
+        })?;
+        Ok(number_of_servers)
+    }
+
+    /// Returns true when at least one [`Server`](crate::port::server::Server) is connected,
+    /// otherwise false. Can be used to wait for a [`Server`](crate::port::server::Server)
+    /// before a [`RequestMut`] is sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// #
+    /// let client = service.client_builder().create()?;
+    ///
+    /// while !client.has_server()? {
+    ///     node.wait(Duration::from_millis(100))?;
+    /// }
+    ///
+    /// let pending_response = client.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_server(&self) -> Result<bool, ConnectionFailure> {
+        Ok(self.number_of_connected_servers()? > 0)
+    }
+
+    /// Updates the connections and calls the provided callback with the [`ServerDetails`],
+    /// containing the [`UniqueServerId`], of every [`Server`](crate::port::server::Server) of
+    /// the [`Service`](crate::service::Service) until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn connected_servers<F: FnMut(&ServerDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let state = self.client_shared_state.lock();
+        state.update_connections()?;
+        unsafe { (*state.server_list_state.get()).for_each(|_, details| callback(details)) };
+        Ok(())
+    }
+
+    /// Returns a counter that is increased whenever a [`Server`](crate::port::server::Server)
+    /// connects to or disconnects from the [`Client`]. The connections are updated before the
+    /// counter is read. Since the counter never decreases, a caller that polls it and compares
+    /// it with the previously read value detects every change. The connected
+    /// [`Server`](crate::port::server::Server)s can then be acquired with
+    /// [`Client::list_connected_servers()`].
+    ///
+    /// The connections are also updated on every send, therefore the counter may change
+    /// without calling this method.
+    pub fn connection_generation(&self) -> Result<u64, ConnectionFailure> {
+        let state = self.client_shared_state.lock();
+        state.update_connections()?;
+        Ok(state.request_sender.connection_generation())
+    }
+
+    /// Updates the connections and calls the provided callback with the [`UniqueServerId`]
+    /// of every connected [`Server`](crate::port::server::Server) until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_connected_servers<F: FnMut(UniqueServerId) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let state = self.client_shared_state.lock();
+        state.update_connections()?;
+        state
+            .request_sender
+            .list_connected_receivers(|id| callback(UniqueServerId(UniqueSystemId::from(id))));
+        Ok(())
+    }
+
+    /// Cancels the in-flight request of the provided [`PendingResponse`]. The request slot is
+    /// released immediately and all [`Server`](crate::port::server::Server)s are informed that
+    /// the [`Response`](crate::response::Response)s are no longer required. Unless
+    /// fire-and-forget requests are enabled, a [`Server`](crate::port::server::Server) that
+    /// has not yet received the request will not receive it anymore. All
+    /// [`Response`](crate::response::Response)s that are sent afterwards are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// #
+    /// let client = service.client_builder().create()?;
+    /// let pending_response = client.send_copy(1234)?;
+    ///
+    /// // the user aborted the operation, the response is no longer required
+    /// client.cancel_pending_response(pending_response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_pending_response(
+        &self,
+        pending_response: PendingResponse<
+            Service,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+    ) {
+        debug_assert!(
+            pending_response.header().client_id() == self.client_id,
+            "The pending response must belong to the client that cancels it."
+        );
+
+        // closes the connection and releases the request slot
+        drop(pending_response);
+    }
 }
 
 ////////////////////////
@@ -659,6 +912,8 @@ impl<
                     channel_id,
                     request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
                     number_of_elements: 1,
+                    payload_size: client_shared_state.request_sender.payload_size() as _,
+                    timestamp_ns: 0,
                 },
             )
         };
@@ -831,6 +1086,18 @@ impl<
         self.client_shared_state.lock().config.initial_max_slice_len
     }
 
+    /// Releases the memory the request data segment acquired when it grew beyond the
+    /// [`Client::initial_max_slice_len()`] by replacing it with a data segment of the initial
+    /// size. Requests that are still loaned, in flight or held by a
+    /// [`Server`](crate::port::server::Server) keep the previous data segment alive until the
+    /// last of them is released. Returns the number of bytes that are reclaimed.
+    pub fn shrink_to_fit(&self) -> Result<usize, ShrinkToFitError> {
+        self.client_shared_state
+            .lock()
+            .request_sender
+            .shrink_to_fit()
+    }
+
     /// Loans/allocates a [`RequestMutUninit`] from the underlying data segment of the [`Client`].
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -902,6 +1169,20 @@ impl<
         let client_shared_state = self.client_shared_state.lock();
         let max_slice_len = client_shared_state.config.initial_max_slice_len;
 
+        if let Some(max_payload_size) = client_shared_state
+            .request_sender
+            .service_state
+            .static_config
+            .request_response()
+            .max_request_payload_size
+        {
+            if max_payload_size < slice_len {
+                fail!(from self, with LoanError::PayloadTooLarge,
+                    "Unable to loan slice with {} elements since it would exceed the max request payload size of {} of the service.",
+                    slice_len, max_payload_size);
+            }
+        }
+
         if client_shared_state.config.allocation_strategy == AllocationStrategy::Static
             && max_slice_len < slice_len
         {
@@ -914,6 +1195,7 @@ impl<
         let chunk = client_shared_state
             .request_sender
             .allocate(request_layout)?;
+        client_shared_state.update_data_segment_size();
 
         let channel_id =
             match unsafe { &mut *client_shared_state.available_channel_ids.get() }.pop() {
@@ -931,6 +1213,8 @@ impl<
                 channel_id,
                 request_id: self.request_id_counter.fetch_add(1, Ordering::Relaxed),
                 number_of_elements: slice_len as _,
+                payload_size: client_shared_state.request_sender.payload_size() as _,
+                timestamp_ns: 0,
             })
         };
 
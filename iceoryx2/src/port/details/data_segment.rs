@@ -10,9 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::alloc::Layout;
+use core::{alloc::Layout, sync::atomic::Ordering};
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::huge_pages::HugePages;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::{
     event::NamedConceptBuilder,
@@ -26,15 +27,21 @@ use iceoryx2_cal::{
         SegmentId, ShmAllocationError,
     },
 };
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU8;
 
 use crate::{
     config,
+    node::shm_region::ShmRegionUsage,
+    port::ShrinkToFitError,
     service::{
         self,
         config_scheme::{data_segment_config, resizable_data_segment_config},
     },
 };
 
+extern crate alloc;
+use alloc::sync::Arc;
+
 /// Defines the data segment type of a zero copy capable sender port.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -63,15 +70,34 @@ enum MemoryType<Service: service::Service> {
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
     memory: MemoryType<Service>,
+    current_segment_id: IoxAtomicU8,
+    usage: Arc<ShmRegionUsage>,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
+    /// Aligns chunks that span at least one huge page to the huge page size so that every
+    /// chunk starts at a huge page boundary.
+    fn chunk_layout_for_huge_pages(chunk_layout: Layout, use_huge_pages: bool) -> Layout {
+        let huge_page_size = HugePages::page_size();
+        if !use_huge_pages || chunk_layout.size() < huge_page_size {
+            return chunk_layout;
+        }
+
+        match chunk_layout.align_to(huge_page_size) {
+            Ok(layout) => layout.pad_to_align(),
+            Err(_) => chunk_layout,
+        }
+    }
+
     pub(crate) fn create_static_segment(
         segment_name: &FileName,
         chunk_layout: Layout,
         global_config: &config::Config,
         number_of_chunks: usize,
+        use_huge_pages: bool,
+        numa_node: Option<usize>,
     ) -> Result<Self, SharedMemoryCreateError> {
+        let chunk_layout = Self::chunk_layout_for_huge_pages(chunk_layout, use_huge_pages);
         let allocator_config = shm_allocator::pool_allocator::Config {
             bucket_layout: chunk_layout,
         };
@@ -79,17 +105,26 @@ impl<Service: service::Service> DataSegment<Service> {
         let origin = "DataSegment::create_static_segment()";
 
         let segment_config = data_segment_config::<Service>(global_config);
-        let memory = fail!(from origin,
-                                when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-                                Service::SharedMemory,
-                                    >>::new(segment_name)
-                                    .config(&segment_config)
-                                    .size(chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1)
-                                    .create(&allocator_config),
-                                "{msg}");
+        let segment_size = chunk_layout.size() * number_of_chunks + chunk_layout.align() - 1;
+        let builder = <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+        >>::new(segment_name)
+        .config(&segment_config)
+        .use_huge_pages(use_huge_pages)
+        .size(segment_size);
+        let builder = match numa_node {
+            Some(node_id) => builder.numa_node(node_id),
+            None => builder,
+        };
+        let memory = fail!(from origin, when builder.create(&allocator_config), "{msg}");
+        let usage = Arc::new(ShmRegionUsage::default());
+        let capacity = memory.bucket_size() * memory.number_of_buckets();
+        usage.update_capacity(capacity, capacity);
 
         Ok(Self {
             memory: MemoryType::Static(memory),
+            current_segment_id: IoxAtomicU8::new(0),
+            usage,
         })
     }
 
@@ -99,37 +134,83 @@ impl<Service: service::Service> DataSegment<Service> {
         global_config: &config::Config,
         number_of_chunks: usize,
         allocation_strategy: AllocationStrategy,
+        use_huge_pages: bool,
+        numa_node: Option<usize>,
     ) -> Result<Self, SharedMemoryCreateError> {
+        let chunk_layout = Self::chunk_layout_for_huge_pages(chunk_layout, use_huge_pages);
         let msg = "Unable to create the dynamic data segment since the underlying shared memory could not be created.";
         let origin = "DataSegment::create_dynamic_segment()";
 
         let segment_config = resizable_data_segment_config::<Service>(global_config);
-        let memory = fail!(from origin,
-                    when <<Service::ResizableSharedMemory as ResizableSharedMemory<
-                        PoolAllocator,
-                        Service::SharedMemory,
-                    >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
-                        segment_name,
-                    )
-                    .config(&segment_config)
-                    .max_number_of_chunks_hint(number_of_chunks)
-                    .max_chunk_layout_hint(chunk_layout)
-                    .allocation_strategy(allocation_strategy)
-                    .create(),
-                    "{msg}");
+        let builder = <<Service::ResizableSharedMemory as ResizableSharedMemory<
+            PoolAllocator,
+            Service::SharedMemory,
+        >>::MemoryBuilder as NamedConceptBuilder<Service::ResizableSharedMemory>>::new(
+            segment_name,
+        )
+        .config(&segment_config)
+        .max_number_of_chunks_hint(number_of_chunks)
+        .max_chunk_layout_hint(chunk_layout)
+        .allocation_strategy(allocation_strategy)
+        .use_huge_pages(use_huge_pages);
+        let builder = match numa_node {
+            Some(node_id) => builder.numa_node(node_id),
+            None => builder,
+        };
+        let memory = fail!(from origin, when builder.create(), "{msg}");
 
-        Ok(Self {
+        let data_segment = Self {
             memory: MemoryType::Dynamic(memory),
-        })
+            current_segment_id: IoxAtomicU8::new(0),
+            usage: Arc::new(ShmRegionUsage::default()),
+        };
+        data_segment.update_capacity();
+
+        Ok(data_segment)
+    }
+
+    /// Returns the usage counters that are shared with the
+    /// [`SharedMemoryRegion`](crate::node::shm_region::SharedMemoryRegion) of the node.
+    pub(crate) fn usage(&self) -> &Arc<ShmRegionUsage> {
+        &self.usage
+    }
+
+    /// Refreshes the capacity of the usage counters whenever a segment of a dynamic data
+    /// segment was created or released.
+    fn update_capacity(&self) {
+        if let MemoryType::Dynamic(memory) = &self.memory {
+            let current_segment_id =
+                SegmentId::new(self.current_segment_id.load(Ordering::Relaxed));
+            self.usage.update_capacity(
+                memory.capacity(),
+                memory.bucket_size(current_segment_id)
+                    * memory.number_of_buckets(current_segment_id),
+            );
+        }
     }
 
     pub(crate) fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
         let msg = "Unable to allocate memory from the data segment";
         match &self.memory {
-            MemoryType::Static(memory) => Ok(fail!(from self, when memory.allocate(layout),
-                                            "{msg}.")),
+            MemoryType::Static(memory) => {
+                let ptr = fail!(from self, when memory.allocate(layout), "{msg}.");
+                self.usage.acquire(memory.bucket_size());
+                Ok(ptr)
+            }
             MemoryType::Dynamic(memory) => match memory.allocate(layout) {
-                Ok(ptr) => Ok(ptr),
+                Ok(ptr) => {
+                    let segment_id = ptr.offset.segment_id();
+                    // segment ids are increasing with every reallocation
+                    let previous_segment_id = self
+                        .current_segment_id
+                        .fetch_max(segment_id.value(), Ordering::Relaxed);
+                    if previous_segment_id < segment_id.value() {
+                        self.usage.start_new_segment();
+                        self.update_capacity();
+                    }
+                    self.usage.acquire(memory.bucket_size(segment_id));
+                    Ok(ptr)
+                }
                 Err(ResizableShmAllocationError::ShmAllocationError(e)) => {
                     fail!(from self, with e,
                         "{msg} caused by {:?}.", e);
@@ -150,8 +231,22 @@ impl<Service: service::Service> DataSegment<Service> {
 
     pub(crate) unsafe fn deallocate_bucket(&self, offset: PointerOffset) {
         match &self.memory {
-            MemoryType::Static(memory) => memory.deallocate_bucket(offset),
-            MemoryType::Dynamic(memory) => memory.deallocate_bucket(offset),
+            MemoryType::Static(memory) => {
+                memory.deallocate_bucket(offset);
+                self.usage.release(memory.bucket_size(), true);
+            }
+            MemoryType::Dynamic(memory) => {
+                let segment_id = offset.segment_id();
+                let bucket_size = memory.bucket_size(segment_id);
+                let is_current_segment =
+                    segment_id.value() == self.current_segment_id.load(Ordering::Relaxed);
+                memory.deallocate_bucket(offset);
+                self.usage.release(bucket_size, is_current_segment);
+                if !is_current_segment {
+                    // the previous segment is released with its last chunk
+                    self.update_capacity();
+                }
+            }
         }
     }
 
@@ -162,6 +257,62 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
+    /// Returns true when the current segment is backed by huge pages.
+    pub(crate) fn has_huge_pages(&self) -> bool {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.has_huge_pages(),
+            MemoryType::Dynamic(memory) => memory.has_huge_pages(),
+        }
+    }
+
+    /// Returns the id of the NUMA node the current segment is bound to.
+    pub(crate) fn numa_node(&self) -> Option<usize> {
+        match &self.memory {
+            MemoryType::Static(memory) => memory.numa_node(),
+            MemoryType::Dynamic(memory) => memory.numa_node(),
+        }
+    }
+
+    /// Returns the bucket size of the most recently created segment.
+    pub(crate) fn current_bucket_size(&self) -> usize {
+        self.bucket_size(SegmentId::new(
+            self.current_segment_id.load(Ordering::Relaxed),
+        ))
+    }
+
+    /// Replaces a dynamic segment that grew beyond its initial size with a segment of the
+    /// initial size. The previous segment stays alive until all of its samples are released.
+    /// Returns the number of bytes the segment was reduced by.
+    pub(crate) fn shrink_to_fit(&self) -> Result<usize, ShrinkToFitError> {
+        let msg = "Unable to shrink the data segment";
+        match &self.memory {
+            MemoryType::Static(_) => Ok(0),
+            MemoryType::Dynamic(memory) => match memory.shrink_to_fit() {
+                Ok(0) => Ok(0),
+                Ok(reclaimed_bytes) => {
+                    // the replacement segment always has the next segment id
+                    self.current_segment_id.fetch_add(1, Ordering::Relaxed);
+                    self.usage.start_new_segment();
+                    self.update_capacity();
+                    Ok(reclaimed_bytes)
+                }
+                Err(ResizableShmAllocationError::MaxReallocationsReached) => {
+                    fail!(from self, with ShrinkToFitError::MaxReallocationsReached,
+                        "{msg} since the maximum number of reallocations was reached.");
+                }
+                Err(e) => {
+                    fail!(from self, with ShrinkToFitError::InternalFailure,
+                        "{msg} due to an internal failure ({:?}).", e);
+                }
+            },
+        }
+    }
+
+    /// Returns how often the data segment was reallocated. Always zero for static segments.
+    pub(crate) fn number_of_reallocations(&self) -> usize {
+        self.current_segment_id.load(Ordering::Relaxed) as usize
+    }
+
     pub(crate) fn max_number_of_segments(data_segment_type: DataSegmentType) -> u8 {
         match data_segment_type {
             DataSegmentType::Static => 1,
@@ -0,0 +1,136 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, Notifier, NotifierBuilder, NotifierCreateError,
+    NotifierNotifyError, TriggerId,
+};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+
+use crate::config::Config;
+use crate::service;
+use crate::service::config_scheme::event_config;
+use crate::service::naming_scheme::delivery_event_name;
+
+const DELIVERY_TRIGGER_ID: TriggerId = TriggerId::new(0);
+
+/// Is owned by a receiver of a service with notify on delivery. Every sender signals it after
+/// it delivered new data so that the receiver can be attached to a
+/// [`WaitSet`](crate::waitset::WaitSet).
+#[derive(Debug)]
+pub(crate) struct DeliveryListener<Service: service::Service> {
+    listener: <Service::Event as Event>::Listener,
+    // signals the own listener again when data remains after all notifications were consumed
+    notifier: <Service::Event as Event>::Notifier,
+}
+
+impl<Service: service::Service> DeliveryListener<Service> {
+    pub(crate) fn new<E: Debug>(
+        receiver_port_id: u128,
+        config: &Config,
+        error: E,
+    ) -> Result<Self, E> {
+        let origin = format!("DeliveryListener::new({receiver_port_id})");
+        let msg = "Unable to create the delivery listener";
+        let event_name = delivery_event_name(receiver_port_id);
+        let event_config = event_config::<Service>(config);
+
+        let listener = fail!(from origin,
+            when <Service::Event as Event>::ListenerBuilder::new(&event_name)
+                .config(&event_config)
+                .trigger_id_max(DELIVERY_TRIGGER_ID)
+                .create(),
+            with error,
+            "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
+
+        let notifier = match <Service::Event as Event>::NotifierBuilder::new(&event_name)
+            .config(&event_config)
+            .open()
+        {
+            Ok(notifier) => notifier,
+            Err(e) => {
+                fail!(from origin, with error,
+                    "{} since the underlying event concept \"{}\" could not be opened ({:?}).",
+                    msg, event_name, e);
+            }
+        };
+
+        Ok(Self { listener, notifier })
+    }
+
+    /// Consumes all pending delivery notifications.
+    pub(crate) fn reset(&self) {
+        if let Err(e) = self.listener.try_wait_all(|_| {}) {
+            warn!(from self, "Unable to consume the pending delivery notifications ({:?}).", e);
+        }
+    }
+
+    /// Signals the listener again, required when data remains after [`DeliveryListener::reset()`].
+    pub(crate) fn rearm(&self) {
+        if let Err(e) = self.notifier.notify(DELIVERY_TRIGGER_ID) {
+            warn!(from self, "Unable to re-arm the delivery notification ({:?}).", e);
+        }
+    }
+}
+
+impl<Service: service::Service> DeliveryListener<Service>
+where
+    <Service::Event as Event>::Listener: FileDescriptorBased,
+{
+    pub(crate) fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+/// Is owned by a sender connection and signals the [`DeliveryListener`] of the receiver.
+#[derive(Debug)]
+pub(crate) struct DeliveryNotifier<Service: service::Service> {
+    notifier: <Service::Event as Event>::Notifier,
+}
+
+impl<Service: service::Service> DeliveryNotifier<Service> {
+    /// Opens the [`DeliveryListener`] of the receiver. Returns [`None`] when it does not exist,
+    /// for instance when the receiver is already gone.
+    pub(crate) fn open(receiver_port_id: u128, config: &Config) -> Option<Self> {
+        let event_name = delivery_event_name(receiver_port_id);
+        match <Service::Event as Event>::NotifierBuilder::new(&event_name)
+            .config(&event_config::<Service>(config))
+            .open()
+        {
+            Ok(notifier) => Some(Self { notifier }),
+            Err(
+                NotifierCreateError::DoesNotExist
+                | NotifierCreateError::InitializationNotYetFinalized,
+            ) => None,
+            Err(e) => {
+                warn!(from "DeliveryNotifier::open()",
+                    "Unable to open the delivery listener of receiver {} ({:?}). The receiver will not be notified about delivered data.",
+                    receiver_port_id, e);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn notify(&self) {
+        match self.notifier.notify(DELIVERY_TRIGGER_ID) {
+            // the receiver is gone and the connection will be removed with the next update
+            Ok(()) | Err(NotifierNotifyError::Disconnected) => (),
+            Err(e) => {
+                warn!(from self, "Unable to notify the receiver about the delivered data ({:?}).", e);
+            }
+        }
+    }
+}
@@ -14,6 +14,10 @@ pub(crate) mod channel_management;
 pub(crate) mod chunk;
 pub(crate) mod chunk_details;
 pub(crate) mod data_segment;
+pub(crate) mod delivery_notification;
+pub(crate) mod ownership_arbiter;
+pub(crate) mod padded_payload;
 pub(crate) mod receiver;
+pub(crate) mod sample_loss_tracker;
 pub(crate) mod segment_state;
 pub(crate) mod sender;
@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2_bb_container::vec::Vec;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+
+use crate::{
+    config::Config,
+    node::{NodeId, NodeState, NodeView},
+    service::{self, dynamic_config::publish_subscribe::PublisherDetails},
+};
+
+/// Decides from which publisher a subscriber receives samples when the service has exclusive
+/// ownership. The owner is the alive publisher with the highest ownership strength, ties are
+/// resolved by the smallest publisher id so that all subscribers agree on the same owner.
+/// Since the liveness of a publisher is determined via the node monitoring, the owner is
+/// reevaluated at least once every monitoring interval of the owning node. When the interval of
+/// the owning node is unknown, the configured ownership monitoring interval is used.
+/// The liveness of every node is cached for its monitoring interval so that the node state is
+/// not acquired on every evaluation.
+#[derive(Debug)]
+pub(crate) struct OwnershipArbiter {
+    monitoring_interval: Duration,
+    owner: Option<u128>,
+    next_evaluation: Option<Duration>,
+    liveness_cache: Vec<CachedLiveness>,
+}
+
+#[derive(Debug)]
+struct CachedLiveness {
+    node_id: NodeId,
+    liveness: Option<Option<Duration>>,
+    valid_until: Duration,
+}
+
+impl OwnershipArbiter {
+    pub(crate) fn new(monitoring_interval: Duration, max_number_of_nodes: usize) -> Self {
+        Self {
+            monitoring_interval,
+            owner: None,
+            next_evaluation: None,
+            liveness_cache: Vec::new(max_number_of_nodes),
+        }
+    }
+
+    /// Enforces the reevaluation of the owner, required whenever publishers were added or
+    /// removed.
+    pub(crate) fn invalidate(&mut self) {
+        self.next_evaluation = None;
+    }
+
+    pub(crate) fn is_owner<Service: service::Service>(
+        &mut self,
+        origin: u128,
+        publishers: &ContainerState<PublisherDetails>,
+        config: &Config,
+        now: Option<Duration>,
+    ) -> bool {
+        let requires_evaluation = match (self.next_evaluation, now) {
+            (Some(next_evaluation), Some(now)) => next_evaluation <= now,
+            _ => true,
+        };
+
+        if requires_evaluation {
+            let owner = self.evaluate_owner::<Service>(publishers, config, now);
+            let monitoring_interval = owner
+                .and_then(|(_, interval)| interval)
+                .unwrap_or(self.monitoring_interval);
+            self.owner = owner.map(|(id, _)| id);
+            self.next_evaluation = now.map(|now| now + monitoring_interval);
+        }
+
+        match self.owner {
+            Some(owner) => owner == origin,
+            None => true,
+        }
+    }
+
+    /// Returns the id of the owning publisher together with the monitoring interval of its
+    /// node, if it is known.
+    fn evaluate_owner<Service: service::Service>(
+        &mut self,
+        publishers: &ContainerState<PublisherDetails>,
+        config: &Config,
+        now: Option<Duration>,
+    ) -> Option<(u128, Option<Duration>)> {
+        if let Some(now) = now {
+            while let Some(index) = self
+                .liveness_cache
+                .iter()
+                .position(|entry| entry.valid_until <= now)
+            {
+                self.liveness_cache.remove(index);
+            }
+        }
+
+        let mut owner: Option<(u32, u128, Option<Duration>)> = None;
+        publishers.for_each(|_, details| {
+            let strength = details.ownership_strength;
+            let id = details.publisher_id.value();
+            let is_stronger = match owner {
+                None => true,
+                Some((owner_strength, owner_id, _)) => {
+                    owner_strength < strength || (owner_strength == strength && id < owner_id)
+                }
+            };
+
+            if is_stronger {
+                if let Some(monitoring_interval) =
+                    self.liveness::<Service>(&details.node_id, config, now)
+                {
+                    owner = Some((strength, id, monitoring_interval));
+                }
+            }
+            CallbackProgression::Continue
+        });
+
+        owner.map(|(_, id, monitoring_interval)| (id, monitoring_interval))
+    }
+
+    /// Returns [`None`] when the node is dead, otherwise the monitoring interval of the node
+    /// when its details are accessible.
+    fn liveness<Service: service::Service>(
+        &mut self,
+        node_id: &NodeId,
+        config: &Config,
+        now: Option<Duration>,
+    ) -> Option<Option<Duration>> {
+        if let Some(entry) = self
+            .liveness_cache
+            .iter()
+            .find(|entry| entry.node_id == *node_id)
+        {
+            return entry.liveness;
+        }
+
+        let liveness = Self::acquire_liveness::<Service>(node_id, config);
+        if let Some(now) = now {
+            let monitoring_interval = liveness.flatten().unwrap_or(self.monitoring_interval);
+            self.liveness_cache.push(CachedLiveness {
+                node_id: *node_id,
+                liveness,
+                valid_until: now + monitoring_interval,
+            });
+        }
+
+        liveness
+    }
+
+    fn acquire_liveness<Service: service::Service>(
+        node_id: &NodeId,
+        config: &Config,
+    ) -> Option<Option<Duration>> {
+        // only nodes that are known to be dead or already removed lose the ownership, a node
+        // whose state cannot be determined keeps it
+        match NodeState::<Service>::new(node_id, config) {
+            Ok(None) | Ok(Some(NodeState::Dead(_))) => None,
+            Ok(Some(NodeState::Alive(view))) => {
+                Some(view.details().as_ref().map(|d| d.monitoring_interval()))
+            }
+            _ => Some(None),
+        }
+    }
+}
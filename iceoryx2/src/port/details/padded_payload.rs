@@ -0,0 +1,58 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+extern crate alloc;
+
+/// Zeroed copy of a received payload that was sent with an older, smaller version of the
+/// payload type when type evolution is enabled. The receiver reads zeros for all trailing
+/// fields the sender does not know about instead of reading beyond the senders chunk.
+#[derive(Debug)]
+pub(crate) struct PaddedPayload {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+// the copy is owned exclusively and never modified after its creation
+unsafe impl Send for PaddedPayload {}
+unsafe impl Sync for PaddedPayload {}
+
+impl Drop for PaddedPayload {
+    fn drop(&mut self) {
+        unsafe { alloc::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+impl PaddedPayload {
+    /// Returns [`None`] when the received payload of `received_size` bytes already covers the
+    /// local payload `layout` and can be used directly. Otherwise, the received bytes are copied
+    /// into zeroed memory with the local `layout`.
+    pub(crate) fn new(payload: *const u8, received_size: usize, layout: Layout) -> Option<Self> {
+        if received_size >= layout.size() {
+            return None;
+        }
+
+        let ptr = match NonNull::new(unsafe { alloc::alloc::alloc_zeroed(layout) }) {
+            Some(ptr) => ptr,
+            None => alloc::alloc::handle_alloc_error(layout),
+        };
+        unsafe { core::ptr::copy_nonoverlapping(payload, ptr.as_ptr(), received_size) };
+
+        Some(Self { ptr, layout })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+}
@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::alloc::Layout;
 use core::cell::UnsafeCell;
+use core::sync::atomic::Ordering;
 
 extern crate alloc;
 use super::channel_management::ChannelManagement;
@@ -18,6 +20,8 @@ use super::channel_management::INVALID_CHANNEL_STATE;
 use super::chunk::Chunk;
 use super::chunk_details::ChunkDetails;
 use super::data_segment::{DataSegmentType, DataSegmentView};
+use super::delivery_notification::DeliveryListener;
+use super::padded_payload::PaddedPayload;
 use crate::port::update_connections::ConnectionFailure;
 use crate::port::{DegradationAction, DegradationCallback, ReceiveError};
 use crate::service::naming_scheme::data_segment_name;
@@ -29,10 +33,14 @@ use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::slotmap::SlotMapKey;
 use iceoryx2_bb_container::vec::Vec;
 use iceoryx2_bb_elementary::cyclic_tagger::*;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_log::{error, fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_cal::event::Event;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::*;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 #[derive(Clone, Copy)]
 pub(crate) struct SenderDetails {
@@ -123,6 +131,8 @@ pub(crate) struct Receiver<Service: service::Service> {
     pub(crate) enable_safe_overflow: bool,
     pub(crate) number_of_channels: usize,
     pub(crate) connection_storage: UnsafeCell<SlotMap<Connection<Service>>>,
+    pub(crate) connection_generation: IoxAtomicU64,
+    pub(crate) delivery_listener: Option<DeliveryListener<Service>>,
 }
 
 impl<Service: service::Service> Receiver<Service> {
@@ -179,6 +189,19 @@ impl<Service: service::Service> Receiver<Service> {
         self.receiver_port_id
     }
 
+    /// Returns the index of the active connection from which the chunk was received. If the
+    /// sender is no longer connected it returns [`None`].
+    pub(crate) fn connection_index(&self, chunk: &ChunkDetails) -> Option<usize> {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        match connection_storage.get(chunk.connection_key) {
+            Some(connection) if connection.sender_port_id == chunk.origin => self
+                .connections
+                .iter()
+                .position(|key| unsafe { *key.get() } == Some(chunk.connection_key)),
+            _ => None,
+        }
+    }
+
     pub(crate) fn create(
         &self,
         index: usize,
@@ -201,10 +224,53 @@ impl<Service: service::Service> Receiver<Service> {
         };
 
         unsafe { *self.connections[index].get() = Some(key) };
+        self.connection_generation.fetch_add(1, Ordering::Relaxed);
 
         Ok(())
     }
 
+    pub(crate) fn connection_generation(&self) -> u64 {
+        self.connection_generation.load(Ordering::Relaxed)
+    }
+
+    fn for_each_connection<F: FnMut(&Connection<Service>) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        let connection_storage = unsafe { &*self.connection_storage.get() };
+        for connection_key in self.connections.iter() {
+            if let Some(connection_key) = unsafe { &*connection_key.get() } {
+                if let Some(connection) = connection_storage.get(*connection_key) {
+                    if callback(connection) == CallbackProgression::Stop {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn list_connected_senders<F: FnMut(u128) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.for_each_connection(|connection| callback(connection.sender_port_id))
+    }
+
+    /// Calls the callback with the port id of every connected sender and the number of
+    /// samples the receiver currently borrows from it on the given channel.
+    pub(crate) fn list_borrow_counts<F: FnMut(u128, usize) -> CallbackProgression>(
+        &self,
+        channel_id: ChannelId,
+        mut callback: F,
+    ) {
+        self.for_each_connection(|connection| {
+            callback(
+                connection.sender_port_id,
+                connection.receiver.borrow_count(channel_id),
+            )
+        })
+    }
+
     pub(crate) fn prepare_connection_removal(&self, index: usize) {
         if let Some(to_be_removed_connections) = &self.to_be_removed_connections {
             let key = unsafe { *self.connections[index].get() };
@@ -238,6 +304,9 @@ impl<Service: service::Service> Receiver<Service> {
     }
 
     pub(crate) fn remove_connection(&self, index: usize) {
+        if unsafe { &*self.connections[index].get() }.is_some() {
+            self.connection_generation.fetch_add(1, Ordering::Relaxed);
+        }
         self.prepare_connection_removal(index);
         unsafe { *self.connections[index].get() = None };
     }
@@ -267,6 +336,10 @@ impl<Service: service::Service> Receiver<Service> {
         false
     }
 
+    fn has_samples_in_any_channel(&self) -> bool {
+        (0..self.number_of_channels).any(|id| self.has_samples(ChannelId::new(id)))
+    }
+
     fn receive_from_connection(
         &self,
         connection: &Connection<Service>,
@@ -365,6 +438,31 @@ impl<Service: service::Service> Receiver<Service> {
     pub(crate) fn receive(
         &self,
         channel_id: ChannelId,
+    ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let data = self.receive_impl(channel_id)?;
+        if data.is_some() {
+            return Ok(data);
+        }
+
+        match &self.delivery_listener {
+            None => Ok(None),
+            Some(delivery_listener) => {
+                // The notifications are consumed before receiving again, so that data that
+                // arrives in between is not lost. When data remains, for instance in another
+                // channel, the listener is re-armed so that an attached WaitSet wakes up again.
+                delivery_listener.reset();
+                let data = self.receive_impl(channel_id)?;
+                if self.has_samples_in_any_channel() {
+                    delivery_listener.rearm();
+                }
+                Ok(data)
+            }
+        }
+    }
+
+    fn receive_impl(
+        &self,
+        channel_id: ChannelId,
     ) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         if let Some(data) = self.receive_from_to_be_removed_connections(channel_id)? {
             return Ok(Some(data));
@@ -429,6 +527,9 @@ impl<Service: service::Service> Receiver<Service> {
         };
 
         if is_connected {
+            if unsafe { &*self.connections[index].get() }.is_some() {
+                self.connection_generation.fetch_add(1, Ordering::Relaxed);
+            }
             self.prepare_connection_removal(index);
 
             match self.create(index, &sender_details) {
@@ -481,4 +582,38 @@ impl<Service: service::Service> Receiver<Service> {
     pub(crate) fn payload_size(&self) -> usize {
         self.message_type_details.payload.size
     }
+
+    /// When the sender uses an older version of an evolved payload type with a payload size
+    /// smaller than the local one, the payload of the [`Chunk`] is copied into zeroed memory
+    /// of the local payload size and the [`Chunk`] refers to the copy afterwards.
+    pub(crate) fn pad_evolved_payload(
+        &self,
+        chunk: &mut Chunk,
+        sender_payload_size: usize,
+        number_of_elements: usize,
+    ) -> Option<PaddedPayload> {
+        let payload = &self.message_type_details.payload;
+        let layout =
+            Layout::from_size_align(payload.size * number_of_elements, payload.alignment).ok()?;
+        let padded_payload = PaddedPayload::new(
+            chunk.payload,
+            sender_payload_size * number_of_elements,
+            layout,
+        )?;
+        chunk.payload = padded_payload.as_ptr();
+        Some(padded_payload)
+    }
+}
+
+impl<Service: service::Service> Receiver<Service>
+where
+    <Service::Event as Event>::Listener: FileDescriptorBased,
+{
+    /// Returns the [`FileDescriptor`] that is signalled whenever new data is delivered or
+    /// [`None`] when the service does not notify on delivery.
+    pub(crate) fn delivery_file_descriptor(&self) -> Option<&FileDescriptor> {
+        self.delivery_listener
+            .as_ref()
+            .map(|delivery_listener| delivery_listener.file_descriptor())
+    }
 }
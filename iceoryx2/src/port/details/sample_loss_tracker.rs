@@ -0,0 +1,78 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SenderSampleLoss {
+    pub(crate) origin: u128,
+    pub(crate) missed_samples: u64,
+    next_sequence_number: u64,
+}
+
+/// Detects lost samples from gaps in the sequence numbers of the received samples. Every
+/// sender numbers its samples consecutively, so a gap between two received samples of the same
+/// sender means that the samples in between were never received.
+#[derive(Debug)]
+pub(crate) struct SampleLossTracker {
+    senders: Vec<SenderSampleLoss>,
+    capacity: usize,
+    total_missed_samples: u64,
+}
+
+impl SampleLossTracker {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            senders: Vec::with_capacity(capacity),
+            capacity,
+            total_missed_samples: 0,
+        }
+    }
+
+    pub(crate) fn total_missed_samples(&self) -> u64 {
+        self.total_missed_samples
+    }
+
+    pub(crate) fn senders(&self) -> &[SenderSampleLoss] {
+        &self.senders
+    }
+
+    pub(crate) fn track(&mut self, origin: u128, sequence_number: u64) {
+        match self.senders.iter_mut().find(|s| s.origin == origin) {
+            Some(sender) => {
+                // a smaller sequence number means that the sender started over, it is used
+                // as new reference without counting anything as missed
+                if sender.next_sequence_number < sequence_number {
+                    let missed_samples = sequence_number - sender.next_sequence_number;
+                    sender.missed_samples += missed_samples;
+                    self.total_missed_samples += missed_samples;
+                }
+                sender.next_sequence_number = sequence_number.wrapping_add(1);
+            }
+            None => {
+                // the first received sample is the reference, samples that were sent before
+                // the connection was established are not missed
+                if !self.senders.is_empty() && self.capacity <= self.senders.len() {
+                    self.senders.remove(0);
+                }
+
+                self.senders.push(SenderSampleLoss {
+                    origin,
+                    missed_samples: 0,
+                    next_sequence_number: sequence_number.wrapping_add(1),
+                });
+            }
+        }
+    }
+
+    pub(crate) fn retain_senders<F: FnMut(u128) -> bool>(&mut self, mut is_connected: F) {
+        self.senders.retain(|s| is_connected(s.origin));
+    }
+}
@@ -55,4 +55,11 @@ impl SegmentState {
         self.sample_reference_counter[self.sample_index(distance_to_chunk)]
             .fetch_sub(1, Ordering::Relaxed)
     }
+
+    pub(crate) fn number_of_borrowed_samples(&self) -> usize {
+        self.sample_reference_counter
+            .iter()
+            .filter(|counter| counter.load(Ordering::Relaxed) != 0)
+            .count()
+    }
 }
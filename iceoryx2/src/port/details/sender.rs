@@ -18,19 +18,22 @@ extern crate alloc;
 use alloc::sync::Arc;
 
 use iceoryx2_bb_elementary::cyclic_tagger::*;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shm_allocator::{AllocationError, PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
     ZeroCopySendError, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
 
 use crate::node::SharedNode;
-use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError};
+use crate::port::{DegradationAction, DegradationCallback, LoanError, SendError, ShrinkToFitError};
 use crate::prelude::UnableToDeliverStrategy;
 use crate::service::config_scheme::connection_config;
+use crate::service::dynamic_config::publish_subscribe::DataSegmentUsage;
 use crate::service::static_config::message_type_details::{MessageTypeDetails, TypeVariant};
 use crate::service::ServiceState;
 use crate::{service, service::naming_scheme::connection_name};
@@ -39,8 +42,12 @@ use super::channel_management::ChannelManagement;
 use super::channel_management::INVALID_CHANNEL_STATE;
 use super::chunk::ChunkMut;
 use super::data_segment::DataSegment;
+use super::delivery_notification::DeliveryNotifier;
 use super::segment_state::SegmentState;
 
+/// Marks a [`Sender`] whose data segment usage is not tracked in the dynamic config.
+pub(crate) const INVALID_DATA_SEGMENT_USAGE_INDEX: usize = usize::MAX;
+
 #[derive(Clone, Copy)]
 pub(crate) struct ReceiverDetails {
     pub(crate) port_id: u128,
@@ -51,6 +58,7 @@ pub(crate) struct ReceiverDetails {
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) receiver_port_id: u128,
+    delivery_notifier: Option<DeliveryNotifier<Service>>,
     tag: Tag,
 }
 
@@ -92,9 +100,16 @@ impl<Service: service::Service> Connection<Service> {
                                 .create_sender(),
                         "{}.", msg);
 
+        let delivery_notifier = if this.enable_notify_on_delivery {
+            DeliveryNotifier::open(receiver_port_id, this.shared_node.config())
+        } else {
+            None
+        };
+
         Ok(Self {
             sender,
             receiver_port_id,
+            delivery_notifier,
             tag,
         })
     }
@@ -117,9 +132,13 @@ pub(crate) struct Sender<Service: service::Service> {
     pub(crate) service_state: Arc<ServiceState<Service>>,
     pub(crate) tagger: CyclicTagger,
     pub(crate) loan_counter: IoxAtomicUsize,
+    pub(crate) number_of_overflows: IoxAtomicU64,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) message_type_details: MessageTypeDetails,
     pub(crate) number_of_channels: usize,
+    pub(crate) data_segment_usage_index: IoxAtomicUsize,
+    pub(crate) connection_generation: IoxAtomicU64,
+    pub(crate) enable_notify_on_delivery: bool,
 }
 
 impl<Service: service::Service> Sender<Service> {
@@ -148,12 +167,13 @@ impl<Service: service::Service> Sender<Service> {
         None
     }
 
-    fn deliver_offset_to_connection_impl(
+    fn deliver_offset_to_connection_impl<F: FnMut(usize, bool)>(
         &self,
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
         connection_id: usize,
+        on_delivery: &mut F,
     ) -> Result<usize, SendError> {
         let deliver_call = match self.unable_to_deliver_strategy {
             UnableToDeliverStrategy::Block => {
@@ -167,12 +187,15 @@ impl<Service: service::Service> Sender<Service> {
         let mut number_of_recipients = 0;
         if let Some(ref connection) = self.get(connection_id) {
             match deliver_call(&connection.sender, offset, sample_size, channel_id) {
-                Err(ZeroCopySendError::ReceiveBufferFull)
-                | Err(ZeroCopySendError::UsedChunkListFull) => {
+                Err(e @ ZeroCopySendError::ReceiveBufferFull)
+                | Err(e @ ZeroCopySendError::UsedChunkListFull) => {
                     /* causes no problem
                      *   blocking_send => can never happen
                      *   try_send => we tried and expect that the buffer is full
                      * */
+                    if e == ZeroCopySendError::ReceiveBufferFull {
+                        self.number_of_overflows.fetch_add(1, Ordering::Relaxed);
+                    }
                 }
                 Err(ZeroCopySendError::ConnectionCorrupted) => match &self.degradation_callback {
                     Some(c) => match c.call(
@@ -203,8 +226,14 @@ impl<Service: service::Service> Sender<Service> {
                     number_of_recipients += 1;
 
                     if let Some(old) = overflow {
+                        self.number_of_overflows.fetch_add(1, Ordering::Relaxed);
                         self.release_sample(old)
                     }
+                    on_delivery(connection_id, overflow.is_some());
+
+                    if let Some(delivery_notifier) = &connection.delivery_notifier {
+                        delivery_notifier.notify();
+                    }
                 }
             }
         }
@@ -245,7 +274,13 @@ impl<Service: service::Service> Sender<Service> {
         connection_id: usize,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
-        self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, connection_id)
+        self.deliver_offset_to_connection_impl(
+            offset,
+            sample_size,
+            channel_id,
+            connection_id,
+            &mut |_, _| {},
+        )
     }
 
     pub(crate) fn deliver_offset(
@@ -253,13 +288,31 @@ impl<Service: service::Service> Sender<Service> {
         offset: PointerOffset,
         sample_size: usize,
         channel_id: ChannelId,
+    ) -> Result<usize, SendError> {
+        self.deliver_offset_and_track(offset, sample_size, channel_id, |_, _| {})
+    }
+
+    /// Delivers the offset to all connections like [`Sender::deliver_offset()`] and calls
+    /// `on_delivery` for every connection that received it with the connection id and whether
+    /// an older sample in the receive buffer was overwritten.
+    pub(crate) fn deliver_offset_and_track<F: FnMut(usize, bool)>(
+        &self,
+        offset: PointerOffset,
+        sample_size: usize,
+        channel_id: ChannelId,
+        mut on_delivery: F,
     ) -> Result<usize, SendError> {
         self.retrieve_returned_samples();
 
         let mut number_of_recipients = 0;
         for i in 0..self.len() {
-            number_of_recipients +=
-                self.deliver_offset_to_connection_impl(offset, sample_size, channel_id, i)?;
+            number_of_recipients += self.deliver_offset_to_connection_impl(
+                offset,
+                sample_size,
+                channel_id,
+                i,
+                &mut on_delivery,
+            )?;
         }
         Ok(number_of_recipients)
     }
@@ -281,10 +334,28 @@ impl<Service: service::Service> Sender<Service> {
             self.number_of_samples,
             self.tagger.create_tag(),
         )?);
+        self.connection_generation.fetch_add(1, Ordering::Relaxed);
 
         Ok(())
     }
 
+    pub(crate) fn connection_generation(&self) -> u64 {
+        self.connection_generation.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn list_connected_receivers<F: FnMut(u128) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        for i in 0..self.len() {
+            if let Some(connection) = self.get(i) {
+                if callback(connection.receiver_port_id) == CallbackProgression::Stop {
+                    return;
+                }
+            }
+        }
+    }
+
     fn len(&self) -> usize {
         self.connections.len()
     }
@@ -321,6 +392,13 @@ impl<Service: service::Service> Sender<Service> {
                 "{} since the allocated sample is already in use! This should never happen!", msg);
         }
 
+        if let Some(usage) = self.data_segment_usage() {
+            usage.record_allocation(
+                sample_size,
+                self.data_segment.current_bucket_size() * self.number_of_samples,
+            );
+        }
+
         self.loan_counter.fetch_add(1, Ordering::Relaxed);
         Ok(ChunkMut::new(
             &self.message_type_details,
@@ -340,7 +418,8 @@ impl<Service: service::Service> Sender<Service> {
         (segment_state.borrow_sample(offset.offset()), payload_size)
     }
 
-    pub(crate) fn retrieve_returned_samples(&self) {
+    pub(crate) fn retrieve_returned_samples(&self) -> usize {
+        let mut number_of_retrieved_samples = 0;
         for i in 0..self.len() {
             if let Some(ref connection) = self.get(i) {
                 for channel_id in 0..self.number_of_channels {
@@ -349,6 +428,7 @@ impl<Service: service::Service> Sender<Service> {
                         match connection.sender.reclaim(id) {
                             Ok(Some(ptr_dist)) => {
                                 self.release_sample(ptr_dist);
+                                number_of_retrieved_samples += 1;
                             }
                             Ok(None) => break,
                             Err(e) => {
@@ -359,15 +439,42 @@ impl<Service: service::Service> Sender<Service> {
                 }
             }
         }
+
+        number_of_retrieved_samples
+    }
+
+    pub(crate) fn number_of_borrowed_samples(&self) -> usize {
+        self.segment_states
+            .iter()
+            .map(|segment_state| segment_state.number_of_borrowed_samples())
+            .sum()
     }
 
     pub(crate) fn release_sample(&self, offset: PointerOffset) {
-        if self.segment_states[offset.segment_id().value() as usize].release_sample(offset.offset())
-            == 1
-        {
+        let segment_state = &self.segment_states[offset.segment_id().value() as usize];
+        if segment_state.release_sample(offset.offset()) == 1 {
+            // the segment may be released with the deallocation of its last bucket
+            let chunk_size = segment_state.payload_size();
             unsafe {
                 self.data_segment.deallocate_bucket(offset);
             }
+
+            if let Some(usage) = self.data_segment_usage() {
+                usage.record_deallocation(chunk_size);
+            }
+        }
+    }
+
+    fn data_segment_usage(&self) -> Option<&DataSegmentUsage> {
+        match self.data_segment_usage_index.load(Ordering::Relaxed) {
+            INVALID_DATA_SEGMENT_USAGE_INDEX => None,
+            index => Some(
+                self.service_state
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .data_segment_usage(index),
+            ),
         }
     }
 
@@ -382,6 +489,7 @@ impl<Service: service::Service> Sender<Service> {
             };
 
             *self.get_mut(i) = None;
+            self.connection_generation.fetch_add(1, Ordering::Relaxed);
         }
     }
 
@@ -464,6 +572,17 @@ impl<Service: service::Service> Sender<Service> {
         self.message_type_details.sample_layout(number_of_elements)
     }
 
+    pub(crate) fn shrink_to_fit(&self) -> Result<usize, ShrinkToFitError> {
+        // samples the receivers already returned shall not keep the old segment alive
+        self.retrieve_returned_samples();
+        self.data_segment.shrink_to_fit()
+    }
+
+    pub(crate) fn current_max_number_of_elements(&self) -> usize {
+        self.message_type_details
+            .max_number_of_elements(self.data_segment.current_bucket_size())
+    }
+
     pub(crate) fn payload_type_variant(&self) -> TypeVariant {
         self.message_type_details.payload.variant
     }
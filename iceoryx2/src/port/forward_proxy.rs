@@ -0,0 +1,250 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::forward_proxy::ForwardProxy;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let upstream_service = node.service_builder(&"My/Funk/Upstream".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let downstream_service = node.service_builder(&"My/Funk/Downstream".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let mut proxy = ForwardProxy::new(
+//!     upstream_service.subscriber_builder().create()?,
+//!     downstream_service.publisher_builder().create()?,
+//! );
+//!
+//! # let publisher = upstream_service.publisher_builder().create()?;
+//! # publisher.send_copy(1234)?;
+//! let number_of_forwarded_samples = proxy.forward()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::publisher::Publisher;
+use crate::port::subscriber::Subscriber;
+use crate::port::{LoanError, ReceiveError, SendError};
+use crate::service;
+
+/// Failures that can occur when [`Sample`](crate::sample::Sample)s are forwarded with
+/// [`ForwardProxy::forward()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForwardProxyError {
+    /// The [`Sample`](crate::sample::Sample) could not be received from the upstream
+    /// [`Subscriber`].
+    ReceiveError(ReceiveError),
+    /// The downstream [`Publisher`] was unable to loan memory. The
+    /// [`Sample`](crate::sample::Sample) stays in the upstream [`Subscriber`] and is
+    /// forwarded with the next [`ForwardProxy::forward()`] call.
+    LoanError(LoanError),
+    /// The [`Sample`](crate::sample::Sample) could not be sent with the downstream
+    /// [`Publisher`].
+    SendError(SendError),
+}
+
+impl From<ReceiveError> for ForwardProxyError {
+    fn from(value: ReceiveError) -> Self {
+        ForwardProxyError::ReceiveError(value)
+    }
+}
+
+impl From<LoanError> for ForwardProxyError {
+    fn from(value: LoanError) -> Self {
+        ForwardProxyError::LoanError(value)
+    }
+}
+
+impl From<SendError> for ForwardProxyError {
+    fn from(value: SendError) -> Self {
+        ForwardProxyError::SendError(value)
+    }
+}
+
+impl core::fmt::Display for ForwardProxyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ForwardProxyError::{self:?}")
+    }
+}
+
+impl core::error::Error for ForwardProxyError {}
+
+/// Receives every [`Sample`](crate::sample::Sample) with an upstream [`Subscriber`] and
+/// republishes it with a downstream [`Publisher`] whenever [`ForwardProxy::forward()`] is
+/// called. Both ports must share the same payload and user header type. The
+/// [`Service`](crate::service::Service) type of both ports can differ.
+///
+/// The payload and the user header are copied once from the upstream data segment into a
+/// [`SampleMut`](crate::sample_mut::SampleMut) of the downstream [`Publisher`]. A
+/// [`Sample`](crate::sample::Sample) cannot be republished without this copy since the
+/// [`Subscriber`]s of the downstream [`Service`](crate::service::Service) have no access to
+/// the data segment of the upstream [`Publisher`].
+///
+/// The [`ForwardProxy`] does not spawn a thread, the user decides when and from which thread
+/// [`ForwardProxy::forward()`] is called. [`Sample`](crate::sample::Sample)s that were sent by
+/// the downstream [`Publisher`] itself are not forwarded again, so that a [`ForwardProxy`]
+/// that subscribes to the [`Service`](crate::service::Service) it publishes on does not loop.
+#[derive(Debug)]
+pub struct ForwardProxy<
+    Upstream: service::Service,
+    Downstream: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    upstream: Subscriber<Upstream, Payload, UserHeader>,
+    downstream: Publisher<Downstream, Payload, UserHeader>,
+}
+
+impl<
+        Upstream: service::Service,
+        Downstream: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > ForwardProxy<Upstream, Downstream, Payload, UserHeader>
+{
+    /// Creates a new [`ForwardProxy`] that forwards from the `upstream` [`Subscriber`] to the
+    /// `downstream` [`Publisher`].
+    pub fn new(
+        upstream: Subscriber<Upstream, Payload, UserHeader>,
+        downstream: Publisher<Downstream, Payload, UserHeader>,
+    ) -> Self {
+        Self {
+            upstream,
+            downstream,
+        }
+    }
+
+    /// Returns the upstream [`Subscriber`].
+    pub fn upstream(&self) -> &Subscriber<Upstream, Payload, UserHeader> {
+        &self.upstream
+    }
+
+    /// Returns the downstream [`Publisher`].
+    pub fn downstream(&self) -> &Publisher<Downstream, Payload, UserHeader> {
+        &self.downstream
+    }
+
+    /// Releases the [`ForwardProxy`] and returns the upstream [`Subscriber`] and the
+    /// downstream [`Publisher`].
+    pub fn into_ports(
+        self,
+    ) -> (
+        Subscriber<Upstream, Payload, UserHeader>,
+        Publisher<Downstream, Payload, UserHeader>,
+    ) {
+        (self.upstream, self.downstream)
+    }
+}
+
+impl<
+        Upstream: service::Service,
+        Downstream: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ForwardProxy<Upstream, Downstream, Payload, UserHeader>
+{
+    /// Forwards all received [`Sample`](crate::sample::Sample)s from the upstream
+    /// [`Subscriber`] to the downstream [`Publisher`] and returns the number of forwarded
+    /// [`Sample`](crate::sample::Sample)s.
+    pub fn forward(&mut self) -> Result<usize, ForwardProxyError> {
+        let downstream_id = self.downstream.id();
+        let mut number_of_forwarded_samples = 0;
+        while let Some(sample) = self.upstream.peek()? {
+            if sample.header().publisher_id() == downstream_id {
+                drop(self.upstream.receive()?);
+                continue;
+            }
+
+            let mut target_sample = fail!(from self.downstream, when self.downstream.loan_uninit(),
+                "Unable to forward sample since the downstream publisher is unable to loan memory.");
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.user_header(),
+                    target_sample.user_header_mut(),
+                    1,
+                );
+                core::ptr::copy_nonoverlapping(
+                    sample.payload(),
+                    target_sample.payload_mut().as_mut_ptr(),
+                    1,
+                );
+            }
+
+            drop(self.upstream.receive()?);
+            fail!(from self, when unsafe { target_sample.assume_init() }.send(),
+                "Unable to forward sample since it could not be sent with the downstream publisher.");
+            number_of_forwarded_samples += 1;
+        }
+
+        Ok(number_of_forwarded_samples)
+    }
+}
+
+impl<
+        Upstream: service::Service,
+        Downstream: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ForwardProxy<Upstream, Downstream, [Payload], UserHeader>
+{
+    /// Forwards all received [`Sample`](crate::sample::Sample)s from the upstream
+    /// [`Subscriber`] to the downstream [`Publisher`] and returns the number of forwarded
+    /// [`Sample`](crate::sample::Sample)s. Slices that exceed the max slice len of the
+    /// downstream [`Publisher`] can only be forwarded when it was created with an
+    /// [`AllocationStrategy`](crate::prelude::AllocationStrategy) that allows it to grow.
+    pub fn forward(&mut self) -> Result<usize, ForwardProxyError> {
+        let downstream_id = self.downstream.id();
+        let mut number_of_forwarded_samples = 0;
+        while let Some(sample) = self.upstream.peek()? {
+            if sample.header().publisher_id() == downstream_id {
+                drop(self.upstream.receive()?);
+                continue;
+            }
+
+            let mut target_sample = fail!(from self.downstream, when self.downstream.loan_slice_uninit(sample.payload().len()),
+                "Unable to forward sample since the downstream publisher is unable to loan memory.");
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.user_header(),
+                    target_sample.user_header_mut(),
+                    1,
+                );
+                core::ptr::copy_nonoverlapping(
+                    sample.payload().as_ptr(),
+                    target_sample.payload_mut().as_mut_ptr().cast(),
+                    sample.payload().len(),
+                );
+            }
+
+            drop(self.upstream.receive()?);
+            fail!(from self, when unsafe { target_sample.assume_init() }.send(),
+                "Unable to forward sample since it could not be sent with the downstream publisher.");
+            number_of_forwarded_samples += 1;
+        }
+
+        Ok(number_of_forwarded_samples)
+    }
+}
@@ -105,6 +105,22 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+/// Defines the failures that can occur when the deadline of the
+/// [`Service`](crate::service::Service) is checked with [`Listener::has_missed_deadline()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ListenerDeadlineError {
+    /// The elapsed system time could not be acquired.
+    UnableToAcquireElapsedTime,
+}
+
+impl core::fmt::Display for ListenerDeadlineError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ListenerDeadlineError::{self:?}")
+    }
+}
+
+impl core::error::Error for ListenerDeadlineError {}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -222,6 +238,33 @@ impl<Service: service::Service> Listener<Service> {
             .map(|v| v.value)
     }
 
+    /// Returns true when the [`Service`](crate::service::Service) has a deadline and no
+    /// [`Notifier`](crate::port::notifier::Notifier) has sent a notification within it since the
+    /// last notification or, if there was none, since the creation of the
+    /// [`Service`](crate::service::Service). Without a deadline it always returns false.
+    pub fn has_missed_deadline(&self) -> Result<bool, ListenerDeadlineError> {
+        let deadline = match self.service_state.static_config.event().deadline {
+            Some(deadline) => deadline,
+            None => return Ok(false),
+        };
+
+        let duration_since_creation = fail!(from self, when deadline.creation_time.elapsed(),
+            with ListenerDeadlineError::UnableToAcquireElapsedTime,
+            "Unable to check the deadline since the elapsed system time could not be acquired.");
+
+        let last_notification_since_creation = Duration::from_nanos(
+            self.service_state
+                .dynamic_storage
+                .get()
+                .event()
+                .elapsed_time_since_last_notification
+                .load(Ordering::Relaxed),
+        );
+
+        Ok(deadline.value
+            < duration_since_creation.saturating_sub(last_notification_since_creation))
+    }
+
     /// Non-blocking wait for new [`EventId`]s. Collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
     pub fn try_wait_all<F: FnMut(EventId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
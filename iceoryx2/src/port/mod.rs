@@ -22,6 +22,9 @@ pub use details::data_segment::DataSegmentType;
 pub mod client;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
+/// Republishes the samples of a [`Subscriber`](crate::port::subscriber::Subscriber) with a
+/// [`Publisher`](crate::port::publisher::Publisher) of another service.
+pub mod forward_proxy;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
 /// Sending endpoint (port) for event based communication
@@ -92,6 +95,9 @@ pub enum LoanError {
     /// port must be configured with an
     /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy).
     ExceedsMaxLoanSize,
+    /// The provided slice size exceeds the maximum payload size that was defined when the
+    /// [`Service`](crate::service::Service) was created.
+    PayloadTooLarge,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
     InternalFailure,
 }
@@ -104,6 +110,27 @@ impl core::fmt::Display for LoanError {
 
 impl core::error::Error for LoanError {}
 
+/// Defines a failure that can occur in
+/// [`Publisher::shrink_to_fit()`](crate::port::publisher::Publisher::shrink_to_fit()),
+/// [`Client::shrink_to_fit()`](crate::port::client::Client::shrink_to_fit()) and
+/// [`Server::shrink_to_fit()`](crate::port::server::Server::shrink_to_fit()).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ShrinkToFitError {
+    /// The data segment cannot be replaced since the maximum number of reallocations was
+    /// already reached.
+    MaxReallocationsReached,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl core::fmt::Display for ShrinkToFitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ShrinkToFitError::{self:?}")
+    }
+}
+
+impl core::error::Error for ShrinkToFitError {}
+
 /// Failure that can be emitted when data is sent.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum SendError {
@@ -115,6 +142,10 @@ pub enum SendError {
     LoanError(LoanError),
     /// A failure occurred while establishing a connection to the ports counterpart port.
     ConnectionError(ConnectionFailure),
+    /// A [`Response`](crate::response::Response) was already sent for the
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest) and the
+    /// [`Service`](crate::service::Service) does not support multiple responses per request.
+    MultiResponseDisabled,
 }
 
 impl From<LoanError> for SendError {
@@ -146,6 +177,12 @@ pub enum ReceiveError {
     /// until the user has released older data.
     ExceedsMaxBorrows,
 
+    /// The received slice has more elements than the max slice len that was configured for the
+    /// receiver, for instance with
+    /// [`PortFactorySubscriber::max_slice_len()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_slice_len()).
+    /// The data was discarded.
+    ExceedsMaxSliceLen,
+
     /// Occurs when a receiver is unable to connect to a corresponding sender.
     ConnectionFailure(ConnectionFailure),
 }
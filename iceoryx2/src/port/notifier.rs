@@ -25,7 +25,7 @@
 //!     .default_event_id(EventId::new(12))
 //!     .create()?;
 //!
-//! // notify with default event id 123
+//! // notify with default event id 12
 //! notifier.notify()?;
 //!
 //! // notify with some custom event id
@@ -96,6 +96,11 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::notify_with_custom_event_ids()`] was called and at least one
+    /// [`Listener`](crate::port::listener::Listener) was unable to hold all provided
+    /// [`EventId`]s. None of the [`EventId`]s were delivered to this
+    /// [`Listener`](crate::port::listener::Listener).
+    ListenerBufferOverflow,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -393,8 +398,16 @@ impl<Service: service::Service> Notifier<Service> {
         self.notifier_id
     }
 
+    /// Returns the [`EventId`] that is used by [`Notifier::notify()`]. If no default
+    /// [`EventId`] was provided via
+    /// [`PortFactoryNotifier::default_event_id()`](crate::service::port_factory::notifier::PortFactoryNotifier::default_event_id())
+    /// it is `EventId::new(0)`.
+    pub fn default_event_id(&self) -> EventId {
+        self.default_event_id
+    }
+
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
-    /// event id provided on creation.
+    /// event id provided on creation, see [`Notifier::default_event_id()`].
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
     /// [`NotifierNotifyError`].
@@ -425,6 +438,45 @@ impl<Service: service::Service> Notifier<Service> {
         self.__internal_notify(value, false)
     }
 
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with all
+    /// provided [`EventId`]s as one batch. Every [`crate::port::listener::Listener`] either
+    /// receives all [`EventId`]s or none of them, so that the batch is not interleaved with
+    /// other notifications.
+    /// If one [`EventId`] exceeds the maximum supported [`EventId`] of the
+    /// [`Service`](crate::service::Service) nothing is delivered and
+    /// [`NotifierNotifyError::EventIdOutOfBounds`] is returned. If a
+    /// [`crate::port::listener::Listener`] cannot hold the whole batch, the remaining
+    /// [`crate::port::listener::Listener`]s are still notified and
+    /// [`NotifierNotifyError::ListenerBufferOverflow`] is returned.
+    /// On success the number of [`crate::port::listener::Listener`]s that were notified
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    ///
+    /// let notifier = event.notifier_builder().create()?;
+    /// notifier.notify_with_custom_event_ids(&[EventId::new(3), EventId::new(7)])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn notify_with_custom_event_ids(
+        &self,
+        values: &[EventId],
+    ) -> Result<usize, NotifierNotifyError> {
+        if values.is_empty() {
+            return Ok(0);
+        }
+
+        self.notify_impl(values, false)
+    }
+
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with a custom
     /// [`EventId`].
     /// On success the number of
@@ -439,6 +491,14 @@ impl<Service: service::Service> Notifier<Service> {
         &self,
         value: EventId,
         skip_self_deliver: bool,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(core::slice::from_ref(&value), skip_self_deliver)
+    }
+
+    fn notify_impl(
+        &self,
+        values: &[EventId],
+        skip_self_deliver: bool,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         let listener_connections = self.listener_connections.lock();
@@ -446,8 +506,12 @@ impl<Service: service::Service> Notifier<Service> {
 
         use iceoryx2_cal::event::Notifier;
         let mut number_of_triggered_listeners = 0;
+        let mut has_overflow = false;
 
-        if self.event_id_max_value < value.as_value() {
+        if let Some(value) = values
+            .iter()
+            .find(|v| self.event_id_max_value < v.as_value())
+        {
             fail!(from self, with NotifierNotifyError::EventIdOutOfBounds,
                             "{} since the EventId {:?} exceeds the maximum supported EventId value of {}.",
                             msg, value, self.event_id_max_value);
@@ -456,10 +520,22 @@ impl<Service: service::Service> Notifier<Service> {
         for i in 0..listener_connections.len() {
             if let Some(ref connection) = listener_connections.get(i) {
                 if !(skip_self_deliver && connection.node_id == self.node_id) {
-                    match connection.notifier.notify(value) {
+                    let result = match values {
+                        [value] => connection.notifier.notify(*value),
+                        _ => connection.notifier.notify_multiple(values),
+                    };
+
+                    match result {
                         Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                             listener_connections.remove(i);
                         }
+                        Err(iceoryx2_cal::event::NotifierNotifyError::FailedToDeliverSignal)
+                            if values.len() > 1 =>
+                        {
+                            warn!(from self, "Unable to deliver {} EventIds as one batch via connection {:?} since the listener cannot hold all of them.",
+                                    values.len(), connection);
+                            has_overflow = true;
+                        }
                         Err(e) => {
                             warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
                                     connection, e)
@@ -503,6 +579,12 @@ impl<Service: service::Service> Notifier<Service> {
             }
         }
 
+        if has_overflow {
+            fail!(from self, with NotifierNotifyError::ListenerBufferOverflow,
+                "{} since at least one listener was unable to hold all {} EventIds.",
+                msg, values.len());
+        }
+
         Ok(number_of_triggered_listeners)
     }
 }
@@ -20,7 +20,19 @@ macro_rules! generate_id {
         $id_name:ident } => {
         $(#[$documentation])*
         #[repr(C)]
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ZeroCopySend)]
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            ZeroCopySend,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
         pub struct $id_name(pub(crate) UniqueSystemId);
 
         impl Default for $id_name {
@@ -76,7 +88,7 @@ generate_id! {
 }
 
 /// Enum that contains the unique port id
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum UniquePortId {
     /// The system-wide unique id of a [`Publisher`](crate::port::publisher::Publisher).
     Publisher(UniquePublisherId),
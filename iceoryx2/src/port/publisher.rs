@@ -103,8 +103,9 @@
 
 use super::details::data_segment::{DataSegment, DataSegmentType};
 use super::details::segment_state::SegmentState;
-use super::port_identifiers::UniquePublisherId;
-use super::{LoanError, SendError};
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::{LoanError, SendError, ShrinkToFitError};
+use crate::node::Node;
 use crate::port::details::sender::*;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::prelude::UnableToDeliverStrategy;
@@ -113,7 +114,7 @@ use crate::sample_mut::SampleMut;
 use crate::sample_mut_uninit::SampleMutUninit;
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
-use crate::service::header::publish_subscribe::Header;
+use crate::service::header::publish_subscribe::{Header, Timestamp};
 use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::publisher::LocalPublisherConfig;
 use crate::service::static_config::message_type_details::TypeVariant;
@@ -133,11 +134,12 @@ use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::monitoring::State;
 use iceoryx2_cal::shm_allocator::{AllocationStrategy, PointerOffset};
 use iceoryx2_cal::zero_copy_connection::{
     ChannelId, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySender,
 };
-use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 extern crate alloc;
 
@@ -165,6 +167,47 @@ impl core::fmt::Display for PublisherCreateError {
 
 impl core::error::Error for PublisherCreateError {}
 
+/// Defines a failure that can occur when the history size of a [`Publisher`] is adjusted with
+/// [`Publisher::set_history_size()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PublisherSetHistorySizeError {
+    /// The requested history size exceeds the history size the
+    /// [`Service`](crate::service::Service) was created with. The history buffer is never
+    /// reallocated.
+    ExceedsMaxHistorySize,
+}
+
+impl core::fmt::Display for PublisherSetHistorySizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "PublisherSetHistorySizeError::{self:?}")
+    }
+}
+
+impl core::error::Error for PublisherSetHistorySizeError {}
+
+/// Summarizes the state of the shared memory of a [`Publisher`] after
+/// [`Publisher::drain()`] was called.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct PublisherDrainSummary {
+    /// The number of samples that were returned by the connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s and released.
+    pub reclaimed_samples: usize,
+    /// The number of samples that were held in the history and released.
+    pub released_history_samples: usize,
+    /// The number of samples that are still loaned by the user and neither sent nor dropped.
+    pub outstanding_loans: usize,
+    /// The number of sent samples that are still in the buffer of or borrowed by a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub samples_in_flight: usize,
+}
+
+impl PublisherDrainSummary {
+    /// Returns true when no sample of the [`Publisher`] is loaned or in flight anymore.
+    pub fn is_clean(&self) -> bool {
+        self.outstanding_loans == 0 && self.samples_in_flight == 0
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct OffsetAndSize {
     offset: u64,
@@ -177,6 +220,9 @@ pub(crate) struct PublisherSharedState<Service: service::Service> {
     pub(crate) sender: Sender<Service>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<OffsetAndSize>>>,
+    history_size: IoxAtomicUsize,
+    sequence_number: IoxAtomicU64,
+    enable_send_timestamp: bool,
     is_active: IoxAtomicBool,
 }
 
@@ -185,6 +231,11 @@ impl<Service: service::Service> PublisherSharedState<Service> {
         match &self.history {
             None => (),
             Some(history) => {
+                let history_size = self.history_size.load(Ordering::Relaxed);
+                if history_size == 0 {
+                    return;
+                }
+
                 let history = unsafe { &mut *history.get() };
                 self.sender.borrow_sample(offset);
                 match history.push_with_overflow(OffsetAndSize {
@@ -196,10 +247,72 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                         .sender
                         .release_sample(PointerOffset::from_value(old.offset)),
                 }
+
+                self.evict_history_entries(history, history_size);
+            }
+        }
+    }
+
+    fn evict_history_entries(&self, history: &mut Queue<OffsetAndSize>, history_size: usize) {
+        while history.len() > history_size {
+            match history.pop() {
+                Some(old) => self
+                    .sender
+                    .release_sample(PointerOffset::from_value(old.offset)),
+                None => break,
+            }
+        }
+    }
+
+    fn clear_history(&self) -> usize {
+        match &self.history {
+            None => 0,
+            Some(history) => {
+                let history = unsafe { &mut *history.get() };
+                let number_of_entries = history.len();
+                self.evict_history_entries(history, 0);
+                number_of_entries
             }
         }
     }
 
+    fn drain(&self) -> PublisherDrainSummary {
+        let released_history_samples = self.clear_history();
+        let reclaimed_samples = self.sender.retrieve_returned_samples();
+        let outstanding_loans = self.sender.loan_counter.load(Ordering::Relaxed);
+        let samples_in_flight = self
+            .sender
+            .number_of_borrowed_samples()
+            .saturating_sub(outstanding_loans);
+
+        PublisherDrainSummary {
+            reclaimed_samples,
+            released_history_samples,
+            outstanding_loans,
+            samples_in_flight,
+        }
+    }
+
+    fn set_history_size(&self, value: usize) -> Result<(), PublisherSetHistorySizeError> {
+        let max_history_size = self
+            .history
+            .as_ref()
+            .map_or(0, |history| unsafe { &*history.get() }.capacity());
+
+        if max_history_size < value {
+            fail!(from self, with PublisherSetHistorySizeError::ExceedsMaxHistorySize,
+                "Unable to set the history size to {} since the service supports a history size of at most {}.",
+                value, max_history_size);
+        }
+
+        self.history_size.store(value, Ordering::Relaxed);
+        if let Some(history) = &self.history {
+            self.evict_history_entries(unsafe { &mut *history.get() }, value);
+        }
+
+        Ok(())
+    }
+
     fn force_update_connections(&self) -> Result<(), ZeroCopyCreationError> {
         let mut result = Ok(());
         self.sender.start_update_connection_cycle();
@@ -279,8 +392,10 @@ impl<Service: service::Service> PublisherSharedState<Service> {
 
     pub(crate) fn send_sample(
         &self,
+        header: &mut Header,
         offset: PointerOffset,
         sample_size: usize,
+        timestamp: Option<Timestamp>,
     ) -> Result<usize, SendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
@@ -288,6 +403,18 @@ impl<Service: service::Service> PublisherSharedState<Service> {
                 "{} since the corresponding publisher is already disconnected.", msg);
         }
 
+        header.set_sequence_number(self.sequence_number.fetch_add(1, Ordering::Relaxed));
+
+        if let Some(timestamp) = timestamp {
+            header.set_timestamp(timestamp);
+        } else if self.enable_send_timestamp {
+            match Timestamp::now() {
+                Ok(timestamp) => header.set_timestamp(timestamp),
+                Err(e) => warn!(from self,
+                    "The sample is sent without timestamp since the current time could not be acquired ({:?}).", e),
+            }
+        }
+
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
@@ -340,6 +467,12 @@ impl<
     fn drop(&mut self) {
         let shared_state = self.publisher_shared_state.lock();
         shared_state.is_active.store(false, Ordering::Relaxed);
+        // samples that outlive the publisher must not be accounted to the publisher that
+        // reuses the slot
+        shared_state
+            .sender
+            .data_segment_usage_index
+            .store(INVALID_DATA_SEGMENT_USAGE_INDEX, Ordering::Relaxed);
         if let Some(handle) = self.dynamic_publisher_handle {
             shared_state
                 .sender
@@ -399,6 +532,7 @@ impl<
             max_slice_len,
             node_id: *service.__internal_state().shared_node.id(),
             max_number_of_segments,
+            ownership_strength: config.ownership_strength,
         };
         let global_config = service.__internal_state().shared_node.config();
 
@@ -409,6 +543,8 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_samples,
+                config.use_huge_pages,
+                config.numa_node,
             ),
             DataSegmentType::Dynamic => DataSegment::create_dynamic_segment(
                 &segment_name,
@@ -416,6 +552,8 @@ impl<
                 global_config,
                 number_of_samples,
                 config.allocation_strategy,
+                config.use_huge_pages,
+                config.numa_node,
             ),
         };
 
@@ -423,6 +561,10 @@ impl<
                 when data_segment,
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
+        service
+            .__internal_state()
+            .shared_node
+            .register_shm_region(&segment_name, data_segment.usage());
 
         let publisher_shared_state =
             <Service as service::Service>::ArcThreadSafetyPolicy::new(PublisherSharedState {
@@ -451,10 +593,14 @@ impl<
                     service_state: service.__internal_state().clone(),
                     tagger: CyclicTagger::new(),
                     loan_counter: IoxAtomicUsize::new(0),
+                    number_of_overflows: IoxAtomicU64::new(0),
                     sender_max_borrowed_samples: config.max_loaned_samples,
                     unable_to_deliver_strategy: config.unable_to_deliver_strategy,
                     message_type_details: static_config.message_type_details.clone(),
                     number_of_channels: 1,
+                    data_segment_usage_index: IoxAtomicUsize::new(INVALID_DATA_SEGMENT_USAGE_INDEX),
+                    connection_generation: IoxAtomicU64::new(0),
+                    enable_notify_on_delivery: static_config.enable_notify_on_delivery,
                 },
                 config,
                 subscriber_list_state: UnsafeCell::new(unsafe { subscriber_list.get_state() }),
@@ -462,6 +608,11 @@ impl<
                     true => None,
                     false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
                 },
+                history_size: IoxAtomicUsize::new(static_config.history_size),
+                sequence_number: IoxAtomicU64::new(0),
+                // the subscriber requires the send timestamp to discard expired samples
+                enable_send_timestamp: static_config.enable_send_timestamp
+                    || static_config.sample_lifespan.is_some(),
             });
 
         let publisher_shared_state = match publisher_shared_state {
@@ -509,6 +660,12 @@ impl<
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        new_self
+            .publisher_shared_state
+            .lock()
+            .sender
+            .data_segment_usage_index
+            .store(dynamic_publisher_handle.index() as usize, Ordering::Relaxed);
 
         Ok(new_self)
     }
@@ -520,6 +677,12 @@ impl<
         ))
     }
 
+    /// Returns the ownership strength of the [`Publisher`], see
+    /// [`PortFactoryPublisher::ownership_strength()`](crate::service::port_factory::publisher::PortFactoryPublisher::ownership_strength()).
+    pub fn ownership_strength(&self) -> u32 {
+        self.publisher_shared_state.lock().config.ownership_strength
+    }
+
     /// Returns the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
     /// since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer is full.
     pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
@@ -528,6 +691,176 @@ impl<
             .sender
             .unable_to_deliver_strategy
     }
+
+    /// Prepares the [`Publisher`] for a clean shutdown. Releases all samples held in the
+    /// history and reclaims every sample the connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s have already returned. Sending is
+    /// synchronous, so there are no pending sends to complete. Samples that are still loaned
+    /// are owned by the user and are released when they are sent or dropped, they are reported
+    /// in the returned [`PublisherDrainSummary`] together with the samples that are still in
+    /// flight.
+    ///
+    /// Samples sent after the call are added to the history again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    ///
+    /// let summary = publisher.drain();
+    /// assert!(summary.is_clean());
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn drain(&self) -> PublisherDrainSummary {
+        self.publisher_shared_state.lock().drain()
+    }
+
+    /// Returns the number of samples that were dropped due to a buffer overflow of a connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) since the [`Publisher`] was created.
+    /// It counts the samples that were replaced in the buffer when safe overflow is enabled
+    /// and the samples that were discarded with [`UnableToDeliverStrategy::DiscardSample`].
+    /// Every affected [`Subscriber`](crate::port::subscriber::Subscriber) is counted
+    /// separately.
+    pub fn overflow_count(&self) -> u64 {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .number_of_overflows
+            .load(Ordering::Relaxed)
+    }
+
+    /// Returns true when the current data segment of the [`Publisher`] is backed by huge pages.
+    /// It is false when huge pages were not requested with
+    /// [`PortFactoryPublisher::use_huge_pages()`](crate::service::port_factory::publisher::PortFactoryPublisher::use_huge_pages())
+    /// or when they could not be obtained from the system.
+    pub fn has_huge_pages(&self) -> bool {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .data_segment
+            .has_huge_pages()
+    }
+
+    /// Returns the id of the NUMA node the current data segment of the [`Publisher`] is bound
+    /// to. It is [`None`] when no node was requested with
+    /// [`PortFactoryPublisher::numa_node()`](crate::service::port_factory::publisher::PortFactoryPublisher::numa_node())
+    /// or [`crate::config::PublishSubscribe::publisher_numa_node`], or when the system does not
+    /// support NUMA.
+    pub fn numa_node(&self) -> Option<usize> {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .data_segment
+            .numa_node()
+    }
+
+    /// Returns the number of samples the [`Publisher`] currently keeps in its history and
+    /// delivers to newly connected [`Subscriber`](crate::port::subscriber::Subscriber)s.
+    pub fn history_size(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .history_size
+            .load(Ordering::Relaxed)
+    }
+
+    /// Adjusts the number of samples the [`Publisher`] keeps in its history at runtime. The
+    /// new size applies to every [`Subscriber`](crate::port::subscriber::Subscriber) that
+    /// connects afterwards. When the history shrinks, the oldest samples are evicted. The
+    /// history size cannot exceed the history size the [`Service`](crate::service::Service)
+    /// was created with, otherwise [`PublisherSetHistorySizeError::ExceedsMaxHistorySize`] is
+    /// returned.
+    pub fn set_history_size(&self, value: usize) -> Result<(), PublisherSetHistorySizeError> {
+        self.publisher_shared_state.lock().set_history_size(value)
+    }
+
+    /// Returns the highest sequence number of this [`Publisher`]s samples that the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) has acknowledged with
+    /// [`Subscriber::mark_received()`](crate::port::subscriber::Subscriber::mark_received()).
+    /// If nothing was acknowledged yet or the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) is not connected, it returns [`None`].
+    pub fn acknowledged_sequence_number(&self, subscriber_id: UniqueSubscriberId) -> Option<u64> {
+        let publisher_id = self.id();
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .acknowledged_sequence_number(publisher_id, subscriber_id)
+    }
+
+    /// Updates the connections and calls the provided callback with the [`SubscriberDetails`]
+    /// of every [`Subscriber`](crate::port::subscriber::Subscriber) that is registered in the
+    /// dynamic state of the [`Service`](crate::service::Service) until it returns
+    /// [`CallbackProgression::Stop`]. The details contain the [`UniqueSubscriberId`] and the
+    /// [`NodeId`](crate::node::NodeId) of the owning [`Node`](crate::node::Node).
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s of [`Node`](crate::node::Node)s
+    /// that are detected as dead by the node monitoring are skipped, even when their stale
+    /// resources were not yet cleaned up.
+    ///
+    /// The [`Publisher`] iterates over its internal copy of the dynamic state, therefore
+    /// no memory is allocated.
+    pub fn connected_subscribers<F: FnMut(&SubscriberDetails) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let state = self.publisher_shared_state.lock();
+        state.update_connections()?;
+        let config = state.sender.shared_node.config();
+        unsafe {
+            (*state.subscriber_list_state.get()).for_each(|_, details| {
+                match Node::<Service>::get_node_state(config, &details.node_id) {
+                    Ok(State::Dead) => CallbackProgression::Continue,
+                    _ => callback(details),
+                }
+            })
+        };
+        Ok(())
+    }
+
+    /// Returns a counter that is increased whenever a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) connects to or disconnects from the
+    /// [`Publisher`]. The connections are updated before the counter is read. Since the
+    /// counter never decreases, a caller that polls it and compares it with the previously
+    /// read value detects every change, even when a connect and a disconnect happened
+    /// in between. The connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s can then be acquired with
+    /// [`Publisher::list_connected_subscribers()`].
+    ///
+    /// The connections are also updated on every send, therefore the counter may change
+    /// without calling this method.
+    pub fn connection_generation(&self) -> Result<u64, ConnectionFailure> {
+        let state = self.publisher_shared_state.lock();
+        state.update_connections()?;
+        Ok(state.sender.connection_generation())
+    }
+
+    /// Updates the connections and calls the provided callback with the
+    /// [`UniqueSubscriberId`] of every connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_connected_subscribers<F: FnMut(UniqueSubscriberId) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let state = self.publisher_shared_state.lock();
+        state.update_connections()?;
+        state
+            .sender
+            .list_connected_receivers(|id| callback(UniqueSubscriberId(UniqueSystemId::from(id))));
+        Ok(())
+    }
 }
 
 ////////////////////////
@@ -725,6 +1058,91 @@ impl<
             .initial_max_slice_len
     }
 
+    /// Returns the maximum slice length the [`Publisher`] can currently loan without
+    /// reallocating its data segment. It starts with [`Publisher::initial_max_slice_len()`] and
+    /// grows whenever the [`AllocationStrategy`] requires a larger data segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///                        .initial_max_slice_len(16)
+    ///                        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    ///                        .create()?;
+    ///
+    /// let sample = publisher.loan_slice(128)?;
+    /// assert!(publisher.max_slice_len() >= 128);
+    /// assert!(publisher.number_of_data_segment_reallocations() > 0);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn max_slice_len(&self) -> usize {
+        let shared_state = self.publisher_shared_state.lock();
+        let initial_max_slice_len = shared_state.config.initial_max_slice_len;
+        if shared_state.sender.data_segment.number_of_reallocations() == 0 {
+            return initial_max_slice_len;
+        }
+
+        shared_state
+            .sender
+            .current_max_number_of_elements()
+            .max(initial_max_slice_len)
+    }
+
+    /// Returns how often the data segment of the [`Publisher`] was reallocated since its
+    /// creation. If this is non-zero in a production setup, the
+    /// [`Publisher::initial_max_slice_len()`] shall be increased to avoid reallocations at
+    /// runtime. Always zero for [`AllocationStrategy::Static`].
+    pub fn number_of_data_segment_reallocations(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .data_segment
+            .number_of_reallocations()
+    }
+
+    /// Releases the memory a data segment acquired when it grew beyond the
+    /// [`Publisher::initial_max_slice_len()`] by replacing it with a data segment of the initial
+    /// size. Samples that are still loaned, in flight or held by a
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) keep the previous data segment
+    /// alive until the last of them is released. Returns the number of bytes that are reclaimed,
+    /// zero when the data segment never grew or the [`AllocationStrategy`] is
+    /// [`AllocationStrategy::Static`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder()
+    ///                        .initial_max_slice_len(16)
+    ///                        .allocation_strategy(AllocationStrategy::PowerOfTwo)
+    ///                        .create()?;
+    ///
+    /// let sample = publisher.loan_slice(1024)?;
+    /// sample.send()?;
+    ///
+    /// assert!(publisher.shrink_to_fit()? > 0);
+    /// assert!(publisher.max_slice_len() < 1024);
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn shrink_to_fit(&self) -> Result<usize, ShrinkToFitError> {
+        self.publisher_shared_state.lock().sender.shrink_to_fit()
+    }
+
     /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -805,6 +1223,48 @@ impl<
     }
 }
 
+impl<
+        Service: service::Service,
+        Payload: Debug + Copy + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > Publisher<Service, [Payload], UserHeader>
+{
+    /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it. When
+    /// the slice does not fit into the current data segment, it is grown according to the
+    /// configured [`AllocationStrategy`].
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendError`] describing the failure. When the slice exceeds
+    /// [`Publisher::initial_max_slice_len()`] and the [`AllocationStrategy::Static`] is used,
+    /// it fails with [`SendError::LoanError`] containing [`LoanError::ExceedsMaxLoanSize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .initial_max_slice_len(16)
+    ///                          .create()?;
+    ///
+    /// publisher.send_slice_copy(&[1, 2, 3, 4])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy(&self, value: &[Payload]) -> Result<usize, SendError> {
+        let msg = "Unable to send copy of slice payload";
+        let sample = fail!(from self, when self.loan_slice_uninit(value.len()),
+                                    "{} since the loan of a sample failed.", msg);
+
+        sample.write_from_slice(value).send()
+    }
+}
+
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     Publisher<Service, [CustomPayloadMarker], UserHeader>
 {
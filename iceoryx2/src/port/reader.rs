@@ -10,5 +10,309 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-#[doc(hidden)]
-pub struct Reader {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .add::<u32>(1, 2)
+//!     .create()?;
+//!
+//! let reader = service.reader_builder().create()?;
+//! let writer = service.writer_builder().create();
+//!
+//! writer.write::<i32>(&0, 123)?;
+//! writer.write::<i32>(&0, 456)?;
+//!
+//! // both writes happened since the reader was created, the key is reported once
+//! reader.changed_keys(|key| {
+//!     println!("entry {} changed to {}", key, reader.read::<i32>(key).unwrap());
+//!     CallbackProgression::Continue
+//! });
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service::builder::CustomKeyMarker;
+use crate::service::dynamic_config::blackboard::ReaderDetails;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use crate::service::{self, ServiceState};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+/// Defines the failures that can occur when a [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    /// The maximum amount of [`Reader`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Reader`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedReaders,
+}
+
+impl core::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ReaderCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReaderCreateError {}
+
+/// Defines the failures that can occur when an entry is accessed with a [`Reader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderReadError {
+    /// The [`Service`](crate::service::Service) has no entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry stores a value of another type than the requested one.
+    IncompatibleValueType,
+}
+
+impl core::fmt::Display for ReaderReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ReaderReadError::{self:?}")
+    }
+}
+
+impl core::error::Error for ReaderReadError {}
+
+/// Represents the reading endpoint of a blackboard based communication. Every [`Reader`]
+/// tracks which entries it has seen, so that changes since its creation or its last
+/// [`Reader::read()`] of an entry can be queried. An entry that was written multiple times
+/// in between is reported only once.
+#[derive(Debug)]
+pub struct Reader<Service: service::Service, KeyType: ZeroCopySend + Debug> {
+    dynamic_reader_handle: Option<ContainerHandle>,
+    service_state: Arc<ServiceState<Service>>,
+    reader_id: UniqueReaderId,
+    seen_generations: Vec<IoxAtomicU64>,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug> Drop for Reader<Service, KeyType> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .release_reader_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug> Reader<Service, KeyType> {
+    pub(crate) fn new(service: &Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Failed to create reader";
+        let origin = "Reader::new()";
+        let reader_id = UniqueReaderId::new();
+        let dynamic_config = service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .blackboard();
+
+        let seen_generations = (0..dynamic_config.number_of_entries())
+            .map(|n| {
+                IoxAtomicU64::new(Self::stable_generation(
+                    dynamic_config.entry(n).generation(),
+                ))
+            })
+            .collect();
+
+        let mut new_self = Self {
+            dynamic_reader_handle: None,
+            service_state: service.__internal_state().clone(),
+            reader_id,
+            seen_generations,
+            _key: PhantomData,
+        };
+
+        let dynamic_reader_handle = match dynamic_config.add_reader_id(ReaderDetails {
+            reader_id,
+            node_id: *service.__internal_state().shared_node.id(),
+        }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, service.__internal_state().static_config.blackboard().max_readers);
+            }
+        };
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    // a generation is odd while a write is in progress, the value the reader will see
+    // afterwards is the one of the next even generation
+    fn stable_generation(generation: u64) -> u64 {
+        generation + generation % 2
+    }
+
+    fn find_entry(&self, key: &[u8]) -> Result<usize, ReaderReadError> {
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        match (0..dynamic_config.number_of_entries())
+            .find(|n| dynamic_config.has_key(dynamic_config.entry(*n), key))
+        {
+            Some(index) => Ok(index),
+            None => {
+                fail!(from self, with ReaderReadError::EntryDoesNotExist,
+                    "Unable to access the entry since the key does not exist.");
+            }
+        }
+    }
+
+    unsafe fn read_impl(
+        &self,
+        key: &[u8],
+        value_type: &TypeDetail,
+        target: *mut u8,
+    ) -> Result<(), ReaderReadError> {
+        let index = self.find_entry(key)?;
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        let entry = dynamic_config.entry(index);
+
+        if entry.value_type() != value_type {
+            fail!(from self, with ReaderReadError::IncompatibleValueType,
+                "Unable to read the entry since it stores a value of type {:?} and not {:?}.",
+                entry.value_type(), value_type);
+        }
+
+        let generation = dynamic_config.read_value(entry, target);
+        self.seen_generations[index].store(generation, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn has_changed_impl(&self, key: &[u8]) -> Result<bool, ReaderReadError> {
+        let index = self.find_entry(key)?;
+        let generation = self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .entry(index)
+            .generation();
+
+        Ok(self.seen_generations[index].load(Ordering::Relaxed)
+            != Self::stable_generation(generation))
+    }
+
+    fn changed_entries<F: FnMut(usize) -> CallbackProgression>(&self, mut callback: F) {
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        for (index, seen_generation) in self.seen_generations.iter().enumerate() {
+            let generation = Self::stable_generation(dynamic_config.entry(index).generation());
+            if seen_generation.swap(generation, Ordering::Relaxed) != generation
+                && callback(index) == CallbackProgression::Stop
+            {
+                return;
+            }
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug + Eq + Copy>
+    Reader<Service, KeyType>
+{
+    fn key_bytes(key: &KeyType) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                (key as *const KeyType).cast::<u8>(),
+                core::mem::size_of::<KeyType>(),
+            )
+        }
+    }
+
+    /// Reads the current value of the entry with the provided key and marks it as seen.
+    pub fn read<ValueType: ZeroCopySend + Copy + 'static>(
+        &self,
+        key: &KeyType,
+    ) -> Result<ValueType, ReaderReadError> {
+        let mut value = MaybeUninit::<ValueType>::uninit();
+        unsafe {
+            self.read_impl(
+                Self::key_bytes(key),
+                &TypeDetail::__internal_new::<ValueType>(TypeVariant::FixedSize),
+                value.as_mut_ptr().cast(),
+            )?;
+            Ok(value.assume_init())
+        }
+    }
+
+    /// Returns true when the entry with the provided key was written since the [`Reader`]
+    /// was created or since it was read the last time.
+    pub fn has_changed(&self, key: &KeyType) -> Result<bool, ReaderReadError> {
+        self.has_changed_impl(Self::key_bytes(key))
+    }
+
+    /// Calls the provided callback with the key of every entry that was written since the
+    /// [`Reader`] was created, since it was read the last time or since it was reported by a
+    /// previous call. Every change is reported exactly once, independent of how often the
+    /// entry was written in between.
+    pub fn changed_keys<F: FnMut(&KeyType) -> CallbackProgression>(&self, mut callback: F) {
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        self.changed_entries(|index| {
+            let mut key = MaybeUninit::<KeyType>::uninit();
+            unsafe {
+                dynamic_config.copy_key(dynamic_config.entry(index), key.as_mut_ptr().cast());
+                callback(&key.assume_init())
+            }
+        })
+    }
+}
+
+impl<Service: service::Service> Reader<Service, CustomKeyMarker> {
+    #[doc(hidden)]
+    pub unsafe fn __internal_read(
+        &self,
+        key: &[u8],
+        value_type: &TypeDetail,
+        target: *mut u8,
+    ) -> Result<(), ReaderReadError> {
+        self.read_impl(key, value_type, target)
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_has_changed(&self, key: &[u8]) -> Result<bool, ReaderReadError> {
+        self.has_changed_impl(key)
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_changed_keys<F: FnMut(&[u8]) -> CallbackProgression>(&self, mut callback: F) {
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        let key_size = self
+            .service_state
+            .static_config
+            .blackboard()
+            .type_details
+            .size;
+        let mut key = vec![0u8; key_size];
+        self.changed_entries(|index| {
+            unsafe { dynamic_config.copy_key(dynamic_config.entry(index), key.as_mut_ptr()) };
+            callback(&key)
+        })
+    }
+}
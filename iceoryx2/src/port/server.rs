@@ -82,11 +82,13 @@ use iceoryx2_bb_container::slotmap::SlotMap;
 use iceoryx2_bb_container::vec::Vec;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
 
 use iceoryx2_bb_elementary::{cyclic_tagger::CyclicTagger, CallbackProgression};
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
@@ -94,6 +96,7 @@ use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::naming_scheme::data_segment_name;
 use crate::service::port_factory::server::LocalServerConfig;
+use crate::waitset::Attachable;
 use crate::{
     active_request::ActiveRequest,
     prelude::PortFactory,
@@ -108,22 +111,60 @@ use crate::{
 
 use super::details::data_segment::DataSegment;
 use super::details::segment_state::SegmentState;
-use super::details::sender::{ReceiverDetails, Sender};
+use super::details::sender::{ReceiverDetails, Sender, INVALID_DATA_SEGMENT_USAGE_INDEX};
 use super::{
     details::{
         chunk::Chunk,
         chunk_details::ChunkDetails,
         data_segment::DataSegmentType,
+        delivery_notification::DeliveryListener,
+        padded_payload::PaddedPayload,
         receiver::{Receiver, SenderDetails},
     },
     update_connections::ConnectionFailure,
-    ReceiveError, UniqueServerId,
+    ReceiveError, ShrinkToFitError, UniqueClientId, UniqueServerId,
 };
 
 // All requests are received via one channel with id 0
 const REQUEST_CHANNEL_ID: ChannelId = ChannelId::new(0);
 pub(crate) const INVALID_CONNECTION_ID: usize = usize::MAX;
 
+/// Describes how many [`ActiveRequest`]s of a connected [`Client`](crate::port::client::Client)
+/// a [`Server`] currently holds. Acquired with [`Server::active_requests_per_client()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientActiveRequests {
+    client_id: UniqueClientId,
+    number_of_active_requests: usize,
+    max_active_requests: usize,
+}
+
+impl ClientActiveRequests {
+    /// Returns the [`UniqueClientId`] of the [`Client`](crate::port::client::Client).
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_id
+    }
+
+    /// Returns the number of [`ActiveRequest`]s of the
+    /// [`Client`](crate::port::client::Client) the [`Server`] currently holds.
+    pub fn number_of_active_requests(&self) -> usize {
+        self.number_of_active_requests
+    }
+
+    /// Returns the maximum number of [`ActiveRequest`]s the [`Server`] can hold per
+    /// [`Client`](crate::port::client::Client), see
+    /// [`StaticConfig::max_active_requests_per_client()`](crate::service::static_config::request_response::StaticConfig::max_active_requests_per_client()).
+    pub fn max_active_requests(&self) -> usize {
+        self.max_active_requests
+    }
+
+    /// Returns true when the [`Server`] holds the maximum number of [`ActiveRequest`]s of the
+    /// [`Client`](crate::port::client::Client). No further request of the
+    /// [`Client`](crate::port::client::Client) can be received until one of them is dropped.
+    pub fn is_exhausted(&self) -> bool {
+        self.number_of_active_requests >= self.max_active_requests
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SharedServerState<Service: service::Service> {
     pub(crate) config: LocalServerConfig,
@@ -147,6 +188,43 @@ impl<Service: service::Service> Drop for SharedServerState<Service> {
 }
 
 impl<Service: service::Service> SharedServerState<Service> {
+    /// Publishes the current size of the data segment in the dynamic config so that the
+    /// memory footprint of the service can be acquired from any process.
+    pub(crate) fn update_data_segment_size(&self) {
+        if let Some(handle) = unsafe { *self.server_handle.get() } {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .set_server_data_segment_size(
+                    handle,
+                    self.response_sender.data_segment.usage().total_bytes(),
+                );
+        }
+    }
+
+    /// Returns the credit this server granted for the request to the
+    /// [`Client`](crate::port::client::Client) with the provided connection id when it was not
+    /// yet returned. Every server grants its own credits, a request delivered to multiple
+    /// servers does not free more than one credit of the client.
+    pub(crate) fn acknowledge_request(
+        &self,
+        returned_credit: &Option<Arc<IoxAtomicBool>>,
+        connection_id: usize,
+    ) {
+        if let (Some(returned_credit), Some(handle)) =
+            (returned_credit, unsafe { *self.server_handle.get() })
+        {
+            if !returned_credit.swap(true, Ordering::Relaxed) {
+                self.service_state
+                    .dynamic_storage
+                    .get()
+                    .request_response()
+                    .acknowledge_request(handle.index() as usize, connection_id);
+            }
+        }
+    }
+
     pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
         if unsafe {
             self.request_receiver
@@ -223,6 +301,8 @@ pub struct Server<
     shared_state: Service::ArcThreadSafetyPolicy<SharedServerState<Service>>,
     max_loaned_responses_per_request: usize,
     enable_fire_and_forget: bool,
+    enable_multi_response: bool,
+    has_credit_flow_control: bool,
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
     _response_payload: PhantomData<ResponsePayload>,
@@ -253,6 +333,33 @@ where
 {
 }
 
+/// Can only be attached to a [`WaitSet`](crate::waitset::WaitSet) when the
+/// [`Service`](crate::service::Service) was created with
+/// [`enable_notify_on_delivery()`](crate::service::builder::request_response::Builder::enable_notify_on_delivery()),
+/// otherwise attaching it fails with
+/// [`WaitSetAttachmentError::UnsupportedAttachment`](crate::waitset::WaitSetAttachmentError::UnsupportedAttachment).
+impl<
+        Service: service::Service,
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    > Attachable for Server<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+    fn attachment_file_descriptor(&self) -> Option<&FileDescriptor> {
+        let fd = self
+            .shared_state
+            .lock()
+            .request_receiver
+            .delivery_file_descriptor()? as *const FileDescriptor;
+        // the delivery listener and its file descriptor never change during the lifetime
+        // of the server port
+        Some(unsafe { &*fd })
+    }
+}
+
 impl<
         Service: service::Service,
         RequestPayload: Debug + ZeroCopySend + ?Sized,
@@ -314,6 +421,17 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        let delivery_listener = match static_config.enable_notify_on_delivery {
+            true => Some(fail!(from origin,
+                when DeliveryListener::new(
+                    server_id.value(),
+                    service.__internal_state().shared_node.config(),
+                    ServerCreateError::UnableToCreateDeliveryListener,
+                ),
+                "{} since the delivery listener could not be created.", msg)),
+            false => None,
+        };
+
         let request_receiver = Receiver {
             connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
             receiver_port_id: server_id.value(),
@@ -321,7 +439,7 @@ impl<
             message_type_details: static_config.request_message_type_details.clone(),
             receiver_max_borrowed_samples: static_config.max_active_requests_per_client,
             enable_safe_overflow: static_config.enable_safe_overflow_for_requests,
-            buffer_size: static_config.max_active_requests_per_client,
+            buffer_size: static_config.max_request_queue_depth_per_client(),
             tagger: CyclicTagger::new(),
             to_be_removed_connections: if static_config.enable_fire_and_forget_requests {
                 Some(UnsafeCell::new(Vec::new(
@@ -333,6 +451,8 @@ impl<
             degradation_callback: server_factory.request_degradation_callback,
             number_of_channels: 1,
             connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+            connection_generation: IoxAtomicU64::new(0),
+            delivery_listener,
         };
 
         let global_config = service.__internal_state().shared_node.config();
@@ -351,6 +471,8 @@ impl<
                 sample_layout,
                 global_config,
                 number_of_responses,
+                false,
+                None,
             ),
             DataSegmentType::Dynamic => DataSegment::<Service>::create_dynamic_segment(
                 &segment_name,
@@ -358,6 +480,8 @@ impl<
                 global_config,
                 number_of_responses,
                 server_factory.config.allocation_strategy,
+                false,
+                None,
             ),
         };
 
@@ -365,6 +489,10 @@ impl<
             when data_segment,
             with ServerCreateError::UnableToCreateDataSegment,
             "{} since the server data segment could not be created.", msg);
+        service
+            .__internal_state()
+            .shared_node
+            .register_shm_region(&segment_name, data_segment.usage());
 
         let response_sender = Sender {
             segment_states: {
@@ -394,9 +522,13 @@ impl<
             service_state: service.__internal_state().clone(),
             tagger: CyclicTagger::new(),
             loan_counter: IoxAtomicUsize::new(0),
+            number_of_overflows: IoxAtomicU64::new(0),
             unable_to_deliver_strategy: server_factory.config.unable_to_deliver_strategy,
             message_type_details: static_config.response_message_type_details.clone(),
             number_of_channels: number_of_requests_per_client,
+            data_segment_usage_index: IoxAtomicUsize::new(INVALID_DATA_SEGMENT_USAGE_INDEX),
+            connection_generation: IoxAtomicU64::new(0),
+            enable_notify_on_delivery: static_config.enable_notify_on_delivery,
         };
 
         let shared_state = Service::ArcThreadSafetyPolicy::new(SharedServerState {
@@ -423,6 +555,17 @@ impl<
                 .static_config
                 .request_response()
                 .enable_fire_and_forget_requests,
+            enable_multi_response: service
+                .__internal_state()
+                .static_config
+                .request_response()
+                .enable_multi_response,
+            has_credit_flow_control: service
+                .__internal_state()
+                .static_config
+                .request_response()
+                .credits_per_client
+                .is_some(),
             shared_state,
             _request_payload: PhantomData,
             _request_header: PhantomData,
@@ -447,7 +590,7 @@ impl<
                 .add_server_id(ServerDetails {
                     server_id,
                     node_id: *service.__internal_state().shared_node.id(),
-                    request_buffer_size: static_config.max_active_requests_per_client,
+                    request_buffer_size: static_config.max_request_queue_depth_per_client(),
                     number_of_responses,
                     max_slice_len: server_factory.config.initial_max_slice_len,
                     data_segment_type,
@@ -462,6 +605,7 @@ impl<
                 }
             }
         };
+        new_self.shared_state.lock().update_data_segment_size();
 
         Ok(new_self)
     }
@@ -473,6 +617,95 @@ impl<
         ))
     }
 
+    /// Returns a counter that is increased whenever a [`Client`](crate::port::client::Client)
+    /// connects to or disconnects from the [`Server`]. The connections are updated before the
+    /// counter is read. Since the counter never decreases, a caller that polls it and compares
+    /// it with the previously read value detects every change. The connected
+    /// [`Client`](crate::port::client::Client)s can then be acquired with
+    /// [`Server::list_connected_clients()`].
+    ///
+    /// The connections are also updated on every receive, therefore the counter may change
+    /// without calling this method.
+    pub fn connection_generation(&self) -> Result<u64, ConnectionFailure> {
+        let shared_state = self.shared_state.lock();
+        shared_state.update_connections()?;
+        Ok(shared_state.request_receiver.connection_generation())
+    }
+
+    /// Updates the connections and calls the provided callback with the [`UniqueClientId`]
+    /// of every connected [`Client`](crate::port::client::Client) until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_connected_clients<F: FnMut(UniqueClientId) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let shared_state = self.shared_state.lock();
+        shared_state.update_connections()?;
+        shared_state
+            .request_receiver
+            .list_connected_senders(|id| callback(UniqueClientId(UniqueSystemId::from(id))));
+        Ok(())
+    }
+
+    /// Returns the number of [`Client`](crate::port::client::Client)s that are connected to
+    /// the [`Server`]. The connections are updated before they are counted.
+    pub fn number_of_connected_clients(&self) -> Result<usize, ConnectionFailure> {
+        let mut number_of_clients = 0;
+        self.list_connected_clients(|_| {
+            number_of_clients += 1;
+            CallbackProgression::Continue
+        })?;
+        Ok(number_of_clients)
+    }
+
+    /// Updates the connections and calls the provided callback with the
+    /// [`ClientActiveRequests`] of every connected [`Client`](crate::port::client::Client)
+    /// until it returns [`CallbackProgression::Stop`]. It describes how many
+    /// [`ActiveRequest`]s of the [`Client`](crate::port::client::Client) this [`Server`]
+    /// currently holds. A [`Client`](crate::port::client::Client) whose requests are never
+    /// completed can be detected with [`ClientActiveRequests::is_exhausted()`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node
+    /// #    .service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #    .request_response::<u64, u64>()
+    /// #    .open_or_create()?;
+    /// let server = service.server_builder().create()?;
+    ///
+    /// server.active_requests_per_client(|usage| {
+    ///     if usage.is_exhausted() {
+    ///         println!("client {:?} exhausted all of its {} active requests",
+    ///                  usage.client_id(), usage.max_active_requests());
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn active_requests_per_client<F: FnMut(&ClientActiveRequests) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        let shared_state = self.shared_state.lock();
+        shared_state.update_connections()?;
+        let max_active_requests = shared_state.request_receiver.receiver_max_borrowed_samples;
+        shared_state.request_receiver.list_borrow_counts(
+            REQUEST_CHANNEL_ID,
+            |client_id, number_of_active_requests| {
+                callback(&ClientActiveRequests {
+                    client_id: UniqueClientId(UniqueSystemId::from(client_id)),
+                    number_of_active_requests,
+                    max_active_requests,
+                })
+            },
+        );
+        Ok(())
+    }
+
     /// Returns true if the [`Server`] has [`RequestMut`](crate::request_mut::RequestMut)s in its buffer.
     pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
         let shared_state = self.shared_state.lock();
@@ -489,7 +722,9 @@ impl<
         }
     }
 
-    fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+    fn receive_impl(
+        &self,
+    ) -> Result<Option<(ChunkDetails, Chunk, Option<PaddedPayload>)>, ReceiveError> {
         let shared_state = self.shared_state.lock();
         if let Err(e) = shared_state.update_connections() {
             fail!(from self,
@@ -497,7 +732,20 @@ impl<
                   "Some requests are not being received since not all connections to the clients could be established.");
         }
 
-        shared_state.request_receiver.receive(REQUEST_CHANNEL_ID)
+        match shared_state.request_receiver.receive(REQUEST_CHANNEL_ID)? {
+            None => Ok(None),
+            Some((details, mut chunk)) => {
+                let header = unsafe {
+                    &*(chunk.header as *const service::header::request_response::RequestHeader)
+                };
+                let padded_payload = shared_state.request_receiver.pad_evolved_payload(
+                    &mut chunk,
+                    header.payload_size as _,
+                    header.number_of_elements as _,
+                );
+                Ok(Some((details, chunk, padded_payload)))
+            }
+        }
     }
 }
 
@@ -513,6 +761,7 @@ impl<
         &self,
         details: ChunkDetails,
         chunk: Chunk,
+        padded_payload: Option<PaddedPayload>,
         connection_id: usize,
     ) -> ActiveRequest<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>
     {
@@ -521,11 +770,17 @@ impl<
 
         ActiveRequest {
             details,
+            _padded_payload: padded_payload,
             shared_loan_counter: Arc::new(IoxAtomicUsize::new(0)),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
             connection_id,
+            sent_single_response: (!self.enable_multi_response)
+                .then(|| Arc::new(IoxAtomicBool::new(false))),
+            returned_credit: self
+                .has_credit_flow_control
+                .then(|| Arc::new(IoxAtomicBool::new(false))),
             shared_state: self.shared_state.clone(),
             ptr: unsafe {
                 RawSample::new_unchecked(
@@ -578,7 +833,7 @@ impl<
     > {
         loop {
             match self.receive_impl()? {
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let header = unsafe {
                         &*(chunk.header as *const service::header::request_response::RequestHeader)
                     };
@@ -589,8 +844,12 @@ impl<
                         .response_sender
                         .get_connection_id_of(header.client_id.value())
                     {
-                        let active_request =
-                            self.create_active_request(details, chunk, connection_id);
+                        let active_request = self.create_active_request(
+                            details,
+                            chunk,
+                            padded_payload,
+                            connection_id,
+                        );
 
                         if !self.enable_fire_and_forget && !active_request.is_connected() {
                             continue;
@@ -598,8 +857,12 @@ impl<
 
                         return Ok(Some(active_request));
                     } else if self.enable_fire_and_forget {
-                        let active_request =
-                            self.create_active_request(details, chunk, INVALID_CONNECTION_ID);
+                        let active_request = self.create_active_request(
+                            details,
+                            chunk,
+                            padded_payload,
+                            INVALID_CONNECTION_ID,
+                        );
                         return Ok(Some(active_request));
                     }
                 }
@@ -621,6 +884,7 @@ impl<
         &self,
         details: ChunkDetails,
         chunk: Chunk,
+        padded_payload: Option<PaddedPayload>,
         connection_id: usize,
         number_of_elements: usize,
     ) -> ActiveRequest<Service, [RequestPayload], RequestHeader, ResponsePayload, ResponseHeader>
@@ -630,11 +894,17 @@ impl<
 
         ActiveRequest {
             details,
+            _padded_payload: padded_payload,
             shared_loan_counter: Arc::new(IoxAtomicUsize::new(0)),
             max_loan_count: self.max_loaned_responses_per_request,
             request_id: header.request_id,
             channel_id: header.channel_id,
             connection_id,
+            sent_single_response: (!self.enable_multi_response)
+                .then(|| Arc::new(IoxAtomicBool::new(false))),
+            returned_credit: self
+                .has_credit_flow_control
+                .then(|| Arc::new(IoxAtomicBool::new(false))),
             shared_state: self.shared_state.clone(),
             ptr: unsafe {
                 RawSample::new_slice_unchecked(
@@ -695,7 +965,7 @@ impl<
     > {
         loop {
             match self.receive_impl()? {
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let header = unsafe {
                         &*(chunk.header as *const service::header::request_response::RequestHeader)
                     };
@@ -709,6 +979,7 @@ impl<
                         let active_request = self.create_active_request(
                             details,
                             chunk,
+                            padded_payload,
                             connection_id,
                             header.number_of_elements() as _,
                         );
@@ -738,6 +1009,15 @@ impl<
     pub fn initial_max_slice_len(&self) -> usize {
         self.shared_state.lock().config.initial_max_slice_len
     }
+
+    /// Releases the memory the response data segment acquired when it grew beyond the
+    /// [`Server::initial_max_slice_len()`] by replacing it with a data segment of the initial
+    /// size. Responses that are still loaned, in flight or held by a
+    /// [`Client`](crate::port::client::Client) keep the previous data segment alive until the
+    /// last of them is released. Returns the number of bytes that are reclaimed.
+    pub fn shrink_to_fit(&self) -> Result<usize, ShrinkToFitError> {
+        self.shared_state.lock().response_sender.shrink_to_fit()
+    }
 }
 
 impl<
@@ -766,7 +1046,7 @@ impl<
         let shared_state = self.shared_state.lock();
         loop {
             match self.receive_impl()? {
-                Some((details, chunk)) => {
+                Some((details, chunk, padded_payload)) => {
                     let header = unsafe {
                         &*(chunk.header as *const service::header::request_response::RequestHeader)
                     };
@@ -781,6 +1061,7 @@ impl<
                         let active_request = self.create_active_request(
                             details,
                             chunk,
+                            padded_payload,
                             connection_id,
                             number_of_bytes,
                         );
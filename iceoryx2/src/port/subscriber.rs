@@ -36,6 +36,7 @@ use core::cell::UnsafeCell;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::sync::atomic::Ordering;
+use core::time::Duration;
 
 extern crate alloc;
 
@@ -46,22 +47,30 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::service::builder::CustomPayloadMarker;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::waitset::Attachable;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
+use super::details::delivery_notification::DeliveryListener;
+use super::details::ownership_arbiter::OwnershipArbiter;
 use super::details::receiver::*;
-use super::port_identifiers::UniqueSubscriberId;
+use super::details::sample_loss_tracker::SampleLossTracker;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::ConnectionFailure;
 use super::ReceiveError;
 
@@ -80,6 +89,9 @@ pub enum SubscriberCreateError {
     /// Caused by a failure when instantiating a [`ArcSyncPolicy`] defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) notifies on delivery but the underlying
+    /// resources to receive the notifications could not be created.
+    UnableToCreateDeliveryListener,
 }
 
 impl core::fmt::Display for SubscriberCreateError {
@@ -90,10 +102,91 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+/// Describes the failures when a [`Sample`] is acknowledged with
+/// [`Subscriber::mark_received()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SubscriberMarkReceivedError {
+    /// The [`Sample`] was received by another [`Subscriber`].
+    SampleBelongsToDifferentSubscriber,
+    /// The [`Publisher`](crate::port::publisher::Publisher) that sent the [`Sample`] is no
+    /// longer connected, therefore no one can evaluate the acknowledgement.
+    PublisherNoLongerConnected,
+}
+
+impl core::fmt::Display for SubscriberMarkReceivedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "SubscriberMarkReceivedError::{self:?}")
+    }
+}
+
+impl core::error::Error for SubscriberMarkReceivedError {}
+
+/// The number of [`Sample`]s that were sent to a [`Subscriber`] but were never received, for
+/// instance since they were replaced in the buffer when safe overflow is enabled. It is
+/// acquired with [`Subscriber::missed_samples()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MissedSamples {
+    total: u64,
+    per_publisher: alloc::vec::Vec<(UniquePublisherId, u64)>,
+}
+
+impl MissedSamples {
+    /// Returns the cumulative number of missed [`Sample`]s over the whole lifetime of the
+    /// [`Subscriber`], including [`Publisher`](crate::port::publisher::Publisher)s that are no
+    /// longer connected.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of missed [`Sample`]s for every connected
+    /// [`Publisher`](crate::port::publisher::Publisher) from which at least one [`Sample`] was
+    /// received.
+    pub fn per_publisher(&self) -> &[(UniquePublisherId, u64)] {
+        &self.per_publisher
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    pub(crate) sample_loss_tracker: UnsafeCell<SampleLossTracker>,
+    pub(crate) sample_lifespan: Option<Duration>,
+    pub(crate) max_sample_age: UnsafeCell<Option<Duration>>,
+    pub(crate) has_send_timestamp: bool,
+    pub(crate) number_of_expired_samples: UnsafeCell<u64>,
+    pub(crate) max_slice_len: Option<usize>,
+    pub(crate) ownership_arbiter: Option<UnsafeCell<OwnershipArbiter>>,
+}
+
+impl<Service: service::Service> SubscriberSharedState<Service> {
+    fn max_age(&self) -> Option<Duration> {
+        match (self.sample_lifespan, unsafe { *self.max_sample_age.get() }) {
+            (Some(lifespan), Some(max_sample_age)) => Some(lifespan.min(max_sample_age)),
+            (lifespan, max_sample_age) => lifespan.or(max_sample_age),
+        }
+    }
+
+    fn is_expired(&self, header: &Header, now: Option<Duration>) -> bool {
+        match (self.max_age(), now, header.timestamp()) {
+            (Some(max_age), Some(now), Some(timestamp)) => {
+                now.saturating_sub(timestamp.monotonic()) > max_age
+            }
+            _ => false,
+        }
+    }
+
+    fn is_from_owner(&self, origin: u128, now: Option<Duration>) -> bool {
+        match &self.ownership_arbiter {
+            Some(arbiter) => unsafe { &mut *arbiter.get() }.is_owner::<Service>(
+                origin,
+                unsafe { &*self.publisher_list_state.get() },
+                self.receiver.service_state.shared_node.config(),
+                now,
+            ),
+            None => true,
+        }
+    }
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
@@ -105,6 +198,7 @@ pub struct Subscriber<
 > {
     dynamic_subscriber_handle: Option<ContainerHandle>,
     subscriber_shared_state: Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    pinned_sample: UnsafeCell<Option<Sample<Service, Payload, UserHeader>>>,
 
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -130,6 +224,31 @@ where
 {
 }
 
+/// Can only be attached to a [`WaitSet`](crate::waitset::WaitSet) when the
+/// [`Service`](crate::service::Service) was created with
+/// [`enable_notify_on_delivery()`](crate::service::builder::publish_subscribe::Builder::enable_notify_on_delivery()),
+/// otherwise attaching it fails with
+/// [`WaitSetAttachmentError::UnsupportedAttachment`](crate::waitset::WaitSetAttachmentError::UnsupportedAttachment).
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Attachable for Subscriber<Service, Payload, UserHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+{
+    fn attachment_file_descriptor(&self) -> Option<&FileDescriptor> {
+        let fd = self
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .delivery_file_descriptor()? as *const FileDescriptor;
+        // the delivery listener and its file descriptor never change during the lifetime
+        // of the subscriber port
+        Some(unsafe { &*fd })
+    }
+}
+
 impl<
         Service: service::Service,
         Payload: Debug + ZeroCopySend + ?Sized,
@@ -184,10 +303,8 @@ impl<
             None => static_config.subscriber_max_buffer_size,
         };
 
-        let number_of_to_be_removed_connections = service
-            .__internal_state()
-            .shared_node
-            .config()
+        let global_config = service.__internal_state().shared_node.config();
+        let number_of_to_be_removed_connections = global_config
             .defaults
             .publish_subscribe
             .subscriber_expired_connection_buffer;
@@ -195,8 +312,36 @@ impl<
         let number_of_connections =
             number_of_to_be_removed_connections + number_of_active_connections;
 
+        let delivery_listener = match static_config.enable_notify_on_delivery {
+            true => Some(fail!(from origin,
+                when DeliveryListener::new(
+                    subscriber_id.value(),
+                    global_config,
+                    SubscriberCreateError::UnableToCreateDeliveryListener,
+                ),
+                "{} since the delivery listener could not be created.", msg)),
+            false => None,
+        };
+
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            sample_loss_tracker: UnsafeCell::new(SampleLossTracker::new(number_of_connections)),
+            sample_lifespan: static_config.sample_lifespan,
+            max_sample_age: UnsafeCell::new(None),
+            // the publisher captures the send timestamp also when a sample lifespan is set
+            has_send_timestamp: static_config.enable_send_timestamp
+                || static_config.sample_lifespan.is_some(),
+            number_of_expired_samples: UnsafeCell::new(0),
+            max_slice_len: config.max_slice_len,
+            ownership_arbiter: static_config.enable_exclusive_ownership.then(|| {
+                UnsafeCell::new(OwnershipArbiter::new(
+                    global_config
+                        .defaults
+                        .publish_subscribe
+                        .ownership_monitoring_interval,
+                    static_config.max_publishers,
+                ))
+            }),
             receiver: Receiver {
                 connections: Vec::from_fn(number_of_active_connections, |_| UnsafeCell::new(None)),
                 receiver_port_id: subscriber_id.value(),
@@ -212,6 +357,8 @@ impl<
                 degradation_callback: config.degradation_callback,
                 number_of_channels: 1,
                 connection_storage: UnsafeCell::new(SlotMap::new(number_of_connections)),
+                connection_generation: IoxAtomicU64::new(0),
+                delivery_listener,
             },
         });
 
@@ -227,6 +374,7 @@ impl<
         let mut new_self = Self {
             subscriber_shared_state,
             dynamic_subscriber_handle: None,
+            pinned_sample: UnsafeCell::new(None),
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -295,6 +443,25 @@ impl<
             .receiver
             .finish_update_connection_cycle();
 
+        if let Some(arbiter) = &subscriber_shared_state.ownership_arbiter {
+            unsafe { &mut *arbiter.get() }.invalidate();
+        }
+
+        unsafe {
+            let publisher_list_state = &*subscriber_shared_state.publisher_list_state.get();
+            (*subscriber_shared_state.sample_loss_tracker.get()).retain_senders(|origin| {
+                let mut is_connected = false;
+                publisher_list_state.for_each(|_, details| {
+                    is_connected = details.publisher_id.value() == origin;
+                    match is_connected {
+                        true => CallbackProgression::Stop,
+                        false => CallbackProgression::Continue,
+                    }
+                });
+                is_connected
+            })
+        };
+
         result
     }
 
@@ -313,25 +480,295 @@ impl<
         self.subscriber_shared_state.lock().receiver.buffer_size
     }
 
+    /// Returns the maximum number of elements a received slice may contain. If no limit was
+    /// defined with
+    /// [`PortFactorySubscriber::max_slice_len()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_slice_len())
+    /// [`None`] is returned.
+    pub fn max_slice_len(&self) -> Option<usize> {
+        self.subscriber_shared_state.lock().max_slice_len
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        Ok(unsafe { (*self.pinned_sample.get()).is_some() }
+            || subscriber_shared_state
+                .receiver
+                .has_samples(ChannelId::new(0)))
+    }
+
+    /// Returns a counter that is increased whenever a
+    /// [`Publisher`](crate::port::publisher::Publisher) connects to or disconnects from the
+    /// [`Subscriber`]. The connections are updated before the counter is read. Since the
+    /// counter never decreases, a caller that polls it and compares it with the previously
+    /// read value detects every change. The connected
+    /// [`Publisher`](crate::port::publisher::Publisher)s can then be acquired with
+    /// [`Subscriber::list_connected_publishers()`].
+    ///
+    /// The connections are also updated on every receive, therefore the counter may change
+    /// without calling this method.
+    pub fn connection_generation(&self) -> Result<u64, ConnectionFailure> {
+        self.update_connections()?;
         Ok(self
             .subscriber_shared_state
             .lock()
             .receiver
-            .has_samples(ChannelId::new(0)))
+            .connection_generation())
+    }
+
+    /// Updates the connections and calls the provided callback with the
+    /// [`UniquePublisherId`] of every connected
+    /// [`Publisher`](crate::port::publisher::Publisher) until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn list_connected_publishers<F: FnMut(UniquePublisherId) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ConnectionFailure> {
+        self.update_connections()?;
+        self.subscriber_shared_state
+            .lock()
+            .receiver
+            .list_connected_senders(|id| callback(UniquePublisherId(UniqueSystemId::from(id))));
+        Ok(())
+    }
+
+    fn take_pinned_sample(&self) -> Option<Sample<Service, Payload, UserHeader>> {
+        // the lock serializes the access to the pinned sample between all receive calls
+        let _subscriber_shared_state = self.subscriber_shared_state.lock();
+        unsafe { (*self.pinned_sample.get()).take() }
+    }
+
+    fn peek_impl<
+        F: FnOnce(&Self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError>,
+    >(
+        &mut self,
+        receive: F,
+    ) -> Result<Option<&Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        if self.pinned_sample.get_mut().is_none() {
+            let sample = receive(self)?;
+            *self.pinned_sample.get_mut() = sample;
+        }
+
+        Ok(self.pinned_sample.get_mut().as_ref())
+    }
+
+    /// Acknowledges that the [`Sample`] was processed. The sequence number of the [`Sample`]
+    /// is recorded in an acknowledgement log in the shared memory of the
+    /// [`Service`](crate::service::Service) so that the
+    /// [`Publisher`](crate::port::publisher::Publisher), or any other participant, can
+    /// determine with
+    /// [`Publisher::acknowledged_sequence_number()`](crate::port::publisher::Publisher::acknowledged_sequence_number())
+    /// which samples were not processed when the [`Subscriber`] crashes.
+    ///
+    /// The log stores only the highest acknowledged sequence number per
+    /// [`Publisher`](crate::port::publisher::Publisher), acknowledging a [`Sample`] implicitly
+    /// acknowledges all of its predecessors.
+    pub fn mark_received(
+        &self,
+        sample: &Sample<Service, Payload, UserHeader>,
+    ) -> Result<(), SubscriberMarkReceivedError> {
+        let msg = "Unable to mark the sample as received";
+        let sample_receiver_port_id = sample
+            .subscriber_shared_state
+            .lock()
+            .receiver
+            .receiver_port_id();
+
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if sample_receiver_port_id != subscriber_shared_state.receiver.receiver_port_id() {
+            fail!(from self, with SubscriberMarkReceivedError::SampleBelongsToDifferentSubscriber,
+                "{} since the sample was received by a different subscriber.", msg);
+        }
+
+        let publisher_index = match subscriber_shared_state
+            .receiver
+            .connection_index(&sample.details)
+        {
+            Some(index) => index,
+            None => {
+                fail!(from self, with SubscriberMarkReceivedError::PublisherNoLongerConnected,
+                    "{} since the publisher {:?} is no longer connected.", msg, sample.origin());
+            }
+        };
+
+        if let Some(handle) = self.dynamic_subscriber_handle {
+            subscriber_shared_state
+                .receiver
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .acknowledge(handle, publisher_index, sample.header().sequence_number());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`MissedSamples`], the number of [`Sample`]s that were sent by a
+    /// [`Publisher`](crate::port::publisher::Publisher) but never received by this
+    /// [`Subscriber`]. They are detected from gaps in the
+    /// [`Header::sequence_number()`] of the received [`Sample`]s, therefore a loss is only
+    /// visible after the next [`Sample`] of the same
+    /// [`Publisher`](crate::port::publisher::Publisher) was received. [`Sample`]s that were sent
+    /// before the first [`Sample`] of a [`Publisher`](crate::port::publisher::Publisher) was
+    /// received are not counted. A restarted [`Publisher`](crate::port::publisher::Publisher)
+    /// starts a new count and is never reported as a gap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    ///
+    /// println!("missed samples: {}", subscriber.missed_samples().total());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn missed_samples(&self) -> MissedSamples {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let sample_loss_tracker = unsafe { &*subscriber_shared_state.sample_loss_tracker.get() };
+
+        MissedSamples {
+            total: sample_loss_tracker.total_missed_samples(),
+            per_publisher: sample_loss_tracker
+                .senders()
+                .iter()
+                .map(|s| {
+                    (
+                        UniquePublisherId(UniqueSystemId::from(s.origin)),
+                        s.missed_samples,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the number of [`Sample`]s that were dropped due to a buffer overflow since the
+    /// [`Subscriber`] was created. It is the same as [`MissedSamples::total()`] of
+    /// [`Subscriber::missed_samples()`] and is therefore only updated when the next
+    /// [`Sample`] of the affected [`Publisher`](crate::port::publisher::Publisher) is
+    /// received.
+    pub fn dropped_sample_count(&self) -> u64 {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        unsafe { (*subscriber_shared_state.sample_loss_tracker.get()).total_missed_samples() }
+    }
+
+    /// Returns the number of [`Sample`]s that were discarded since they exceeded the
+    /// [`StaticConfig::sample_lifespan()`] or the [`Subscriber::max_sample_age()`] before they
+    /// were received. Without a sample lifespan and a max sample age it is always zero.
+    pub fn number_of_expired_samples(&self) -> u64 {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        unsafe { *subscriber_shared_state.number_of_expired_samples.get() }
+    }
+
+    /// Returns the maximum age a [`Sample`] can have to be received by this [`Subscriber`]. If
+    /// no max sample age is set, it returns [`None`].
+    pub fn max_sample_age(&self) -> Option<Duration> {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        unsafe { *subscriber_shared_state.max_sample_age.get() }
+    }
+
+    /// Defines the maximum age a [`Sample`] can have to be received by this [`Subscriber`].
+    /// Older [`Sample`]s are discarded and released on receive, like [`Sample`]s that exceed
+    /// the [`StaticConfig::sample_lifespan()`], and are counted in
+    /// [`Subscriber::number_of_expired_samples()`]. In contrast to the sample lifespan, the max
+    /// sample age applies only to this [`Subscriber`]. When both are set, the smaller one is
+    /// used. A max sample age of zero disables it.
+    ///
+    /// The age is determined from the send
+    /// [`Timestamp`](crate::service::header::publish_subscribe::Timestamp). When the
+    /// [`Publisher`](crate::port::publisher::Publisher)s of the
+    /// [`Service`](crate::service::Service) do not capture it, see
+    /// [`StaticConfig::has_send_timestamp()`], the max sample age has no effect.
+    pub fn set_max_sample_age(&self, value: Duration) {
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        if !value.is_zero() && !subscriber_shared_state.has_send_timestamp {
+            warn!(from self,
+                "The max sample age of {:?} has no effect since the publishers of the service do not capture a send timestamp.",
+                value);
+        }
+
+        unsafe {
+            *subscriber_shared_state.max_sample_age.get() = (!value.is_zero()).then_some(value)
+        };
+    }
+
+    /// Removes the max sample age, [`Sample`]s are received independent of their age unless
+    /// they exceed the [`StaticConfig::sample_lifespan()`].
+    pub fn disable_max_sample_age(&self) {
+        self.set_max_sample_age(Duration::ZERO);
     }
 
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        let now = match subscriber_shared_state.max_age().is_some()
+            || subscriber_shared_state.ownership_arbiter.is_some()
+        {
+            true => match Time::now_with_clock(ClockType::Monotonic) {
+                Ok(now) => Some(now.as_duration()),
+                Err(e) => {
+                    warn!(from self,
+                        "Unable to discard expired samples or to monitor the owning publisher since the current time could not be acquired ({:?}).", e);
+                    None
+                }
+            },
+            false => None,
+        };
+
+        loop {
+            let result = subscriber_shared_state
+                .receiver
+                .receive(ChannelId::new(0))?;
+
+            if let Some((details, chunk)) = &result {
+                let header = unsafe { &*(chunk.header as *const Header) };
+                unsafe { &mut *subscriber_shared_state.sample_loss_tracker.get() }
+                    .track(details.origin, header.sequence_number());
+
+                if subscriber_shared_state.is_expired(header, now) {
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(details, ChannelId::new(0));
+                    unsafe { *subscriber_shared_state.number_of_expired_samples.get() += 1 };
+                    continue;
+                }
+
+                if !subscriber_shared_state.is_from_owner(details.origin, now) {
+                    subscriber_shared_state
+                        .receiver
+                        .release_offset(details, ChannelId::new(0));
+                    continue;
+                }
+
+                if let Some(max_slice_len) = subscriber_shared_state.max_slice_len {
+                    if max_slice_len < header.number_of_elements() as usize {
+                        subscriber_shared_state
+                            .receiver
+                            .release_offset(details, ChannelId::new(0));
+                        fail!(from self, with ReceiveError::ExceedsMaxSliceLen,
+                            "Unable to receive sample since the slice with {} elements exceeds the max slice len of {}.",
+                            header.number_of_elements(), max_slice_len);
+                    }
+                }
+            }
+
+            return Ok(result);
+        }
     }
 
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
@@ -363,6 +800,10 @@ impl<
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
     pub fn receive(&self) -> Result<Option<Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        if let Some(sample) = self.take_pinned_sample() {
+            return Ok(Some(sample));
+        }
+
         Ok(self.receive_impl()?.map(|(details, chunk)| Sample {
             subscriber_shared_state: self.subscriber_shared_state.clone(),
             details,
@@ -375,6 +816,47 @@ impl<
             },
         }))
     }
+
+    /// Returns a reference to the oldest [`crate::sample::Sample`] without consuming it. The
+    /// next call to [`Subscriber::receive()`] returns the same [`crate::sample::Sample`]. If no
+    /// sample could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    ///
+    /// The peeked [`crate::sample::Sample`] is removed from the buffer and pinned inside the
+    /// [`Subscriber`] until it is received. Therefore, it is never overwritten when safe
+    /// overflow is enabled and counts towards the max borrowed samples. When the
+    /// [`Subscriber`] already holds the max borrowed samples, the call fails with
+    /// [`ReceiveError::ExceedsMaxBorrows`]. Consecutive calls return the same
+    /// [`crate::sample::Sample`] until it was received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    /// let mut subscriber = service.subscriber_builder().create()?;
+    ///
+    /// publisher.send_copy(42)?;
+    ///
+    /// if let Some(sample) = subscriber.peek()? {
+    ///     println!("next sample: {:?}", sample.payload());
+    /// }
+    ///
+    /// // returns the peeked sample
+    /// let sample = subscriber.receive()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(&mut self) -> Result<Option<&Sample<Service, Payload, UserHeader>>, ReceiveError> {
+        self.peek_impl(|this| this.receive())
+    }
 }
 
 impl<
@@ -388,6 +870,10 @@ impl<
     pub fn receive(&self) -> Result<Option<Sample<Service, [Payload], UserHeader>>, ReceiveError> {
         debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
 
+        if let Some(sample) = self.take_pinned_sample() {
+            return Ok(Some(sample));
+        }
+
         Ok(self.receive_impl()?.map(|(details, chunk)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
@@ -405,6 +891,50 @@ impl<
             }
         }))
     }
+
+    /// Returns a reference to the oldest [`crate::sample::Sample`] without consuming it. The
+    /// next call to [`Subscriber::receive()`] returns the same [`crate::sample::Sample`]. If no
+    /// sample could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    ///
+    /// The peeked [`crate::sample::Sample`] is removed from the buffer and pinned inside the
+    /// [`Subscriber`] until it is received. Therefore, it is never overwritten when safe
+    /// overflow is enabled and counts towards the max borrowed samples. When the
+    /// [`Subscriber`] already holds the max borrowed samples, the call fails with
+    /// [`ReceiveError::ExceedsMaxBorrows`]. Consecutive calls return the same
+    /// [`crate::sample::Sample`] until it was received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(4).create()?;
+    /// let mut subscriber = service.subscriber_builder().create()?;
+    ///
+    /// publisher.send_slice_copy(&[1, 2, 3])?;
+    ///
+    /// if let Some(sample) = subscriber.peek()? {
+    ///     println!("next sample: {:?}", sample.payload());
+    /// }
+    ///
+    /// // returns the peeked sample
+    /// let sample = subscriber.receive()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn peek(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, [Payload], UserHeader>>, ReceiveError> {
+        self.peek_impl(|this| this.receive())
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
@@ -423,6 +953,10 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
     pub unsafe fn receive_custom_payload(
         &self,
     ) -> Result<Option<Sample<Service, [CustomPayloadMarker], UserHeader>>, ReceiveError> {
+        if let Some(sample) = self.take_pinned_sample() {
+            return Ok(Some(sample));
+        }
+
         Ok(self.receive_impl()?.map(|(details, chunk)| {
             let header_ptr = chunk.header as *const Header;
             let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
@@ -442,4 +976,15 @@ impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
             }
         }))
     }
+
+    /// # Safety
+    ///
+    ///  * see [`Subscriber::receive_custom_payload()`]
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    #[doc(hidden)]
+    pub unsafe fn peek_custom_payload(
+        &mut self,
+    ) -> Result<Option<&Sample<Service, [CustomPayloadMarker], UserHeader>>, ReceiveError> {
+        self.peek_impl(|this| this.receive_custom_payload())
+    }
 }
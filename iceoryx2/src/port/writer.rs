@@ -10,5 +10,130 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-#[doc(hidden)]
-pub struct Writer {}
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .create()?;
+//!
+//! let writer = service.writer_builder().create();
+//! writer.write::<i32>(&0, 123)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::builder::CustomKeyMarker;
+use crate::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+use crate::service::{self, ServiceState};
+
+extern crate alloc;
+use alloc::sync::Arc;
+
+/// Defines the failures that can occur when an entry is written with a [`Writer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterWriteError {
+    /// The [`Service`](crate::service::Service) has no entry with the provided key.
+    EntryDoesNotExist,
+    /// The entry stores a value of another type than the provided one.
+    IncompatibleValueType,
+}
+
+impl core::fmt::Display for WriterWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WriterWriteError::{self:?}")
+    }
+}
+
+impl core::error::Error for WriterWriteError {}
+
+/// Represents the writing endpoint of a blackboard based communication. A write replaces
+/// the value of an existing entry, every [`Reader`](crate::port::reader::Reader) sees the
+/// entry as changed afterwards.
+#[derive(Debug)]
+pub struct Writer<Service: service::Service, KeyType: ZeroCopySend + Debug> {
+    service_state: Arc<ServiceState<Service>>,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug> Writer<Service, KeyType> {
+    pub(crate) fn new(service: &Service) -> Self {
+        Self {
+            service_state: service.__internal_state().clone(),
+            _key: PhantomData,
+        }
+    }
+
+    unsafe fn write_impl(
+        &self,
+        key: &[u8],
+        value_type: &TypeDetail,
+        source: *const u8,
+    ) -> Result<(), WriterWriteError> {
+        let dynamic_config = self.service_state.dynamic_storage.get().blackboard();
+        let entry = match (0..dynamic_config.number_of_entries())
+            .map(|n| dynamic_config.entry(n))
+            .find(|entry| dynamic_config.has_key(entry, key))
+        {
+            Some(entry) => entry,
+            None => {
+                fail!(from self, with WriterWriteError::EntryDoesNotExist,
+                    "Unable to write the entry since the key does not exist.");
+            }
+        };
+
+        if entry.value_type() != value_type {
+            fail!(from self, with WriterWriteError::IncompatibleValueType,
+                "Unable to write the entry since it stores a value of type {:?} and not {:?}.",
+                entry.value_type(), value_type);
+        }
+
+        dynamic_config.write_value(entry, source);
+        Ok(())
+    }
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug + Eq + Copy>
+    Writer<Service, KeyType>
+{
+    /// Replaces the value of the entry with the provided key.
+    pub fn write<ValueType: ZeroCopySend + Copy + 'static>(
+        &self,
+        key: &KeyType,
+        value: ValueType,
+    ) -> Result<(), WriterWriteError> {
+        unsafe {
+            self.write_impl(
+                core::slice::from_raw_parts(
+                    (key as *const KeyType).cast::<u8>(),
+                    core::mem::size_of::<KeyType>(),
+                ),
+                &TypeDetail::__internal_new::<ValueType>(TypeVariant::FixedSize),
+                (&value as *const ValueType).cast(),
+            )
+        }
+    }
+}
+
+impl<Service: service::Service> Writer<Service, CustomKeyMarker> {
+    #[doc(hidden)]
+    pub unsafe fn __internal_write(
+        &self,
+        key: &[u8],
+        value_type: &TypeDetail,
+        source: *const u8,
+    ) -> Result<(), WriterWriteError> {
+        self.write_impl(key, value_type, source)
+    }
+}
@@ -48,13 +48,13 @@ use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use iceoryx2_cal::shm_allocator::PointerOffset;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
 
 use crate::{
     pending_response::PendingResponse,
     port::client::{ClientSharedState, RequestSendError},
     raw_sample::RawSampleMut,
-    service,
+    service::{self, header::request_response::monotonic_timestamp_ns},
 };
 
 /// The [`RequestMut`] represents the object that contains the payload that the
@@ -216,11 +216,13 @@ impl<
     /// [`Server`](crate::port::server::Server)s of the
     /// [`Service`](crate::service::Service).
     pub fn send(
-        self,
+        mut self,
     ) -> Result<
         PendingResponse<Service, RequestPayload, RequestHeader, ResponsePayload, ResponseHeader>,
         RequestSendError,
     > {
+        self.ptr.as_header_mut().timestamp_ns = monotonic_timestamp_ns();
+
         let client_shared_state = self.client_shared_state.lock();
         match client_shared_state.send_request(
             self.offset_to_chunk,
@@ -234,9 +236,18 @@ impl<
                     .request_sender
                     .loan_counter
                     .fetch_sub(1, Ordering::Relaxed);
+                let response_deadline = client_shared_state
+                    .request_sender
+                    .service_state
+                    .static_config
+                    .request_response()
+                    .response_deadline;
                 drop(client_shared_state);
                 let active_request = PendingResponse {
                     number_of_server_connections,
+                    response_deadline,
+                    first_response_timestamp_ns: IoxAtomicU64::new(0),
+                    latest_response_timestamp_ns: IoxAtomicU64::new(0),
                     request: self,
                     _service: PhantomData,
                     _response_payload: PhantomData,
@@ -50,6 +50,7 @@ use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use crate::port::client::ClientSharedState;
 use crate::port::details::chunk_details::ChunkDetails;
+use crate::port::details::padded_payload::PaddedPayload;
 use crate::port::port_identifiers::UniqueServerId;
 use crate::raw_sample::RawSample;
 use crate::service;
@@ -71,6 +72,9 @@ pub struct Response<
     pub(crate) client_shared_state: Service::ArcThreadSafetyPolicy<ClientSharedState<Service>>,
     pub(crate) details: ChunkDetails,
     pub(crate) channel_id: ChannelId,
+    // owns the zero padded copy of the payload `ptr` refers to, when the sender used an older,
+    // smaller version of an evolved payload type
+    pub(crate) _padded_payload: Option<PaddedPayload>,
 }
 
 unsafe impl<
@@ -49,7 +49,7 @@ use core::{
     sync::atomic::Ordering,
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::{
@@ -62,7 +62,7 @@ use crate::{
         SendError,
     },
     raw_sample::RawSampleMut,
-    service,
+    service::{self, header::request_response::monotonic_timestamp_ns},
 };
 
 /// Acquired by a [`ActiveRequest`](crate::active_request::ActiveRequest) with
@@ -90,6 +90,8 @@ pub struct ResponseMut<
     pub(crate) sample_size: usize,
     pub(crate) channel_id: ChannelId,
     pub(crate) connection_id: usize,
+    pub(crate) sent_single_response: Option<Arc<IoxAtomicBool>>,
+    pub(crate) returned_credit: Option<Arc<IoxAtomicBool>>,
     pub(crate) _response_payload: PhantomData<ResponsePayload>,
     pub(crate) _response_header: PhantomData<ResponseHeader>,
 }
@@ -197,6 +199,12 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    pub(crate) fn mark_as_error(&mut self, error_code: u64) {
+        let header = self.ptr.as_header_mut();
+        header.is_error = true;
+        header.error_code = error_code;
+    }
+
     /// Returns a reference to the user header of the response.
     ///
     /// ```
@@ -332,20 +340,49 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<(), SendError> {
+    pub fn send(mut self) -> Result<(), SendError> {
         let msg = "Unable to send response";
+        self.ptr.as_header_mut().timestamp_ns = monotonic_timestamp_ns();
 
         let shared_state = self.shared_state.lock();
         fail!(from self, when shared_state.update_connections(),
             "{} since the connections could not be updated.", msg);
 
+        if let Some(sent_single_response) = &self.sent_single_response {
+            if sent_single_response.swap(true, Ordering::Relaxed) {
+                fail!(from self, with SendError::MultiResponseDisabled,
+                    "{} since the service does not support multiple responses and the response was already sent.", msg);
+            }
+        }
+
         if self.connection_id != INVALID_CONNECTION_ID {
+            shared_state.acknowledge_request(&self.returned_credit, self.connection_id);
+
+            let request_id = self.ptr.as_header_ref().request_id;
+            if !shared_state.response_sender.has_channel_state(
+                self.channel_id,
+                self.connection_id,
+                request_id,
+            ) {
+                // the client is no longer interested in the response, e.g. because it
+                // cancelled the request, therefore the response is discarded
+                return Ok(());
+            }
+
             shared_state.response_sender.deliver_offset_to_connection(
                 self.offset_to_chunk,
                 self.sample_size,
                 self.channel_id,
                 self.connection_id,
             )?;
+
+            if self.sent_single_response.is_some() {
+                shared_state.response_sender.invalidate_channel_state(
+                    self.channel_id,
+                    self.connection_id,
+                    request_id,
+                );
+            }
         }
 
         Ok(())
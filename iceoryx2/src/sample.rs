@@ -24,17 +24,22 @@
 //! while let Some(sample) = subscriber.receive()? {
 //!     println!("received: {:?}", *sample);
 //!     println!("header publisher id {:?}", sample.header().publisher_id());
+//!     if let Some(age) = sample.age()? {
+//!         println!("sample age {:?}", age);
+//!     }
 //! }
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use core::{fmt::Debug, ops::Deref};
+use core::{fmt::Debug, ops::Deref, time::Duration};
 
 extern crate alloc;
 
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::{monotonic_clock_domain, ClockType, Time};
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
 use iceoryx2_cal::zero_copy_connection::ChannelId;
@@ -45,6 +50,25 @@ use crate::port::subscriber::SubscriberSharedState;
 use crate::raw_sample::RawSample;
 use crate::service::header::publish_subscribe::Header;
 
+/// Defines the failure that can occur when the age of a [`Sample`] is acquired with
+/// [`Sample::age()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SampleAgeError {
+    /// The [`Sample`] was timestamped in another clock domain, e.g. on another host, and its
+    /// monotonic time is not comparable with the local monotonic clock.
+    ClockDomainMismatch,
+    /// The current time of the monotonic clock could not be acquired.
+    UnableToAcquireCurrentTime,
+}
+
+impl core::fmt::Display for SampleAgeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SampleAgeError::{self:?}")
+    }
+}
+
+impl core::error::Error for SampleAgeError {}
+
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
 /// it receives new data from a [`Publisher`](crate::port::publisher::Publisher) via
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
@@ -139,4 +163,30 @@ impl<
     pub fn origin(&self) -> UniquePublisherId {
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
+
+    /// Returns how much time has passed on the monotonic clock since the [`Sample`] was
+    /// timestamped. If the [`Sample`] does not contain a
+    /// [`Timestamp`](crate::service::header::publish_subscribe::Timestamp) it returns [`None`].
+    /// When the [`Sample`] was timestamped in another clock domain, e.g. on another host, the
+    /// age cannot be determined and [`SampleAgeError::ClockDomainMismatch`] is returned.
+    pub fn age(&self) -> Result<Option<Duration>, SampleAgeError> {
+        let msg = "Unable to acquire the age of the sample";
+        let timestamp = match self.header().timestamp() {
+            Some(timestamp) => timestamp,
+            None => return Ok(None),
+        };
+
+        if timestamp.clock_domain() != monotonic_clock_domain() {
+            fail!(from self, with SampleAgeError::ClockDomainMismatch,
+                "{msg} since it was timestamped in another clock domain ({}).", timestamp.clock_domain());
+        }
+
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+                        with SampleAgeError::UnableToAcquireCurrentTime,
+                        "{msg} since the current time could not be acquired.");
+
+        Ok(Some(
+            now.as_duration().saturating_sub(timestamp.monotonic()),
+        ))
+    }
 }
@@ -64,8 +64,10 @@
 //! ```
 
 use crate::{
-    port::publisher::PublisherSharedState, port::SendError, raw_sample::RawSampleMut,
-    service::header::publish_subscribe::Header,
+    port::publisher::PublisherSharedState,
+    port::SendError,
+    raw_sample::RawSampleMut,
+    service::header::publish_subscribe::{Header, Timestamp},
 };
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
@@ -333,9 +335,49 @@ impl<
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, SendError> {
-        self.publisher_shared_state
-            .lock()
-            .send_sample(self.offset_to_chunk, self.sample_size)
+    pub fn send(mut self) -> Result<usize, SendError> {
+        self.publisher_shared_state.lock().send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+            None,
+        )
+    }
+
+    /// Sends the [`SampleMut`] like [`SampleMut::send()`] but stores the provided [`Timestamp`]
+    /// in the [`Header`] instead of the time of sending. It can be used to record when the data
+    /// was captured. The [`Timestamp`] is stored even when the
+    /// [`Service`](crate::service::Service) was created without send timestamps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::header::publish_subscribe::Timestamp;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let capture_time = Timestamp::from_monotonic(Duration::from_secs(12)).unwrap();
+    /// let sample = publisher.loan_uninit()?.write_payload(4567);
+    ///
+    /// sample.send_with_timestamp(capture_time)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_with_timestamp(mut self, timestamp: Timestamp) -> Result<usize, SendError> {
+        self.publisher_shared_state.lock().send_sample(
+            self.ptr.as_header_mut(),
+            self.offset_to_chunk,
+            self.sample_size,
+            Some(timestamp),
+        )
     }
 }
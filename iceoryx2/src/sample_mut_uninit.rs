@@ -102,6 +102,24 @@ use crate::{
     service::header::publish_subscribe::Header,
 };
 
+/// Defines the failures that can occur when the payload of a [`SampleMutUninit`] is written
+/// with [`SampleMutUninit::try_write_from_iter()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriteFromIterError {
+    /// The iterator provided less elements than the slice contains.
+    TooFewElements,
+    /// The iterator provided more elements than the slice contains.
+    TooManyElements,
+}
+
+impl core::fmt::Display for WriteFromIterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "WriteFromIterError::{self:?}")
+    }
+}
+
+impl core::error::Error for WriteFromIterError {}
+
 /// Acquired by a [`crate::port::publisher::Publisher`] via
 ///  * [`crate::port::publisher::Publisher::loan_uninit()`]
 ///  * [`crate::port::publisher::Publisher::loan_slice_uninit()`]
@@ -452,6 +470,115 @@ impl<Service: crate::service::Service, Payload: Debug + ZeroCopySend, UserHeader
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
     }
+
+    /// Writes the elements of the [`ExactSizeIterator`] directly into the payload of the sample
+    /// and labels the sample as initialized. For iterators of unknown length use
+    /// [`SampleMutUninit::try_write_from_iter()`].
+    ///
+    /// # Panics
+    ///
+    ///  * when the length of the iterator does not match the slice length.
+    ///  * when the iterator provides less or more elements than it announced. The
+    ///    [`SampleMutUninit`] is released without being labeled as initialized and the
+    ///    elements that were already written are not dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let data = [1, 2, 3];
+    ///
+    /// let sample = publisher.loan_slice_uninit(data.len())?;
+    /// let sample = sample.write_from_iter(data.iter().map(|n| n * 2));
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_iter<I: IntoIterator<Item = Payload>>(
+        self,
+        iter: I,
+    ) -> SampleMut<Service, [Payload], UserHeader>
+    where
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        assert!(
+            iter.len() == self.payload().len(),
+            "The iterator provides {} elements but the slice has a length of {}.",
+            iter.len(),
+            self.payload().len()
+        );
+
+        match self.try_write_from_iter(iter) {
+            Ok(sample) => sample,
+            Err(e) => panic!(
+                "The number of elements of the iterator does not match the slice length ({e})."
+            ),
+        }
+    }
+
+    /// Writes the elements of the iterator directly into the payload of the sample and labels
+    /// the sample as initialized. If the iterator provides less or more elements than the
+    /// slice contains, the [`SampleMutUninit`] is released without being labeled as
+    /// initialized, the elements that were already written are not dropped and
+    /// [`WriteFromIterError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().initial_max_slice_len(16).create()?;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let even_numbers = data.iter().filter(|n| *n % 2 == 0);
+    ///
+    /// let sample = publisher.loan_slice_uninit(3)?;
+    /// let sample = sample.try_write_from_iter(even_numbers.copied())?;
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_write_from_iter<I: IntoIterator<Item = Payload>>(
+        mut self,
+        iter: I,
+    ) -> Result<SampleMut<Service, [Payload], UserHeader>, WriteFromIterError> {
+        let mut iter = iter.into_iter();
+        for element in self.payload_mut().iter_mut() {
+            match iter.next() {
+                Some(value) => {
+                    element.write(value);
+                }
+                None => return Err(WriteFromIterError::TooFewElements),
+            }
+        }
+
+        if iter.next().is_some() {
+            return Err(WriteFromIterError::TooManyElements);
+        }
+
+        // SAFETY: this is safe since every element of the payload was initialized above
+        Ok(unsafe { self.assume_init() })
+    }
 }
 
 impl<
@@ -15,7 +15,7 @@
 //! See [`crate::service`]
 //!
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
-use super::{OpenDynamicStorageFailure, ServiceState};
+use super::{CustomKeyMarker, OpenDynamicStorageFailure, ServiceState};
 use crate::service;
 use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
 use crate::service::port_factory::blackboard;
@@ -24,6 +24,7 @@ use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use builder::RETRY_LIMIT;
 use core::marker::PhantomData;
+use core::time::Duration;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
@@ -110,6 +111,8 @@ pub enum BlackboardCreateError {
     /// The [`Service`]s creation timeout has passed and it is still not initialized. Can be caused
     /// by a process that crashed during [`Service`] creation.
     HangsInCreation,
+    /// The key or the value of an added entry does not match the size of its type.
+    IncompatibleEntry,
 }
 
 impl core::fmt::Display for BlackboardCreateError {
@@ -151,6 +154,9 @@ pub enum BlackboardOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 }
 
 impl core::fmt::Display for BlackboardOpenOrCreateError {
@@ -161,6 +167,16 @@ impl core::fmt::Display for BlackboardOpenOrCreateError {
 
 impl core::error::Error for BlackboardOpenOrCreateError {}
 
+impl BlackboardOpenOrCreateError {
+    fn is_hanging_in_creation(&self) -> bool {
+        matches!(
+            self,
+            Self::BlackboardOpenError(BlackboardOpenError::HangsInCreation)
+                | Self::BlackboardCreateError(BlackboardCreateError::HangsInCreation)
+        )
+    }
+}
+
 impl From<ServiceAvailabilityState> for BlackboardOpenOrCreateError {
     fn from(value: ServiceAvailabilityState) -> Self {
         Self::BlackboardOpenError(value.into())
@@ -179,16 +195,25 @@ impl From<BlackboardCreateError> for BlackboardOpenOrCreateError {
     }
 }
 
+#[derive(Debug, Clone)]
+struct BuilderEntry {
+    key: Vec<u8>,
+    value_type: TypeDetail,
+    value: Vec<u8>,
+}
+
 /// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
 ///
 /// # Example
 ///
 /// See [`crate::service`]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Builder<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> {
     base: builder::BuilderWithServiceType<ServiceType>,
     verify_max_readers: bool,
     verify_max_nodes: bool,
+    override_key_type: Option<TypeDetail>,
+    entries: Vec<BuilderEntry>,
     _key: PhantomData<KeyType>,
 }
 
@@ -198,12 +223,19 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
             base,
             verify_max_readers: false,
             verify_max_nodes: false,
+            override_key_type: None,
+            entries: Vec::new(),
             _key: PhantomData,
         };
 
-        new_self.base.service_config.messaging_pattern = MessagingPattern::Blackboard(
-            static_config::blackboard::StaticConfig::new(new_self.base.shared_node.config()),
-        );
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Blackboard(static_config::blackboard::StaticConfig::new(
+                &new_self
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.base.service_config.name()),
+            ));
 
         new_self
     }
@@ -217,8 +249,10 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
             Ok(Some((config, storage))) => {
                 if !(self.config_details().type_details == config.blackboard().type_details) {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleKeys,
-                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.blackboard().type_details , self.config_details().type_details);
+                        "{} since the service offers a key type which is not compatible to the requested key type: {}.",
+                        error_msg, self.config_details().type_details
+                            .mismatch_description("key", &config.blackboard().type_details)
+                            .unwrap_or_default());
                 }
 
                 Ok(Some((config, storage)))
@@ -264,15 +298,60 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
         self
     }
 
-    #[doc(hidden)]
-    #[allow(unused_mut)]
-    pub fn add<ValueType: ZeroCopySend>(mut self, _key: KeyType, _value: ValueType) -> Self {
-        todo!()
+    /// Overrides the [`Config`](crate::config::Config)s service creation timeout for this
+    /// builder only. It defines how long it is waited for a [`Service`] that is still being
+    /// created by another process before opening it fails with
+    /// [`BlackboardOpenError::HangsInCreation`].
+    pub fn creation_timeout(mut self, value: Duration) -> Self {
+        self.base.creation_timeout = Some(value);
+        self
+    }
+
+    /// Adds an entry with its initial value to the [`Service`]. The entries are defined by
+    /// the creator of the [`Service`], entries added when an existing [`Service`] is opened
+    /// are ignored. Adding a key twice replaces the previous entry.
+    pub fn add<ValueType: ZeroCopySend + Copy + 'static>(
+        self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        let key = unsafe {
+            core::slice::from_raw_parts(
+                (&key as *const KeyType).cast::<u8>(),
+                core::mem::size_of::<KeyType>(),
+            )
+        };
+        let value = unsafe {
+            core::slice::from_raw_parts(
+                (&value as *const ValueType).cast::<u8>(),
+                core::mem::size_of::<ValueType>(),
+            )
+        };
+
+        self.add_entry(
+            key,
+            &TypeDetail::__internal_new::<ValueType>(message_type_details::TypeVariant::FixedSize),
+            value,
+        )
+    }
+
+    fn add_entry(mut self, key: &[u8], value_type: &TypeDetail, value: &[u8]) -> Self {
+        self.entries.retain(|entry| entry.key != key);
+        self.entries.push(BuilderEntry {
+            key: key.to_vec(),
+            value_type: value_type.clone(),
+            value: value.to_vec(),
+        });
+        self
     }
 
     fn prepare_config_details(&mut self) {
         self.config_details_mut().type_details =
             TypeDetail::__internal_new::<KeyType>(message_type_details::TypeVariant::FixedSize);
+
+        if let Some(details) = &self.override_key_type {
+            self.config_details_mut().type_details = details.clone();
+        }
     }
 
     fn verify_service_configuration(
@@ -335,10 +414,23 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     /// created.
     pub fn open_or_create(
         self,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardOpenOrCreateError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
     /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
@@ -346,13 +438,32 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     pub fn open_or_create_with_attributes(
         mut self,
         verifier: &AttributeVerifier,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardOpenOrCreateError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
         self.prepare_config_details();
 
+        let has_deadline = self.base.open_or_create_deadline.is_some();
+
+        match self.open_or_create_with_retry(verifier) {
+            Err(e) if has_deadline && e.is_hanging_in_creation() => {
+                fail!(with BlackboardOpenOrCreateError::Timeout,
+                    "Unable to open or create blackboard service since it is still being created by another instance and the timeout has passed.");
+            }
+            v => v,
+        }
+    }
+
+    fn open_or_create_with_retry(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
         let msg = "Unable to open or create blackboard service";
 
         let mut retry_count = 0;
         loop {
+            if 0 < retry_count && self.base.has_open_or_create_deadline_passed() {
+                fail!(from self, with BlackboardOpenOrCreateError::Timeout, "{} since the service could not be opened or created within the provided timeout.", msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self, with BlackboardOpenOrCreateError::SystemInFlux, "{} since an instance is creating and removing the same service repeatedly.", msg);
             }
@@ -385,7 +496,9 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     }
 
     /// Opens an existing [`Service`].
-    pub fn open(self) -> Result<blackboard::PortFactory<ServiceType>, BlackboardOpenError> {
+    pub fn open(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
@@ -394,7 +507,7 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     pub fn open_with_attributes(
         mut self,
         verifier: &AttributeVerifier,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardOpenError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
         self.prepare_config_details();
         self.open_impl(verifier)
     }
@@ -402,7 +515,7 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     fn open_impl(
         &mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardOpenError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
         let msg = "Unable to open blackboard service";
 
         let mut service_open_retry_count = 0;
@@ -473,7 +586,9 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     }
 
     /// Creates a new [`Service`].
-    pub fn create(mut self) -> Result<blackboard::PortFactory<ServiceType>, BlackboardCreateError> {
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
         self.prepare_config_details();
         self.create_impl(&AttributeSpecifier::new())
     }
@@ -482,7 +597,7 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     pub fn create_with_attributes(
         mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardCreateError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
         self.prepare_config_details();
         self.create_impl(attributes)
     }
@@ -490,11 +605,32 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
     fn create_impl(
         &mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<blackboard::PortFactory<ServiceType>, BlackboardCreateError> {
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
         self.adjust_configuration_to_meaningful_values();
 
         let msg = "Unable to create blackboard service";
 
+        let key_size = self.config_details().type_details.size;
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|entry| entry.key.len() != key_size)
+        {
+            fail!(from self, with BlackboardCreateError::IncompatibleEntry,
+                "{} since the entry with a key of {} bytes does not match the key type of {} bytes.",
+                msg, entry.key.len(), key_size);
+        }
+
+        if let Some(entry) = self
+            .entries
+            .iter()
+            .find(|entry| entry.value.len() != entry.value_type.size)
+        {
+            fail!(from self, with BlackboardCreateError::IncompatibleEntry,
+                "{} since the entry with a value of {} bytes does not match its value type of {} bytes.",
+                msg, entry.value.len(), entry.value_type.size);
+        }
+
         match self.is_service_available(msg)? {
             Some(_) => {
                 fail!(from self, with BlackboardCreateError::AlreadyExists, "{} since the service already exists.", msg);
@@ -530,14 +666,30 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
                 // create dynamic config
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_readers: blackboard_config.max_readers,
+                    number_of_entries: self.entries.len(),
+                    entry_data_size: self
+                        .entries
+                        .iter()
+                        .map(|entry| entry.key.len() + entry.value.len())
+                        .sum(),
                 };
 
-                let dynamic_config = match self.base.create_dynamic_config_storage(
+                let entries = &self.entries;
+                let dynamic_config = match self.base.create_dynamic_config_storage_with_initializer(
                     dynamic_config::MessagingPattern::Blackboard(
                         dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
                     ),
                     dynamic_config::blackboard::DynamicConfig::memory_size(&dynamic_config_setting),
                     blackboard_config.max_nodes,
+                    |config| {
+                        entries.iter().all(|entry| unsafe {
+                            config.blackboard_mut().add_entry(
+                                &entry.key,
+                                &entry.value_type,
+                                &entry.value,
+                            )
+                        })
+                    },
                 ) {
                     Ok(dynamic_config) => dynamic_config,
                     Err(DynamicStorageCreateError::AlreadyExists) => {
@@ -566,6 +718,11 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
                     service_tag.release_ownership();
                 }
 
+                service::internal::announce_service_change(
+                    &self.base.shared_node,
+                    service::ServiceAnnouncement::ServiceAdded(self.base.service_config.clone()),
+                );
+
                 Ok(blackboard::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -578,3 +735,16 @@ impl<KeyType: ZeroCopySend + Debug, ServiceType: service::Service> Builder<KeyTy
         }
     }
 }
+
+impl<ServiceType: service::Service> Builder<CustomKeyMarker, ServiceType> {
+    #[doc(hidden)]
+    pub unsafe fn __internal_set_key_type_details(mut self, value: &TypeDetail) -> Self {
+        self.override_key_type = Some(value.clone());
+        self
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn __internal_add(self, key: &[u8], value_type: &TypeDetail, value: &[u8]) -> Self {
+        self.add_entry(key, value_type, value)
+    }
+}
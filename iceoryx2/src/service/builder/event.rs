@@ -145,6 +145,9 @@ pub enum EventOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 }
 
 impl From<EventOpenError> for EventOpenOrCreateError {
@@ -167,6 +170,16 @@ impl core::fmt::Display for EventOpenOrCreateError {
 
 impl core::error::Error for EventOpenOrCreateError {}
 
+impl EventOpenOrCreateError {
+    fn is_hanging_in_creation(&self) -> bool {
+        matches!(
+            self,
+            Self::EventOpenError(EventOpenError::HangsInCreation)
+                | Self::EventCreateError(EventCreateError::HangsInCreation)
+        )
+    }
+}
+
 impl From<ServiceState> for EventOpenOrCreateError {
     fn from(value: ServiceState) -> Self {
         EventOpenOrCreateError::EventOpenError(value.into())
@@ -205,9 +218,14 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_notifier_dropped_event: false,
         };
 
-        new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
-            static_config::event::StaticConfig::new(new_self.base.shared_node.config()),
-        );
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Event(static_config::event::StaticConfig::new(
+                &new_self
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.base.service_config.name()),
+            ));
 
         new_self
     }
@@ -249,6 +267,15 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// Overrides the [`Config`](crate::config::Config)s service creation timeout for this
+    /// builder only. It defines how long it is waited for a [`Service`] that is still being
+    /// created by another process before opening it fails with
+    /// [`EventOpenError::HangsInCreation`].
+    pub fn creation_timeout(mut self, value: Duration) -> Self {
+        self.base.creation_timeout = Some(value);
+        self
+    }
+
     /// If the [`Service`] is created it set the greatest supported [`NodeId`] value
     /// If an existing [`Service`] is opened it defines the value size the [`NodeId`]
     /// must at least support.
@@ -330,11 +357,39 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
     /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
     /// does not exist the required attributes will be defined in the [`Service`].
     pub fn open_or_create_with_attributes(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
+        let has_deadline = self.base.open_or_create_deadline.is_some();
+
+        match self.open_or_create_with_retry(verifier) {
+            Err(e) if has_deadline && e.is_hanging_in_creation() => {
+                fail!(with EventOpenOrCreateError::Timeout,
+                    "Unable to open or create event service since it is still being created by another instance and the timeout has passed.");
+            }
+            v => v,
+        }
+    }
+
+    fn open_or_create_with_retry(
         mut self,
         verifier: &AttributeVerifier,
     ) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -342,6 +397,13 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
 
         let mut retry_count = 0;
         loop {
+            if 0 < retry_count && self.base.has_open_or_create_deadline_passed() {
+                fail!(from self,
+                      with EventOpenOrCreateError::Timeout,
+                      "{} since the service could not be opened or created within the provided timeout.",
+                      msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with EventOpenOrCreateError::SystemInFlux,
@@ -546,6 +608,11 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     service_tag.release_ownership();
                 }
 
+                service::internal::announce_service_change(
+                    &self.base.shared_node,
+                    service::ServiceAnnouncement::ServiceAdded(self.base.service_config.clone()),
+                );
+
                 Ok(event::PortFactory::new(ServiceType::__internal_from_state(
                     service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -33,6 +33,7 @@ use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use core::time::Duration;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -47,6 +48,7 @@ use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
+use std::time::Instant;
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -54,7 +56,8 @@ use alloc::sync::Arc;
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::service_tag_config;
 use super::config_scheme::static_config_storage_config;
-use super::service_name::ServiceName;
+use super::segment_provider;
+use super::service_name::{ServiceName, ServiceNameError};
 use super::Service;
 
 const RETRY_LIMIT: usize = 5;
@@ -77,6 +80,11 @@ pub struct CustomHeaderMarker {}
 #[doc(hidden)]
 pub struct CustomPayloadMarker(u8);
 
+#[repr(C)]
+#[derive(Debug, ZeroCopySend, Clone)]
+#[doc(hidden)]
+pub struct CustomKeyMarker(u8);
+
 enum_gen! {
 #[doc(hidden)]
     OpenDynamicStorageFailure
@@ -194,6 +202,8 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
     service_config: StaticConfig,
     shared_node: Arc<SharedNode<ServiceType>>,
+    creation_timeout: Option<Duration>,
+    open_or_create_deadline: Option<Instant>,
     _phantom_data: PhantomData<ServiceType>,
 }
 
@@ -202,10 +212,41 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         Self {
             service_config,
             shared_node,
+            creation_timeout: None,
+            open_or_create_deadline: None,
             _phantom_data: PhantomData,
         }
     }
 
+    fn creation_timeout(&self) -> Duration {
+        let timeout = self
+            .creation_timeout
+            .unwrap_or(self.shared_node.config().global.service.creation_timeout);
+
+        match self.open_or_create_deadline {
+            Some(deadline) => timeout.min(deadline.saturating_duration_since(Instant::now())),
+            None => timeout,
+        }
+    }
+
+    fn prepend_to_service_name(&mut self, prefix: &str) -> Result<(), ServiceNameError> {
+        let name = ServiceName::new(&(prefix.to_owned() + self.service_config.name().as_str()))?;
+        self.service_config
+            .set_name::<ServiceType::ServiceNameHasher>(&name);
+        Ok(())
+    }
+
+    fn set_open_or_create_timeout(&mut self, timeout: Duration) {
+        self.open_or_create_deadline = Some(Instant::now() + timeout);
+    }
+
+    fn has_open_or_create_deadline_passed(&self) -> bool {
+        match self.open_or_create_deadline {
+            Some(deadline) => deadline <= Instant::now(),
+            None => false,
+        }
+    }
+
     fn request_response<
         RequestPayload: Debug + ZeroCopySend + ?Sized,
         ResponsePayload: Debug + ZeroCopySend + ?Sized,
@@ -238,7 +279,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         let static_storage_config =
             static_config_storage_config::<ServiceType>(self.shared_node.config());
         let file_name_uuid = self.service_config.service_id().0.clone().into();
-        let creation_timeout = self.shared_node.config().global.service.creation_timeout;
+        let creation_timeout = self.creation_timeout();
 
         match <ServiceType::StaticStorage as NamedConceptMgmt>::does_exist_cfg(
             &file_name_uuid,
@@ -312,8 +353,26 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         messaging_pattern: super::dynamic_config::MessagingPattern,
         additional_size: usize,
         max_number_of_nodes: usize,
+    ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
+        self.create_dynamic_config_storage_with_initializer(
+            messaging_pattern,
+            additional_size,
+            max_number_of_nodes,
+            |_| true,
+        )
+    }
+
+    // the initializer is called after the dynamic config was initialized and before the
+    // service becomes available to other processes
+    fn create_dynamic_config_storage_with_initializer<F: FnMut(&mut DynamicConfig) -> bool>(
+        &self,
+        messaging_pattern: super::dynamic_config::MessagingPattern,
+        additional_size: usize,
+        max_number_of_nodes: usize,
+        mut initializer: F,
     ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
         let msg = "Failed to create dynamic storage for service";
+        segment_provider::seal(self.shared_node.config());
         let required_memory_size = DynamicConfig::memory_size(max_number_of_nodes);
         match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
@@ -323,14 +382,14 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
             .supplementary_size(additional_size + required_memory_size)
             .has_ownership(false)
-            .initializer(Self::config_init_call)
+            .initializer(|config, allocator| Self::config_init_call(config, allocator) && initializer(config))
             .create(DynamicConfig::new_uninit(messaging_pattern, max_number_of_nodes) ) {
                 Ok(dynamic_storage) => {
                     let node_id = self.shared_node.id();
                     let node_handle = fatal_panic!(from self,
                             when dynamic_storage.get().register_node_id(*node_id),
                             "{} since event the first NodeId could not be registered.", msg);
-                    self.shared_node.registered_services().add(self.service_config.service_id(), node_handle);
+                    self.shared_node.registered_services().add(&self.service_config, node_handle);
                     Ok(dynamic_storage)
                 },
                 Err(e) => {
@@ -343,13 +402,14 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         &self,
     ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
+        segment_provider::seal(self.shared_node.config());
         let storage = fail!(from self, when
             <<ServiceType::DynamicStorage as DynamicStorage<
                     DynamicConfig,
                 >>::Builder<'_> as NamedConceptBuilder<
                     ServiceType::DynamicStorage,
                 >>::new(&self.service_config.service_id().0.clone().into())
-                    .timeout(self.shared_node.config().global.service.creation_timeout)
+                    .timeout(self.creation_timeout())
                     .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
                 .has_ownership(false)
                 .open(),
@@ -357,7 +417,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
 
         self.shared_node
             .registered_services()
-            .add_or(self.service_config.service_id(), || {
+            .add_or(&self.service_config, || {
                 let node_id = self.shared_node.id();
                 match storage.get().register_node_id(*node_id) {
                     Ok(handle) => Ok(handle),
@@ -15,6 +15,7 @@
 //! See [`crate::service`]
 //!
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
@@ -23,6 +24,7 @@ use crate::service::port_factory::publish_subscribe;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use builder::RETRY_LIMIT;
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
@@ -32,7 +34,9 @@ use iceoryx2_cal::static_storage::StaticStorageLocked;
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    message_type_details::{MessageTypeDetails, TypeDetail, TypeVariant},
+    message_type_details::{
+        MessageTypeDetails, TypeCompatibility, TypeDetail, TypeName, TypeVariant,
+    },
 };
 
 use super::{CustomHeaderMarker, CustomPayloadMarker, OpenDynamicStorageFailure, ServiceState};
@@ -64,6 +68,14 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] required send timestamp behavior is not compatible.
+    IncompatibleSendTimestampBehavior,
+    /// The [`Service`]s sample lifespan is not equal to the user given requirement.
+    IncompatibleSampleLifespan,
+    /// The [`Service`] required exclusive ownership behavior is not compatible.
+    IncompatibleOwnershipBehavior,
+    /// The [`Service`] required notify on delivery behavior is not compatible.
+    IncompatibleNotifyOnDeliveryBehavior,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -176,6 +188,9 @@ pub enum PublishSubscribeOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 }
 
 impl From<ServiceAvailabilityState> for PublishSubscribeOpenOrCreateError {
@@ -204,6 +219,16 @@ impl core::fmt::Display for PublishSubscribeOpenOrCreateError {
 
 impl core::error::Error for PublishSubscribeOpenOrCreateError {}
 
+impl PublishSubscribeOpenOrCreateError {
+    fn is_hanging_in_creation(&self) -> bool {
+        matches!(
+            self,
+            Self::PublishSubscribeOpenError(PublishSubscribeOpenError::HangsInCreation)
+                | Self::PublishSubscribeCreateError(PublishSubscribeCreateError::HangsInCreation)
+        )
+    }
+}
+
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
 /// # Example
@@ -219,12 +244,18 @@ pub struct Builder<
     override_alignment: Option<usize>,
     override_payload_type: Option<TypeDetail>,
     override_user_header_type: Option<TypeDetail>,
+    override_payload_type_name: Option<TypeName>,
+    override_user_header_type_name: Option<TypeName>,
     verify_number_of_subscribers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_send_timestamp: bool,
+    verify_sample_lifespan: bool,
+    verify_enable_exclusive_ownership: bool,
+    verify_enable_notify_on_delivery: bool,
     verify_max_nodes: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
@@ -242,12 +273,18 @@ impl<
             override_alignment: self.override_alignment,
             override_payload_type: self.override_payload_type.clone(),
             override_user_header_type: self.override_user_header_type.clone(),
+            override_payload_type_name: self.override_payload_type_name.clone(),
+            override_user_header_type_name: self.override_user_header_type_name.clone(),
             verify_number_of_subscribers: self.verify_number_of_subscribers,
             verify_number_of_publishers: self.verify_number_of_publishers,
             verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
             verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
             verify_publisher_history_size: self.verify_publisher_history_size,
             verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            verify_enable_send_timestamp: self.verify_enable_send_timestamp,
+            verify_sample_lifespan: self.verify_sample_lifespan,
+            verify_enable_exclusive_ownership: self.verify_enable_exclusive_ownership,
+            verify_enable_notify_on_delivery: self.verify_enable_notify_on_delivery,
             verify_max_nodes: self.verify_max_nodes,
             _data: PhantomData,
             _user_header: PhantomData,
@@ -270,16 +307,28 @@ impl<
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_send_timestamp: false,
+            verify_sample_lifespan: false,
+            verify_enable_exclusive_ownership: false,
+            verify_enable_notify_on_delivery: false,
             verify_max_nodes: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
+            override_payload_type_name: None,
+            override_user_header_type_name: None,
             _data: PhantomData,
             _user_header: PhantomData,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
-            static_config::publish_subscribe::StaticConfig::new(new_self.base.shared_node.config()),
+            static_config::publish_subscribe::StaticConfig::new(
+                &new_self
+                    .base
+                    .shared_node
+                    .config()
+                    .defaults_for(new_self.base.service_config.name()),
+            ),
         );
 
         new_self
@@ -310,14 +359,22 @@ impl<
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
-                    .message_type_details
-                    .is_compatible_to(&config.publish_subscribe().message_type_details)
-                {
+                let type_compatibility = self.config_details().type_compatibility;
+                if config.publish_subscribe().type_compatibility != type_compatibility {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service was created with the type compatibility {:?} but {:?} is required.",
+                        error_msg, config.publish_subscribe().type_compatibility, type_compatibility);
+                }
+
+                if !self.config_details().message_type_details.is_compatible_to(
+                    &config.publish_subscribe().message_type_details,
+                    type_compatibility,
+                ) {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
-                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.publish_subscribe().message_type_details , self.config_details().message_type_details);
+                        "{} since the service offers a type which is not compatible to the requested type with the type compatibility {:?} the service was created with: {}.",
+                        error_msg, type_compatibility,
+                        self.config_details().message_type_details.mismatch_description(
+                            &config.publish_subscribe().message_type_details, type_compatibility, false));
                 }
 
                 Ok(Some((config, storage)))
@@ -341,6 +398,32 @@ impl<
         self
     }
 
+    /// Overrides the type name of the payload that is stored in the static configuration, e.g.
+    /// with a language neutral name like `com.acme.Image`. Without an override the Rust type
+    /// name is used. If an existing [`Service`] is opened, the name is used to verify the
+    /// payload type.
+    pub fn payload_type_name(mut self, value: &TypeName) -> Self {
+        self.override_payload_type_name = Some(value.clone());
+        self
+    }
+
+    /// Overrides the type name of the user header that is stored in the static configuration.
+    /// Without an override the Rust type name is used. If an existing [`Service`] is opened,
+    /// the name is used to verify the user header type.
+    pub fn user_header_type_name(mut self, value: &TypeName) -> Self {
+        self.override_user_header_type_name = Some(value.clone());
+        self
+    }
+
+    /// Defines how the payload and user header types are matched when an existing [`Service`]
+    /// is opened. By default, [`TypeCompatibility::Strict`] is used. If the [`Service`] is
+    /// created, the mode is stored in the static configuration. If an existing [`Service`] is
+    /// opened, it must have been created with the same mode.
+    pub fn type_compatibility(mut self, value: TypeCompatibility) -> Self {
+        self.config_details_mut().type_compatibility = value;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
@@ -349,6 +432,67 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if the [`crate::port::publisher::Publisher`]
+    /// captures a [`Timestamp`](crate::service::header::publish_subscribe::Timestamp) in the
+    /// [`Header`](crate::service::header::publish_subscribe::Header) of every
+    /// [`crate::sample::Sample`] it sends. It is enabled by default. The
+    /// [`Header`](crate::service::header::publish_subscribe::Header) always reserves the space
+    /// for the timestamp, so disabling it only saves the two clock reads on every send and
+    /// not any memory. Without a send timestamp a
+    /// [`crate::port::subscriber::Subscriber::set_max_sample_age()`] has no effect. If an
+    /// existing [`Service`] is opened it requires the service to have the defined send
+    /// timestamp behavior.
+    pub fn enable_send_timestamp(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_send_timestamp = value;
+        self.verify_enable_send_timestamp = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines how long a [`crate::sample::Sample`] is valid
+    /// after it was sent. The [`crate::port::subscriber::Subscriber`] discards and releases
+    /// older [`crate::sample::Sample`]s instead of receiving them. The age is determined from
+    /// the send [`Timestamp`](crate::service::header::publish_subscribe::Timestamp), therefore
+    /// the [`crate::port::publisher::Publisher`] always captures it when a lifespan is set. A
+    /// lifespan of zero disables the lifespan. If an existing [`Service`] is opened it requires
+    /// the service to have the same lifespan.
+    pub fn sample_lifespan(mut self, value: Duration) -> Self {
+        self.config_details_mut().sample_lifespan = (!value.is_zero()).then_some(value);
+        self.verify_sample_lifespan = true;
+        self
+    }
+
+    /// If the [`Service`] is created, [`crate::sample::Sample`]s never expire. If an existing
+    /// [`Service`] is opened it requires the service to have no sample lifespan.
+    pub fn disable_sample_lifespan(mut self) -> Self {
+        self.config_details_mut().sample_lifespan = None;
+        self.verify_sample_lifespan = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the [`crate::port::subscriber::Subscriber`]s
+    /// arbitrate between redundant [`crate::port::publisher::Publisher`]s. When enabled, they
+    /// receive only the [`crate::sample::Sample`]s of the alive
+    /// [`crate::port::publisher::Publisher`] with the highest
+    /// [`PortFactoryPublisher::ownership_strength()`](crate::service::port_factory::publisher::PortFactoryPublisher::ownership_strength()).
+    /// When it dies, the next strongest takes over. If an existing [`Service`] is opened it
+    /// requires the service to have the same setting.
+    pub fn enable_exclusive_ownership(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_exclusive_ownership = value;
+        self.verify_enable_exclusive_ownership = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if every [`crate::port::subscriber::Subscriber`]
+    /// is notified when a [`crate::sample::Sample`] is delivered. When enabled, a
+    /// [`crate::port::subscriber::Subscriber`] can be attached directly to a
+    /// [`WaitSet`](crate::waitset::WaitSet) without a separate event service. If an existing
+    /// [`Service`] is opened it requires the service to have the same setting.
+    pub fn enable_notify_on_delivery(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notify_on_delivery = value;
+        self.verify_enable_notify_on_delivery = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -403,6 +547,15 @@ impl<
         self
     }
 
+    /// Overrides the [`Config`](crate::config::Config)s service creation timeout for this
+    /// builder only. It defines how long it is waited for a [`Service`] that is still being
+    /// created by another process before opening it fails with
+    /// [`PublishSubscribeOpenError::HangsInCreation`].
+    pub fn creation_timeout(mut self, value: Duration) -> Self {
+        self.base.creation_timeout = Some(value);
+        self
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{self:?}");
@@ -512,6 +665,40 @@ impl<
                                 msg);
         }
 
+        if self.verify_enable_send_timestamp
+            && existing_settings.enable_send_timestamp != required_settings.enable_send_timestamp
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSendTimestampBehavior,
+                                "{} since the service has an incompatible send timestamp behavior.",
+                                msg);
+        }
+
+        if self.verify_sample_lifespan
+            && existing_settings.sample_lifespan != required_settings.sample_lifespan
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSampleLifespan,
+                                "{} since the sample lifespan is {:?} but a sample lifespan of {:?} is required.",
+                                msg, existing_settings.sample_lifespan, required_settings.sample_lifespan);
+        }
+
+        if self.verify_enable_exclusive_ownership
+            && existing_settings.enable_exclusive_ownership
+                != required_settings.enable_exclusive_ownership
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleOwnershipBehavior,
+                                "{} since the service has an incompatible exclusive ownership behavior.",
+                                msg);
+        }
+
+        if self.verify_enable_notify_on_delivery
+            && existing_settings.enable_notify_on_delivery
+                != required_settings.enable_notify_on_delivery
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleNotifyOnDeliveryBehavior,
+                                "{} since the service has an incompatible notify on delivery behavior.",
+                                msg);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -613,6 +800,11 @@ impl<
                     service_tag.release_ownership();
                 }
 
+                service::internal::announce_service_change(
+                    &self.base.shared_node,
+                    service::ServiceAnnouncement::ServiceAdded(self.base.service_config.clone()),
+                );
+
                 Ok(publish_subscribe::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -708,6 +900,24 @@ impl<
     }
 
     fn open_or_create_impl(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        let has_deadline = self.base.open_or_create_deadline.is_some();
+
+        match self.open_or_create_with_retry(verifier) {
+            Err(e) if has_deadline && e.is_hanging_in_creation() => {
+                fail!(with PublishSubscribeOpenOrCreateError::Timeout,
+                    "Unable to open or create publish subscribe service since it is still being created by another instance and the timeout has passed.");
+            }
+            v => v,
+        }
+    }
+
+    fn open_or_create_with_retry(
         mut self,
         verifier: &AttributeVerifier,
     ) -> Result<
@@ -718,6 +928,13 @@ impl<
 
         let mut retry_count = 0;
         loop {
+            if 0 < retry_count && self.base.has_open_or_create_deadline_passed() {
+                fail!(from self,
+                      with PublishSubscribeOpenOrCreateError::Timeout,
+                      "{} since the service could not be opened or created within the provided timeout.",
+                      msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with PublishSubscribeOpenOrCreateError::SystemInFlux,
@@ -748,6 +965,22 @@ impl<
         }
     }
 
+    fn adjust_type_names(&mut self) {
+        if let Some(name) = self.override_payload_type_name.clone() {
+            self.config_details_mut()
+                .message_type_details
+                .payload
+                .type_name = name.as_string().clone();
+        }
+
+        if let Some(name) = self.override_user_header_type_name.clone() {
+            self.config_details_mut()
+                .message_type_details
+                .user_header
+                .type_name = name.as_string().clone();
+        }
+    }
+
     fn adjust_payload_alignment(&mut self) {
         if let Some(alignment) = self.override_alignment {
             self.config_details_mut()
@@ -801,6 +1034,7 @@ impl<
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.adjust_type_names();
         self.adjust_payload_alignment();
     }
 
@@ -815,6 +1049,22 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, Payload, UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes.
     ///
@@ -896,6 +1146,7 @@ impl<
             self.config_details_mut().message_type_details.user_header = details.clone();
         }
 
+        self.adjust_type_names();
         self.adjust_payload_alignment();
     }
 
@@ -910,6 +1161,22 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [Payload], UserHeader>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
     /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
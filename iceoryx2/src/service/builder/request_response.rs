@@ -12,15 +12,20 @@
 
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use core::time::Duration;
 
 use crate::prelude::{AttributeSpecifier, AttributeVerifier};
 use crate::service::builder::OpenDynamicStorageFailure;
 use crate::service::dynamic_config::request_response::DynamicConfigSettings;
 use crate::service::port_factory::request_response;
-use crate::service::static_config::message_type_details::TypeDetail;
+use crate::service::service_name::ServiceNameError;
+use crate::service::static_config::message_type_details::{
+    TypeCompatibility, TypeDetail, TypeName,
+};
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::{self, header, static_config};
 use crate::service::{builder, dynamic_config, Service};
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
@@ -42,6 +47,9 @@ pub enum RequestResponseOpenError {
     DoesNotSupportRequestedAmountOfClientRequestLoans,
     /// The [`Service`] has a lower maximum amount of [`ActiveRequest`](crate::active_request::ActiveRequest)s than requested.
     DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
+    /// The [`Service`] has a lower request queue depth per [`Client`](crate::port::client::Client)
+    /// than requested.
+    DoesNotSupportRequestedRequestQueueDepthPerClient,
     /// The [`Service`] has a lower maximum response buffer size than requested.
     DoesNotSupportRequestedResponseBufferSize,
     /// The [`Service`] has a lower maximum number of servers than requested.
@@ -71,6 +79,14 @@ pub enum RequestResponseOpenError {
     IncompatibleOverflowBehaviorForResponses,
     /// The [`Service`] does not support the required behavior for fire and forget requests.
     IncompatibleBehaviorForFireAndForgetRequests,
+    /// The [`Service`] does not support the required behavior for multiple responses per request.
+    IncompatibleBehaviorForMultiResponse,
+    /// The [`Service`] does not support the required notify on delivery behavior.
+    IncompatibleNotifyOnDeliveryBehavior,
+    /// The [`Service`]s response deadline is not equal to the required response deadline.
+    IncompatibleResponseDeadline,
+    /// The [`Service`]s credit flow control is not equal to the required credit flow control.
+    IncompatibleCreditFlowControl,
     /// The process has not enough permissions to open the [`Service`].
     InsufficientPermissions,
     /// Errors that indicate either an implementation issue or a wrongly configured system.
@@ -174,6 +190,12 @@ pub enum RequestResponseOpenOrCreateError {
     /// Can occur when another process creates and removes the same [`Service`] repeatedly with a
     /// high frequency.
     SystemInFlux,
+    /// The existing [`Service`] was opened with [`Builder::open_or_create_or_fail()`] but its
+    /// parameters differ from the parameters of the [`Builder`].
+    ConfigurationMismatch,
+    /// The [`Service`] could neither be opened nor created within the timeout provided to
+    /// `open_or_create_with_timeout()` since another instance is still creating it.
+    Timeout,
 }
 
 impl From<ServiceAvailabilityState> for RequestResponseOpenOrCreateError {
@@ -202,6 +224,38 @@ impl core::fmt::Display for RequestResponseOpenOrCreateError {
 
 impl core::error::Error for RequestResponseOpenOrCreateError {}
 
+impl RequestResponseOpenOrCreateError {
+    fn is_hanging_in_creation(&self) -> bool {
+        matches!(
+            self,
+            Self::RequestResponseOpenError(RequestResponseOpenError::HangsInCreation)
+                | Self::RequestResponseCreateError(RequestResponseCreateError::HangsInCreation)
+        )
+    }
+}
+
+impl RequestResponseOpenError {
+    fn is_configuration_mismatch(&self) -> bool {
+        matches!(
+            self,
+            RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans
+                | RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient
+                | RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient
+                | RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize
+                | RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers
+                | RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients
+                | RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes
+                | RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse
+                | RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests
+                | RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses
+                | RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests
+                | RequestResponseOpenError::IncompatibleBehaviorForMultiResponse
+                | RequestResponseOpenError::IncompatibleResponseDeadline
+                | RequestResponseOpenError::IncompatibleCreditFlowControl
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 enum ServiceAvailabilityState {
     ServiceState(ServiceState),
@@ -229,9 +283,14 @@ pub struct Builder<
     override_response_payload_type: Option<TypeDetail>,
     override_request_header_type: Option<TypeDetail>,
     override_response_header_type: Option<TypeDetail>,
+    override_request_payload_type_name: Option<TypeName>,
+    override_response_payload_type_name: Option<TypeName>,
+    override_request_header_type_name: Option<TypeName>,
+    override_response_header_type_name: Option<TypeName>,
     verify_enable_safe_overflow_for_requests: bool,
     verify_enable_safe_overflow_for_responses: bool,
     verify_max_active_requests_per_client: bool,
+    verify_max_request_queue_depth_per_client: bool,
     verify_max_loaned_requests: bool,
     verify_max_response_buffer_size: bool,
     verify_max_servers: bool,
@@ -239,6 +298,13 @@ pub struct Builder<
     verify_max_nodes: bool,
     verify_max_borrowed_responses_per_pending_response: bool,
     verify_enable_fire_and_forget_requests: bool,
+    verify_enable_multi_response: bool,
+    verify_enable_notify_on_delivery: bool,
+    verify_response_deadline: bool,
+    verify_credit_flow_control: bool,
+    verify_exact_limits: bool,
+    verify_all_parameters: bool,
+    enable_type_evolution: bool,
 
     _request_payload: PhantomData<RequestPayload>,
     _request_header: PhantomData<RequestHeader>,
@@ -264,10 +330,16 @@ impl<
             override_response_payload_type: self.override_response_payload_type.clone(),
             override_request_header_type: self.override_request_header_type.clone(),
             override_response_header_type: self.override_response_header_type.clone(),
+            override_request_payload_type_name: self.override_request_payload_type_name.clone(),
+            override_response_payload_type_name: self.override_response_payload_type_name.clone(),
+            override_request_header_type_name: self.override_request_header_type_name.clone(),
+            override_response_header_type_name: self.override_response_header_type_name.clone(),
             verify_enable_safe_overflow_for_requests: self.verify_enable_safe_overflow_for_requests,
             verify_enable_safe_overflow_for_responses: self
                 .verify_enable_safe_overflow_for_responses,
             verify_max_active_requests_per_client: self.verify_max_active_requests_per_client,
+            verify_max_request_queue_depth_per_client: self
+                .verify_max_request_queue_depth_per_client,
             verify_max_loaned_requests: self.verify_max_loaned_requests,
             verify_max_response_buffer_size: self.verify_max_response_buffer_size,
             verify_max_servers: self.verify_max_servers,
@@ -276,6 +348,13 @@ impl<
             verify_max_borrowed_responses_per_pending_response: self
                 .verify_max_borrowed_responses_per_pending_response,
             verify_enable_fire_and_forget_requests: self.verify_enable_fire_and_forget_requests,
+            verify_enable_multi_response: self.verify_enable_multi_response,
+            verify_enable_notify_on_delivery: self.verify_enable_notify_on_delivery,
+            verify_response_deadline: self.verify_response_deadline,
+            verify_credit_flow_control: self.verify_credit_flow_control,
+            verify_exact_limits: self.verify_exact_limits,
+            verify_all_parameters: self.verify_all_parameters,
+            enable_type_evolution: self.enable_type_evolution,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -301,16 +380,28 @@ impl<
             override_request_payload_type: None,
             override_response_header_type: None,
             override_response_payload_type: None,
+            override_request_payload_type_name: None,
+            override_response_payload_type_name: None,
+            override_request_header_type_name: None,
+            override_response_header_type_name: None,
             verify_enable_safe_overflow_for_requests: false,
             verify_enable_safe_overflow_for_responses: false,
             verify_max_loaned_requests: false,
             verify_max_active_requests_per_client: false,
+            verify_max_request_queue_depth_per_client: false,
             verify_max_response_buffer_size: false,
             verify_max_servers: false,
             verify_max_clients: false,
             verify_max_nodes: false,
             verify_max_borrowed_responses_per_pending_response: false,
             verify_enable_fire_and_forget_requests: false,
+            verify_enable_multi_response: false,
+            verify_enable_notify_on_delivery: false,
+            verify_response_deadline: false,
+            verify_credit_flow_control: false,
+            verify_exact_limits: false,
+            verify_all_parameters: false,
+            enable_type_evolution: false,
             _request_payload: PhantomData,
             _request_header: PhantomData,
             _response_payload: PhantomData,
@@ -405,6 +496,31 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created, defines if a [`Server`](crate::port::server::Server) can
+    /// send a stream of arbitrary many [`Response`](crate::response::Response)s per
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest) or exactly one. When disabled,
+    /// sending a further [`Response`](crate::response::Response) fails with
+    /// [`SendError::MultiResponseDisabled`](crate::port::SendError::MultiResponseDisabled).
+    /// If an existing [`Service`] is opened it requires the service to have the defined multi
+    /// response behavior.
+    pub fn enable_multi_response(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_multi_response = value;
+        self.verify_enable_multi_response = true;
+        self
+    }
+
+    /// If the [`Service`] is created, defines if the [`Server`](crate::port::server::Server) is
+    /// notified when a request and the
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) when a
+    /// [`Response`](crate::response::Response) is delivered. When enabled, both can be attached
+    /// directly to a [`WaitSet`](crate::waitset::WaitSet) without a separate event service. If
+    /// an existing [`Service`] is opened it requires the service to have the same setting.
+    pub fn enable_notify_on_delivery(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notify_on_delivery = value;
+        self.verify_enable_notify_on_delivery = true;
+        self
+    }
+
     /// Defines how many active requests a [`Server`](crate::port::server::Server) can hold in
     /// parallel per [`Client`](crate::port::client::Client). The objects are used to send answers to a request that was received earlier
     /// from a [`Client`](crate::port::client::Client)
@@ -414,6 +530,29 @@ impl<
         self
     }
 
+    /// Defines the depth of the queue in which the requests of every
+    /// [`Client`](crate::port::client::Client) wait until the
+    /// [`Server`](crate::port::server::Server) receives them. When not set, it is equal to
+    /// [`Builder::max_active_requests_per_client()`].
+    ///
+    /// [`Builder::max_active_requests_per_client()`] limits all requests of a
+    /// [`Client`](crate::port::client::Client) that are not yet completed, the queued ones as
+    /// well as the ones the [`Server`](crate::port::server::Server) has already received.
+    /// Therefore, the depth is limited to [`Builder::max_active_requests_per_client()`]. A
+    /// smaller depth reduces the memory of every [`Client`](crate::port::client::Client) data
+    /// segment, which contains `max_servers * (max_active_requests_per_client +
+    /// max_request_queue_depth_per_client) + max_loaned_requests` requests. When the queue is
+    /// full, the request is handled according to the safe overflow setting for requests and the
+    /// [`UnableToDeliverStrategy`](crate::prelude::UnableToDeliverStrategy) of the
+    /// [`Client`](crate::port::client::Client).
+    ///
+    /// If an existing [`Service`] is opened it defines the minimum required.
+    pub fn max_request_queue_depth_per_client(mut self, value: usize) -> Self {
+        self.config_details_mut().max_request_queue_depth_per_client = Some(value);
+        self.verify_max_request_queue_depth_per_client = true;
+        self
+    }
+
     /// Defines how many requests the [`Client`](crate::port::client::Client) can loan in parallel.
     pub fn max_loaned_requests(mut self, value: usize) -> Self {
         self.config_details_mut().max_loaned_requests = value;
@@ -457,6 +596,119 @@ impl<
         self
     }
 
+    /// If the [`Service`] is created it defines the maximum slice length of a request payload.
+    /// [`Client::loan_slice()`](crate::port::client::Client::loan_slice()) fails with
+    /// [`LoanError::PayloadTooLarge`](crate::port::LoanError::PayloadTooLarge) when a larger
+    /// slice is requested. If an existing [`Service`] is opened, the limit of the existing
+    /// [`Service`] applies.
+    pub fn max_request_payload_size(mut self, value: usize) -> Self {
+        self.config_details_mut().max_request_payload_size = Some(value);
+        self
+    }
+
+    /// If the [`Service`] is created it defines the maximum slice length of a response payload.
+    /// [`ActiveRequest::loan_slice()`](crate::active_request::ActiveRequest::loan_slice())
+    /// fails with [`LoanError::PayloadTooLarge`](crate::port::LoanError::PayloadTooLarge) when
+    /// a larger slice is requested. If an existing [`Service`] is opened, the limit of the
+    /// existing [`Service`] applies.
+    pub fn max_response_payload_size(mut self, value: usize) -> Self {
+        self.config_details_mut().max_response_payload_size = Some(value);
+        self
+    }
+
+    /// Overrides the [`Config`](crate::config::Config)s service creation timeout for this
+    /// builder only. It defines how long it is waited for a [`Service`] that is still being
+    /// created by another process before opening it fails with
+    /// [`RequestResponseOpenError::HangsInCreation`].
+    pub fn creation_timeout(mut self, value: Duration) -> Self {
+        self.base.creation_timeout = Some(value);
+        self
+    }
+
+    /// Prepends the prefix to the [`ServiceName`](crate::service::service_name::ServiceName)
+    /// of the builder to group [`Service`]s into name-spaces. With the prefix `"robot/arm/"`
+    /// the builder for `"joint_state"` creates or opens the [`Service`] with the name
+    /// `"robot/arm/joint_state"`. The per-service [`Config`](crate::config::Config) overrides
+    /// are resolved with the name that was provided to the
+    /// [`Node::service_builder()`](crate::node::Node::service_builder()).
+    ///
+    /// Fails with [`ServiceNameError`] when the prefixed name is not a valid
+    /// [`ServiceName`](crate::service::service_name::ServiceName).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node
+    ///     .service_builder(&"joint_state".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .with_topic_prefix("robot/arm/")?
+    ///     .open_or_create()?;
+    ///
+    /// assert_eq!(service.name().as_str(), "robot/arm/joint_state");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_topic_prefix(mut self, prefix: &str) -> Result<Self, ServiceNameError> {
+        fail!(from self, when self.base.prepend_to_service_name(prefix),
+            "Unable to prepend the topic prefix \"{}\" to the service name.", prefix);
+        Ok(self)
+    }
+
+    /// Enables the response deadline property of the service. A
+    /// [`Server`](crate::port::server::Server) must send the first
+    /// [`Response`](crate::response::Response) to a request within the provided `deadline`,
+    /// otherwise the [`PendingResponse`](crate::pending_response::PendingResponse) has missed
+    /// its deadline.
+    pub fn response_deadline(mut self, deadline: Duration) -> Self {
+        self.config_details_mut().response_deadline = Some(deadline);
+        self.verify_response_deadline = true;
+        self
+    }
+
+    /// Disables the response deadline property of the service.
+    /// [`Server`](crate::port::server::Server)s can respond to requests at any time.
+    pub fn disable_response_deadline(mut self) -> Self {
+        self.config_details_mut().response_deadline = None;
+        self.verify_response_deadline = true;
+        self
+    }
+
+    /// Enables credit based flow control to protect the [`Server`](crate::port::server::Server)s
+    /// from [`Client`](crate::port::client::Client)s that flood their request queues. Every
+    /// [`Server`](crate::port::server::Server) grants every
+    /// [`Client`](crate::port::client::Client) `credits_per_client` credits and every request
+    /// it receives consumes one of them. The credit is returned when the
+    /// [`Server`](crate::port::server::Server) acknowledges the request, either by sending the
+    /// first [`Response`](crate::response::Response) or by dropping the
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest). With multiple
+    /// [`Server`](crate::port::server::Server)s the [`Client`](crate::port::client::Client) has
+    /// as many credits left as the [`Server`](crate::port::server::Server) with the most
+    /// unacknowledged requests grants, so the slowest one determines the pace. Requests that
+    /// will never be acknowledged return their credit as well, namely requests that are
+    /// overwritten in the request queue by safe overflow and requests that are still queued
+    /// when the [`Server`](crate::port::server::Server) is removed. When no credits are left,
+    /// sending a request fails with
+    /// [`RequestSendError::BackPressure`](crate::port::client::RequestSendError::BackPressure).
+    /// If an existing [`Service`] is opened it requires the service to have the same setting.
+    pub fn enable_credit_flow_control(mut self, credits_per_client: u32) -> Self {
+        self.config_details_mut().credits_per_client = Some(credits_per_client);
+        self.verify_credit_flow_control = true;
+        self
+    }
+
+    /// Disables the credit based flow control of the service.
+    /// [`Client`](crate::port::client::Client)s can send requests until the request queue of
+    /// the [`Server`](crate::port::server::Server) is full.
+    pub fn disable_credit_flow_control(mut self) -> Self {
+        self.config_details_mut().credits_per_client = None;
+        self.verify_credit_flow_control = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`Response`](crate::response::Response)s shall
     /// be able to be borrowed in parallel per [`PendingResponse`](crate::pending_response::PendingResponse). If an existing [`Service`] is opened it defines how many
     /// borrows must be at least supported.
@@ -467,6 +719,70 @@ impl<
         self
     }
 
+    /// Overrides the type name of the request payload that is stored in the static
+    /// configuration, e.g. with a language neutral name like `com.acme.Request`. Without an
+    /// override the Rust type name is used. If an existing [`Service`] is opened, the name is
+    /// used to verify the request payload type.
+    pub fn request_payload_type_name(mut self, value: &TypeName) -> Self {
+        self.override_request_payload_type_name = Some(value.clone());
+        self
+    }
+
+    /// Overrides the type name of the response payload that is stored in the static
+    /// configuration. Without an override the Rust type name is used. If an existing
+    /// [`Service`] is opened, the name is used to verify the response payload type.
+    pub fn response_payload_type_name(mut self, value: &TypeName) -> Self {
+        self.override_response_payload_type_name = Some(value.clone());
+        self
+    }
+
+    /// Overrides the type name of the request user header that is stored in the static
+    /// configuration. Without an override the Rust type name is used. If an existing
+    /// [`Service`] is opened, the name is used to verify the request user header type.
+    pub fn request_user_header_type_name(mut self, value: &TypeName) -> Self {
+        self.override_request_header_type_name = Some(value.clone());
+        self
+    }
+
+    /// Overrides the type name of the response user header that is stored in the static
+    /// configuration. Without an override the Rust type name is used. If an existing
+    /// [`Service`] is opened, the name is used to verify the response user header type.
+    pub fn response_user_header_type_name(mut self, value: &TypeName) -> Self {
+        self.override_response_header_type_name = Some(value.clone());
+        self
+    }
+
+    /// Defines how the request and response types are matched when an existing [`Service`] is
+    /// opened. By default, [`TypeCompatibility::Strict`] is used. If the [`Service`] is created,
+    /// the mode is stored in the static configuration. If an existing [`Service`] is opened, it
+    /// must have been created with the same mode.
+    pub fn type_compatibility(mut self, value: TypeCompatibility) -> Self {
+        self.config_details_mut().type_compatibility = value;
+        self
+    }
+
+    /// Relaxes the payload type check when an existing [`Service`] is opened. By default, the
+    /// request and response payload size must be identical. With type evolution enabled, the
+    /// [`Service`]s payload may be larger or smaller than the local payload, as it is the case
+    /// when the [`Service`] was created with a newer or an older version of the type that
+    /// differs in its trailing fields. Every participant sends payloads of its local size. When
+    /// a received payload is smaller than the local payload type, it is copied and the trailing
+    /// fields the sender does not know about are zeroed. The static configuration of the opened
+    /// [`Service`] contains the local payload sizes.
+    ///
+    /// The type name, headers and alignments must still be compatible. Slice payloads always
+    /// require an identical element size.
+    pub fn enable_type_evolution(mut self, value: bool) -> Self {
+        #[cfg(debug_assertions)]
+        if value {
+            warn!(from self,
+                "Type evolution is enabled. Payload types with identical names but different sizes are considered compatible.");
+        }
+
+        self.enable_type_evolution = value;
+        self
+    }
+
     fn adjust_configuration_to_meaningful_values(&mut self) {
         let origin = format!("{self:?}");
         let settings = self.base.service_config.request_response_mut();
@@ -483,6 +799,22 @@ impl<
             settings.max_active_requests_per_client = 1;
         }
 
+        match settings.max_request_queue_depth_per_client {
+            Some(0) => {
+                warn!(from origin,
+                    "Setting the request queue depth per client to 0 is not supported. Adjust it to 1, the smallest supported value.");
+                settings.max_request_queue_depth_per_client = Some(1);
+            }
+            Some(depth) if depth > settings.max_active_requests_per_client => {
+                warn!(from origin,
+                    "The request queue depth per client of {} exceeds the maximum number of active requests per client. Adjust it to {}, the largest supported value.",
+                    depth, settings.max_active_requests_per_client);
+                settings.max_request_queue_depth_per_client =
+                    Some(settings.max_active_requests_per_client);
+            }
+            _ => (),
+        }
+
         if settings.max_servers == 0 {
             warn!(from origin,
                 "Setting the maximum number of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
@@ -512,6 +844,12 @@ impl<
                 "Setting the maximum loaned requests for clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_loaned_requests = 1;
         }
+
+        if settings.credits_per_client == Some(0) {
+            warn!(from origin,
+                "Setting the credits per client to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.credits_per_client = Some(1);
+        }
     }
 
     fn verify_service_configuration(
@@ -538,7 +876,13 @@ impl<
             }
         };
 
-        if self.verify_enable_safe_overflow_for_requests
+        let limit_requirement = if self.verify_exact_limits || self.verify_all_parameters {
+            "exactly"
+        } else {
+            "at least"
+        };
+
+        if (self.verify_all_parameters || self.verify_enable_safe_overflow_for_requests)
             && existing_configuration.enable_safe_overflow_for_requests
                 != required_configuration.enable_safe_overflow_for_requests
         {
@@ -547,7 +891,7 @@ impl<
                 msg);
         }
 
-        if self.verify_enable_safe_overflow_for_responses
+        if (self.verify_all_parameters || self.verify_enable_safe_overflow_for_responses)
             && existing_configuration.enable_safe_overflow_for_responses
                 != required_configuration.enable_safe_overflow_for_responses
         {
@@ -556,7 +900,7 @@ impl<
                 msg);
         }
 
-        if self.verify_enable_fire_and_forget_requests
+        if (self.verify_all_parameters || self.verify_enable_fire_and_forget_requests)
             && existing_configuration.enable_fire_and_forget_requests
                 != required_configuration.enable_fire_and_forget_requests
         {
@@ -565,69 +909,140 @@ impl<
                 msg);
         }
 
-        if self.verify_max_active_requests_per_client
-            && existing_configuration.max_active_requests_per_client
-                < required_configuration.max_active_requests_per_client
+        if (self.verify_all_parameters || self.verify_enable_multi_response)
+            && existing_configuration.enable_multi_response
+                != required_configuration.enable_multi_response
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleBehaviorForMultiResponse,
+                "{} since the service has an incompatible behavior for multiple responses per request.",
+                msg);
+        }
+
+        if (self.verify_all_parameters || self.verify_enable_notify_on_delivery)
+            && existing_configuration.enable_notify_on_delivery
+                != required_configuration.enable_notify_on_delivery
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleNotifyOnDeliveryBehavior,
+                "{} since the service has an incompatible notify on delivery behavior.",
+                msg);
+        }
+
+        if (self.verify_all_parameters || self.verify_response_deadline)
+            && existing_configuration.response_deadline != required_configuration.response_deadline
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleResponseDeadline,
+                "{} since the response deadline is {:?} but a response deadline of {:?} is required.",
+                msg, existing_configuration.response_deadline, required_configuration.response_deadline);
+        }
+
+        if (self.verify_all_parameters || self.verify_credit_flow_control)
+            && existing_configuration.credits_per_client
+                != required_configuration.credits_per_client
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleCreditFlowControl,
+                "{} since the service has {:?} credits per client but {:?} are required.",
+                msg, existing_configuration.credits_per_client, required_configuration.credits_per_client);
+        }
+
+        if (self.verify_all_parameters || self.verify_max_active_requests_per_client)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_active_requests_per_client,
+                required_configuration.max_active_requests_per_client,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient,
-                "{} since the service supports only {} active requests per client but {} are required.",
-                msg, existing_configuration.max_active_requests_per_client, required_configuration.max_active_requests_per_client);
+                "{} since the service supports {} active requests per client but {} {} are required.",
+                msg, existing_configuration.max_active_requests_per_client, limit_requirement, required_configuration.max_active_requests_per_client);
         }
 
-        if self.verify_max_loaned_requests
-            && existing_configuration.max_loaned_requests
-                < required_configuration.max_loaned_requests
+        if (self.verify_all_parameters || self.verify_max_request_queue_depth_per_client)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_request_queue_depth_per_client(),
+                required_configuration.max_request_queue_depth_per_client(),
+            )
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient,
+                "{} since the service supports a request queue depth of {} per client but {} {} is required.",
+                msg, existing_configuration.max_request_queue_depth_per_client(), limit_requirement, required_configuration.max_request_queue_depth_per_client());
+        }
+
+        if (self.verify_all_parameters || self.verify_max_loaned_requests)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_loaned_requests,
+                required_configuration.max_loaned_requests,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans,
-                "{} since the service supports only {} loaned requests per client but {} are required.",
-                msg, existing_configuration.max_loaned_requests, required_configuration.max_loaned_requests);
+                "{} since the service supports {} loaned requests per client but {} {} are required.",
+                msg, existing_configuration.max_loaned_requests, limit_requirement, required_configuration.max_loaned_requests);
         }
 
-        if self.verify_max_borrowed_responses_per_pending_response
-            && existing_configuration.max_borrowed_responses_per_pending_response
-                < required_configuration.max_borrowed_responses_per_pending_response
+        if (self.verify_all_parameters || self.verify_max_borrowed_responses_per_pending_response)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_borrowed_responses_per_pending_response,
+                required_configuration.max_borrowed_responses_per_pending_response,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse,
-                "{} since the service supports only {} borrowed responses per pending response but {} are required.",
-                msg, existing_configuration.max_borrowed_responses_per_pending_response, required_configuration.max_borrowed_responses_per_pending_response);
+                "{} since the service supports {} borrowed responses per pending response but {} {} are required.",
+                msg, existing_configuration.max_borrowed_responses_per_pending_response, limit_requirement, required_configuration.max_borrowed_responses_per_pending_response);
         }
 
-        if self.verify_max_response_buffer_size
-            && existing_configuration.max_response_buffer_size
-                < required_configuration.max_response_buffer_size
+        if (self.verify_all_parameters || self.verify_max_response_buffer_size)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_response_buffer_size,
+                required_configuration.max_response_buffer_size,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize,
-                "{} since the service supports a maximum response buffer size of {} but a size of {} is required.",
-                msg, existing_configuration.max_response_buffer_size, required_configuration.max_response_buffer_size);
+                "{} since the service supports a maximum response buffer size of {} but a size of {} {} is required.",
+                msg, existing_configuration.max_response_buffer_size, limit_requirement, required_configuration.max_response_buffer_size);
         }
 
-        if self.verify_max_servers
-            && existing_configuration.max_servers < required_configuration.max_servers
+        if (self.verify_all_parameters || self.verify_max_servers)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_servers,
+                required_configuration.max_servers,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
-                "{} since the service supports at most {} servers but {} are required.",
-                msg, existing_configuration.max_servers, required_configuration.max_servers);
+                "{} since the service supports {} servers but {} {} are required.",
+                msg, existing_configuration.max_servers, limit_requirement, required_configuration.max_servers);
         }
 
-        if self.verify_max_clients
-            && existing_configuration.max_clients < required_configuration.max_clients
+        if (self.verify_all_parameters || self.verify_max_clients)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_clients,
+                required_configuration.max_clients,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
-                "{} since the service supports at most {} clients but {} are required.",
-                msg, existing_configuration.max_clients, required_configuration.max_clients);
+                "{} since the service supports {} clients but {} {} are required.",
+                msg, existing_configuration.max_clients, limit_requirement, required_configuration.max_clients);
         }
 
-        if self.verify_max_nodes
-            && existing_configuration.max_nodes < required_configuration.max_nodes
+        if (self.verify_all_parameters || self.verify_max_nodes)
+            && !self.is_limit_satisfied(
+                existing_configuration.max_nodes,
+                required_configuration.max_nodes,
+            )
         {
             fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes,
-                "{} since the service supports at most {} nodes but {} are required.",
-                msg, existing_configuration.max_nodes, required_configuration.max_nodes);
+                "{} since the service supports {} nodes but {} {} are required.",
+                msg, existing_configuration.max_nodes, limit_requirement, required_configuration.max_nodes);
         }
 
         Ok(existing_configuration.clone())
     }
 
+    fn is_limit_satisfied(&self, existing_limit: usize, required_limit: usize) -> bool {
+        if self.verify_exact_limits || self.verify_all_parameters {
+            existing_limit == required_limit
+        } else {
+            existing_limit >= required_limit
+        }
+    }
+
     fn is_service_available(
         &mut self,
         error_msg: &str,
@@ -637,26 +1052,43 @@ impl<
     > {
         match self.base.is_service_available(error_msg) {
             Ok(Some((config, storage))) => {
-                if !self
-                    .config_details()
-                    .request_message_type_details
-                    .is_compatible_to(&config.request_response().request_message_type_details)
-                {
+                let type_compatibility = self.config_details().type_compatibility;
+                if config.request_response().type_compatibility != type_compatibility {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
-                        "{} since the services uses the request type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.request_response().request_message_type_details,
-                        self.config_details().request_message_type_details);
+                        "{} since the service was created with the type compatibility {:?} but {:?} is required.",
+                        error_msg, config.request_response().type_compatibility, type_compatibility);
                 }
 
-                if !self
-                    .config_details()
-                    .response_message_type_details
-                    .is_compatible_to(&config.request_response().response_message_type_details)
-                {
+                let is_compatible = |lhs: &MessageTypeDetails, rhs: &MessageTypeDetails| {
+                    if self.enable_type_evolution {
+                        lhs.is_evolution_compatible_to(rhs, type_compatibility)
+                    } else {
+                        lhs.is_compatible_to(rhs, type_compatibility)
+                    }
+                };
+
+                if !is_compatible(
+                    &self.config_details().request_message_type_details,
+                    &config.request_response().request_message_type_details,
+                ) {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleRequestType,
+                        "{} since the service uses a request type which is not compatible to the requested type with the type compatibility {:?} the service was created with: {}.",
+                        error_msg, type_compatibility,
+                        self.config_details().request_message_type_details.mismatch_description(
+                            &config.request_response().request_message_type_details,
+                            type_compatibility, self.enable_type_evolution));
+                }
+
+                if !is_compatible(
+                    &self.config_details().response_message_type_details,
+                    &config.request_response().response_message_type_details,
+                ) {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleResponseType,
-                        "{} since the services uses the response type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.request_response().response_message_type_details,
-                        self.config_details().response_message_type_details);
+                        "{} since the service uses a response type which is not compatible to the requested type with the type compatibility {:?} the service was created with: {}.",
+                        error_msg, type_compatibility,
+                        self.config_details().response_message_type_details.mismatch_description(
+                            &config.request_response().response_message_type_details,
+                            type_compatibility, self.enable_type_evolution));
                 }
 
                 Ok(Some((config, storage)))
@@ -719,6 +1151,7 @@ impl<
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_servers: request_response_config.max_servers,
                     number_of_clients: request_response_config.max_clients,
+                    credits_per_client: request_response_config.credits_per_client.unwrap_or(0),
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -763,6 +1196,11 @@ impl<
                     service_tag.release_ownership();
                 }
 
+                service::internal::announce_service_change(
+                    &self.base.shared_node,
+                    service::ServiceAnnouncement::ServiceAdded(self.base.service_config.clone()),
+                );
+
                 Ok(request_response::PortFactory::new(
                     ServiceType::__internal_from_state(service::ServiceState::new(
                         self.base.service_config.clone(),
@@ -799,10 +1237,31 @@ impl<
                         "{} since the service does not exist.",
                         msg);
                 }
-                Some((static_config, static_storage)) => {
+                Some((mut static_config, static_storage)) => {
+                    let local_request_payload_size = self
+                        .config_details()
+                        .request_message_type_details
+                        .payload
+                        .size;
+                    let local_response_payload_size = self
+                        .config_details()
+                        .response_message_type_details
+                        .payload
+                        .size;
+
                     let request_response_static_config =
                         self.verify_service_configuration(&static_config, attributes)?;
 
+                    if self.enable_type_evolution {
+                        // the ports loan chunks that fit the local payload types, receivers
+                        // zero the trailing fields of payloads that were sent with smaller types
+                        let config = static_config.request_response_mut();
+                        config.request_message_type_details.payload.size =
+                            local_request_payload_size;
+                        config.response_message_type_details.payload.size =
+                            local_response_payload_size;
+                    }
+
                     let service_tag = self
                         .base
                         .create_node_service_tag(msg, RequestResponseOpenError::InternalFailure)?;
@@ -865,6 +1324,30 @@ impl<
     }
 
     fn open_or_create_impl(
+        self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        let has_deadline = self.base.open_or_create_deadline.is_some();
+
+        match self.open_or_create_with_retry(verifier) {
+            Err(e) if has_deadline && e.is_hanging_in_creation() => {
+                fail!(with RequestResponseOpenOrCreateError::Timeout,
+                    "Unable to open or create request response service since it is still being created by another instance and the timeout has passed.");
+            }
+            v => v,
+        }
+    }
+
+    fn open_or_create_with_retry(
         mut self,
         verifier: &AttributeVerifier,
     ) -> Result<
@@ -881,6 +1364,13 @@ impl<
 
         let mut retry_count = 0;
         loop {
+            if 0 < retry_count && self.base.has_open_or_create_deadline_passed() {
+                fail!(from self,
+                      with RequestResponseOpenOrCreateError::Timeout,
+                      "{} since the service could not be opened or created within the provided timeout.",
+                      msg);
+            }
+
             if RETRY_LIMIT < retry_count {
                 fail!(from self,
                       with RequestResponseOpenOrCreateError::SystemInFlux,
@@ -893,6 +1383,11 @@ impl<
                 match self.open_impl(verifier) {
                     Ok(factory) => return Ok(factory),
                     Err(RequestResponseOpenError::DoesNotExist) => continue,
+                    Err(e) if self.verify_all_parameters && e.is_configuration_mismatch() => {
+                        fail!(from self, with RequestResponseOpenOrCreateError::ConfigurationMismatch,
+                            "{} since the parameters of the existing service differ from the required parameters ({:?}).",
+                            msg, e);
+                    }
                     Err(e) => return Err(e.into()),
                 }
             } else {
@@ -934,6 +1429,34 @@ impl<
                 .user_header = details.clone();
         }
 
+        if let Some(name) = self.override_request_payload_type_name.clone() {
+            self.config_details_mut()
+                .request_message_type_details
+                .payload
+                .type_name = name.as_string().clone();
+        }
+
+        if let Some(name) = self.override_request_header_type_name.clone() {
+            self.config_details_mut()
+                .request_message_type_details
+                .user_header
+                .type_name = name.as_string().clone();
+        }
+
+        if let Some(name) = self.override_response_payload_type_name.clone() {
+            self.config_details_mut()
+                .response_message_type_details
+                .payload
+                .type_name = name.as_string().clone();
+        }
+
+        if let Some(name) = self.override_response_header_type_name.clone() {
+            self.config_details_mut()
+                .response_message_type_details
+                .user_header
+                .type_name = name.as_string().clone();
+        }
+
         if let Some(alignment) = self.override_request_alignment {
             self.config_details_mut()
                 .request_message_type_details
@@ -1001,6 +1524,28 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes.
     ///
@@ -1024,6 +1569,49 @@ impl<
         self.open_or_create_impl(verifier)
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Builder::open_or_create()`], an existing [`Service`] is only
+    /// opened when all of its parameters, including the ones taken from the
+    /// [`Config`](crate::config::Config) defaults, are identical to the parameters of the
+    /// [`Builder`]. Otherwise [`RequestResponseOpenOrCreateError::ConfigurationMismatch`] is
+    /// returned. It allows to detect configuration drifts between processes.
+    pub fn open_or_create_or_fail(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_or_fail_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created with the required attributes. Like [`Builder::open_or_create_or_fail()`], an
+    /// existing [`Service`] is only opened when all of its parameters are identical to the
+    /// parameters of the [`Builder`].
+    pub fn open_or_create_or_fail_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.verify_all_parameters = true;
+        self.adjust_configuration_to_meaningful_values();
+        self.open_or_create_with_attributes(verifier)
+    }
+
     /// Opens an existing [`Service`].
     pub fn open(
         self,
@@ -1059,6 +1647,44 @@ impl<
         self.open_impl(verifier)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but requires that all explicitly
+    /// defined limits, like [`Builder::max_clients()`], match the limits of the [`Service`]
+    /// exactly. [`Builder::open()`] treats them as minimum requirements.
+    pub fn open_strict(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_strict_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements like
+    /// [`Builder::open_with_attributes()`] but requires that all explicitly defined limits
+    /// match the limits of the [`Service`] exactly.
+    pub fn open_strict_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.verify_exact_limits = true;
+        self.open_with_attributes(verifier)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -1135,6 +1761,28 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes.
     ///
@@ -1158,6 +1806,49 @@ impl<
         self.open_or_create_impl(verifier)
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Builder::open_or_create()`], an existing [`Service`] is only
+    /// opened when all of its parameters, including the ones taken from the
+    /// [`Config`](crate::config::Config) defaults, are identical to the parameters of the
+    /// [`Builder`]. Otherwise [`RequestResponseOpenOrCreateError::ConfigurationMismatch`] is
+    /// returned. It allows to detect configuration drifts between processes.
+    pub fn open_or_create_or_fail(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_or_fail_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created with the required attributes. Like [`Builder::open_or_create_or_fail()`], an
+    /// existing [`Service`] is only opened when all of its parameters are identical to the
+    /// parameters of the [`Builder`].
+    pub fn open_or_create_or_fail_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.verify_all_parameters = true;
+        self.adjust_configuration_to_meaningful_values();
+        self.open_or_create_with_attributes(verifier)
+    }
+
     /// Opens an existing [`Service`].
     pub fn open(
         self,
@@ -1193,6 +1884,44 @@ impl<
         self.open_impl(verifier)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but requires that all explicitly
+    /// defined limits, like [`Builder::max_clients()`], match the limits of the [`Service`]
+    /// exactly. [`Builder::open()`] treats them as minimum requirements.
+    pub fn open_strict(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_strict_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements like
+    /// [`Builder::open_with_attributes()`] but requires that all explicitly defined limits
+    /// match the limits of the [`Service`] exactly.
+    pub fn open_strict_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.verify_exact_limits = true;
+        self.open_with_attributes(verifier)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -1270,6 +1999,29 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes.
     ///
@@ -1294,6 +2046,51 @@ impl<
         self.open_or_create_impl(verifier)
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Builder::open_or_create()`], an existing [`Service`] is only
+    /// opened when all of its parameters, including the ones taken from the
+    /// [`Config`](crate::config::Config) defaults, are identical to the parameters of the
+    /// [`Builder`]. Otherwise [`RequestResponseOpenOrCreateError::ConfigurationMismatch`] is
+    /// returned. It allows to detect configuration drifts between processes.
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn open_or_create_or_fail(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_or_fail_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created with the required attributes. Like [`Builder::open_or_create_or_fail()`], an
+    /// existing [`Service`] is only opened when all of its parameters are identical to the
+    /// parameters of the [`Builder`].
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn open_or_create_or_fail_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.verify_all_parameters = true;
+        self.adjust_configuration_to_meaningful_values();
+        self.open_or_create_with_attributes(verifier)
+    }
+
     /// Opens an existing [`Service`].
     #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
     pub fn open(
@@ -1331,6 +2128,46 @@ impl<
         self.open_impl(verifier)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but requires that all explicitly
+    /// defined limits, like [`Builder::max_clients()`], match the limits of the [`Service`]
+    /// exactly. [`Builder::open()`] treats them as minimum requirements.
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn open_strict(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_strict_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements like
+    /// [`Builder::open_with_attributes()`] but requires that all explicitly defined limits
+    /// match the limits of the [`Service`] exactly.
+    #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
+    pub fn open_strict_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            [RequestPayload],
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.verify_exact_limits = true;
+        self.open_with_attributes(verifier)
+    }
+
     /// Creates a new [`Service`].
     #[allow(clippy::type_complexity)] // type alias would require 5 generic parameters which hardly reduces complexity
     pub fn create(
@@ -1409,6 +2246,28 @@ impl<
         self.open_or_create_with_attributes(&AttributeVerifier::new())
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Self::open_or_create()`] the call is guaranteed to return
+    /// promptly: when another instance is still creating the [`Service`] and it does not
+    /// become available within the provided timeout, it fails with `Timeout` so that the
+    /// caller can retry later instead of blocking for the whole creation timeout.
+    pub fn open_or_create_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.base.set_open_or_create_timeout(timeout);
+        self.open_or_create()
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created. It defines a set of attributes.
     ///
@@ -1432,6 +2291,49 @@ impl<
         self.open_or_create_impl(verifier)
     }
 
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. In contrast to [`Builder::open_or_create()`], an existing [`Service`] is only
+    /// opened when all of its parameters, including the ones taken from the
+    /// [`Config`](crate::config::Config) defaults, are identical to the parameters of the
+    /// [`Builder`]. Otherwise [`RequestResponseOpenOrCreateError::ConfigurationMismatch`] is
+    /// returned. It allows to detect configuration drifts between processes.
+    pub fn open_or_create_or_fail(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.open_or_create_or_fail_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created with the required attributes. Like [`Builder::open_or_create_or_fail()`], an
+    /// existing [`Service`] is only opened when all of its parameters are identical to the
+    /// parameters of the [`Builder`].
+    pub fn open_or_create_or_fail_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenOrCreateError,
+    > {
+        self.verify_all_parameters = true;
+        self.adjust_configuration_to_meaningful_values();
+        self.open_or_create_with_attributes(verifier)
+    }
+
     /// Opens an existing [`Service`].
     pub fn open(
         self,
@@ -1467,6 +2369,44 @@ impl<
         self.open_impl(verifier)
     }
 
+    /// Opens an existing [`Service`] like [`Builder::open()`] but requires that all explicitly
+    /// defined limits, like [`Builder::max_clients()`], match the limits of the [`Service`]
+    /// exactly. [`Builder::open()`] treats them as minimum requirements.
+    pub fn open_strict(
+        self,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.open_strict_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements like
+    /// [`Builder::open_with_attributes()`] but requires that all explicitly defined limits
+    /// match the limits of the [`Service`] exactly.
+    pub fn open_strict_with_attributes(
+        mut self,
+        verifier: &AttributeVerifier,
+    ) -> Result<
+        request_response::PortFactory<
+            ServiceType,
+            RequestPayload,
+            RequestHeader,
+            [ResponsePayload],
+            ResponseHeader,
+        >,
+        RequestResponseOpenError,
+    > {
+        self.verify_exact_limits = true;
+        self.open_with_attributes(verifier)
+    }
+
     /// Creates a new [`Service`].
     pub fn create(
         self,
@@ -1543,4 +2483,29 @@ impl<ServiceType: Service>
         self.override_response_header_type = Some(value.clone());
         self
     }
+
+    #[doc(hidden)]
+    pub fn __internal_static_config(&self) -> &static_config::request_response::StaticConfig {
+        self.config_details()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_request_payload_type_details(&self) -> Option<&TypeDetail> {
+        self.override_request_payload_type.as_ref()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_response_payload_type_details(&self) -> Option<&TypeDetail> {
+        self.override_response_payload_type.as_ref()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_request_header_type_details(&self) -> Option<&TypeDetail> {
+        self.override_request_header_type.as_ref()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_response_header_type_details(&self) -> Option<&TypeDetail> {
+        self.override_response_header_type.as_ref()
+    }
 }
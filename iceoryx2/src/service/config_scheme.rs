@@ -10,9 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::{config, node::NodeId};
+use crate::{config, node::NodeId, service::segment_provider};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::segment_provider::SegmentProviderConfiguration;
 
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
@@ -60,19 +61,29 @@ pub(crate) fn event_config<Service: crate::service::Service>(
 pub(crate) fn data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
-    <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
+    let config = <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(global_config.global.root_path());
+
+    match segment_provider::registered_segment_provider(global_config) {
+        Some(provider) => config.segment_provider(provider),
+        None => config,
+    }
 }
 
 pub(crate) fn resizable_data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration {
-    <<Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration>::default()
+    let config = <<Service::ResizableSharedMemory as NamedConceptMgmt>::Configuration>::default()
         .prefix(&global_config.global.prefix)
         .suffix(&global_config.global.service.data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+        .path_hint(global_config.global.root_path());
+
+    match segment_provider::registered_segment_provider(global_config) {
+        Some(provider) => config.segment_provider(provider),
+        None => config,
+    }
 }
 
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
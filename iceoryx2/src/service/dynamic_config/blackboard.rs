@@ -10,12 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::sync::atomic::Ordering;
+
 use crate::node::NodeId;
 use crate::port::port_identifiers::{UniquePortId, UniqueReaderId};
+use crate::service::static_config::message_type_details::TypeDetail;
 use iceoryx2_bb_container::queue::RelocatableContainer;
-use iceoryx2_bb_lock_free::mpmc::container::Container;
+use iceoryx2_bb_container::vec::RelocatableVec;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8};
 
 use super::PortCleanupAction;
 
@@ -23,6 +29,33 @@ use super::PortCleanupAction;
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_readers: usize,
+    pub number_of_entries: usize,
+    pub entry_data_size: usize,
+}
+
+/// Describes where the key and the value of a blackboard entry are stored. The layout is
+/// defined once by the creator of the service, only the value and its generation change
+/// afterwards.
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct EntryDetails {
+    // even while the value is consistent, odd while a writer updates it. Every write
+    // increments it by two so that a reader can detect changes since its last read.
+    generation: IoxAtomicU64,
+    key_offset: usize,
+    key_size: usize,
+    value_offset: usize,
+    value_type: TypeDetail,
+}
+
+impl EntryDetails {
+    pub(crate) fn value_type(&self) -> &TypeDetail {
+        &self.value_type
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
 }
 
 /// Contains the communication settings of the connected
@@ -44,12 +77,20 @@ pub struct ReaderDetails {
 #[derive(Debug)]
 pub struct DynamicConfig {
     pub(crate) readers: Container<ReaderDetails>,
+    entries: RelocatableVec<EntryDetails>,
+    entry_data: RelocatableVec<IoxAtomicU8>,
 }
 
 impl DynamicConfig {
     pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
         Self {
             readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            entries: unsafe {
+                RelocatableVec::new_uninit(Self::capacity(config.number_of_entries))
+            },
+            entry_data: unsafe {
+                RelocatableVec::new_uninit(Self::capacity(config.entry_data_size))
+            },
         }
     }
 
@@ -57,19 +98,172 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.readers.init(allocator),
             "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from self,
+            when self.entries.init(allocator),
+            "This should never happen! Unable to initialize blackboard entry container.");
+        fatal_panic!(from self,
+            when self.entry_data.init(allocator),
+            "This should never happen! Unable to initialize blackboard entry data.");
+        self.entry_data.fill_with(|| IoxAtomicU8::new(0));
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + RelocatableVec::<EntryDetails>::memory_size(Self::capacity(config.number_of_entries))
+            + RelocatableVec::<IoxAtomicU8>::memory_size(Self::capacity(config.entry_data_size))
+    }
+
+    // a blackboard without entries is valid but the allocation of an empty vector fails
+    fn capacity(value: usize) -> usize {
+        value.max(1)
+    }
+
+    /// Adds an entry with its initial value. Must be called only by the creator of the
+    /// service before the service becomes available.
+    pub(crate) unsafe fn add_entry(
+        &mut self,
+        key: &[u8],
+        value_type: &TypeDetail,
+        value: &[u8],
+    ) -> bool {
+        let key_offset = match self.entries.last() {
+            Some(entry) => entry.value_offset + entry.value_type.size,
+            None => 0,
+        };
+        let value_offset = key_offset + key.len();
+
+        if self.entry_data.len() < value_offset + value.len() {
+            return false;
+        }
+
+        for (n, byte) in key.iter().chain(value.iter()).enumerate() {
+            self.entry_data[key_offset + n].store(*byte, Ordering::Relaxed);
+        }
+
+        self.entries.push(EntryDetails {
+            generation: IoxAtomicU64::new(0),
+            key_offset,
+            key_size: key.len(),
+            value_offset,
+            value_type: value_type.clone(),
+        })
+    }
+
+    pub(crate) fn number_of_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn entry(&self, index: usize) -> &EntryDetails {
+        &self.entries[index]
+    }
+
+    /// Copies the key of the entry into the provided memory that must have the size of the key.
+    pub(crate) unsafe fn copy_key(&self, entry: &EntryDetails, target: *mut u8) {
+        for n in 0..entry.key_size {
+            target
+                .add(n)
+                .write(self.entry_data[entry.key_offset + n].load(Ordering::Relaxed));
+        }
+    }
+
+    pub(crate) fn has_key(&self, entry: &EntryDetails, key: &[u8]) -> bool {
+        entry.key_size == key.len()
+            && key.iter().enumerate().all(|(n, byte)| {
+                self.entry_data[entry.key_offset + n].load(Ordering::Relaxed) == *byte
+            })
+    }
+
+    /// Copies a consistent snapshot of the value of the entry into the provided memory that
+    /// must have the size of the value and returns the generation of the snapshot.
+    pub(crate) unsafe fn read_value(&self, entry: &EntryDetails, target: *mut u8) -> u64 {
+        loop {
+            let generation = entry.generation.load(Ordering::Acquire);
+            if generation % 2 == 1 {
+                core::hint::spin_loop();
+                continue;
+            }
+
+            for n in 0..entry.value_type.size {
+                target
+                    .add(n)
+                    .write(self.entry_data[entry.value_offset + n].load(Ordering::Relaxed));
+            }
+
+            core::sync::atomic::fence(Ordering::Acquire);
+            if entry.generation.load(Ordering::Relaxed) == generation {
+                return generation;
+            }
+        }
+    }
+
+    /// Replaces the value of the entry with the provided memory that must have the size of
+    /// the value.
+    pub(crate) unsafe fn write_value(&self, entry: &EntryDetails, source: *const u8) {
+        let mut generation = entry.generation.load(Ordering::Relaxed);
+        loop {
+            if generation % 2 == 1 {
+                core::hint::spin_loop();
+                generation = entry.generation.load(Ordering::Relaxed);
+                continue;
+            }
+
+            match entry.generation.compare_exchange_weak(
+                generation,
+                generation + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => generation = v,
+            }
+        }
+        core::sync::atomic::fence(Ordering::Release);
+
+        for n in 0..entry.value_type.size {
+            self.entry_data[entry.value_offset + n].store(source.add(n).read(), Ordering::Relaxed);
+        }
+
+        entry.generation.store(generation + 2, Ordering::Release);
+    }
+
+    pub(crate) fn list_ports_of_node<F: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut callback: F,
+    ) {
+        unsafe { self.readers.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Reader(details.reader_id));
+            }
+            CallbackProgression::Continue
+        });
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
         PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
     >(
         &self,
-        _node_id: &NodeId,
-        mut _port_cleanup_callback: PortCleanup,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
     ) {
-        todo!();
+        self.readers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_reader| {
+                if registered_reader.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Reader(registered_reader.reader_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_reader_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_reader_id(&self, id: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(id).ok() }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle, ReleaseMode::Default) };
     }
 }
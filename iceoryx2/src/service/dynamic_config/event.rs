@@ -173,6 +173,26 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) fn list_ports_of_node<F: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut callback: F,
+    ) {
+        unsafe { self.notifiers.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Notifier(details.notifier_id));
+            }
+            CallbackProgression::Continue
+        });
+
+        unsafe { self.listeners.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Listener(details.listener_id));
+            }
+            CallbackProgression::Continue
+        });
+    }
+
     pub(crate) fn add_listener_id(&self, id: ListenerDetails) -> Option<ContainerHandle> {
         unsafe { self.listeners.add(id).ok() }
     }
@@ -150,6 +150,15 @@ impl DynamicConfig {
         ret_val
     }
 
+    pub(crate) fn list_ports_of_node<F: FnMut(UniquePortId)>(&self, node_id: &NodeId, callback: F) {
+        match self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => v.list_ports_of_node(node_id, callback),
+            MessagingPattern::Event(ref v) => v.list_ports_of_node(node_id, callback),
+            MessagingPattern::RequestResponse(ref v) => v.list_ports_of_node(node_id, callback),
+            MessagingPattern::Blackboard(ref v) => v.list_ports_of_node(node_id, callback),
+        }
+    }
+
     pub(crate) fn register_node_id(
         &self,
         node_id: NodeId,
@@ -222,4 +231,13 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::DynamicConfig {
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from "DynamicConfig::blackboard_mut()", "This should never happen! Trying to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
@@ -23,13 +23,18 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//! println!("memory usage:                     {:?}", pubsub.dynamic_config().memory_usage());
 //! # Ok(())
 //! # }
 //! ```
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 
 use crate::{
     node::NodeId,
@@ -70,6 +75,98 @@ pub struct PublisherDetails {
     /// [`DataSegmentType::Dynamic`] it defines how many segment the
     /// [`Publisher`](crate::port::publisher::Publisher) can have at most.
     pub max_number_of_segments: u8,
+    /// The ownership strength of the [`Publisher`](crate::port::publisher::Publisher). When
+    /// the service has exclusive ownership, the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s receive only the samples of the
+    /// alive [`Publisher`](crate::port::publisher::Publisher) with the highest strength.
+    pub ownership_strength: u32,
+}
+
+/// The shared memory usage of the data segments of all
+/// [`Publisher`](crate::port::publisher::Publisher)s of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of bytes of all chunks that are currently allocated, meaning they are either
+    /// loaned, in delivery or held by a [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub allocated_bytes: u64,
+    /// The number of chunks that are currently allocated.
+    pub number_of_chunks: u64,
+    /// The sum of the highest number of bytes every
+    /// [`Publisher`](crate::port::publisher::Publisher) had allocated at the same time.
+    pub allocated_bytes_high_water_mark: u64,
+    /// The sum of the highest number of chunks every
+    /// [`Publisher`](crate::port::publisher::Publisher) had allocated at the same time.
+    pub number_of_chunks_high_water_mark: u64,
+    /// The number of bytes the current data segments of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s can provide. It grows when a
+    /// data segment is reallocated with a larger chunk size.
+    pub data_segment_size: u64,
+}
+
+/// Tracks the shared memory usage of the data segment of a single
+/// [`Publisher`](crate::port::publisher::Publisher).
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct DataSegmentUsage {
+    allocated_bytes: IoxAtomicU64,
+    number_of_chunks: IoxAtomicU64,
+    allocated_bytes_high_water_mark: IoxAtomicU64,
+    number_of_chunks_high_water_mark: IoxAtomicU64,
+    data_segment_size: IoxAtomicU64,
+}
+
+impl DataSegmentUsage {
+    fn new() -> Self {
+        Self {
+            allocated_bytes: IoxAtomicU64::new(0),
+            number_of_chunks: IoxAtomicU64::new(0),
+            allocated_bytes_high_water_mark: IoxAtomicU64::new(0),
+            number_of_chunks_high_water_mark: IoxAtomicU64::new(0),
+            data_segment_size: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn reset(&self) {
+        self.allocated_bytes.store(0, Ordering::Relaxed);
+        self.number_of_chunks.store(0, Ordering::Relaxed);
+        self.allocated_bytes_high_water_mark
+            .store(0, Ordering::Relaxed);
+        self.number_of_chunks_high_water_mark
+            .store(0, Ordering::Relaxed);
+        self.data_segment_size.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_allocation(&self, chunk_size: usize, data_segment_size: usize) {
+        let allocated_bytes = self
+            .allocated_bytes
+            .fetch_add(chunk_size as u64, Ordering::Relaxed)
+            + chunk_size as u64;
+        let number_of_chunks = self.number_of_chunks.fetch_add(1, Ordering::Relaxed) + 1;
+        self.allocated_bytes_high_water_mark
+            .fetch_max(allocated_bytes, Ordering::Relaxed);
+        self.number_of_chunks_high_water_mark
+            .fetch_max(number_of_chunks, Ordering::Relaxed);
+        self.data_segment_size
+            .store(data_segment_size as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_deallocation(&self, chunk_size: usize) {
+        self.allocated_bytes
+            .fetch_sub(chunk_size as u64, Ordering::Relaxed);
+        self.number_of_chunks.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn accumulate(&self, usage: &mut MemoryUsage) {
+        usage.allocated_bytes += self.allocated_bytes.load(Ordering::Relaxed);
+        usage.number_of_chunks += self.number_of_chunks.load(Ordering::Relaxed);
+        usage.allocated_bytes_high_water_mark +=
+            self.allocated_bytes_high_water_mark.load(Ordering::Relaxed);
+        usage.number_of_chunks_high_water_mark += self
+            .number_of_chunks_high_water_mark
+            .load(Ordering::Relaxed);
+        usage.data_segment_size += self.data_segment_size.load(Ordering::Relaxed);
+    }
 }
 
 /// Contains the communication settings of the connected
@@ -94,6 +191,11 @@ pub struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    // One entry for every subscriber/publisher slot combination. Stores the highest
+    // acknowledged sequence number + 1, 0 means that nothing was acknowledged yet.
+    acknowledgements: RelocatableVec<IoxAtomicU64>,
+    // One entry for every publisher slot.
+    data_segment_usage: RelocatableVec<DataSegmentUsage>,
 }
 
 impl DynamicConfig {
@@ -101,6 +203,12 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            acknowledgements: unsafe {
+                RelocatableVec::new_uninit(
+                    config.number_of_subscribers * config.number_of_publishers,
+                )
+            },
+            data_segment_usage: unsafe { RelocatableVec::new_uninit(config.number_of_publishers) },
         }
     }
 
@@ -111,11 +219,23 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from self,
+            when self.acknowledgements.init(allocator),
+            "This should never happen! Unable to initialize the acknowledgement log.");
+        self.acknowledgements.fill_with(|| IoxAtomicU64::new(0));
+        fatal_panic!(from self,
+            when self.data_segment_usage.init(allocator),
+            "This should never happen! Unable to initialize the data segment usage.");
+        self.data_segment_usage.fill_with(DataSegmentUsage::new);
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(
+                config.number_of_subscribers * config.number_of_publishers,
+            )
+            + RelocatableVec::<DataSegmentUsage>::memory_size(config.number_of_publishers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -152,6 +272,26 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) fn list_ports_of_node<F: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut callback: F,
+    ) {
+        unsafe { self.publishers.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Publisher(details.publisher_id));
+            }
+            CallbackProgression::Continue
+        });
+
+        unsafe { self.subscribers.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Subscriber(details.subscriber_id));
+            }
+            CallbackProgression::Continue
+        });
+    }
+
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
     pub fn number_of_publishers(&self) -> usize {
         self.publishers.len()
@@ -188,8 +328,91 @@ impl DynamicConfig {
         state.for_each(|_, details| callback(details));
     }
 
+    /// Returns the highest sequence number the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) has acknowledged with
+    /// [`Subscriber::mark_received()`](crate::port::subscriber::Subscriber::mark_received())
+    /// for samples of the [`Publisher`](crate::port::publisher::Publisher). Returns [`None`]
+    /// when nothing was acknowledged yet or when one of the ports is not connected.
+    ///
+    /// The acknowledgement log resides in the shared memory of the service, therefore it
+    /// survives the crash of the [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// until its resources are cleaned up.
+    pub fn acknowledged_sequence_number(
+        &self,
+        publisher_id: UniquePublisherId,
+        subscriber_id: UniqueSubscriberId,
+    ) -> Option<u64> {
+        let mut publisher_index = None;
+        unsafe { self.publishers.get_state() }.for_each(|handle, details| {
+            if details.publisher_id == publisher_id {
+                publisher_index = Some(handle.index() as usize);
+                return CallbackProgression::Stop;
+            }
+            CallbackProgression::Continue
+        });
+
+        let mut subscriber_index = None;
+        unsafe { self.subscribers.get_state() }.for_each(|handle, details| {
+            if details.subscriber_id == subscriber_id {
+                subscriber_index = Some(handle.index() as usize);
+                return CallbackProgression::Stop;
+            }
+            CallbackProgression::Continue
+        });
+
+        match (publisher_index, subscriber_index) {
+            (Some(publisher_index), Some(subscriber_index)) => {
+                match self.acknowledgements
+                    [self.acknowledgement_index(subscriber_index, publisher_index)]
+                .load(Ordering::Relaxed)
+                {
+                    0 => None,
+                    v => Some(v - 1),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the accumulated shared memory usage of the data segments of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage::default();
+        unsafe { self.publishers.get_state() }.for_each(|handle, _| {
+            self.data_segment_usage[handle.index() as usize].accumulate(&mut usage);
+            CallbackProgression::Continue
+        });
+
+        usage
+    }
+
+    pub(crate) fn data_segment_usage(&self, publisher_index: usize) -> &DataSegmentUsage {
+        &self.data_segment_usage[publisher_index]
+    }
+
+    fn acknowledgement_index(&self, subscriber_index: usize, publisher_index: usize) -> usize {
+        subscriber_index * self.publishers.capacity() + publisher_index
+    }
+
+    pub(crate) fn acknowledge(
+        &self,
+        subscriber_handle: ContainerHandle,
+        publisher_index: usize,
+        sequence_number: u64,
+    ) {
+        self.acknowledgements
+            [self.acknowledgement_index(subscriber_handle.index() as usize, publisher_index)]
+        .fetch_max(sequence_number + 1, Ordering::Relaxed);
+    }
+
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe { self.subscribers.add(details).ok() }
+        let handle = unsafe { self.subscribers.add(details).ok() }?;
+        for publisher_index in 0..self.publishers.capacity() {
+            self.acknowledgements
+                [self.acknowledgement_index(handle.index() as usize, publisher_index)]
+            .store(0, Ordering::Relaxed);
+        }
+        Some(handle)
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
@@ -197,7 +420,14 @@ impl DynamicConfig {
     }
 
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe { self.publishers.add(details).ok() }
+        let handle = unsafe { self.publishers.add(details).ok() }?;
+        self.data_segment_usage[handle.index() as usize].reset();
+        for subscriber_index in 0..self.subscribers.capacity() {
+            self.acknowledgements
+                [self.acknowledgement_index(subscriber_index, handle.index() as usize)]
+            .store(0, Ordering::Relaxed);
+        }
+        Some(handle)
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
@@ -10,11 +10,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::sync::atomic::Ordering;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{Container, ContainerHandle, ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicI32, IoxAtomicU64};
 
 use crate::{
     node::NodeId,
@@ -83,6 +87,7 @@ pub struct ClientDetails {
 pub(crate) struct DynamicConfigSettings {
     pub number_of_servers: usize,
     pub number_of_clients: usize,
+    pub credits_per_client: u32,
 }
 
 /// The dynamic configuration of an
@@ -93,6 +98,16 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) servers: Container<ServerDetails>,
     pub(crate) clients: Container<ClientDetails>,
+    // One entry for every server slot, contains the size of its current data segment.
+    server_data_segment_sizes: RelocatableVec<IoxAtomicU64>,
+    // One entry for every client slot, contains the size of its current data segment.
+    client_data_segment_sizes: RelocatableVec<IoxAtomicU64>,
+    // One entry for every server and client slot pair, contains the number of requests the
+    // client delivered to the server that were not yet acknowledged. Every server grants
+    // every client its own credits, the client has as many credits left as the server with
+    // the most unacknowledged requests of the client.
+    outstanding_requests: RelocatableVec<IoxAtomicI32>,
+    credits_per_client: u32,
 }
 
 impl DynamicConfig {
@@ -100,6 +115,16 @@ impl DynamicConfig {
         Self {
             servers: unsafe { Container::new_uninit(config.number_of_servers) },
             clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            server_data_segment_sizes: unsafe {
+                RelocatableVec::new_uninit(config.number_of_servers)
+            },
+            client_data_segment_sizes: unsafe {
+                RelocatableVec::new_uninit(config.number_of_clients)
+            },
+            outstanding_requests: unsafe {
+                RelocatableVec::new_uninit(config.number_of_servers * config.number_of_clients)
+            },
+            credits_per_client: config.credits_per_client,
         }
     }
 
@@ -110,11 +135,30 @@ impl DynamicConfig {
         fatal_panic!(from self,
             when self.clients.init(allocator),
             "This should never happen! Unable to initialize clients port id container.");
+        fatal_panic!(from self,
+            when self.server_data_segment_sizes.init(allocator),
+            "This should never happen! Unable to initialize the server data segment sizes.");
+        self.server_data_segment_sizes
+            .fill_with(|| IoxAtomicU64::new(0));
+        fatal_panic!(from self,
+            when self.client_data_segment_sizes.init(allocator),
+            "This should never happen! Unable to initialize the client data segment sizes.");
+        self.client_data_segment_sizes
+            .fill_with(|| IoxAtomicU64::new(0));
+        fatal_panic!(from self,
+            when self.outstanding_requests.init(allocator),
+            "This should never happen! Unable to initialize the outstanding requests.");
+        self.outstanding_requests.fill_with(|| IoxAtomicI32::new(0));
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<ServerDetails>::memory_size(config.number_of_servers)
             + Container::<ClientDetails>::memory_size(config.number_of_clients)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_servers)
+            + RelocatableVec::<IoxAtomicU64>::memory_size(config.number_of_clients)
+            + RelocatableVec::<IoxAtomicI32>::memory_size(
+                config.number_of_servers * config.number_of_clients,
+            )
     }
 
     /// Returns how many [`crate::port::client::Client`] ports are currently connected.
@@ -159,22 +203,128 @@ impl DynamicConfig {
             });
     }
 
+    pub(crate) fn list_ports_of_node<F: FnMut(UniquePortId)>(
+        &self,
+        node_id: &NodeId,
+        mut callback: F,
+    ) {
+        unsafe { self.servers.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Server(details.server_id));
+            }
+            CallbackProgression::Continue
+        });
+
+        unsafe { self.clients.get_state() }.for_each(|_, details| {
+            if details.node_id == *node_id {
+                callback(UniquePortId::Client(details.client_id));
+            }
+            CallbackProgression::Continue
+        });
+    }
+
     pub(crate) fn add_client_id(&self, details: ClientDetails) -> Option<ContainerHandle> {
         unsafe { self.clients.add(details).ok() }
     }
 
     pub(crate) fn release_client_handle(&self, handle: ContainerHandle) {
+        self.client_data_segment_sizes[handle.index() as usize].store(0, Ordering::Relaxed);
+        for server_index in 0..self.server_data_segment_sizes.capacity() {
+            let index = self.outstanding_index(server_index, handle.index() as usize);
+            self.outstanding_requests[index].store(0, Ordering::Relaxed);
+        }
         unsafe { self.clients.remove(handle, ReleaseMode::Default) };
     }
 
+    pub(crate) fn set_client_data_segment_size(&self, handle: ContainerHandle, size: usize) {
+        self.client_data_segment_sizes[handle.index() as usize]
+            .store(size as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the number of requests the client in the provided slot can send until a
+    /// server has to acknowledge previous ones.
+    pub(crate) fn client_credits(&self, client_index: usize) -> u32 {
+        let max_outstanding = (0..self.server_data_segment_sizes.capacity())
+            .map(|server_index| {
+                let index = self.outstanding_index(server_index, client_index);
+                self.outstanding_requests[index].load(Ordering::Acquire)
+            })
+            .max()
+            .unwrap_or(0)
+            .max(0) as u32;
+
+        self.credits_per_client.saturating_sub(max_outstanding)
+    }
+
+    fn outstanding_index(&self, server_index: usize, client_index: usize) -> usize {
+        server_index * self.client_data_segment_sizes.capacity() + client_index
+    }
+
+    /// Records that the client delivered a request to the server that has to be acknowledged.
+    pub(crate) fn add_outstanding_request(&self, server_index: usize, client_index: usize) {
+        let index = self.outstanding_index(server_index, client_index);
+        self.outstanding_requests[index].fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Acknowledges a request the client delivered to the server and returns the credit the
+    /// server granted for it.
+    pub(crate) fn acknowledge_request(&self, server_index: usize, client_index: usize) {
+        // the counter may become negative temporarily when the server acknowledges the
+        // request before the client recorded its delivery
+        let index = self.outstanding_index(server_index, client_index);
+        self.outstanding_requests[index].fetch_sub(1, Ordering::AcqRel);
+    }
+
+    /// Returns the credits of all requests the server in the provided slot did not
+    /// acknowledge, since they will never be acknowledged once the server is gone.
+    fn return_outstanding_credits_of_server(&self, server_index: usize) {
+        for client_index in 0..self.client_data_segment_sizes.capacity() {
+            let index = self.outstanding_index(server_index, client_index);
+            self.outstanding_requests[index].store(0, Ordering::Release);
+        }
+    }
+
     pub(crate) fn add_server_id(&self, details: ServerDetails) -> Option<ContainerHandle> {
         unsafe { self.servers.add(details).ok() }
     }
 
     pub(crate) fn release_server_handle(&self, handle: ContainerHandle) {
+        self.server_data_segment_sizes[handle.index() as usize].store(0, Ordering::Relaxed);
+        self.return_outstanding_credits_of_server(handle.index() as usize);
         unsafe { self.servers.remove(handle, ReleaseMode::Default) };
     }
 
+    pub(crate) fn set_server_data_segment_size(&self, handle: ContainerHandle, size: usize) {
+        self.server_data_segment_sizes[handle.index() as usize]
+            .store(size as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the sum of the sizes of the data segments of all
+    /// [`Client`](crate::port::client::Client)s, which provide the requests.
+    pub fn request_pool_bytes(&self) -> u64 {
+        let mut bytes = 0;
+        unsafe { self.clients.get_state() }.for_each(|handle, _| {
+            bytes +=
+                self.client_data_segment_sizes[handle.index() as usize].load(Ordering::Relaxed);
+            CallbackProgression::Continue
+        });
+
+        bytes
+    }
+
+    /// Returns the sum of the sizes of the data segments of all
+    /// [`Server`](crate::port::server::Server)s, which provide the responses.
+    pub fn response_pool_bytes(&self) -> u64 {
+        let mut bytes = 0;
+        unsafe { self.servers.get_state() }.for_each(|handle, _| {
+            bytes +=
+                self.server_data_segment_sizes[handle.index() as usize].load(Ordering::Relaxed);
+            CallbackProgression::Continue
+        });
+
+        bytes
+    }
+
     /// Iterates over all [`Server`](crate::port::server::Server)s and calls the
     /// callback with the corresponding [`ServerDetails`].
     /// The callback shall return [`CallbackProgression::Continue`] when the iteration shall
@@ -31,11 +31,85 @@
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_posix::clock::{monotonic_clock_domain, ClockType, Time, TimeError};
 
 use crate::{node::NodeId, port::port_identifiers::UniquePublisherId};
 
+/// The point in time when a [`Sample`](crate::sample::Sample) was sent by the
+/// [`Publisher`](crate::port::publisher::Publisher). It is only captured when the
+/// [`Service`](crate::service::Service) was created with
+/// [`Builder::enable_send_timestamp()`](crate::service::builder::publish_subscribe::Builder::enable_send_timestamp())
+/// or when it is explicitly provided with
+/// [`SampleMut::send_with_timestamp()`](crate::sample_mut::SampleMut::send_with_timestamp()).
+/// The [`Header`] contains the space for it in any case, so its size does not depend on
+/// the setting.
+#[derive(Debug, Copy, Clone, ZeroCopySend, PartialEq, Eq)]
+#[repr(C)]
+pub struct Timestamp {
+    monotonic_ns: u64,
+    system_time_ns: u64,
+    clock_domain: u64,
+}
+
+impl Timestamp {
+    pub(crate) fn now() -> Result<Self, TimeError> {
+        let monotonic = Time::now_with_clock(ClockType::Monotonic)?.as_duration();
+        let system_time = Time::now_with_clock(ClockType::Realtime)?.as_duration();
+
+        Ok(Self {
+            monotonic_ns: monotonic.as_nanos() as u64,
+            system_time_ns: system_time.as_nanos() as u64,
+            clock_domain: monotonic_clock_domain(),
+        })
+    }
+
+    /// Creates a [`Timestamp`] from a point in time of the monotonic clock, for instance the
+    /// time when the data of a [`Sample`](crate::sample::Sample) was captured. The
+    /// [`Timestamp::system_time()`] is derived from the current offset between the monotonic
+    /// and the system clock.
+    pub fn from_monotonic(monotonic: Duration) -> Result<Self, TimeError> {
+        let now = Self::now()?;
+        let now_monotonic = now.monotonic();
+        let now_system_time = now.system_time();
+
+        let system_time = if monotonic <= now_monotonic {
+            now_system_time.saturating_sub(now_monotonic - monotonic)
+        } else {
+            now_system_time.saturating_add(monotonic - now_monotonic)
+        };
+
+        Ok(Self {
+            monotonic_ns: monotonic.as_nanos() as u64,
+            system_time_ns: system_time.as_nanos() as u64,
+            clock_domain: monotonic_clock_domain(),
+        })
+    }
+
+    /// Returns the time of the monotonic clock. The starting point of the monotonic clock is
+    /// unspecified and differs from host to host, therefore comparing the value of
+    /// [`Sample`](crate::sample::Sample)s that were sent on different hosts is meaningless.
+    pub fn monotonic(&self) -> Duration {
+        Duration::from_nanos(self.monotonic_ns)
+    }
+
+    /// Returns the wall-clock time since the UNIX epoch. In contrast to
+    /// [`Timestamp::monotonic()`] it can jump when the system time is adjusted.
+    pub fn system_time(&self) -> Duration {
+        Duration::from_nanos(self.system_time_ns)
+    }
+
+    /// Returns the domain of the monotonic clock in which the [`Timestamp`] was captured.
+    /// [`Timestamp::monotonic()`] values are only comparable when they originate from the same
+    /// clock domain.
+    pub fn clock_domain(&self) -> u64 {
+        self.clock_domain
+    }
+}
+
 /// Sample header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 #[derive(Debug, Copy, Clone, ZeroCopySend, PartialEq, Eq)]
@@ -44,6 +118,9 @@ pub struct Header {
     node_id: NodeId,
     publisher_port_id: UniquePublisherId,
     number_of_elements: u64,
+    sequence_number: u64,
+    timestamp: Timestamp,
+    has_timestamp: bool,
 }
 
 impl Header {
@@ -56,9 +133,25 @@ impl Header {
             node_id,
             publisher_port_id,
             number_of_elements,
+            sequence_number: 0,
+            timestamp: Timestamp {
+                monotonic_ns: 0,
+                system_time_ns: 0,
+                clock_domain: 0,
+            },
+            has_timestamp: false,
         }
     }
 
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_timestamp(&mut self, value: Timestamp) {
+        self.timestamp = value;
+        self.has_timestamp = true;
+    }
+
     /// Returns the [`NodeId`] of the source node that published the
     /// [`Sample`](crate::sample::Sample).
     pub fn node_id(&self) -> NodeId {
@@ -83,4 +176,20 @@ impl Header {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the sequence number of the [`Sample`](crate::sample::Sample). Every
+    /// [`Publisher`](crate::port::publisher::Publisher) numbers its sent samples
+    /// consecutively, starting with 0.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the [`Timestamp`] that was captured when the [`Sample`](crate::sample::Sample)
+    /// was sent or that was provided with
+    /// [`SampleMut::send_with_timestamp()`](crate::sample_mut::SampleMut::send_with_timestamp()).
+    /// If the [`Service`](crate::service::Service) does not support send timestamps and no
+    /// timestamp was provided it returns [`None`].
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.has_timestamp.then_some(self.timestamp)
+    }
 }
@@ -12,6 +12,8 @@
 
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
@@ -25,6 +27,10 @@ pub struct RequestHeader {
     pub(crate) channel_id: ChannelId,
     pub(crate) request_id: u64,
     pub(crate) number_of_elements: u64,
+    // size of a single payload element of the sender, differs from the local payload size
+    // when the sender uses another version of an evolved payload type
+    pub(crate) payload_size: u64,
+    pub(crate) timestamp_ns: u64,
 }
 
 impl RequestHeader {
@@ -46,6 +52,14 @@ impl RequestHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns the time of the monotonic clock in nanoseconds when the
+    /// [`RequestMut`](crate::request_mut::RequestMut) was sent by the
+    /// [`Client`](crate::port::client::Client). As long as the request was not sent it
+    /// returns 0.
+    pub fn timestamp_ns(&self) -> u64 {
+        self.timestamp_ns
+    }
 }
 
 /// Response header used by
@@ -56,6 +70,25 @@ pub struct ResponseHeader {
     pub(crate) server_id: UniqueServerId,
     pub(crate) request_id: u64,
     pub(crate) number_of_elements: u64,
+    // size of a single payload element of the sender, differs from the local payload size
+    // when the sender uses another version of an evolved payload type
+    pub(crate) payload_size: u64,
+    pub(crate) error_code: u64,
+    pub(crate) timestamp_ns: u64,
+    pub(crate) is_error: bool,
+}
+
+/// Captures the current time of the monotonic clock in nanoseconds. When the clock is not
+/// available 0 is returned.
+pub(crate) fn monotonic_timestamp_ns() -> u64 {
+    match Time::now_with_clock(ClockType::Monotonic) {
+        Ok(now) => now.as_duration().as_nanos() as u64,
+        Err(e) => {
+            warn!(from "monotonic_timestamp_ns()",
+                "Unable to acquire the send timestamp ({:?}).", e);
+            0
+        }
+    }
 }
 
 impl ResponseHeader {
@@ -77,4 +110,25 @@ impl ResponseHeader {
     pub fn number_of_elements(&self) -> u64 {
         self.number_of_elements
     }
+
+    /// Returns `true` when the [`Server`](crate::port::server::Server) rejected the request
+    /// with [`ActiveRequest::reject()`](crate::active_request::ActiveRequest::reject). The
+    /// payload of such a response does not contain any user data.
+    pub fn is_error(&self) -> bool {
+        self.is_error
+    }
+
+    /// Returns the user defined error code the request was rejected with or [`None`] when
+    /// the response is not an error response.
+    pub fn error_code(&self) -> Option<u64> {
+        self.is_error.then_some(self.error_code)
+    }
+
+    /// Returns the time of the monotonic clock in nanoseconds when the
+    /// [`ResponseMut`](crate::response_mut::ResponseMut) was sent by the
+    /// [`Server`](crate::port::server::Server). As long as the response was not sent it
+    /// returns 0.
+    pub fn timestamp_ns(&self) -> u64 {
+        self.timestamp_ns
+    }
 }
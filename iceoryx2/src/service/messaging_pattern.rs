@@ -47,7 +47,9 @@
 //! on the entire shared memory.
 
 /// Identifies the kind of messaging pattern the [`Service`](crate::service::Service) will use.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
 #[repr(u32)]
 pub enum MessagingPattern {
     /// Unidirectional communication pattern where the
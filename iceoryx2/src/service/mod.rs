@@ -219,6 +219,10 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// Allows to back the data segments of [`Service`]s with memory from a custom
+/// [`SegmentProvider`](crate::service::segment_provider::SegmentProvider).
+pub mod segment_provider;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -236,8 +240,10 @@ use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_log::{debug, fail, trace, warn};
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{debug, fail, fatal_panic, trace, warn};
 use iceoryx2_cal::dynamic_storage::{
     DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
 };
@@ -254,10 +260,59 @@ use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 use service_id::ServiceId;
 
+use self::attribute::AttributeVerifier;
 use self::dynamic_config::DeregisterNodeState;
 use self::messaging_pattern::MessagingPattern;
 use self::service_name::ServiceName;
 
+const DISCOVERY_ANNOUNCEMENT_SERVICE_NAME: &str = "discovery/announcements";
+
+/// Returns the name of the publish-subscribe service on which a [`ServiceAnnouncement`] is
+/// published for the creation and removal of every [`Service`] when
+/// [`config::Service::enable_discovery_announcements`] is enabled.
+#[doc(hidden)]
+pub fn __internal_discovery_announcement_service_name() -> ServiceName {
+    fatal_panic!(from "__internal_discovery_announcement_service_name()",
+        when ServiceName::__internal_new_prefixed(DISCOVERY_ANNOUNCEMENT_SERVICE_NAME),
+        "This should never happen! The discovery announcement service name is not a valid service name.")
+}
+
+/// The record that is published on the discovery announcement service when a [`Service`] is
+/// created or removed and [`config::Service::enable_discovery_announcements`] is enabled.
+///
+/// Every [`Node`](crate::node::Node) announces through one publisher that is opened
+/// lazily with the [`Node`](crate::node::Node)s config, as soon as the announcement service
+/// exists. Announcements are best effort:
+///
+///  * The announcement service is created by the consumer, e.g. the discovery service. When
+///    its maximum number of publishers is exhausted, the [`Node`](crate::node::Node)s that
+///    could not connect do not announce anything and retry with the next change. When its
+///    maximum number of subscribers is exhausted, no further subscriber can be created.
+///  * When the buffer of a subscriber overflows, the oldest records are discarded.
+///  * A [`Service`] that is removed after its [`Node`](crate::node::Node) was dropped or
+///    that is removed by the dead node cleanup of a crashed creator is not announced as
+///    [`ServiceAnnouncement::ServiceRemoved`]. Its [`ServiceAnnouncement::ServiceAdded`]
+///    record becomes stale and must be verified with [`Service::does_exist()`].
+#[derive(Debug, Clone, PartialEq, Eq, ZeroCopySend)]
+#[repr(C)]
+pub enum ServiceAnnouncement {
+    /// A [`Service`] was created, contains its [`StaticConfig`].
+    ServiceAdded(StaticConfig),
+    /// The last owner of a [`Service`] released it and its resources were removed, contains
+    /// its [`StaticConfig`].
+    ServiceRemoved(StaticConfig),
+}
+
+impl ServiceAnnouncement {
+    /// Returns the [`StaticConfig`] of the announced [`Service`].
+    pub fn static_config(&self) -> &StaticConfig {
+        match self {
+            ServiceAnnouncement::ServiceAdded(static_config)
+            | ServiceAnnouncement::ServiceRemoved(static_config) => static_config,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ServiceRemoveNodeError {
     VersionMismatch,
@@ -342,6 +397,24 @@ pub struct ServiceState<S: Service> {
     pub(crate) shared_node: Arc<SharedNode<S>>,
     pub(crate) dynamic_storage: S::DynamicStorage,
     pub(crate) static_storage: S::StaticStorage,
+    // must be the last member since the removal can only be announced after the underlying
+    // resources were removed
+    removal_announcement: Option<RemovalAnnouncement<S>>,
+}
+
+#[derive(Debug)]
+struct RemovalAnnouncement<S: Service> {
+    shared_node: Arc<SharedNode<S>>,
+    static_config: StaticConfig,
+}
+
+impl<S: Service> Drop for RemovalAnnouncement<S> {
+    fn drop(&mut self) {
+        internal::announce_service_change(
+            &self.shared_node,
+            ServiceAnnouncement::ServiceRemoved(self.static_config.clone()),
+        );
+    }
 }
 
 impl<S: Service> ServiceState<S> {
@@ -356,6 +429,7 @@ impl<S: Service> ServiceState<S> {
             shared_node,
             dynamic_storage,
             static_storage,
+            removal_announcement: None,
         };
         trace!(from "Service::open()", "open service: {} ({:?})",
             new_self.static_config.name(), new_self.static_config.service_id());
@@ -382,6 +456,18 @@ impl<S: Service> Drop for ServiceState<S> {
                 DeregisterNodeState::NoMoreOwners => {
                     self.static_storage.acquire_ownership();
                     self.dynamic_storage.acquire_ownership();
+                    if self
+                        .shared_node
+                        .config()
+                        .global
+                        .service
+                        .enable_discovery_announcements
+                    {
+                        self.removal_announcement = Some(RemovalAnnouncement {
+                            shared_node: self.shared_node.clone(),
+                            static_config: self.static_config.clone(),
+                        });
+                    }
                     trace!(from origin, "close and remove service: {} ({:?})",
                             self.static_config.name(), id);
                 }
@@ -392,19 +478,21 @@ impl<S: Service> Drop for ServiceState<S> {
 
 pub(crate) mod internal {
     use builder::event::EventOpenError;
+    use builder::publish_subscribe::PublishSubscribeOpenError;
     use dynamic_config::{PortCleanupAction, RemoveDeadNodeResult};
     use port_factory::PortFactory;
 
     use crate::{
-        node::{NodeBuilder, NodeId},
+        node::{DiscoveryAnnouncer, NodeBuilder, NodeId},
         port::{
             listener::remove_connection_of_listener, notifier::Notifier,
-            port_identifiers::UniquePortId,
+            port_identifiers::UniquePortId, unable_to_deliver_strategy::UnableToDeliverStrategy,
+            SendError,
         },
         prelude::EventId,
         service::stale_resource_cleanup::{
-            remove_data_segment_of_port, remove_receiver_port_from_all_connections,
-            remove_sender_port_from_all_connections,
+            remove_data_segment_of_port, remove_delivery_listener_of_port,
+            remove_receiver_port_from_all_connections, remove_sender_port_from_all_connections,
         },
     };
 
@@ -485,6 +573,80 @@ pub(crate) mod internal {
         trace!(from origin, "Send dead node signal on service {}.", service_name);
     }
 
+    /// Publishes the [`ServiceAnnouncement`] with the discovery announcer of the provided
+    /// node. Announcements are only hints, a lost announcement is detected by the next
+    /// periodic [`Service::list()`] call of the discovery service.
+    pub(crate) fn announce_service_change<S: Service>(
+        shared_node: &Arc<SharedNode<S>>,
+        announcement: ServiceAnnouncement,
+    ) {
+        let origin = "announce_service_change()";
+        let config = shared_node.config();
+
+        if !config.global.service.enable_discovery_announcements {
+            return;
+        }
+
+        // the announcement service itself is not announced, this also ensures that the
+        // announcer is never locked recursively when its own service is released
+        let service_name = __internal_discovery_announcement_service_name();
+        if *announcement.static_config().name() == service_name {
+            return;
+        }
+
+        let mut announcer = shared_node.discovery_announcer();
+        if let DiscoveryAnnouncer::Unopened = *announcer {
+            match S::does_exist(&service_name, config, MessagingPattern::PublishSubscribe) {
+                Ok(true) => (),
+                Ok(false) => return,
+                Err(e) => {
+                    warn!(from origin,
+                        "Unable to announce service change since the existence of the discovery announcement service could not be verified ({:?}).", e);
+                    return;
+                }
+            }
+
+            let service = match builder::Builder::new(&service_name, shared_node.clone())
+                .publish_subscribe::<ServiceAnnouncement>()
+                .open()
+            {
+                Ok(service) => service,
+                Err(PublishSubscribeOpenError::DoesNotExist) => return,
+                Err(e) => {
+                    warn!(from origin,
+                        "Unable to open discovery announcement service to announce service change ({:?}).", e);
+                    return;
+                }
+            };
+
+            match service
+                .publisher_builder()
+                .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+                .create()
+            {
+                Ok(publisher) => *announcer = DiscoveryAnnouncer::Open(publisher),
+                Err(e) => {
+                    warn!(from origin,
+                        "Unable to create publisher to announce service change, the change will only be detected by the next discovery cycle ({:?}).", e);
+                    return;
+                }
+            }
+        }
+
+        if let DiscoveryAnnouncer::Open(publisher) = &*announcer {
+            let result = publisher
+                .loan_uninit()
+                .map_err(SendError::from)
+                .and_then(|sample| sample.write_payload(announcement).send());
+            if let Err(e) = result {
+                warn!(from origin, "Unable to announce service change ({:?}).", e);
+                return;
+            }
+
+            trace!(from origin, "Announced service change.");
+        }
+    }
+
     fn remove_sender_connection_and_data_segment<S: Service>(
         id: u128,
         config: &config::Config,
@@ -522,6 +684,13 @@ pub(crate) mod internal {
             CleanupFailure
         })?;
 
+        unsafe { remove_delivery_listener_of_port::<S>(id, config) }.map_err(|e| {
+            debug!(from origin,
+                    "Failed to remove the {} ({:?}) delivery listener ({:?}).",
+                    port_name, id, e);
+            CleanupFailure
+        })?;
+
         Ok(())
     }
 
@@ -577,6 +746,13 @@ pub(crate) mod internal {
                             debug!(from origin, "Failed to remove the subscriber ({:?}) from all of its connections ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
+
+                        if let Err(e) =
+                            unsafe { remove_delivery_listener_of_port::<S>(id.value(), config) }
+                        {
+                            debug!(from origin, "Failed to remove the subscriber ({:?}) delivery listener ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
                     }
                     UniquePortId::Notifier(_) => {
                         number_of_dead_node_notifications += 1;
@@ -638,7 +814,9 @@ pub(crate) mod internal {
                 } {
                     Ok(_) => {
                         trace!(from origin, "Remove unused service.");
-                        dynamic_config.acquire_ownership()
+                        dynamic_config.acquire_ownership();
+                        // the removal is not announced since no node of the crashed creator
+                        // is available to publish it, see [`ServiceAnnouncement`]
                     }
                     Err(e) => {
                         warn!(from origin, "Unable to remove static config of unused service ({:?}).",
@@ -797,6 +975,41 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> + Clone {
 
         Ok(())
     }
+
+    /// Returns a list of all services created under a given [`config::Config`] whose
+    /// attributes satisfy the provided [`AttributeVerifier`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let verifier = AttributeVerifier::new().require(&"schema".try_into()?, &"v2".try_into()?);
+    /// ipc::Service::list_with_attributes(Config::global_config(), &verifier, |service| {
+    ///     println!("\n{:#?}", &service);
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_with_attributes<F: FnMut(ServiceDetails<Self>) -> CallbackProgression>(
+        config: &config::Config,
+        attribute_verifier: &AttributeVerifier,
+        mut callback: F,
+    ) -> Result<(), ServiceListError> {
+        Self::list(config, |service_details| {
+            if attribute_verifier
+                .verify_requirements(service_details.static_details.attributes())
+                .is_ok()
+            {
+                callback(service_details)
+            } else {
+                CallbackProgression::Continue
+            }
+        })
+    }
 }
 
 pub(crate) unsafe fn remove_static_service_config<S: Service>(
@@ -889,7 +1102,7 @@ fn details<S: Service>(
     }))
 }
 
-fn open_dynamic_config<S: Service>(
+pub(crate) fn open_dynamic_config<S: Service>(
     config: &config::Config,
     service_id: &ServiceId,
 ) -> Result<Option<S::DynamicStorage>, ServiceDetailsError> {
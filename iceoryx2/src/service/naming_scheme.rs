@@ -43,6 +43,15 @@ pub(crate) fn extract_receiver_port_id_from_connection(connection: &FileName) ->
     receiver_port_id.parse::<u128>().ok()
 }
 
+pub(crate) fn delivery_event_name(receiver_port_id: u128) -> FileName {
+    let msg = "The system does not support the required file name length for the delivery event.";
+    let origin = "delivery_event_name()";
+
+    fatal_panic!(from origin,
+                 when FileName::new(receiver_port_id.to_string().as_bytes()),
+                 "{}", msg)
+}
+
 pub(crate) fn data_segment_name(port_id_value: u128) -> FileName {
     let msg = "The system does not support the required file name length for the data segment.";
     let origin = "data_segment_name()";
@@ -15,21 +15,29 @@ use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
+use core::fmt::Debug;
+use core::marker::PhantomData;
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use super::nodes;
+use super::reader::PortFactoryReader;
+use super::writer::PortFactoryWriter;
 
 /// The factory for
 /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
 /// It can acquire dynamic and static service informations and create
 /// [`crate::port::reader::Reader`] or [`crate::port::writer::Writer`] ports.
 #[derive(Debug)]
-pub struct PortFactory<Service: service::Service> {
+pub struct PortFactory<Service: service::Service, KeyType: ZeroCopySend + Debug> {
     pub(crate) service: Service,
+    _key: PhantomData<KeyType>,
 }
 
-impl<Service: service::Service> crate::service::port_factory::PortFactory for PortFactory<Service> {
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug>
+    crate::service::port_factory::PortFactory for PortFactory<Service, KeyType>
+{
     type Service = Service;
     type StaticConfig = static_config::blackboard::StaticConfig;
     type DynamicConfig = dynamic_config::blackboard::DynamicConfig;
@@ -70,8 +78,21 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
     }
 }
 
-impl<Service: service::Service> PortFactory<Service> {
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug> PortFactory<Service, KeyType> {
     pub(crate) fn new(service: Service) -> Self {
-        Self { service }
+        Self {
+            service,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new [`crate::port::reader::Reader`] port.
+    pub fn reader_builder(&self) -> PortFactoryReader<'_, Service, KeyType> {
+        PortFactoryReader { factory: self }
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new [`crate::port::writer::Writer`] port.
+    pub fn writer_builder(&self) -> PortFactoryWriter<'_, Service, KeyType> {
+        PortFactoryWriter { factory: self }
     }
 }
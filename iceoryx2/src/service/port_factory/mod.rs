@@ -51,6 +51,12 @@ pub mod publisher;
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -57,7 +57,7 @@ impl<'factory, Service: service::Service> PortFactoryNotifier<'factory, Service>
     }
 
     /// Sets a default [`EventId`] for the [`Notifier`] that is used in
-    /// [`Notifier::notify()`]
+    /// [`Notifier::notify()`]. If it is not set, `EventId::new(0)` is used.
     pub fn default_event_id(mut self, value: EventId) -> Self {
         self.default_event_id = value;
         self
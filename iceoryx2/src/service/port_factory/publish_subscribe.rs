@@ -197,4 +197,38 @@ impl<
     pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, Payload, UserHeader> {
         PortFactoryPublisher::new(self)
     }
+
+    /// Returns the current shared memory usage of the data segments of all
+    /// [`crate::port::publisher::Publisher`]s of the [`Service`](crate::service::Service),
+    /// see [`MemoryUsage`](dynamic_config::publish_subscribe::MemoryUsage).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder().create()?;
+    /// let sample = publisher.loan()?;
+    ///
+    /// let usage = pubsub.memory_usage();
+    /// println!("allocated bytes: {}", usage.allocated_bytes);
+    /// println!("number of chunks: {}", usage.number_of_chunks);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_usage(&self) -> dynamic_config::publish_subscribe::MemoryUsage {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .memory_usage()
+    }
 }
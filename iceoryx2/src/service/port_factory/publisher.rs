@@ -77,6 +77,9 @@ pub(crate) struct LocalPublisherConfig {
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
     pub(crate) initial_max_slice_len: usize,
     pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) ownership_strength: u32,
+    pub(crate) use_huge_pages: bool,
+    pub(crate) numa_node: Option<usize>,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -119,6 +122,9 @@ impl<
                 degradation_callback: None,
                 initial_max_slice_len: self.config.initial_max_slice_len,
                 allocation_strategy: self.config.allocation_strategy,
+                ownership_strength: self.config.ownership_strength,
+                use_huge_pages: self.config.use_huge_pages,
+                numa_node: self.config.numa_node,
             },
             factory: self.factory,
         }
@@ -138,6 +144,16 @@ impl<
                 allocation_strategy: AllocationStrategy::Static,
                 degradation_callback: None,
                 initial_max_slice_len: 1,
+                ownership_strength: 0,
+                use_huge_pages: false,
+                numa_node: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_numa_node,
                 max_loaned_samples: factory
                     .service
                     .__internal_state()
@@ -173,6 +189,40 @@ impl<
         self
     }
 
+    /// Sets the ownership strength of the [`Publisher`]. If the service has exclusive
+    /// ownership, the [`crate::port::subscriber::Subscriber`]s receive only the samples of the
+    /// alive [`Publisher`] with the highest ownership strength. If multiple [`Publisher`]s
+    /// have the same strength, the one with the smallest
+    /// [`UniquePublisherId`](crate::port::port_identifiers::UniquePublisherId) owns the
+    /// service. Without exclusive ownership the strength has no effect.
+    pub fn ownership_strength(mut self, value: u32) -> Self {
+        self.config.ownership_strength = value;
+        self
+    }
+
+    /// Backs the data segment of the [`Publisher`] with huge pages on platforms that support
+    /// them, e.g. transparent huge pages on Linux. Reduces the TLB pressure when large payloads
+    /// are transmitted. Samples that span at least one huge page start at a huge page
+    /// boundary. If the huge pages cannot be obtained, the [`Publisher`] falls back to regular
+    /// pages, see [`Publisher::has_huge_pages()`]. The setting only affects the memory of
+    /// the [`Publisher`] and does not need to match the
+    /// [`crate::port::subscriber::Subscriber`]s. By default it is disabled.
+    pub fn use_huge_pages(mut self, value: bool) -> Self {
+        self.config.use_huge_pages = value;
+        self
+    }
+
+    /// Binds the data segment of the [`Publisher`] to the NUMA node with the id `node_id`.
+    /// On multi-socket systems the node should be the one of the CPUs that run the
+    /// [`Publisher`] and the [`crate::port::subscriber::Subscriber`]s to avoid cross-node
+    /// memory traffic. Systems without NUMA support ignore the setting, see
+    /// [`Publisher::numa_node()`]. Overrides
+    /// [`crate::config::PublishSubscribe::publisher_numa_node`].
+    pub fn numa_node(mut self, node_id: usize) -> Self {
+        self.config.numa_node = Some(node_id);
+        self
+    }
+
     /// Sets the [`DegradationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegradationAction`] measures will be taken.
@@ -217,6 +267,12 @@ impl<
     /// [`PortFactoryPublisher::initial_max_slice_len()`] is exhausted. This happens when the user
     /// acquires a more than max slice len in [`Publisher::loan_slice()`] or
     /// [`Publisher::loan_slice_uninit()`].
+    ///
+    /// Connected [`Subscriber`](crate::port::subscriber::Subscriber)s map the grown data
+    /// segments on demand and therefore receive the larger slices without any further action.
+    /// A [`Subscriber`](crate::port::subscriber::Subscriber) that cannot handle slices above a
+    /// certain length can define an upper limit with
+    /// [`PortFactorySubscriber::max_slice_len()`](crate::service::port_factory::subscriber::PortFactorySubscriber::max_slice_len()).
     pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
         self.config.allocation_strategy = value;
         self
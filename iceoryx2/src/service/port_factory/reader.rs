@@ -0,0 +1,55 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<u32>(0, 0)
+//!     .create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+
+use crate::port::reader::{Reader, ReaderCreateError};
+use crate::service;
+
+use super::blackboard::PortFactory;
+
+/// Factory to create a new [`Reader`] port/endpoint for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based communication.
+#[derive(Debug, Clone)]
+pub struct PortFactoryReader<'factory, Service: service::Service, KeyType: ZeroCopySend + Debug> {
+    pub(crate) factory: &'factory PortFactory<Service, KeyType>,
+}
+
+impl<Service: service::Service, KeyType: ZeroCopySend + Debug>
+    PortFactoryReader<'_, Service, KeyType>
+{
+    /// Creates the [`Reader`] port or returns a [`ReaderCreateError`] on failure.
+    pub fn create(self) -> Result<Reader<Service, KeyType>, ReaderCreateError> {
+        Ok(fail!(from self, when Reader::new(&self.factory.service),
+                    "Failed to create new Reader port."))
+    }
+}
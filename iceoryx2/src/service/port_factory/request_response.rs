@@ -43,6 +43,7 @@ use core::{fmt::Debug, marker::PhantomData};
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::static_storage::StaticStorage;
 
 use crate::{
     node::NodeListFailure,
@@ -54,6 +55,26 @@ use crate::{
 
 use super::{client::PortFactoryClient, nodes, server::PortFactoryServer};
 
+/// The shared memory footprint of a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based [`Service`](crate::service::Service), acquired with [`PortFactory::memory_footprint()`].
+/// The pool sizes are the sizes the allocators of the data segments actually provide, including
+/// the padding of every chunk and all additional segments of dynamic data segments.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ServiceMemoryFootprint {
+    /// The sum of all other fields.
+    pub total_bytes: u64,
+    /// The number of bytes of the data segments of all
+    /// [`Client`](crate::port::client::Client)s that provide the requests.
+    pub request_pool_bytes: u64,
+    /// The number of bytes of the data segments of all
+    /// [`Server`](crate::port::server::Server)s that provide the responses.
+    pub response_pool_bytes: u64,
+    /// The number of bytes of the static and dynamic configuration of the
+    /// [`Service`](crate::service::Service).
+    pub metadata_bytes: u64,
+}
+
 /// The factory for
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
 /// It can acquire dynamic and static service informations and create
@@ -241,4 +262,59 @@ impl<
     {
         PortFactoryServer::new(self)
     }
+
+    /// Returns the [`ServiceMemoryFootprint`] of the
+    /// [`Service`](crate::service::Service). It contains the data segments of all
+    /// [`Client`](crate::port::client::Client)s and [`Server`](crate::port::server::Server)s
+    /// that are currently connected to the [`Service`](crate::service::Service), independent
+    /// of the process they were created in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let request_response = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = request_response.client_builder().create()?;
+    /// let server = request_response.server_builder().create()?;
+    ///
+    /// let footprint = request_response.memory_footprint();
+    /// println!("request pool: {} bytes", footprint.request_pool_bytes);
+    /// println!("response pool: {} bytes", footprint.response_pool_bytes);
+    /// println!("total: {} bytes", footprint.total_bytes);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_footprint(&self) -> ServiceMemoryFootprint {
+        let state = self.service.__internal_state();
+        let dynamic_config = state.dynamic_storage.get().request_response();
+        let static_config = state.static_config.request_response();
+        let dynamic_config_settings = dynamic_config::request_response::DynamicConfigSettings {
+            number_of_servers: static_config.max_servers(),
+            number_of_clients: static_config.max_clients(),
+            credits_per_client: static_config.credits_per_client().unwrap_or(0),
+        };
+
+        let request_pool_bytes = dynamic_config.request_pool_bytes();
+        let response_pool_bytes = dynamic_config.response_pool_bytes();
+        let metadata_bytes = state.static_storage.len()
+            + (core::mem::size_of::<dynamic_config::DynamicConfig>()
+                + dynamic_config::DynamicConfig::memory_size(static_config.max_nodes())
+                + dynamic_config::request_response::DynamicConfig::memory_size(
+                    &dynamic_config_settings,
+                )) as u64;
+
+        ServiceMemoryFootprint {
+            total_bytes: request_pool_bytes + response_pool_bytes + metadata_bytes,
+            request_pool_bytes,
+            response_pool_bytes,
+            metadata_bytes,
+        }
+    }
 }
@@ -64,6 +64,9 @@ pub enum ServerCreateError {
     /// [`ArcSyncPolicy`](iceoryx2_cal::arc_sync_policy::ArcSyncPolicy) defined in the
     /// [`Service`](crate::service::Service) as `ArcThreadSafetyPolicy`.
     FailedToDeployThreadsafetyPolicy,
+    /// The [`Service`](crate::service::Service) notifies on delivery but the underlying
+    /// resources to receive the notifications could not be created.
+    UnableToCreateDeliveryListener,
 }
 
 impl core::fmt::Display for ServerCreateError {
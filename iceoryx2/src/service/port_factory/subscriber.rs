@@ -46,6 +46,7 @@ use super::publish_subscribe::PortFactory;
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
+    pub(crate) max_slice_len: Option<usize>,
     pub(crate) degradation_callback: Option<DegradationCallback<'static>>,
 }
 
@@ -86,6 +87,7 @@ impl<
         Self {
             config: SubscriberConfig {
                 buffer_size: self.config.buffer_size,
+                max_slice_len: self.config.max_slice_len,
                 degradation_callback: None,
             },
             factory: self.factory,
@@ -96,6 +98,7 @@ impl<
         Self {
             config: SubscriberConfig {
                 buffer_size: None,
+                max_slice_len: None,
                 degradation_callback: None,
             },
             factory,
@@ -103,6 +106,10 @@ impl<
     }
 
     /// Defines the buffer size of the [`Subscriber`]. Smallest possible value is `1`.
+    /// Every [`Subscriber`] of a [`Service`](crate::service::Service) can have its own buffer
+    /// size as long as it does not exceed the services subscriber max buffer size, otherwise
+    /// [`PortFactorySubscriber::create()`] fails with
+    /// [`SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService`].
     pub fn buffer_size(mut self, value: usize) -> Self {
         self.config.buffer_size = Some(value.max(1));
         self
@@ -136,3 +143,24 @@ impl<
         )
     }
 }
+
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > PortFactorySubscriber<'_, Service, [Payload], UserHeader>
+{
+    /// Defines the maximum number of elements a received slice may contain. By default, the
+    /// [`Subscriber`] accepts slices of any length, including the ones of a
+    /// [`Publisher`](crate::port::publisher::Publisher) that grows its data segment with an
+    /// [`AllocationStrategy`](iceoryx2_cal::shm_allocator::AllocationStrategy) other than
+    /// [`AllocationStrategy::Static`](iceoryx2_cal::shm_allocator::AllocationStrategy::Static).
+    /// When a larger slice is received, it is discarded and
+    /// [`Subscriber::receive()`] fails with
+    /// [`ReceiveError::ExceedsMaxSliceLen`](crate::port::ReceiveError::ExceedsMaxSliceLen).
+    /// Smallest possible value is `1`.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.config.max_slice_len = Some(value.max(1));
+        self
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Allows to replace the memory of the data segments of all [`Service`](crate::service::Service)s
+//! that share the same [`Config`] with memory provided by a custom [`SegmentProvider`], for
+//! instance a pre-reserved and locked (`mlock`'d) memory pool.
+//!
+//! The [`SegmentProvider`] is used for the initial data segment of a port and for every
+//! data segment that is created when the port has to grow its memory. It must be registered
+//! with [`register_segment_provider()`] before the first [`Service`](crate::service::Service)
+//! is created or opened with the [`Config`] in the current process. Every process that
+//! participates in the communication must register an equivalent [`SegmentProvider`].
+
+extern crate alloc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
+
+use crate::config::Config;
+
+pub use iceoryx2_cal::shared_memory::segment_provider::{
+    SegmentMapping, SegmentProvider, SegmentProviderError,
+};
+
+/// Failures that can occur when a [`SegmentProvider`] is registered with
+/// [`register_segment_provider()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SegmentProviderRegistrationError {
+    /// A [`Service`](crate::service::Service) was already created or opened with the
+    /// [`Config`] in this process.
+    ServiceAlreadyCreated,
+    /// Another [`SegmentProvider`] is already registered for the [`Config`].
+    AlreadyRegistered,
+}
+
+impl core::fmt::Display for SegmentProviderRegistrationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SegmentProviderRegistrationError::{self:?}")
+    }
+}
+
+impl core::error::Error for SegmentProviderRegistrationError {}
+
+#[derive(Debug)]
+struct Registration {
+    prefix: FileName,
+    root_path: Path,
+    provider: Option<Arc<dyn SegmentProvider>>,
+    is_sealed: bool,
+}
+
+impl Registration {
+    fn is_for(&self, config: &Config) -> bool {
+        self.prefix == config.global.prefix && self.root_path == *config.global.root_path()
+    }
+}
+
+static REGISTRATIONS: Mutex<Vec<Registration>> = Mutex::new(Vec::new());
+
+fn with_registration<R, F: FnOnce(&mut Registration) -> R>(config: &Config, f: F) -> R {
+    let mut registrations = match REGISTRATIONS.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    match registrations.iter_mut().find(|r| r.is_for(config)) {
+        Some(registration) => f(registration),
+        None => {
+            registrations.push(Registration {
+                prefix: config.global.prefix.clone(),
+                root_path: config.global.root_path().clone(),
+                provider: None,
+                is_sealed: false,
+            });
+            f(registrations
+                .last_mut()
+                .expect("registration was just added"))
+        }
+    }
+}
+
+/// Registers a [`SegmentProvider`] that acquires the memory for all data segments of the
+/// [`Service`](crate::service::Service)s that use the provided [`Config`]. The [`Config`]
+/// is identified by its prefix and root path.
+///
+/// # Example
+///
+/// ```no_run
+/// use iceoryx2::prelude::*;
+/// use iceoryx2::service::segment_provider::*;
+/// # use std::sync::Arc;
+/// # fn my_locked_pool() -> Arc<dyn SegmentProvider> { unimplemented!() }
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let config = Config::global_config().clone();
+/// // must happen before the first service is created or opened with the config
+/// register_segment_provider(&config, my_locked_pool())?;
+///
+/// let node = NodeBuilder::new().config(&config).create::<ipc::Service>()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn register_segment_provider(
+    config: &Config,
+    provider: Arc<dyn SegmentProvider>,
+) -> Result<(), SegmentProviderRegistrationError> {
+    let msg = "Unable to register segment provider";
+    with_registration(config, |registration| {
+        if registration.is_sealed {
+            fail!(from "register_segment_provider()",
+                with SegmentProviderRegistrationError::ServiceAlreadyCreated,
+                "{} since a service was already created or opened with the config.", msg);
+        }
+
+        if registration.provider.is_some() {
+            fail!(from "register_segment_provider()",
+                with SegmentProviderRegistrationError::AlreadyRegistered,
+                "{} since another segment provider is already registered for the config.", msg);
+        }
+
+        registration.provider = Some(provider);
+        Ok(())
+    })
+}
+
+/// Prevents any further registration of a [`SegmentProvider`] for the [`Config`].
+pub(crate) fn seal(config: &Config) {
+    with_registration(config, |registration| registration.is_sealed = true)
+}
+
+/// Returns the [`SegmentProvider`] that is registered for the [`Config`].
+pub(crate) fn registered_segment_provider(config: &Config) -> Option<Arc<dyn SegmentProvider>> {
+    let registrations = match REGISTRATIONS.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    registrations
+        .iter()
+        .find(|r| r.is_for(config))
+        .and_then(|r| r.provider.clone())
+}
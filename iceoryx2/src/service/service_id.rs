@@ -18,7 +18,7 @@ use iceoryx2_bb_system_types::file_name::RestrictedFileName;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
 
-use super::{messaging_pattern::MessagingPattern, service_name::ServiceName};
+use super::{messaging_pattern::MessagingPattern, service_name::ServiceName, Service};
 
 const SERVICE_ID_CAPACITY: usize = 64;
 
@@ -43,6 +43,47 @@ impl ServiceId {
                    "This should never happen! The Hasher used to create the ServiceId created an illegal value ({value}, len = {}).", value.len()))
     }
 
+    /// Derives the [`ServiceId`] of a [`Service`] from its [`ServiceName`] and its
+    /// [`MessagingPattern`] without creating or opening the [`Service`]. It is the same
+    /// [`ServiceId`] that the [`Service`] acquires when it is created.
+    ///
+    /// The mapping is deterministic and stable across versions, a change of the mapping is a
+    /// breaking change and requires a major version bump.
+    ///
+    /// The [`ServiceId`] is the lowercase hex encoded SHA-1 hash of the decimal value of the
+    /// [`MessagingPattern`] followed by the [`ServiceName`], e.g. `"0My/Funk/ServiceName"` for
+    /// [`MessagingPattern::PublishSubscribe`]. Therefore, collisions are practically
+    /// impossible. If two distinct combinations of [`ServiceName`] and [`MessagingPattern`]
+    /// would collide they would share the same underlying resources and the second one would
+    /// open the [`Service`] of the first one.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::messaging_pattern::MessagingPattern;
+    /// use iceoryx2::service::service_id::ServiceId;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let service_id =
+    ///     ServiceId::from_name::<ipc::Service>(&service_name, MessagingPattern::PublishSubscribe);
+    ///
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node
+    ///     .service_builder(&service_name)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// assert_eq!(service.service_id(), &service_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_name<S: Service>(
+        service_name: &ServiceName,
+        messaging_pattern: MessagingPattern,
+    ) -> Self {
+        Self::new::<S::ServiceNameHasher>(service_name, messaging_pattern)
+    }
+
     /// Returns the maximum string length of a [`ServiceId`]
     pub const fn max_number_of_characters() -> usize {
         SERVICE_ID_CAPACITY
@@ -110,6 +110,13 @@ impl ServiceName {
              "This should never happen! The underlying service name does not contain a valid UTF-8 string.")
     }
 
+    /// Returns the remainder of the [`ServiceName`] without the provided prefix, e.g.
+    /// `"joint_state"` for the name `"robot/arm/joint_state"` and the prefix `"robot/arm/"`.
+    /// If the [`ServiceName`] does not start with the prefix it returns [`None`].
+    pub fn strip_topic_prefix(&self, prefix: &str) -> Option<&str> {
+        self.as_str().strip_prefix(prefix)
+    }
+
     /// Checks if a service is an internal iceoryx2 service.
     pub fn has_iox2_prefix(name: &str) -> bool {
         name.starts_with(INTERNAL_SERVICE_PREFIX)
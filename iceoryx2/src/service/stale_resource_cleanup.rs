@@ -19,8 +19,8 @@ use iceoryx2_cal::zero_copy_connection::{ZeroCopyConnection, ZeroCopyPortRemoveE
 
 use crate::config;
 use crate::service;
-use crate::service::config_scheme::data_segment_config;
-use crate::service::naming_scheme::data_segment_name;
+use crate::service::config_scheme::{data_segment_config, event_config};
+use crate::service::naming_scheme::{data_segment_name, delivery_event_name};
 
 use super::config_scheme::connection_config;
 use super::naming_scheme::extract_receiver_port_id_from_connection;
@@ -53,6 +53,25 @@ pub(crate) unsafe fn remove_data_segment_of_port<Service: service::Service>(
     Ok(())
 }
 
+pub(crate) unsafe fn remove_delivery_listener_of_port<Service: service::Service>(
+    port_id: u128,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_delivery_listener_of_port::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::Event as NamedConceptMgmt>::remove_cfg(
+            &delivery_event_name(port_id),
+            &event_config::<Service>(config),
+        ), "Unable to remove the ports ({port_id}) delivery listener."
+    );
+
+    Ok(())
+}
+
 fn connections<Service: service::Service>(
     origin: &str,
     msg: &str,
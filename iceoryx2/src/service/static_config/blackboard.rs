@@ -48,10 +48,10 @@ pub struct StaticConfig {
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_readers: config.defaults.blackboard.max_readers,
-            max_nodes: config.defaults.blackboard.max_nodes,
+            max_readers: defaults.blackboard.max_readers,
+            max_nodes: defaults.blackboard.max_nodes,
             type_details: TypeDetail::default(),
         }
     }
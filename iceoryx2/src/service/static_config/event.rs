@@ -64,19 +64,19 @@ pub struct StaticConfig {
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_notifiers: config.defaults.event.max_notifiers,
-            max_listeners: config.defaults.event.max_listeners,
-            max_nodes: config.defaults.event.max_nodes,
-            deadline: config.defaults.event.deadline.map(|v| Deadline {
+            max_notifiers: defaults.event.max_notifiers,
+            max_listeners: defaults.event.max_listeners,
+            max_nodes: defaults.event.max_nodes,
+            deadline: defaults.event.deadline.map(|v| Deadline {
                 creation_time: Time::default(),
                 value: v,
             }),
-            event_id_max_value: config.defaults.event.event_id_max_value,
-            notifier_created_event: config.defaults.event.notifier_created_event,
-            notifier_dropped_event: config.defaults.event.notifier_dropped_event,
-            notifier_dead_event: config.defaults.event.notifier_dead_event,
+            event_id_max_value: defaults.event.event_id_max_value,
+            notifier_created_event: defaults.event.notifier_created_event,
+            notifier_dropped_event: defaults.event.notifier_dropped_event,
+            notifier_dead_event: defaults.event.notifier_dead_event,
         }
     }
 
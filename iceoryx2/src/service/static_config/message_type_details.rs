@@ -50,9 +50,64 @@ pub enum TypeVariant {
     Dynamic,
 }
 
+/// Defines how the [`TypeDetail`]s of the payload and the user header are matched when an
+/// existing [`crate::service::Service`] is opened. The mode is stored in the static
+/// configuration of the [`crate::service::Service`] and all participants must use the same mode.
+#[repr(C)]
+#[derive(
+    Default, Debug, Clone, Copy, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize,
+)]
+pub enum TypeCompatibility {
+    #[default]
+    /// The type name, the [`TypeVariant`], the size and the alignment must match.
+    Strict,
+
+    /// Only the [`TypeVariant`], the size and the alignment must match, the type names may
+    /// differ. Useful when participants are written in different languages that name the same
+    /// type differently, like a C++ type in a namespace and its Rust counterpart.
+    LayoutOnly,
+}
+
 /// A fixed-size string type used to store type names.
 pub type TypeNameString = FixedSizeByteString<MAX_TYPE_NAME_LENGTH>;
 
+/// Module containing the validated type name that can override the type name of a payload or
+/// user header.
+mod type_name {
+    use core::hash::Hash;
+    use core::hash::Hasher;
+
+    use iceoryx2_bb_container::semantic_string;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_derive_macros::ZeroCopySend;
+    use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+    use crate::constants::MAX_TYPE_NAME_LENGTH;
+
+    semantic_string! {
+      /// Fixed string for type names that are defined explicitly.
+      name: FixedString,
+      capacity: MAX_TYPE_NAME_LENGTH,
+      invalid_content: |string: &[u8]| {
+        string.is_empty()
+      },
+      invalid_characters: |string: &[u8]| {
+        // only printable ASCII characters without whitespaces, so that every language can
+        // represent the name
+        string.iter().any(|c| !c.is_ascii_graphic())
+      },
+      normalize: |this: &FixedString| {
+          this.clone()
+      }
+    }
+}
+
+/// Type name that overrides the language specific name of a payload or user header type, e.g.
+/// `com.acme.Image`, so that participants written in different languages can agree on a
+/// neutral name. It must not be empty and consist only of printable ASCII characters without
+/// whitespaces.
+pub type TypeName = type_name::FixedString;
+
 /// Contains all type details required to connect to a [`crate::service::Service`]
 #[derive(Default, Debug, Clone, Eq, Hash, PartialEq, ZeroCopySend, Serialize, Deserialize)]
 #[repr(C)]
@@ -84,6 +139,90 @@ impl TypeDetail {
             alignment: core::mem::align_of::<T>(),
         }
     }
+
+    fn is_name_compatible_to(&self, rhs: &Self, type_compatibility: TypeCompatibility) -> bool {
+        match type_compatibility {
+            TypeCompatibility::Strict => self.type_name == rhs.type_name,
+            TypeCompatibility::LayoutOnly => true,
+        }
+    }
+
+    /// Returns the names of all properties that differ between `self` and `rhs`.
+    fn differing_properties(&self, rhs: &Self) -> Vec<&'static str> {
+        let mut properties = vec![];
+        if self.type_name != rhs.type_name {
+            properties.push("type name");
+        }
+        if self.variant != rhs.variant {
+            properties.push("variant");
+        }
+        if self.size != rhs.size {
+            properties.push("size");
+        }
+        if self.alignment != rhs.alignment {
+            properties.push("alignment");
+        }
+        properties
+    }
+
+    /// Returns the names of all properties that make `rhs` incompatible to `self`. The
+    /// alignment of `rhs` may be larger and, when `is_size_evolution_allowed` is set, the size
+    /// of a [`TypeVariant::FixedSize`] type may differ as well.
+    fn incompatible_properties(
+        &self,
+        rhs: &Self,
+        type_compatibility: TypeCompatibility,
+        is_size_evolution_allowed: bool,
+    ) -> Vec<&'static str> {
+        let is_size_compatible = match self.variant {
+            TypeVariant::FixedSize if is_size_evolution_allowed => true,
+            _ => self.size == rhs.size,
+        };
+
+        let mut properties = vec![];
+        if !self.is_name_compatible_to(rhs, type_compatibility) {
+            properties.push("type name");
+        }
+        if self.variant != rhs.variant {
+            properties.push("variant");
+        }
+        if !is_size_compatible {
+            properties.push("size");
+        }
+        if self.alignment > rhs.alignment {
+            properties.push("alignment");
+        }
+        properties
+    }
+
+    fn describe_mismatch(&self, part: &str, rhs: &Self, properties: &[&str]) -> String {
+        format!(
+            "{part} differs in {} (requested: {self}, offered: {rhs})",
+            properties.join(", ")
+        )
+    }
+
+    /// Returns a human readable description of every property in which `offered` differs from
+    /// `self`, naming the expected and actual type name, variant, size and alignment, or
+    /// [`None`] when both are identical.
+    pub(crate) fn mismatch_description(&self, part: &str, offered: &Self) -> Option<String> {
+        let properties = self.differing_properties(offered);
+        if properties.is_empty() {
+            None
+        } else {
+            Some(self.describe_mismatch(part, offered, &properties))
+        }
+    }
+}
+
+impl core::fmt::Display for TypeDetail {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "\"{}\" {{ variant: {:?}, size: {}, alignment: {} }}",
+            self.type_name, self.variant, self.size, self.alignment
+        )
+    }
 }
 
 /// Contains all type information to the header and payload type.
@@ -138,17 +277,111 @@ impl MessageTypeDetails {
         }
     }
 
-    pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
+    /// The inverse of [`MessageTypeDetails::sample_layout()`]. Returns the maximum number of
+    /// payload elements that fit into a sample of the given size.
+    pub(crate) fn max_number_of_elements(&self, sample_size: usize) -> usize {
+        if self.payload.size == 0 {
+            return 0;
+        }
+
+        let overhead = self.header.size + self.user_header.size + self.user_header.alignment - 1
+            + self.payload.alignment
+            - 1;
+        sample_size.saturating_sub(overhead) / self.payload.size
+    }
+
+    pub(crate) fn is_compatible_to(
+        &self,
+        rhs: &Self,
+        type_compatibility: TypeCompatibility,
+    ) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
+            && self
+                .user_header
+                .is_name_compatible_to(&rhs.user_header, type_compatibility)
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
+            && self
+                .payload
+                .is_name_compatible_to(&rhs.payload, type_compatibility)
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
     }
+
+    /// Returns a human readable description of every part (header, user header and payload)
+    /// that prevents `offered` from being compatible to `self` according to
+    /// [`MessageTypeDetails::is_compatible_to()`], or
+    /// [`MessageTypeDetails::is_evolution_compatible_to()`] when `is_type_evolution_enabled`
+    /// is set. Every entry names the differing properties together with the requested and the
+    /// offered type name, variant, size and alignment.
+    pub(crate) fn mismatch_description(
+        &self,
+        offered: &Self,
+        type_compatibility: TypeCompatibility,
+        is_type_evolution_enabled: bool,
+    ) -> String {
+        let mut mismatches = vec![];
+        if let Some(mismatch) = self.header.mismatch_description("header", &offered.header) {
+            mismatches.push(mismatch);
+        }
+
+        let user_header = self.user_header.incompatible_properties(
+            &offered.user_header,
+            type_compatibility,
+            false,
+        );
+        if !user_header.is_empty() {
+            mismatches.push(self.user_header.describe_mismatch(
+                "user header",
+                &offered.user_header,
+                &user_header,
+            ));
+        }
+
+        let payload = self.payload.incompatible_properties(
+            &offered.payload,
+            type_compatibility,
+            is_type_evolution_enabled,
+        );
+        if !payload.is_empty() {
+            mismatches.push(
+                self.payload
+                    .describe_mismatch("payload", &offered.payload, &payload),
+            );
+        }
+
+        mismatches.join("; ")
+    }
+
+    /// Like [`MessageTypeDetails::is_compatible_to()`] but a [`TypeVariant::FixedSize`] payload
+    /// of `rhs` is allowed to have another size. This is the case when one side uses an evolved
+    /// version of the payload type with additional trailing fields.
+    pub(crate) fn is_evolution_compatible_to(
+        &self,
+        rhs: &Self,
+        type_compatibility: TypeCompatibility,
+    ) -> bool {
+        let is_payload_size_compatible = match self.payload.variant {
+            TypeVariant::FixedSize => true,
+            TypeVariant::Dynamic => self.payload.size == rhs.payload.size,
+        };
+
+        self.header == rhs.header
+            && self
+                .user_header
+                .is_name_compatible_to(&rhs.user_header, type_compatibility)
+            && self.user_header.variant == rhs.user_header.variant
+            && self.user_header.size == rhs.user_header.size
+            && self.user_header.alignment <= rhs.user_header.alignment
+            && self
+                .payload
+                .is_name_compatible_to(&rhs.payload, type_compatibility)
+            && self.payload.variant == rhs.payload.variant
+            && is_payload_size_compatible
+            && self.payload.alignment <= rhs.payload.alignment
+    }
 }
 
 #[cfg(test)]
@@ -339,12 +572,45 @@ mod tests {
     fn test_is_compatible_to_failed_when_types_differ() {
         let left = MessageTypeDetails::from::<i64, i64, i8>(TypeVariant::FixedSize);
         let right = MessageTypeDetails::from::<i64, i64, u8>(TypeVariant::FixedSize);
-        let sut = left.is_compatible_to(&right);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::Strict);
         assert_that!(sut, eq false);
 
         let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
         let right = MessageTypeDetails::from::<i64, i64, i32>(TypeVariant::FixedSize);
-        let sut = left.is_compatible_to(&right);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq false);
+    }
+
+    #[test]
+    fn test_is_compatible_to_with_layout_only_ignores_type_names() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = left.clone();
+        right.user_header.type_name = "ns::Header".try_into().unwrap();
+        right.payload.type_name = "ns::Payload".try_into().unwrap();
+
+        let sut = left.is_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq false);
+
+        let sut = left.is_compatible_to(&right, TypeCompatibility::LayoutOnly);
+        assert_that!(sut, eq true);
+
+        let sut = left.is_evolution_compatible_to(&right, TypeCompatibility::LayoutOnly);
+        assert_that!(sut, eq true);
+    }
+
+    #[test]
+    fn test_is_compatible_to_with_layout_only_fails_when_layout_differs() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let right = MessageTypeDetails::from::<i64, i64, u32>(TypeVariant::FixedSize);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::LayoutOnly);
+        assert_that!(sut, eq false);
+
+        let right = MessageTypeDetails::from::<i64, i64, u64>(TypeVariant::Dynamic);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::LayoutOnly);
+        assert_that!(sut, eq false);
+
+        let right = MessageTypeDetails::from::<i64, u32, u64>(TypeVariant::FixedSize);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::LayoutOnly);
         assert_that!(sut, eq false);
     }
 
@@ -375,11 +641,11 @@ mod tests {
             },
         };
         // smaller to bigger is allowed.
-        let sut = left.is_compatible_to(&right);
+        let sut = left.is_compatible_to(&right, TypeCompatibility::Strict);
         assert_that!(sut, eq true);
 
         // bigger to smaller is invalid.
-        let sut = right.is_compatible_to(&left);
+        let sut = right.is_compatible_to(&left, TypeCompatibility::Strict);
         assert_that!(sut, eq false);
     }
 
@@ -410,7 +676,113 @@ mod tests {
             },
         };
         // bigger to smaller is invalid.
-        let sut = right.is_compatible_to(&left);
+        let sut = right.is_compatible_to(&left, TypeCompatibility::Strict);
         assert_that!(sut, eq false);
     }
+
+    #[test]
+    fn test_is_evolution_compatible_to_succeeds_when_payload_sizes_differ() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = left.clone();
+        right.payload.size = 2 * left.payload.size;
+
+        // the service may use a larger, evolved payload
+        let sut = left.is_evolution_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq true);
+
+        // or an older, smaller one
+        let sut = right.is_evolution_compatible_to(&left, TypeCompatibility::Strict);
+        assert_that!(sut, eq true);
+
+        // strict type checking still fails
+        let sut = left.is_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq false);
+    }
+
+    #[test]
+    fn test_is_evolution_compatible_to_fails_for_different_slice_element_sizes() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::Dynamic);
+        let mut right = left.clone();
+        right.payload.size = 2 * left.payload.size;
+
+        let sut = left.is_evolution_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq false);
+    }
+
+    #[test]
+    fn test_is_evolution_compatible_to_fails_when_types_differ() {
+        let left = MessageTypeDetails::from::<i64, i64, i32>(TypeVariant::FixedSize);
+        let right = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+
+        let sut = left.is_evolution_compatible_to(&right, TypeCompatibility::Strict);
+        assert_that!(sut, eq false);
+    }
+
+    #[test]
+    fn test_mismatch_description_names_differing_payload_size_and_alignment() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let right = MessageTypeDetails::from::<i64, i64, i32>(TypeVariant::FixedSize);
+
+        let sut = left.mismatch_description(&right, TypeCompatibility::LayoutOnly, false);
+        assert_that!(sut, eq format!(
+            "payload differs in size, alignment (requested: \"i64\" {{ variant: FixedSize, size: 8, alignment: {ALIGNMENT} }}, offered: \"i32\" {{ variant: FixedSize, size: 4, alignment: 4 }})"
+        ));
+
+        let sut = left.mismatch_description(&right, TypeCompatibility::Strict, false);
+        assert_that!(sut.starts_with("payload differs in type name, size, alignment"), eq true);
+    }
+
+    #[test]
+    fn test_mismatch_description_lists_every_incompatible_part() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = MessageTypeDetails::from::<i64, u8, i64>(TypeVariant::Dynamic);
+        right.header.type_name = "Header".try_into().unwrap();
+
+        let sut = left.mismatch_description(&right, TypeCompatibility::Strict, false);
+        assert_that!(sut.starts_with("header differs in type name (requested: \"i64\""), eq true);
+        assert_that!(sut.contains("; user header differs in type name, size, alignment ("), eq true);
+        assert_that!(sut.contains("; payload differs in variant ("), eq true);
+    }
+
+    #[test]
+    fn test_mismatch_description_is_empty_for_compatible_types() {
+        let left = MessageTypeDetails::from::<i64, i64, i64>(TypeVariant::FixedSize);
+        let mut right = left.clone();
+        right.payload.alignment = 2 * ALIGNMENT;
+
+        let sut = left.mismatch_description(&right, TypeCompatibility::Strict, false);
+        assert_that!(sut, is_empty);
+
+        right.payload.size = 2 * left.payload.size;
+        let sut = left.mismatch_description(&right, TypeCompatibility::Strict, true);
+        assert_that!(sut, is_empty);
+
+        let sut = left.mismatch_description(&right, TypeCompatibility::Strict, false);
+        assert_that!(sut.starts_with("payload differs in size ("), eq true);
+    }
+
+    #[test]
+    fn test_type_name_accepts_printable_ascii_names() {
+        use iceoryx2_bb_container::semantic_string::SemanticString;
+
+        let sut = TypeName::new(b"com.acme.Image");
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.as_bytes(), eq b"com.acme.Image");
+
+        let sut = TypeName::new(b"acme::Image<u8>");
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn test_type_name_rejects_invalid_names() {
+        use iceoryx2_bb_container::semantic_string::SemanticString;
+
+        assert_that!(TypeName::new(b""), is_err);
+        assert_that!(TypeName::new(b"com.acme Image"), is_err);
+        assert_that!(TypeName::new(b"com.acme.Image\n"), is_err);
+        assert_that!(TypeName::new("com.acme.Bild\u{00e4}".as_bytes()), is_err);
+        assert_that!(TypeName::new(&[b'a'; MAX_TYPE_NAME_LENGTH + 1]), is_err);
+        assert_that!(TypeName::new(&[b'a'; MAX_TYPE_NAME_LENGTH]), is_ok);
+    }
 }
@@ -57,8 +57,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern =
-            MessagingPattern::RequestResponse(request_response::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::RequestResponse(
+            request_response::StaticConfig::new(&config.defaults_for(service_name)),
+        );
         Self {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
@@ -74,7 +75,8 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern = MessagingPattern::Event(event::StaticConfig::new(config));
+        let messaging_pattern =
+            MessagingPattern::Event(event::StaticConfig::new(&config.defaults_for(service_name)));
         Self {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
@@ -90,8 +92,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern =
-            MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::PublishSubscribe(
+            publish_subscribe::StaticConfig::new(&config.defaults_for(service_name)),
+        );
         Self {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
@@ -107,7 +110,9 @@ impl StaticConfig {
         service_name: &ServiceName,
         config: &config::Config,
     ) -> Self {
-        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(config));
+        let messaging_pattern = MessagingPattern::Blackboard(blackboard::StaticConfig::new(
+            &config.defaults_for(service_name),
+        ));
         Self {
             service_id: ServiceId::new::<Hasher>(
                 service_name,
@@ -134,6 +139,11 @@ impl StaticConfig {
         &self.service_name
     }
 
+    pub(crate) fn set_name<Hasher: Hash>(&mut self, service_name: &ServiceName) {
+        self.service_id = ServiceId::new::<Hasher>(service_name, self.messaging_pattern.pattern());
+        self.service_name = service_name.clone();
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
@@ -28,12 +28,16 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("sample lifespan:                  {:?}", pubsub.static_config().sample_lifespan());
+//! println!("exclusive ownership:              {:?}", pubsub.static_config().has_exclusive_ownership());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use super::message_type_details::MessageTypeDetails;
+use core::time::Duration;
+
+use super::message_type_details::{MessageTypeDetails, TypeCompatibility};
 use crate::config;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
@@ -53,26 +57,32 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_send_timestamp: bool,
+    pub(crate) sample_lifespan: Option<Duration>,
+    pub(crate) enable_exclusive_ownership: bool,
+    pub(crate) enable_notify_on_delivery: bool,
     pub(crate) message_type_details: MessageTypeDetails,
+    pub(crate) type_compatibility: TypeCompatibility,
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_subscribers: config.defaults.publish_subscribe.max_subscribers,
-            max_publishers: config.defaults.publish_subscribe.max_publishers,
-            max_nodes: config.defaults.publish_subscribe.max_nodes,
-            history_size: config.defaults.publish_subscribe.publisher_history_size,
-            subscriber_max_buffer_size: config
-                .defaults
-                .publish_subscribe
-                .subscriber_max_buffer_size,
-            subscriber_max_borrowed_samples: config
-                .defaults
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_publishers: defaults.publish_subscribe.max_publishers,
+            max_nodes: defaults.publish_subscribe.max_nodes,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            subscriber_max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            subscriber_max_borrowed_samples: defaults
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
-            enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_safe_overflow: defaults.publish_subscribe.enable_safe_overflow,
+            enable_send_timestamp: defaults.publish_subscribe.enable_send_timestamp,
+            sample_lifespan: defaults.publish_subscribe.sample_lifespan,
+            enable_exclusive_ownership: defaults.publish_subscribe.enable_exclusive_ownership,
+            enable_notify_on_delivery: defaults.publish_subscribe.enable_notify_on_delivery,
             message_type_details: MessageTypeDetails::default(),
+            type_compatibility: TypeCompatibility::default(),
         }
     }
 
@@ -126,8 +136,42 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns true if the [`crate::port::publisher::Publisher`] captures a
+    /// [`Timestamp`](crate::service::header::publish_subscribe::Timestamp) for every
+    /// [`crate::sample::Sample`] it sends, otherwise false.
+    pub fn has_send_timestamp(&self) -> bool {
+        self.enable_send_timestamp
+    }
+
+    /// Returns the lifespan of a [`crate::sample::Sample`]. A [`crate::sample::Sample`] that
+    /// is older than the lifespan is discarded by the [`crate::port::subscriber::Subscriber`]
+    /// instead of being received. If no lifespan is set, it returns [`None`].
+    pub fn sample_lifespan(&self) -> Option<Duration> {
+        self.sample_lifespan
+    }
+
+    /// Returns true if the [`crate::port::subscriber::Subscriber`]s receive only the
+    /// [`crate::sample::Sample`]s of the alive [`crate::port::publisher::Publisher`] with the
+    /// highest ownership strength, otherwise false.
+    pub fn has_exclusive_ownership(&self) -> bool {
+        self.enable_exclusive_ownership
+    }
+
+    /// Returns true if every [`crate::port::subscriber::Subscriber`] is notified when a
+    /// [`crate::sample::Sample`] is delivered, so that it can be attached directly to a
+    /// [`WaitSet`](crate::waitset::WaitSet), otherwise false.
+    pub fn has_notify_on_delivery(&self) -> bool {
+        self.enable_notify_on_delivery
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
     }
+
+    /// Returns the [`TypeCompatibility`] that is used to match the type details when the
+    /// [`crate::service::Service`] is opened.
+    pub fn type_compatibility(&self) -> TypeCompatibility {
+        self.type_compatibility
+    }
 }
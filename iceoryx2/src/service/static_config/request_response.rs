@@ -33,18 +33,21 @@
 //! println!("response safe overflow: {:?}", req_res.static_config().has_safe_overflow_for_responses());
 //! println!("max borrowed responses per pending response: {:?}", req_res.static_config().max_borrowed_responses_per_pending_response());
 //! println!("does support fire and forget requests: {:?}", req_res.static_config().does_support_fire_and_forget_requests());
+//! println!("does support multi response: {:?}", req_res.static_config().does_support_multi_response());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use serde::{Deserialize, Serialize};
 
 use crate::config;
 
-use super::message_type_details::MessageTypeDetails;
+use super::message_type_details::{MessageTypeDetails, TypeCompatibility};
 
 /// The static configuration of an
 /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
@@ -56,47 +59,60 @@ pub struct StaticConfig {
     pub(crate) enable_safe_overflow_for_requests: bool,
     pub(crate) enable_safe_overflow_for_responses: bool,
     pub(crate) enable_fire_and_forget_requests: bool,
+    pub(crate) enable_multi_response: bool,
+    pub(crate) enable_notify_on_delivery: bool,
     pub(crate) max_active_requests_per_client: usize,
+    pub(crate) max_request_queue_depth_per_client: Option<usize>,
     pub(crate) max_loaned_requests: usize,
     pub(crate) max_response_buffer_size: usize,
     pub(crate) max_servers: usize,
     pub(crate) max_clients: usize,
     pub(crate) max_nodes: usize,
     pub(crate) max_borrowed_responses_per_pending_response: usize,
+    pub(crate) max_request_payload_size: Option<usize>,
+    pub(crate) max_response_payload_size: Option<usize>,
+    pub(crate) response_deadline: Option<Duration>,
+    pub(crate) credits_per_client: Option<u32>,
     pub(crate) request_message_type_details: MessageTypeDetails,
     pub(crate) response_message_type_details: MessageTypeDetails,
+    pub(crate) type_compatibility: TypeCompatibility,
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            enable_safe_overflow_for_requests: config
-                .defaults
+            enable_safe_overflow_for_requests: defaults
                 .request_response
                 .enable_safe_overflow_for_requests,
-            enable_safe_overflow_for_responses: config
-                .defaults
+            enable_safe_overflow_for_responses: defaults
                 .request_response
                 .enable_safe_overflow_for_responses,
-            max_active_requests_per_client: config
-                .defaults
+            max_active_requests_per_client: defaults
                 .request_response
                 .max_active_requests_per_client,
-            max_response_buffer_size: config.defaults.request_response.max_response_buffer_size,
-            max_servers: config.defaults.request_response.max_servers,
-            max_clients: config.defaults.request_response.max_clients,
-            max_nodes: config.defaults.request_response.max_nodes,
-            max_borrowed_responses_per_pending_response: config
-                .defaults
+            max_request_queue_depth_per_client: defaults
+                .request_response
+                .max_request_queue_depth_per_client,
+            max_response_buffer_size: defaults.request_response.max_response_buffer_size,
+            max_servers: defaults.request_response.max_servers,
+            max_clients: defaults.request_response.max_clients,
+            max_nodes: defaults.request_response.max_nodes,
+            max_borrowed_responses_per_pending_response: defaults
                 .request_response
                 .max_borrowed_responses_per_pending_response,
-            max_loaned_requests: config.defaults.request_response.max_loaned_requests,
-            enable_fire_and_forget_requests: config
-                .defaults
+            max_loaned_requests: defaults.request_response.max_loaned_requests,
+            enable_fire_and_forget_requests: defaults
                 .request_response
                 .enable_fire_and_forget_requests,
+            enable_multi_response: defaults.request_response.enable_multi_response,
+            enable_notify_on_delivery: defaults.request_response.enable_notify_on_delivery,
+            max_request_payload_size: None,
+            max_response_payload_size: None,
+            response_deadline: defaults.request_response.response_deadline,
+            credits_per_client: None,
             request_message_type_details: MessageTypeDetails::default(),
             response_message_type_details: MessageTypeDetails::default(),
+            type_compatibility: TypeCompatibility::default(),
         }
     }
 
@@ -109,8 +125,8 @@ impl StaticConfig {
             // a client sent so many active requests to a server in parallel
             self.max_active_requests_per_client +
             // the server can still hold old requests that the client has already dropped. in this case
-            // the client can fill up the server's buffer with at most max_active_requests_per_client again
-            self.max_active_requests_per_client
+            // the client can fill up the server's request queue again
+            self.max_request_queue_depth_per_client()
         )
         // all chunks a client can loan in parallel
             + client_max_loaned_data
@@ -138,6 +154,12 @@ impl StaticConfig {
         &self.response_message_type_details
     }
 
+    /// Returns the [`TypeCompatibility`] that is used to match the type details when the
+    /// [`crate::service::Service`] is opened.
+    pub fn type_compatibility(&self) -> TypeCompatibility {
+        self.type_compatibility
+    }
+
     /// Returns true if the request buffer of the [`crate::service::Service`] safely overflows,
     /// otherwise false. Safe overflow means that the [`crate::port::client::Client`] will
     /// recycle the oldest requests from the [`crate::port::server::Server`] when its buffer
@@ -160,6 +182,28 @@ impl StaticConfig {
         self.enable_fire_and_forget_requests
     }
 
+    /// Returns true if a [`Server`](crate::port::server::Server) can send arbitrary many
+    /// [`Response`](crate::response::Response)s per
+    /// [`ActiveRequest`](crate::active_request::ActiveRequest), otherwise false.
+    pub fn does_support_multi_response(&self) -> bool {
+        self.enable_multi_response
+    }
+
+    /// Returns true if the [`Server`](crate::port::server::Server) is notified when a request
+    /// and the [`PendingResponse`](crate::pending_response::PendingResponse) when a
+    /// [`Response`](crate::response::Response) is delivered, so that both can be attached
+    /// directly to a [`WaitSet`](crate::waitset::WaitSet), otherwise false.
+    pub fn has_notify_on_delivery(&self) -> bool {
+        self.enable_notify_on_delivery
+    }
+
+    /// Returns the number of requests a [`Client`](crate::port::client::Client) can send
+    /// before a [`Server`](crate::port::server::Server) acknowledged them when credit flow
+    /// control is enabled, otherwise [`None`].
+    pub fn credits_per_client(&self) -> Option<u32> {
+        self.credits_per_client
+    }
+
     /// Returns the maximum number of borrowed [`Response`](crate::response::Response)s a
     /// [`Client`](`crate::port::client::Client`) can hold in
     /// parallel per [`PendingResponse`](crate::pending_response::PendingResponse)
@@ -173,6 +217,14 @@ impl StaticConfig {
         self.max_active_requests_per_client
     }
 
+    /// Returns the depth of the queue in which the requests of a [`crate::port::client::Client`]
+    /// wait until the [`crate::port::server::Server`] receives them. It is never larger than
+    /// [`StaticConfig::max_active_requests_per_client()`].
+    pub fn max_request_queue_depth_per_client(&self) -> usize {
+        self.max_request_queue_depth_per_client
+            .unwrap_or(self.max_active_requests_per_client)
+    }
+
     /// Returns the maximum buffer size for responses for an active request.
     pub fn max_response_buffer_size(&self) -> usize {
         self.max_response_buffer_size
@@ -201,4 +253,25 @@ impl StaticConfig {
     pub fn max_nodes(&self) -> usize {
         self.max_nodes
     }
+
+    /// Returns the maximum slice length of a request payload. If it is [`None`], the slice
+    /// length is only limited by the [`Client`](crate::port::client::Client)s configuration.
+    pub fn max_request_payload_size(&self) -> Option<usize> {
+        self.max_request_payload_size
+    }
+
+    /// Returns the maximum slice length of a response payload. If it is [`None`], the slice
+    /// length is only limited by the [`Server`](crate::port::server::Server)s configuration.
+    pub fn max_response_payload_size(&self) -> Option<usize> {
+        self.max_response_payload_size
+    }
+
+    /// Returns the response deadline of the service. If a
+    /// [`Server`](crate::port::server::Server) does not respond to a request within the
+    /// deadline, the [`PendingResponse`](crate::pending_response::PendingResponse) has missed
+    /// its deadline, see
+    /// [`PendingResponse::has_missed_deadline()`](crate::pending_response::PendingResponse::has_missed_deadline()).
+    pub fn response_deadline(&self) -> Option<Duration> {
+        self.response_deadline
+    }
 }
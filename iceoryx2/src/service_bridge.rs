@@ -0,0 +1,571 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Forwards the [`Sample`](crate::sample::Sample)s of a
+//! [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! [`Service`](crate::service::Service) to a [`Service`](crate::service::Service) of another
+//! type with the same name, for instance from a [`local::Service`](crate::service::local::Service)
+//! into an [`ipc::Service`](crate::service::ipc::Service) or vice versa.
+//!
+//! The [`ServiceBridge`] consists of a [`Subscriber`] on the source and a [`Publisher`] on the
+//! target [`Service`](crate::service::Service). Since both services share the same payload and
+//! user header type, the payload is forwarded with a single memory copy from the sources data
+//! segment into the targets data segment without any serialization. A true zero-copy
+//! forwarding is not possible since the data segments of different
+//! [`Service`](crate::service::Service) types are not accessible from each other.
+//!
+//! # Threading
+//!
+//! Like every other iceoryx2 construct, the [`ServiceBridge`] does not spawn any threads. The
+//! forwarding is performed whenever [`ServiceBridge::forward()`] is called, therefore the user
+//! decides in which thread and with which frequency this happens. It can be called in the
+//! event loop of [`Node::wait()`](crate::node::Node::wait()) or of a
+//! [`WaitSet`](crate::waitset::WaitSet), or it can be moved into a dedicated thread when
+//! the threadsafe [`Service`](crate::service::Service) variants like
+//! [`ipc_threadsafe::Service`](crate::service::ipc_threadsafe::Service) are used.
+//!
+//! # Backpressure
+//!
+//! When the target [`Publisher`] cannot loan another [`SampleMut`](crate::sample_mut::SampleMut)
+//! the [`BackpressureStrategy`] defines if the source [`Sample`](crate::sample::Sample) is
+//! retained until the next [`ServiceBridge::forward()`] call or discarded. When the target
+//! [`Subscriber`]s buffers are full, the configured [`UnableToDeliverStrategy`] of the target
+//! [`Publisher`] is applied.
+//!
+//! # Loop Prevention
+//!
+//! When two [`ServiceBridge`]s forward in opposite directions between the same
+//! [`Service`](crate::service::Service)s, every [`Sample`](crate::sample::Sample) would be
+//! forwarded back and forth. To prevent this, each [`ServiceBridge`] must ignore the
+//! [`Sample`](crate::sample::Sample)s of the target [`Publisher`] of the other one with
+//! [`ServiceBridge::ignore_publisher()`]. All other [`Sample`](crate::sample::Sample)s are
+//! forwarded, also the ones that are published by the [`Node`](crate::node::Node) of the
+//! [`ServiceBridge`] itself.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service_bridge::ServiceBridgeBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! # let local_node = NodeBuilder::new().create::<local::Service>()?;
+//! # let ipc_node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service_name: ServiceName = "My/Funk/ServiceName".try_into()?;
+//! # let local_service = local_node.service_builder(&service_name)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! # let ipc_service = ipc_node.service_builder(&service_name)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! let mut local_to_ipc = ServiceBridgeBuilder::new(&local_service, &ipc_service).create()?;
+//! let mut ipc_to_local = ServiceBridgeBuilder::new(&ipc_service, &local_service).create()?;
+//!
+//! local_to_ipc.ignore_publisher(ipc_to_local.publisher().id());
+//! ipc_to_local.ignore_publisher(local_to_ipc.publisher().id());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service_bridge::{BackpressureStrategy, ServiceBridgeBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn core::error::Error>> {
+//! let local_node = NodeBuilder::new().create::<local::Service>()?;
+//! let ipc_node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service_name: ServiceName = "My/Funk/ServiceName".try_into()?;
+//!
+//! let local_service = local_node.service_builder(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let ipc_service = ipc_node.service_builder(&service_name)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let mut bridge = ServiceBridgeBuilder::new(&local_service, &ipc_service)
+//!     .backpressure_strategy(BackpressureStrategy::RetainSample)
+//!     .create()?;
+//!
+//! # let local_publisher = local_service.publisher_builder().create()?;
+//! # local_publisher.send_copy(1234)?;
+//! let number_of_forwarded_samples = bridge.forward()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::shm_allocator::AllocationStrategy;
+
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::port::publisher::{Publisher, PublisherCreateError};
+use crate::port::subscriber::{Subscriber, SubscriberCreateError};
+use crate::port::unable_to_deliver_strategy::UnableToDeliverStrategy;
+use crate::port::{LoanError, ReceiveError, SendError};
+use crate::service;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::publisher::PortFactoryPublisher;
+
+/// Defines what the [`ServiceBridge`] shall do with a received
+/// [`Sample`](crate::sample::Sample) when the target [`Publisher`] is unable to loan memory
+/// since it either holds already the maximum number of loans or its data segment is exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressureStrategy {
+    /// The [`Sample`](crate::sample::Sample) stays in the source
+    /// [`Subscriber`] and [`ServiceBridge::forward()`] returns. The next call retries the
+    /// forwarding. When the backpressure persists, the source
+    /// [`Subscriber`]s buffer fills up and the source
+    /// [`Publisher`]s [`UnableToDeliverStrategy`] is applied.
+    #[default]
+    RetainSample,
+    /// The [`Sample`](crate::sample::Sample) is discarded and counted in
+    /// [`ServiceBridge::number_of_discarded_samples()`].
+    DiscardSample,
+}
+
+/// Failures that can occur when a [`ServiceBridge`] is created with
+/// [`ServiceBridgeBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceBridgeCreateError {
+    /// The [`Subscriber`] of the source [`Service`](crate::service::Service) could not be
+    /// created.
+    UnableToCreateSubscriber(SubscriberCreateError),
+    /// The [`Publisher`] of the target [`Service`](crate::service::Service) could not be
+    /// created.
+    UnableToCreatePublisher(PublisherCreateError),
+}
+
+impl core::fmt::Display for ServiceBridgeCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceBridgeCreateError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceBridgeCreateError {}
+
+/// Failures that can occur when [`Sample`](crate::sample::Sample)s are forwarded with
+/// [`ServiceBridge::forward()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceBridgeForwardError {
+    /// The [`Sample`](crate::sample::Sample) could not be received from the source
+    /// [`Service`](crate::service::Service).
+    ReceiveError(ReceiveError),
+    /// The [`Sample`](crate::sample::Sample) could not be loaned or sent on the target
+    /// [`Service`](crate::service::Service). The [`Sample`](crate::sample::Sample) is
+    /// discarded.
+    SendError(SendError),
+}
+
+impl From<ReceiveError> for ServiceBridgeForwardError {
+    fn from(value: ReceiveError) -> Self {
+        ServiceBridgeForwardError::ReceiveError(value)
+    }
+}
+
+impl From<SendError> for ServiceBridgeForwardError {
+    fn from(value: SendError) -> Self {
+        ServiceBridgeForwardError::SendError(value)
+    }
+}
+
+impl From<LoanError> for ServiceBridgeForwardError {
+    fn from(value: LoanError) -> Self {
+        ServiceBridgeForwardError::SendError(SendError::LoanError(value))
+    }
+}
+
+impl core::fmt::Display for ServiceBridgeForwardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(f, "ServiceBridgeForwardError::{self:?}")
+    }
+}
+
+impl core::error::Error for ServiceBridgeForwardError {}
+
+/// Creates a [`ServiceBridge`] that forwards all [`Sample`](crate::sample::Sample)s from the
+/// source to the target [`Service`](crate::service::Service).
+#[derive(Debug)]
+pub struct ServiceBridgeBuilder<
+    'a,
+    Source: service::Service,
+    Target: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    source: &'a PortFactory<Source, Payload, UserHeader>,
+    target: &'a PortFactory<Target, Payload, UserHeader>,
+    backpressure_strategy: BackpressureStrategy,
+    unable_to_deliver_strategy: Option<UnableToDeliverStrategy>,
+    subscriber_buffer_size: Option<usize>,
+    max_samples_per_forward: usize,
+    initial_max_slice_len: usize,
+}
+
+impl<
+        'a,
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridgeBuilder<'a, Source, Target, Payload, UserHeader>
+{
+    /// Creates a new [`ServiceBridgeBuilder`] that forwards from the `source` to the `target`
+    /// [`Service`](crate::service::Service).
+    pub fn new(
+        source: &'a PortFactory<Source, Payload, UserHeader>,
+        target: &'a PortFactory<Target, Payload, UserHeader>,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            backpressure_strategy: BackpressureStrategy::default(),
+            unable_to_deliver_strategy: None,
+            subscriber_buffer_size: None,
+            max_samples_per_forward: usize::MAX,
+            initial_max_slice_len: 1,
+        }
+    }
+
+    /// Defines the [`BackpressureStrategy`] that is applied when the target [`Publisher`]
+    /// is unable to loan memory.
+    pub fn backpressure_strategy(mut self, value: BackpressureStrategy) -> Self {
+        self.backpressure_strategy = value;
+        self
+    }
+
+    /// Defines the [`UnableToDeliverStrategy`] of the target [`Publisher`] that is applied
+    /// when a target [`Subscriber`]s buffer is full. When it is not set, the default of the
+    /// target [`Service`](crate::service::Service) is used.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.unable_to_deliver_strategy = Some(value);
+        self
+    }
+
+    /// Defines the buffer size of the source [`Subscriber`]. When it is not set, the maximum
+    /// buffer size of the source [`Service`](crate::service::Service) is used.
+    pub fn subscriber_buffer_size(mut self, value: usize) -> Self {
+        self.subscriber_buffer_size = Some(value);
+        self
+    }
+
+    /// Defines the maximum number of [`Sample`](crate::sample::Sample)s that are forwarded in
+    /// one [`ServiceBridge::forward()`] call so that a busy source does not starve the
+    /// remaining tasks of the calling thread. Smallest possible value is `1`. By default,
+    /// all available [`Sample`](crate::sample::Sample)s are forwarded.
+    pub fn max_samples_per_forward(mut self, value: usize) -> Self {
+        self.max_samples_per_forward = value.max(1);
+        self
+    }
+
+    fn create_impl<
+        F: FnOnce(
+            PortFactoryPublisher<'a, Target, Payload, UserHeader>,
+        ) -> PortFactoryPublisher<'a, Target, Payload, UserHeader>,
+    >(
+        self,
+        configure_publisher: F,
+    ) -> Result<ServiceBridge<Source, Target, Payload, UserHeader>, ServiceBridgeCreateError> {
+        let msg = "Unable to create ServiceBridge";
+        let mut subscriber_builder = self.source.subscriber_builder();
+        if let Some(buffer_size) = self.subscriber_buffer_size {
+            subscriber_builder = subscriber_builder.buffer_size(buffer_size);
+        }
+
+        let subscriber = match subscriber_builder.create() {
+            Ok(subscriber) => subscriber,
+            Err(e) => {
+                fail!(from self, with ServiceBridgeCreateError::UnableToCreateSubscriber(e),
+                    "{msg} since the subscriber of the source service could not be created ({e:?}).");
+            }
+        };
+
+        let mut publisher_builder = self.target.publisher_builder();
+        if let Some(strategy) = self.unable_to_deliver_strategy {
+            publisher_builder = publisher_builder.unable_to_deliver_strategy(strategy);
+        }
+
+        let publisher = match configure_publisher(publisher_builder).create() {
+            Ok(publisher) => publisher,
+            Err(e) => {
+                fail!(from self, with ServiceBridgeCreateError::UnableToCreatePublisher(e),
+                    "{msg} since the publisher of the target service could not be created ({e:?}).");
+            }
+        };
+
+        Ok(ServiceBridge {
+            ignored_publishers: Vec::new(),
+            subscriber,
+            publisher,
+            backpressure_strategy: self.backpressure_strategy,
+            max_samples_per_forward: self.max_samples_per_forward,
+            number_of_discarded_samples: 0,
+        })
+    }
+}
+
+impl<
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridgeBuilder<'_, Source, Target, Payload, UserHeader>
+{
+    /// Creates the [`ServiceBridge`] or returns a [`ServiceBridgeCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<ServiceBridge<Source, Target, Payload, UserHeader>, ServiceBridgeCreateError> {
+        self.create_impl(|publisher_builder| publisher_builder)
+    }
+}
+
+impl<
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridgeBuilder<'_, Source, Target, [Payload], UserHeader>
+{
+    /// Defines the initial max slice len of the target [`Publisher`]. The target
+    /// [`Publisher`] uses [`AllocationStrategy::PowerOfTwo`] so that it can forward slices
+    /// of any length.
+    pub fn initial_max_slice_len(mut self, value: usize) -> Self {
+        self.initial_max_slice_len = value;
+        self
+    }
+
+    /// Creates the [`ServiceBridge`] or returns a [`ServiceBridgeCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<ServiceBridge<Source, Target, [Payload], UserHeader>, ServiceBridgeCreateError>
+    {
+        let initial_max_slice_len = self.initial_max_slice_len;
+        self.create_impl(|publisher_builder| {
+            publisher_builder
+                .initial_max_slice_len(initial_max_slice_len)
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+        })
+    }
+}
+
+/// Forwards all [`Sample`](crate::sample::Sample)s from the source to the target
+/// [`Service`](crate::service::Service) whenever [`ServiceBridge::forward()`] is called.
+/// Created with the [`ServiceBridgeBuilder`].
+#[derive(Debug)]
+pub struct ServiceBridge<
+    Source: service::Service,
+    Target: service::Service,
+    Payload: Debug + ZeroCopySend + ?Sized + 'static,
+    UserHeader: Debug + ZeroCopySend,
+> {
+    subscriber: Subscriber<Source, Payload, UserHeader>,
+    publisher: Publisher<Target, Payload, UserHeader>,
+    ignored_publishers: Vec<UniquePublisherId>,
+    backpressure_strategy: BackpressureStrategy,
+    max_samples_per_forward: usize,
+    number_of_discarded_samples: u64,
+}
+
+impl<
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridge<Source, Target, Payload, UserHeader>
+{
+    /// Returns the [`Subscriber`] that receives from the source
+    /// [`Service`](crate::service::Service).
+    pub fn subscriber(&self) -> &Subscriber<Source, Payload, UserHeader> {
+        &self.subscriber
+    }
+
+    /// Returns the [`Publisher`] that sends to the target
+    /// [`Service`](crate::service::Service).
+    pub fn publisher(&self) -> &Publisher<Target, Payload, UserHeader> {
+        &self.publisher
+    }
+
+    /// Returns the number of [`Sample`](crate::sample::Sample)s that were discarded due to
+    /// [`BackpressureStrategy::DiscardSample`].
+    pub fn number_of_discarded_samples(&self) -> u64 {
+        self.number_of_discarded_samples
+    }
+
+    /// [`Sample`](crate::sample::Sample)s that were sent by the [`Publisher`] with the
+    /// provided [`UniquePublisherId`] are received but not forwarded. Used to prevent that
+    /// two [`ServiceBridge`]s, which forward in opposite directions, forward the
+    /// [`Sample`](crate::sample::Sample)s of each other in a loop.
+    pub fn ignore_publisher(&mut self, publisher_id: UniquePublisherId) {
+        if !self.ignored_publishers.contains(&publisher_id) {
+            self.ignored_publishers.push(publisher_id);
+        }
+    }
+
+    fn loan_failure_action(&mut self, error: LoanError) -> LoanFailureAction {
+        match error {
+            LoanError::ExceedsMaxLoans | LoanError::OutOfMemory => {
+                match self.backpressure_strategy {
+                    BackpressureStrategy::RetainSample => LoanFailureAction::Retain,
+                    BackpressureStrategy::DiscardSample => {
+                        self.number_of_discarded_samples += 1;
+                        LoanFailureAction::Discard
+                    }
+                }
+            }
+            _ => LoanFailureAction::Fail,
+        }
+    }
+}
+
+enum LoanFailureAction {
+    Retain,
+    Discard,
+    Fail,
+}
+
+impl<
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridge<Source, Target, Payload, UserHeader>
+{
+    /// Forwards the received [`Sample`](crate::sample::Sample)s from the source to the target
+    /// [`Service`](crate::service::Service) and returns the number of forwarded
+    /// [`Sample`](crate::sample::Sample)s. Returns when either no more
+    /// [`Sample`](crate::sample::Sample)s are available, the configured max samples per
+    /// forward were reached or the target [`Publisher`] applies backpressure with
+    /// [`BackpressureStrategy::RetainSample`].
+    pub fn forward(&mut self) -> Result<usize, ServiceBridgeForwardError> {
+        let mut number_of_forwarded_samples = 0;
+        while number_of_forwarded_samples < self.max_samples_per_forward {
+            let sample = match self.subscriber.peek()? {
+                Some(sample) => sample,
+                None => break,
+            };
+
+            if self
+                .ignored_publishers
+                .contains(&sample.header().publisher_id())
+            {
+                drop(self.subscriber.receive()?);
+                continue;
+            }
+
+            let mut target_sample = match self.publisher.loan_uninit() {
+                Ok(target_sample) => target_sample,
+                Err(e) => match self.loan_failure_action(e) {
+                    LoanFailureAction::Retain => break,
+                    LoanFailureAction::Discard => {
+                        drop(self.subscriber.receive()?);
+                        continue;
+                    }
+                    LoanFailureAction::Fail => {
+                        drop(self.subscriber.receive()?);
+                        fail!(from self, with ServiceBridgeForwardError::from(e),
+                            "Unable to forward sample since the target publisher is unable to loan memory ({e:?}).");
+                    }
+                },
+            };
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.user_header(),
+                    target_sample.user_header_mut(),
+                    1,
+                );
+                core::ptr::copy_nonoverlapping(
+                    sample.payload(),
+                    target_sample.payload_mut().as_mut_ptr(),
+                    1,
+                );
+            }
+
+            drop(self.subscriber.receive()?);
+            fail!(from self, when unsafe { target_sample.assume_init() }.send(),
+                "Unable to forward sample since it could not be sent to the target service.");
+            number_of_forwarded_samples += 1;
+        }
+
+        Ok(number_of_forwarded_samples)
+    }
+}
+
+impl<
+        Source: service::Service,
+        Target: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > ServiceBridge<Source, Target, [Payload], UserHeader>
+{
+    /// Forwards the received [`Sample`](crate::sample::Sample)s from the source to the target
+    /// [`Service`](crate::service::Service) and returns the number of forwarded
+    /// [`Sample`](crate::sample::Sample)s. Returns when either no more
+    /// [`Sample`](crate::sample::Sample)s are available, the configured max samples per
+    /// forward were reached or the target [`Publisher`] applies backpressure with
+    /// [`BackpressureStrategy::RetainSample`].
+    pub fn forward(&mut self) -> Result<usize, ServiceBridgeForwardError> {
+        let mut number_of_forwarded_samples = 0;
+        while number_of_forwarded_samples < self.max_samples_per_forward {
+            let sample = match self.subscriber.peek()? {
+                Some(sample) => sample,
+                None => break,
+            };
+
+            if self
+                .ignored_publishers
+                .contains(&sample.header().publisher_id())
+            {
+                drop(self.subscriber.receive()?);
+                continue;
+            }
+
+            let mut target_sample = match self.publisher.loan_slice_uninit(sample.payload().len()) {
+                Ok(target_sample) => target_sample,
+                Err(e) => match self.loan_failure_action(e) {
+                    LoanFailureAction::Retain => break,
+                    LoanFailureAction::Discard => {
+                        drop(self.subscriber.receive()?);
+                        continue;
+                    }
+                    LoanFailureAction::Fail => {
+                        drop(self.subscriber.receive()?);
+                        fail!(from self, with ServiceBridgeForwardError::from(e),
+                            "Unable to forward sample since the target publisher is unable to loan memory ({e:?}).");
+                    }
+                },
+            };
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.user_header(),
+                    target_sample.user_header_mut(),
+                    1,
+                );
+                core::ptr::copy_nonoverlapping(
+                    sample.payload().as_ptr(),
+                    target_sample.payload_mut().as_mut_ptr().cast(),
+                    sample.payload().len(),
+                );
+            }
+
+            drop(self.subscriber.receive()?);
+            fail!(from self, when unsafe { target_sample.assume_init() }.send(),
+                "Unable to forward sample since it could not be sent to the target service.");
+            number_of_forwarded_samples += 1;
+        }
+
+        Ok(number_of_forwarded_samples)
+    }
+}
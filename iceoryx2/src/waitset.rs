@@ -218,18 +218,22 @@ use core::{
     time::Duration,
 };
 use std::collections::HashMap;
+use std::time::Instant;
 
 use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
+    clock::Time,
     deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
-    file_descriptor::FileDescriptor,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
     file_descriptor_set::SynchronousMultiplexing,
     signal::SignalHandler,
 };
 use iceoryx2_cal::reactor::*;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
+use crate::pending_response::PendingResponse;
 use crate::signal_handling_mode::SignalHandlingMode;
 
 /// States why the [`WaitSet::wait_and_process()`] method returned.
@@ -253,6 +257,8 @@ pub enum WaitSetAttachmentError {
     InsufficientCapacity,
     /// The attachment is already attached.
     AlreadyAttached,
+    /// The attachment is not supported on the current platform or by the requested operation.
+    UnsupportedAttachment,
     /// An internal error has occurred.
     InternalError,
 }
@@ -330,7 +336,9 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
     /// [`WaitSet::attach_deadline()`].
     pub fn from_guard(guard: &WaitSetGuard<Service>) -> Self {
         match &guard.guard_type {
-            GuardType::Tick(t) => WaitSetAttachmentId::tick(guard.waitset, t.index()),
+            GuardType::Tick(t) | GuardType::TimePoint(t) => {
+                WaitSetAttachmentId::tick(guard.waitset, t.index())
+            }
             GuardType::Deadline(r, t) => WaitSetAttachmentId::deadline(
                 guard.waitset,
                 unsafe { r.file_descriptor().native_handle() },
@@ -434,11 +442,20 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
     }
 }
 
+// an attachment that is ready in the current wakeup
+#[derive(Debug)]
+struct ReadyAttachment<Service: crate::service::Service> {
+    priority: u8,
+    sequence_number: usize,
+    attachment_id: WaitSetAttachmentId<Service>,
+}
+
 enum GuardType<'waitset, 'attachment, Service: crate::service::Service>
 where
     Service::Reactor: 'waitset,
 {
     Tick(DeadlineQueueGuard<'waitset>),
+    TimePoint(DeadlineQueueGuard<'waitset>),
     Deadline(
         <Service::Reactor as Reactor>::Guard<'waitset, 'attachment>,
         DeadlineQueueGuard<'waitset>,
@@ -456,11 +473,48 @@ where
     guard_type: GuardType<'waitset, 'attachment, Service>,
 }
 
+impl<Service: crate::service::Service> WaitSetGuard<'_, '_, Service> {
+    /// Re-arms an attachment that was created with [`WaitSet::attach_deadline_at()`] so that
+    /// it fires once at the provided [`Instant`]. This allows to realize a cyclic schedule with
+    /// a single attachment. If the [`Instant`] is already in the past, the attachment fires
+    /// with the next wakeup of the [`WaitSet`].
+    ///
+    /// Fails with [`WaitSetAttachmentError::UnsupportedAttachment`] when the guard belongs to
+    /// any other kind of attachment.
+    pub fn reset_to(&self, deadline: Instant) -> Result<(), WaitSetAttachmentError> {
+        match &self.guard_type {
+            GuardType::TimePoint(t) => {
+                t.reset_to(self.waitset.to_deadline_queue_time_point(deadline)?);
+                Ok(())
+            }
+            _ => {
+                fail!(from self.waitset, with WaitSetAttachmentError::UnsupportedAttachment,
+                    "Unable to reset the attachment to an absolute deadline since it was not attached with WaitSet::attach_deadline_at().");
+            }
+        }
+    }
+}
+
 impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
     fn drop(&mut self) {
-        if let GuardType::Deadline(r, t) = &self.guard_type {
-            self.waitset
-                .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
+        match &self.guard_type {
+            GuardType::Tick(t) | GuardType::TimePoint(t) => {
+                self.waitset.tick_priority.borrow_mut().remove(&t.index());
+            }
+            GuardType::Deadline(r, t) => {
+                let reactor_idx = unsafe { r.file_descriptor().native_handle() };
+                self.waitset.remove_deadline(reactor_idx, t.index());
+                self.waitset
+                    .reactor_priority
+                    .borrow_mut()
+                    .remove(&reactor_idx);
+            }
+            GuardType::Notification(r) => {
+                self.waitset
+                    .reactor_priority
+                    .borrow_mut()
+                    .remove(&unsafe { r.file_descriptor().native_handle() });
+            }
         }
         self.waitset.detach();
     }
@@ -502,6 +556,9 @@ impl WaitSetBuilder {
                 deadline_queue,
                 attachment_to_deadline: RefCell::new(HashMap::new()),
                 deadline_to_attachment: RefCell::new(HashMap::new()),
+                reactor_priority: RefCell::new(HashMap::new()),
+                tick_priority: RefCell::new(HashMap::new()),
+                ready_attachments: RefCell::new(Vec::new()),
                 attachment_counter: IoxAtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
             }),
@@ -513,15 +570,57 @@ impl WaitSetBuilder {
     }
 }
 
+/// A [`FileDescriptor`] that is attached to the reactor. A plain [`FileDescriptor`] is not
+/// [`SynchronousMultiplexing`] since not every platform can multiplex arbitrary file
+/// descriptors, an external one owned by the user is attached only via
+/// [`WaitSet::attach_external_notification()`] and [`WaitSet::attach_external_deadline()`]
+/// which verify the platform support first. All other file descriptors stem from an
+/// [`Attachable`] object.
+#[derive(Debug)]
+#[repr(transparent)]
+struct ExternalFileDescriptor(FileDescriptor);
+
+impl ExternalFileDescriptor {
+    fn from_ref(file_descriptor: &FileDescriptor) -> &Self {
+        // ExternalFileDescriptor is a transparent wrapper around FileDescriptor
+        unsafe { &*(file_descriptor as *const FileDescriptor).cast::<Self>() }
+    }
+}
+
+impl FileDescriptorBased for ExternalFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for ExternalFileDescriptor {}
+
+/// Is implemented by everything that can be attached to a [`WaitSet`]. Every
+/// [`SynchronousMultiplexing`] object is attachable. Ports like the
+/// [`Subscriber`](crate::port::subscriber::Subscriber) are only attachable when their
+/// [`Service`](crate::service::Service) notifies on delivery, otherwise attaching them fails
+/// with [`WaitSetAttachmentError::UnsupportedAttachment`].
+pub trait Attachable: Debug {
+    /// Returns the [`FileDescriptor`] the [`WaitSet`] waits on or [`None`] when the object
+    /// cannot be attached.
+    fn attachment_file_descriptor(&self) -> Option<&FileDescriptor>;
+}
+
+impl<T: SynchronousMultiplexing + Debug> Attachable for T {
+    fn attachment_file_descriptor(&self) -> Option<&FileDescriptor> {
+        Some(self.file_descriptor())
+    }
+}
+
 /// The [`WaitSet`] implements a reactor pattern and allows to wait on multiple events in one
 /// single call [`WaitSet::wait_and_process_once()`] until it wakes up or to run repeatedly with
 /// [`WaitSet::wait_and_process()`] until the a interrupt or termination signal was received or the user
 /// has explicitly requested to stop by returning [`CallbackProgression::Stop`] in the provided
 /// callback.
 ///
-/// An struct must implement [`SynchronousMultiplexing`] to be attachable. The
+/// An struct must implement [`Attachable`] to be attachable. The
 /// [`Listener`](crate::port::listener::Listener) can be attached as well as sockets or anything else that
-/// is [`FileDescriptorBased`](iceoryx2_bb_posix::file_descriptor::FileDescriptorBased).
+/// is [`SynchronousMultiplexing`].
 ///
 /// Can be created via the [`WaitSetBuilder`].
 #[derive(Debug)]
@@ -530,6 +629,9 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_queue: DeadlineQueue,
     attachment_to_deadline: RefCell<HashMap<i32, DeadlineQueueIndex>>,
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
+    reactor_priority: RefCell<HashMap<i32, u8>>,
+    tick_priority: RefCell<HashMap<DeadlineQueueIndex, u8>>,
+    ready_attachments: RefCell<Vec<ReadyAttachment<Service>>>,
     attachment_counter: IoxAtomicUsize,
     signal_handling_mode: SignalHandlingMode,
 }
@@ -546,6 +648,7 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
 
         self.attachment_counter.fetch_add(1, Ordering::Relaxed);
+        self.reserve_ready_attachments();
         Ok(())
     }
 
@@ -574,33 +677,151 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
-    fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+    fn set_reactor_priority(&self, reactor_idx: i32, priority: u8) {
+        if priority != 0 {
+            self.reactor_priority
+                .borrow_mut()
+                .insert(reactor_idx, priority);
+        }
+    }
+
+    fn set_tick_priority(&self, deadline_queue_idx: DeadlineQueueIndex, priority: u8) {
+        if priority != 0 {
+            self.tick_priority
+                .borrow_mut()
+                .insert(deadline_queue_idx, priority);
+        }
+    }
+
+    fn priority_of(&self, attachment_id: &WaitSetAttachmentId<Service>) -> u8 {
+        let priority = match attachment_id.attachment_type {
+            AttachmentIdType::Tick(_, idx) => self.tick_priority.borrow().get(&idx).copied(),
+            AttachmentIdType::Deadline(_, reactor_idx, _)
+            | AttachmentIdType::Notification(_, reactor_idx) => {
+                self.reactor_priority.borrow().get(&reactor_idx).copied()
+            }
+        };
+
+        priority.unwrap_or_default()
+    }
+
+    fn has_priorities(&self) -> bool {
+        !self.reactor_priority.borrow().is_empty() || !self.tick_priority.borrow().is_empty()
+    }
+
+    fn reserve_ready_attachments(&self) {
+        // every attachment can be ready with a missed deadline and a notification in one
+        // wakeup, reserving the memory on attachment avoids allocations in every wakeup
+        let required_capacity = 2 * self.len();
+        let mut ready_attachments = self.ready_attachments.borrow_mut();
+        let additional = required_capacity.saturating_sub(ready_attachments.len());
+        ready_attachments.reserve(additional);
+    }
+
+    fn push_ready_attachment(
         &self,
-        fn_call: &mut F,
+        ready_attachments: &mut Vec<ReadyAttachment<Service>>,
+        attachment_id: WaitSetAttachmentId<Service>,
+        has_priorities: bool,
+    ) {
+        ready_attachments.push(ReadyAttachment {
+            priority: if has_priorities {
+                self.priority_of(&attachment_id)
+            } else {
+                0
+            },
+            sequence_number: ready_attachments.len(),
+            attachment_id,
+        });
+    }
+
+    fn collect_missed_deadlines(
+        &self,
+        ready_attachments: &mut Vec<ReadyAttachment<Service>>,
+        has_priorities: bool,
         error_msg: &str,
-    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+    ) -> Result<(), WaitSetRunError> {
         let deadline_to_attachment = self.deadline_to_attachment.borrow();
-        let mut result = WaitSetRunResult::AllEventsHandled;
-        let call = |idx: DeadlineQueueIndex| -> CallbackProgression {
-            let progression = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
-                fn_call(WaitSetAttachmentId::deadline(self, *reactor_idx, idx))
+        let collect = |idx: DeadlineQueueIndex| -> CallbackProgression {
+            let attachment_id = if let Some(reactor_idx) = deadline_to_attachment.get(&idx) {
+                WaitSetAttachmentId::deadline(self, *reactor_idx, idx)
             } else {
-                fn_call(WaitSetAttachmentId::tick(self, idx))
+                WaitSetAttachmentId::tick(self, idx)
             };
+            self.push_ready_attachment(ready_attachments, attachment_id, has_priorities);
 
-            if let CallbackProgression::Stop = progression {
-                result = WaitSetRunResult::StopRequest;
-            }
-
-            progression
+            CallbackProgression::Continue
         };
 
         fail!(from self,
-                  when self.deadline_queue.missed_deadlines(call),
+                  when self.deadline_queue.missed_deadlines(collect),
                   with WaitSetRunError::InternalError,
                   "{error_msg} since the missed deadlines could not be acquired.");
 
-        Ok(result)
+        Ok(())
+    }
+
+    fn process_ready_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        ready_attachments: &mut Vec<ReadyAttachment<Service>>,
+        has_priorities: bool,
+        fn_call: &mut F,
+    ) -> WaitSetRunResult {
+        // attachments with equal priority keep their order: missed deadlines and ticks first,
+        // followed by the notifications
+        if has_priorities {
+            ready_attachments.sort_unstable_by_key(|ready| {
+                (core::cmp::Reverse(ready.priority), ready.sequence_number)
+            });
+        }
+
+        for ready in ready_attachments.drain(..) {
+            if let CallbackProgression::Stop = fn_call(ready.attachment_id) {
+                return WaitSetRunResult::StopRequest;
+            }
+        }
+
+        WaitSetRunResult::AllEventsHandled
+    }
+
+    fn handle_ready_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        triggered_file_descriptors: &[i32],
+        fn_call: &mut F,
+        error_msg: &str,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        // the ready attachments are collected before the first callback is called, so that the
+        // callback is able to modify the attachments, for instance with
+        // WaitSetGuard::reset_to(). The buffer is taken out of the WaitSet for the same reason.
+        let has_priorities = self.has_priorities();
+        let mut ready_attachments = self.ready_attachments.take();
+
+        let result = self
+            .collect_missed_deadlines(&mut ready_attachments, has_priorities, error_msg)
+            .map(|_| {
+                for fd in triggered_file_descriptors {
+                    self.push_ready_attachment(
+                        &mut ready_attachments,
+                        WaitSetAttachmentId::notification(self, *fd),
+                        has_priorities,
+                    );
+                }
+
+                Self::process_ready_attachments(&mut ready_attachments, has_priorities, fn_call)
+            });
+
+        ready_attachments.clear();
+        self.ready_attachments.replace(ready_attachments);
+        // attachments added by a callback reserved their memory in the empty placeholder
+        self.reserve_ready_attachments();
+        result
+    }
+
+    fn handle_deadlines<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
+        &self,
+        fn_call: &mut F,
+        error_msg: &str,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.handle_ready_attachments(&[], fn_call, error_msg)
     }
 
     fn handle_all_attachments<F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression>(
@@ -611,40 +832,41 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     ) -> Result<WaitSetRunResult, WaitSetRunError> {
         // we need to reset the deadlines first, otherwise a long fn_call may extend the
         // deadline unintentionally
-        let mut fd_and_deadline_queue_idx = Vec::with_capacity(triggered_file_descriptors.len());
-
         for fd in triggered_file_descriptors {
-            fd_and_deadline_queue_idx.push((fd, self.reset_deadline(*fd)?));
+            self.reset_deadline(*fd)?;
         }
 
         // must be called after the deadlines have been reset, in the case that the
         // event has been received shortly before the deadline ended.
-
-        match self.handle_deadlines(fn_call, error_msg)? {
-            WaitSetRunResult::AllEventsHandled => (),
-            v => return Ok(v),
-        };
-
-        for fd in triggered_file_descriptors {
-            if let CallbackProgression::Stop = fn_call(WaitSetAttachmentId::notification(self, *fd))
-            {
-                return Ok(WaitSetRunResult::StopRequest);
-            }
-        }
-
-        Ok(WaitSetRunResult::AllEventsHandled)
+        self.handle_ready_attachments(triggered_file_descriptors, fn_call, error_msg)
     }
 
     /// Attaches an object as notification to the [`WaitSet`]. Whenever an event is received on the
     /// object the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] to handle the event.
     /// The object cannot be attached twice and the
     /// [`WaitSet::capacity()`] is limited by the underlying implementation.
-    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+    pub fn attach_notification<'waitset, 'attachment, T: Attachable>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        self.attach_notification_with_priority(attachment, 0)
+    }
+
+    /// Like [`WaitSet::attach_notification()`] but with a priority. When multiple attachments
+    /// are ready in one wakeup, [`WaitSet::wait_and_process()`] calls the callback in
+    /// descending priority order. Attachments with equal priority are processed in the same
+    /// order as attachments without priority, which have the priority `0`.
+    pub fn attach_notification_with_priority<'waitset, 'attachment, T: Attachable>(
         &'waitset self,
         attachment: &'attachment T,
+        priority: u8,
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
         let reactor_guard = self.attach_to_reactor(attachment)?;
         self.attach()?;
+        self.set_reactor_priority(
+            unsafe { reactor_guard.file_descriptor().native_handle() },
+            priority,
+        );
 
         Ok(WaitSetGuard {
             waitset: self,
@@ -657,10 +879,22 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     /// The object cannot be attached twice and the
     /// [`WaitSet::capacity()`] is limited by the underlying implementation.
     /// Whenever the object emits an event the deadline is reset by the [`WaitSet`].
-    pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+    pub fn attach_deadline<'waitset, 'attachment, T: Attachable>(
         &'waitset self,
         attachment: &'attachment T,
         deadline: Duration,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        self.attach_deadline_with_priority(attachment, deadline, 0)
+    }
+
+    /// Like [`WaitSet::attach_deadline()`] but with a priority that applies to the received
+    /// events as well as to the missed deadlines. See
+    /// [`WaitSet::attach_notification_with_priority()`] for the ordering guarantees.
+    pub fn attach_deadline_with_priority<'waitset, 'attachment, T: Attachable>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+        priority: u8,
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
         let reactor_guard = self.attach_to_reactor(attachment)?;
         let deadline_queue_guard = self.attach_to_deadline_queue(deadline)?;
@@ -675,6 +909,7 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             .borrow_mut()
             .insert(deadline_idx, reactor_idx);
         self.attach()?;
+        self.set_reactor_priority(reactor_idx, priority);
 
         Ok(WaitSetGuard {
             waitset: self,
@@ -682,14 +917,80 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches an external [`FileDescriptor`] that is owned by the user, like a socket or a
+    /// timer file descriptor, as notification to the [`WaitSet`]. Whenever the file descriptor
+    /// becomes readable the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] with
+    /// the [`WaitSetAttachmentId`] of the returned [`WaitSetGuard`].
+    ///
+    /// The [`WaitSet`] never closes the file descriptor, dropping the [`WaitSetGuard`] only
+    /// detaches it. Create the [`FileDescriptor`] with [`FileDescriptor::non_owning_new()`]
+    /// so that it also stays open when the [`FileDescriptor`] itself goes out of scope.
+    ///
+    /// Arbitrary file descriptors cannot be multiplexed on Windows, therefore it fails with
+    /// [`WaitSetAttachmentError::UnsupportedAttachment`] there.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use std::os::fd::AsRawFd;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let (socket, _) = std::os::unix::net::UnixDatagram::pair()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let fd = FileDescriptor::non_owning_new(socket.as_raw_fd()).unwrap();
+    /// let guard = waitset.attach_external_notification(&fd)?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         // read from the socket
+    ///     }
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// waitset.wait_and_process(on_event)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_external_notification<'waitset, 'attachment>(
+        &'waitset self,
+        file_descriptor: &'attachment FileDescriptor,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        self.verify_external_attachment_is_supported(file_descriptor)?;
+        self.attach_notification(ExternalFileDescriptor::from_ref(file_descriptor))
+    }
+
+    /// Attaches an external [`FileDescriptor`] that is owned by the user as deadline to the
+    /// [`WaitSet`]. See [`WaitSet::attach_external_notification()`] and
+    /// [`WaitSet::attach_deadline()`] for details.
+    pub fn attach_external_deadline<'waitset, 'attachment>(
+        &'waitset self,
+        file_descriptor: &'attachment FileDescriptor,
+        deadline: Duration,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        self.verify_external_attachment_is_supported(file_descriptor)?;
+        self.attach_deadline(ExternalFileDescriptor::from_ref(file_descriptor), deadline)
+    }
+
     /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
     /// informs the user in [`WaitSet::wait_and_process()`].
     pub fn attach_interval(
         &self,
         interval: Duration,
     ) -> Result<WaitSetGuard<Service>, WaitSetAttachmentError> {
+        self.attach_interval_with_priority(interval, 0)
+    }
+
+    /// Like [`WaitSet::attach_interval()`] but with a priority. See
+    /// [`WaitSet::attach_notification_with_priority()`] for the ordering guarantees.
+    pub fn attach_interval_with_priority(
+        &self,
+        interval: Duration,
+        priority: u8,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
         let deadline_queue_guard = self.attach_to_deadline_queue(interval)?;
         self.attach()?;
+        self.set_tick_priority(deadline_queue_guard.index(), priority);
 
         Ok(WaitSetGuard {
             waitset: self,
@@ -697,6 +998,119 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         })
     }
 
+    /// Attaches an absolute deadline to the [`WaitSet`] that fires exactly once at the provided
+    /// [`Instant`]. The [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] with the
+    /// [`WaitSetAttachmentId`] of the returned [`WaitSetGuard`]. If the [`Instant`] is already
+    /// in the past, the attachment fires with the next wakeup of the [`WaitSet`].
+    ///
+    /// The attachment can be re-armed with [`WaitSetGuard::reset_to()`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # use std::time::Instant;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// const CYCLE_TIME: Duration = Duration::from_millis(100);
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let mut next_cycle = Instant::now() + CYCLE_TIME;
+    /// let guard = waitset.attach_deadline_at(next_cycle)?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         // do the cyclic work and schedule the next cycle
+    ///         next_cycle += CYCLE_TIME;
+    ///         guard.reset_to(next_cycle).unwrap();
+    ///     }
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// waitset.wait_and_process(on_event)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_deadline_at(
+        &self,
+        deadline: Instant,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        let time_point = self.to_deadline_queue_time_point(deadline)?;
+        let deadline_queue_guard = self.deadline_queue.add_deadline_at(time_point);
+        self.attach()?;
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::TimePoint(deadline_queue_guard),
+        })
+    }
+
+    /// Attaches the response deadline of a [`PendingResponse`] to the [`WaitSet`]. It is armed
+    /// with [`PendingResponse::time_until_deadline()`] and fires exactly once when the deadline
+    /// is reached. The [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] with the
+    /// [`WaitSetAttachmentId`] of the returned [`WaitSetGuard`], then
+    /// [`PendingResponse::has_missed_deadline()`] tells if the
+    /// [`Response`](crate::response::Response) was indeed not received in time.
+    ///
+    /// Fails with [`WaitSetAttachmentError::UnsupportedAttachment`] when the
+    /// [`Service`](crate::service::Service) has no response deadline or when the
+    /// [`PendingResponse`] has already received a [`Response`](crate::response::Response).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .response_deadline(Duration::from_millis(100))
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    /// let pending_response = client.send_copy(1234)?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_response_deadline(&pending_response)?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     if attachment_id.has_event_from(&guard) && pending_response.has_missed_deadline() {
+    ///         // retry the request or fail over to another service
+    ///     }
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// waitset.wait_and_process_once(on_event)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_response_deadline<
+        RequestPayload: Debug + ZeroCopySend + ?Sized,
+        RequestHeader: Debug + ZeroCopySend,
+        ResponsePayload: Debug + ZeroCopySend + ?Sized,
+        ResponseHeader: Debug + ZeroCopySend,
+    >(
+        &self,
+        pending_response: &PendingResponse<
+            Service,
+            RequestPayload,
+            RequestHeader,
+            ResponsePayload,
+            ResponseHeader,
+        >,
+    ) -> Result<WaitSetGuard<'_, '_, Service>, WaitSetAttachmentError> {
+        match pending_response.time_until_deadline() {
+            Some(time_until_deadline) => {
+                self.attach_deadline_at(Instant::now() + time_until_deadline)
+            }
+            None => {
+                fail!(from self, with WaitSetAttachmentError::UnsupportedAttachment,
+                    "Unable to attach the response deadline of {:?} since the service has no response deadline or a response was already received.",
+                    pending_response);
+            }
+        }
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then collects all events by calling the
     /// provided `fn_call` callback with the corresponding [`WaitSetAttachmentId`]. In contrast
     /// to [`WaitSet::wait_and_process_once()`] it will never return until the user explicitly
@@ -938,14 +1352,57 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.signal_handling_mode
     }
 
-    fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+    /// Returns the duration until the next deadline or interval attachment is due. If a
+    /// deadline was already missed it returns [`Duration::ZERO`] and if the [`WaitSet`] has no
+    /// timed attachments it returns [`None`]. Useful to integrate the [`WaitSet`] into an
+    /// external event loop that polls it with
+    /// [`WaitSet::wait_and_process_once_with_timeout()`] and a timeout of zero.
+    pub fn duration_until_next_deadline(&self) -> Result<Option<Duration>, WaitSetRunError> {
+        let duration = fail!(from self,
+                             when self.deadline_queue.duration_until_next_deadline(),
+                             with WaitSetRunError::InternalError,
+                             "Unable to acquire the duration until the next deadline.");
+
+        match duration {
+            Duration::MAX => Ok(None),
+            v => Ok(Some(v)),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn verify_external_attachment_is_supported(
+        &self,
+        _file_descriptor: &FileDescriptor,
+    ) -> Result<(), WaitSetAttachmentError> {
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    fn verify_external_attachment_is_supported(
+        &self,
+        file_descriptor: &FileDescriptor,
+    ) -> Result<(), WaitSetAttachmentError> {
+        fail!(from self, with WaitSetAttachmentError::UnsupportedAttachment,
+            "Unable to attach the external file descriptor {:?} since arbitrary file descriptors are not supported on Windows.",
+            file_descriptor);
+    }
+
+    fn attach_to_reactor<'waitset, 'attachment, T: Attachable>(
         &'waitset self,
         attachment: &'attachment T,
     ) -> Result<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>, WaitSetAttachmentError>
     {
         let msg = "Unable to attach object to internal reactor";
 
-        match self.reactor.attach(attachment) {
+        let file_descriptor = match attachment.attachment_file_descriptor() {
+            Some(file_descriptor) => ExternalFileDescriptor::from_ref(file_descriptor),
+            None => {
+                fail!(from self, with WaitSetAttachmentError::UnsupportedAttachment,
+                    "{msg} {:?} since it provides no file descriptor to wait on.", attachment);
+            }
+        };
+
+        match self.reactor.attach(file_descriptor) {
             Ok(guard) => Ok(guard),
             Err(ReactorAttachError::AlreadyAttached) => {
                 fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
@@ -963,6 +1420,26 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
+    fn to_deadline_queue_time_point(
+        &self,
+        deadline: Instant,
+    ) -> Result<Duration, WaitSetAttachmentError> {
+        // both clocks are sampled at the same time to translate the instant into the
+        // monotonic time base of the deadline queue
+        let now_instant = Instant::now();
+        let now = fail!(from self,
+                    when Time::now_with_clock(self.deadline_queue.clock_type()),
+                    with WaitSetAttachmentError::InternalError,
+                    "Unable to convert the deadline {:?} since the current time could not be acquired.",
+                    deadline)
+        .as_duration();
+
+        match deadline.checked_duration_since(now_instant) {
+            Some(duration_until_deadline) => Ok(now + duration_until_deadline),
+            None => Ok(now.saturating_sub(now_instant - deadline)),
+        }
+    }
+
     fn attach_to_deadline_queue(
         &self,
         timeout: Duration,
@@ -213,6 +213,44 @@ mod client {
         assert_that!(*data, eq 123);
     }
 
+    #[test]
+    fn requests_exceeding_the_request_queue_depth_are_discarded<Sut: Service>() {
+        const QUEUE_DEPTH: usize = 2;
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_requests(false)
+            .max_active_requests_per_client(4)
+            .max_request_queue_depth_per_client(QUEUE_DEPTH)
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let sut = service
+            .client_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let mut pending_responses = vec![];
+        for n in 0..QUEUE_DEPTH as u64 + 1 {
+            pending_responses.push(sut.send_copy(n).unwrap());
+        }
+
+        for n in 0..QUEUE_DEPTH as u64 {
+            let request = server.receive().unwrap().unwrap();
+            assert_that!(*request, eq n);
+        }
+        assert_that!(server.receive().unwrap(), is_none);
+
+        // the queue has space again
+        pending_responses.push(sut.send_copy(3).unwrap());
+        let request = server.receive().unwrap().unwrap();
+        assert_that!(*request, eq 3);
+    }
+
     #[test]
     fn loan_request_is_initialized_with_default_value<Sut: Service>() {
         let service_name = generate_service_name();
@@ -345,6 +383,318 @@ mod client {
         }
     }
 
+    #[test]
+    fn cancel_pending_response_releases_active_request<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(1)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        assert_that!(sut.send_copy(456).err(), eq Some(RequestSendError::ExceedsMaxActiveRequests));
+
+        sut.cancel_pending_response(pending_response);
+        assert_that!(sut.send_copy(789), is_ok);
+    }
+
+    #[test]
+    fn client_without_credit_flow_control_has_no_credits<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+
+        assert_that!(sut.available_credits(), eq None);
+    }
+
+    #[test]
+    fn send_request_fails_with_back_pressure_when_credits_are_exhausted<Sut: Service>() {
+        const CREDITS: u32 = 2;
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(CREDITS)
+            .max_active_requests_per_client(4)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+        assert_that!(sut.available_credits(), eq Some(CREDITS));
+
+        let mut pending_responses = vec![];
+        for n in 0..CREDITS {
+            pending_responses.push(sut.send_copy(123).unwrap());
+            assert_that!(sut.available_credits(), eq Some(CREDITS - n - 1));
+        }
+
+        assert_that!(sut.send_copy(456).err(), eq Some(RequestSendError::BackPressure));
+    }
+
+    #[test]
+    fn credit_is_returned_when_server_sends_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(1)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+        assert_that!(sut.available_credits(), eq Some(0));
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(sut.available_credits(), eq Some(0));
+
+        active_request.send_copy(456).unwrap();
+        assert_that!(sut.available_credits(), eq Some(1));
+
+        active_request.send_copy(789).unwrap();
+        drop(active_request);
+        assert_that!(sut.available_credits(), eq Some(1));
+        assert_that!(sut.send_copy(123), is_ok);
+    }
+
+    #[test]
+    fn credit_is_returned_when_server_drops_active_request<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(1)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+        assert_that!(sut.send_copy(123).err(), eq Some(RequestSendError::BackPressure));
+
+        drop(server.receive().unwrap().unwrap());
+
+        assert_that!(sut.available_credits(), eq Some(1));
+        assert_that!(sut.send_copy(123), is_ok);
+    }
+
+    #[test]
+    fn request_delivered_to_multiple_servers_returns_one_credit<Sut: Service>() {
+        const CREDITS: u32 = 2;
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(CREDITS)
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+        assert_that!(sut.available_credits(), eq Some(CREDITS - 1));
+
+        server_1.receive().unwrap().unwrap().send_copy(456).unwrap();
+        assert_that!(sut.available_credits(), eq Some(CREDITS - 1));
+
+        server_2.receive().unwrap().unwrap().send_copy(789).unwrap();
+        assert_that!(sut.available_credits(), eq Some(CREDITS));
+    }
+
+    #[test]
+    fn slowest_server_determines_the_available_credits<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(1)
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let fast_server = service.server_builder().create().unwrap();
+        let slow_server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+        drop(fast_server.receive().unwrap().unwrap());
+
+        assert_that!(sut.available_credits(), eq Some(0));
+        assert_that!(sut.send_copy(123).err(), eq Some(RequestSendError::BackPressure));
+
+        drop(slow_server.receive().unwrap().unwrap());
+
+        assert_that!(sut.available_credits(), eq Some(1));
+        assert_that!(sut.send_copy(123), is_ok);
+    }
+
+    #[test]
+    fn credit_is_returned_when_request_is_overwritten_by_safe_overflow<Sut: Service>() {
+        const CREDITS: u32 = 3;
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(CREDITS)
+            .enable_safe_overflow_for_requests(true)
+            .max_active_requests_per_client(CREDITS as usize)
+            .max_request_queue_depth_per_client(1)
+            .create()
+            .unwrap();
+
+        let _server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for _ in 0..CREDITS {
+            pending_responses.push(sut.send_copy(123).unwrap());
+            assert_that!(sut.available_credits(), eq Some(CREDITS - 1));
+        }
+    }
+
+    #[test]
+    fn credits_of_queued_requests_are_returned_when_server_is_dropped<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(1)
+            .create()
+            .unwrap();
+
+        let server = service.server_builder().create().unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+        assert_that!(sut.available_credits(), eq Some(0));
+
+        drop(server);
+
+        assert_that!(sut.available_credits(), eq Some(1));
+    }
+
+    #[test]
+    fn request_without_server_consumes_no_credit<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(1)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+
+        let _pending_response = sut.send_copy(123).unwrap();
+
+        assert_that!(sut.available_credits(), eq Some(1));
+    }
+
+    #[test]
+    fn cancel_pending_response_disconnects_active_request<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.is_connected(), eq true);
+
+        sut.cancel_pending_response(pending_response);
+        assert_that!(active_request.is_connected(), eq false);
+    }
+
+    #[test]
+    fn responses_to_cancelled_request_are_discarded<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(1)
+            .max_response_buffer_size(1)
+            .enable_safe_overflow_for_responses(false)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let server = service
+            .server_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        sut.cancel_pending_response(pending_response);
+
+        for _ in 0..3 {
+            assert_that!(active_request.send_copy(1), is_ok);
+        }
+        drop(active_request);
+
+        let pending_response = sut.send_copy(456).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_copy(2), is_ok);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 2);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn fire_and_forget_disabled_server_does_not_receive_cancelled_request<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(false)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = sut.send_copy(123).unwrap();
+        sut.cancel_pending_response(pending_response);
+
+        assert_that!(server.receive().unwrap(), is_none);
+    }
+
     #[test]
     fn send_request_fails_when_already_active_requests_is_at_max<Sut: Service>() {
         const MAX_ACTIVE_REQUESTS: usize = 9;
@@ -697,6 +1047,87 @@ mod client {
         }
     }
 
+    #[test]
+    fn connected_servers_track_servers_that_appear_and_disappear<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        assert_that!(sut.has_server(), eq Ok(false));
+        assert_that!(sut.number_of_connected_servers(), eq Ok(0));
+
+        let server_1 = service.server_builder().create().unwrap();
+        assert_that!(sut.has_server(), eq Ok(true));
+        assert_that!(sut.number_of_connected_servers(), eq Ok(1));
+
+        let server_2 = service.server_builder().create().unwrap();
+        assert_that!(sut.number_of_connected_servers(), eq Ok(2));
+
+        let mut server_ids = vec![];
+        sut.connected_servers(|details| {
+            server_ids.push(details.server_id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(server_ids, len 2);
+        assert_that!(server_ids, contains server_1.id());
+        assert_that!(server_ids, contains server_2.id());
+
+        drop(server_1);
+        assert_that!(sut.number_of_connected_servers(), eq Ok(1));
+
+        drop(server_2);
+        assert_that!(sut.has_server(), eq Ok(false));
+    }
+
+    #[test]
+    fn connection_generation_tracks_connected_servers<Sut: Service>() {
+        let service_name = generate_service_name();
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let sut = service.client_builder().create().unwrap();
+        let generation = sut.connection_generation().unwrap();
+        assert_that!(sut.connection_generation().unwrap(), eq generation);
+
+        let server_1 = service.server_builder().create().unwrap();
+        let server_2 = service.server_builder().create().unwrap();
+        let connected_generation = sut.connection_generation().unwrap();
+        assert_that!(connected_generation, gt generation);
+
+        let mut ids = vec![];
+        sut.list_connected_servers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains server_1.id());
+        assert_that!(ids, contains server_2.id());
+
+        drop(server_1);
+        assert_that!(sut.connection_generation().unwrap(), gt connected_generation);
+
+        let mut ids = vec![];
+        sut.list_connected_servers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, eq vec![server_2.id()]);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
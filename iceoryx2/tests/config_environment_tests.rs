@@ -0,0 +1,177 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod config_environment {
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use iceoryx2::config::ConfigCreationError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    // the environment is shared by all tests of the process
+    static ENVIRONMENT: Mutex<()> = Mutex::new(());
+
+    fn with_environment<F: FnOnce()>(variables: &[(&str, &str)], test: F) {
+        let _guard = ENVIRONMENT.lock().unwrap_or_else(|e| e.into_inner());
+        for (name, value) in variables {
+            std::env::set_var(name, value);
+        }
+
+        test();
+
+        for (name, _) in variables {
+            std::env::remove_var(name);
+        }
+    }
+
+    fn write_config_file(config: &Config) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "iox2_config_environment_tests_{}.toml",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        std::fs::write(&path, config.to_toml()).unwrap();
+        path
+    }
+
+    #[test]
+    fn environment_variables_override_settings() {
+        with_environment(
+            &[
+                ("IOX2_GLOBAL_PREFIX", "env_prefix_"),
+                ("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS", "7"),
+                ("IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_NANOS", "0"),
+                ("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS", "64"),
+                ("IOX2_DEFAULTS_EVENT_NOTIFIER_CREATED_EVENT", "3"),
+                (
+                    "IOX2_DEFAULTS_REQUEST_RESPONSE_ENABLE_MULTI_RESPONSE",
+                    "false",
+                ),
+            ],
+            || {
+                let mut sut = Config::default();
+                sut.defaults.event.notifier_created_event = Some(1);
+
+                assert_that!(sut.apply_environment_overrides(), is_ok);
+
+                assert_that!(sut.global.prefix, eq FileName::new(b"env_prefix_").unwrap());
+                assert_that!(sut.global.service.creation_timeout, eq Duration::from_secs(7));
+                assert_that!(sut.defaults.publish_subscribe.max_subscribers, eq 64);
+                assert_that!(sut.defaults.event.notifier_created_event, eq Some(3));
+                assert_that!(sut.defaults.request_response.enable_multi_response, eq false);
+            },
+        );
+    }
+
+    #[test]
+    fn invalid_environment_variable_value_is_rejected() {
+        with_environment(
+            &[
+                ("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_NODES", "99"),
+                ("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS", "many"),
+            ],
+            || {
+                let mut sut = Config::default();
+
+                let result = sut.apply_environment_overrides();
+
+                assert_that!(result.err(), eq Some(ConfigCreationError::InvalidEnvironmentVariableValue));
+                assert_that!(sut, eq Config::default());
+            },
+        );
+    }
+
+    #[test]
+    fn environment_variable_with_invalid_setting_is_rejected() {
+        with_environment(
+            &[(
+                "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_UNABLE_TO_DELIVER_STRATEGY",
+                "DoNotKnow",
+            )],
+            || {
+                let mut sut = Config::default();
+
+                let result = sut.apply_environment_overrides();
+
+                assert_that!(result.err(), eq Some(ConfigCreationError::InvalidEnvironmentVariableValue));
+                assert_that!(sut, eq Config::default());
+            },
+        );
+    }
+
+    #[test]
+    fn environment_variables_are_applied_when_config_file_is_loaded() {
+        let mut file_config = Config::default();
+        file_config.defaults.publish_subscribe.max_subscribers = 12;
+        file_config.defaults.publish_subscribe.max_publishers = 13;
+        let config_file = write_config_file(&file_config);
+
+        with_environment(
+            &[("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS", "64")],
+            || {
+                let sut = Config::from_file(
+                    &FilePath::new(config_file.to_str().unwrap().as_bytes()).unwrap(),
+                )
+                .unwrap();
+
+                assert_that!(sut.defaults.publish_subscribe.max_subscribers, eq 64);
+                assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 13);
+            },
+        );
+
+        std::fs::remove_file(config_file).unwrap();
+    }
+
+    #[test]
+    fn builder_settings_take_precedence_over_environment_variables() {
+        let mut config = generate_isolated_config();
+        with_environment(
+            &[("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_SUBSCRIBERS", "64")],
+            || {
+                assert_that!(config.apply_environment_overrides(), is_ok);
+            },
+        );
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .max_subscribers(3)
+            .create()
+            .unwrap();
+        let default_sut = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_subscribers(), eq 3);
+        assert_that!(default_sut.static_config().max_subscribers(), eq 64);
+    }
+
+    #[test]
+    fn effective_config_can_be_dumped_and_loaded_again() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_subscribers = 64;
+        config.defaults.event.deadline = Some(Duration::from_millis(123));
+
+        let sut: Config = toml::from_str(&config.to_toml()).unwrap();
+
+        assert_that!(sut, eq config);
+    }
+}
@@ -40,3 +40,384 @@ mod node_name {
         assert_that!(default_config, eq file_config);
     }
 }
+
+#[generic_tests::define]
+mod service_overrides {
+    use iceoryx2::config::{Config, ServiceOverrides};
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn config_with_overrides(sections: &[(&str, ServiceOverrides)]) -> Config {
+        let mut config = generate_isolated_config();
+        for (pattern, overrides) in sections {
+            config
+                .services
+                .insert(pattern.to_string(), overrides.clone());
+        }
+        config
+    }
+
+    fn max_subscribers_override(value: usize) -> ServiceOverrides {
+        let mut overrides = ServiceOverrides::default();
+        overrides.publish_subscribe.max_subscribers = Some(value);
+        overrides
+    }
+
+    #[test]
+    fn override_is_applied_only_to_matching_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config =
+            config_with_overrides(&[(service_name.as_str(), max_subscribers_override(64))]);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let other = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_subscribers(), eq 64);
+        assert_that!(other.static_config().max_subscribers(), eq config.defaults.publish_subscribe.max_subscribers);
+    }
+
+    #[test]
+    fn builder_setting_takes_precedence_over_override<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config =
+            config_with_overrides(&[(service_name.as_str(), max_subscribers_override(64))]);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(3)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_subscribers(), eq 3);
+    }
+
+    #[test]
+    fn overrides_are_applied_to_all_messaging_patterns<Sut: Service>() {
+        let mut overrides = ServiceOverrides::default();
+        overrides.event.max_listeners = Some(3);
+        overrides.request_response.max_servers = Some(5);
+        overrides.blackboard.max_readers = Some(7);
+        let config = config_with_overrides(&[("*", overrides)]);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let event = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+        let request_response = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let blackboard = node
+            .service_builder(&generate_service_name())
+            .blackboard::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(event.static_config().max_listeners(), eq 3);
+        assert_that!(request_response.static_config().max_servers(), eq 5);
+        assert_that!(blackboard.static_config().max_readers(), eq 7);
+    }
+
+    #[test]
+    fn more_specific_override_takes_precedence<Sut: Service>() {
+        let service_name = ServiceName::new("sensor/front/lidar").unwrap();
+        let mut least_specific = max_subscribers_override(10);
+        least_specific.publish_subscribe.max_publishers = Some(11);
+        least_specific.publish_subscribe.max_nodes = Some(12);
+        let mut more_specific = max_subscribers_override(20);
+        more_specific.publish_subscribe.max_publishers = Some(21);
+        let config = config_with_overrides(&[
+            ("sensor/front/lidar", max_subscribers_override(30)),
+            ("sensor/front/*", more_specific),
+            ("*", least_specific),
+        ]);
+
+        let sut = config.defaults_for(&service_name);
+
+        assert_that!(sut.publish_subscribe.max_subscribers, eq 30);
+        assert_that!(sut.publish_subscribe.max_publishers, eq 21);
+        assert_that!(sut.publish_subscribe.max_nodes, eq 12);
+        assert_that!(sut.publish_subscribe.subscriber_max_buffer_size, eq config.defaults.publish_subscribe.subscriber_max_buffer_size);
+    }
+
+    #[test]
+    fn patterns_match_service_names_correctly<Sut: Service>() {
+        let config = config_with_overrides(&[
+            ("sensor/?/speed", max_subscribers_override(1)),
+            ("*/camera*", max_subscribers_override(2)),
+        ]);
+        let max_subscribers = |name: &str| {
+            config
+                .defaults_for(&ServiceName::new(name).unwrap())
+                .publish_subscribe
+                .max_subscribers
+        };
+        let default_value = config.defaults.publish_subscribe.max_subscribers;
+
+        assert_that!(max_subscribers("sensor/a/speed"), eq 1);
+        assert_that!(max_subscribers("sensor/ab/speed"), eq default_value);
+        assert_that!(max_subscribers("front/camera"), eq 2);
+        assert_that!(max_subscribers("rear/camera/raw"), eq 2);
+        assert_that!(max_subscribers("camera"), eq default_value);
+    }
+
+    #[test]
+    fn overrides_are_parsed_from_toml<Sut: Service>() {
+        let mut contents = toml::to_string(&Config::default()).unwrap();
+        contents.push_str("[services.\"my/service\".publish-subscribe]\nmax-subscribers = 64\n");
+
+        let sut: Config = toml::from_str(&contents).unwrap();
+
+        assert_that!(sut.services.get("my/service").unwrap().publish_subscribe.max_subscribers, eq Some(64));
+    }
+
+    #[test]
+    fn unknown_key_in_override_section_fails_to_parse<Sut: Service>() {
+        let mut contents = toml::to_string(&Config::default()).unwrap();
+        contents.push_str("[services.\"my/service\".publish-subscribe]\nmax-subscriberz = 64\n");
+
+        let sut = toml::from_str::<Config>(&contents);
+
+        assert_that!(sut, is_err);
+        let message = sut.err().unwrap().to_string();
+        assert_that!(message.contains("[services.\"my/service\"]"), eq true);
+        assert_that!(message.contains("max-subscriberz"), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
+
+mod config_watcher {
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    use iceoryx2::config::{Config, ConfigCreationError, ConfigUpdate};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+
+    struct ConfigFile(PathBuf);
+
+    impl ConfigFile {
+        fn new(config: &Config) -> Self {
+            let sut = Self(std::env::temp_dir().join(format!(
+                "iox2_config_watcher_tests_{}.toml",
+                UniqueSystemId::new().unwrap().value()
+            )));
+            sut.write(&config.to_toml());
+            sut
+        }
+
+        fn write(&self, contents: &str) {
+            std::fs::write(&self.0, contents).unwrap();
+        }
+
+        fn path(&self) -> FilePath {
+            FilePath::new(self.0.to_str().unwrap().as_bytes()).unwrap()
+        }
+    }
+
+    impl Drop for ConfigFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn watch_loads_initial_config_without_calling_callback() {
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.max_subscribers = 21;
+        let file = ConfigFile::new(&config);
+        let updates = RefCell::new(Vec::<ConfigUpdate>::new());
+
+        let mut sut =
+            Config::watch(&file.path(), |u| updates.borrow_mut().push(u.clone())).unwrap();
+
+        assert_that!(*sut.config(), eq config);
+        assert_that!(sut.check_for_changes(), eq Ok(false));
+        drop(sut);
+        assert_that!(updates.borrow().is_empty(), eq true);
+    }
+
+    #[test]
+    fn watch_fails_when_config_file_does_not_exist() {
+        let file = ConfigFile::new(&Config::default());
+        let path = file.path();
+        drop(file);
+
+        let sut = Config::watch(&path, |_| {});
+
+        assert_that!(sut.err(), eq Some(ConfigCreationError::ConfigFileDoesNotExist));
+    }
+
+    #[test]
+    fn changed_config_is_delivered_to_callback() {
+        let mut config = Config::default();
+        let file = ConfigFile::new(&config);
+        let updates = RefCell::new(Vec::<ConfigUpdate>::new());
+        let mut sut =
+            Config::watch(&file.path(), |u| updates.borrow_mut().push(u.clone())).unwrap();
+
+        config.defaults.publish_subscribe.max_subscribers = 42;
+        config.global.node.cleanup_dead_nodes_on_creation =
+            !config.global.node.cleanup_dead_nodes_on_creation;
+        file.write(&config.to_toml());
+
+        assert_that!(sut.check_for_changes(), eq Ok(true));
+        assert_that!(*sut.config(), eq config);
+        assert_that!(sut.check_for_changes(), eq Ok(false));
+        drop(sut);
+
+        let updates = updates.borrow();
+        assert_that!(updates, len 1);
+        assert_that!(*updates[0].config(), eq config);
+        assert_that!(updates[0].requires_restart(), eq false);
+    }
+
+    #[test]
+    fn changed_immutable_settings_are_flagged_as_requiring_restart() {
+        let mut config = Config::default();
+        let file = ConfigFile::new(&config);
+        let updates = RefCell::new(Vec::<ConfigUpdate>::new());
+        let mut sut =
+            Config::watch(&file.path(), |u| updates.borrow_mut().push(u.clone())).unwrap();
+
+        let mut new_config = config.clone();
+        new_config.global.prefix = FileName::new(b"another_prefix_").unwrap();
+        new_config.global.node.directory = Path::new(b"another_node_dir").unwrap();
+        new_config.defaults.event.max_listeners = 13;
+        file.write(&new_config.to_toml());
+
+        assert_that!(sut.check_for_changes(), eq Ok(true));
+        config.defaults.event.max_listeners = 13;
+        assert_that!(*sut.config(), eq config);
+        drop(sut);
+
+        let updates = updates.borrow();
+        assert_that!(updates, len 1);
+        assert_that!(updates[0].requires_restart(), eq true);
+        assert_that!(
+            updates[0].settings_requiring_restart(),
+            eq & ["global.prefix", "global.node.directory"]
+        );
+        assert_that!(*updates[0].config(), eq config);
+    }
+
+    #[test]
+    fn invalid_config_is_not_delivered_and_current_config_stays_active() {
+        let mut config = Config::default();
+        let file = ConfigFile::new(&config);
+        let updates = RefCell::new(Vec::<ConfigUpdate>::new());
+        let mut sut =
+            Config::watch(&file.path(), |u| updates.borrow_mut().push(u.clone())).unwrap();
+
+        file.write("[defaults.publish-subscribe]\nmax-subscribers = \"many\"\n");
+        assert_that!(sut.check_for_changes(), eq Err(ConfigCreationError::UnableToDeserializeContents));
+        assert_that!(*sut.config(), eq Config::default());
+        assert_that!(updates.borrow().is_empty(), eq true);
+
+        config.defaults.publish_subscribe.max_subscribers = 7;
+        file.write(&config.to_toml());
+        assert_that!(sut.check_for_changes(), eq Ok(true));
+        assert_that!(*sut.config(), eq config);
+        drop(sut);
+        assert_that!(updates.borrow(), len 1);
+    }
+}
+
+mod merge {
+    use core::time::Duration;
+
+    use iceoryx2::config::{Config, ServiceOverrides};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn settings_of_other_that_differ_from_default_are_applied() {
+        let mut base = Config::default();
+        base.global.prefix = FileName::new(b"base_").unwrap();
+        base.defaults.publish_subscribe.max_subscribers = 5;
+        let base_copy = base.clone();
+
+        let mut other = Config::default();
+        other.defaults.publish_subscribe.max_publishers = 3;
+        other.defaults.event.deadline = Some(Duration::from_millis(10));
+
+        let sut = base.merge(&other);
+
+        assert_that!(sut.global.prefix, eq base.global.prefix);
+        assert_that!(sut.defaults.publish_subscribe.max_subscribers, eq 5);
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 3);
+        assert_that!(sut.defaults.event.deadline, eq Some(Duration::from_millis(10)));
+        assert_that!(base, eq base_copy);
+    }
+
+    #[test]
+    fn settings_of_other_that_equal_default_do_not_override_base() {
+        let mut base = Config::default();
+        base.defaults.publish_subscribe.max_subscribers = 5;
+        base.defaults.event.deadline = Some(Duration::from_millis(10));
+
+        let mut other = Config::default();
+        other.defaults.publish_subscribe.max_subscribers =
+            Config::default().defaults.publish_subscribe.max_subscribers;
+
+        let sut = base.merge(&other);
+
+        assert_that!(sut, eq base);
+    }
+
+    #[test]
+    fn merging_default_config_returns_base() {
+        let mut base = Config::default();
+        base.global.prefix = FileName::new(b"base_").unwrap();
+        base.defaults.request_response.max_servers = 9;
+
+        assert_that!(base.merge(&Config::default()), eq base);
+        assert_that!(Config::default().merge(&base), eq base);
+    }
+
+    #[test]
+    fn service_overrides_are_added_and_merged() {
+        let mut base = Config::default();
+        let mut overrides = ServiceOverrides::default();
+        overrides.publish_subscribe.max_subscribers = Some(4);
+        base.services.insert("sensor/*".into(), overrides.clone());
+        base.services.insert("actuator/*".into(), overrides);
+
+        let mut other = Config::default();
+        let mut overrides = ServiceOverrides::default();
+        overrides.publish_subscribe.max_publishers = Some(2);
+        other.services.insert("sensor/*".into(), overrides.clone());
+        other.services.insert("camera/*".into(), overrides);
+
+        let sut = base.merge(&other);
+
+        assert_that!(sut.services, len 3);
+        let sensor = &sut.services["sensor/*"].publish_subscribe;
+        assert_that!(sensor.max_subscribers, eq Some(4));
+        assert_that!(sensor.max_publishers, eq Some(2));
+        assert_that!(sut.services["actuator/*"], eq base.services["actuator/*"]);
+        assert_that!(sut.services["camera/*"], eq other.services["camera/*"]);
+    }
+}
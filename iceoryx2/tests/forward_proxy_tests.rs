@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod forward_proxy {
+    use iceoryx2::port::forward_proxy::{ForwardProxy, ForwardProxyError};
+    use iceoryx2::port::LoanError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct Header {
+        value: u32,
+    }
+
+    #[test]
+    fn error_display_works<Upstream: Service, Downstream: Service>() {
+        assert_that!(
+            format!("{}", ForwardProxyError::LoanError(LoanError::ExceedsMaxLoans)),
+            eq "ForwardProxyError::LoanError(ExceedsMaxLoans)");
+    }
+
+    #[test]
+    fn samples_are_forwarded_to_downstream_service<Upstream: Service, Downstream: Service>() {
+        let config = generate_isolated_config();
+        let upstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Upstream>()
+            .unwrap();
+        let downstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Downstream>()
+            .unwrap();
+
+        let upstream_service = upstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .user_header::<Header>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+        let downstream_service = downstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .user_header::<Header>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let mut sut = ForwardProxy::new(
+            upstream_service.subscriber_builder().create().unwrap(),
+            downstream_service.publisher_builder().create().unwrap(),
+        );
+
+        let publisher = upstream_service.publisher_builder().create().unwrap();
+        let subscriber = downstream_service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.forward(), eq Ok(0));
+
+        for n in 0..3 {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = Header {
+                value: n as u32 + 7,
+            };
+            sample.write_payload(n).send().unwrap();
+        }
+
+        assert_that!(sut.forward(), eq Ok(3));
+
+        for n in 0..3 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(*sample.user_header(), eq Header { value: n as u32 + 7 });
+            assert_that!(sample.header().publisher_id(), eq sut.downstream().id());
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn slices_are_forwarded_to_downstream_service<Upstream: Service, Downstream: Service>() {
+        let config = generate_isolated_config();
+        let upstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Upstream>()
+            .unwrap();
+        let downstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Downstream>()
+            .unwrap();
+
+        let upstream_service = upstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let downstream_service = downstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+
+        let mut sut = ForwardProxy::new(
+            upstream_service.subscriber_builder().create().unwrap(),
+            downstream_service
+                .publisher_builder()
+                .initial_max_slice_len(2)
+                .allocation_strategy(AllocationStrategy::PowerOfTwo)
+                .create()
+                .unwrap(),
+        );
+
+        let publisher = upstream_service
+            .publisher_builder()
+            .initial_max_slice_len(64)
+            .create()
+            .unwrap();
+        let subscriber = downstream_service.subscriber_builder().create().unwrap();
+
+        let payload: Vec<u16> = (0..50).collect();
+        publisher.send_slice_copy(&payload).unwrap();
+
+        assert_that!(sut.forward(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq payload.as_slice());
+    }
+
+    #[test]
+    fn sample_is_retained_when_downstream_publisher_cannot_loan<
+        Upstream: Service,
+        Downstream: Service,
+    >() {
+        let config = generate_isolated_config();
+        let upstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Upstream>()
+            .unwrap();
+        let downstream_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Downstream>()
+            .unwrap();
+
+        let upstream_service = upstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let downstream_service = downstream_node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut = ForwardProxy::new(
+            upstream_service.subscriber_builder().create().unwrap(),
+            downstream_service
+                .publisher_builder()
+                .max_loaned_samples(1)
+                .create()
+                .unwrap(),
+        );
+
+        let publisher = upstream_service.publisher_builder().create().unwrap();
+        let subscriber = downstream_service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(42).unwrap();
+
+        let loaned_sample = sut.downstream().loan_uninit().unwrap();
+        assert_that!(sut.forward(), eq Err(ForwardProxyError::LoanError(LoanError::ExceedsMaxLoans)));
+
+        drop(loaned_sample);
+        assert_that!(sut.forward(), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 42);
+    }
+
+    #[test]
+    fn proxy_on_the_same_service_does_not_forward_its_own_samples<
+        Upstream: Service,
+        Downstream: Service,
+    >() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<Upstream>()
+            .unwrap();
+
+        let service = node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let mut sut = ForwardProxy::new(
+            service.subscriber_builder().create().unwrap(),
+            service.publisher_builder().create().unwrap(),
+        );
+
+        let publisher = service.publisher_builder().create().unwrap();
+        publisher.send_copy(42).unwrap();
+
+        assert_that!(sut.forward(), eq Ok(1));
+        assert_that!(sut.forward(), eq Ok(0));
+
+        let (subscriber, _publisher) = sut.into_ports();
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2::service::ipc::Service>)]
+    mod ipc_to_ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, iceoryx2::service::ipc::Service>)]
+    mod local_to_ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service, iceoryx2::service::local_threadsafe::Service>)]
+    mod ipc_threadsafe_to_local_threadsafe {}
+}
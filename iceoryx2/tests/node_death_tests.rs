@@ -12,17 +12,31 @@
 
 #[generic_tests::define]
 mod node_death_tests {
+    use core::alloc::Layout;
     use core::sync::atomic::{AtomicU32, Ordering};
+    use core::time::Duration;
+    use std::sync::{Arc, Mutex};
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::node_group::NodeGroup;
+    use iceoryx2::node::stale_resources::{StaleResourceKind, StaleResourceRemovalFailure};
     use iceoryx2::node::testing::__internal_node_staged_death;
     use iceoryx2::node::{CleanupState, NodeState};
     use iceoryx2::prelude::*;
-    use iceoryx2::service::Service;
+    use iceoryx2::service::{
+        __internal_discovery_announcement_service_name, Service, ServiceAnnouncement,
+    };
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
+    use iceoryx2_cal::named_concept::{
+        NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    };
+    use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+    use iceoryx2_cal::shm_allocator::pool_allocator;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
     struct TestDetails<S: Service> {
         node: Node<S>,
@@ -578,6 +592,892 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn dead_node_callback_is_called_once_for_every_dead_node<S: Test>() {
+        const NUMBER_OF_DEAD_NODES: usize = 3;
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let reported_nodes = Arc::new(Mutex::new(Vec::new()));
+        let reported_nodes_clone = reported_nodes.clone();
+        sut.on_dead_node(move |node_id| reported_nodes_clone.lock().unwrap().push(*node_id));
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(reported_nodes.lock().unwrap(), len 0);
+
+        let mut dead_node_ids = vec![];
+        for _ in 0..NUMBER_OF_DEAD_NODES {
+            let mut dead_node = S::create_test_node(&config);
+            dead_node_ids.push(*dead_node.node.id());
+            S::staged_death(&mut dead_node.node);
+            core::mem::forget(dead_node.node);
+        }
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+
+        let mut reported_nodes = reported_nodes.lock().unwrap().clone();
+        reported_nodes.sort();
+        dead_node_ids.sort();
+        assert_that!(reported_nodes, eq dead_node_ids);
+    }
+
+    #[test]
+    fn dead_node_callback_is_called_in_wait<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        sut.on_dead_node(move |_| {
+            counter_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.wait(Duration::ZERO), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+        assert_that!(sut.wait(Duration::ZERO), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn dead_node_callback_can_register_further_callbacks<S: Test>()
+    where
+        S::Service: 'static,
+    {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = Arc::new(
+            NodeBuilder::new()
+                .config(&config)
+                .create::<S::Service>()
+                .unwrap(),
+        );
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        let sut_clone = sut.clone();
+        sut.on_dead_node(move |_| {
+            let counter_clone = counter_clone.clone();
+            sut_clone.on_dead_node(move |_| {
+                counter_clone.fetch_add(1, Ordering::Relaxed);
+            });
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 0);
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn dead_node_callbacks_remain_registered_when_a_callback_panics<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let has_panicked = Arc::new(IoxAtomicBool::new(false));
+        let has_panicked_clone = has_panicked.clone();
+        sut.on_dead_node(move |_| {
+            if !has_panicked_clone.swap(true, Ordering::Relaxed) {
+                panic!("first dead node callback panics");
+            }
+        });
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        sut.on_dead_node(move |_| {
+            counter_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| sut.check_for_dead_nodes()));
+        assert_that!(result, is_err);
+        assert_that!(counter.load(Ordering::Relaxed), eq 0);
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn concurrent_checks_report_every_dead_node_once<S: Test>()
+    where
+        S::Service: 'static,
+    {
+        const NUMBER_OF_THREADS: usize = 4;
+        let _watchdog = Watchdog::new();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = Arc::new(
+            NodeBuilder::new()
+                .config(&config)
+                .create::<S::Service>()
+                .unwrap(),
+        );
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        sut.on_dead_node(move |_| {
+            counter_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        let barrier = Arc::new(std::sync::Barrier::new(NUMBER_OF_THREADS));
+        let threads: Vec<_> = (0..NUMBER_OF_THREADS)
+            .map(|_| {
+                let sut = sut.clone();
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    sut.check_for_dead_nodes().unwrap();
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn dead_node_callback_that_checks_again_does_not_report_the_node_twice<S: Test>()
+    where
+        S::Service: 'static,
+    {
+        let _watchdog = Watchdog::new();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = Arc::new(
+            NodeBuilder::new()
+                .config(&config)
+                .create::<S::Service>()
+                .unwrap(),
+        );
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        let sut_clone = sut.clone();
+        sut.on_dead_node(move |_| {
+            counter_clone.fetch_add(1, Ordering::Relaxed);
+            sut_clone.check_for_dead_nodes().unwrap();
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 1);
+    }
+
+    #[test]
+    fn dead_node_callback_is_not_called_for_cleaned_up_nodes<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let counter = Arc::new(AtomicU32::new(0));
+        let counter_clone = counter.clone();
+        sut.on_dead_node(move |_| {
+            counter_clone.fetch_add(1, Ordering::Relaxed);
+        });
+
+        let mut dead_node = S::create_test_node(&config);
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config).cleanups, eq 1);
+        assert_that!(sut.check_for_dead_nodes(), is_ok);
+        assert_that!(counter.load(Ordering::Relaxed), eq 0);
+    }
+
+    #[test]
+    fn dead_member_of_node_group_is_removed_from_services_of_other_members<S: Test>() {
+        let _watchdog = Watchdog::new();
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let mut sut = NodeGroup::<S::Service>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        assert_that!(sut.start_all(), is_ok);
+
+        let mut dead_member = S::create_test_node(&config).node;
+        let dead_member_id = *dead_member.id();
+        sut.watch_node(dead_member_id);
+
+        let service = sut.nodes()[0]
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _publisher = service.publisher_builder().create().unwrap();
+        let dead_service = dead_member
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let dead_subscriber = dead_service.subscriber_builder().create().unwrap();
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+
+        S::staged_death(&mut dead_member);
+        core::mem::forget(dead_subscriber);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_member);
+
+        let terminated = sut.wait_any_terminated(Duration::from_millis(10));
+        assert_that!(terminated, eq Ok(vec![dead_member_id]));
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(sut.member_ids(), len 1);
+    }
+
+    #[test]
+    fn connected_subscribers_of_dead_nodes_are_not_listed<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut dead_node = S::create_test_node(&config);
+        let dead_service = dead_node
+            .node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let dead_subscriber = dead_service.subscriber_builder().create().unwrap();
+
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_subscriber);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node.node);
+
+        // the stale resources of the dead node are not yet removed
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 2);
+
+        let mut subscribers = vec![];
+        sut.connected_subscribers(|details| {
+            subscribers.push((details.subscriber_id, details.node_id));
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(subscribers, eq vec![(subscriber.id(), *node.id())]);
+    }
+
+    #[test]
+    fn servers_of_dead_nodes_are_removed_from_connected_servers_after_cleanup<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let mut dead_node = S::create_test_node(&config);
+        let dead_service = dead_node
+            .node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        let dead_server = dead_service.server_builder().create().unwrap();
+        assert_that!(sut.has_server(), eq Ok(true));
+
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_server);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config).cleanups, eq 1);
+        assert_that!(sut.has_server(), eq Ok(false));
+        assert_that!(sut.number_of_connected_servers(), eq Ok(0));
+    }
+
+    #[test]
+    fn credits_of_requests_queued_at_dead_server_are_returned_after_cleanup<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(2)
+            .create()
+            .unwrap();
+        let sut = service.client_builder().create().unwrap();
+
+        let mut dead_node = S::create_test_node(&config);
+        let dead_service = dead_node
+            .node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        let dead_server = dead_service.server_builder().create().unwrap();
+
+        let _pending_response_1 = sut.send_copy(123).unwrap();
+        let _pending_response_2 = sut.send_copy(456).unwrap();
+        assert_that!(sut.available_credits(), eq Some(0));
+
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_server);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config).cleanups, eq 1);
+        assert_that!(sut.available_credits(), eq Some(2));
+    }
+
+    #[test]
+    fn stale_resource_report_contains_all_resources_of_dead_nodes_without_removing_them<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut dead_node = S::create_test_node(&config);
+        let pubsub = dead_node
+            .node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let req_res = dead_node
+            .node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = req_res.client_builder().create().unwrap();
+        let server = req_res.server_builder().create().unwrap();
+        let dead_node_id = *dead_node.node.id();
+
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(publisher);
+        core::mem::forget(client);
+        core::mem::forget(server);
+        core::mem::forget(pubsub);
+        core::mem::forget(req_res);
+        core::mem::forget(dead_node.node);
+
+        let sut = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        let number_of = |kind| {
+            sut.resources()
+                .iter()
+                .filter(|resource| resource.kind() == kind)
+                .count()
+        };
+        assert_that!(number_of(StaleResourceKind::DeadNode), eq 1);
+        assert_that!(number_of(StaleResourceKind::DataSegment), eq 3);
+        assert_that!(number_of(StaleResourceKind::ServiceFiles), eq 2);
+        for resource in sut.resources() {
+            assert_that!(resource.node_id(), eq Some(&dead_node_id));
+            assert_that!(resource.service_id().is_some(), eq resource.kind() != StaleResourceKind::DeadNode);
+            if resource.kind() == StaleResourceKind::DataSegment {
+                assert_that!(resource.size().unwrap(), gt 0);
+            }
+        }
+        assert_that!(sut.total_size(), gt 0);
+
+        // nothing was removed
+        assert_that!(Node::<S::Service>::stale_resource_report(&config).unwrap(), eq sut);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals, len sut.len());
+        for removal in &removals {
+            assert_that!(removal.result(), eq Ok(true));
+        }
+
+        assert_that!(
+            Node::<S::Service>::stale_resource_report(&config).unwrap(),
+            is_empty
+        );
+        assert_that!(
+            S::Service::list(&config, |_| {
+                test_fail!("after the cleanup there shall be no more services");
+            }),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn stale_resource_report_does_not_contain_services_with_alive_nodes<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let _service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut dead_node = S::create_test_node(&config);
+        let dead_service = dead_node
+            .node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node.node);
+
+        let sut = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        assert_that!(sut, len 1);
+        assert_that!(sut.resources()[0].kind(), eq StaleResourceKind::DeadNode);
+    }
+
+    #[test]
+    fn remove_reported_stale_resources_removes_only_nodes_of_the_report<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut dead_node_ids = vec![];
+        for _ in 0..2 {
+            let mut dead_node = S::create_test_node(&config);
+            dead_node_ids.push(*dead_node.node.id());
+            S::staged_death(&mut dead_node.node);
+            core::mem::forget(dead_node.node);
+        }
+
+        let mut sut = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        assert_that!(sut, len 2);
+        sut.retain(|resource| resource.node_id() == Some(&dead_node_ids[0]));
+        assert_that!(sut, len 1);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals, len 1);
+        assert_that!(*removals[0].resource(), eq sut.resources()[0]);
+        assert_that!(removals[0].result(), eq Ok(true));
+
+        let remaining = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        assert_that!(remaining, len 1);
+        assert_that!(remaining.resources()[0].node_id(), eq Some(&dead_node_ids[1]));
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals[0].result(), eq Ok(false));
+    }
+
+    #[test]
+    fn remove_reported_stale_resources_rejects_incomplete_node_selections<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut dead_node = S::create_test_node(&config);
+        let service = dead_node
+            .node
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(publisher);
+        core::mem::forget(service);
+        core::mem::forget(dead_node.node);
+
+        let report = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        assert_that!(report, len 3);
+
+        let mut sut = report.clone();
+        sut.retain(|resource| resource.kind() != StaleResourceKind::DataSegment);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals, len 2);
+        for removal in &removals {
+            assert_that!(removal.result(), eq Err(StaleResourceRemovalFailure::IncompleteNodeSelection));
+        }
+        assert_that!(Node::<S::Service>::stale_resource_report(&config).unwrap(), eq report);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &report);
+        for removal in &removals {
+            assert_that!(removal.result(), eq Ok(true));
+        }
+        assert_that!(
+            Node::<S::Service>::stale_resource_report(&config).unwrap(),
+            is_empty
+        );
+    }
+
+    #[test]
+    fn remove_reported_stale_resources_rejects_service_files_used_by_unselected_dead_nodes<
+        S: Test,
+    >() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_service_name();
+
+        let mut dead_node_ids = vec![];
+        for _ in 0..2 {
+            let mut dead_node = S::create_test_node(&config);
+            let service = dead_node
+                .node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            dead_node_ids.push(*dead_node.node.id());
+            S::staged_death(&mut dead_node.node);
+            core::mem::forget(service);
+            core::mem::forget(dead_node.node);
+        }
+
+        let report = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        let owner = *report
+            .resources()
+            .iter()
+            .find(|resource| resource.kind() == StaleResourceKind::ServiceFiles)
+            .unwrap()
+            .node_id()
+            .unwrap();
+
+        let mut sut = report.clone();
+        sut.retain(|resource| resource.node_id() == Some(&owner));
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        for removal in &removals {
+            assert_that!(removal.result(), eq Err(StaleResourceRemovalFailure::IncompleteNodeSelection));
+        }
+        assert_that!(Node::<S::Service>::stale_resource_report(&config).unwrap(), eq report);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &report);
+        for removal in &removals {
+            assert_that!(removal.result(), eq Ok(true));
+        }
+        assert_that!(
+            Node::<S::Service>::stale_resource_report(&config).unwrap(),
+            is_empty
+        );
+    }
+
+    #[test]
+    fn stale_resource_report_contains_orphaned_data_segments<S: Test>() {
+        let config = generate_isolated_config();
+
+        // a port id whose creating process does not exist
+        let port_id = i32::MAX as u128;
+        let segment_name = FileName::new(port_id.to_string().as_bytes()).unwrap();
+        let segment_config =
+            <<S::Service as Service>::SharedMemory as NamedConceptMgmt>::Configuration::default()
+                .prefix(&config.global.prefix)
+                .suffix(&config.global.service.data_segment_suffix)
+                .path_hint(config.global.root_path());
+        let segment =
+            <<S::Service as Service>::SharedMemory as SharedMemory<_>>::Builder::new(&segment_name)
+                .config(&segment_config)
+                .size(1024)
+                .has_ownership(false)
+                .create(&pool_allocator::Config {
+                    bucket_layout: Layout::new::<u64>(),
+                })
+                .unwrap();
+        drop(segment);
+
+        let sut = Node::<S::Service>::stale_resource_report(&config).unwrap();
+        assert_that!(sut, len 1);
+        assert_that!(sut.resources()[0].kind(), eq StaleResourceKind::DataSegment);
+        assert_that!(*sut.resources()[0].name(), eq segment_name);
+        assert_that!(sut.resources()[0].node_id(), eq None);
+        assert_that!(sut.resources()[0].size().unwrap(), ge 1024);
+
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals, len 1);
+        assert_that!(removals[0].result(), eq Ok(true));
+
+        assert_that!(
+            Node::<S::Service>::stale_resource_report(&config).unwrap(),
+            is_empty
+        );
+        let removals = Node::<S::Service>::remove_reported_stale_resources(&config, &sut);
+        assert_that!(removals[0].result(), eq Ok(false));
+    }
+
+    #[test]
+    fn removal_of_services_of_dead_nodes_is_not_announced<S: Test>() {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.global.service.enable_discovery_announcements = true;
+        let service_name = generate_service_name();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let announcements = node
+            .service_builder(&__internal_discovery_announcement_service_name())
+            .publish_subscribe::<ServiceAnnouncement>()
+            .create()
+            .unwrap();
+        let subscriber = announcements.subscriber_builder().create().unwrap();
+
+        let mut dead_node = S::create_test_node(&config);
+        let dead_service = dead_node
+            .node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(matches!(*sample, ServiceAnnouncement::ServiceAdded(_)), eq true);
+        assert_that!(*sample.static_config().name(), eq service_name);
+        assert_that!(sample.static_config().service_id(), eq dead_service.service_id());
+        drop(sample);
+
+        S::staged_death(&mut dead_node.node);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node.node);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(
+            S::Service::does_exist(&service_name, &config, MessagingPattern::PublishSubscribe),
+            eq Ok(false)
+        );
+
+        // the ServiceAdded record of the crashed creator is stale
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
+
+mod exclusive_ownership_failover {
+    use core::time::Duration;
+    use std::process::Command;
+    use std::time::Instant;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::node::CleanupState;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const PREFIX_ENV: &str = "IOX2_TEST_OWNERSHIP_PREFIX";
+    const SERVICE_NAME_ENV: &str = "IOX2_TEST_OWNERSHIP_SERVICE_NAME";
+    const NODE_MONITORING_INTERVAL_ENV: &str = "IOX2_TEST_OWNERSHIP_NODE_MONITORING_INTERVAL_MS";
+    const MONITORING_INTERVAL: Duration = Duration::from_millis(100);
+    // the failover has to happen within a few monitoring intervals, the margin keeps the
+    // tests independent of the scheduling of the system
+    const FAILOVER_LATENCY_FACTOR: u32 = 10;
+    const OWNER_PROCESS_LIFETIME: Duration = Duration::from_secs(10);
+    const STRONG_PAYLOAD: u64 = 10;
+    const WEAK_PAYLOAD: u64 = 1;
+
+    fn generate_config(prefix: Option<&str>, ownership_monitoring_interval: Duration) -> Config {
+        let mut config = generate_isolated_config();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        config.defaults.publish_subscribe.enable_exclusive_ownership = true;
+        config
+            .defaults
+            .publish_subscribe
+            .ownership_monitoring_interval = ownership_monitoring_interval;
+        if let Some(prefix) = prefix {
+            config.global.prefix = FileName::new(prefix.as_bytes()).unwrap();
+        }
+        config
+    }
+
+    // the process that owns the strong publisher, it is started and killed by
+    // failover_after_owner_process_is_killed() and terminates by itself when it is not killed
+    #[test]
+    #[ignore]
+    fn strong_publisher_process() {
+        let (Ok(prefix), Ok(service_name)) =
+            (std::env::var(PREFIX_ENV), std::env::var(SERVICE_NAME_ENV))
+        else {
+            return;
+        };
+
+        let config = generate_config(Some(&prefix), MONITORING_INTERVAL);
+        let mut node_builder = NodeBuilder::new().config(&config);
+        if let Ok(monitoring_interval) = std::env::var(NODE_MONITORING_INTERVAL_ENV) {
+            node_builder = node_builder
+                .monitoring_interval(Duration::from_millis(monitoring_interval.parse().unwrap()));
+        }
+        let node = node_builder.create::<ipc::Service>().unwrap();
+        let service = node
+            .service_builder(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .ownership_strength(10)
+            .create()
+            .unwrap();
+
+        let start = Instant::now();
+        while start.elapsed() < OWNER_PROCESS_LIFETIME {
+            publisher.send_copy(STRONG_PAYLOAD).unwrap();
+            std::thread::sleep(MONITORING_INTERVAL / 10);
+        }
+    }
+
+    // spawns the owning process, kills it and verifies that the subscriber receives the
+    // samples of the weaker publisher within FAILOVER_LATENCY_FACTOR monitoring intervals
+    fn failover_after_owner_process_is_killed(
+        ownership_monitoring_interval: Duration,
+        owner_node_monitoring_interval: Option<Duration>,
+    ) {
+        let config = generate_config(None, ownership_monitoring_interval);
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<ipc::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let weak_publisher = service
+            .publisher_builder()
+            .ownership_strength(1)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut owner_process = Command::new(std::env::current_exe().unwrap());
+        owner_process
+            .args([
+                "--ignored",
+                "--exact",
+                "exclusive_ownership_failover::strong_publisher_process",
+            ])
+            .env(PREFIX_ENV, config.global.prefix.to_string())
+            .env(SERVICE_NAME_ENV, service_name.as_str());
+        if let Some(monitoring_interval) = owner_node_monitoring_interval {
+            owner_process.env(
+                NODE_MONITORING_INTERVAL_ENV,
+                monitoring_interval.as_millis().to_string(),
+            );
+        }
+        let mut owner_process = owner_process.spawn().unwrap();
+
+        // wait until the strong publisher owns the service
+        loop {
+            if let Some(sample) = subscriber.receive().unwrap() {
+                if *sample == STRONG_PAYLOAD {
+                    break;
+                }
+            }
+            std::thread::sleep(MONITORING_INTERVAL / 10);
+        }
+
+        weak_publisher.send_copy(WEAK_PAYLOAD).unwrap();
+        while let Some(sample) = subscriber.receive().unwrap() {
+            assert_that!(*sample, eq STRONG_PAYLOAD);
+        }
+
+        owner_process.kill().unwrap();
+        owner_process.wait().unwrap();
+
+        // the dead publisher remains in the service until the dead node is cleaned up, the
+        // subscriber detects the death with the node monitoring
+        let failover_timeout = owner_node_monitoring_interval
+            .unwrap_or(config.global.node.monitoring_interval)
+            * FAILOVER_LATENCY_FACTOR;
+        let start = Instant::now();
+        let mut has_failed_over = false;
+        while !has_failed_over && start.elapsed() < failover_timeout {
+            weak_publisher.send_copy(WEAK_PAYLOAD).unwrap();
+            while let Some(sample) = subscriber.receive().unwrap() {
+                has_failed_over |= *sample == WEAK_PAYLOAD;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_that!(has_failed_over, eq true);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 2);
+
+        assert_that!(Node::<ipc::Service>::cleanup_dead_nodes(&config), eq CleanupState { cleanups: 1, failed_cleanups: 0 });
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+    }
+
+    #[test]
+    fn subscriber_fails_over_to_weaker_publisher_when_owner_process_is_killed() {
+        let _watchdog = Watchdog::new();
+
+        failover_after_owner_process_is_killed(MONITORING_INTERVAL, None);
+    }
+
+    #[test]
+    fn subscriber_uses_monitoring_interval_of_owner_node_to_detect_its_death() {
+        let _watchdog = Watchdog::new();
+        let owner_node_monitoring_interval = Duration::from_millis(20);
+
+        // the configured ownership monitoring interval is only the fallback when the interval
+        // of the owning node is unknown, with the fallback the failover would take an hour
+        failover_after_owner_process_is_killed(
+            Duration::from_secs(3600),
+            Some(owner_node_monitoring_interval),
+        );
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node_group {
+    use core::time::Duration;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::node::node_group::NodeGroup;
+    use iceoryx2::node::NodeState;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const CYCLE_TIME: Duration = Duration::from_millis(10);
+
+    fn number_of_alive_nodes<S: Service>(config: &Config) -> usize {
+        let mut counter = 0;
+        Node::<S>::list(config, |node_state| {
+            if let NodeState::Alive(_) = node_state {
+                counter += 1;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        counter
+    }
+
+    #[test]
+    fn new_node_group_is_empty_and_not_running<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeGroup::<S>::new(&config);
+
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.len(), eq 0);
+        assert_that!(sut.is_running(), eq false);
+        assert_that!(sut.nodes(), is_empty);
+    }
+
+    #[test]
+    fn start_all_creates_all_nodes_with_the_group_config<S: Service>() {
+        const NUMBER_OF_NODES: usize = 4;
+        let config = generate_isolated_config();
+        let mut sut = NodeGroup::<S>::new(&config);
+        for n in 0..NUMBER_OF_NODES {
+            sut.add_node(
+                NodeBuilder::new().name(&format!("node_{n}").as_str().try_into().unwrap()),
+            );
+        }
+        assert_that!(sut.len(), eq NUMBER_OF_NODES);
+        assert_that!(number_of_alive_nodes::<S>(&config), eq 0);
+
+        assert_that!(sut.start_all(), is_ok);
+
+        assert_that!(sut.is_running(), eq true);
+        assert_that!(sut.nodes(), len NUMBER_OF_NODES);
+        for (n, node) in sut.nodes().iter().enumerate() {
+            assert_that!(node.name().as_str(), eq format!("node_{n}"));
+            assert_that!(*node.config(), eq config);
+        }
+        assert_that!(number_of_alive_nodes::<S>(&config), eq NUMBER_OF_NODES);
+    }
+
+    #[test]
+    fn stop_all_drops_all_nodes<S: Service>() {
+        const NUMBER_OF_NODES: usize = 3;
+        let config = generate_isolated_config();
+        let mut sut = NodeGroup::<S>::new(&config);
+        for _ in 0..NUMBER_OF_NODES {
+            sut.add_node(NodeBuilder::new());
+        }
+
+        assert_that!(sut.start_all(), is_ok);
+        sut.stop_all();
+
+        assert_that!(sut.is_running(), eq false);
+        assert_that!(sut.nodes(), is_empty);
+        assert_that!(sut.len(), eq NUMBER_OF_NODES);
+        assert_that!(number_of_alive_nodes::<S>(&config), eq 0);
+    }
+
+    #[test]
+    fn node_group_can_be_restarted<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        sut.add_node(NodeBuilder::new());
+
+        assert_that!(sut.start_all(), is_ok);
+        let first_ids = sut.member_ids();
+        sut.stop_all();
+        assert_that!(sut.start_all(), is_ok);
+
+        assert_that!(sut.nodes(), len 2);
+        for id in sut.member_ids() {
+            assert_that!(first_ids.contains(&id), eq false);
+        }
+        assert_that!(number_of_alive_nodes::<S>(&config), eq 2);
+    }
+
+    #[test]
+    fn start_all_creates_only_nodes_that_are_not_running<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        assert_that!(sut.start_all(), is_ok);
+        let first_id = *sut.nodes()[0].id();
+
+        sut.add_node(NodeBuilder::new());
+        assert_that!(sut.is_running(), eq false);
+        assert_that!(sut.start_all(), is_ok);
+
+        assert_that!(sut.is_running(), eq true);
+        assert_that!(sut.nodes(), len 2);
+        assert_that!(*sut.nodes()[0].id(), eq first_id);
+    }
+
+    #[test]
+    fn dropping_the_node_group_stops_all_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        sut.add_node(NodeBuilder::new());
+        assert_that!(sut.start_all(), is_ok);
+
+        drop(sut);
+
+        assert_that!(number_of_alive_nodes::<S>(&config), eq 0);
+    }
+
+    #[test]
+    fn member_ids_contain_running_and_watched_nodes<S: Service>() {
+        let config = generate_isolated_config();
+        let watched_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        sut.watch_node(*watched_node.id());
+        sut.watch_node(*watched_node.id());
+        assert_that!(sut.start_all(), is_ok);
+
+        let ids = sut.member_ids();
+        let group_node_id = *sut.nodes()[0].id();
+        let watched_node_id = *watched_node.id();
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains group_node_id);
+        assert_that!(ids, contains watched_node_id);
+    }
+
+    #[test]
+    fn wait_any_terminated_reports_watched_node_that_was_dropped<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let watched_node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let watched_node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let watched_id_1 = *watched_node_1.id();
+        let watched_id_2 = *watched_node_2.id();
+
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.add_node(NodeBuilder::new());
+        sut.watch_node(watched_id_1);
+        sut.watch_node(watched_id_2);
+        assert_that!(sut.start_all(), is_ok);
+
+        drop(watched_node_1);
+
+        let terminated = sut.wait_any_terminated(CYCLE_TIME);
+        assert_that!(terminated, eq Ok(vec![watched_id_1]));
+        assert_that!(sut.member_ids().contains(&watched_id_1), eq false);
+        assert_that!(sut.member_ids(), contains watched_id_2);
+    }
+
+    #[test]
+    fn wait_all_terminated_returns_when_all_watched_nodes_terminated<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let watched_node_1 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let watched_node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let mut sut = NodeGroup::<S>::new(&config);
+        sut.watch_node(*watched_node_1.id());
+        sut.watch_node(*watched_node_2.id());
+
+        drop(watched_node_1);
+        drop(watched_node_2);
+
+        assert_that!(sut.wait_all_terminated(CYCLE_TIME), is_ok);
+        assert_that!(sut.member_ids(), is_empty);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
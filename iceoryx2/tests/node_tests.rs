@@ -19,7 +19,9 @@ mod node {
     use iceoryx2::config::Config;
     use iceoryx2::node::{
         NodeCleanupFailure, NodeCreationFailure, NodeId, NodeListFailure, NodeState, NodeView,
+        NodeWaitFailure, OpenedService, ServiceRole,
     };
+    use iceoryx2::port::port_identifiers::UniquePortId;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2::testing::*;
@@ -394,6 +396,385 @@ mod node {
         assert_that!(node.signal_handling_mode(), eq SignalHandlingMode::HandleTerminationRequests);
     }
 
+    #[test]
+    fn shutdown_request_is_delivered_to_all_nodes_that_handle_it<S: Service>() {
+        let config = generate_isolated_config();
+        let sut_1 = NodeBuilder::new()
+            .handle_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_2 = NodeBuilder::new()
+            .handle_shutdown_requests(true)
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let ignoring_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut_1.wait(Duration::ZERO), is_ok);
+        assert_that!(sut_2.wait(Duration::ZERO), is_ok);
+
+        assert_that!(ignoring_node.signal_shutdown(), is_ok);
+
+        assert_that!(sut_1.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+        assert_that!(sut_2.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+        assert_that!(ignoring_node.wait(Duration::ZERO), is_ok);
+
+        // the shutdown request remains active
+        assert_that!(sut_1.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+    }
+
+    #[test]
+    fn shutdown_requests_are_separated_by_config<S: Service>() {
+        let config_1 = generate_isolated_config();
+        let config_2 = generate_isolated_config();
+        let sut_1 = NodeBuilder::new()
+            .handle_shutdown_requests(true)
+            .config(&config_1)
+            .create::<S>()
+            .unwrap();
+        let sut_2 = NodeBuilder::new()
+            .handle_shutdown_requests(true)
+            .config(&config_2)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut_1.signal_shutdown(), is_ok);
+
+        assert_that!(sut_1.wait(Duration::ZERO).err(), eq Some(NodeWaitFailure::ShutdownRequest));
+        assert_that!(sut_2.wait(Duration::ZERO), is_ok);
+    }
+
+    #[test]
+    fn wait_returns_early_when_shutdown_is_requested<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let sut = NodeBuilder::new()
+                    .handle_shutdown_requests(true)
+                    .config(&config)
+                    .create::<S>()
+                    .unwrap();
+                barrier.wait();
+
+                let result = sut.wait(Duration::from_secs(3600));
+                assert_that!(result.err(), eq Some(NodeWaitFailure::ShutdownRequest));
+            });
+
+            barrier.wait();
+            let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+            assert_that!(node.signal_shutdown(), is_ok);
+        });
+    }
+
+    fn monitoring_interval_in_node_details<S: Service>(node: &Node<S>) -> Duration {
+        let mut monitoring_interval = None;
+        Node::<S>::list(node.config(), |node_state| {
+            if let NodeState::Alive(view) = node_state {
+                if view.id() == node.id() {
+                    monitoring_interval =
+                        Some(view.details().as_ref().unwrap().monitoring_interval());
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        monitoring_interval.unwrap()
+    }
+
+    #[test]
+    fn by_default_monitoring_interval_of_config_is_used<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.monitoring_interval = Duration::from_millis(250);
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        assert_that!(sut.monitoring_interval(), eq Duration::from_millis(250));
+        assert_that!(monitoring_interval_in_node_details(&sut), eq Duration::from_millis(250));
+    }
+
+    #[test]
+    fn custom_monitoring_interval_is_propagated_into_node_details<S: Service>() {
+        let config = generate_isolated_config();
+        let sut_1 = NodeBuilder::new()
+            .monitoring_interval(Duration::from_millis(20))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_2 = NodeBuilder::new()
+            .monitoring_interval(Duration::from_secs(2))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut_1.monitoring_interval(), eq Duration::from_millis(20));
+        assert_that!(sut_2.monitoring_interval(), eq Duration::from_secs(2));
+        assert_that!(monitoring_interval_in_node_details(&sut_1), eq Duration::from_millis(20));
+        assert_that!(monitoring_interval_in_node_details(&sut_2), eq Duration::from_secs(2));
+    }
+
+    #[test]
+    fn monitoring_interval_is_clamped_to_configured_bounds<S: Service>() {
+        let mut config = generate_isolated_config();
+        config.global.node.min_monitoring_interval = Duration::from_millis(10);
+        config.global.node.max_monitoring_interval = Duration::from_secs(1);
+
+        let sut_too_small = NodeBuilder::new()
+            .monitoring_interval(Duration::from_nanos(1))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+        let sut_too_large = NodeBuilder::new()
+            .monitoring_interval(Duration::from_secs(3600))
+            .config(&config)
+            .create::<S>()
+            .unwrap();
+
+        assert_that!(sut_too_small.monitoring_interval(), eq Duration::from_millis(10));
+        assert_that!(sut_too_large.monitoring_interval(), eq Duration::from_secs(1));
+        assert_that!(monitoring_interval_in_node_details(&sut_too_small), eq Duration::from_millis(10));
+        assert_that!(monitoring_interval_in_node_details(&sut_too_large), eq Duration::from_secs(1));
+    }
+
+    fn opened_service<S: Service>(node: &Node<S>, name: &ServiceName) -> Option<OpenedService> {
+        node.opened_services()
+            .unwrap()
+            .into_iter()
+            .find(|s| s.name() == name)
+    }
+
+    #[test]
+    fn opened_services_contains_messaging_pattern_and_role<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let event_service_name = generate_service_name();
+        let creator = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let opener = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = creator
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _event_service = creator
+            .service_builder(&event_service_name)
+            .event()
+            .create()
+            .unwrap();
+        let _opened_service = opener
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let sut = opened_service(&creator, &service_name).unwrap();
+        assert_that!(sut.messaging_pattern(), eq MessagingPattern::PublishSubscribe);
+        assert_that!(sut.role(), eq ServiceRole::Creator);
+        assert_that!(sut.ports(), len 0);
+
+        let sut = opened_service(&creator, &event_service_name).unwrap();
+        assert_that!(sut.messaging_pattern(), eq MessagingPattern::Event);
+        assert_that!(sut.role(), eq ServiceRole::Creator);
+
+        let sut = opened_service(&opener, &service_name).unwrap();
+        assert_that!(sut.service_id(), eq service.service_id());
+        assert_that!(sut.role(), eq ServiceRole::Opener);
+        assert_that!(opened_service(&opener, &event_service_name), is_none);
+    }
+
+    #[test]
+    fn opened_services_lists_only_alive_ports_of_the_node<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let other_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let _other_subscriber = other_service.subscriber_builder().create().unwrap();
+
+        let ports = opened_service(&sut, &service_name)
+            .unwrap()
+            .ports()
+            .to_vec();
+        assert_that!(ports, len 2);
+        assert_that!(ports.contains(&UniquePortId::Publisher(publisher.id())), eq true);
+        assert_that!(ports.contains(&UniquePortId::Subscriber(subscriber.id())), eq true);
+
+        drop(publisher);
+
+        let ports = opened_service(&sut, &service_name)
+            .unwrap()
+            .ports()
+            .to_vec();
+        assert_that!(ports, eq vec![UniquePortId::Subscriber(subscriber.id())]);
+
+        drop(subscriber);
+        drop(service);
+
+        assert_that!(opened_service(&sut, &service_name), is_none);
+    }
+
+    #[test]
+    fn opened_services_can_be_serialized<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let _server = service.server_builder().create().unwrap();
+        let _client = service.client_builder().create().unwrap();
+
+        let sut = opened_service(&node, &service_name).unwrap();
+        assert_that!(sut.ports(), len 2);
+
+        let serialized = toml::to_string(&sut).unwrap();
+        let deserialized: OpenedService = toml::from_str(&serialized).unwrap();
+        assert_that!(deserialized, eq sut);
+    }
+
+    #[test]
+    fn shm_regions_lists_regions_of_alive_ports<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let pubsub = sut
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let reqres = sut
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let other_pubsub = other_node
+            .service_builder(pubsub.name())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(sut.shm_regions(), len 0);
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let _subscriber = pubsub.subscriber_builder().create().unwrap();
+        let _client = reqres.client_builder().create().unwrap();
+        let _server = reqres.server_builder().create().unwrap();
+        let _other_publisher = other_pubsub.publisher_builder().create().unwrap();
+
+        let regions = sut.shm_regions();
+        assert_that!(regions, len 3);
+        assert_that!(other_node.shm_regions(), len 1);
+
+        drop(publisher);
+
+        assert_that!(regions.iter().filter(|r| r.is_released()).count(), eq 1);
+        assert_that!(sut.shm_regions(), len 2);
+    }
+
+    #[test]
+    fn shm_region_usage_stats_track_loaned_samples<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = sut
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(2)
+            .create()
+            .unwrap();
+
+        let regions = sut.shm_regions();
+        assert_that!(regions, len 1);
+        let region = &regions[0];
+
+        let stats = region.usage_stats();
+        assert_that!(stats.region_name(), eq region.name());
+        assert_that!(stats.total_bytes(), gt 0);
+        assert_that!(stats.used_bytes(), eq 0);
+        assert_that!(stats.free_bytes(), eq stats.total_bytes());
+        assert_that!(stats.fragmentation_ratio(), eq 0.0);
+
+        let sample_1 = publisher.loan_uninit().unwrap();
+        let bucket_size = region.usage_stats().used_bytes();
+        assert_that!(bucket_size, ge core::mem::size_of::<u64>());
+
+        let sample_2 = publisher.loan_uninit().unwrap();
+        let stats = region.usage_stats();
+        assert_that!(stats.used_bytes(), eq 2 * bucket_size);
+        assert_that!(stats.free_bytes(), eq stats.total_bytes() - 2 * bucket_size);
+
+        drop(sample_1);
+        drop(sample_2);
+        assert_that!(region.usage_stats().used_bytes(), eq 0);
+
+        drop(publisher);
+        let stats = region.usage_stats();
+        assert_that!(region.is_released(), eq true);
+        assert_that!(stats.total_bytes(), eq 0);
+        assert_that!(stats.free_bytes(), eq 0);
+    }
+
+    #[test]
+    fn shm_region_reports_fragmentation_of_previous_segments<S: Service>() {
+        let config = generate_isolated_config();
+        let sut = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = sut
+            .service_builder(&generate_service_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .max_loaned_samples(2)
+            .create()
+            .unwrap();
+        let region = sut.shm_regions().remove(0);
+
+        let small_sample = publisher.loan_slice(8).unwrap();
+        let initial_total_bytes = region.usage_stats().total_bytes();
+        let large_sample = publisher.loan_slice(4096).unwrap();
+
+        let stats = region.usage_stats();
+        assert_that!(stats.total_bytes(), gt initial_total_bytes);
+        assert_that!(stats.fragmentation_ratio(), gt 0.0);
+        assert_that!(stats.fragmentation_ratio(), lt 1.0);
+
+        let grown_total_bytes = stats.total_bytes();
+        drop(small_sample);
+
+        let stats = region.usage_stats();
+        assert_that!(stats.total_bytes(), eq grown_total_bytes - initial_total_bytes);
+        assert_that!(stats.fragmentation_ratio(), eq 0.0);
+
+        drop(large_sample);
+        assert_that!(region.usage_stats().used_bytes(), eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
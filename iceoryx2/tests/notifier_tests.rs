@@ -18,6 +18,7 @@ mod notifier {
     use iceoryx2::{
         node::NodeBuilder,
         port::notifier::{NotifierCreateError, NotifierNotifyError},
+        prelude::EventId,
         service::Service,
     };
     use iceoryx2_bb_testing::assert_that;
@@ -32,6 +33,8 @@ mod notifier {
     fn notify_error_display_works<S: Service>() {
         assert_that!(
             format!("{}", NotifierNotifyError::EventIdOutOfBounds), eq "NotifierNotifyError::EventIdOutOfBounds");
+        assert_that!(
+            format!("{}", NotifierNotifyError::ListenerBufferOverflow), eq "NotifierNotifyError::ListenerBufferOverflow");
     }
 
     #[test]
@@ -58,6 +61,51 @@ mod notifier {
         }
     }
 
+    #[test]
+    fn default_event_id_is_zero_when_not_set<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = service.notifier_builder().create().unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        assert_that!(sut.default_event_id(), eq EventId::new(0));
+
+        assert_that!(sut.notify(), eq Ok(1));
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(sut.default_event_id()));
+    }
+
+    #[test]
+    fn default_event_id_returns_configured_event_id<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(19);
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let sut = service
+            .notifier_builder()
+            .default_event_id(event_id)
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        assert_that!(sut.default_event_id(), eq event_id);
+
+        assert_that!(sut.notify(), eq Ok(1));
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(event_id));
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -12,6 +12,8 @@
 
 #[generic_tests::define]
 mod pending_response {
+    use core::time::Duration;
+
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
     use iceoryx2::service::port_factory::request_response::PortFactory;
@@ -137,6 +139,115 @@ mod pending_response {
         assert_that!(sut.receive().unwrap(), is_none);
     }
 
+    #[test]
+    fn request_and_response_timestamps_are_set_on_send<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let request = test.client.loan().unwrap();
+        assert_that!(request.header().timestamp_ns(), eq 0);
+        let sut = request.send().unwrap();
+        let request_timestamp = sut.header().timestamp_ns();
+        assert_that!(request_timestamp, ne 0);
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        assert_that!(active_request.header().timestamp_ns(), eq request_timestamp);
+        let response = active_request.loan().unwrap();
+        assert_that!(response.header().timestamp_ns(), eq 0);
+        response.send().unwrap();
+
+        let response = sut.receive().unwrap().unwrap();
+        assert_that!(response.header().timestamp_ns(), ge request_timestamp);
+    }
+
+    #[test]
+    fn round_trip_latency_is_available_after_response_was_received<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(9).unwrap();
+        assert_that!(sut.round_trip_latency_ns(), is_none);
+
+        let active_request = test.server_1.receive().unwrap().unwrap();
+        active_request.send_copy(8).unwrap();
+        assert_that!(sut.round_trip_latency_ns(), is_none);
+
+        let response = sut.receive().unwrap().unwrap();
+        assert_that!(sut.round_trip_latency_ns(), eq Some(
+            response.header().timestamp_ns() - sut.header().timestamp_ns()
+        ));
+    }
+
+    #[test]
+    fn round_trip_latency_refers_to_latest_received_response<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(9).unwrap();
+
+        let active_request_1 = test.server_1.receive().unwrap().unwrap();
+        let active_request_2 = test.server_2.receive().unwrap().unwrap();
+        active_request_1.send_copy(1).unwrap();
+        active_request_2.send_copy(2).unwrap();
+
+        let _response_1 = sut.receive().unwrap().unwrap();
+        let response_2 = sut.receive().unwrap().unwrap();
+        assert_that!(sut.round_trip_latency_ns(), eq Some(
+            response_2.header().timestamp_ns() - sut.header().timestamp_ns()
+        ));
+    }
+
+    fn create_service_with_deadline<Sut: Service>(
+        node: &Node<Sut>,
+        deadline: Duration,
+    ) -> PortFactory<Sut, u64, (), u64, ()> {
+        node.service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .response_deadline(deadline)
+            .create()
+            .unwrap()
+    }
+
+    #[test]
+    fn without_response_deadline_the_deadline_is_never_missed<Sut: Service>() {
+        let test = TestFixture::<Sut>::new();
+        let sut = test.client.send_copy(9).unwrap();
+
+        assert_that!(sut.response_deadline(), eq None);
+        assert_that!(sut.time_until_deadline(), eq None);
+        assert_that!(sut.has_missed_deadline(), eq false);
+    }
+
+    #[test]
+    fn response_deadline_is_missed_when_no_response_arrives_in_time<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let deadline = Duration::from_millis(10);
+        let service = create_service_with_deadline(&node, deadline);
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let sut = client.send_copy(9).unwrap();
+        assert_that!(sut.response_deadline(), eq Some(deadline));
+        assert_that!(sut.time_until_deadline().unwrap(), le deadline);
+
+        std::thread::sleep(deadline * 2);
+
+        assert_that!(sut.has_missed_deadline(), eq true);
+        assert_that!(sut.time_until_deadline(), eq Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn response_deadline_is_not_missed_when_response_arrives_in_time<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = create_service_with_deadline(&node, Duration::from_secs(3600));
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let sut = client.send_copy(9).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(1).unwrap();
+        let _response = sut.receive().unwrap().unwrap();
+
+        assert_that!(sut.has_missed_deadline(), eq false);
+        assert_that!(sut.time_until_deadline(), eq None);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -17,13 +17,17 @@ mod publisher {
     use std::sync::Mutex;
     use std::time::Instant;
 
-    use iceoryx2::port::{publisher::PublisherCreateError, LoanError};
+    use iceoryx2::port::publisher::{PublisherCreateError, PublisherSetHistorySizeError};
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::port::{LoanError, SendError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2::testing::*;
     use iceoryx2_bb_posix::barrier::*;
+    use iceoryx2_bb_posix::huge_pages::HugePages;
+    use iceoryx2_bb_posix::numa::Numa;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
@@ -213,6 +217,454 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_send_slice_copy_delivers_slice<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 32;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let payload: Vec<u64> = (0..NUMBER_OF_ELEMENTS as u64).map(|n| n * 3).collect();
+        assert_that!(sut.send_slice_copy(&payload), eq Ok(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq payload.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_can_loan_and_send_empty_slice<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample = sut.loan_slice(0)?;
+        assert_that!(sample.payload(), len 0);
+        assert_that!(sample.send(), eq Ok(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), len 0);
+        assert_that!(sample.header().number_of_elements(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_slice_copy_with_more_than_max_elements_fails<Sut: Service>() -> TestResult<()>
+    {
+        const NUMBER_OF_ELEMENTS: usize = 125;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()?;
+
+        let payload = vec![0u64; NUMBER_OF_ELEMENTS + 1];
+        assert_that!(sut.send_slice_copy(&payload).err(), eq Some(SendError::LoanError(LoanError::ExceedsMaxLoanSize)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_slice_copy_grows_data_segment<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let payload = vec![7u64; NUMBER_OF_ELEMENTS * 8];
+        assert_that!(sut.send_slice_copy(&payload), eq Ok(1));
+        assert_that!(sut.number_of_data_segment_reallocations(), ge 1);
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq payload.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_slice_len_is_initial_max_slice_len_without_reallocation<Sut: Service>(
+    ) -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 125;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+
+        let _sample = sut.loan_slice(NUMBER_OF_ELEMENTS)?;
+
+        assert_that!(sut.max_slice_len(), eq NUMBER_OF_ELEMENTS);
+        assert_that!(sut.number_of_data_segment_reallocations(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_slice_len_grows_with_data_segment<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+
+        let mut slice_len = NUMBER_OF_ELEMENTS;
+        for n in 1..4 {
+            slice_len *= 4;
+            let sample = sut.loan_slice(slice_len)?;
+            drop(sample);
+
+            assert_that!(sut.max_slice_len(), ge slice_len);
+            assert_that!(sut.number_of_data_segment_reallocations(), eq n);
+        }
+
+        // fits into the current data segment
+        let _sample = sut.loan_slice(slice_len)?;
+        assert_that!(sut.number_of_data_segment_reallocations(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shrink_to_fit_releases_grown_data_segment<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+
+        assert_that!(sut.shrink_to_fit()?, eq 0);
+
+        let sample = sut.loan_slice(NUMBER_OF_ELEMENTS * 64)?;
+        drop(sample);
+        assert_that!(sut.max_slice_len(), ge NUMBER_OF_ELEMENTS * 64);
+
+        assert_that!(sut.shrink_to_fit()?, gt 0);
+        assert_that!(sut.max_slice_len(), lt NUMBER_OF_ELEMENTS * 64);
+        assert_that!(sut.max_slice_len(), ge NUMBER_OF_ELEMENTS);
+        assert_that!(sut.shrink_to_fit()?, eq 0);
+
+        let _sample = sut.loan_slice(NUMBER_OF_ELEMENTS)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn shrink_to_fit_keeps_samples_held_by_subscribers_valid<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        const LARGE_SLICE_LEN: usize = NUMBER_OF_ELEMENTS * 64;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample = sut.loan_slice_uninit(LARGE_SLICE_LEN)?;
+        let sample = sample.write_from_fn(|n| n as u64 * 3);
+        sample.send()?;
+
+        let received_sample = subscriber.receive()?.unwrap();
+        assert_that!(sut.shrink_to_fit()?, gt 0);
+
+        let sample = sut.loan_slice_uninit(NUMBER_OF_ELEMENTS)?;
+        let sample = sample.write_from_fn(|n| n as u64 * 5);
+        sample.send()?;
+
+        assert_that!(received_sample.payload(), len LARGE_SLICE_LEN);
+        for (n, value) in received_sample.payload().iter().enumerate() {
+            assert_that!(*value, eq n as u64 * 3);
+        }
+        drop(received_sample);
+
+        let received_sample = subscriber.receive()?.unwrap();
+        assert_that!(received_sample.payload(), len NUMBER_OF_ELEMENTS);
+        for (n, value) in received_sample.payload().iter().enumerate() {
+            assert_that!(*value, eq n as u64 * 5);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_does_not_use_huge_pages_by_default<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(sut.has_huge_pages(), eq false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_is_not_bound_to_numa_node_by_default<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+
+        assert_that!(sut.numa_node(), eq None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_ignores_non_existing_numa_node<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .numa_node(Numa::number_of_nodes())
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.numa_node(), eq None);
+        sut.send_copy(1234)?;
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1234);
+
+        Ok(())
+    }
+
+    fn publisher_numa_node_from_config_can_be_overridden<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.publisher_numa_node = Some(Numa::number_of_nodes());
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let sut_from_config = service
+            .publisher_builder()
+            .allocation_strategy(allocation_strategy)
+            .create()?;
+        let sut = service
+            .publisher_builder()
+            .allocation_strategy(allocation_strategy)
+            .numa_node(0)
+            .create()?;
+
+        assert_that!(sut_from_config.numa_node(), eq None);
+        // the setting is ignored when the system does not support numa
+        if Numa::is_supported() {
+            assert_that!(sut.numa_node() == Some(0) || sut.numa_node().is_none(), eq true);
+        } else {
+            assert_that!(sut.numa_node(), eq None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_numa_node_from_config_can_be_overridden_with_static_segment<Sut: Service>(
+    ) -> TestResult<()> {
+        publisher_numa_node_from_config_can_be_overridden::<Sut>(AllocationStrategy::Static)
+    }
+
+    #[test]
+    fn publisher_numa_node_from_config_can_be_overridden_with_dynamic_segment<Sut: Service>(
+    ) -> TestResult<()> {
+        publisher_numa_node_from_config_can_be_overridden::<Sut>(AllocationStrategy::PowerOfTwo)
+    }
+
+    fn publisher_with_huge_pages_aligns_samples_to_huge_page_size<Sut: Service>(
+        allocation_strategy: AllocationStrategy,
+    ) -> TestResult<()> {
+        let huge_page_size = HugePages::page_size();
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        // falls back to regular pages when the system does not provide huge pages
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(huge_page_size)
+            .allocation_strategy(allocation_strategy)
+            .use_huge_pages(true)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample = sut.loan_slice_uninit(huge_page_size)?;
+        assert_that!((sample.header() as *const _ as usize) % huge_page_size, eq 0);
+        let sample = sample.write_from_fn(|n| (n % 251) as u8);
+        sample.send()?;
+
+        let received_sample = subscriber.receive()?.unwrap();
+        assert_that!(received_sample.payload(), len huge_page_size);
+        for (n, value) in received_sample.payload().iter().enumerate() {
+            assert_that!(*value, eq(n % 251) as u8);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_huge_pages_and_static_segment_aligns_samples_to_huge_page_size<
+        Sut: Service,
+    >() -> TestResult<()> {
+        publisher_with_huge_pages_aligns_samples_to_huge_page_size::<Sut>(
+            AllocationStrategy::Static,
+        )
+    }
+
+    #[test]
+    fn publisher_with_huge_pages_and_dynamic_segment_aligns_samples_to_huge_page_size<
+        Sut: Service,
+    >() -> TestResult<()> {
+        publisher_with_huge_pages_aligns_samples_to_huge_page_size::<Sut>(
+            AllocationStrategy::PowerOfTwo,
+        )
+    }
+
+    #[test]
+    fn shrink_to_fit_with_static_allocation_strategy_reclaims_nothing<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()?;
+
+        assert_that!(sut.shrink_to_fit()?, eq 0);
+        assert_that!(sut.number_of_data_segment_reallocations(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_static_allocation_strategy_never_reallocates<Sut: Service>() -> TestResult<()>
+    {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .initial_max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()?;
+
+        let _sample = sut.loan_slice(NUMBER_OF_ELEMENTS)?;
+        assert_that!(sut.loan_slice(NUMBER_OF_ELEMENTS + 1), is_err);
+
+        assert_that!(sut.max_slice_len(), eq NUMBER_OF_ELEMENTS);
+        assert_that!(sut.number_of_data_segment_reallocations(), eq 0);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -559,6 +1011,294 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn set_history_size_larger_than_service_history_size_fails<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(3)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.history_size(), eq 3);
+
+        assert_that!(sut.set_history_size(4).err(), eq Some(PublisherSetHistorySizeError::ExceedsMaxHistorySize));
+        assert_that!(sut.history_size(), eq 3);
+
+        assert_that!(sut.set_history_size(1), is_ok);
+        assert_that!(sut.history_size(), eq 1);
+        assert_that!(sut.set_history_size(3), is_ok);
+        assert_that!(sut.history_size(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_history_size_evicts_oldest_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(4)
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        for value in 0..4 {
+            sut.send_copy(value)?;
+        }
+
+        assert_that!(sut.set_history_size(2), is_ok);
+
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.update_connections(), is_ok);
+
+        assert_that!(*subscriber.receive()?.unwrap(), eq 2);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 3);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow_count_counts_samples_replaced_by_safe_overflow<Sut: Service>() -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let _subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.overflow_count(), eq 0);
+
+        for value in 0..BUFFER_SIZE as u64 + 3 {
+            sut.send_copy(value)?;
+        }
+
+        assert_that!(sut.overflow_count(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow_count_counts_discarded_samples<Sut: Service>() -> TestResult<()> {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let _subscriber_2 = service.subscriber_builder().create()?;
+
+        for value in 0..BUFFER_SIZE as u64 + 1 {
+            sut.send_copy(value)?;
+        }
+        assert_that!(sut.overflow_count(), eq 2);
+
+        while subscriber_1.receive()?.is_some() {}
+        sut.send_copy(0)?;
+        assert_that!(sut.overflow_count(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drain_releases_history_and_reclaims_returned_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        sut.send_copy(2)?;
+        while subscriber.receive()?.is_some() {}
+
+        let summary = sut.drain();
+        assert_that!(summary.released_history_samples, eq 2);
+        assert_that!(summary.reclaimed_samples, eq 2);
+        assert_that!(summary.outstanding_loans, eq 0);
+        assert_that!(summary.samples_in_flight, eq 0);
+        assert_that!(summary.is_clean(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drain_reports_outstanding_loans_and_samples_in_flight<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        sut.send_copy(1)?;
+        let sample = sut.loan()?;
+
+        let summary = sut.drain();
+        assert_that!(summary.outstanding_loans, eq 1);
+        assert_that!(summary.samples_in_flight, eq 1);
+        assert_that!(summary.is_clean(), eq false);
+
+        drop(sample);
+        assert_that!(subscriber.receive()?, is_some);
+
+        let summary = sut.drain();
+        assert_that!(summary.reclaimed_samples, eq 1);
+        assert_that!(summary.is_clean(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_history_size_of_zero_disables_history<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        sut.send_copy(12)?;
+        assert_that!(sut.set_history_size(0), is_ok);
+        sut.send_copy(13)?;
+
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.update_connections(), is_ok);
+        assert_that!(subscriber.receive()?, is_none);
+
+        assert_that!(sut.set_history_size(1), is_ok);
+        sut.send_copy(14)?;
+        sut.send_copy(15)?;
+
+        let subscriber = service.subscriber_builder().create()?;
+        assert_that!(sut.update_connections(), is_ok);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 15);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn connected_subscribers_lists_subscriber_and_node_ids<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let other_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+        let other_service = other_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        let other_subscriber = other_service.subscriber_builder().create()?;
+
+        let mut subscribers = vec![];
+        sut.connected_subscribers(|details| {
+            subscribers.push((details.subscriber_id, details.node_id));
+            CallbackProgression::Continue
+        })?;
+        assert_that!(subscribers, len 2);
+        assert_that!(subscribers, contains(subscriber.id(), *node.id()));
+        assert_that!(
+            subscribers,
+            contains(other_subscriber.id(), *other_node.id())
+        );
+
+        drop(subscriber);
+        subscribers.clear();
+        sut.connected_subscribers(|details| {
+            subscribers.push((details.subscriber_id, details.node_id));
+            CallbackProgression::Continue
+        })?;
+        assert_that!(subscribers, eq vec![(other_subscriber.id(), *other_node.id())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn connection_generation_tracks_connected_subscribers<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let generation = sut.connection_generation()?;
+        assert_that!(sut.connection_generation()?, eq generation);
+
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+        let connected_generation = sut.connection_generation()?;
+        assert_that!(connected_generation, gt generation);
+
+        let mut ids = vec![];
+        sut.list_connected_subscribers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })?;
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains subscriber_1.id());
+        assert_that!(ids, contains subscriber_2.id());
+
+        drop(subscriber_1);
+        assert_that!(sut.connection_generation()?, gt connected_generation);
+
+        let mut ids = vec![];
+        sut.list_connected_subscribers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })?;
+        assert_that!(ids, eq vec![subscriber_2.id()]);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -0,0 +1,265 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod reader {
+    use iceoryx2::port::reader::{ReaderCreateError, ReaderReadError};
+    use iceoryx2::port::writer::WriterWriteError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::BlackboardCreateError;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "reader_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn changed_keys<Sut: Service>(reader: &iceoryx2::port::reader::Reader<Sut, u64>) -> Vec<u64> {
+        let mut keys = vec![];
+        reader.changed_keys(|key| {
+            keys.push(*key);
+            CallbackProgression::Continue
+        });
+        keys
+    }
+
+    #[test]
+    fn initial_values_can_be_read<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<i32>(0, -5)
+            .add::<u16>(1, 7)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.read::<i32>(&0), eq Ok(-5));
+        assert_that!(reader.read::<u16>(&1), eq Ok(7));
+    }
+
+    #[test]
+    fn opener_reads_entries_of_creator<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u64>(3, 33)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard::<u64>()
+            .add::<u64>(4, 44)
+            .open()
+            .unwrap();
+
+        let reader = sut2.reader_builder().create().unwrap();
+        assert_that!(reader.read::<u64>(&3), eq Ok(33));
+        assert_that!(reader.read::<u64>(&4), eq Err(ReaderReadError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn adding_same_key_twice_replaces_entry<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<u8>(0, 1)
+            .add::<i64>(0, 2)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        assert_that!(reader.read::<i64>(&0), eq Ok(2));
+    }
+
+    #[test]
+    fn read_with_wrong_value_type_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 1)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create();
+
+        assert_that!(reader.read::<i32>(&0), eq Err(ReaderReadError::IncompatibleValueType));
+        assert_that!(writer.write::<i32>(&0, 2), eq Err(WriterWriteError::IncompatibleValueType));
+        assert_that!(writer.write::<u32>(&1, 2), eq Err(WriterWriteError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn written_value_can_be_read<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<[u8; 3]>(0, [0, 0, 0])
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create();
+
+        assert_that!(writer.write::<[u8; 3]>(&0, [1, 2, 3]), is_ok);
+        assert_that!(reader.read::<[u8; 3]>(&0), eq Ok([1, 2, 3]));
+    }
+
+    #[test]
+    fn has_changed_is_reset_by_read<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create();
+
+        assert_that!(reader.has_changed(&0), eq Ok(false));
+        writer.write::<u32>(&0, 1).unwrap();
+        assert_that!(reader.has_changed(&0), eq Ok(true));
+        assert_that!(reader.read::<u32>(&0), eq Ok(1));
+        assert_that!(reader.has_changed(&0), eq Ok(false));
+        assert_that!(reader.has_changed(&1), eq Err(ReaderReadError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn key_written_and_overwritten_between_reads_is_reported_exactly_once<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .add::<u32>(1, 0)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create();
+
+        writer.write::<u32>(&1, 10).unwrap();
+        writer.write::<u32>(&1, 11).unwrap();
+
+        assert_that!(changed_keys(&reader), eq vec![1]);
+        assert_that!(changed_keys(&reader), eq vec![]);
+        assert_that!(reader.read::<u32>(&1), eq Ok(11));
+
+        writer.write::<u32>(&0, 12).unwrap();
+        assert_that!(reader.read::<u32>(&0), eq Ok(12));
+        assert_that!(changed_keys(&reader), eq vec![]);
+    }
+
+    #[test]
+    fn every_reader_tracks_changes_independently<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let reader_1 = sut.reader_builder().create().unwrap();
+        let reader_2 = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create();
+
+        writer.write::<u32>(&0, 1).unwrap();
+        assert_that!(changed_keys(&reader_1), eq vec![0]);
+
+        let reader_3 = sut.reader_builder().create().unwrap();
+        assert_that!(changed_keys(&reader_2), eq vec![0]);
+        assert_that!(changed_keys(&reader_3), eq vec![]);
+    }
+
+    #[test]
+    fn number_of_readers_is_limited<Sut: Service>() {
+        const MAX_READERS: usize = 3;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&generate_name())
+            .blackboard::<u64>()
+            .max_readers(MAX_READERS)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for _ in 0..MAX_READERS {
+            readers.push(sut.reader_builder().create().unwrap());
+        }
+
+        let reader = sut.reader_builder().create();
+        assert_that!(reader.err(), eq Some(ReaderCreateError::ExceedsMaxSupportedReaders));
+
+        readers.pop();
+        assert_that!(sut.reader_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn entry_with_mismatching_key_size_cannot_be_created<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let value_type =
+            iceoryx2::service::static_config::message_type_details::TypeDetail::__internal_new::<u8>(
+                iceoryx2::service::static_config::message_type_details::TypeVariant::FixedSize,
+            );
+        let sut = unsafe {
+            node.service_builder(&generate_name())
+                .blackboard::<iceoryx2::service::builder::CustomKeyMarker>()
+                .__internal_set_key_type_details(
+                    &iceoryx2::service::static_config::message_type_details::TypeDetail::__internal_new::<u64>(
+                        iceoryx2::service::static_config::message_type_details::TypeVariant::FixedSize,
+                    ),
+                )
+                .__internal_add(&[0, 1], &value_type, &[2])
+                .create()
+        };
+
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::IncompatibleEntry));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -16,6 +16,7 @@ mod server {
     use core::time::Duration;
     use std::sync::Barrier;
 
+    use iceoryx2::port::server::Server;
     use iceoryx2::port::ReceiveError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::port_factory::request_response::PortFactory;
@@ -755,6 +756,121 @@ mod server {
         }
     }
 
+    #[test]
+    fn connection_generation_tracks_connected_clients<Sut: Service>() {
+        let (_node, service) = create_node_and_service::<Sut>();
+
+        let sut = service.server_builder().create().unwrap();
+        let generation = sut.connection_generation().unwrap();
+        assert_that!(sut.connection_generation().unwrap(), eq generation);
+
+        let client_1 = service.client_builder().create().unwrap();
+        let client_2 = service.client_builder().create().unwrap();
+        let connected_generation = sut.connection_generation().unwrap();
+        assert_that!(connected_generation, gt generation);
+
+        let mut ids = vec![];
+        sut.list_connected_clients(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains client_1.id());
+        assert_that!(ids, contains client_2.id());
+
+        drop(client_1);
+        assert_that!(sut.connection_generation().unwrap(), gt connected_generation);
+
+        let mut ids = vec![];
+        sut.list_connected_clients(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, eq vec![client_2.id()]);
+    }
+
+    #[test]
+    fn number_of_connected_clients_tracks_clients<Sut: Service>() {
+        let (_node, service) = create_node_and_service::<Sut>();
+
+        let sut = service.server_builder().create().unwrap();
+        assert_that!(sut.number_of_connected_clients(), eq Ok(0));
+
+        let client_1 = service.client_builder().create().unwrap();
+        let _client_2 = service.client_builder().create().unwrap();
+        assert_that!(sut.number_of_connected_clients(), eq Ok(2));
+
+        drop(client_1);
+        assert_that!(sut.number_of_connected_clients(), eq Ok(1));
+    }
+
+    #[test]
+    fn active_requests_per_client_are_tracked_per_server<Sut: Service>() {
+        const MAX_ACTIVE_REQUESTS: usize = 2;
+        let node = create_node::<Sut>();
+        let service = node
+            .service_builder(&generate_service_name())
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(MAX_ACTIVE_REQUESTS)
+            .max_servers(2)
+            .create()
+            .unwrap();
+
+        let sut = service.server_builder().create().unwrap();
+        let other_server = service.server_builder().create().unwrap();
+        let client_1 = service.client_builder().create().unwrap();
+        let client_2 = service.client_builder().create().unwrap();
+
+        let active_requests = |server: &Server<Sut, u64, (), u64, ()>| {
+            let mut usage = vec![];
+            server
+                .active_requests_per_client(|u| {
+                    usage.push(*u);
+                    CallbackProgression::Continue
+                })
+                .unwrap();
+            usage
+        };
+
+        let _pending_response_1 = client_1.send_copy(1).unwrap();
+        let _pending_response_2 = client_1.send_copy(2).unwrap();
+        let _pending_response_3 = client_2.send_copy(3).unwrap();
+
+        let request_1 = sut.receive().unwrap().unwrap();
+        let request_2 = sut.receive().unwrap().unwrap();
+        let _request_3 = sut.receive().unwrap().unwrap();
+
+        let usage = active_requests(&sut);
+        assert_that!(usage, len 2);
+        for u in &usage {
+            assert_that!(u.max_active_requests(), eq MAX_ACTIVE_REQUESTS);
+            if u.client_id() == client_1.id() {
+                assert_that!(u.number_of_active_requests(), eq 2);
+                assert_that!(u.is_exhausted(), eq true);
+            } else {
+                assert_that!(u.client_id(), eq client_2.id());
+                assert_that!(u.number_of_active_requests(), eq 1);
+                assert_that!(u.is_exhausted(), eq false);
+            }
+        }
+
+        let usage = active_requests(&other_server);
+        assert_that!(usage, len 2);
+        for u in &usage {
+            assert_that!(u.number_of_active_requests(), eq 0);
+        }
+
+        drop(request_1);
+        drop(request_2);
+        let usage = active_requests(&sut);
+        for u in &usage {
+            assert_that!(u.number_of_active_requests(), le 1);
+            assert_that!(u.is_exhausted(), eq false);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -711,6 +711,8 @@ mod service_blackboard {
     "BlackboardCreateError::InternalFailure");
         assert_that!(format!("{}", BlackboardCreateError::IsBeingCreatedByAnotherInstance), eq
     "BlackboardCreateError::IsBeingCreatedByAnotherInstance");
+        assert_that!(format!("{}", BlackboardCreateError::IncompatibleEntry), eq
+    "BlackboardCreateError::IncompatibleEntry");
     }
 
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
@@ -0,0 +1,357 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_bridge {
+    use iceoryx2::port::ReceiveError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2::service_bridge::{ServiceBridgeBuilder, ServiceBridgeForwardError};
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct Header {
+        value: u32,
+    }
+
+    #[test]
+    fn forward_error_display_works<Source: Service, Target: Service>() {
+        assert_that!(
+            format!("{}", ServiceBridgeForwardError::ReceiveError(ReceiveError::ExceedsMaxBorrows)),
+            eq "ServiceBridgeForwardError::ReceiveError(ExceedsMaxBorrows)");
+    }
+
+    #[test]
+    fn samples_are_forwarded_to_target_service<Source: Service, Target: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<Header>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<Header>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let mut sut = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .create()
+            .unwrap();
+
+        let app_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let app_service = app_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<Header>()
+            .open()
+            .unwrap();
+        let publisher = app_service.publisher_builder().create().unwrap();
+        let subscriber = target_service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.forward(), eq Ok(0));
+
+        for n in 0..3 {
+            let mut sample = publisher.loan_uninit().unwrap();
+            *sample.user_header_mut() = Header {
+                value: n as u32 * 2,
+            };
+            sample.write_payload(n).send().unwrap();
+        }
+
+        assert_that!(sut.forward(), eq Ok(3));
+
+        for n in 0..3 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n);
+            assert_that!(*sample.user_header(), eq Header { value: n as u32 * 2 });
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(sut.number_of_discarded_samples(), eq 0);
+    }
+
+    #[test]
+    fn slices_of_any_length_are_forwarded<Source: Service, Target: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+        let app_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+        let app_service = app_node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u16]>()
+            .open()
+            .unwrap();
+
+        let mut sut = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .initial_max_slice_len(2)
+            .create()
+            .unwrap();
+
+        let publisher = app_service
+            .publisher_builder()
+            .initial_max_slice_len(128)
+            .create()
+            .unwrap();
+        let subscriber = target_service.subscriber_builder().create().unwrap();
+
+        let payload: Vec<u16> = (0..100).collect();
+        publisher.send_slice_copy(&payload).unwrap();
+
+        assert_that!(sut.forward(), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq payload.as_slice());
+    }
+
+    #[test]
+    fn max_samples_per_forward_limits_forwarded_samples<Source: Service, Target: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+        let app_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+        let app_service = app_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let mut sut = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .max_samples_per_forward(2)
+            .create()
+            .unwrap();
+
+        let publisher = app_service.publisher_builder().create().unwrap();
+        let subscriber = target_service.subscriber_builder().create().unwrap();
+
+        for n in 0..5 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(sut.forward(), eq Ok(2));
+        assert_that!(sut.forward(), eq Ok(2));
+        assert_that!(sut.forward(), eq Ok(1));
+        assert_that!(sut.forward(), eq Ok(0));
+
+        for n in 0..5 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq n);
+        }
+    }
+
+    #[test]
+    fn bidirectional_bridges_do_not_forward_in_a_loop<Source: Service, Target: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+        let app_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let app_service = app_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let mut sut_forward = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .create()
+            .unwrap();
+        let mut sut_backward = ServiceBridgeBuilder::new(&target_service, &source_service)
+            .create()
+            .unwrap();
+        sut_forward.ignore_publisher(sut_backward.publisher().id());
+        sut_backward.ignore_publisher(sut_forward.publisher().id());
+
+        let publisher = app_service.publisher_builder().create().unwrap();
+        let subscriber = app_service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(42).unwrap();
+
+        assert_that!(sut_forward.forward(), eq Ok(1));
+        assert_that!(sut_backward.forward(), eq Ok(0));
+        assert_that!(sut_forward.forward(), eq Ok(0));
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 42);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn samples_published_by_the_node_of_the_source_service_are_forwarded<
+        Source: Service,
+        Target: Service,
+    >() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .create()
+            .unwrap();
+
+        let publisher = source_service.publisher_builder().create().unwrap();
+        let subscriber = target_service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1337).unwrap();
+
+        assert_that!(sut.forward(), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1337);
+    }
+
+    #[test]
+    fn samples_of_ignored_publishers_are_not_forwarded<Source: Service, Target: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let source_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Source>()
+            .unwrap();
+        let target_node = NodeBuilder::new()
+            .config(&config)
+            .create::<Target>()
+            .unwrap();
+
+        let source_service = source_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+        let target_service = target_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let mut sut = ServiceBridgeBuilder::new(&source_service, &target_service)
+            .create()
+            .unwrap();
+
+        let ignored_publisher = source_service.publisher_builder().create().unwrap();
+        let publisher = source_service.publisher_builder().create().unwrap();
+        let subscriber = target_service.subscriber_builder().create().unwrap();
+        sut.ignore_publisher(ignored_publisher.id());
+
+        ignored_publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        assert_that!(sut.forward(), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, iceoryx2::service::ipc::Service>)]
+    mod local_to_ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2::service::local::Service>)]
+    mod ipc_to_local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service, iceoryx2::service::local_threadsafe::Service>)]
+    mod ipc_threadsafe_to_local_threadsafe {}
+}
@@ -37,6 +37,53 @@ mod service_event {
         .unwrap()
     }
 
+    #[test]
+    fn service_with_custom_creation_timeout_can_be_created_and_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .creation_timeout(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .creation_timeout(Duration::ZERO)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .event()
+            .creation_timeout(Duration::ZERO)
+            .open_or_create();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_creates_and_opens_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .event()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut2, is_ok);
+    }
+
     #[test]
     fn creating_non_existing_service_works<Sut: Service>() {
         let service_name = generate_name();
@@ -724,6 +771,83 @@ mod service_event {
         assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
     }
 
+    #[test]
+    fn notify_with_custom_event_ids_delivers_all_event_ids<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const NUMBER_OF_LISTENERS: usize = 2;
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(NUMBER_OF_LISTENERS)
+            .create()
+            .unwrap();
+
+        let mut listeners = vec![];
+        for _ in 0..NUMBER_OF_LISTENERS {
+            listeners.push(sut.listener_builder().create().unwrap());
+        }
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let event_ids = [EventId::new(3), EventId::new(12), EventId::new(27)];
+        assert_that!(notifier.notify_with_custom_event_ids(&event_ids).unwrap(), eq NUMBER_OF_LISTENERS);
+
+        for listener in &listeners {
+            let mut received_ids = vec![];
+            listener.try_wait_all(|id| received_ids.push(id)).unwrap();
+
+            assert_that!(received_ids, len event_ids.len());
+            for id in &event_ids {
+                assert_that!(received_ids, contains * id);
+            }
+        }
+    }
+
+    #[test]
+    fn notify_with_custom_event_ids_with_out_of_bounds_event_id_delivers_nothing<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const EVENT_ID_MAX_VALUE: usize = 12;
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(EVENT_ID_MAX_VALUE)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let result = notifier
+            .notify_with_custom_event_ids(&[EventId::new(1), EventId::new(EVENT_ID_MAX_VALUE + 1)]);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notify_with_no_custom_event_ids_notifies_nobody<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_ids(&[]).unwrap(), eq 0);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
     #[test]
     fn concurrent_reconnecting_notifier_can_trigger_waiting_listener<Sut: Service>() {
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
@@ -1402,6 +1526,52 @@ mod service_event {
         assert_that!(listener.try_wait_one().unwrap(), is_some);
     }
 
+    #[test]
+    fn listener_detects_missed_deadline<S: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        assert_that!(listener.has_missed_deadline(), eq Ok(false));
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(listener.has_missed_deadline(), eq Ok(true));
+
+        // a missed deadline is still a delivered notification
+        let _ = notifier.notify();
+        assert_that!(listener.has_missed_deadline(), eq Ok(false));
+    }
+
+    #[test]
+    fn listener_never_misses_deadline_without_deadline<S: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.event.deadline = None;
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(listener.has_missed_deadline(), eq Ok(false));
+    }
+
     #[test]
     fn listing_all_notifiers_works<S: Service>() {
         const NUMBER_OF_NOTIFIERS: usize = 18;
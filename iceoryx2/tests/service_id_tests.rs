@@ -0,0 +1,103 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_id {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::messaging_pattern::MessagingPattern;
+    use iceoryx2::service::port_factory::PortFactory;
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2::service::Service;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn from_name_is_equal_to_service_id_of_created_service<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service_name = generate_service_name();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        assert_that!(*sut.service_id(), eq ServiceId::from_name::<Sut>(&service_name, MessagingPattern::PublishSubscribe));
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        assert_that!(*sut.service_id(), eq ServiceId::from_name::<Sut>(&service_name, MessagingPattern::Event));
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        assert_that!(*sut.service_id(), eq ServiceId::from_name::<Sut>(&service_name, MessagingPattern::RequestResponse));
+    }
+
+    #[test]
+    fn from_name_differs_for_different_messaging_patterns<Sut: Service>() {
+        let service_name = ServiceName::new("all/glory/to/the/hypnotoad").unwrap();
+
+        let publish_subscribe =
+            ServiceId::from_name::<Sut>(&service_name, MessagingPattern::PublishSubscribe);
+        let event = ServiceId::from_name::<Sut>(&service_name, MessagingPattern::Event);
+
+        assert_that!(publish_subscribe, ne event);
+    }
+
+    // The mapping from ServiceName and MessagingPattern to ServiceId is part of the public
+    // contract. When this test fails the mapping was changed which breaks compatibility with
+    // all processes and tools that use an older version.
+    #[test]
+    fn from_name_mapping_is_stable<Sut: Service>() {
+        let service_name = ServiceName::new("all/glory/to/the/hypnotoad").unwrap();
+
+        for (messaging_pattern, expected_service_id) in [
+            (
+                MessagingPattern::PublishSubscribe,
+                "a753c40ed2ecb822b3c39d8c0d0d61f6d469b826",
+            ),
+            (
+                MessagingPattern::Event,
+                "fc43eade28311f8516023a717cc44fc9f669931f",
+            ),
+            (
+                MessagingPattern::RequestResponse,
+                "5522d7d5918ce8f47d2d0b51aa6967cf49fa4258",
+            ),
+            (
+                MessagingPattern::Blackboard,
+                "e388dfa8d94cfdef15f28bdb022f40038cb9d36d",
+            ),
+        ] {
+            let sut = ServiceId::from_name::<Sut>(&service_name, messaging_pattern);
+            assert_that!(sut.as_str(), eq expected_service_id);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
@@ -13,6 +13,7 @@
 #[generic_tests::define]
 mod service_publish_subscribe {
     use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use core::time::Duration;
     use std::sync::{Barrier, Mutex};
     use std::thread;
 
@@ -22,20 +23,32 @@ mod service_publish_subscribe {
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::port::LoanError;
     use iceoryx2::prelude::{AllocationStrategy, *};
+    use iceoryx2::sample_mut_uninit::WriteFromIterError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
+    use iceoryx2::service::header::publish_subscribe::Timestamp;
     use iceoryx2::service::messaging_pattern::MessagingPattern;
-    use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
+    use iceoryx2::service::service_id::ServiceId;
+    use iceoryx2::service::static_config::message_type_details::{
+        TypeCompatibility, TypeDetail, TypeName, TypeVariant,
+    };
     use iceoryx2::service::{Service, ServiceDetails};
     use iceoryx2::testing::*;
     use iceoryx2_bb_derive_macros::ZeroCopySend;
     use iceoryx2_bb_elementary::alignment::Alignment;
     use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
+    use iceoryx2_bb_posix::clock::{monotonic_clock_domain, ClockType, Time};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::named_concept::{
+        NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    };
+    use iceoryx2_cal::static_storage::{StaticStorage, StaticStorageBuilder};
 
     #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
@@ -71,6 +84,97 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn service_with_custom_creation_timeout_can_be_created_and_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .creation_timeout(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .creation_timeout(Duration::ZERO)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .creation_timeout(Duration::ZERO)
+            .open_or_create();
+        assert_that!(sut3, is_ok);
+    }
+
+    fn hold_service_in_creation<Sut: Service>(
+        config: &Config,
+        service_name: &ServiceName,
+    ) -> <Sut::StaticStorage as StaticStorage>::Locked {
+        let service_id =
+            ServiceId::from_name::<Sut>(service_name, MessagingPattern::PublishSubscribe);
+        let mut path_hint = config.global.root_path().clone();
+        path_hint
+            .add_path_entry(&config.global.service.directory)
+            .unwrap();
+        let storage_config = <<Sut::StaticStorage as NamedConceptMgmt>::Configuration>::default()
+            .prefix(&config.global.prefix)
+            .suffix(&config.global.service.static_config_storage_suffix)
+            .path_hint(&path_hint);
+
+        <<Sut::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+            Sut::StaticStorage,
+        >>::new(&FileName::new(service_id.as_str().as_bytes()).unwrap())
+        .config(&storage_config)
+        .create_locked()
+        .unwrap()
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_creates_and_opens_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_fails_promptly_when_service_hangs_in_creation<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(10);
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.global.service.creation_timeout = Duration::from_secs(60);
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service_in_creation = hold_service_in_creation::<Sut>(&config, &service_name);
+
+        let start = Time::now_with_clock(ClockType::Monotonic).unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create_with_timeout(TIMEOUT);
+        let elapsed = start.elapsed().unwrap();
+
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenOrCreateError::Timeout));
+        assert_that!(elapsed, lt Duration::from_secs(10));
+    }
+
     #[test]
     fn open_or_create_with_attributes_succeeds_when_attribute_is_satisfied<Sut: Service>() {
         let service_name = generate_name();
@@ -242,6 +346,130 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_with_layout_only_type_compatibility_ignores_type_name<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .create();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().type_compatibility(), eq TypeCompatibility::LayoutOnly);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<i64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[i64]>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u32>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+        assert_that!(sut4.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn payload_and_user_header_type_name_can_be_overridden<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let payload_type_name = TypeName::new(b"com.acme.Image").unwrap();
+        let user_header_type_name = TypeName::new(b"com.acme.Header").unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .payload_type_name(&payload_type_name)
+            .user_header_type_name(&user_header_type_name)
+            .create()
+            .unwrap();
+
+        let details = &sut.static_config().message_type_details();
+        assert_that!(details.payload.type_name.as_bytes(), eq b"com.acme.Image");
+        assert_that!(details.user_header.type_name.as_bytes(), eq b"com.acme.Header");
+
+        let details = Sut::details(&service_name, &config, MessagingPattern::PublishSubscribe)
+            .unwrap()
+            .unwrap();
+        let details = details
+            .static_details
+            .publish_subscribe()
+            .message_type_details();
+        assert_that!(details.payload.type_name.as_bytes(), eq b"com.acme.Image");
+        assert_that!(details.user_header.type_name.as_bytes(), eq b"com.acme.Header");
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<u32>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<i64>()
+            .user_header::<i32>()
+            .payload_type_name(&payload_type_name)
+            .user_header_type_name(&user_header_type_name)
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn payload_type_name_defaults_to_rust_type_name<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let details = &sut.static_config().message_type_details();
+        assert_that!(details.payload.type_name.as_bytes(), eq core::any::type_name::<u64>().as_bytes());
+    }
+
+    #[test]
+    fn open_fails_when_type_compatibility_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<i64>()
+            .open();
+        assert_that!(sut3.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
     #[test]
     fn open_fails_when_service_has_wrong_slice_base_type<Sut: Service>() {
         let service_name = generate_name();
@@ -424,221 +652,373 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_send_timestamp_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(2)
+            .enable_send_timestamp(false)
             .create();
         assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().has_send_timestamp(), eq false);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(3)
+            .enable_send_timestamp(true)
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
+            PublishSubscribeOpenError::IncompatibleSendTimestampBehavior
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .history_size(1)
             .open();
 
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_subscriber_max_borrow_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_exclusive_ownership_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(2)
+            .enable_exclusive_ownership(true)
             .create();
         assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(3)
+            .enable_exclusive_ownership(false)
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
+            PublishSubscribeOpenError::IncompatibleOwnershipBehavior
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_borrowed_samples(1)
+            .enable_exclusive_ownership(true)
             .open();
-
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_subscriber_max_buffer_size_requirement<
-        Sut: Service,
-    >() {
+    fn open_fails_when_service_does_not_satisfy_notify_on_delivery_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(2)
+            .enable_notify_on_delivery(true)
             .create();
         assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut.static_config().has_notify_on_delivery(), eq true);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(3)
+            .enable_notify_on_delivery(false)
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
+            PublishSubscribeOpenError::IncompatibleNotifyOnDeliveryBehavior
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(1)
+            .enable_notify_on_delivery(true)
             .open();
-
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_ok);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_alignment_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_sample_lifespan_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(128).unwrap())
+            .sample_lifespan(Duration::from_millis(100))
             .create();
         assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().sample_lifespan(), eq Some(Duration::from_millis(100)));
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(512).unwrap())
+            .sample_lifespan(Duration::from_millis(200))
             .open();
 
         assert_that!(sut2, is_err);
         assert_that!(
             sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::IncompatibleTypes
+            PublishSubscribeOpenError::IncompatibleSampleLifespan
         );
 
-        let sut2 = node
+        let sut3 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .payload_alignment(Alignment::new(16).unwrap())
+            .disable_sample_lifespan()
             .open();
 
-        assert_that!(sut2, is_ok);
+        assert_that!(sut3, is_err);
+        assert_that!(
+            sut3.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleSampleLifespan
+        );
+
+        let sut4 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .sample_lifespan(Duration::from_millis(100))
+            .open();
+
+        assert_that!(sut4, is_ok);
+
+        let sut5 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+
+        assert_that!(sut5, is_ok);
     }
 
     #[test]
-    fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .history_size(2)
             .create();
         assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .history_size(3)
             .open();
-        assert_that!(sut2, is_ok);
 
-        drop(sut);
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
+        );
 
-        let sut3 = node
+        let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .history_size(1)
             .open();
-        assert_that!(sut3, is_ok);
+
+        assert_that!(sut2, is_ok);
     }
 
     #[test]
-    fn open_fails_when_all_previous_owners_have_been_dropped<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_subscriber_max_borrow_requirement<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(2)
             .create();
         assert_that!(sut, is_ok);
 
         let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(3)
             .open();
-        assert_that!(sut2, is_ok);
 
-        drop(sut);
-        drop(sut2);
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
+        );
 
-        let sut3 = node
+        let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
             .open();
-        assert_that!(sut3, is_err);
-        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+
+        assert_that!(sut2, is_ok);
     }
 
     #[test]
-    fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_subscriber_max_buffer_size_requirement<
+        Sut: Service,
+    >() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .open_or_create();
-
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create();
         assert_that!(sut, is_ok);
-    }
 
-    #[test]
-    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
-        let service_name = generate_name();
-        let config = generate_isolated_config();
-        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let _sut = node
+        let sut2 = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
+            .subscriber_max_buffer_size(3)
+            .open();
 
-        let sut = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open_or_create();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_alignment_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(128).unwrap())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(512).unwrap())
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleTypes
+        );
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(16).unwrap())
+            .open();
+
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        drop(sut);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_all_previous_owners_have_been_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        drop(sut);
+        drop(sut2);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut3, is_err);
+        assert_that!(sut3.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_or_create_creates_service_if_it_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open_or_create();
+
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open_or_create();
 
         assert_that!(sut, is_ok);
     }
@@ -2378,174 +2758,773 @@ mod service_publish_subscribe {
 
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<usize>()
+            .open()
+            .unwrap();
+
+        for i in 1..=BUFFER_SIZE {
+            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
+            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
+            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
+
+            assert_that!(subscriber.buffer_size(), eq i);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_before_sub.send_copy(n), is_ok);
+            }
+
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
+            }
+
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
+
+            for n in 0..i * 2 {
+                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
+            }
+
+            for n in 0..i {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq i + n);
+            }
+
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_none);
+        }
+    }
+
+    #[test]
+    fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
+        const BUFFER_SIZE: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut2
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE + 1)
+            .create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
+    }
+
+    #[test]
+    fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
+        assert_that!(subscriber.buffer_size(), eq 1);
+    }
+
+    #[test]
+    fn sliced_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+        }
+    }
+
+    #[test]
+    fn sliced_service_can_be_written_from_iter<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 32;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let data: Vec<u64> = (0..2 * MAX_ELEMENTS as u64).collect();
+        let odd_numbers: Vec<u64> = data.iter().copied().filter(|n| n % 2 == 1).collect();
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        let sample = sample.write_from_iter((0..MAX_ELEMENTS).map(|n| 2 * n as u64 + 1));
+        sample.send().unwrap();
+        let recv_sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(recv_sample.payload(), eq odd_numbers.as_slice());
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        let sample = sample
+            .try_write_from_iter(data.iter().copied().filter(|n| n % 2 == 1))
+            .unwrap();
+        sample.send().unwrap();
+        let recv_sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(recv_sample.payload(), eq odd_numbers.as_slice());
+    }
+
+    #[test]
+    fn try_write_from_iter_fails_when_number_of_elements_does_not_match<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 8;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        let result = sample.try_write_from_iter(0..MAX_ELEMENTS as u64 - 1);
+        assert_that!(result.err(), eq Some(WriteFromIterError::TooFewElements));
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        let result = sample.try_write_from_iter(0..MAX_ELEMENTS as u64 + 1);
+        assert_that!(result.err(), eq Some(WriteFromIterError::TooManyElements));
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_from_iter_panics_when_length_does_not_match<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 8;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        sample.write_from_iter((0..MAX_ELEMENTS - 1).map(|n| n as u64));
+    }
+
+    #[test]
+    fn slice_aligned_service_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 91;
+        const ALIGNMENT: usize = 64;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service_pub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
+            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()
+            .unwrap();
+
+        let service_sub = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open()
+            .unwrap();
+
+        let publisher = service_pub
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = service_sub.subscriber_builder().create().unwrap();
+
+        let mut samples = vec![];
+        for n in 0..=MAX_ELEMENTS {
+            let sample = publisher.loan_slice_uninit(n).unwrap();
+            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
+            assert_that!(recv_sample.payload(), len n);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq i as u64 * 25);
+            }
+            samples.push(recv_sample);
+        }
+    }
+
+    #[test]
+    fn simple_communication_with_user_header_works<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<SomeUserHeader>()
+            .open()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut2.publisher_builder().create().unwrap();
+        let mut sample = publisher.loan().unwrap();
+
+        for i in 0..1024 {
+            sample.user_header_mut().value[i] = i as u64;
+        }
+        *sample.payload_mut() = 1829731;
+        sample.send().unwrap();
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        let sample = result.unwrap();
+        assert_that!(*sample, eq 1829731);
+        assert_that!(*sample.payload(), eq 1829731);
+
+        for i in 0..1024 {
+            assert_that!(sample.user_header().value[i], eq i as u64);
+        }
+    }
+
+    #[test]
+    fn sample_header_contains_send_timestamp_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().has_send_timestamp(), eq true);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample_1 = subscriber.receive().unwrap().unwrap();
+        let sample_2 = subscriber.receive().unwrap().unwrap();
+
+        let timestamp_1 = sample_1.header().timestamp();
+        let timestamp_2 = sample_2.header().timestamp();
+        assert_that!(timestamp_1, is_some);
+        assert_that!(timestamp_2, is_some);
+
+        let timestamp_1 = timestamp_1.unwrap();
+        let timestamp_2 = timestamp_2.unwrap();
+        assert_that!(timestamp_1.monotonic(), ne Duration::ZERO);
+        assert_that!(timestamp_1.system_time(), ne Duration::ZERO);
+        assert_that!(timestamp_1.monotonic(), le timestamp_2.monotonic());
+    }
+
+    #[test]
+    fn sample_header_contains_no_send_timestamp_when_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().has_send_timestamp(), eq false);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().timestamp(), is_none);
+    }
+
+    #[test]
+    fn send_with_timestamp_stores_provided_timestamp<Sut: Service>() {
+        const CAPTURE_TIME: Duration = Duration::from_nanos(123456789);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let timestamp = Timestamp::from_monotonic(CAPTURE_TIME).unwrap();
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        sample.send_with_timestamp(timestamp).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().timestamp(), eq Some(timestamp));
+        assert_that!(sample.header().timestamp().unwrap().monotonic(), eq CAPTURE_TIME);
+    }
+
+    #[test]
+    fn send_with_timestamp_stores_timestamp_when_send_timestamp_is_disabled<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let timestamp = Timestamp::from_monotonic(Duration::from_secs(7)).unwrap();
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        sample.send_with_timestamp(timestamp).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().timestamp(), eq Some(timestamp));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().timestamp(), is_none);
+    }
+
+    #[test]
+    fn sample_age_is_none_without_timestamp<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.age(), eq Ok(None));
+    }
+
+    #[test]
+    fn sample_age_increases_after_sample_was_sent<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        std::thread::sleep(TIMEOUT);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        let age = sample.age().unwrap();
+        assert_that!(age, is_some);
+        let age = age.unwrap();
+        assert_that!(age, ge TIMEOUT);
+        assert_that!(sample.age().unwrap().unwrap(), ge age);
+    }
+
+    #[test]
+    fn sample_age_is_derived_from_provided_timestamp<Sut: Service>() {
+        const CAPTURE_DELAY: Duration = Duration::from_secs(1);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let capture_time = Time::now_with_clock(ClockType::Monotonic)
+            .unwrap()
+            .as_duration()
+            .saturating_sub(CAPTURE_DELAY);
+        let timestamp = Timestamp::from_monotonic(capture_time).unwrap();
+        assert_that!(timestamp.clock_domain(), eq monotonic_clock_domain());
+        let sample = publisher.loan_uninit().unwrap().write_payload(1);
+        sample.send_with_timestamp(timestamp).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.age().unwrap().unwrap(), ge CAPTURE_DELAY);
+    }
+
+    #[test]
+    fn timestamp_from_monotonic_derives_system_time_from_clock_offset<Sut: Service>() {
+        const CAPTURE_DELAY: Duration = Duration::from_secs(2);
+        let reference = Timestamp::from_monotonic(Duration::from_secs(3600)).unwrap();
+
+        let capture_time =
+            Timestamp::from_monotonic(reference.monotonic() - CAPTURE_DELAY).unwrap();
+        let difference = reference.system_time().abs_diff(capture_time.system_time());
+        assert_that!(difference, ge CAPTURE_DELAY - Duration::from_millis(100));
+        assert_that!(difference, le CAPTURE_DELAY + Duration::from_millis(100));
+    }
+
+    #[test]
+    fn subscriber_discards_samples_that_exceed_the_sample_lifespan<Sut: Service>() {
+        const LIFESPAN: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .subscriber_max_borrowed_samples(1)
+            .sample_lifespan(LIFESPAN)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        std::thread::sleep(LIFESPAN * 2);
+        publisher.send_copy(3).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(subscriber.number_of_expired_samples(), eq 2);
+        drop(sample);
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.number_of_expired_samples(), eq 2);
+        assert_that!(subscriber.missed_samples().total(), eq 0);
+    }
+
+    #[test]
+    fn sample_lifespan_discards_samples_when_send_timestamp_is_disabled<Sut: Service>() {
+        const LIFESPAN: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .sample_lifespan(LIFESPAN)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        std::thread::sleep(LIFESPAN * 2);
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+        assert_that!(subscriber.number_of_expired_samples(), eq 1);
+    }
+
+    #[test]
+    fn sample_lifespan_of_zero_never_discards_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = generate_isolated_config();
+        config.defaults.publish_subscribe.sample_lifespan = Some(Duration::from_nanos(1));
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
+            .sample_lifespan(Duration::ZERO)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().sample_lifespan(), is_none);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(sample.header().timestamp(), is_none);
+        assert_that!(subscriber.number_of_expired_samples(), eq 0);
+    }
+
+    #[test]
+    fn subscriber_discards_samples_that_exceed_the_max_sample_age<Sut: Service>() {
+        const MAX_AGE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(3)
+            .enable_send_timestamp(true)
             .create()
             .unwrap();
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<usize>()
-            .open()
-            .unwrap();
-
-        for i in 1..=BUFFER_SIZE {
-            let publisher_before_sub = sut2.publisher_builder().create().unwrap();
-            let subscriber = sut.subscriber_builder().buffer_size(i).create().unwrap();
-            let publisher_after_sub = sut2.publisher_builder().create().unwrap();
-
-            assert_that!(subscriber.buffer_size(), eq i);
-
-            for n in 0..i * 2 {
-                assert_that!(publisher_before_sub.send_copy(n), is_ok);
-            }
-
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
-            }
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.max_sample_age(), is_none);
+        subscriber.set_max_sample_age(MAX_AGE);
+        assert_that!(subscriber.max_sample_age(), eq Some(MAX_AGE));
 
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        std::thread::sleep(MAX_AGE * 2);
+        publisher.send_copy(3).unwrap();
 
-            for n in 0..i * 2 {
-                assert_that!(publisher_after_sub.send_copy(n as _), is_ok);
-            }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(subscriber.number_of_expired_samples(), eq 2);
+        drop(sample);
 
-            for n in 0..i {
-                let sample = subscriber.receive().unwrap().unwrap();
-                assert_that!(*sample, eq i + n);
-            }
+        subscriber.disable_max_sample_age();
+        assert_that!(subscriber.max_sample_age(), is_none);
 
-            let sample = subscriber.receive().unwrap();
-            assert_that!(sample, is_none);
-        }
+        publisher.send_copy(4).unwrap();
+        std::thread::sleep(MAX_AGE * 2);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(subscriber.number_of_expired_samples(), eq 2);
     }
 
     #[test]
-    fn subscriber_creation_fails_when_buffer_size_exceeds_service_max<Sut: Service>() {
-        const BUFFER_SIZE: usize = 16;
+    fn subscriber_of_default_service_discards_samples_that_exceed_the_max_sample_age<
+        Sut: Service,
+    >() {
+        const MAX_AGE: Duration = Duration::from_millis(50);
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let _sut = node
+        let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_buffer_size(2)
             .create()
             .unwrap();
+        assert_that!(sut.static_config().has_send_timestamp(), eq true);
 
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .open()
-            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        subscriber.set_max_sample_age(MAX_AGE);
 
-        let subscriber = sut2
-            .subscriber_builder()
-            .buffer_size(BUFFER_SIZE + 1)
-            .create();
-        assert_that!(subscriber, is_err);
-        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
+        publisher.send_copy(1).unwrap();
+        std::thread::sleep(MAX_AGE * 2);
+        publisher.send_copy(2).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 2);
+        assert_that!(subscriber.number_of_expired_samples(), eq 1);
     }
 
     #[test]
-    fn subscriber_buffer_size_is_at_least_one<Sut: Service>() {
+    fn max_sample_age_has_no_effect_without_send_timestamp<Sut: Service>() {
+        const MAX_AGE: Duration = Duration::from_millis(10);
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
+            .enable_send_timestamp(false)
             .create()
             .unwrap();
 
-        let subscriber = sut.subscriber_builder().buffer_size(0).create().unwrap();
-        assert_that!(subscriber.buffer_size(), eq 1);
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        subscriber.set_max_sample_age(MAX_AGE);
+
+        publisher.send_copy(1).unwrap();
+        std::thread::sleep(MAX_AGE * 2);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(subscriber.number_of_expired_samples(), eq 0);
     }
 
     #[test]
-    fn sliced_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
+    fn subscriber_receives_only_samples_of_strongest_publisher_with_exclusive_ownership<
+        Sut: Service,
+    >() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
         let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .subscriber_max_buffer_size(4)
+            .enable_exclusive_ownership(true)
             .create()
             .unwrap();
+        assert_that!(sut.static_config().has_exclusive_ownership(), eq true);
 
-        let publisher = sut
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let weak_publisher = sut
             .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
+            .ownership_strength(1)
             .create()
             .unwrap();
-        let subscriber = sut.subscriber_builder().create().unwrap();
+        let strong_publisher = sut
+            .publisher_builder()
+            .ownership_strength(5)
+            .create()
+            .unwrap();
+        let medium_publisher = sut
+            .publisher_builder()
+            .ownership_strength(3)
+            .create()
+            .unwrap();
+        assert_that!(strong_publisher.ownership_strength(), eq 5);
 
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+        weak_publisher.send_copy(1).unwrap();
+        strong_publisher.send_copy(5).unwrap();
+        medium_publisher.send_copy(3).unwrap();
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5);
+        assert_that!(subscriber.receive().unwrap(), is_none);
 
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
-            }
-        }
+        // a removed owner hands over the ownership to the next strongest publisher
+        drop(strong_publisher);
+        weak_publisher.send_copy(1).unwrap();
+        medium_publisher.send_copy(3).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn slice_aligned_service_works<Sut: Service>() {
-        const MAX_ELEMENTS: usize = 91;
-        const ALIGNMENT: usize = 64;
+    fn publisher_with_smallest_id_owns_service_when_strengths_are_equal<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
 
-        let service_pub = node
-            .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .subscriber_max_buffer_size(MAX_ELEMENTS + 1)
-            .subscriber_max_borrowed_samples(MAX_ELEMENTS + 1)
-            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
-            .create()
-            .unwrap();
-
-        let service_sub = node
+        let sut = node
             .service_builder(&service_name)
-            .publish_subscribe::<[u64]>()
-            .open()
-            .unwrap();
-
-        let publisher = service_pub
-            .publisher_builder()
-            .initial_max_slice_len(MAX_ELEMENTS)
+            .publish_subscribe::<u128>()
+            .subscriber_max_buffer_size(4)
+            .enable_exclusive_ownership(true)
             .create()
             .unwrap();
-        let subscriber = service_sub.subscriber_builder().create().unwrap();
 
-        let mut samples = vec![];
-        for n in 0..=MAX_ELEMENTS {
-            let sample = publisher.loan_slice_uninit(n).unwrap();
-            assert_that!((sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            sample.write_from_fn(|i| i as u64 * 25).send().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
 
-            let recv_sample = subscriber.receive().unwrap().unwrap();
+        publisher_1.send_copy(publisher_1.id().value()).unwrap();
+        publisher_2.send_copy(publisher_2.id().value()).unwrap();
 
-            assert_that!((recv_sample.payload().as_ptr() as usize) % ALIGNMENT, eq 0);
-            assert_that!(recv_sample.payload(), len n);
-            for (i, element) in recv_sample.payload().iter().enumerate() {
-                assert_that!(*element, eq i as u64 * 25);
-            }
-            samples.push(recv_sample);
-        }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq publisher_1.id().value().min(publisher_2.id().value()));
+        assert_that!(subscriber.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn simple_communication_with_user_header_works<Sut: Service>() {
+    fn ownership_strength_has_no_effect_without_exclusive_ownership<Sut: Service>() {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
@@ -2553,36 +3532,32 @@ mod service_publish_subscribe {
         let sut = node
             .service_builder(&service_name)
             .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
+            .subscriber_max_buffer_size(4)
             .create()
             .unwrap();
-
-        let sut2 = node
-            .service_builder(&service_name)
-            .publish_subscribe::<u64>()
-            .user_header::<SomeUserHeader>()
-            .open()
-            .unwrap();
+        assert_that!(sut.static_config().has_exclusive_ownership(), eq false);
 
         let subscriber = sut.subscriber_builder().create().unwrap();
-        let publisher = sut2.publisher_builder().create().unwrap();
-        let mut sample = publisher.loan().unwrap();
-
-        for i in 0..1024 {
-            sample.user_header_mut().value[i] = i as u64;
-        }
-        *sample.payload_mut() = 1829731;
-        sample.send().unwrap();
+        let weak_publisher = sut
+            .publisher_builder()
+            .ownership_strength(1)
+            .create()
+            .unwrap();
+        let strong_publisher = sut
+            .publisher_builder()
+            .ownership_strength(5)
+            .create()
+            .unwrap();
 
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        let sample = result.unwrap();
-        assert_that!(*sample, eq 1829731);
-        assert_that!(*sample.payload(), eq 1829731);
+        weak_publisher.send_copy(1).unwrap();
+        strong_publisher.send_copy(5).unwrap();
 
-        for i in 0..1024 {
-            assert_that!(sample.user_header().value[i], eq i as u64);
+        let mut received = vec![];
+        while let Some(sample) = subscriber.receive().unwrap() {
+            received.push(*sample);
         }
+        received.sort();
+        assert_that!(received, eq vec![1, 5]);
     }
 
     #[test]
@@ -2756,6 +3731,8 @@ mod service_publish_subscribe {
                                   "PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes");
         assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleOverflowBehavior), eq
                                   "PublishSubscribeOpenError::IncompatibleOverflowBehavior");
+        assert_that!(format!("{}", PublishSubscribeOpenError::IncompatibleSendTimestampBehavior), eq
+                                  "PublishSubscribeOpenError::IncompatibleSendTimestampBehavior");
         assert_that!(format!("{}", PublishSubscribeOpenError::InsufficientPermissions), eq
                                   "PublishSubscribeOpenError::InsufficientPermissions");
         assert_that!(format!("{}", PublishSubscribeOpenError::ServiceInCorruptedState), eq
@@ -3448,6 +4425,141 @@ mod service_publish_subscribe {
         assert_that!(recv_res, is_ok);
     }
 
+    #[test]
+    fn memory_usage_is_empty_without_publishers<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.memory_usage(), eq Default::default());
+    }
+
+    #[test]
+    fn memory_usage_tracks_allocated_chunks<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        let usage = sut.memory_usage();
+        assert_that!(usage.number_of_chunks, eq 0);
+        assert_that!(usage.allocated_bytes, eq 0);
+
+        let sample_1 = publisher.loan().unwrap();
+        let sample_2 = publisher.loan().unwrap();
+        let usage = sut.memory_usage();
+        assert_that!(usage.number_of_chunks, eq 2);
+        assert_that!(usage.allocated_bytes, ge 2 * core::mem::size_of::<u64>() as u64);
+        assert_that!(usage.data_segment_size, ge usage.allocated_bytes);
+        let allocated_bytes_of_two_chunks = usage.allocated_bytes;
+
+        drop(sample_1);
+        drop(sample_2);
+        let usage = sut.memory_usage();
+        assert_that!(usage.number_of_chunks, eq 0);
+        assert_that!(usage.allocated_bytes, eq 0);
+        assert_that!(usage.number_of_chunks_high_water_mark, eq 2);
+        assert_that!(usage.allocated_bytes_high_water_mark, eq allocated_bytes_of_two_chunks);
+    }
+
+    #[test]
+    fn memory_usage_contains_chunks_held_by_subscribers<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        assert_that!(sut.memory_usage().number_of_chunks, eq 1);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sut.memory_usage().number_of_chunks, eq 1);
+
+        drop(sample);
+        // returned samples are reclaimed by the publisher on the next loan
+        let _sample = publisher.loan().unwrap();
+        assert_that!(sut.memory_usage().number_of_chunks, eq 1);
+    }
+
+    #[test]
+    fn memory_usage_is_visible_to_all_service_instances<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+
+        let _sample_1 = publisher_1.loan().unwrap();
+        let sample_2 = publisher_2.loan().unwrap();
+        assert_that!(sut.memory_usage().number_of_chunks, eq 2);
+        assert_that!(service.memory_usage(), eq sut.memory_usage());
+
+        drop(sample_2);
+        drop(publisher_2);
+        assert_that!(sut.memory_usage().number_of_chunks, eq 1);
+        assert_that!(sut.memory_usage().number_of_chunks_high_water_mark, eq 1);
+    }
+
+    #[test]
+    fn memory_usage_reflects_growing_data_segment<S: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(8)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let sample = publisher.loan_slice(8).unwrap();
+        let initial_usage = sut.memory_usage();
+        drop(sample);
+
+        let _sample = publisher.loan_slice(4096).unwrap();
+        let usage = sut.memory_usage();
+        assert_that!(usage.allocated_bytes, ge 4096);
+        assert_that!(usage.data_segment_size, gt initial_usage.data_segment_size);
+        assert_that!(usage.allocated_bytes_high_water_mark, ge 4096);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
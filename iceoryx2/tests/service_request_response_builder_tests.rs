@@ -12,15 +12,20 @@
 
 #[generic_tests::define]
 mod service_request_response {
+    use core::time::Duration;
+
     use iceoryx2::node::NodeBuilder;
     use iceoryx2::prelude::*;
     use iceoryx2::service::attribute::*;
     use iceoryx2::service::builder::request_response::{
-        RequestResponseCreateError, RequestResponseOpenError,
+        RequestResponseCreateError, RequestResponseOpenError, RequestResponseOpenOrCreateError,
     };
     use iceoryx2::service::port_factory::client::ClientCreateError;
     use iceoryx2::service::port_factory::server::ServerCreateError;
-    use iceoryx2::service::static_config::message_type_details::TypeVariant;
+    use iceoryx2::service::service_name::ServiceNameError;
+    use iceoryx2::service::static_config::message_type_details::{
+        TypeCompatibility, TypeName, TypeVariant,
+    };
     use iceoryx2::testing::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -200,6 +205,118 @@ mod service_request_response {
         assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
     }
 
+    #[test]
+    fn opening_service_with_layout_only_type_compatibility_ignores_type_names<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .create();
+
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<i64, i64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+
+        assert_that!(sut_open, is_ok);
+        assert_that!(sut_open.unwrap().static_config().type_compatibility(), eq TypeCompatibility::LayoutOnly);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u32, u64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u32>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseType));
+    }
+
+    #[test]
+    fn request_and_response_type_names_can_be_overridden<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let request_type_name = TypeName::new(b"com.acme.Request").unwrap();
+        let response_type_name = TypeName::new(b"com.acme.Response").unwrap();
+        let request_header_type_name = TypeName::new(b"com.acme.RequestHeader").unwrap();
+        let response_header_type_name = TypeName::new(b"com.acme.ResponseHeader").unwrap();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u32>()
+            .request_user_header::<u16>()
+            .response_user_header::<u8>()
+            .request_payload_type_name(&request_type_name)
+            .response_payload_type_name(&response_type_name)
+            .request_user_header_type_name(&request_header_type_name)
+            .response_user_header_type_name(&response_header_type_name)
+            .create()
+            .unwrap();
+
+        let request_details = sut_create.static_config().request_message_type_details();
+        let response_details = sut_create.static_config().response_message_type_details();
+        assert_that!(request_details.payload.type_name.as_bytes(), eq b"com.acme.Request");
+        assert_that!(request_details.user_header.type_name.as_bytes(), eq b"com.acme.RequestHeader");
+        assert_that!(response_details.payload.type_name.as_bytes(), eq b"com.acme.Response");
+        assert_that!(response_details.user_header.type_name.as_bytes(), eq b"com.acme.ResponseHeader");
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u32>()
+            .request_user_header::<u16>()
+            .response_user_header::<u8>()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<i64, i32>()
+            .request_user_header::<i16>()
+            .response_user_header::<i8>()
+            .request_payload_type_name(&request_type_name)
+            .response_payload_type_name(&response_type_name)
+            .request_user_header_type_name(&request_header_type_name)
+            .response_user_header_type_name(&response_header_type_name)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn opening_service_with_different_type_compatibility_fails<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .type_compatibility(TypeCompatibility::LayoutOnly)
+            .create();
+
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+    }
+
     #[test]
     fn opening_service_with_incompatible_request_type_alignment_fails<Sut: Service>() {
         let service_name = generate_service_name();
@@ -442,182 +559,705 @@ mod service_request_response {
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_request_overflow_requirement<Sut: Service>() {
+    fn open_fails_when_service_does_not_satisfy_request_overflow_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_requests(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_requests(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests));
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_response_overflow_requirement<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_responses(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_safe_overflow_for_responses(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses));
+    }
+
+    #[test]
+    fn open_verifies_fire_and_forget_requests_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_fire_and_forget_requests(true)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn service_with_custom_creation_timeout_can_be_created_and_opened<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .creation_timeout(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .creation_timeout(Duration::ZERO)
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .creation_timeout(Duration::ZERO)
+            .open_or_create();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn topic_prefix_is_prepended_to_service_name<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _existing_service = node
+            .service_builder(&"robot/arm/joint_state".try_into().unwrap())
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&"joint_state".try_into().unwrap())
+            .request_response::<u64, u64>()
+            .with_topic_prefix("robot/arm/")
+            .unwrap()
+            .open();
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        assert_that!(sut.name().as_str(), eq "robot/arm/joint_state");
+        assert_that!(sut.name().strip_topic_prefix("robot/arm/"), eq Some("joint_state"));
+        assert_that!(sut.name().strip_topic_prefix("robot/leg/"), eq None);
+    }
+
+    #[test]
+    fn topic_prefix_fails_when_service_name_exceeds_max_length<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let prefix = "x".repeat(ServiceName::max_len());
+
+        let sut = node
+            .service_builder(&"joint_state".try_into().unwrap())
+            .request_response::<u64, u64>()
+            .with_topic_prefix(&prefix);
+        assert_that!(sut.err(), eq Some(ServiceNameError::ExceedsMaximumLength));
+    }
+
+    #[test]
+    fn open_or_create_with_timeout_creates_and_opens_service<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create_with_timeout(Duration::ZERO);
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_multi_response_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(false)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().does_support_multi_response(), eq false);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(true)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleBehaviorForMultiResponse));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(false)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_notify_on_delivery_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_delivery(true)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().has_notify_on_delivery(), eq true);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_delivery(false)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleNotifyOnDeliveryBehavior));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_notify_on_delivery(true)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_response_deadline_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let deadline = Duration::from_millis(100);
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .response_deadline(deadline)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().response_deadline(), eq Some(deadline));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .disable_response_deadline()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseDeadline));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .response_deadline(deadline * 2)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleResponseDeadline));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .response_deadline(deadline)
+            .open();
+        assert_that!(sut_open, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_credit_flow_control_setting_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(3)
+            .create();
+        assert_that!(sut_create, is_ok);
+        let sut_create = sut_create.unwrap();
+        assert_that!(sut_create.static_config().credits_per_client(), eq Some(3));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .disable_credit_flow_control()
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleCreditFlowControl));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(4)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleCreditFlowControl));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(3)
+            .open();
+        assert_that!(sut_open, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn credit_flow_control_with_zero_credits_is_adjusted_to_one<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_credit_flow_control(0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().credits_per_client(), eq Some(1));
+    }
+
+    #[test]
+    fn open_verifies_max_borrowed_responses_per_pending_response_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_borrowed_responses_per_pending_response(10)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_borrowed_responses_per_pending_response(11)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_borrowed_responses_per_pending_response(10)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_max_active_requests_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(10)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(11)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(10)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_max_request_queue_depth_per_client_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(10)
+            .max_request_queue_depth_per_client(5)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_request_queue_depth_per_client(6)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedRequestQueueDepthPerClient));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_request_queue_depth_per_client(5)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn request_queue_depth_per_client_is_by_default_max_active_requests_per_client<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(7)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_request_queue_depth_per_client(), eq 7);
+    }
+
+    #[test]
+    fn request_queue_depth_per_client_is_limited_to_max_active_requests_per_client<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(3)
+            .max_request_queue_depth_per_client(8)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_active_requests_per_client(), eq 3);
+        assert_that!(sut.static_config().max_request_queue_depth_per_client(), eq 3);
+    }
+
+    #[test]
+    fn open_verifies_max_response_buffer_size_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(10)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(11)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(10)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_max_amount_of_servers_correctly<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(10)
+            .create();
+        assert_that!(sut_create, is_ok);
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(11)
+            .open();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(9)
+            .open();
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn open_verifies_max_amount_of_clients_correctly<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_safe_overflow_for_requests(true)
+            .max_clients(10)
             .create();
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_safe_overflow_for_requests(false)
+            .max_clients(11)
             .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForRequests));
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(9)
+            .open();
+        assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_fails_when_service_does_not_satisfy_response_overflow_requirement<Sut: Service>() {
+    fn open_verifies_max_amount_of_nodes_correctly<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_safe_overflow_for_responses(true)
+            .max_nodes(10)
             .create();
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_safe_overflow_for_responses(false)
+            .max_nodes(11)
             .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleOverflowBehaviorForResponses));
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_nodes(9)
+            .open();
+        assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_fire_and_forget_requests_setting_correctly<Sut: Service>() {
+    fn open_verifies_max_loaned_requests_correctly<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_fire_and_forget_requests(true)
+            .max_loaned_requests(10)
             .create();
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_fire_and_forget_requests(false)
+            .max_loaned_requests(11)
             .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleBehaviorForFireAndForgetRequests));
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans));
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .enable_fire_and_forget_requests(true)
+            .max_loaned_requests(9)
             .open();
         assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_max_borrowed_responses_per_pending_response_correctly<Sut: Service>() {
+    fn open_strict_requires_exact_match_of_max_amount_of_clients<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_borrowed_responses_per_pending_response(10)
+            .max_clients(10)
             .create();
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_borrowed_responses_per_pending_response(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfBorrowedResponsesPerPendingResponse));
+            .max_clients(9)
+            .open_strict();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients));
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_borrowed_responses_per_pending_response(10)
-            .open();
+            .max_clients(11)
+            .open_strict();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(10)
+            .open_strict();
         assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_max_active_requests_correctly<Sut: Service>() {
+    fn open_strict_ignores_limits_that_are_not_explicitly_defined<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_active_requests_per_client(10)
+            .max_servers(7)
+            .max_nodes(12)
             .create();
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_active_requests_per_client(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequestsPerClient));
+            .max_servers(7)
+            .open_strict();
+        assert_that!(sut_open, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_active_requests_per_client(10)
-            .open();
-        assert_that!(sut_open, is_ok);
+            .max_servers(7)
+            .max_nodes(11)
+            .open_strict();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes));
     }
 
     #[test]
-    fn open_verifies_max_response_buffer_size_correctly<Sut: Service>() {
+    fn open_strict_with_attributes_verifies_attributes_and_limits<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
+        let key: AttributeKey = "tenant".try_into().unwrap();
+        let value_a: AttributeValue = "a".try_into().unwrap();
+        let value_b: AttributeValue = "b".try_into().unwrap();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_response_buffer_size(10)
-            .create();
+            .max_response_buffer_size(4)
+            .create_with_attributes(&AttributeSpecifier::new().define(&key, &value_a));
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_response_buffer_size(11)
-            .open();
+            .max_response_buffer_size(4)
+            .open_strict_with_attributes(&AttributeVerifier::new().require(&key, &value_b));
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleAttributes));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_response_buffer_size(2)
+            .open_strict_with_attributes(&AttributeVerifier::new().require(&key, &value_a));
         assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedResponseBufferSize));
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_response_buffer_size(10)
-            .open();
+            .max_response_buffer_size(4)
+            .open_strict_with_attributes(&AttributeVerifier::new().require(&key, &value_a));
         assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_max_amount_of_servers_correctly<Sut: Service>() {
+    fn open_or_create_or_fail_creates_non_existing_service<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let sut_create = node
+
+        let sut = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_servers(10)
-            .create();
-        assert_that!(sut_create, is_ok);
+            .max_clients(3)
+            .open_or_create_or_fail();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().static_config().max_clients(), eq 3);
+    }
 
-        let sut_open = node
+    #[test]
+    fn open_or_create_or_fail_opens_service_with_identical_parameters<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_servers(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers));
+            .max_clients(3)
+            .max_response_buffer_size(0)
+            .open_or_create_or_fail();
+        assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_servers(9)
-            .open();
+            .max_clients(3)
+            .max_response_buffer_size(0)
+            .open_or_create_or_fail();
         assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_max_amount_of_clients_correctly<Sut: Service>() {
+    fn open_or_create_or_fail_fails_when_explicit_parameters_differ<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
@@ -625,75 +1265,102 @@ mod service_request_response {
             .service_builder(&service_name)
             .request_response::<u64, u64>()
             .max_clients(10)
+            .enable_fire_and_forget_requests(true)
             .create();
         assert_that!(sut_create, is_ok);
 
+        // open_or_create succeeds since the service supports more clients than required
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_clients(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients));
+            .max_clients(9)
+            .open_or_create();
+        assert_that!(sut_open, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
             .max_clients(9)
-            .open();
-        assert_that!(sut_open, is_ok);
+            .open_or_create_or_fail();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenOrCreateError::ConfigurationMismatch));
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(10)
+            .enable_fire_and_forget_requests(false)
+            .open_or_create_or_fail();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenOrCreateError::ConfigurationMismatch));
     }
 
     #[test]
-    fn open_verifies_max_amount_of_nodes_correctly<Sut: Service>() {
+    fn open_or_create_or_fail_fails_when_default_parameters_differ<Sut: Service>() {
         let service_name = generate_service_name();
-        let config = generate_isolated_config();
+        let mut config = generate_isolated_config();
+        config.defaults.request_response.max_servers = 3;
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_nodes(10)
-            .create();
+            .open_or_create_or_fail();
         assert_that!(sut_create, is_ok);
 
-        let sut_open = node
+        let mut drifted_config = config.clone();
+        drifted_config.defaults.request_response.max_servers = 4;
+        let drifted_node = NodeBuilder::new()
+            .config(&drifted_config)
+            .create::<Sut>()
+            .unwrap();
+
+        let sut_open = drifted_node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_nodes(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfNodes));
+            .open_or_create();
+        assert_that!(sut_open, is_ok);
+
+        let sut_open = drifted_node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open_or_create_or_fail();
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenOrCreateError::ConfigurationMismatch));
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_nodes(9)
-            .open();
+            .open_or_create_or_fail();
         assert_that!(sut_open, is_ok);
     }
 
     #[test]
-    fn open_verifies_max_loaned_requests_correctly<Sut: Service>() {
+    fn open_or_create_or_fail_with_attributes_reports_incompatible_attributes<Sut: Service>() {
         let service_name = generate_service_name();
         let config = generate_isolated_config();
+        let key: AttributeKey = "tenant".try_into().unwrap();
+        let value_a: AttributeValue = "a".try_into().unwrap();
+        let value_b: AttributeValue = "b".try_into().unwrap();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let sut_create = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_loaned_requests(10)
-            .create();
+            .open_or_create_or_fail_with_attributes(
+                &AttributeVerifier::new().require(&key, &value_a),
+            );
         assert_that!(sut_create, is_ok);
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_loaned_requests(11)
-            .open();
-        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::DoesNotSupportRequestedAmountOfClientRequestLoans));
+            .open_or_create_or_fail_with_attributes(
+                &AttributeVerifier::new().require(&key, &value_b),
+            );
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleAttributes)));
 
         let sut_open = node
             .service_builder(&service_name)
             .request_response::<u64, u64>()
-            .max_loaned_requests(9)
-            .open();
+            .open_or_create_or_fail_with_attributes(
+                &AttributeVerifier::new().require(&key, &value_a),
+            );
         assert_that!(sut_open, is_ok);
     }
 
@@ -707,6 +1374,7 @@ mod service_request_response {
             .service_builder(&service_name)
             .request_response::<u64, u64>()
             .max_active_requests_per_client(0)
+            .max_request_queue_depth_per_client(0)
             .max_response_buffer_size(0)
             .max_borrowed_responses_per_pending_response(0)
             .max_servers(0)
@@ -718,6 +1386,7 @@ mod service_request_response {
         let sut_create = sut_create.unwrap();
 
         assert_that!(sut_create.static_config().max_active_requests_per_client(), eq 1);
+        assert_that!(sut_create.static_config().max_request_queue_depth_per_client(), eq 1);
         assert_that!(sut_create.static_config().max_response_buffer_size(), eq 1);
         assert_that!(sut_create.static_config().max_servers(), eq 1);
         assert_that!(sut_create.static_config().max_clients(), eq 1);
@@ -1231,6 +1900,192 @@ mod service_request_response {
         assert_that!(sut_open, is_ok);
     }
 
+    mod v1 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, Default, ZeroCopySend)]
+        #[type_name("EvolvingType")]
+        #[repr(C)]
+        pub struct EvolvingType {
+            pub a: u64,
+        }
+    }
+
+    mod v2 {
+        use iceoryx2::prelude::*;
+
+        #[derive(Debug, Default, ZeroCopySend)]
+        #[type_name("EvolvingType")]
+        #[repr(C)]
+        pub struct EvolvingType {
+            pub a: u64,
+            pub b: u64,
+        }
+    }
+
+    #[test]
+    fn opening_service_with_smaller_evolved_type_fails_without_type_evolution<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<v2::EvolvingType, u64>()
+            .create()
+            .unwrap();
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::EvolvingType, u64>()
+            .open();
+
+        assert_that!(sut_open.err(), eq Some(RequestResponseOpenError::IncompatibleRequestType));
+    }
+
+    #[test]
+    fn opening_service_with_smaller_evolved_type_works_with_type_evolution<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<v2::EvolvingType, v2::EvolvingType>()
+            .create()
+            .unwrap();
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<v1::EvolvingType, v1::EvolvingType>()
+            .enable_type_evolution(true)
+            .open();
+
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn opening_service_with_larger_evolved_type_works_with_type_evolution<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, v1::EvolvingType>()
+            .create()
+            .unwrap();
+
+        let sut_open = node
+            .service_builder(&service_name)
+            .request_response::<u64, v2::EvolvingType>()
+            .enable_type_evolution(true)
+            .open();
+
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn type_evolution_zeroes_fields_unknown_to_the_sender<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let new_service = node
+            .service_builder(&service_name)
+            .request_response::<v2::EvolvingType, v2::EvolvingType>()
+            .create()
+            .unwrap();
+        let old_service = node
+            .service_builder(&service_name)
+            .request_response::<v1::EvolvingType, v1::EvolvingType>()
+            .enable_type_evolution(true)
+            .open()
+            .unwrap();
+
+        let new_server = new_service.server_builder().create().unwrap();
+        let new_client = new_service.client_builder().create().unwrap();
+        let old_server = old_service.server_builder().create().unwrap();
+        let old_client = old_service.client_builder().create().unwrap();
+
+        // an old client sends a request to a new server
+        let _pending_response = old_client.send_copy(v1::EvolvingType { a: 123 }).unwrap();
+
+        let active_request = new_server.receive().unwrap().unwrap();
+        assert_that!(active_request.a, eq 123);
+        assert_that!(active_request.b, eq 0);
+        drop(active_request);
+        assert_that!(old_server.receive().unwrap().unwrap().a, eq 123);
+
+        // an old server responds to a new client
+        let pending_response = new_client
+            .send_copy(v2::EvolvingType { a: 1, b: 2 })
+            .unwrap();
+        let _ = new_server.receive().unwrap();
+        let active_request = old_server.receive().unwrap().unwrap();
+        active_request
+            .send_copy(v1::EvolvingType { a: 456 })
+            .unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.a, eq 456);
+        assert_that!(response.b, eq 0);
+    }
+
+    #[test]
+    fn type_evolution_zeroes_fields_unknown_to_the_sender_when_old_version_creates_service<
+        Sut: Service,
+    >() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+
+        let old_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let new_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let old_service = old_node
+            .service_builder(&service_name)
+            .request_response::<v1::EvolvingType, v1::EvolvingType>()
+            .create()
+            .unwrap();
+        let new_service = new_node
+            .service_builder(&service_name)
+            .request_response::<v2::EvolvingType, v2::EvolvingType>()
+            .enable_type_evolution(true)
+            .open()
+            .unwrap();
+
+        let old_server = old_service.server_builder().create().unwrap();
+        let old_client = old_service.client_builder().create().unwrap();
+        let new_server = new_service.server_builder().create().unwrap();
+        let new_client = new_service.client_builder().create().unwrap();
+
+        // a new client sends a request to an old and a new server
+        let pending_response = new_client
+            .send_copy(v2::EvolvingType { a: 1, b: 2 })
+            .unwrap();
+
+        let active_request = old_server.receive().unwrap().unwrap();
+        assert_that!(active_request.a, eq 1);
+        let active_request_from_new_client = new_server.receive().unwrap().unwrap();
+        assert_that!(active_request_from_new_client.a, eq 1);
+        assert_that!(active_request_from_new_client.b, eq 2);
+
+        // an old server responds to a new client
+        active_request
+            .send_copy(v1::EvolvingType { a: 456 })
+            .unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.a, eq 456);
+        assert_that!(response.b, eq 0);
+
+        // an old client sends a request to a new server
+        let _pending_response = old_client.send_copy(v1::EvolvingType { a: 123 }).unwrap();
+
+        let active_request = new_server.receive().unwrap().unwrap();
+        assert_that!(active_request.a, eq 123);
+        assert_that!(active_request.b, eq 0);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
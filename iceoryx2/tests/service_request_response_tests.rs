@@ -17,7 +17,7 @@ mod service_request_response {
     use iceoryx2::node::NodeBuilder;
     use iceoryx2::port::client::Client;
     use iceoryx2::port::server::Server;
-    use iceoryx2::port::LoanError;
+    use iceoryx2::port::{LoanError, SendError};
     use iceoryx2::prelude::{PortFactory, *};
     use iceoryx2::service::builder::{CustomHeaderMarker, CustomPayloadMarker};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
@@ -163,6 +163,163 @@ mod service_request_response {
         }
     }
 
+    #[test]
+    fn server_can_send_only_one_response_without_multi_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(false)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.is_connected(), eq true);
+
+        assert_that!(active_request.send_copy(1), is_ok);
+        assert_that!(active_request.is_connected(), eq false);
+        assert_that!(pending_response.is_connected(), eq false);
+        assert_that!(active_request.send_copy(2).err(), eq Some(SendError::MultiResponseDisabled));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response.payload(), eq 1);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn only_one_of_many_loaned_responses_can_be_sent_without_multi_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(false)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response_1 = active_request.loan_uninit().unwrap().write_payload(1);
+        let response_2 = active_request.loan_uninit().unwrap().write_payload(2);
+
+        assert_that!(response_2.send(), is_ok);
+        assert_that!(response_1.send().err(), eq Some(SendError::MultiResponseDisabled));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response.payload(), eq 2);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn finishing_active_request_ends_stream_of_responses<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        assert_that!(active_request.send_copy(1), is_ok);
+        assert_that!(active_request.send_copy(2), is_ok);
+        assert_that!(pending_response.is_connected(), eq true);
+
+        active_request.finish();
+        assert_that!(pending_response.is_connected(), eq false);
+
+        for n in 1..=2 {
+            let response = pending_response.receive().unwrap().unwrap();
+            assert_that!(*response.payload(), eq n);
+        }
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn rejected_request_delivers_error_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_copy(1), is_ok);
+        assert_that!(active_request.reject(4711), is_ok);
+        assert_that!(pending_response.is_connected(), eq false);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().is_error(), eq false);
+        assert_that!(response.header().error_code(), is_none);
+        assert_that!(*response.payload(), eq 1);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().is_error(), eq true);
+        assert_that!(response.header().error_code(), eq Some(4711));
+    }
+
+    #[test]
+    fn rejected_slice_request_delivers_empty_error_response<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, [u64]>()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.reject(0), is_ok);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.header().error_code(), eq Some(0));
+        assert_that!(response.payload(), len 0);
+    }
+
+    #[test]
+    fn reject_fails_when_single_response_was_already_sent<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .enable_multi_response(false)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let server = service.server_builder().create().unwrap();
+
+        let _pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(active_request.send_copy(1), is_ok);
+        assert_that!(active_request.reject(2).err(), eq Some(SendError::MultiResponseDisabled));
+    }
+
     #[test]
     fn response_buffer_size_with_overflow_works<Sut: Service>() {
         let test_args = Args {
@@ -1251,6 +1408,152 @@ mod service_request_response {
         assert_that!(response.err(), eq Some(LoanError::ExceedsMaxLoanSize));
     }
 
+    #[test]
+    fn client_and_server_shrink_grown_data_segments_to_fit<Sut: Service>() {
+        const SLICE_SIZE: usize = 16;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .create()
+            .unwrap();
+
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(SLICE_SIZE)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let server = service
+            .server_builder()
+            .initial_max_slice_len(SLICE_SIZE)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        assert_that!(client.shrink_to_fit().unwrap(), eq 0);
+        assert_that!(server.shrink_to_fit().unwrap(), eq 0);
+
+        let request = client.loan_slice(SLICE_SIZE * 64).unwrap();
+        let pending_response = request.send().unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(client.shrink_to_fit().unwrap(), gt 0);
+
+        let response = active_request.loan_slice(SLICE_SIZE * 64).unwrap();
+        response.send().unwrap();
+        drop(active_request);
+        assert_that!(server.shrink_to_fit().unwrap(), gt 0);
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(response.payload(), len SLICE_SIZE * 64);
+        drop(response);
+        drop(pending_response);
+
+        let request = client.loan_slice(SLICE_SIZE);
+        assert_that!(request, is_ok);
+        assert_that!(client.shrink_to_fit().unwrap(), eq 0);
+        assert_that!(server.shrink_to_fit().unwrap(), eq 0);
+    }
+
+    #[test]
+    fn loan_request_larger_than_max_request_payload_size_fails<Sut: Service>() {
+        const MAX_PAYLOAD_SIZE: usize = 64;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], u64>()
+            .max_request_payload_size(MAX_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+        assert_that!(service.static_config().max_request_payload_size(), eq Some(MAX_PAYLOAD_SIZE));
+        assert_that!(service.static_config().max_response_payload_size(), eq None);
+
+        let client = service
+            .client_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+
+        let request = client.loan_slice(MAX_PAYLOAD_SIZE);
+        assert_that!(request, is_ok);
+        drop(request);
+
+        let request = client.loan_slice(MAX_PAYLOAD_SIZE + 1);
+        assert_that!(request.err(), eq Some(LoanError::PayloadTooLarge));
+    }
+
+    #[test]
+    fn loan_response_larger_than_max_response_payload_size_fails<Sut: Service>() {
+        const MAX_PAYLOAD_SIZE: usize = 64;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, [u8]>()
+            .max_response_payload_size(MAX_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+
+        let client = service.client_builder().create().unwrap();
+        let server = service
+            .server_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let _pending_response = client.send_copy(0).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+
+        let response = active_request.loan_slice(MAX_PAYLOAD_SIZE);
+        assert_that!(response, is_ok);
+        drop(response);
+
+        let response = active_request.loan_slice(MAX_PAYLOAD_SIZE + 1);
+        assert_that!(response.err(), eq Some(LoanError::PayloadTooLarge));
+    }
+
+    #[test]
+    fn max_payload_size_of_existing_service_applies_when_opened<Sut: Service>() {
+        const MAX_PAYLOAD_SIZE: usize = 32;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let _service = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .max_request_payload_size(MAX_PAYLOAD_SIZE)
+            .max_response_payload_size(MAX_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .max_request_payload_size(2 * MAX_PAYLOAD_SIZE)
+            .open()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_request_payload_size(), eq Some(MAX_PAYLOAD_SIZE));
+        assert_that!(sut.static_config().max_response_payload_size(), eq Some(MAX_PAYLOAD_SIZE));
+
+        let client = sut
+            .client_builder()
+            .initial_max_slice_len(2 * MAX_PAYLOAD_SIZE)
+            .create()
+            .unwrap();
+        let request = client.loan_slice(MAX_PAYLOAD_SIZE + 1);
+        assert_that!(request.err(), eq Some(LoanError::PayloadTooLarge));
+    }
+
     fn send_and_receive_increasing_requests_works<Sut: Service>(
         allocation_strategy: AllocationStrategy,
     ) {
@@ -1572,6 +1875,113 @@ mod service_request_response {
         assert_that!(active_request, is_ok);
     }
 
+    #[test]
+    fn memory_footprint_without_ports_contains_only_metadata<S: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let footprint = service.memory_footprint();
+        assert_that!(footprint.request_pool_bytes, eq 0);
+        assert_that!(footprint.response_pool_bytes, eq 0);
+        assert_that!(footprint.metadata_bytes, gt 0);
+        assert_that!(footprint.total_bytes, eq footprint.metadata_bytes);
+    }
+
+    #[test]
+    fn memory_footprint_contains_data_segments_of_all_ports<S: Service>() {
+        const NUMBER_OF_ACTIVE_REQUESTS: usize = 4;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests_per_client(NUMBER_OF_ACTIVE_REQUESTS)
+            .create()
+            .unwrap();
+        let opened_service = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open()
+            .unwrap();
+        let metadata_bytes = sut.memory_footprint().metadata_bytes;
+
+        let client = sut.client_builder().create().unwrap();
+        let server = sut.server_builder().create().unwrap();
+
+        let footprint = opened_service.memory_footprint();
+        assert_that!(
+            footprint.request_pool_bytes,
+            ge(NUMBER_OF_ACTIVE_REQUESTS * core::mem::size_of::<u64>()) as u64
+        );
+        assert_that!(footprint.response_pool_bytes, gt 0);
+        assert_that!(footprint.metadata_bytes, eq metadata_bytes);
+        assert_that!(footprint.total_bytes, eq footprint.request_pool_bytes + footprint.response_pool_bytes + metadata_bytes);
+
+        let second_client = sut.client_builder().create().unwrap();
+        assert_that!(sut.memory_footprint().request_pool_bytes, eq 2 * footprint.request_pool_bytes);
+
+        drop(second_client);
+        drop(client);
+        assert_that!(sut.memory_footprint().request_pool_bytes, eq 0);
+        assert_that!(sut.memory_footprint().response_pool_bytes, eq footprint.response_pool_bytes);
+
+        drop(server);
+        assert_that!(sut.memory_footprint().response_pool_bytes, eq 0);
+    }
+
+    #[test]
+    fn memory_footprint_contains_reallocated_data_segments<S: Service>() {
+        const SLICE_LEN: usize = 16;
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<[u8], [u8]>()
+            .create()
+            .unwrap();
+
+        let client = sut
+            .client_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let server = sut
+            .server_builder()
+            .initial_max_slice_len(SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let initial_footprint = sut.memory_footprint();
+
+        let pending_response = client.loan_slice(SLICE_LEN * 64).unwrap().send().unwrap();
+        let footprint = sut.memory_footprint();
+        assert_that!(footprint.request_pool_bytes, gt initial_footprint.request_pool_bytes);
+        assert_that!(footprint.response_pool_bytes, eq initial_footprint.response_pool_bytes);
+
+        let active_request = server.receive().unwrap().unwrap();
+        active_request
+            .loan_slice(SLICE_LEN * 64)
+            .unwrap()
+            .send()
+            .unwrap();
+        assert_that!(sut.memory_footprint().response_pool_bytes, gt initial_footprint.response_pool_bytes);
+
+        drop(active_request);
+        drop(pending_response);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
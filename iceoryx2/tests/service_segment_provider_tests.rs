@@ -0,0 +1,257 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_segment_provider {
+    use core::alloc::Layout;
+    use core::ptr::NonNull;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use iceoryx2::prelude::{AllocationStrategy, *};
+    use iceoryx2::service::segment_provider::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(Debug)]
+    struct Segment {
+        name: FileName,
+        address: usize,
+        layout: Layout,
+        number_of_mappings: usize,
+        is_removed: bool,
+    }
+
+    #[derive(Debug, Default)]
+    struct TestProvider {
+        segments: Mutex<Vec<Segment>>,
+        create_calls: AtomicUsize,
+    }
+
+    impl TestProvider {
+        fn number_of_segments(&self) -> usize {
+            self.segments
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| !s.is_removed)
+                .count()
+        }
+
+        fn release_unused(segments: &mut Vec<Segment>) {
+            segments.retain(|s| {
+                if s.is_removed && s.number_of_mappings == 0 {
+                    unsafe { std::alloc::dealloc(s.address as *mut u8, s.layout) };
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+    }
+
+    impl SegmentProvider for TestProvider {
+        fn create(
+            &self,
+            name: &FileName,
+            size: usize,
+        ) -> Result<SegmentMapping, SegmentProviderError> {
+            self.create_calls.fetch_add(1, Ordering::Relaxed);
+            let mut segments = self.segments.lock().unwrap();
+            if segments.iter().any(|s| !s.is_removed && s.name == *name) {
+                return Err(SegmentProviderError::AlreadyExists);
+            }
+
+            let layout = Layout::from_size_align(size, SystemInfo::PageSize.value()).unwrap();
+            let address = unsafe { std::alloc::alloc_zeroed(layout) };
+            if address.is_null() {
+                return Err(SegmentProviderError::OutOfMemory);
+            }
+
+            segments.push(Segment {
+                name: name.clone(),
+                address: address as usize,
+                layout,
+                number_of_mappings: 1,
+                is_removed: false,
+            });
+
+            Ok(unsafe { SegmentMapping::new(NonNull::new_unchecked(address), size) })
+        }
+
+        fn map(&self, name: &FileName) -> Result<SegmentMapping, SegmentProviderError> {
+            let mut segments = self.segments.lock().unwrap();
+            match segments
+                .iter_mut()
+                .find(|s| !s.is_removed && s.name == *name)
+            {
+                Some(s) => {
+                    s.number_of_mappings += 1;
+                    Ok(unsafe {
+                        SegmentMapping::new(
+                            NonNull::new_unchecked(s.address as *mut u8),
+                            s.layout.size(),
+                        )
+                    })
+                }
+                None => Err(SegmentProviderError::DoesNotExist),
+            }
+        }
+
+        fn unmap(&self, _name: &FileName, mapping: &SegmentMapping) {
+            let mut segments = self.segments.lock().unwrap();
+            if let Some(s) = segments
+                .iter_mut()
+                .find(|s| s.address == mapping.base_address().as_ptr() as usize)
+            {
+                s.number_of_mappings -= 1;
+            }
+            Self::release_unused(&mut segments);
+        }
+
+        fn remove(&self, name: &FileName) -> Result<bool, SegmentProviderError> {
+            let mut segments = self.segments.lock().unwrap();
+            let has_removed = match segments
+                .iter_mut()
+                .find(|s| !s.is_removed && s.name == *name)
+            {
+                Some(s) => {
+                    s.is_removed = true;
+                    true
+                }
+                None => false,
+            };
+            Self::release_unused(&mut segments);
+            Ok(has_removed)
+        }
+
+        fn list(&self) -> Result<Vec<FileName>, SegmentProviderError> {
+            Ok(self
+                .segments
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| !s.is_removed)
+                .map(|s| s.name.clone())
+                .collect())
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn registering_segment_provider_after_service_creation_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let _service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = register_segment_provider(&config, Arc::new(TestProvider::default()));
+
+        assert_that!(sut.err(), eq Some(SegmentProviderRegistrationError::ServiceAlreadyCreated));
+    }
+
+    #[test]
+    fn registering_segment_provider_twice_fails<Sut: Service>() {
+        let config = generate_isolated_config();
+
+        let sut = register_segment_provider(&config, Arc::new(TestProvider::default()));
+        assert_that!(sut, is_ok);
+
+        let sut = register_segment_provider(&config, Arc::new(TestProvider::default()));
+        assert_that!(sut.err(), eq Some(SegmentProviderRegistrationError::AlreadyRegistered));
+    }
+
+    #[test]
+    fn publisher_data_segment_is_acquired_from_segment_provider<Sut: Service>() {
+        let config = generate_isolated_config();
+        let provider = Arc::new(TestProvider::default());
+        register_segment_provider(&config, provider.clone()).unwrap();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        assert_that!(provider.create_calls.load(Ordering::Relaxed), eq 1);
+
+        publisher.send_copy(8192837).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192837);
+
+        drop(sample);
+        drop(publisher);
+        assert_that!(provider.number_of_segments(), eq 0);
+    }
+
+    #[test]
+    fn grown_publisher_data_segments_are_acquired_from_segment_provider<Sut: Service>() {
+        let config = generate_isolated_config();
+        let provider = Arc::new(TestProvider::default());
+        register_segment_provider(&config, provider.clone()).unwrap();
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let initial_create_calls = provider.create_calls.load(Ordering::Relaxed);
+
+        let sample = publisher.loan_slice_uninit(1024).unwrap();
+        let sample = sample.write_from_fn(|n| (n % 255) as u8);
+        sample.send().unwrap();
+        assert_that!(provider.create_calls.load(Ordering::Relaxed), gt initial_create_calls);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload().len(), eq 1024);
+        for (n, value) in sample.payload().iter().enumerate() {
+            assert_that!(*value, eq(n % 255) as u8);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
+    mod ipc_threadsafe {}
+
+    #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
+    mod local_threadsafe {}
+}
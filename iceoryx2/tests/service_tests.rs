@@ -29,13 +29,16 @@ mod service {
     };
     use iceoryx2::service::messaging_pattern::MessagingPattern;
     use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
-    use iceoryx2::service::{ServiceDetailsError, ServiceListError};
+    use iceoryx2::service::{
+        __internal_discovery_announcement_service_name, ServiceAnnouncement, ServiceDetailsError,
+        ServiceListError,
+    };
     use iceoryx2::testing::*;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
-    use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_bb_testing::{assert_that, test_fail};
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -297,7 +300,7 @@ mod service {
     }
 
     impl<Sut: Service> SutFactory<Sut> for BlackboardTests<Sut> {
-        type Factory = blackboard::PortFactory<Sut>;
+        type Factory = blackboard::PortFactory<Sut, u64>;
         type CreateError = BlackboardCreateError;
         type OpenError = BlackboardOpenError;
 
@@ -902,6 +905,62 @@ mod service {
         assert_that!(service_counter, eq 1);
     }
 
+    #[test]
+    fn list_services_with_attributes_returns_only_matching_services<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const NUMBER_OF_SERVICES: usize = 8;
+        let test = Factory::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let mut services = vec![];
+        let mut matching_service_ids = vec![];
+        for n in 0..NUMBER_OF_SERVICES {
+            let schema = if n % 2 == 0 { "v2" } else { "v1" };
+            let sut = test
+                .create(
+                    &node,
+                    &generate_name(),
+                    &AttributeSpecifier::new()
+                        .define(&"schema".try_into().unwrap(), &schema.try_into().unwrap()),
+                )
+                .unwrap();
+
+            if n % 2 == 0 {
+                matching_service_ids.push(sut.service_id().clone());
+            }
+            services.push(sut);
+        }
+        services.push(
+            test.create(&node, &generate_name(), &AttributeSpecifier::new())
+                .unwrap(),
+        );
+
+        let verifier = AttributeVerifier::new()
+            .require(&"schema".try_into().unwrap(), &"v2".try_into().unwrap());
+        let mut listed_services = vec![];
+        let result = Sut::list_with_attributes(&config, &verifier, |service| {
+            listed_services.push(service.static_details.service_id().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_services, len matching_service_ids.len());
+        for s in listed_services {
+            assert_that!(matching_service_ids, contains s);
+        }
+
+        let verifier = AttributeVerifier::new().require_key(&"schema".try_into().unwrap());
+        let mut service_counter = 0;
+        let result = Sut::list_with_attributes(&config, &verifier, |_| {
+            service_counter += 1;
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(service_counter, eq NUMBER_OF_SERVICES);
+    }
+
     #[test]
     fn concurrent_service_creation_and_listing_works<Sut: Service, Factory: SutFactory<Sut>>() {
         let _watch_dog = Watchdog::new_with_timeout(Duration::from_secs(120));
@@ -1179,6 +1238,92 @@ mod service {
         assert_that!(sut.service_id(), eq sut2.service_id());
     }
 
+    #[test]
+    fn created_and_removed_service_is_announced_with_its_static_config<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let attributes = AttributeSpecifier::new().define(
+            &"what is your".try_into().unwrap(),
+            &"favorite color".try_into().unwrap(),
+        );
+        let mut config = generate_isolated_config();
+        config.global.service.enable_discovery_announcements = true;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let announcements = node
+            .service_builder(&__internal_discovery_announcement_service_name())
+            .publish_subscribe::<ServiceAnnouncement>()
+            .create()
+            .unwrap();
+        let subscriber = announcements.subscriber_builder().create().unwrap();
+
+        let sut = test.create(&node, &service_name, &attributes).unwrap();
+        let service_id = sut.service_id().clone();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        match &*sample {
+            ServiceAnnouncement::ServiceAdded(static_config) => {
+                assert_that!(*static_config.name(), eq service_name);
+                assert_that!(*static_config.service_id(), eq service_id);
+                assert_that!(static_config.attributes(), eq attributes.attributes());
+            }
+            ServiceAnnouncement::ServiceRemoved(_) => test_fail!("expected ServiceAdded"),
+        }
+        drop(sample);
+
+        drop(sut);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        match &*sample {
+            ServiceAnnouncement::ServiceRemoved(static_config) => {
+                assert_that!(*static_config.name(), eq service_name);
+                assert_that!(*static_config.service_id(), eq service_id);
+            }
+            ServiceAnnouncement::ServiceAdded(_) => test_fail!("expected ServiceRemoved"),
+        }
+        drop(sample);
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn service_is_created_without_announcement_when_announcement_publishers_are_exhausted<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let mut config = generate_isolated_config();
+        config.global.service.enable_discovery_announcements = true;
+        let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let announcements = node_1
+            .service_builder(&__internal_discovery_announcement_service_name())
+            .publish_subscribe::<ServiceAnnouncement>()
+            .max_publishers(1)
+            .create()
+            .unwrap();
+        let subscriber = announcements.subscriber_builder().create().unwrap();
+
+        // the announcer of node_1 acquires the only publisher
+        let service_name_1 = generate_name();
+        let _sut_1 = test
+            .create(&node_1, &service_name_1, &AttributeSpecifier::new())
+            .unwrap();
+
+        let service_name_2 = generate_name();
+        let sut_2 = test.create(&node_2, &service_name_2, &AttributeSpecifier::new());
+        assert_that!(sut_2, is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample.static_config().name(), eq service_name_1);
+        drop(sample);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
     mod ipc {
         use iceoryx2::service::ipc::Service;
 
@@ -13,14 +13,15 @@
 #[generic_tests::define]
 mod subscriber {
     use iceoryx2::port::ReceiveError;
+    use iceoryx2::prelude::{AllocationStrategy, CallbackProgression};
     use iceoryx2::service::builder::CustomPayloadMarker;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use std::collections::HashSet;
 
     use iceoryx2::{
         node::NodeBuilder,
-        port::subscriber::SubscriberCreateError,
-        service::{service_name::ServiceName, Service},
+        port::subscriber::{SubscriberCreateError, SubscriberMarkReceivedError},
+        service::{port_factory::PortFactory, service_name::ServiceName, Service},
         testing::*,
     };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -38,6 +39,8 @@ mod subscriber {
     fn receive_error_display_works<S: Service>() {
         assert_that!(
             format!("{}", ReceiveError::ExceedsMaxBorrows), eq "ReceiveError::ExceedsMaxBorrows");
+        assert_that!(
+            format!("{}", ReceiveError::ExceedsMaxSliceLen), eq "ReceiveError::ExceedsMaxSliceLen");
     }
 
     #[test]
@@ -72,6 +75,415 @@ mod subscriber {
         }
     }
 
+    #[test]
+    fn sequence_numbers_are_consecutive_per_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        for n in 0..5 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for n in 0..5 {
+            let sample = sut.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq n);
+        }
+    }
+
+    #[test]
+    fn mark_received_records_the_highest_acknowledged_sequence_number<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.acknowledged_sequence_number(sut.id()), eq None);
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.mark_received(&sample), is_ok);
+        drop(sample);
+        assert_that!(publisher.acknowledged_sequence_number(sut.id()), eq Some(0));
+
+        let _unacknowledged_sample = sut.receive().unwrap().unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.mark_received(&sample), is_ok);
+        assert_that!(publisher.acknowledged_sequence_number(sut.id()), eq Some(2));
+        assert_that!(
+            service
+                .dynamic_config()
+                .acknowledged_sequence_number(publisher.id(), sut.id()),
+            eq Some(2)
+        );
+    }
+
+    #[test]
+    fn acknowledgements_survive_the_subscriber_until_its_slot_is_reused<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.mark_received(&sample), is_ok);
+        drop(sample);
+        drop(sut);
+
+        let sut = service.subscriber_builder().create().unwrap();
+        assert_that!(publisher.acknowledged_sequence_number(sut.id()), eq None);
+    }
+
+    #[test]
+    fn mark_received_fails_for_samples_of_other_subscribers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+        let other_subscriber = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        let sample = other_subscriber.receive().unwrap().unwrap();
+
+        assert_that!(sut.mark_received(&sample).err(), eq Some(SubscriberMarkReceivedError::SampleBelongsToDifferentSubscriber));
+        assert_that!(publisher.acknowledged_sequence_number(sut.id()), eq None);
+        assert_that!(publisher.acknowledged_sequence_number(other_subscriber.id()), eq None);
+    }
+
+    #[test]
+    fn mark_received_fails_when_publisher_is_disconnected<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        drop(publisher);
+        // updates the connections
+        assert_that!(sut.has_samples(), is_ok);
+
+        assert_that!(sut.mark_received(&sample).err(), eq Some(SubscriberMarkReceivedError::PublisherNoLongerConnected));
+    }
+
+    #[test]
+    fn peek_on_empty_buffer_returns_none<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let _publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.peek().unwrap(), is_none);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn peek_does_not_consume_the_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 1);
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 1);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peeked_sample_is_not_overwritten_by_safe_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service
+            .subscriber_builder()
+            .buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 0);
+
+        for n in 1..=2 * BUFFER_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn missed_samples_are_zero_when_nothing_is_lost<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.missed_samples().total(), eq 0);
+        assert_that!(sut.missed_samples().per_publisher(), len 0);
+
+        for n in 0..3 {
+            publisher.send_copy(n).unwrap();
+            assert_that!(sut.receive().unwrap(), is_some);
+        }
+
+        let missed_samples = sut.missed_samples();
+        assert_that!(missed_samples.total(), eq 0);
+        assert_that!(missed_samples.per_publisher(), len 1);
+        assert_that!(missed_samples.per_publisher()[0], eq(publisher.id(), 0));
+    }
+
+    #[test]
+    fn missed_samples_counts_samples_replaced_by_safe_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher_1.send_copy(0).unwrap();
+        publisher_2.send_copy(0).unwrap();
+        while sut.receive().unwrap().is_some() {}
+
+        for n in 1..=5 {
+            publisher_1.send_copy(n).unwrap();
+        }
+        publisher_2.send_copy(1).unwrap();
+        while sut.receive().unwrap().is_some() {}
+
+        let missed_samples = sut.missed_samples();
+        assert_that!(missed_samples.total(), eq 3);
+        assert_that!(missed_samples.per_publisher(), len 2);
+        assert_that!(
+            missed_samples.per_publisher().to_vec(),
+            contains(publisher_1.id(), 3)
+        );
+        assert_that!(
+            missed_samples.per_publisher().to_vec(),
+            contains(publisher_2.id(), 0)
+        );
+    }
+
+    #[test]
+    fn dropped_sample_count_counts_samples_replaced_by_safe_overflow<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+        assert_that!(sut.dropped_sample_count(), eq 0);
+
+        publisher.send_copy(0).unwrap();
+        while sut.receive().unwrap().is_some() {}
+
+        for n in 1..=5 {
+            publisher.send_copy(n).unwrap();
+        }
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.dropped_sample_count(), eq 3);
+        assert_that!(sut.dropped_sample_count(), eq sut.missed_samples().total());
+        assert_that!(publisher.overflow_count(), eq 3);
+    }
+
+    #[test]
+    fn missed_samples_does_not_report_a_gap_when_the_publisher_restarts<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        for n in 0..10 {
+            publisher.send_copy(n).unwrap();
+            assert_that!(sut.receive().unwrap(), is_some);
+        }
+        drop(publisher);
+
+        let publisher = service.publisher_builder().create().unwrap();
+        publisher.send_copy(0).unwrap();
+        assert_that!(sut.receive().unwrap(), is_some);
+
+        let missed_samples = sut.missed_samples();
+        assert_that!(missed_samples.total(), eq 0);
+        assert_that!(missed_samples.per_publisher(), len 1);
+        assert_that!(missed_samples.per_publisher()[0], eq(publisher.id(), 0));
+    }
+
+    #[test]
+    fn peek_fails_when_max_borrowed_samples_are_exceeded<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sut.peek().err(), eq Some(ReceiveError::ExceedsMaxBorrows));
+        drop(sample);
+
+        assert_that!(*sut.peek().unwrap().unwrap().payload(), eq 2);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 2);
+    }
+
+    #[test]
+    fn peek_works_for_slices<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(4)
+            .create()
+            .unwrap();
+        let mut sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_slice_copy(&[1, 2, 3]).unwrap();
+
+        assert_that!(sut.peek().unwrap().unwrap().payload(), eq [1, 2, 3]);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [1, 2, 3]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
@@ -99,6 +511,207 @@ mod subscriber {
         let _sample = sut.receive();
     }
 
+    #[test]
+    fn subscribers_of_the_same_service_can_have_distinct_buffer_sizes<Sut: Service>() {
+        const SMALL_BUFFER_SIZE: usize = 4;
+        const LARGE_BUFFER_SIZE: usize = 16;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(LARGE_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let small_sut = service
+            .subscriber_builder()
+            .buffer_size(SMALL_BUFFER_SIZE)
+            .create()
+            .unwrap();
+        let large_sut = service
+            .subscriber_builder()
+            .buffer_size(LARGE_BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        assert_that!(small_sut.buffer_size(), eq SMALL_BUFFER_SIZE);
+        assert_that!(large_sut.buffer_size(), eq LARGE_BUFFER_SIZE);
+
+        for n in 0..LARGE_BUFFER_SIZE as u64 {
+            publisher.send_copy(n).unwrap();
+        }
+
+        for n in (LARGE_BUFFER_SIZE - SMALL_BUFFER_SIZE) as u64..LARGE_BUFFER_SIZE as u64 {
+            assert_that!(*small_sut.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(small_sut.receive().unwrap(), is_none);
+
+        for n in 0..LARGE_BUFFER_SIZE as u64 {
+            assert_that!(*large_sut.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(large_sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn create_fails_when_buffer_size_exceeds_max_buffer_size_of_service<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().buffer_size(5).create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService));
+
+        let sut = service.subscriber_builder().buffer_size(4).create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn max_slice_len_is_unlimited_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+        assert_that!(sut.max_slice_len(), eq None);
+
+        let sut = service
+            .subscriber_builder()
+            .max_slice_len(0)
+            .create()
+            .unwrap();
+        assert_that!(sut.max_slice_len(), eq Some(1));
+    }
+
+    #[test]
+    fn slices_exceeding_the_max_slice_len_are_discarded<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(3)
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(MAX_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let sut = service
+            .subscriber_builder()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+
+        publisher.send_slice_copy(&[1; MAX_SLICE_LEN]).unwrap();
+        publisher.send_slice_copy(&[2; MAX_SLICE_LEN * 8]).unwrap();
+        publisher.send_slice_copy(&[3; MAX_SLICE_LEN]).unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq & [1; MAX_SLICE_LEN]);
+
+        assert_that!(sut.receive().err(), eq Some(ReceiveError::ExceedsMaxSliceLen));
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq & [3; MAX_SLICE_LEN]);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn slices_of_a_growing_publisher_are_received_without_max_slice_len<Sut: Service>() {
+        const INITIAL_SLICE_LEN: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .initial_max_slice_len(INITIAL_SLICE_LEN)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher
+            .send_slice_copy(&[5; INITIAL_SLICE_LEN * 8])
+            .unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq & [5; INITIAL_SLICE_LEN * 8]);
+    }
+
+    #[test]
+    fn connection_generation_tracks_connected_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+        let generation = sut.connection_generation().unwrap();
+        assert_that!(sut.connection_generation().unwrap(), eq generation);
+
+        let publisher_1 = service.publisher_builder().create().unwrap();
+        let publisher_2 = service.publisher_builder().create().unwrap();
+        let connected_generation = sut.connection_generation().unwrap();
+        assert_that!(connected_generation, gt generation);
+
+        let mut ids = vec![];
+        sut.list_connected_publishers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, len 2);
+        assert_that!(ids, contains publisher_1.id());
+        assert_that!(ids, contains publisher_2.id());
+
+        drop(publisher_1);
+        assert_that!(sut.connection_generation().unwrap(), gt connected_generation);
+
+        let mut ids = vec![];
+        sut.list_connected_publishers(|id| {
+            ids.push(id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(ids, eq vec![publisher_2.id()]);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -19,7 +19,7 @@ mod waitset {
     use iceoryx2::port::notifier::Notifier;
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
-    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError, WaitSetRunResult};
     use iceoryx2_bb_posix::config::test_directory;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::file::Permission;
@@ -222,6 +222,52 @@ mod waitset {
         assert_that!(receiver_1_triggered, eq true);
     }
 
+    #[test]
+    fn attachments_added_in_a_callback_are_reported_in_the_next_wakeup<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const NUMBER_OF_LATE_ATTACHMENTS: usize = 8;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener, notifier) = create_event::<S>(&node);
+        let events: Vec<_> = (0..NUMBER_OF_LATE_ATTACHMENTS)
+            .map(|_| create_event::<S>(&node))
+            .collect();
+        let _guard = sut.attach_notification(&listener).unwrap();
+        let mut late_guards = vec![];
+
+        notifier.notify().unwrap();
+        sut.wait_and_process_once(|_| {
+            for (late_listener, _) in &events {
+                late_guards.push(sut.attach_notification(late_listener).unwrap());
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        listener.try_wait_all(|_| {}).unwrap();
+
+        for (_, late_notifier) in &events {
+            late_notifier.notify().unwrap();
+        }
+
+        let mut number_of_triggered_attachments = 0;
+        sut.wait_and_process_once(|attachment_id| {
+            if late_guards
+                .iter()
+                .any(|guard| attachment_id.has_event_from(guard))
+            {
+                number_of_triggered_attachments += 1;
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(number_of_triggered_attachments, eq NUMBER_OF_LATE_ATTACHMENTS);
+    }
+
     #[test]
     fn wait_and_process_once_with_tick_interval_blocks_for_at_least_timeout<S: Service>()
     where
@@ -604,6 +650,587 @@ mod waitset {
         assert_that!(now.elapsed(), time_at_least TIMEOUT / 2);
     }
 
+    #[test]
+    fn wait_and_process_once_processes_notifications_in_priority_order<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+        let (listener_3, notifier_3) = create_event::<S>(&node);
+        let (receiver_1, sender_1) = create_socket();
+
+        let guard_1 = sut
+            .attach_notification_with_priority(&listener_1, 1)
+            .unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+        let guard_3 = sut
+            .attach_notification_with_priority(&listener_3, 200)
+            .unwrap();
+        let guard_4 = sut
+            .attach_notification_with_priority(&receiver_1, 7)
+            .unwrap();
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+        notifier_3.notify().unwrap();
+        sender_1.try_send(b"bla").unwrap();
+
+        let mut order = vec![];
+        sut.wait_and_process_once(|attachment_id| {
+            if attachment_id.has_event_from(&guard_1) {
+                order.push(1);
+            } else if attachment_id.has_event_from(&guard_2) {
+                order.push(2);
+            } else if attachment_id.has_event_from(&guard_3) {
+                order.push(3);
+            } else if attachment_id.has_event_from(&guard_4) {
+                order.push(4);
+            } else {
+                test_fail!("only attachments shall trigger");
+            }
+
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(order, eq vec![3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn wait_and_process_once_processes_mixed_attachments_in_priority_order<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, _notifier_2) = create_event::<S>(&node);
+
+        let tick_guard = sut
+            .attach_interval_with_priority(Duration::from_nanos(1), 5)
+            .unwrap();
+        let notification_guard = sut
+            .attach_notification_with_priority(&listener_1, 10)
+            .unwrap();
+        let deadline_guard = sut
+            .attach_deadline_with_priority(&listener_2, Duration::from_nanos(1), 1)
+            .unwrap();
+
+        std::thread::sleep(TIMEOUT);
+        notifier_1.notify().unwrap();
+
+        let mut order = vec![];
+        sut.wait_and_process_once(|attachment_id| {
+            if attachment_id.has_event_from(&tick_guard) {
+                order.push(1);
+            } else if attachment_id.has_event_from(&notification_guard) {
+                order.push(2);
+            } else if attachment_id.has_missed_deadline(&deadline_guard) {
+                order.push(3);
+            } else {
+                test_fail!("only attachments shall trigger");
+            }
+
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(order, eq vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn wait_and_process_once_stops_at_highest_priority_when_requested<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, notifier_1) = create_event::<S>(&node);
+        let (listener_2, notifier_2) = create_event::<S>(&node);
+
+        let _guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut
+            .attach_notification_with_priority(&listener_2, 1)
+            .unwrap();
+
+        notifier_1.notify().unwrap();
+        notifier_2.notify().unwrap();
+
+        let mut number_of_calls = 0;
+        let result = sut
+            .wait_and_process_once(|attachment_id| {
+                number_of_calls += 1;
+                assert_that!(attachment_id.has_event_from(&guard_2), eq true);
+                CallbackProgression::Stop
+            })
+            .unwrap();
+
+        assert_that!(result, eq WaitSetRunResult::StopRequest);
+        assert_that!(number_of_calls, eq 1);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn attach_external_notification_reports_event<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (receiver_1, sender_1) = create_socket();
+        let (receiver_2, _sender_2) = create_socket();
+        let fd_1 =
+            FileDescriptor::non_owning_new(unsafe { receiver_1.file_descriptor().native_handle() })
+                .unwrap();
+        let fd_2 =
+            FileDescriptor::non_owning_new(unsafe { receiver_2.file_descriptor().native_handle() })
+                .unwrap();
+
+        let guard_1 = sut.attach_external_notification(&fd_1).unwrap();
+        let guard_2 = sut.attach_external_notification(&fd_2).unwrap();
+        assert_that!(sut.len(), eq 2);
+
+        sender_1.try_send(b"bla").unwrap();
+
+        let mut triggered = vec![];
+        sut.wait_and_process_once(|attachment_id| {
+            triggered.push(attachment_id);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(triggered, len 1);
+        assert_that!(triggered[0].has_event_from(&guard_1), eq true);
+        assert_that!(triggered[0].has_event_from(&guard_2), eq false);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn detaching_external_notification_does_not_close_file_descriptor<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (receiver, sender) = create_socket();
+        let fd =
+            FileDescriptor::non_owning_new(unsafe { receiver.file_descriptor().native_handle() })
+                .unwrap();
+
+        let guard = sut.attach_external_notification(&fd).unwrap();
+        drop(guard);
+        drop(fd);
+        assert_that!(sut.is_empty(), eq true);
+
+        sender.try_send(b"bla").unwrap();
+        let mut buffer = [0u8; 3];
+        assert_that!(receiver.try_receive(&mut buffer), eq Ok(3));
+        assert_that!(&buffer, eq b"bla");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn attach_external_deadline_reports_missed_deadline<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (receiver, _sender) = create_socket();
+        let fd =
+            FileDescriptor::non_owning_new(unsafe { receiver.file_descriptor().native_handle() })
+                .unwrap();
+
+        let guard = sut
+            .attach_external_deadline(&fd, Duration::from_nanos(1))
+            .unwrap();
+
+        let mut missed_deadline = false;
+        sut.wait_and_process_once(|attachment_id| {
+            missed_deadline = attachment_id.has_missed_deadline(&guard);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(missed_deadline, eq true);
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn attach_external_notification_is_unsupported<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (receiver, _sender) = create_socket();
+        let fd =
+            FileDescriptor::non_owning_new(unsafe { receiver.file_descriptor().native_handle() })
+                .unwrap();
+
+        assert_that!(sut.attach_external_notification(&fd).err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+    }
+
+    #[test]
+    fn attach_deadline_at_wakes_up_at_absolute_deadline<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let start = Instant::now();
+        let deadline = start + TIMEOUT;
+        let guard = sut.attach_deadline_at(deadline).unwrap();
+        assert_that!(sut.len(), eq 1);
+
+        let mut number_of_wakeups = 0;
+        sut.wait_and_process_once(|attachment_id| {
+            assert_that!(attachment_id.has_event_from(&guard), eq true);
+            number_of_wakeups += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(Instant::now(), ge deadline);
+        assert_that!(number_of_wakeups, eq 1);
+    }
+
+    #[test]
+    fn attach_deadline_at_in_the_past_fires_immediately<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let _watchdog = Watchdog::new();
+        let deadline = Instant::now() - TIMEOUT;
+        std::thread::sleep(TIMEOUT);
+        let guard = sut.attach_deadline_at(deadline).unwrap();
+
+        let mut has_fired = false;
+        sut.wait_and_process_once(|attachment_id| {
+            has_fired = attachment_id.has_event_from(&guard);
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_fired, eq true);
+    }
+
+    #[test]
+    fn attach_deadline_at_fires_only_once<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let guard = sut.attach_deadline_at(Instant::now()).unwrap();
+        let _interval_guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        let mut number_of_deadline_events = 0;
+        for _ in 0..2 {
+            sut.wait_and_process_once(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    number_of_deadline_events += 1;
+                }
+                CallbackProgression::Continue
+            })
+            .unwrap();
+        }
+
+        assert_that!(number_of_deadline_events, eq 1);
+    }
+
+    #[test]
+    fn attach_deadline_at_can_be_rearmed_for_a_cyclic_schedule<S: Service>() {
+        const NUMBER_OF_CYCLES: u32 = 3;
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let start = Instant::now();
+        let mut next_cycle = start + TIMEOUT / 4;
+        let guard = sut.attach_deadline_at(next_cycle).unwrap();
+
+        let mut number_of_cycles = 0;
+        sut.wait_and_process(|attachment_id| {
+            if attachment_id.has_event_from(&guard) {
+                number_of_cycles += 1;
+                if number_of_cycles == NUMBER_OF_CYCLES {
+                    return CallbackProgression::Stop;
+                }
+                next_cycle += TIMEOUT / 4;
+                guard.reset_to(next_cycle).unwrap();
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(number_of_cycles, eq NUMBER_OF_CYCLES);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT / 4 * NUMBER_OF_CYCLES);
+    }
+
+    #[test]
+    fn reset_to_fails_for_attachments_without_absolute_deadline<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let guard = sut.attach_interval(TIMEOUT).unwrap();
+
+        assert_that!(guard.reset_to(Instant::now()).err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+    }
+
+    #[test]
+    fn attached_response_deadline_fires_when_no_response_arrives<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .response_deadline(TIMEOUT)
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+        let _server = service.server_builder().create().unwrap();
+
+        let start = Instant::now();
+        let pending_response = client.send_copy(1234).unwrap();
+        let guard = sut.attach_response_deadline(&pending_response).unwrap();
+
+        let mut has_missed_deadline = false;
+        sut.wait_and_process_once(|attachment_id| {
+            has_missed_deadline =
+                attachment_id.has_event_from(&guard) && pending_response.has_missed_deadline();
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(has_missed_deadline, eq true);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    }
+
+    #[test]
+    fn attaching_response_deadline_fails_without_response_deadline<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .disable_response_deadline()
+            .create()
+            .unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+
+        assert_that!(sut.attach_response_deadline(&pending_response).err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+        assert_that!(sut.len(), eq 0);
+    }
+
+    #[test]
+    fn attached_subscriber_is_notified_on_delivery<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new_with_timeout(TIMEOUT * 40);
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_delivery(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let guard = sut.attach_notification(&subscriber).unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let mut triggered = false;
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                triggered = attachment_id.has_event_from(&guard);
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(triggered, eq true);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn attached_subscriber_is_notified_again_while_samples_remain<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new_with_timeout(TIMEOUT * 40);
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_delivery(true)
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service
+            .subscriber_builder()
+            .buffer_size(2)
+            .create()
+            .unwrap();
+
+        let _guard = sut.attach_notification(&subscriber).unwrap();
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+
+        let mut received = vec![];
+        for _ in 0..3 {
+            sut.wait_and_process_once_with_timeout(
+                |_| {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        received.push(*sample);
+                    }
+                    CallbackProgression::Continue
+                },
+                TIMEOUT,
+            )
+            .unwrap();
+        }
+
+        assert_that!(received, eq vec![1, 2]);
+    }
+
+    #[test]
+    fn attached_subscriber_is_not_notified_after_all_samples_are_consumed<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new_with_timeout(TIMEOUT * 40);
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_delivery(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let _guard = sut.attach_notification(&subscriber).unwrap();
+        publisher.send_copy(1).unwrap();
+        while subscriber.receive().unwrap().is_some() {}
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        let mut callback_called = false;
+        sut.wait_and_process_once_with_timeout(
+            |_| {
+                callback_called = true;
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(callback_called, eq false);
+    }
+
+    #[test]
+    fn attached_server_and_pending_response_are_notified_on_delivery<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new_with_timeout(TIMEOUT * 40);
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .enable_notify_on_delivery(true)
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+
+        let server_guard = sut.attach_notification(&server).unwrap();
+        let pending_response = client.send_copy(12).unwrap();
+
+        let mut server_triggered = false;
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                server_triggered = attachment_id.has_event_from(&server_guard);
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+        assert_that!(server_triggered, eq true);
+        drop(server_guard);
+
+        let response_guard = sut.attach_notification(&pending_response).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(*active_request + 1).unwrap();
+
+        let mut response_triggered = false;
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                response_triggered = attachment_id.has_event_from(&response_guard);
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(response_triggered, eq true);
+        assert_that!(*pending_response.receive().unwrap().unwrap(), eq 13);
+    }
+
+    #[test]
+    fn attaching_subscriber_fails_without_notify_on_delivery<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .enable_notify_on_delivery(false)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let result = sut.attach_notification(&subscriber);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+
+        let result = sut.attach_deadline(&subscriber, TIMEOUT);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+        assert_that!(sut.len(), eq 0);
+    }
+
+    #[test]
+    fn attaching_server_and_pending_response_fails_without_notify_on_delivery<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .request_response::<u64, u64>()
+            .enable_notify_on_delivery(false)
+            .create()
+            .unwrap();
+        let server = service.server_builder().create().unwrap();
+        let client = service.client_builder().create().unwrap();
+        let pending_response = client.send_copy(12).unwrap();
+
+        let result = sut.attach_notification(&server);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+
+        let result = sut.attach_notification(&pending_response);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::UnsupportedAttachment));
+        assert_that!(sut.len(), eq 0);
+    }
+
     #[test]
     fn signal_handling_mechanism_can_be_configured<S: Service>() {
         let sut_1 = WaitSetBuilder::new()